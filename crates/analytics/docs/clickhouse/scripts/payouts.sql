@@ -20,11 +20,15 @@ CREATE TABLE payout_queue (
     `last_modified_at` DateTime CODEC(T64, LZ4),
     `attempt_count` UInt16,
     `status` LowCardinality(String),
+    `purpose_code` LowCardinality(Nullable(String)),
     `connector` Nullable(String),
     `connector_payout_id` String,
     `is_eligible` Nullable(Bool),
     `error_message` Nullable(String),
     `error_code` Nullable(String),
+    `reason_code` LowCardinality(Nullable(String)),
+    `reason` Nullable(String),
+    `recalled_amount` Nullable(UInt64),
     `business_country` LowCardinality(Nullable(String)),
     `business_label` Nullable(String),
     `merchant_connector_id` Nullable(String),
@@ -57,11 +61,15 @@ CREATE TABLE payout (
     `last_modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `attempt_count` UInt16,
     `status` LowCardinality(String),
+    `purpose_code` LowCardinality(Nullable(String)),
     `connector` Nullable(String),
     `connector_payout_id` String,
     `is_eligible` Nullable(Bool),
     `error_message` Nullable(String),
     `error_code` Nullable(String),
+    `reason_code` LowCardinality(Nullable(String)),
+    `reason` Nullable(String),
+    `recalled_amount` Nullable(UInt64),
     `business_country` LowCardinality(Nullable(String)),
     `business_label` Nullable(String),
     `merchant_connector_id` Nullable(String),
@@ -99,11 +107,15 @@ CREATE MATERIALIZED VIEW payout_mv TO payout (
     `last_modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `attempt_count` UInt16,
     `status` LowCardinality(String),
+    `purpose_code` LowCardinality(Nullable(String)),
     `connector` Nullable(String),
     `connector_payout_id` String,
     `is_eligible` Nullable(Bool),
     `error_message` Nullable(String),
     `error_code` Nullable(String),
+    `reason_code` LowCardinality(Nullable(String)),
+    `reason` Nullable(String),
+    `recalled_amount` Nullable(UInt64),
     `business_country` LowCardinality(Nullable(String)),
     `business_label` Nullable(String),
     `merchant_connector_id` Nullable(String),
@@ -132,11 +144,15 @@ SELECT
     last_modified_at,
     attempt_count,
     status,
+    purpose_code,
     connector,
     connector_payout_id,
     is_eligible,
     error_message,
     error_code,
+    reason_code,
+    reason,
+    recalled_amount,
     business_country,
     business_label,
     merchant_connector_id,
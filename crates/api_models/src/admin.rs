@@ -4,6 +4,7 @@ use common_utils::{
     consts,
     crypto::{Encryptable, OptionalEncryptableName},
     link_utils, pii,
+    types::MinorUnit,
 };
 use masking::Secret;
 use serde::{Deserialize, Serialize};
@@ -1172,6 +1173,9 @@ pub struct PaymentLinkConfigRequest {
     /// merchant display logo
     #[schema(value_type = Option<String>, max_length = 255, example = "https://i.pinimg.com/736x/4d/83/5c/4d835ca8aafbbb15f84d07d926fda473.jpg")]
     pub logo: Option<String>,
+    /// Dimensions in which the merchant logo is displayed, the SDK defaults apply when not provided
+    #[schema(value_type = Option<PaymentLinkLogoDimensions>)]
+    pub logo_dimensions: Option<PaymentLinkLogoDimensions>,
     /// Custom merchant name for payment link
     #[schema(value_type = Option<String>, max_length = 255, example = "hyperswitch")]
     pub seller_name: Option<String>,
@@ -1184,6 +1188,28 @@ pub struct PaymentLinkConfigRequest {
     /// Enable saved payment method option for payment link
     #[schema(default = false, example = true)]
     pub enabled_saved_payment_method: Option<bool>,
+    /// Font family for the payment link, one of `Montserrat`, `Arial`, `Helvetica`, `Verdana`,
+    /// `Georgia`, `Times New Roman` or `system-ui`
+    #[schema(value_type = Option<String>, max_length = 255, example = "Arial")]
+    pub font_family: Option<String>,
+    /// Border radius (in px) for the payment link containers, between 0 and 32
+    #[schema(value_type = Option<u8>, maximum = 32, example = 8)]
+    pub border_radius: Option<u8>,
+    /// Additional fields to collect from the customer on the payment link, at most 10
+    #[schema(value_type = Option<Vec<PaymentLinkCustomField>>)]
+    pub custom_fields: Option<Vec<PaymentLinkCustomField>>,
+    /// Bounds within which the customer can adjust the amount on the payment link, such as to add
+    /// a tip. The amount is fixed when not provided
+    #[schema(value_type = Option<PaymentLinkAdjustableAmount>)]
+    pub adjustable_amount: Option<PaymentLinkAdjustableAmount>,
+    /// Payment methods accepted on the payment link, must be enabled for the merchant. All the
+    /// enabled payment methods are accepted when not provided
+    #[schema(value_type = Option<Vec<PaymentMethod>>, example = json!(["card", "wallet"]))]
+    pub allowed_payment_methods: Option<Vec<api_enums::PaymentMethod>>,
+    /// Currency in which amounts are displayed on the payment link, must be the currency of the
+    /// payment. The currency of the payment is displayed when not provided
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub display_currency: Option<api_enums::Currency>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
@@ -1192,6 +1218,9 @@ pub struct PaymentLinkConfig {
     pub theme: String,
     /// merchant display logo
     pub logo: String,
+    /// Dimensions in which the merchant logo is displayed
+    #[serde(default)]
+    pub logo_dimensions: Option<PaymentLinkLogoDimensions>,
     /// Custom merchant name for payment link
     pub seller_name: String,
     /// Custom layout for sdk
@@ -1200,6 +1229,95 @@ pub struct PaymentLinkConfig {
     pub display_sdk_only: bool,
     /// Enable saved payment method option for payment link
     pub enabled_saved_payment_method: bool,
+    /// Font family for the payment link
+    pub font_family: Option<String>,
+    /// Border radius (in px) for the payment link containers
+    pub border_radius: Option<u8>,
+    /// Additional fields to collect from the customer on the payment link
+    #[serde(default)]
+    pub custom_fields: Vec<PaymentLinkCustomField>,
+    /// Bounds within which the customer can adjust the amount on the payment link
+    #[serde(default)]
+    pub adjustable_amount: Option<PaymentLinkAdjustableAmount>,
+    /// Payment methods accepted on the payment link, all enabled payment methods when not set
+    #[serde(default)]
+    #[schema(value_type = Option<Vec<PaymentMethod>>)]
+    pub allowed_payment_methods: Option<Vec<api_enums::PaymentMethod>>,
+    /// Currency in which amounts are displayed on the payment link, the currency of the payment
+    /// when not set
+    #[serde(default)]
+    #[schema(value_type = Option<Currency>)]
+    pub display_currency: Option<api_enums::Currency>,
+}
+
+/// Dimensions (in px) in which the merchant logo is displayed on the payment link. A dimension
+/// which is not provided is scaled to keep the aspect ratio of the logo
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkLogoDimensions {
+    /// Width of the logo, between 1 and 512
+    #[schema(minimum = 1, maximum = 512, example = 120)]
+    pub width: Option<u16>,
+    /// Height of the logo, between 1 and 512
+    #[schema(minimum = 1, maximum = 512, example = 48)]
+    pub height: Option<u16>,
+}
+
+/// Additional field collected from the customer on the payment link, the collected value is
+/// stored in the metadata of the payment under the key of the field
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkCustomField {
+    /// Label of the field displayed to the customer
+    #[schema(max_length = 255, example = "Invoice number")]
+    pub label: String,
+    /// Key under which the collected value is stored in the metadata of the payment
+    #[schema(max_length = 64, example = "invoice_number")]
+    pub key: String,
+    /// Whether the customer has to fill in the field to make the payment
+    #[serde(default)]
+    #[schema(default = false, example = true)]
+    pub required: bool,
+    /// Type of the value collected in the field
+    #[serde(rename = "type")]
+    #[schema(value_type = PaymentLinkCustomFieldType, example = "text")]
+    pub field_type: PaymentLinkCustomFieldType,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkCustomFieldType {
+    Text,
+    Number,
+    Email,
+}
+
+/// Bounds within which the customer can adjust the amount of the payment on the payment link, the
+/// adjusted amount is submitted as the amount of the payment on confirm
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkAdjustableAmount {
+    /// Minimum amount (in minor units) which the customer can pay
+    #[schema(value_type = i64, example = 1000)]
+    pub min_amount: MinorUnit,
+    /// Maximum amount (in minor units) which the customer can pay
+    #[schema(value_type = i64, example = 5000)]
+    pub max_amount: MinorUnit,
+    /// Tip amounts (in minor units) suggested to the customer, at most 5
+    #[serde(default)]
+    #[schema(value_type = Vec<i64>, example = json!([100, 200, 500]))]
+    pub suggested_tips: Vec<MinorUnit>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
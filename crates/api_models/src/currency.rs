@@ -1,5 +1,25 @@
 use common_utils::{events::ApiEventMetric, types::MinorUnit};
 
+/// Sources from which the forex rates can be fetched
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ForexProvider {
+    OpenExchangeRates,
+    Apilayer,
+}
+
 /// QueryParams to be send to convert the amount -> from_currency -> to_currency
 #[derive(Debug, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -7,15 +27,189 @@ pub struct CurrencyConversionParams {
     pub amount: MinorUnit,
     pub to_currency: String,
     pub from_currency: String,
+    /// Source of the forex rates to be used for the conversion, the default rates are used when
+    /// not passed
+    pub provider: Option<ForexProvider>,
+    /// Use the default rates instead of failing when the requested provider is unavailable
+    #[serde(default)]
+    pub allow_fallback: bool,
+    /// Number of decimal places of the returned rate, defaults to 6 and is capped at 12. It does
+    /// not affect the converted amount, which is always computed using the unrounded rate.
+    pub rate_precision: Option<u8>,
+    /// Locale in which the amounts are additionally formatted for display, as a language code
+    /// optionally followed by a region code (for example `de-DE`). Locales whose conventions are
+    /// not known are formatted in a neutral format.
+    pub locale: Option<String>,
 }
 
 /// Response to be send for convert currency route
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct CurrencyConversionResponse {
+    /// The amount which was converted, in the minor unit of the source currency
+    pub amount: MinorUnit,
+    /// The currency or crypto asset converted from
+    pub from_currency: String,
+    /// The currency or crypto asset converted to
+    pub to_currency: String,
     pub converted_amount: String,
+    /// Same as `to_currency`, retained for existing clients
     pub currency: String,
+    /// Rate at which one unit of the source currency is converted, rounded to the requested
+    /// number of decimal places
+    pub rate: String,
+    /// Source of the forex rates used for the conversion, not known for rates cached before it
+    /// was tracked
+    pub provider: Option<ForexProvider>,
+    /// The source amount formatted for display in the requested locale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_amount: Option<String>,
+    /// The converted amount formatted for display in the requested locale, rounded to the minor
+    /// unit of the currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_converted_amount: Option<String>,
+    /// Mid-market rate before the markup of the merchant was applied, rounded like `rate`. This is
+    /// present only when a markup was applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_rate: Option<String>,
+    /// Markup of the merchant applied over the mid-market rate, in basis points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markup_bps: Option<u16>,
+    /// UNIX timestamp at which the rates used for the conversion were last updated. As the rates of
+    /// the two sides can be updated at different times, this is the time of the older of them
+    pub rates_timestamp: i64,
+    /// The legs of the conversion, present only when it was triangulated through a pivot currency
+    /// as neither side is the base currency of the rates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_path: Option<CurrencyConversionRatePath>,
+}
+
+/// The legs of a conversion which was triangulated through a pivot currency
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CurrencyConversionRatePath {
+    /// The currency through which the conversion was triangulated
+    pub pivot_currency: String,
+    /// The legs of the conversion in order, from the source currency through the pivot currency
+    /// to the target currency. A fiat currency is converted to the pivot currency through the base
+    /// currency of the rates when they are quoted against another currency
+    pub legs: Vec<CurrencyConversionLeg>,
+}
+
+/// A leg of a triangulated conversion
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CurrencyConversionLeg {
+    pub from_currency: String,
+    pub to_currency: String,
+    /// Mid-market rate of the leg, rounded like the rate of the conversion
+    pub rate: String,
+}
+
+/// QueryParams to be send to retrieve the forex rates, optionally along with the conversion of an
+/// amount from_currency -> to_currency
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ForexRetrieveParams {
+    pub amount: Option<MinorUnit>,
+    pub to_currency: Option<String>,
+    pub from_currency: Option<String>,
+}
+
+/// QueryParams to be send to fetch the display metadata of currencies
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CurrencyMetadataParams {
+    /// Comma separated list of ISO 4217 currency codes
+    pub currencies: String,
+}
+
+/// Display and formatting metadata of a currency
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CurrencyMetadata {
+    /// ISO 4217 alphabetic code of the currency
+    pub iso_code: String,
+    /// Symbol used while displaying amounts in the currency
+    pub symbol: String,
+    /// Number of digits after the decimal separator in the currency's minor unit
+    pub minor_units: u8,
+}
+
+/// Response to be send for currency metadata route
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CurrencyMetadataResponse {
+    pub currencies: Vec<CurrencyMetadata>,
+}
+
+/// Request to be send to create a quote locking the rate of a conversion
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ForexQuoteCreateRequest {
+    /// The amount to be converted, in the minor unit of the source currency
+    pub amount: MinorUnit,
+    pub to_currency: String,
+    pub from_currency: String,
+}
+
+/// A conversion whose rate is locked until the quote expires. The quote can be used once, and
+/// can be cancelled until then
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ForexQuoteResponse {
+    /// Unique identifier of the quote
+    pub quote_id: String,
+    #[serde(flatten)]
+    pub conversion: CurrencyConversionResponse,
+    /// UNIX timestamp after which the quote can no longer be used
+    pub expires_at: i64,
+}
+
+/// Path of the routes acting on an existing quote
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ForexQuoteId {
+    pub quote_id: String,
+}
+
+/// State of a quote once it can no longer be used
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ForexQuoteStatus {
+    /// The quote was cancelled, either by this request or an earlier one
+    Cancelled,
+    /// The quote was used before it could be cancelled
+    Consumed,
+    /// The quote expired before it could be cancelled
+    Expired,
+}
+
+/// Response to be send for the cancellation of a quote
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ForexQuoteCancelResponse {
+    pub quote_id: String,
+    pub status: ForexQuoteStatus,
 }
 
 impl ApiEventMetric for CurrencyConversionResponse {}
 impl ApiEventMetric for CurrencyConversionParams {}
+impl ApiEventMetric for ForexRetrieveParams {}
+impl ApiEventMetric for CurrencyMetadataParams {}
+impl ApiEventMetric for CurrencyMetadataResponse {}
+impl ApiEventMetric for ForexQuoteCreateRequest {}
+impl ApiEventMetric for ForexQuoteResponse {}
+impl ApiEventMetric for ForexQuoteId {}
+impl ApiEventMetric for ForexQuoteCancelResponse {}
@@ -157,6 +157,63 @@ impl Connector {
     pub fn supports_payout_eligibility(&self, payout_method: Option<PayoutType>) -> bool {
         matches!((self, payout_method), (_, Some(PayoutType::Card)))
     }
+    /// Whether the connector checks the payout destination through its payout eligibility API,
+    /// which is then usable for verifying the destination without moving any funds
+    #[cfg(feature = "payouts")]
+    pub fn supports_payout_account_check(&self, payout_method: Option<PayoutType>) -> bool {
+        matches!((self, payout_method), (Self::Adyen, Some(PayoutType::Card)))
+    }
+    #[cfg(feature = "payouts")]
+    pub fn supports_network_token_payout(&self) -> bool {
+        matches!(self, Self::Cybersource)
+    }
+    #[cfg(feature = "payouts")]
+    pub fn supports_payout_value_date(&self) -> bool {
+        matches!(self, Self::Adyenplatform)
+    }
+    #[cfg(feature = "payouts")]
+    pub fn supports_payout_charge_bearer(&self, charge_bearer: PayoutChargeBearer) -> bool {
+        matches!(
+            (self, charge_bearer),
+            (_, PayoutChargeBearer::Sender) | (Self::Adyenplatform, _)
+        )
+    }
+    /// Whether the connector sends payouts over the settlement network when it is requested.
+    /// Connectors which don't support selecting the settlement network send payouts over the
+    /// standard network of the destination. Adyen Platform selects the network through the
+    /// priority of the transfer, whose instant priority is specific to SEPA Instant, and no
+    /// connector is able to pick between the instant networks in the US.
+    #[cfg(feature = "payouts")]
+    pub fn supports_payout_settlement_network(
+        &self,
+        settlement_network: PayoutSettlementNetwork,
+    ) -> bool {
+        match settlement_network {
+            PayoutSettlementNetwork::Ach
+            | PayoutSettlementNetwork::Sepa
+            | PayoutSettlementNetwork::Bacs => true,
+            PayoutSettlementNetwork::SepaInstant => matches!(self, Self::Adyenplatform),
+            PayoutSettlementNetwork::Rtp
+            | PayoutSettlementNetwork::FedNow
+            | PayoutSettlementNetwork::FasterPayments => false,
+        }
+    }
+    /// Maximum length of the statement descriptor of payouts, for connectors which pass it on to
+    /// the recipient
+    #[cfg(feature = "payouts")]
+    pub fn get_payout_statement_descriptor_max_length(&self) -> Option<usize> {
+        match self {
+            // The reference for the beneficiary of bank transfers to accounts other than IBANs
+            Self::Adyenplatform => Some(15),
+            // The shortest reference supported across the currency routes
+            Self::Wise => Some(10),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "payouts")]
+    pub fn supports_spaces_in_payout_statement_descriptor(&self) -> bool {
+        !matches!(self, Self::Adyenplatform)
+    }
     #[cfg(feature = "payouts")]
     pub fn is_payout_quote_call_required(&self) -> bool {
         matches!(self, Self::Wise)
@@ -305,6 +362,7 @@ impl AuthenticationConnectors {
     serde::Serialize,
     serde::Deserialize,
     strum::Display,
+    strum::EnumIter,
     strum::EnumString,
     ToSchema,
 )]
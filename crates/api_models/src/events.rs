@@ -51,6 +51,7 @@ impl_misc_api_event_type!(
     MandateResponse,
     MandateRevokedResponse,
     RetrievePaymentLinkRequest,
+    PaymentLinkPreviewRequest,
     PaymentLinkListConstraints,
     MandateId,
     DisputeListConstraints,
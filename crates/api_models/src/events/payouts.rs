@@ -1,9 +1,19 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::payouts::{
-    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
+    PayoutActionRequest, PayoutAdminRetrieveResponse, PayoutApproveRequest,
+    PayoutBatchCreateRequest, PayoutBatchCreateResponse, PayoutBulkRetrieveRequest,
+    PayoutBulkRetrieveResponse, PayoutConnectorUsageResponse, PayoutCreateRequest,
+    PayoutCreateResponse, PayoutCurrencyConversionAuditLogResponse,
+    PayoutDestinationVerificationConfirmRequest, PayoutDestinationVerificationId,
+    PayoutDestinationVerificationRequest, PayoutDestinationVerificationResponse, PayoutExportJobId,
+    PayoutExportJobResponse, PayoutHoldBalanceResponse, PayoutHoldCaptureRequest,
+    PayoutHoldCreateRequest, PayoutHoldId, PayoutHoldResponse, PayoutLinkInitiateRequest,
     PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
-    PayoutRetrieveRequest,
+    PayoutRecallRequest, PayoutReceiptResponse, PayoutReprocessRequest,
+    PayoutRetrieveByConnectorReferenceRequest, PayoutRetrieveRequest, PayoutSeriesCancelRequest,
+    PayoutSeriesCancelResponse, PayoutTemplateCreateRequest, PayoutTemplateId,
+    PayoutTemplateListResponse, PayoutTemplateResponse, PayoutVerificationRequest,
 };
 
 impl ApiEventMetric for PayoutRetrieveRequest {
@@ -30,6 +40,34 @@ impl ApiEventMetric for PayoutCreateResponse {
     }
 }
 
+impl ApiEventMetric for PayoutAdminRetrieveResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout {
+            payout_id: self.payout.payout_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutBatchCreateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutCurrencyConversionAuditLogResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout {
+            payout_id: self.payout_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PayoutActionRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Payout {
@@ -38,6 +76,65 @@ impl ApiEventMetric for PayoutActionRequest {
     }
 }
 
+impl ApiEventMetric for PayoutApproveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout {
+            payout_id: self.payout_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutVerificationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout {
+            payout_id: self.payout_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutReprocessRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout {
+            payout_id: self.payout_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutRecallRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout {
+            payout_id: self.payout_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutSeriesCancelRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutSeriesCancelResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutBulkRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+// The payout is only known once it is resolved, its event type is taken from the response
+impl ApiEventMetric for PayoutRetrieveByConnectorReferenceRequest {}
+
+impl ApiEventMetric for PayoutBulkRetrieveResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
 impl ApiEventMetric for PayoutListConstraints {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -62,6 +159,12 @@ impl ApiEventMetric for PayoutListFilters {
     }
 }
 
+impl ApiEventMetric for PayoutConnectorUsageResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
 impl ApiEventMetric for PayoutLinkInitiateRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Payout {
@@ -69,3 +172,101 @@ impl ApiEventMetric for PayoutLinkInitiateRequest {
         })
     }
 }
+
+impl ApiEventMetric for PayoutReceiptResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout {
+            payout_id: self.payout_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutTemplateCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutExportJobId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutExportJobResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutTemplateId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutTemplateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutTemplateListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PayoutDestinationVerificationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutDestinationVerificationConfirmRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutDestinationVerificationId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutDestinationVerificationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutHoldCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutHoldId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutHoldCaptureRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutHoldResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutHoldBalanceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
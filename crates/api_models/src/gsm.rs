@@ -60,6 +60,8 @@ pub struct GsmRetrieveRequest {
 pub enum GsmDecision {
     Retry,
     Requeue,
+    /// Retry a payout with its next backup destination, the failure was caused by the destination
+    RetryWithNextDestination,
     #[default]
     DoDefault,
 }
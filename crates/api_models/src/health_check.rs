@@ -1,18 +1,73 @@
+use std::collections::BTreeMap;
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RouterHealthCheckResponse {
+    /// Overall status, `degraded` if any of the components is degraded. `down` if a component other
+    /// than a connector is down, which is only reported in the Prometheus format, since a failing
+    /// probe fails the JSON check, or if the router is draining for shutdown
+    pub status: ComponentHealthStatus,
+    /// Whether the heavier probes, namely the database schema check, the locker round trip and the
+    /// connector pings, were run in addition to the basic ones
+    pub deep: bool,
+    /// Status and probe latency of each of the components which were probed
+    pub components: BTreeMap<String, ComponentHealth>,
     pub database: bool,
     pub redis: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vault: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_store: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets_manager: Option<bool>,
     #[cfg(feature = "olap")]
     pub analytics: bool,
     #[cfg(feature = "olap")]
     pub opensearch: bool,
     pub outgoing_request: bool,
+    /// Version of the running build, to verify that a deployment has rolled out
+    pub build: BuildInfo,
 }
 
 impl common_utils::events::ApiEventMetric for RouterHealthCheckResponse {}
 
+/// Version metadata of the running build. Details of the build environment, such as the compiler
+/// version and the target, are deliberately not reported
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BuildInfo {
+    /// Version of the router crate
+    pub version: String,
+    /// Short hash of the commit the binary was built from, only available in builds with git
+    /// metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// Timestamp of the commit the binary was built from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_timestamp: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct DeepHealthCheckQuery {
+    /// Probe the dependencies even if a recent result is cached, requires the admin API key
+    pub force_refresh: Option<bool>,
+    /// Also run the heavier probes, namely the database schema check, the locker round trip and the
+    /// pings of the configured connectors, requires the admin API key. These are skipped by default to keep frequent
+    /// scrapes lightweight
+    pub deep: Option<bool>,
+    /// Format of the response, defaults to `json`
+    pub format: Option<HealthCheckResponseFormat>,
+}
+
+impl common_utils::events::ApiEventMetric for DeepHealthCheckQuery {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckResponseFormat {
+    #[default]
+    Json,
+    /// Prometheus text exposition format, for monitoring stacks which scrape the health check
+    Prometheus,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SchedulerHealthCheckResponse {
     pub database: bool,
@@ -20,6 +75,55 @@ pub struct SchedulerHealthCheckResponse {
     pub outgoing_request: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentHealthStatus {
+    Up,
+    /// The probe succeeded, but took longer than its latency threshold
+    Degraded,
+    Down,
+    NotApplicable,
+    /// The probe is one of the heavier ones, and was not run as the check was not deep
+    Skipped,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ComponentHealth {
+    pub status: ComponentHealthStatus,
+    /// Time taken by the probe, in milliseconds
+    pub latency_ms: u64,
+    /// Latency beyond which the component is reported as degraded, in milliseconds
+    pub latency_threshold_ms: u64,
+}
+
+impl ComponentHealth {
+    pub fn new(state: HealthState, latency_ms: u64, latency_threshold_ms: u64) -> Self {
+        let status = match state {
+            HealthState::Running if latency_ms > latency_threshold_ms => {
+                ComponentHealthStatus::Degraded
+            }
+            HealthState::Running => ComponentHealthStatus::Up,
+            HealthState::Error => ComponentHealthStatus::Down,
+            HealthState::NotApplicable => ComponentHealthStatus::NotApplicable,
+        };
+        Self {
+            status,
+            latency_ms,
+            latency_threshold_ms,
+        }
+    }
+
+    /// Health of a component whose probe was not run
+    pub fn skipped(latency_threshold_ms: u64) -> Self {
+        Self {
+            status: ComponentHealthStatus::Skipped,
+            latency_ms: 0,
+            latency_threshold_ms,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
 pub enum HealthState {
     Running,
     Error,
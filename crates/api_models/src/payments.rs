@@ -4051,6 +4051,9 @@ pub struct OrderDetailsWithAmount {
     pub quantity: u16,
     /// the amount per quantity of product
     pub amount: i64,
+    /// The currency of the amount of this product, if it differs from the currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
     // Does the order includes shipping
     pub requires_shipping: Option<bool>,
     /// The image URL of the product
@@ -4988,6 +4991,29 @@ pub struct RetrievePaymentLinkRequest {
     pub client_secret: Option<String>,
 }
 
+/// Sample payment details used to render a preview of the payment link page, no payment is
+/// created for these
+#[derive(Debug, serde::Deserialize, Clone, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkPreviewRequest {
+    /// The sample amount, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The sample currency
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The sample description shown on the payment page
+    #[schema(example = "Monthly subscription")]
+    pub description: Option<String>,
+    /// The sample return url shown on the payment page
+    #[schema(example = "https://hyperswitch.io")]
+    pub return_url: Option<String>,
+    /// The sample products shown on the payment page
+    pub order_details: Option<Vec<OrderDetailsWithAmount>>,
+    /// The configuration of the payment link page to preview
+    pub payment_link_config: admin::PaymentLinkConfig,
+}
+
 #[derive(Clone, Debug, serde::Serialize, PartialEq, ToSchema)]
 pub struct PaymentLinkResponse {
     pub link: String,
@@ -5037,11 +5063,14 @@ pub struct PaymentLinkDetails {
     pub amount: String,
     pub currency: api_enums::Currency,
     pub pub_key: String,
-    pub client_secret: String,
+    /// Serialized as is for the payment link page, but masked in `Debug` so that it never ends up
+    /// in the server logs
+    pub client_secret: Secret<String>,
     pub payment_id: String,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub session_expiry: PrimitiveDateTime,
     pub merchant_logo: String,
+    pub logo_dimensions: Option<admin::PaymentLinkLogoDimensions>,
     pub return_url: String,
     pub merchant_name: String,
     pub order_details: Option<Vec<OrderDetailsWithStringAmount>>,
@@ -5051,6 +5080,9 @@ pub struct PaymentLinkDetails {
     pub sdk_layout: String,
     pub display_sdk_only: bool,
     pub enabled_saved_payment_method: bool,
+    pub custom_fields: Vec<admin::PaymentLinkCustomField>,
+    pub adjustable_amount: Option<admin::PaymentLinkAdjustableAmount>,
+    pub allowed_payment_methods: Option<Vec<api_enums::PaymentMethod>>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -5142,6 +5174,13 @@ pub struct OrderDetailsWithStringAmount {
     pub quantity: u16,
     /// the amount per quantity of product
     pub amount: String,
+    /// The amount per quantity of product in the currency it was specified in, if it differs from
+    /// the currency of the payment
+    pub original_amount: Option<String>,
+    /// The currency the amount per quantity of product was specified in, if it differs from the
+    /// currency of the payment
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub original_currency: Option<api_enums::Currency>,
     /// Product Image link
     pub product_img_link: Option<String>,
 }
@@ -6,7 +6,7 @@ use common_utils::{
 };
 use masking::Secret;
 use serde::{Deserialize, Serialize};
-use time::PrimitiveDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 use utoipa::ToSchema;
 
 use crate::{enums as api_enums, payments};
@@ -18,6 +18,8 @@ pub enum PayoutRequest {
     PayoutRetrieveRequest(PayoutRetrieveRequest),
 }
 
+/// Request to create or update a payout. Unknown fields are rejected, unless the request is sent
+/// with the `X-Ignore-Unknown-Fields: true` header, in which case they are ignored.
 #[derive(Default, Debug, Deserialize, Serialize, Clone, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PayoutCreateRequest {
@@ -45,6 +47,24 @@ pub struct PayoutCreateRequest {
     #[schema(value_type = Currency, example = "USD")]
     pub currency: Option<api_enums::Currency>,
 
+    /// The currency in which `amount` is passed and the payout is funded, defaults to `currency`.
+    /// When it differs from `currency`, the amount is converted into `currency` at the current
+    /// forex rates, and the recipient is paid the converted amount
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub source_currency: Option<api_enums::Currency>,
+
+    /// Identifier of a forex quote locking the rate at which `amount` is converted from
+    /// `source_currency` into `currency`. The quote has to be for the same amount and currencies,
+    /// and can not be used again
+    #[schema(value_type = Option<String>, example = "fxq_Mp3Kx0yRk2qCIdbv6Dnd")]
+    pub forex_quote_id: Option<String>,
+
+    /// How the amount converted from `source_currency` is brought to the minor unit of `currency`,
+    /// defaults to rounding it. The part of the converted amount which is not paid is reported as
+    /// `conversion_residual`
+    #[schema(value_type = Option<PayoutConversionRounding>, example = "round")]
+    pub conversion_rounding: Option<api_enums::PayoutConversionRounding>,
+
     /// Specifies routing algorithm for selecting a connector
     #[schema(value_type = Option<RoutingAlgorithm>, example = json!({
         "type": "single",
@@ -68,8 +88,16 @@ pub struct PayoutCreateRequest {
     #[schema(value_type = Option<PayoutMethodData>)]
     pub payout_method_data: Option<PayoutMethodData>,
 
+    /// An ordered list of destinations for the payout, to be passed instead of
+    /// `payout_method_data`. The first destination is the primary one, the rest are backups which
+    /// are tried in order when the payout fails because of its destination (for example, a closed
+    /// account). All the destinations must be of the same payout method.
+    #[schema(value_type = Option<Vec<PayoutMethodData>>)]
+    pub payout_destinations: Option<Vec<PayoutMethodData>>,
+
     /// The billing address for the payout
-    #[schema(value_type = Option<Object>, example = json!(r#"{
+    /// This field will be deprecated soon, use `recipient.billing` instead
+    #[schema(deprecated, value_type = Option<Object>, example = json!(r#"{
         "address": {
             "line1": "1467",
             "line2": "Harrison Street",
@@ -94,21 +122,34 @@ pub struct PayoutCreateRequest {
     pub auto_fulfill: Option<bool>,
 
     /// description: The customer's email address
-    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    /// This field will be deprecated soon, use `recipient.email` instead
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com", deprecated)]
     pub email: Option<Email>,
 
     /// description: The customer's name
-    #[schema(value_type = Option<String>, max_length = 255, example = "John Test")]
+    /// This field will be deprecated soon, use `recipient.name` instead
+    #[schema(value_type = Option<String>, max_length = 255, example = "John Test", deprecated)]
     pub name: Option<Secret<String>>,
 
     /// The customer's phone number
-    #[schema(value_type = Option<String>, max_length = 255, example = "9123456789")]
+    /// This field will be deprecated soon, use `recipient.phone` instead
+    #[schema(value_type = Option<String>, max_length = 255, example = "9123456789", deprecated)]
     pub phone: Option<Secret<String>>,
 
     /// The country code for the customer phone number
-    #[schema(max_length = 255, example = "+1")]
+    /// This field will be deprecated soon, use `recipient.phone_country_code` instead
+    #[schema(max_length = 255, example = "+1", deprecated)]
     pub phone_country_code: Option<String>,
 
+    /// Details of the recipient of the payout
+    #[schema(value_type = Option<PayoutRecipient>)]
+    pub recipient: Option<PayoutRecipient>,
+
+    /// Details of the originator (payer) of the payout, required by some connectors for some
+    /// destination countries. Defaults to the merchant's registered details when not provided.
+    #[schema(value_type = Option<PayoutOriginator>)]
+    pub originator: Option<PayoutOriginator>,
+
     /// It's a token used for client side verification.
     #[schema(value_type = String, example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
     pub client_secret: Option<String>,
@@ -125,12 +166,14 @@ pub struct PayoutCreateRequest {
     #[schema(example = "food", value_type = Option<String>)]
     pub business_label: Option<String>,
 
-    /// A description of the payout
+    /// A description of the payout. The template variables `{date}`, `{customer_id}` and
+    /// `{sequence}` can be used, which are resolved when the payout is disbursed
     #[schema(example = "It's my first payout request", value_type = String)]
     pub description: Option<String>,
 
     /// Type of entity to whom the payout is being carried out to
-    #[schema(value_type = PayoutEntityType, example = "Individual")]
+    /// This field will be deprecated soon, use `recipient.entity_type` instead
+    #[schema(value_type = PayoutEntityType, example = "Individual", deprecated)]
     pub entity_type: Option<api_enums::PayoutEntityType>,
 
     /// Specifies whether or not the payout request is recurring
@@ -149,10 +192,63 @@ pub struct PayoutCreateRequest {
     /// associated with the merchant account will be used.
     pub profile_id: Option<String>,
 
-    /// The send method for processing payouts
+    /// The send method for processing payouts, which selects the rail the connector sends the
+    /// payout over. Payouts are sent over the connector's regular rail if not passed.
     #[schema(value_type = PayoutSendPriority, example = "instant")]
     pub priority: Option<api_enums::PayoutSendPriority>,
 
+    /// Whether to send the payout using the `regular` priority, if the connector does not support
+    /// the requested priority, and over the standard network of the destination, if the connector
+    /// does not support the requested settlement network. If not set, the payout fails for such
+    /// connectors.
+    #[schema(default = false, example = true)]
+    pub fallback_to_standard: Option<bool>,
+
+    /// Regulatory purpose of payment code, required for payouts to countries which mandate it.
+    /// It must be one of the codes allowed for the destination country.
+    #[schema(max_length = 64, example = "P1301")]
+    pub purpose_code: Option<String>,
+
+    /// The URL to which status notifications of this payout are sent, overriding the webhook URL configured in the business profile.
+    /// It must be an absolute https URL.
+    #[schema(value_type = Option<String>, example = "https://merchant.example.com/webhooks/payouts")]
+    pub webhook_url: Option<String>,
+
+    /// The date on which the funds are to be credited to the recipient, for bank payouts. It must
+    /// be a business day which is not in the past. It is ignored when the connector does not
+    /// support value dates
+    #[schema(value_type = Option<String>, format = Date, example = "2024-07-01")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601_date::option")]
+    pub value_date: Option<time::Date>,
+
+    /// The party bearing the fees of the payout. Defaults to `sender`, in which case the recipient
+    /// is credited the full amount. Bearers other than `sender` are supported only by some connectors
+    #[schema(value_type = Option<PayoutChargeBearer>, example = "shared")]
+    pub charge_bearer: Option<api_enums::PayoutChargeBearer>,
+
+    /// The settlement network to send the bank payout over, for destinations served by multiple
+    /// networks. It must serve the destination currency and country of the payout. The connector
+    /// picks the network as per the `priority` of the payout if not passed
+    #[schema(value_type = Option<PayoutSettlementNetwork>, example = "rtp")]
+    pub settlement_network: Option<api_enums::PayoutSettlementNetwork>,
+
+    /// The descriptor shown to the recipient on their statement, made up of letters, digits and
+    /// spaces. Defaults to the merchant name, and an empty string means the payout has no
+    /// descriptor. It is shortened to the length supported by the connector
+    #[schema(max_length = 35, example = "Acme Corp")]
+    pub statement_descriptor: Option<String>,
+
+    /// Identifier of the payout in the systems of the merchant, such as an ERP reference, made up
+    /// of letters, digits, hyphens and underscores. Unlike `payout_id`, this is not used for
+    /// idempotency, and is unique only when the merchant has opted into unique references
+    #[schema(max_length = 64, example = "INV-2024-0042")]
+    pub merchant_reference_id: Option<String>,
+
+    /// Whether the documents of the recipient need to be verified before the payout is made. Such
+    /// payouts are held in `pending_verification` until the result of the verification is posted
+    #[schema(default = false, example = false)]
+    pub requires_verification: Option<bool>,
+
     /// Whether to get the payout link (if applicable)
     #[schema(default = false, example = true)]
     pub payout_link: Option<bool>,
@@ -165,6 +261,75 @@ pub struct PayoutCreateRequest {
     /// (900) for 15 mins
     #[schema(value_type = Option<u32>, example = 900)]
     pub session_expiry: Option<u32>,
+
+    /// Time at which a confirmed payout is to be disbursed. The payout remains in `scheduled`
+    /// status until then. This needs to be a time in the future.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+
+    /// Identifier of the recurring series the payout is a run of, made up of letters, digits,
+    /// hyphens and underscores. The scheduled runs of a series can be cancelled together
+    #[schema(max_length = 64, example = "payroll-2024-monthly")]
+    pub series_id: Option<String>,
+
+    /// Identifier of a payout template. The currency, connector, payout type, entity type,
+    /// description and priority of the template are used when they are not passed in the request.
+    /// Only applicable while creating a payout
+    #[schema(example = "payout_template_mbabizu24mvu3mela5njyhpit4")]
+    pub template_id: Option<String>,
+}
+
+/// Details of the recipient of a payout
+#[derive(Default, Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutRecipient {
+    /// Name of the recipient. For entities other than individuals, this is the registered name of
+    /// the organization
+    #[schema(value_type = Option<String>, max_length = 255, example = "John Test")]
+    pub name: Option<Secret<String>>,
+
+    /// Email address of the recipient
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Email>,
+
+    /// Phone number of the recipient
+    #[schema(value_type = Option<String>, max_length = 255, example = "9123456789")]
+    pub phone: Option<Secret<String>>,
+
+    /// The country code for the recipient's phone number
+    #[schema(max_length = 255, example = "+1")]
+    pub phone_country_code: Option<String>,
+
+    /// Type of entity the recipient is
+    #[schema(value_type = Option<PayoutEntityType>, example = "Individual")]
+    pub entity_type: Option<api_enums::PayoutEntityType>,
+
+    /// The billing address of the recipient
+    #[schema(value_type = Option<Address>)]
+    pub billing: Option<payments::Address>,
+}
+
+/// Details of the originator (payer) of a payout
+#[derive(Default, Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutOriginator {
+    /// Type of the originator's entity
+    #[schema(value_type = Option<PayoutEntityType>, example = "Company")]
+    pub entity_type: Option<api_enums::PayoutEntityType>,
+
+    /// Name of the originator. For entities other than individuals, this is the registered name of
+    /// the organization
+    #[schema(value_type = Option<String>, max_length = 255, example = "Acme Inc")]
+    pub name: Option<Secret<String>>,
+
+    /// The address of the originator
+    #[schema(value_type = Option<AddressDetails>)]
+    pub address: Option<payments::AddressDetails>,
+
+    /// Identifier of the originator, such as its business registration or tax identification number
+    #[schema(value_type = Option<String>, max_length = 255, example = "12345678")]
+    pub identifier: Option<Secret<String>>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, Clone, ToSchema)]
@@ -188,6 +353,8 @@ pub enum PayoutMethodData {
     Card(CardPayout),
     Bank(Bank),
     Wallet(Wallet),
+    StoreCredit(StoreCredit),
+    NetworkToken(NetworkTokenPayout),
 }
 
 impl Default for PayoutMethodData {
@@ -215,6 +382,34 @@ pub struct CardPayout {
     pub card_holder_name: Option<Secret<String>>,
 }
 
+/// A card payout made to a network token issued for the card, instead of the card number
+#[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct NetworkTokenPayout {
+    /// The network token issued for the card by the card network
+    #[schema(value_type = String, example = "4895370012003478")]
+    pub network_token: Secret<String>,
+
+    /// The expiry month of the network token
+    #[schema(value_type = String, example = "12")]
+    pub token_expiry_month: Secret<String>,
+
+    /// The expiry year of the network token
+    #[schema(value_type = String, example = "2027")]
+    pub token_expiry_year: Secret<String>,
+
+    /// The cryptogram generated by the card network for the network token
+    #[schema(value_type = String, example = "AgAAAAAABk4DWZ4C28yUQAAAAAA=")]
+    pub token_cryptogram: Secret<String>,
+
+    /// The electronic commerce indicator returned along with the cryptogram, if any
+    #[schema(example = "05")]
+    pub eci: Option<String>,
+
+    /// The card holder's name
+    #[schema(value_type = Option<String>, example = "John Doe")]
+    pub card_holder_name: Option<Secret<String>>,
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum Bank {
@@ -346,6 +541,18 @@ pub struct Venmo {
     pub telephone_number: Option<Secret<String>>,
 }
 
+/// Store credit disbursed into the merchant's own ledger instead of through a connector
+#[derive(Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct StoreCredit {
+    /// The identifier of the customer to be credited
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier of the store credit account to be credited, if the customer has several
+    #[schema(value_type = Option<String>, example = "credit_acc_8hd3l1qw")]
+    pub credit_account_id: Option<String>,
+}
+
 #[derive(Debug, ToSchema, Clone, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PayoutCreateResponse {
@@ -373,7 +580,8 @@ pub struct PayoutCreateResponse {
     pub currency: api_enums::Currency,
 
     /// The connector used for the payout
-    #[schema(example = "wise")]
+    /// This field will be deprecated soon, use `connector_details.connector` instead
+    #[schema(example = "wise", deprecated)]
     pub connector: Option<String>,
 
     /// The payout method that is to be used
@@ -458,29 +666,117 @@ pub struct PayoutCreateResponse {
     pub status: api_enums::PayoutStatus,
 
     /// If there was an error while calling the connector the error message is received here
-    #[schema(value_type = String, example = "Failed while verifying the card")]
+    /// This field will be deprecated soon, use `connector_details.error_message` instead
+    #[schema(value_type = String, example = "Failed while verifying the card", deprecated)]
     pub error_message: Option<String>,
 
     /// If there was an error while calling the connectors the code is received here
-    #[schema(value_type = String, example = "E0001")]
+    /// This field will be deprecated soon, use `connector_details.error_code` instead
+    #[schema(value_type = String, example = "E0001", deprecated)]
     pub error_code: Option<String>,
 
     /// The business profile that is associated with this payout
     pub profile_id: String,
 
-    /// Time when the payout was created
-    #[schema(example = "2022-09-10T10:11:12Z")]
-    #[serde(with = "common_utils::custom_serde::iso8601::option")]
-    pub created: Option<PrimitiveDateTime>,
+    /// Time when the payout was created, in UTC unless a `timezone` was requested
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option_with_offset")]
+    pub created: Option<OffsetDateTime>,
 
     /// Underlying processor's payout resource ID
-    #[schema(value_type = Option<String>, example = "S3FC9G9M2MVFDXT5")]
+    /// This field will be deprecated soon, use `connector_details.connector_payout_id` instead
+    #[schema(value_type = Option<String>, example = "S3FC9G9M2MVFDXT5", deprecated)]
     pub connector_transaction_id: Option<String>,
 
+    /// Details of the payout as reported by the connector, kept separate from the payout state
+    /// maintained by Hyperswitch
+    #[schema(value_type = Option<PayoutConnectorDetails>)]
+    pub connector_details: Option<PayoutConnectorDetails>,
+
     /// Payout's send priority (if applicable)
     #[schema(value_type = Option<PayoutSendPriority>, example = "instant")]
     pub priority: Option<api_enums::PayoutSendPriority>,
 
+    /// The settlement network the payout is sent over (if applicable). This is the standard network
+    /// of the destination if the requested network was not supported by the connector
+    #[schema(value_type = Option<PayoutSettlementNetwork>, example = "rtp")]
+    pub settlement_network: Option<api_enums::PayoutSettlementNetwork>,
+
+    /// Regulatory purpose of payment code of the payout (if applicable)
+    #[schema(value_type = Option<String>, example = "P1301")]
+    pub purpose_code: Option<String>,
+
+    /// The URL to which status notifications of this payout are sent (if overridden)
+    #[schema(value_type = Option<String>, example = "https://merchant.example.com/webhooks/payouts")]
+    pub webhook_url: Option<String>,
+
+    /// The date on which the funds are to be credited to the recipient. This is not present when
+    /// the connector of the payout does not support value dates
+    #[schema(value_type = Option<String>, format = Date, example = "2024-07-01")]
+    #[serde(with = "common_utils::custom_serde::iso8601_date::option")]
+    pub value_date: Option<time::Date>,
+
+    /// Breakdown of the fees of the payout, by the party bearing them
+    pub fee_breakdown: PayoutFeeBreakdown,
+
+    /// The descriptor shown to the recipient on their statement, as passed to the connector. This
+    /// is not present when the payout has no descriptor, or its connector does not support them
+    #[schema(value_type = Option<String>, example = "Acme Corp")]
+    pub statement_descriptor: Option<String>,
+
+    /// Identifier of the payout in the systems of the merchant
+    #[schema(value_type = Option<String>, example = "INV-2024-0042")]
+    pub merchant_reference_id: Option<String>,
+
+    /// Identifier of the recurring series the payout is a run of
+    #[schema(value_type = Option<String>, example = "payroll-2024-monthly")]
+    pub series_id: Option<String>,
+
+    /// The amount passed for the payout, in the lowest denomination of `source_currency`. This is
+    /// present only when the amount was converted from a different currency
+    #[schema(value_type = Option<i64>, example = 920)]
+    pub source_amount: Option<common_utils::types::MinorUnit>,
+
+    /// The currency the amount of the payout was converted from. This is present only when the
+    /// amount was converted from a different currency
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub source_currency: Option<api_enums::Currency>,
+
+    /// Rate at which one unit of `source_currency` was converted into `currency`. This is present
+    /// only when the amount was converted from a different currency
+    #[schema(value_type = Option<String>, example = "1.086957")]
+    pub exchange_rate: Option<String>,
+
+    /// Source of the forex rates the amount was converted at. This is not present for payouts
+    /// which were not converted
+    #[schema(value_type = Option<String>, example = "open_exchange_rates")]
+    pub exchange_rate_source: Option<crate::currency::ForexProvider>,
+
+    /// Time at which the forex rates the amount was converted at were last updated, in UTC unless
+    /// a `timezone` was requested. This is not present for payouts which were not converted
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option_with_offset"
+    )]
+    pub exchange_rate_timestamp: Option<OffsetDateTime>,
+
+    /// How the converted amount was brought to the minor unit of `currency`. This is present only
+    /// when the amount was converted from a different currency
+    #[schema(value_type = Option<PayoutConversionRounding>, example = "round")]
+    pub conversion_rounding: Option<api_enums::PayoutConversionRounding>,
+
+    /// The converted amount less the amount paid, in the major unit of `currency`. It is negative
+    /// when the converted amount was rounded up. This is present only when the amount was
+    /// converted from a different currency, after the residual was tracked
+    #[schema(value_type = Option<String>, example = "0.345")]
+    pub conversion_residual: Option<String>,
+
+    /// Time taken by the payout to reach a terminal state since its creation, in seconds.
+    /// This is not present for payouts which are yet to reach a terminal state.
+    #[schema(example = 3600)]
+    pub time_to_fulfillment: Option<i64>,
+
     /// List of attempts
     #[schema(value_type = Option<Vec<PayoutAttemptResponse>>)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -489,6 +785,121 @@ pub struct PayoutCreateResponse {
     // If payout link is request, this represents response on
     #[schema(value_type = Option<PayoutLinkResponse>)]
     pub payout_link: Option<PayoutLinkResponse>,
+
+    /// Identifier of the user or API key which approved the payout, for payouts which required an
+    /// approval
+    #[schema(value_type = Option<String>, example = "user_7ah2k9a2wlqmvx8p1s3d")]
+    pub approved_by: Option<String>,
+
+    /// Time when the payout was approved, in UTC unless a `timezone` was requested
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option_with_offset"
+    )]
+    pub approved_at: Option<OffsetDateTime>,
+
+    /// Time when the documents of the recipient were verified, in UTC unless a `timezone` was
+    /// requested
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option_with_offset"
+    )]
+    pub verified_at: Option<OffsetDateTime>,
+
+    /// Reference of the document verification of the recipient, in the systems of the verifier
+    #[schema(value_type = Option<String>, example = "kyc_check_7f3a2b")]
+    pub verification_reference: Option<String>,
+
+    /// Machine readable reason for the latest status change of the payout, if any
+    #[schema(value_type = Option<PayoutStatusReasonCode>, example = "connector_declined")]
+    pub reason_code: Option<api_enums::PayoutStatusReasonCode>,
+
+    /// Free text explanation for the latest status change of the payout, if any
+    #[schema(value_type = Option<String>, example = "Insufficient funds in the source account")]
+    pub reason: Option<String>,
+
+    /// Amount recalled by the bank of the recipient, for recalled payouts
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub recalled_amount: Option<common_utils::types::MinorUnit>,
+
+    /// Position in `payout_destinations` of the destination used by the latest attempt of the
+    /// payout, `0` being the primary destination. This is not present for payouts which were not
+    /// created with `payout_destinations`.
+    #[schema(example = 1)]
+    pub destination_index: Option<i16>,
+
+    /// Masked details of the payout method submitted for the payout, retaining only the last four
+    /// characters of the card number or account identifier
+    #[schema(value_type = Option<String>, example = "************1234")]
+    pub payout_method_masked: Option<String>,
+
+    /// Details of the bank account the payout is sent to, as normalized before being passed to the
+    /// connector, with the account identifiers masked. This is present only for bank payouts
+    pub bank_details: Option<PayoutBankDetails>,
+
+    /// Time by which the payout is estimated to arrive at the recipient, based on the connector,
+    /// the payout type and the priority of the payout, from the time the payout is submitted or
+    /// scheduled to be. Bank payouts taking one or more days are estimated to arrive on the start
+    /// of a business day. The estimate is in UTC unless a `timezone` was requested. This is not
+    /// present when no estimate is available
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-12T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option_with_offset")]
+    pub estimated_arrival: Option<OffsetDateTime>,
+
+    /// The date on which the payout is processed. Payouts submitted after the cutoff time of their
+    /// currency and payout type are processed on the next business day
+    #[schema(value_type = Option<String>, format = Date, example = "2022-09-12")]
+    #[serde(with = "common_utils::custom_serde::iso8601_date::option")]
+    pub processing_date: Option<time::Date>,
+}
+
+/// Breakdown of the fees of a payout, by the party bearing them
+#[derive(Default, Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub struct PayoutFeeBreakdown {
+    /// The party bearing the fees of the payout
+    #[schema(value_type = PayoutChargeBearer, example = "sender")]
+    pub charge_bearer: api_enums::PayoutChargeBearer,
+
+    /// Amount credited to the recipient, in the lowest denomination of the currency. Fees deducted
+    /// by the connector or intermediary banks are not known in advance, so this is present only
+    /// when the sender bears all fees
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub net_amount: Option<common_utils::types::MinorUnit>,
+}
+
+/// Normalized details of a bank account, with the account identifiers masked
+#[derive(Default, Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub struct PayoutBankDetails {
+    /// Bank name
+    #[schema(example = "Deutsche Bank")]
+    pub bank_name: Option<String>,
+
+    /// Bank country code
+    #[schema(value_type = Option<CountryAlpha2>, example = "DE")]
+    pub bank_country_code: Option<api_enums::CountryAlpha2>,
+
+    /// International Bank Account Number, retaining only the country code, the check digits and
+    /// the last four characters
+    #[schema(example = "DE89**************3000")]
+    pub iban: Option<String>,
+
+    /// Bank Identifier Code (bic) / Swift Code
+    #[schema(example = "COBADEFFXXX")]
+    pub bic: Option<String>,
+
+    /// Bank account number, retaining only the last four characters
+    #[schema(example = "*****3456")]
+    pub bank_account_number: Option<String>,
+
+    /// Sort code of the bank, as digits without separators
+    #[schema(example = "987654")]
+    pub bank_sort_code: Option<String>,
+
+    /// Routing number of the bank
+    #[schema(example = "110000000")]
+    pub bank_routing_number: Option<String>,
 }
 
 #[derive(
@@ -528,10 +939,27 @@ pub struct PayoutAttemptResponse {
     pub unified_message: Option<String>,
 }
 
+#[derive(Default, Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub struct PayoutConnectorDetails {
+    /// The connector used for the payout
+    #[schema(example = "wise")]
+    pub connector: Option<String>,
+    /// Underlying processor's payout resource ID
+    #[schema(value_type = Option<String>, example = "S3FC9G9M2MVFDXT5")]
+    pub connector_payout_id: Option<String>,
+    /// Error code received from the connector, if any
+    #[schema(example = "E0001")]
+    pub error_code: Option<String>,
+    /// Error message received from the connector, if any
+    #[schema(example = "Failed while verifying the card")]
+    pub error_message: Option<String>,
+}
+
 #[derive(Default, Debug, Clone, Deserialize, ToSchema)]
 pub struct PayoutRetrieveBody {
     pub force_sync: Option<bool>,
     pub merchant_id: Option<String>,
+    pub timezone: Option<String>,
 }
 
 #[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
@@ -553,6 +981,622 @@ pub struct PayoutRetrieveRequest {
 
     /// The identifier for the Merchant Account.
     pub merchant_id: Option<String>,
+
+    /// IANA time zone name in which the timestamps in the response are to be returned
+    /// (defaults to UTC)
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: Option<String>,
+}
+
+#[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBulkRetrieveRequest {
+    /// The list of payout identifiers to be retrieved (maximum 50)
+    #[schema(max_items = 50, example = json!(["payout_mbabizu24mvu3mela5njyhpit4"]))]
+    pub payout_ids: Vec<String>,
+
+    /// `force_sync` with the connector to get payout details, applies only to the payouts
+    /// which are not in a terminal state
+    /// (defaults to false)
+    #[schema(value_type = Option<bool>, default = false, example = true)]
+    pub force_sync: Option<bool>,
+
+    /// The identifier for the Merchant Account.
+    pub merchant_id: Option<String>,
+}
+
+#[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutRetrieveByConnectorReferenceRequest {
+    /// The identifier assigned to the payout by the connector
+    #[schema(example = "e7a2b4c1-9f0d-4c3e-8a21-5b6f3d2e1a90")]
+    pub connector_payout_id: String,
+
+    /// The connector which processed the payout, required when the same reference is known to
+    /// more than one connector
+    #[schema(value_type = Option<PayoutConnectors>, example = "wise")]
+    pub connector: Option<api_enums::PayoutConnectors>,
+
+    /// IANA time zone name in which the timestamps in the response are to be returned
+    /// (defaults to UTC)
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBulkRetrieveResponse {
+    /// The list of payouts which were found
+    pub data: Vec<PayoutCreateResponse>,
+
+    /// The payout identifiers which do not exist in our records
+    pub not_found: Vec<String>,
+}
+
+/// A batch of payouts created together, such as a run of vendor payments across regions. Each
+/// item is a payout of its own, in its own currency and through its own connectors
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// Identifier of the batch, unique per merchant. The payout of an item is identified by the
+    /// batch and the position of the item unless the item passes its own `payout_id`, so retrying
+    /// the batch returns the payouts already created for its items instead of creating them again
+    #[schema(max_length = 60, example = "vendor_run_2024_07")]
+    pub batch_id: String,
+
+    /// The payouts of the batch (maximum 100)
+    #[schema(max_items = 100)]
+    pub items: Vec<PayoutCreateRequest>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchCreateResponse {
+    /// Identifier of the batch
+    #[schema(example = "vendor_run_2024_07")]
+    pub batch_id: String,
+
+    /// The result of each item, in the order of the items in the request
+    pub items: Vec<PayoutBatchItemResponse>,
+}
+
+/// Result of an item of a payout batch. Items are processed independently, an item which fails
+/// does not prevent the other items from being created
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    /// Position of the item in the batch, starting from 0
+    #[schema(example = 0)]
+    pub index: usize,
+
+    /// Identifier of the payout of the item
+    #[schema(example = "vendor_run_2024_07_0")]
+    pub payout_id: String,
+
+    /// The currency of the payout of the item
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The payout of the item, present when it was created now or by an earlier attempt of the
+    /// batch
+    pub payout: Option<PayoutCreateResponse>,
+
+    /// The reason the item could not be created
+    pub error: Option<PayoutBatchItemError>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchItemError {
+    /// Code of the error
+    #[schema(example = "IR_06")]
+    pub code: String,
+
+    /// Description of the error
+    #[schema(example = "Currency not supported by the connector")]
+    pub message: String,
+}
+
+/// Defaults for the payouts of a merchant which share the same shape, such as recurring vendor
+/// payments. Payouts reference the template by its id, and the fields passed in the payout
+/// request take precedence over the template
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutTemplateCreateRequest {
+    /// Name of the template
+    #[schema(max_length = 64, example = "Monthly vendor payments")]
+    pub name: String,
+
+    /// The currency of the payouts
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The connectors through which the payouts can go through
+    #[schema(value_type = Option<Vec<PayoutConnectors>>, example = json!(["wise", "adyen"]))]
+    pub connector: Option<Vec<api_enums::PayoutConnectors>>,
+
+    /// The payout_type of the payouts
+    #[schema(value_type = Option<PayoutType>, example = "bank")]
+    pub payout_type: Option<api_enums::PayoutType>,
+
+    /// Type of entity to whom the payouts are carried out to
+    #[schema(value_type = Option<PayoutEntityType>, example = "Company")]
+    pub entity_type: Option<api_enums::PayoutEntityType>,
+
+    /// A description of the payouts, which can use the template variables of payout descriptions
+    #[schema(max_length = 255, example = "Vendor payment for {date}")]
+    pub description: Option<String>,
+
+    /// The send method for processing the payouts
+    #[schema(value_type = Option<PayoutSendPriority>, example = "regular")]
+    pub priority: Option<api_enums::PayoutSendPriority>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutTemplateResponse {
+    /// Unique identifier of the template
+    #[schema(example = "payout_template_mbabizu24mvu3mela5njyhpit4")]
+    pub template_id: String,
+
+    /// The identifier of the merchant account which owns the template
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// Name of the template
+    #[schema(example = "Monthly vendor payments")]
+    pub name: String,
+
+    /// The currency of the payouts
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The connectors through which the payouts can go through
+    #[schema(value_type = Option<Vec<PayoutConnectors>>, example = json!(["wise", "adyen"]))]
+    pub connector: Option<Vec<api_enums::PayoutConnectors>>,
+
+    /// The payout_type of the payouts
+    #[schema(value_type = Option<PayoutType>, example = "bank")]
+    pub payout_type: Option<api_enums::PayoutType>,
+
+    /// Type of entity to whom the payouts are carried out to
+    #[schema(value_type = Option<PayoutEntityType>, example = "Company")]
+    pub entity_type: Option<api_enums::PayoutEntityType>,
+
+    /// A description of the payouts
+    #[schema(example = "Vendor payment for {date}")]
+    pub description: Option<String>,
+
+    /// The send method for processing the payouts
+    #[schema(value_type = Option<PayoutSendPriority>, example = "regular")]
+    pub priority: Option<api_enums::PayoutSendPriority>,
+
+    /// Time when the template was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutTemplateId {
+    /// Unique identifier of the template
+    pub template_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutTemplateListResponse {
+    /// The number of templates included in the list
+    pub size: usize,
+
+    /// The list of templates of the merchant
+    pub data: Vec<PayoutTemplateResponse>,
+}
+
+/// Request to verify a payout destination of a customer. With micro-deposits, two payouts of small
+/// random amounts are sent to the destination, and the destination is verified once the customer
+/// confirms their amounts. With instant verification, the destination is verified right away
+/// through the account check of a connector.
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutDestinationVerificationRequest {
+    /// The identifier of the customer owning the destination
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The destination to be verified
+    pub payout_method_data: PayoutMethodData,
+
+    /// The payout_type of the micro-deposits
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The currency of the micro-deposits
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The connectors through which the micro-deposits can go through. Instant verification uses
+    /// the first of these connectors which supports account checks for the payout_type
+    #[schema(value_type = Option<Vec<PayoutConnectors>>, example = json!(["wise", "adyen"]))]
+    pub connector: Option<Vec<api_enums::PayoutConnectors>>,
+
+    /// How the destination is to be verified. Instant verification is available only for the
+    /// connectors and payout types with an account check, which is currently Adyen for cards
+    #[schema(value_type = Option<PayoutDestinationVerificationMethod>, example = "micro_deposits")]
+    #[serde(default)]
+    pub method: api_enums::PayoutDestinationVerificationMethod,
+}
+
+/// The amounts of the micro-deposits received by the customer, in any order
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutDestinationVerificationConfirmRequest {
+    /// Unique identifier of the verification
+    #[serde(skip_deserializing)]
+    pub verification_id: String,
+
+    /// The amounts of the micro-deposits, in the lowest denomination of the currency
+    #[schema(value_type = Vec<i64>, example = json!([32, 45]))]
+    pub amounts: Vec<common_utils::types::MinorUnit>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutDestinationVerificationId {
+    /// Unique identifier of the verification
+    pub verification_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutDestinationVerificationResponse {
+    /// Unique identifier of the verification
+    #[schema(example = "payout_verification_mbabizu24mvu3mela5njyhpit4")]
+    pub verification_id: String,
+
+    /// The identifier of the customer owning the destination
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// Status of the verification
+    #[schema(value_type = PayoutDestinationVerificationStatus, example = "pending")]
+    pub status: api_enums::PayoutDestinationVerificationStatus,
+
+    /// The identifiers of the payouts through which the micro-deposits were sent. This is empty
+    /// for instant verifications
+    #[schema(example = json!(["payout_mbabizu24mvu3mela5njyhpit4"]))]
+    pub micro_deposit_payout_ids: Vec<String>,
+
+    /// The number of attempts left for confirming the amounts of the micro-deposits. This is `0`
+    /// for instant verifications, which need no confirmation
+    #[schema(example = 3)]
+    pub remaining_attempts: i32,
+
+    /// Time when the verification was initiated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time when the destination was verified
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub verified_at: Option<PrimitiveDateTime>,
+}
+
+/// Request to hold an amount of the merchant's funds for a future payout, without disbursing it.
+/// The held amount is not available to other holds until the hold is captured, released or expires.
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutHoldCreateRequest {
+    /// The amount to be held, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 10000)]
+    pub amount: common_utils::types::MinorUnit,
+
+    /// The currency of the amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The number of seconds after which the hold expires if it is not captured, defaults to 7 days
+    #[schema(example = 86400)]
+    pub expires_in: Option<u32>,
+
+    /// Description of the hold
+    #[schema(max_length = 255, example = "Funds for the seller's weekly payout")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutHoldId {
+    /// Unique identifier of the hold
+    pub hold_id: String,
+}
+
+/// Request to convert a hold into a payout. The payout is in the currency of the hold, and of at
+/// most the held amount, the whole held amount when the amount is not passed.
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutHoldCaptureRequest {
+    /// Unique identifier of the hold
+    #[serde(skip_deserializing)]
+    pub hold_id: String,
+
+    /// The payout to be created from the hold
+    pub payout: PayoutCreateRequest,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutHoldResponse {
+    /// Unique identifier of the hold
+    #[schema(example = "payout_hold_mbabizu24mvu3mela5njyhpit4")]
+    pub hold_id: String,
+
+    /// The held amount, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 10000)]
+    pub amount: common_utils::types::MinorUnit,
+
+    /// The currency of the held amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// Status of the hold
+    #[schema(value_type = PayoutHoldStatus, example = "held")]
+    pub status: api_enums::PayoutHoldStatus,
+
+    /// The identifier of the payout the hold was captured into
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: Option<String>,
+
+    /// Description of the hold
+    pub description: Option<String>,
+
+    /// Time after which the hold is released if it is not captured
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+
+    /// Time when the hold was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The amounts held for payouts and the amounts available for further holds, per currency
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutHoldBalanceResponse {
+    pub balances: Vec<PayoutHoldBalance>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct PayoutHoldBalance {
+    /// The currency of the amounts
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The funds of the merchant for payouts, as configured by the merchant, in the lowest
+    /// denomination of the currency
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub funding_amount: Option<common_utils::types::MinorUnit>,
+
+    /// The total amount of the active holds, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 10000)]
+    pub held_amount: common_utils::types::MinorUnit,
+
+    /// The funds which are not held, in the lowest denomination of the currency. Not present when
+    /// the merchant has not configured its funds for payouts, in which case holds are not limited.
+    #[schema(value_type = Option<i64>, example = 90000)]
+    pub available_amount: Option<common_utils::types::MinorUnit>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutMethodValidationRequest {
+    /// The payout method data to be validated, it is not stored anywhere
+    pub payout_method_data: PayoutMethodData,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutMethodValidationResponse {
+    /// Whether all the fields of the payout method data passed validation
+    pub is_valid: bool,
+
+    /// The outcome of the validation of each field
+    pub fields: Vec<PayoutMethodFieldValidation>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutMethodFieldValidation {
+    /// The field which was validated
+    #[schema(example = "bank.iban")]
+    pub field_name: String,
+
+    /// Whether the field passed validation
+    pub is_valid: bool,
+
+    /// The reason for which the field failed validation
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutAdminRetrieveResponse {
+    #[serde(flatten)]
+    pub payout: PayoutCreateResponse,
+
+    /// Non-reversible fingerprint of the payout method's destination, unique per merchant
+    #[schema(example = "2bfeaf3cd8490535bd256343b835ce63ef321481cfcfd5d8dfd7f45fc1f1da38")]
+    pub payout_method_fingerprint: Option<String>,
+
+    /// Whether a payout to the same destination was made earlier for a different customer
+    #[schema(example = false)]
+    pub payout_method_fingerprint_match: bool,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutCurrencyConversionAuditLogResponse {
+    /// Unique identifier for the payout
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: String,
+
+    /// Conversions applied to the amount of the payout, in the order they were applied
+    pub data: Vec<CurrencyConversionAuditLog>,
+}
+
+/// Record of a currency conversion, kept for reconciliation
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct CurrencyConversionAuditLog {
+    /// Unique identifier for the record
+    #[schema(example = "conv_audit_Mp3Kx0yRk2qCIdbv6Dnd")]
+    pub audit_id: String,
+
+    /// The amount which was converted, in the minor unit of `source_currency`
+    #[schema(value_type = i64, example = 1000)]
+    pub source_amount: common_utils::types::MinorUnit,
+
+    /// The currency the amount was converted from
+    #[schema(example = "EUR")]
+    pub source_currency: String,
+
+    /// The currency the amount was converted into
+    #[schema(example = "USD")]
+    pub destination_currency: String,
+
+    /// The converted amount before any rounding, in the major unit of `destination_currency`
+    #[schema(example = "10.86957")]
+    pub converted_amount: String,
+
+    /// Rate at which one unit of `source_currency` was converted
+    #[schema(example = "1.086957")]
+    pub exchange_rate: String,
+
+    /// Source of the forex rates used for the conversion, when known
+    #[schema(example = "open_exchange_rates")]
+    pub exchange_rate_source: Option<String>,
+
+    /// Time at which the forex rates used for the conversion were last updated, in UTC
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub exchange_rate_timestamp: PrimitiveDateTime,
+
+    /// Whether money was moved at the conversion. Conversions quoted for display are not binding
+    #[schema(example = true)]
+    pub is_binding: bool,
+
+    /// Time at which the conversion was applied
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
+pub struct PayoutApproveRequest {
+    /// Unique identifier for the payout. This ensures idempotency for multiple payouts
+    /// that have been done by a single merchant. This field is auto generated and is returned in the API response.
+    #[schema(
+        value_type = String,
+        min_length = 30,
+        max_length = 30,
+        example = "payout_mbabizu24mvu3mela5njyhpit4"
+    )]
+    #[serde(skip_deserializing)]
+    pub payout_id: String,
+}
+
+/// Result of the document verification of the recipient of a payout held in
+/// `pending_verification`
+#[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
+pub struct PayoutVerificationRequest {
+    /// Unique identifier for the payout. This ensures idempotency for multiple payouts
+    /// that have been done by a single merchant. This field is auto generated and is returned in the API response.
+    #[schema(
+        value_type = String,
+        min_length = 30,
+        max_length = 30,
+        example = "payout_mbabizu24mvu3mela5njyhpit4"
+    )]
+    #[serde(skip_deserializing)]
+    pub payout_id: String,
+
+    /// Result of the verification, a verified payout proceeds and a rejected payout fails
+    #[schema(example = "verified")]
+    pub result: PayoutVerificationResult,
+
+    /// Reference of the verification in the systems of the verifier, recorded against the payout
+    #[schema(max_length = 255, example = "kyc_check_7f3a2b")]
+    pub verification_reference: Option<String>,
+
+    /// Reason for the rejection, recorded against the payout
+    #[schema(max_length = 255, example = "Identity document has expired")]
+    pub reason: Option<String>,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutVerificationResult {
+    #[default]
+    Verified,
+    Rejected,
+}
+
+/// Request to reprocess a failed payout with corrected details. The payout is retried in a new
+/// attempt, the failed attempt is left as is.
+#[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutReprocessRequest {
+    /// Unique identifier for the payout
+    #[schema(
+        value_type = String,
+        min_length = 30,
+        max_length = 30,
+        example = "payout_mbabizu24mvu3mela5njyhpit4"
+    )]
+    #[serde(skip_deserializing)]
+    pub payout_id: String,
+
+    /// The corrected payout method information, the payout method of the failed attempt is used
+    /// when not passed
+    #[schema(value_type = Option<PayoutMethodData>)]
+    pub payout_method_data: Option<PayoutMethodData>,
+
+    /// The corrected billing address of the recipient, the billing address of the payout is used
+    /// when not passed
+    #[schema(value_type = Option<Address>)]
+    pub billing: Option<payments::Address>,
+}
+
+/// Request to record the recall of a successful payout by the bank of the recipient
+#[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutRecallRequest {
+    /// Unique identifier for the payout
+    #[schema(
+        value_type = String,
+        min_length = 30,
+        max_length = 30,
+        example = "payout_mbabizu24mvu3mela5njyhpit4"
+    )]
+    #[serde(skip_deserializing)]
+    pub payout_id: String,
+
+    /// The amount recalled by the bank, in the lowest denomination of the currency. The whole
+    /// amount of the payout is considered recalled when not passed
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<common_utils::types::MinorUnit>,
+
+    /// The reason for the recall, as reported by the bank
+    #[schema(max_length = 255, example = "Fraudulent credit")]
+    pub reason: Option<String>,
+}
+
+/// Request to cancel the scheduled runs of a recurring payout series
+#[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
+pub struct PayoutSeriesCancelRequest {
+    /// Identifier of the recurring payout series
+    #[schema(max_length = 64, example = "payroll-2024-monthly")]
+    pub series_id: String,
+}
+
+/// Result of cancelling the scheduled runs of a recurring payout series
+#[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
+pub struct PayoutSeriesCancelResponse {
+    /// Identifier of the recurring payout series
+    #[schema(example = "payroll-2024-monthly")]
+    pub series_id: String,
+
+    /// Number of scheduled runs of the series which were cancelled. Runs which were already
+    /// disbursed, or are being disbursed, are left as they are
+    #[schema(example = 3)]
+    pub cancelled_count: usize,
 }
 
 #[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
@@ -612,6 +1656,10 @@ pub struct PayoutListConstraints {
     #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
     pub customer_id: Option<id_type::CustomerId>,
 
+    /// The identifier of the payout in the systems of the merchant
+    #[schema(example = "INV-2024-0042")]
+    pub merchant_reference_id: Option<String>,
+
     /// A cursor for use in pagination, fetch the next list after some object
     #[schema(example = "pay_fafa124123")]
     pub starting_after: Option<String>,
@@ -634,6 +1682,62 @@ pub struct PayoutListConstraints {
     #[serde(flatten)]
     #[schema(value_type = Option<TimeRange>)]
     pub time_range: Option<payments::TimeRange>,
+
+    /// IANA time zone name in which the timestamps in the response are to be returned
+    /// (defaults to UTC)
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: Option<String>,
+
+    /// The currency in which the amounts of the listed payouts are to be reported, using the
+    /// rates as of the date on which each payout was created
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub reporting_currency: Option<api_enums::Currency>,
+}
+
+/// Export job created for payouts exports which are too large to be generated synchronously
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutExportJobResponse {
+    /// Identifier of the export job, used to poll for its status
+    #[schema(example = "payout_export_mbabizu24mvu3mela5nj")]
+    pub job_id: String,
+
+    /// Status of the export job
+    #[schema(example = "pending")]
+    pub status: PayoutExportJobStatus,
+
+    /// Link from which the export can be downloaded, present once the job is completed
+    #[schema(
+        example = "https://sandbox.hyperswitch.io/payouts/export/payout_export_mbabizu24mvu3mela5nj/download"
+    )]
+    pub download_url: Option<String>,
+
+    /// Time after which the export can no longer be downloaded, in UTC
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+
+    /// Time when the export job was created, in UTC
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutExportJobStatus {
+    /// The export is being generated
+    Pending,
+    /// The export is generated and can be downloaded
+    Completed,
+    /// The export could not be generated
+    Failed,
+    /// The export was generated, and deleted after its retention period
+    Expired,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PayoutExportJobId {
+    pub job_id: String,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -652,6 +1756,9 @@ pub struct PayoutListFilterConstraints {
     /// The identifier for customer
     #[schema(value_type = Option<String>,example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
     pub customer_id: Option<id_type::CustomerId>,
+    /// The identifier of the payout in the systems of the merchant
+    #[schema(example = "INV-2024-0042")]
+    pub merchant_reference_id: Option<String>,
     /// The limit on the number of objects. The default limit is 10 and max limit is 20
     #[serde(default = "default_payouts_list_limit")]
     pub limit: u32,
@@ -676,6 +1783,12 @@ pub struct PayoutListFilterConstraints {
     /// Type of recipient
     #[schema(value_type = PayoutEntityType, example = "Individual")]
     pub entity_type: Option<common_enums::PayoutEntityType>,
+    /// IANA time zone name in which the timestamps in the response are to be returned (defaults to UTC)
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: Option<String>,
+    /// The currency in which the amounts of the listed payouts are to be reported, using the rates as of the date on which each payout was created
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub reporting_currency: Option<api_enums::Currency>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
@@ -684,6 +1797,38 @@ pub struct PayoutListResponse {
     pub size: usize,
     // The list of payouts response objects
     pub data: Vec<PayoutCreateResponse>,
+    /// The amounts of the listed payouts in the reporting currency, present when a reporting currency is requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reporting_amounts: Option<Vec<PayoutReportingAmount>>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct PayoutReportingAmount {
+    /// Unique identifier for the payout
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: String,
+
+    /// The payout amount in the lowest denomination of the currency of the payout
+    #[schema(value_type = i64, example = 1000)]
+    pub amount: common_utils::types::MinorUnit,
+
+    /// The currency of the payout
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+
+    /// The currency in which the amount is reported
+    #[schema(value_type = Currency, example = "USD")]
+    pub reporting_currency: api_enums::Currency,
+
+    /// The payout amount in the lowest denomination of the reporting currency, not present when
+    /// the rates as of the date on which the payout was created are not available
+    #[schema(value_type = Option<i64>, example = 1081)]
+    pub reporting_amount: Option<common_utils::types::MinorUnit>,
+
+    /// The rate at which the amount was converted to the reporting currency, not present when the
+    /// payout is already in the reporting currency
+    #[schema(example = "1.081081")]
+    pub rate: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -698,6 +1843,65 @@ pub struct PayoutListFilters {
     pub payout_method: Vec<common_enums::PayoutType>,
 }
 
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutConnectorUsage {
+    /// The connector used for the payouts
+    #[schema(value_type = PayoutConnectors)]
+    pub connector: api_enums::PayoutConnectors,
+    /// The number of payout attempts made through the connector
+    pub count: i64,
+    /// The time of the latest payout attempt made through the connector
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_used_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutConnectorUsageResponse {
+    /// The connectors used for the payouts created in the time range, most used first
+    pub data: Vec<PayoutConnectorUsage>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutReceiptResponse {
+    /// Unique identifier for the payout
+    #[schema(value_type = String, example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(max_length = 255, value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The connector used for the payout
+    #[schema(example = "wise")]
+    pub connector: Option<String>,
+
+    /// Underlying processor's payout resource ID
+    #[schema(value_type = Option<String>, example = "S3FC9G9M2MVFDXT5")]
+    pub connector_payout_id: Option<String>,
+
+    /// The payout method that was used
+    #[schema(value_type = Option<PayoutType>, example = "bank")]
+    pub payout_type: Option<api_enums::PayoutType>,
+
+    /// Masked details of the account the payout was sent to
+    #[schema(value_type = Option<String>, example = "************1234")]
+    pub masked_destination: Option<String>,
+
+    /// The payout amount in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1000)]
+    pub amount: common_utils::types::MinorUnit,
+
+    /// Recipient's currency for the payout
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// Time when the payout was completed
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub completed_at: PrimitiveDateTime,
+}
+
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct PayoutLinkResponse {
     pub payout_link_id: String,
@@ -102,6 +102,51 @@ impl common_utils::events::ApiEventMetric for EventRetrieveResponse {
     }
 }
 
+/// The response body for retrieving the retry state of the deliveries of an event.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryRetryStatusResponse {
+    /// The identifier for the initial delivery attempt.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub initial_attempt_id: String,
+
+    /// The number of delivery attempts made so far, including manual retries.
+    #[schema(example = 3)]
+    pub attempt_count: usize,
+
+    /// The HTTP status code received for the latest delivery attempt.
+    #[schema(example = 500)]
+    pub last_status_code: Option<u16>,
+
+    /// Time at which the latest delivery attempt was made.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_attempted_at: PrimitiveDateTime,
+
+    /// The state of the automatic retries for the event.
+    pub retry_status: WebhookDeliveryRetryStatus,
+
+    /// Time at which the next automatic retry is scheduled, if any.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_retry_at: Option<PrimitiveDateTime>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryStatusResponse {}
+
+/// The state of the automatic retries for the deliveries of an event.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryRetryStatus {
+    /// The webhook has been delivered successfully.
+    Delivered,
+    /// The delivery failed and is scheduled to be retried.
+    Retrying,
+    /// The delivery failed for the maximum number of retries, no further retries will be made.
+    Exhausted,
+    /// The delivery failed and no automatic retries are scheduled.
+    NotScheduled,
+}
+
 /// The request information (headers and body) sent in the webhook.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct OutgoingWebhookRequestContent {
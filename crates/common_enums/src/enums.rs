@@ -1122,6 +1122,7 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    PayoutRecalled,
 }
 
 #[derive(
@@ -2146,6 +2147,7 @@ pub enum PayoutStatus {
     Initiated,
     Expired,
     Reversed,
+    Recalled,
     Pending,
     Ineligible,
     #[default]
@@ -2154,6 +2156,9 @@ pub enum PayoutStatus {
     RequiresPayoutMethodData,
     RequiresFulfillment,
     RequiresVendorAccountCreation,
+    Scheduled,
+    PendingApproval,
+    PendingVerification,
 }
 
 #[derive(
@@ -2180,6 +2185,7 @@ pub enum PayoutType {
     Card,
     Bank,
     Wallet,
+    StoreCredit,
 }
 
 #[derive(
@@ -2240,6 +2246,330 @@ pub enum PayoutSendPriority {
     Internal,
 }
 
+/// The party bearing the fees of a payout, corresponding to the OUR, BEN and SHA charge codes of
+/// SWIFT transfers. Fees are borne by the sender when this is not specified
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutChargeBearer {
+    /// All fees are borne by the sender, and the recipient is credited the full amount (OUR)
+    #[default]
+    Sender,
+    /// All fees are borne by the recipient, and deducted from the amount credited (BEN)
+    Recipient,
+    /// The fees of the sending bank are borne by the sender, and the other fees by the recipient (SHA)
+    Shared,
+}
+
+/// The settlement network over which a bank payout is sent, for countries with multiple domestic
+/// payment rails. This is finer grained than the send priority of the payout
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutSettlementNetwork {
+    /// Automated Clearing House, the standard network for payouts in the US
+    Ach,
+    /// The Clearing House's Real-Time Payments network in the US
+    Rtp,
+    /// The Federal Reserve's instant payments network in the US
+    #[serde(rename = "fednow")]
+    #[strum(serialize = "fednow")]
+    FedNow,
+    /// SEPA Credit Transfer, the standard network for payouts in EUR
+    Sepa,
+    /// SEPA Instant Credit Transfer
+    SepaInstant,
+    /// Bankers' Automated Clearing Services, the standard network for payouts in the UK
+    Bacs,
+    /// The Faster Payments Service in the UK
+    FasterPayments,
+}
+
+impl PayoutSettlementNetwork {
+    /// The currency in which payouts are settled over the network
+    pub fn currency(self) -> Currency {
+        match self {
+            Self::Ach | Self::Rtp | Self::FedNow => Currency::USD,
+            Self::Sepa | Self::SepaInstant => Currency::EUR,
+            Self::Bacs | Self::FasterPayments => Currency::GBP,
+        }
+    }
+
+    /// Whether the network serves payouts to the country. The SEPA networks serve the countries
+    /// and territories of the SEPA zone, the other networks are domestic to a single country
+    pub fn serves_country(self, country: CountryAlpha2) -> bool {
+        match self {
+            Self::Ach | Self::Rtp | Self::FedNow => country == CountryAlpha2::US,
+            Self::Sepa | Self::SepaInstant => matches!(
+                country,
+                // Member states of the European Union
+                CountryAlpha2::AT
+                    | CountryAlpha2::BE
+                    | CountryAlpha2::BG
+                    | CountryAlpha2::HR
+                    | CountryAlpha2::CY
+                    | CountryAlpha2::CZ
+                    | CountryAlpha2::DK
+                    | CountryAlpha2::EE
+                    | CountryAlpha2::FI
+                    | CountryAlpha2::FR
+                    | CountryAlpha2::DE
+                    | CountryAlpha2::GR
+                    | CountryAlpha2::HU
+                    | CountryAlpha2::IE
+                    | CountryAlpha2::IT
+                    | CountryAlpha2::LV
+                    | CountryAlpha2::LT
+                    | CountryAlpha2::LU
+                    | CountryAlpha2::MT
+                    | CountryAlpha2::NL
+                    | CountryAlpha2::PL
+                    | CountryAlpha2::PT
+                    | CountryAlpha2::RO
+                    | CountryAlpha2::SK
+                    | CountryAlpha2::SI
+                    | CountryAlpha2::ES
+                    | CountryAlpha2::SE
+                    // Territories of the member states with their own country codes
+                    | CountryAlpha2::AX
+                    | CountryAlpha2::GF
+                    | CountryAlpha2::GP
+                    | CountryAlpha2::MQ
+                    | CountryAlpha2::YT
+                    | CountryAlpha2::RE
+                    | CountryAlpha2::BL
+                    | CountryAlpha2::MF
+                    | CountryAlpha2::PM
+                    // Other countries and territories of the SEPA zone
+                    | CountryAlpha2::IS
+                    | CountryAlpha2::LI
+                    | CountryAlpha2::NO
+                    | CountryAlpha2::CH
+                    | CountryAlpha2::GB
+                    | CountryAlpha2::GI
+                    | CountryAlpha2::GG
+                    | CountryAlpha2::JE
+                    | CountryAlpha2::IM
+                    | CountryAlpha2::MC
+                    | CountryAlpha2::SM
+                    | CountryAlpha2::VA
+                    | CountryAlpha2::AD
+            ),
+            Self::Bacs | Self::FasterPayments => country == CountryAlpha2::GB,
+        }
+    }
+
+    /// The priority with which payouts are sent over the network, for connectors which select the
+    /// network through the priority of the payout
+    pub fn get_priority(self) -> PayoutSendPriority {
+        if self.is_instant() {
+            PayoutSendPriority::Instant
+        } else {
+            PayoutSendPriority::Regular
+        }
+    }
+
+    /// Whether payouts sent over the network are settled in real time
+    pub fn is_instant(self) -> bool {
+        matches!(
+            self,
+            Self::Rtp | Self::FedNow | Self::SepaInstant | Self::FasterPayments
+        )
+    }
+
+    /// The standard network serving the same destinations as this network
+    pub fn get_standard_network(self) -> Self {
+        match self {
+            Self::Ach | Self::Rtp | Self::FedNow => Self::Ach,
+            Self::Sepa | Self::SepaInstant => Self::Sepa,
+            Self::Bacs | Self::FasterPayments => Self::Bacs,
+        }
+    }
+}
+
+/// The machine readable reason for the latest status change of a payout
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatusReasonCode {
+    /// The connector returned an error while processing the payout
+    ConnectorError,
+    /// The payout was declined by the connector
+    ConnectorDeclined,
+    /// The payout was cancelled by the connector
+    ConnectorCancelled,
+    /// The payout was reversed after it was disbursed, as it could not be delivered to the recipient
+    ConnectorReversed,
+    /// The payout was recalled by the bank of the recipient after it was credited
+    BankRecalled,
+    /// The payout expired before it could be disbursed
+    Expired,
+    /// The payout method of the recipient is not eligible for payouts
+    RecipientIneligible,
+    /// The payout was cancelled by the merchant
+    MerchantCancelled,
+    /// The documents of the recipient were rejected during verification
+    VerificationRejected,
+    /// The result of the document verification was not received in time
+    VerificationExpired,
+}
+
+/// Status of the verification of a payout destination of a customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutDestinationVerificationStatus {
+    /// The micro-deposits were sent, and their amounts are yet to be confirmed
+    #[default]
+    Pending,
+    /// The amounts of the micro-deposits were confirmed, or the account check of the connector
+    /// passed
+    Verified,
+    /// The amounts of the micro-deposits were not confirmed within the allowed attempts, the
+    /// micro-deposits could not be sent, or the account check of the connector did not pass
+    Failed,
+    /// The amounts of the micro-deposits were not confirmed before the verification expired
+    Expired,
+}
+
+/// How a payout destination of a customer is verified
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutDestinationVerificationMethod {
+    /// Micro-deposits of random amounts are sent to the destination, and the destination is
+    /// verified once the customer confirms their amounts
+    #[default]
+    MicroDeposits,
+    /// The destination is checked through the account check of a connector, without moving any
+    /// funds, and is verified right away when the check passes
+    Instant,
+}
+
+/// How the converted amount of a cross-currency payout is brought to the minor unit of the
+/// currency the recipient is paid in
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutConversionRounding {
+    /// The converted amount is rounded to the nearest minor unit, halves away from zero
+    #[default]
+    Round,
+    /// The converted amount is paid exactly, down to the minor unit. The fraction of a minor unit
+    /// which cannot be paid is never rounded up
+    PreserveExact,
+}
+
+/// Status of a hold which earmarks an amount of the merchant's funds for a future payout
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutHoldStatus {
+    /// The amount is held, and counts against the available balance
+    #[default]
+    Held,
+    /// The hold was converted into a payout
+    Captured,
+    /// The hold was cancelled by the merchant
+    Released,
+    /// The hold was not captured before it expired
+    Expired,
+}
+
 #[derive(
     Clone,
     Copy,
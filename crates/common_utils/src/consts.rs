@@ -38,6 +38,10 @@ pub const PAYMENTS_LINK_LIST_LIMIT: u32 = 100;
 pub const PAYOUTS_LIST_MAX_LIMIT_GET: u32 = 100;
 /// Maximum limit for payouts list post api
 pub const PAYOUTS_LIST_MAX_LIMIT_POST: u32 = 20;
+/// Maximum number of payouts that can be fetched in a single bulk retrieve api call
+pub const PAYOUTS_BULK_RETRIEVE_MAX_LIMIT: usize = 50;
+/// Maximum number of payouts that can be created in a single payout batch
+pub const PAYOUTS_BATCH_MAX_ITEMS: usize = 100;
 /// Default limit for payouts list API
 pub fn default_payouts_list_limit() -> u32 {
     10
@@ -72,6 +76,35 @@ pub const DEFAULT_SDK_LAYOUT: &str = "tabs";
 /// Payment intent default client secret expiry (in seconds)
 pub const DEFAULT_SESSION_EXPIRY: i64 = 15 * 60;
 
+/// Font families which can be configured for a payment link
+pub const PAYMENT_LINK_ALLOWED_FONT_FAMILIES: [&str; 7] = [
+    "Montserrat",
+    "Arial",
+    "Helvetica",
+    "Verdana",
+    "Georgia",
+    "Times New Roman",
+    "system-ui",
+];
+
+/// Allowed font families which are generic CSS font families and hence must not be quoted
+pub const PAYMENT_LINK_GENERIC_FONT_FAMILIES: [&str; 1] = ["system-ui"];
+
+/// Maximum border radius (in px) which can be configured for a payment link
+pub const PAYMENT_LINK_MAX_BORDER_RADIUS: u8 = 32;
+
+/// Maximum width and height (in px) of the merchant logo on a payment link
+pub const PAYMENT_LINK_MAX_LOGO_DIMENSION: u16 = 512;
+
+/// Maximum number of additional fields which can be collected from the customer on a payment link
+pub const PAYMENT_LINK_MAX_CUSTOM_FIELDS: usize = 10;
+
+/// Maximum length of the key of an additional field collected on a payment link
+pub const PAYMENT_LINK_MAX_CUSTOM_FIELD_KEY_LENGTH: usize = 64;
+
+/// Maximum number of tips which can be suggested to the customer on a payment link
+pub const PAYMENT_LINK_MAX_SUGGESTED_TIPS: usize = 5;
+
 /// Default bool for Display sdk only
 pub const DEFAULT_DISPLAY_SDK_ONLY: bool = false;
 
@@ -84,6 +84,41 @@ pub mod iso8601 {
             })
         }
     }
+
+    /// Use the well-known ISO 8601 format, retaining the UTC offset of the value, when serializing
+    /// and deserializing an [`Option<OffsetDateTime>`][OffsetDateTime].
+    ///
+    /// [OffsetDateTime]: ::time::OffsetDateTime
+    pub mod option_with_offset {
+        use serde::Serialize;
+        use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+        use super::*;
+
+        /// Serialize an [`Option<OffsetDateTime>`] using the well-known ISO 8601 format.
+        pub fn serialize<S>(
+            date_time: &Option<OffsetDateTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            date_time
+                .map(|date_time| date_time.format(&Iso8601::<FORMAT_CONFIG>))
+                .transpose()
+                .map_err(S::Error::custom)?
+                .serialize(serializer)
+        }
+
+        /// Deserialize an [`Option<OffsetDateTime>`] from its ISO 8601 representation.
+        pub fn deserialize<'a, D>(deserializer: D) -> Result<Option<OffsetDateTime>, D::Error>
+        where
+            D: Deserializer<'a>,
+        {
+            iso8601::option::deserialize(deserializer)
+        }
+    }
+
     /// Use the well-known ISO 8601 format which is without timezone when serializing and deserializing an
     /// [`Option<PrimitiveDateTime>`][PrimitiveDateTime].
     ///
@@ -133,6 +168,57 @@ pub mod iso8601 {
     }
 }
 
+/// Use the ISO 8601 calendar date format (`YYYY-MM-DD`) when serializing and deserializing a
+/// [`Date`][Date].
+///
+/// [Date]: ::time::Date
+pub mod iso8601_date {
+    use serde::{
+        de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer,
+    };
+    use time::{
+        format_description::well_known::{
+            iso8601::{Config, EncodedConfig, FormattedComponents},
+            Iso8601,
+        },
+        Date,
+    };
+
+    const FORMAT_CONFIG: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::Date)
+        .encode();
+
+    /// Use the ISO 8601 calendar date format when serializing and deserializing an
+    /// [`Option<Date>`][Date].
+    ///
+    /// [Date]: ::time::Date
+    pub mod option {
+        use super::*;
+
+        /// Serialize an [`Option<Date>`] using the ISO 8601 calendar date format.
+        pub fn serialize<S>(date: &Option<Date>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            date.map(|date| date.format(&Iso8601::<FORMAT_CONFIG>))
+                .transpose()
+                .map_err(S::Error::custom)?
+                .serialize(serializer)
+        }
+
+        /// Deserialize an [`Option<Date>`] from its ISO 8601 calendar date representation.
+        pub fn deserialize<'a, D>(deserializer: D) -> Result<Option<Date>, D::Error>
+        where
+            D: Deserializer<'a>,
+        {
+            <Option<String>>::deserialize(deserializer)?
+                .map(|date| Date::parse(&date, &Iso8601::<FORMAT_CONFIG>))
+                .transpose()
+                .map_err(D::Error::custom)
+        }
+    }
+}
+
 /// Use the UNIX timestamp when serializing and deserializing an
 /// [`PrimitiveDateTime`][PrimitiveDateTime].
 ///
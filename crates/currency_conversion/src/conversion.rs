@@ -14,14 +14,31 @@ pub fn convert(
     amount: i64,
 ) -> Result<Decimal, CurrencyConversionError> {
     let money_minor = Money::from_minor(amount, currency_match(from_currency));
+    convert_major_amount(ex_rates, from_currency, to_currency, *money_minor.amount())
+}
+
+/// Rate at which one unit of `from_currency` is converted to `to_currency`
+pub fn exchange_rate(
+    ex_rates: &ExchangeRates,
+    from_currency: Currency,
+    to_currency: Currency,
+) -> Result<Decimal, CurrencyConversionError> {
+    convert_major_amount(ex_rates, from_currency, to_currency, Decimal::ONE)
+}
+
+fn convert_major_amount(
+    ex_rates: &ExchangeRates,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: Decimal,
+) -> Result<Decimal, CurrencyConversionError> {
     let base_currency = ex_rates.base_currency;
     if to_currency == base_currency {
-        ex_rates.forward_conversion(*money_minor.amount(), from_currency)
+        ex_rates.forward_conversion(amount, from_currency)
     } else if from_currency == base_currency {
-        ex_rates.backward_conversion(*money_minor.amount(), to_currency)
+        ex_rates.backward_conversion(amount, to_currency)
     } else {
-        let base_conversion_amt =
-            ex_rates.forward_conversion(*money_minor.amount(), from_currency)?;
+        let base_conversion_amt = ex_rates.forward_conversion(amount, from_currency)?;
         ex_rates.backward_conversion(base_conversion_amt, to_currency)
     }
 }
@@ -31,7 +48,7 @@ mod tests {
     #![allow(clippy::expect_used)]
     use std::collections::HashMap;
 
-    use crate::types::CurrencyFactors;
+    use crate::types::{CurrencyFactors, ForexRate};
     #[test]
     fn currency_to_currency_conversion() {
         use super::*;
@@ -98,4 +115,56 @@ mod tests {
             amount, convert_from, convert_to, res
         );
     }
+
+    #[test]
+    fn exchange_rate_matches_conversion_of_a_unit() {
+        use super::*;
+        let mut conversion: HashMap<Currency, CurrencyFactors> = HashMap::new();
+        let inr_conversion_rates =
+            CurrencyFactors::new(Decimal::new(823173, 4), Decimal::new(1214, 5));
+        let usd_conversion_rates = CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0));
+        conversion.insert(Currency::USD, usd_conversion_rates);
+        conversion.insert(Currency::INR, inr_conversion_rates);
+        let sample_rate = ExchangeRates::new(Currency::USD, conversion);
+        let rate =
+            exchange_rate(&sample_rate, Currency::USD, Currency::INR).expect("exchange_rate");
+        let converted =
+            convert(&sample_rate, Currency::USD, Currency::INR, 100).expect("converted_currency");
+        assert_eq!(rate, converted);
+    }
+
+    #[test]
+    fn repeated_conversions_do_not_drift() {
+        use super::*;
+        let mut conversion: HashMap<Currency, CurrencyFactors> = HashMap::new();
+        let idr_conversion_rates =
+            CurrencyFactors::from_rate(ForexRate::from(Decimal::new(1_623_456_789, 5)))
+                .expect("currency_factors");
+        conversion.insert(
+            Currency::USD,
+            CurrencyFactors::new(Decimal::ONE, Decimal::ONE),
+        );
+        conversion.insert(Currency::IDR, idr_conversion_rates);
+        let sample_rate = ExchangeRates::new(Currency::USD, conversion);
+
+        let mut total = Decimal::ZERO;
+        for _ in 0..1000 {
+            let converted = convert(&sample_rate, Currency::USD, Currency::IDR, 100_000_001)
+                .expect("converted_currency");
+            assert_eq!(converted, Decimal::new(162_345_680_523_456_789, 7));
+            total += converted;
+        }
+        assert_eq!(total, Decimal::new(162_345_680_523_456_789, 4));
+    }
+
+    #[test]
+    fn forex_rate_conversions() {
+        use super::*;
+        let rate = ForexRate::try_from(16234.56789_f64).expect("forex_rate");
+        assert_eq!(Decimal::from(rate), Decimal::new(1_623_456_789, 5));
+        assert_eq!(ForexRate::from(Decimal::from(rate)), rate);
+        assert!(ForexRate::try_from(f64::NAN).is_err());
+        assert!(ForexRate::try_from(f64::INFINITY).is_err());
+        assert!(ForexRate::from(Decimal::ZERO).inverse().is_err());
+    }
 }
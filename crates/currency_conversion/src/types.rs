@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use common_enums::Currency;
 use rust_decimal::Decimal;
@@ -17,18 +17,85 @@ pub struct ExchangeRates {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CurrencyFactors {
     /// The factor that will be multiplied to provide Currency output
-    pub to_factor: Decimal,
+    pub to_factor: ForexRate,
     /// The factor that will be multiplied to provide for the base output
-    pub from_factor: Decimal,
+    pub from_factor: ForexRate,
 }
 
 impl CurrencyFactors {
     pub fn new(to_factor: Decimal, from_factor: Decimal) -> Self {
         Self {
-            to_factor,
-            from_factor,
+            to_factor: ForexRate::from(to_factor),
+            from_factor: ForexRate::from(from_factor),
         }
     }
+
+    /// Factors of a currency whose rate is `to_factor` units of it per unit of the base currency
+    pub fn from_rate(to_factor: ForexRate) -> Result<Self, CurrencyConversionError> {
+        Ok(Self {
+            from_factor: to_factor.inverse()?,
+            to_factor,
+        })
+    }
+}
+
+/// Rate at which one unit of a currency is converted to another.
+///
+/// The rate is held as a decimal rather than a float, so that rates of large magnitudes and the
+/// amounts converted using them are exact. It is serialized the same way as a [`Decimal`], which
+/// keeps the cached rates readable across the change.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct ForexRate(Decimal);
+
+impl ForexRate {
+    /// Rate of a currency against itself
+    pub const ONE: Self = Self(Decimal::ONE);
+
+    /// Converts the amount using the rate
+    pub fn apply(self, amount: Decimal) -> Result<Decimal, CurrencyConversionError> {
+        amount
+            .checked_mul(self.0)
+            .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)
+    }
+
+    /// Rate of the conversion in the opposite direction
+    pub fn inverse(self) -> Result<Self, CurrencyConversionError> {
+        Decimal::ONE
+            .checked_div(self.0)
+            .map(Self)
+            .ok_or_else(|| CurrencyConversionError::InvalidForexRate(self.to_string()))
+    }
+}
+
+impl From<Decimal> for ForexRate {
+    fn from(rate: Decimal) -> Self {
+        Self(rate)
+    }
+}
+
+impl From<ForexRate> for Decimal {
+    fn from(rate: ForexRate) -> Self {
+        rate.0
+    }
+}
+
+impl TryFrom<f64> for ForexRate {
+    type Error = CurrencyConversionError;
+
+    fn try_from(rate: f64) -> Result<Self, Self::Error> {
+        Decimal::try_from(rate)
+            .map(Self)
+            .map_err(|_| CurrencyConversionError::InvalidForexRate(rate.to_string()))
+    }
+}
+
+impl fmt::Display for ForexRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
 }
 
 impl ExchangeRates {
@@ -53,8 +120,7 @@ impl ExchangeRates {
                 CurrencyConversionError::ConversionNotSupported(from_currency.to_string())
             })?
             .from_factor;
-        amt.checked_mul(from_factor)
-            .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)
+        from_factor.apply(amt)
     }
 
     /// from base_currency -> to_currency
@@ -70,8 +136,7 @@ impl ExchangeRates {
                 CurrencyConversionError::ConversionNotSupported(to_currency.to_string())
             })?
             .to_factor;
-        amt.checked_mul(to_factor)
-            .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)
+        to_factor.apply(amt)
     }
 }
 
@@ -0,0 +1,41 @@
+use common_utils::types::MinorUnit;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::currency_conversion_audit_logs;
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = currency_conversion_audit_logs)]
+pub struct CurrencyConversionAuditLogNew {
+    pub audit_id: String,
+    pub merchant_id: String,
+    pub payout_id: Option<String>,
+    pub source_amount: MinorUnit,
+    pub source_currency: String,
+    pub destination_currency: String,
+    pub converted_amount: String,
+    pub exchange_rate: String,
+    pub exchange_rate_source: Option<String>,
+    pub exchange_rate_timestamp: PrimitiveDateTime,
+    pub is_binding: bool,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = currency_conversion_audit_logs)]
+#[diesel(primary_key(audit_id))]
+pub struct CurrencyConversionAuditLog {
+    pub audit_id: String,
+    pub merchant_id: String,
+    pub payout_id: Option<String>,
+    pub source_amount: MinorUnit,
+    pub source_currency: String,
+    pub destination_currency: String,
+    pub converted_amount: String,
+    pub exchange_rate: String,
+    pub exchange_rate_source: Option<String>,
+    pub exchange_rate_timestamp: PrimitiveDateTime,
+    pub is_binding: bool,
+    pub created_at: PrimitiveDateTime,
+}
@@ -68,6 +68,8 @@ pub enum EventMetadata {
     },
     Payout {
         payout_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        webhook_url: Option<String>,
     },
     Refund {
         payment_id: String,
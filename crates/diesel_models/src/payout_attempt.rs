@@ -1,4 +1,4 @@
-use common_utils::id_type;
+use common_utils::{id_type, types::MinorUnit};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{self, Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -30,6 +30,10 @@ pub struct PayoutAttempt {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub reason_code: Option<storage_enums::PayoutStatusReasonCode>,
+    pub reason: Option<String>,
+    pub destination_index: Option<i16>,
+    pub recalled_amount: Option<MinorUnit>,
 }
 
 #[derive(
@@ -66,6 +70,10 @@ pub struct PayoutAttemptNew {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub reason_code: Option<storage_enums::PayoutStatusReasonCode>,
+    pub reason: Option<String>,
+    pub destination_index: Option<i16>,
+    pub recalled_amount: Option<MinorUnit>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,6 +84,8 @@ pub enum PayoutAttemptUpdate {
         error_message: Option<String>,
         error_code: Option<String>,
         is_eligible: Option<bool>,
+        reason_code: Option<storage_enums::PayoutStatusReasonCode>,
+        reason: Option<String>,
     },
     PayoutTokenUpdate {
         payout_token: String,
@@ -88,6 +98,11 @@ pub enum PayoutAttemptUpdate {
         connector: String,
         routing_info: Option<serde_json::Value>,
     },
+    RecallUpdate {
+        status: storage_enums::PayoutStatus,
+        reason: Option<String>,
+        recalled_amount: MinorUnit,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -103,6 +118,9 @@ pub struct PayoutAttemptUpdateInternal {
     pub business_label: Option<String>,
     pub connector: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub reason_code: Option<Option<storage_enums::PayoutStatusReasonCode>>,
+    pub reason: Option<Option<String>>,
+    pub recalled_amount: Option<MinorUnit>,
     pub last_modified_at: PrimitiveDateTime,
 }
 
@@ -119,6 +137,9 @@ impl Default for PayoutAttemptUpdateInternal {
             business_label: None,
             connector: None,
             routing_info: None,
+            reason_code: None,
+            reason: None,
+            recalled_amount: None,
             last_modified_at: common_utils::date_time::now(),
         }
     }
@@ -137,12 +158,16 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
                 error_message,
                 error_code,
                 is_eligible,
+                reason_code,
+                reason,
             } => Self {
                 connector_payout_id,
                 status: Some(status),
                 error_message,
                 error_code,
                 is_eligible,
+                reason_code: Some(reason_code),
+                reason: Some(reason),
                 ..Default::default()
             },
             PayoutAttemptUpdate::BusinessUpdate {
@@ -161,6 +186,17 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
                 routing_info,
                 ..Default::default()
             },
+            PayoutAttemptUpdate::RecallUpdate {
+                status,
+                reason,
+                recalled_amount,
+            } => Self {
+                status: Some(status),
+                reason_code: Some(Some(storage_enums::PayoutStatusReasonCode::BankRecalled)),
+                reason: Some(reason),
+                recalled_amount: Some(recalled_amount),
+                ..Default::default()
+            },
         }
     }
 }
@@ -178,6 +214,9 @@ impl PayoutAttemptUpdate {
             business_label,
             connector,
             routing_info,
+            reason_code,
+            reason,
+            recalled_amount,
             last_modified_at,
         } = self.into();
         PayoutAttempt {
@@ -191,6 +230,9 @@ impl PayoutAttemptUpdate {
             business_label: business_label.or(source.business_label),
             connector: connector.or(source.connector),
             routing_info: routing_info.or(source.routing_info),
+            reason_code: reason_code.unwrap_or(source.reason_code),
+            reason: reason.unwrap_or(source.reason),
+            recalled_amount: recalled_amount.or(source.recalled_amount),
             last_modified_at,
             ..source
         }
@@ -0,0 +1,72 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout_destination_verifications};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payout_destination_verifications)]
+pub struct PayoutDestinationVerificationNew {
+    pub verification_id: String,
+    pub merchant_id: String,
+    pub customer_id: id_type::CustomerId,
+    pub payout_method_fingerprint: String,
+    pub status: storage_enums::PayoutDestinationVerificationStatus,
+    pub micro_deposits: serde_json::Value,
+    pub remaining_attempts: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub verified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payout_destination_verifications)]
+#[diesel(primary_key(verification_id))]
+pub struct PayoutDestinationVerification {
+    pub verification_id: String,
+    pub merchant_id: String,
+    pub customer_id: id_type::CustomerId,
+    pub payout_method_fingerprint: String,
+    pub status: storage_enums::PayoutDestinationVerificationStatus,
+    pub micro_deposits: serde_json::Value,
+    pub remaining_attempts: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub verified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum PayoutDestinationVerificationUpdate {
+    StatusUpdate {
+        status: storage_enums::PayoutDestinationVerificationStatus,
+        remaining_attempts: i32,
+        verified_at: Option<PrimitiveDateTime>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_destination_verifications)]
+pub struct PayoutDestinationVerificationUpdateInternal {
+    pub status: Option<storage_enums::PayoutDestinationVerificationStatus>,
+    pub remaining_attempts: Option<i32>,
+    pub verified_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutDestinationVerificationUpdate> for PayoutDestinationVerificationUpdateInternal {
+    fn from(update: PayoutDestinationVerificationUpdate) -> Self {
+        match update {
+            PayoutDestinationVerificationUpdate::StatusUpdate {
+                status,
+                remaining_attempts,
+                verified_at,
+            } => Self {
+                status: Some(status),
+                remaining_attempts: Some(remaining_attempts),
+                verified_at,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
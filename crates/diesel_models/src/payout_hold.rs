@@ -0,0 +1,65 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout_holds};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payout_holds)]
+pub struct PayoutHoldNew {
+    pub hold_id: String,
+    pub merchant_id: String,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutHoldStatus,
+    pub payout_id: Option<String>,
+    pub description: Option<String>,
+    pub expires_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payout_holds)]
+#[diesel(primary_key(hold_id))]
+pub struct PayoutHold {
+    pub hold_id: String,
+    pub merchant_id: String,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutHoldStatus,
+    pub payout_id: Option<String>,
+    pub description: Option<String>,
+    pub expires_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutHoldUpdate {
+    StatusUpdate {
+        status: storage_enums::PayoutHoldStatus,
+        payout_id: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_holds)]
+pub struct PayoutHoldUpdateInternal {
+    pub status: Option<storage_enums::PayoutHoldStatus>,
+    pub payout_id: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutHoldUpdate> for PayoutHoldUpdateInternal {
+    fn from(update: PayoutHoldUpdate) -> Self {
+        match update {
+            PayoutHoldUpdate::StatusUpdate { status, payout_id } => Self {
+                status: Some(status),
+                payout_id,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout_templates};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payout_templates)]
+pub struct PayoutTemplateNew {
+    pub template_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub currency: Option<storage_enums::Currency>,
+    pub payout_type: Option<storage_enums::PayoutType>,
+    pub entity_type: Option<storage_enums::PayoutEntityType>,
+    pub connector: Option<serde_json::Value>,
+    pub description: Option<String>,
+    pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payout_templates)]
+#[diesel(primary_key(template_id))]
+pub struct PayoutTemplate {
+    pub template_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub currency: Option<storage_enums::Currency>,
+    pub payout_type: Option<storage_enums::PayoutType>,
+    pub entity_type: Option<storage_enums::PayoutEntityType>,
+    pub connector: Option<serde_json::Value>,
+    pub description: Option<String>,
+    pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub created_at: PrimitiveDateTime,
+}
@@ -3,7 +3,7 @@ use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{self, Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as storage_enums, schema::payouts};
+use crate::{encryption::Encryption, enums as storage_enums, schema::payouts};
 
 // Payouts
 #[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
@@ -36,6 +36,28 @@ pub struct Payouts {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub payout_method_fingerprint: Option<String>,
+    pub approved_by: Option<String>,
+    pub approved_at: Option<PrimitiveDateTime>,
+    pub purpose_code: Option<String>,
+    pub webhook_url: Option<String>,
+    pub value_date: Option<time::Date>,
+    pub charge_bearer: Option<storage_enums::PayoutChargeBearer>,
+    pub statement_descriptor: Option<String>,
+    pub merchant_reference_id: Option<String>,
+    pub requires_verification: Option<bool>,
+    pub verified_at: Option<PrimitiveDateTime>,
+    pub verification_reference: Option<String>,
+    pub settlement_network: Option<storage_enums::PayoutSettlementNetwork>,
+    pub routing: Option<serde_json::Value>,
+    pub eligible_connectors: Option<serde_json::Value>,
+    pub created_by: Option<String>,
+    pub fallback_to_standard: Option<bool>,
+    pub originator: Option<pii::SecretSerdeValue>,
+    pub backup_destinations: Option<Encryption>,
+    pub is_merchant_reference_id_unique: Option<bool>,
+    pub series_id: Option<String>,
+    pub currency_conversion: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -77,6 +99,28 @@ pub struct PayoutsNew {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub payout_method_fingerprint: Option<String>,
+    pub approved_by: Option<String>,
+    pub approved_at: Option<PrimitiveDateTime>,
+    pub purpose_code: Option<String>,
+    pub webhook_url: Option<String>,
+    pub value_date: Option<time::Date>,
+    pub charge_bearer: Option<storage_enums::PayoutChargeBearer>,
+    pub statement_descriptor: Option<String>,
+    pub merchant_reference_id: Option<String>,
+    pub requires_verification: Option<bool>,
+    pub verified_at: Option<PrimitiveDateTime>,
+    pub verification_reference: Option<String>,
+    pub settlement_network: Option<storage_enums::PayoutSettlementNetwork>,
+    pub routing: Option<serde_json::Value>,
+    pub eligible_connectors: Option<serde_json::Value>,
+    pub created_by: Option<String>,
+    pub fallback_to_standard: Option<bool>,
+    pub originator: Option<pii::SecretSerdeValue>,
+    pub backup_destinations: Option<Encryption>,
+    pub is_merchant_reference_id_unique: Option<bool>,
+    pub series_id: Option<String>,
+    pub currency_conversion: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,6 +139,11 @@ pub enum PayoutsUpdate {
         status: Option<storage_enums::PayoutStatus>,
         confirm: Option<bool>,
         payout_type: Option<storage_enums::PayoutType>,
+        fallback_to_standard: Option<bool>,
+        purpose_code: Option<String>,
+        originator: Option<pii::SecretSerdeValue>,
+        backup_destinations: Option<Encryption>,
+        currency_conversion: Option<serde_json::Value>,
     },
     PayoutMethodIdUpdate {
         payout_method_id: String,
@@ -108,6 +157,37 @@ pub enum PayoutsUpdate {
     StatusUpdate {
         status: storage_enums::PayoutStatus,
     },
+    PayoutMethodFingerprintUpdate {
+        payout_method_fingerprint: String,
+    },
+    DescriptionUpdate {
+        description: String,
+    },
+    ApprovalUpdate {
+        approved_by: String,
+        approved_at: PrimitiveDateTime,
+        status: storage_enums::PayoutStatus,
+    },
+    ApprovalRevokeUpdate,
+    VerificationUpdate {
+        verified_at: PrimitiveDateTime,
+        verification_reference: Option<String>,
+        status: storage_enums::PayoutStatus,
+    },
+    PriorityUpdate {
+        priority: storage_enums::PayoutSendPriority,
+    },
+    SettlementNetworkUpdate {
+        settlement_network: storage_enums::PayoutSettlementNetwork,
+        priority: Option<storage_enums::PayoutSendPriority>,
+    },
+    RoutingUpdate {
+        routing: Option<serde_json::Value>,
+        eligible_connectors: Option<serde_json::Value>,
+    },
+    AddressUpdate {
+        address_id: String,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -129,6 +209,21 @@ pub struct PayoutsUpdateInternal {
     pub attempt_count: Option<i16>,
     pub confirm: Option<bool>,
     pub payout_type: Option<common_enums::PayoutType>,
+    pub payout_method_fingerprint: Option<String>,
+    pub approved_by: Option<Option<String>>,
+    pub approved_at: Option<Option<PrimitiveDateTime>>,
+    pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub address_id: Option<String>,
+    pub verified_at: Option<PrimitiveDateTime>,
+    pub verification_reference: Option<String>,
+    pub settlement_network: Option<storage_enums::PayoutSettlementNetwork>,
+    pub routing: Option<serde_json::Value>,
+    pub eligible_connectors: Option<serde_json::Value>,
+    pub fallback_to_standard: Option<bool>,
+    pub purpose_code: Option<String>,
+    pub originator: Option<pii::SecretSerdeValue>,
+    pub backup_destinations: Option<Encryption>,
+    pub currency_conversion: Option<Option<serde_json::Value>>,
 }
 
 impl Default for PayoutsUpdateInternal {
@@ -150,6 +245,21 @@ impl Default for PayoutsUpdateInternal {
             attempt_count: None,
             confirm: None,
             payout_type: None,
+            payout_method_fingerprint: None,
+            approved_by: None,
+            approved_at: None,
+            priority: None,
+            address_id: None,
+            verified_at: None,
+            verification_reference: None,
+            settlement_network: None,
+            routing: None,
+            eligible_connectors: None,
+            fallback_to_standard: None,
+            purpose_code: None,
+            originator: None,
+            backup_destinations: None,
+            currency_conversion: None,
         }
     }
 }
@@ -171,6 +281,11 @@ impl From<PayoutsUpdate> for PayoutsUpdateInternal {
                 status,
                 confirm,
                 payout_type,
+                fallback_to_standard,
+                purpose_code,
+                originator,
+                backup_destinations,
+                currency_conversion,
             } => Self {
                 amount: Some(amount),
                 destination_currency: Some(destination_currency),
@@ -185,6 +300,11 @@ impl From<PayoutsUpdate> for PayoutsUpdateInternal {
                 status,
                 confirm,
                 payout_type,
+                fallback_to_standard,
+                purpose_code,
+                originator,
+                backup_destinations,
+                currency_conversion: Some(currency_conversion),
                 ..Default::default()
             },
             PayoutsUpdate::PayoutMethodIdUpdate { payout_method_id } => Self {
@@ -203,6 +323,65 @@ impl From<PayoutsUpdate> for PayoutsUpdateInternal {
                 status: Some(status),
                 ..Default::default()
             },
+            PayoutsUpdate::PayoutMethodFingerprintUpdate {
+                payout_method_fingerprint,
+            } => Self {
+                payout_method_fingerprint: Some(payout_method_fingerprint),
+                ..Default::default()
+            },
+            PayoutsUpdate::DescriptionUpdate { description } => Self {
+                description: Some(description),
+                ..Default::default()
+            },
+            PayoutsUpdate::ApprovalUpdate {
+                approved_by,
+                approved_at,
+                status,
+            } => Self {
+                approved_by: Some(Some(approved_by)),
+                approved_at: Some(Some(approved_at)),
+                status: Some(status),
+                ..Default::default()
+            },
+            PayoutsUpdate::ApprovalRevokeUpdate => Self {
+                approved_by: Some(None),
+                approved_at: Some(None),
+                ..Default::default()
+            },
+            PayoutsUpdate::VerificationUpdate {
+                verified_at,
+                verification_reference,
+                status,
+            } => Self {
+                verified_at: Some(verified_at),
+                verification_reference,
+                status: Some(status),
+                ..Default::default()
+            },
+            PayoutsUpdate::PriorityUpdate { priority } => Self {
+                priority: Some(priority),
+                ..Default::default()
+            },
+            PayoutsUpdate::SettlementNetworkUpdate {
+                settlement_network,
+                priority,
+            } => Self {
+                settlement_network: Some(settlement_network),
+                priority,
+                ..Default::default()
+            },
+            PayoutsUpdate::RoutingUpdate {
+                routing,
+                eligible_connectors,
+            } => Self {
+                routing,
+                eligible_connectors,
+                ..Default::default()
+            },
+            PayoutsUpdate::AddressUpdate { address_id } => Self {
+                address_id: Some(address_id),
+                ..Default::default()
+            },
         }
     }
 }
@@ -226,6 +405,21 @@ impl PayoutsUpdate {
             attempt_count,
             confirm,
             payout_type,
+            payout_method_fingerprint,
+            approved_by,
+            approved_at,
+            priority,
+            address_id,
+            verified_at,
+            verification_reference,
+            settlement_network,
+            routing,
+            eligible_connectors,
+            fallback_to_standard,
+            purpose_code,
+            originator,
+            backup_destinations,
+            currency_conversion,
         } = self.into();
         Payouts {
             amount: amount.unwrap_or(source.amount),
@@ -244,6 +438,22 @@ impl PayoutsUpdate {
             attempt_count: attempt_count.unwrap_or(source.attempt_count),
             confirm: confirm.or(source.confirm),
             payout_type: payout_type.or(source.payout_type),
+            payout_method_fingerprint: payout_method_fingerprint
+                .or(source.payout_method_fingerprint),
+            approved_by: approved_by.unwrap_or(source.approved_by),
+            approved_at: approved_at.unwrap_or(source.approved_at),
+            priority: priority.or(source.priority),
+            address_id: address_id.unwrap_or(source.address_id),
+            verified_at: verified_at.or(source.verified_at),
+            verification_reference: verification_reference.or(source.verification_reference),
+            settlement_network: settlement_network.or(source.settlement_network),
+            routing: routing.or(source.routing),
+            eligible_connectors: eligible_connectors.or(source.eligible_connectors),
+            fallback_to_standard: fallback_to_standard.or(source.fallback_to_standard),
+            purpose_code: purpose_code.or(source.purpose_code),
+            originator: originator.or(source.originator),
+            backup_destinations: backup_destinations.or(source.backup_destinations),
+            currency_conversion: currency_conversion.unwrap_or(source.currency_conversion),
             ..source
         }
     }
@@ -209,6 +209,10 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    ScheduledPayoutWorkflow,
+    PayoutVerificationExpiryWorkflow,
+    PayoutsExportWorkflow,
+    PayoutHoldExpiryWorkflow,
 }
 
 #[cfg(test)]
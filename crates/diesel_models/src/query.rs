@@ -10,6 +10,7 @@ pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
+pub mod currency_conversion_audit_log;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -30,12 +31,16 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_destination_verification;
+pub mod payout_hold;
+pub mod payout_template;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod store_credit_ledger_entry;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
@@ -3,10 +3,11 @@ use std::collections::HashSet;
 use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{
     associations::HasTable,
-    query_dsl::methods::{DistinctDsl, FilterDsl, SelectDsl},
+    query_dsl::methods::{DistinctDsl, FilterDsl, GroupByDsl, SelectDsl},
     BoolExpressionMethods, ExpressionMethods,
 };
 use error_stack::{report, ResultExt};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -53,6 +54,26 @@ impl PayoutAttempt {
         }
     }
 
+    /// Updates the payout attempt only if it is still in `expected_status`, so that concurrent
+    /// transitions out of a status cannot both apply. Returns `None` if the attempt has moved on.
+    pub async fn update_with_attempt_id_and_status(
+        self,
+        conn: &PgPooledConn,
+        expected_status: enums::PayoutStatus,
+        payout_attempt_update: PayoutAttemptUpdate,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::payout_attempt_id
+                .eq(self.payout_attempt_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+                .and(dsl::status.eq(expected_status)),
+            PayoutAttemptUpdateInternal::from(payout_attempt_update),
+        )
+        .await
+        .map(|mut payout_attempts| payout_attempts.pop())
+    }
+
     pub async fn find_by_merchant_id_payout_id(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -95,6 +116,40 @@ impl PayoutAttempt {
         .await
     }
 
+    pub async fn find_all_by_merchant_id_payout_attempt_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_attempt_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_attempt_id.eq_any(payout_attempt_ids)),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn find_all_by_merchant_id_connector_payout_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_payout_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_payout_id.eq(connector_payout_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
     pub async fn update_by_merchant_id_payout_id(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -209,4 +264,39 @@ impl PayoutAttempt {
             filter_payout_method,
         ))
     }
+
+    /// Counts the attempts of the payouts selected by `payout_ids` by connector, along with the
+    /// time of the latest attempt made through each connector.
+    pub async fn get_connector_usage_for_payouts(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_ids: diesel::dsl::IntoBoxed<
+            'static,
+            diesel::dsl::Select<payout_dsl::table, payout_dsl::payout_id>,
+            diesel::pg::Pg,
+        >,
+    ) -> StorageResult<Vec<(String, i64, PrimitiveDateTime)>> {
+        <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::payout_id.eq_any(payout_ids))
+            .filter(dsl::connector.is_not_null())
+            .group_by(dsl::connector)
+            .select((
+                dsl::connector,
+                diesel::dsl::count_star(),
+                diesel::dsl::max(dsl::created_at),
+            ))
+            .get_results_async::<(Option<String>, i64, Option<PrimitiveDateTime>)>(conn)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error grouping payout attempts by connector")
+            .map(|usage| {
+                usage
+                    .into_iter()
+                    .filter_map(|(connector, count, last_used_at)| {
+                        Some((connector?, count, last_used_at?))
+                    })
+                    .collect()
+            })
+    }
 }
@@ -0,0 +1,73 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payout_destination_verification::{
+        PayoutDestinationVerification, PayoutDestinationVerificationNew,
+        PayoutDestinationVerificationUpdate, PayoutDestinationVerificationUpdateInternal,
+    },
+    schema::payout_destination_verifications::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutDestinationVerificationNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutDestinationVerification> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutDestinationVerification {
+    pub async fn find_by_merchant_id_verification_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        verification_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::verification_id.eq(verification_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_customer_id_fingerprint(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        payout_method_fingerprint: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::payout_method_fingerprint.eq(payout_method_fingerprint.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Updates the verification only if its status and remaining attempts are still the ones which
+    /// were read, so that concurrent confirmations cannot use the same attempt. Returns `None` if
+    /// the verification has been updated since.
+    pub async fn update_if_unchanged(
+        self,
+        conn: &PgPooledConn,
+        update: PayoutDestinationVerificationUpdate,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::verification_id
+                .eq(self.verification_id.to_owned())
+                .and(dsl::status.eq(self.status))
+                .and(dsl::remaining_attempts.eq(self.remaining_attempts)),
+            PayoutDestinationVerificationUpdateInternal::from(update),
+        )
+        .await
+        .map(|mut verifications| verifications.pop())
+    }
+}
@@ -0,0 +1,66 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    payout_hold::{PayoutHold, PayoutHoldNew, PayoutHoldUpdate, PayoutHoldUpdateInternal},
+    schema::payout_holds::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutHoldNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutHold> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutHold {
+    pub async fn find_by_merchant_id_hold_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        hold_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::hold_id.eq(hold_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        status: storage_enums::PayoutHoldStatus,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::status.eq(status)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Updates the hold only if it is still held, so that a hold cannot be both captured and
+    /// released or expired. Returns `None` if the hold is no longer held.
+    pub async fn update_if_held(
+        self,
+        conn: &PgPooledConn,
+        update: PayoutHoldUpdate,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::hold_id
+                .eq(self.hold_id.to_owned())
+                .and(dsl::status.eq(storage_enums::PayoutHoldStatus::Held)),
+            PayoutHoldUpdateInternal::from(update),
+        )
+        .await
+        .map(|mut holds| holds.pop())
+    }
+}
@@ -0,0 +1,58 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payout_template::{PayoutTemplate, PayoutTemplateNew},
+    schema::payout_templates::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutTemplateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutTemplate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutTemplate {
+    pub async fn find_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::template_id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::template_id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+}
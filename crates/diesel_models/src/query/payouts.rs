@@ -1,5 +1,10 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
-use error_stack::report;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::id_type;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::{report, ResultExt};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -87,4 +92,90 @@ impl Payouts {
         )
         .await
     }
+
+    pub async fn find_optional_by_merchant_id_merchant_reference_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        merchant_reference_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_reference_id.eq(merchant_reference_id.to_owned())),
+            Some(1),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+        .map(|payouts| payouts.into_iter().next())
+    }
+
+    pub async fn find_by_merchant_id_series_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        series_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::series_id.eq(series_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Finds the earliest payout of the merchant to the same payout method fingerprint, created
+    /// before the given time by a customer other than the given one.
+    pub async fn find_optional_by_merchant_id_payout_method_fingerprint_other_customer(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_method_fingerprint: &str,
+        customer_id: &id_type::CustomerId,
+        created_before: PrimitiveDateTime,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_method_fingerprint.eq(payout_method_fingerprint.to_owned()))
+                .and(dsl::customer_id.ne(customer_id.to_owned()))
+                .and(dsl::created_at.lt(created_before)),
+            Some(1),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+        .map(|payouts| payouts.into_iter().next())
+    }
+
+    pub async fn get_count_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        created_at_or_before: PrimitiveDateTime,
+    ) -> StorageResult<i64> {
+        let filter = <Self as HasTable>::table()
+            .count()
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::customer_id.eq(customer_id.to_owned()))
+                    .and(dsl::created_at.le(created_at_or_before)),
+            )
+            .into_boxed();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of payouts")
+    }
 }
@@ -0,0 +1,30 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::store_credit_ledger_entries::dsl,
+    store_credit_ledger_entry::{StoreCreditLedgerEntry, StoreCreditLedgerEntryNew},
+    PgPooledConn, StorageResult,
+};
+
+impl StoreCreditLedgerEntryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<StoreCreditLedgerEntry> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl StoreCreditLedgerEntry {
+    pub async fn find_by_merchant_id_payout_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_id.eq(payout_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -277,6 +277,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    currency_conversion_audit_logs (audit_id) {
+        #[max_length = 64]
+        audit_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        source_amount -> Int8,
+        #[max_length = 32]
+        source_currency -> Varchar,
+        #[max_length = 32]
+        destination_currency -> Varchar,
+        #[max_length = 64]
+        converted_amount -> Varchar,
+        #[max_length = 64]
+        exchange_rate -> Varchar,
+        #[max_length = 32]
+        exchange_rate_source -> Nullable<Varchar>,
+        exchange_rate_timestamp -> Timestamp,
+        is_binding -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1012,6 +1040,81 @@ diesel::table! {
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
         routing_info -> Nullable<Jsonb>,
+        #[max_length = 64]
+        reason_code -> Nullable<Varchar>,
+        reason -> Nullable<Text>,
+        destination_index -> Nullable<Int2>,
+        recalled_amount -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_destination_verifications (verification_id) {
+        #[max_length = 64]
+        verification_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        payout_method_fingerprint -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        micro_deposits -> Jsonb,
+        remaining_attempts -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        verified_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_holds (hold_id) {
+        #[max_length = 64]
+        hold_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        expires_at -> Timestamp,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_templates (template_id) {
+        #[max_length = 64]
+        template_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        currency -> Nullable<Currency>,
+        payout_type -> Nullable<PayoutType>,
+        #[max_length = 64]
+        entity_type -> Nullable<Varchar>,
+        connector -> Nullable<Jsonb>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 32]
+        priority -> Nullable<Varchar>,
+        created_at -> Timestamp,
     }
 }
 
@@ -1056,6 +1159,38 @@ diesel::table! {
         client_secret -> Nullable<Varchar>,
         #[max_length = 32]
         priority -> Nullable<Varchar>,
+        #[max_length = 64]
+        payout_method_fingerprint -> Nullable<Varchar>,
+        #[max_length = 255]
+        approved_by -> Nullable<Varchar>,
+        approved_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        purpose_code -> Nullable<Varchar>,
+        webhook_url -> Nullable<Text>,
+        value_date -> Nullable<Date>,
+        #[max_length = 16]
+        charge_bearer -> Nullable<Varchar>,
+        #[max_length = 64]
+        statement_descriptor -> Nullable<Varchar>,
+        #[max_length = 64]
+        merchant_reference_id -> Nullable<Varchar>,
+        requires_verification -> Nullable<Bool>,
+        verified_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        verification_reference -> Nullable<Varchar>,
+        #[max_length = 32]
+        settlement_network -> Nullable<Varchar>,
+        routing -> Nullable<Jsonb>,
+        eligible_connectors -> Nullable<Jsonb>,
+        #[max_length = 255]
+        created_by -> Nullable<Varchar>,
+        fallback_to_standard -> Nullable<Bool>,
+        originator -> Nullable<Jsonb>,
+        backup_destinations -> Nullable<Bytea>,
+        is_merchant_reference_id_unique -> Nullable<Bool>,
+        #[max_length = 64]
+        series_id -> Nullable<Varchar>,
+        currency_conversion -> Nullable<Jsonb>,
     }
 }
 
@@ -1202,6 +1337,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    store_credit_ledger_entries (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        credit_account_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payout_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1298,6 +1454,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     captures,
     cards_info,
     configs,
+    currency_conversion_audit_logs,
     customers,
     dashboard_metadata,
     dispute,
@@ -1318,12 +1475,16 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_link,
     payment_methods,
     payout_attempt,
+    payout_destination_verifications,
+    payout_holds,
+    payout_templates,
     payouts,
     process_tracker,
     refund,
     reverse_lookup,
     roles,
     routing_algorithm,
+    store_credit_ledger_entries,
     user_authentication_methods,
     user_key_store,
     user_roles,
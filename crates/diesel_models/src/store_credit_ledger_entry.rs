@@ -0,0 +1,33 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::store_credit_ledger_entries};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = store_credit_ledger_entries)]
+pub struct StoreCreditLedgerEntryNew {
+    pub entry_id: String,
+    pub merchant_id: String,
+    pub customer_id: id_type::CustomerId,
+    pub credit_account_id: Option<String>,
+    pub payout_id: String,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = store_credit_ledger_entries)]
+#[diesel(primary_key(entry_id))]
+pub struct StoreCreditLedgerEntry {
+    pub entry_id: String,
+    pub merchant_id: String,
+    pub customer_id: id_type::CustomerId,
+    pub credit_account_id: Option<String>,
+    pub payout_id: String,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub created_at: PrimitiveDateTime,
+}
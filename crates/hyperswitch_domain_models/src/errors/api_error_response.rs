@@ -271,6 +271,10 @@ pub enum ApiErrorResponse {
     InvalidCookie,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_27", message = "Extended card info does not exist")]
     ExtendedCardInfoNotFound,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_28", message = "Payouts to {country} are not allowed")]
+    PayoutDestinationCountryNotAllowed { country: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_29", message = "Too many requests to fetch forex rates, please try again later")]
+    ForexRateLimitExceeded,
     #[error(error_type = ErrorType::ProcessingError, code = "HE_06", message = "Missing tenant id")]
     MissingTenantId,
     #[error(error_type = ErrorType::ProcessingError, code = "HE_06", message = "Invalid tenant id: {tenant_id}")]
@@ -607,6 +611,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ExtendedCardInfoNotFound => {
                 AER::NotFound(ApiError::new("IR", 27, "Extended card info does not exist", None))
             }
+            Self::PayoutDestinationCountryNotAllowed { country } => {
+                AER::BadRequest(ApiError::new("IR", 28, format!("Payouts to {country} are not allowed"), None))
+            }
+            Self::ForexRateLimitExceeded => {
+                AER::TooManyRequests(ApiError::new("IR", 29, "Too many requests to fetch forex rates, please try again later", None))
+            }
             Self::MissingTenantId => {
                 AER::InternalServerError(ApiError::new("HE", 6, "Missing Tenant ID in the request".to_string(), None))
             }
@@ -21,6 +21,7 @@ pub struct PayoutListParams {
     pub payout_method: Option<Vec<common_enums::PayoutType>>,
     pub profile_id: Option<String>,
     pub customer_id: Option<id_type::CustomerId>,
+    pub merchant_reference_id: Option<String>,
     pub starting_after_id: Option<String>,
     pub ending_before_id: Option<String>,
     pub entity_type: Option<common_enums::PayoutEntityType>,
@@ -41,6 +42,7 @@ impl From<api_models::payouts::PayoutListConstraints> for PayoutFetchConstraints
             payout_method: None,
             profile_id: None,
             customer_id: value.customer_id,
+            merchant_reference_id: value.merchant_reference_id,
             starting_after_id: value.starting_after,
             ending_before_id: value.ending_before,
             entity_type: None,
@@ -64,6 +66,7 @@ impl From<api_models::payments::TimeRange> for PayoutFetchConstraints {
             payout_method: None,
             profile_id: None,
             customer_id: None,
+            merchant_reference_id: None,
             starting_after_id: None,
             ending_before_id: None,
             entity_type: None,
@@ -87,6 +90,7 @@ impl From<api_models::payouts::PayoutListFilterConstraints> for PayoutFetchConst
                 payout_method: value.payout_method,
                 profile_id: value.profile_id,
                 customer_id: value.customer_id,
+                merchant_reference_id: value.merchant_reference_id,
                 starting_after_id: None,
                 ending_before_id: None,
                 entity_type: value.entity_type,
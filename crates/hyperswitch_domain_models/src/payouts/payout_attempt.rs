@@ -1,6 +1,6 @@
 use api_models::enums::PayoutConnectors;
 use common_enums as storage_enums;
-use common_utils::{generate_customer_id_of_default_length, id_type};
+use common_utils::{generate_customer_id_of_default_length, id_type, types::MinorUnit};
 use serde::{Deserialize, Serialize};
 use storage_enums::MerchantStorageScheme;
 use time::PrimitiveDateTime;
@@ -25,6 +25,17 @@ pub trait PayoutAttemptInterface {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutAttempt, errors::StorageError>;
 
+    /// Updates the payout attempt only if it is still in `expected_status`, returning `None` if a
+    /// concurrent update has moved it out of that status
+    async fn update_payout_attempt_if_status(
+        &self,
+        _this: &PayoutAttempt,
+        _expected_status: storage_enums::PayoutStatus,
+        _payout_attempt_update: PayoutAttemptUpdate,
+        _payouts: &Payouts,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<PayoutAttempt>, errors::StorageError>;
+
     async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
         &self,
         _merchant_id: &str,
@@ -39,12 +50,34 @@ pub trait PayoutAttemptInterface {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutAttempt, errors::StorageError>;
 
+    async fn list_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        _merchant_id: &str,
+        _payout_attempt_ids: &[String],
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError>;
+
+    async fn list_payout_attempts_by_merchant_id_connector_payout_id(
+        &self,
+        _merchant_id: &str,
+        _connector_payout_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError>;
+
     async fn get_filters_for_payouts(
         &self,
         payout: &[Payouts],
         merchant_id: &str,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutListFilters, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn get_connector_usage_for_payouts(
+        &self,
+        merchant_id: &str,
+        filters: &super::PayoutFetchConstraints,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutConnectorUsage>, errors::StorageError>;
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -55,6 +88,15 @@ pub struct PayoutListFilters {
     pub payout_method: Vec<storage_enums::PayoutType>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutConnectorUsage {
+    pub connector: PayoutConnectors,
+    /// Number of payout attempts made through the connector
+    pub count: i64,
+    /// Time of the latest payout attempt made through the connector
+    pub last_used_at: PrimitiveDateTime,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PayoutAttempt {
     pub payout_attempt_id: String,
@@ -78,6 +120,10 @@ pub struct PayoutAttempt {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub reason_code: Option<storage_enums::PayoutStatusReasonCode>,
+    pub reason: Option<String>,
+    pub destination_index: Option<i16>,
+    pub recalled_amount: Option<MinorUnit>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -101,6 +147,10 @@ pub struct PayoutAttemptNew {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub reason_code: Option<storage_enums::PayoutStatusReasonCode>,
+    pub reason: Option<String>,
+    pub destination_index: Option<i16>,
+    pub recalled_amount: Option<MinorUnit>,
 }
 
 impl Default for PayoutAttemptNew {
@@ -127,6 +177,10 @@ impl Default for PayoutAttemptNew {
             profile_id: String::default(),
             merchant_connector_id: None,
             routing_info: None,
+            reason_code: None,
+            reason: None,
+            destination_index: None,
+            recalled_amount: None,
         }
     }
 }
@@ -139,6 +193,8 @@ pub enum PayoutAttemptUpdate {
         error_message: Option<String>,
         error_code: Option<String>,
         is_eligible: Option<bool>,
+        reason_code: Option<storage_enums::PayoutStatusReasonCode>,
+        reason: Option<String>,
     },
     PayoutTokenUpdate {
         payout_token: String,
@@ -151,6 +207,11 @@ pub enum PayoutAttemptUpdate {
         connector: String,
         routing_info: Option<serde_json::Value>,
     },
+    RecallUpdate {
+        status: storage_enums::PayoutStatus,
+        reason: Option<String>,
+        recalled_amount: MinorUnit,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -165,6 +226,9 @@ pub struct PayoutAttemptUpdateInternal {
     pub business_label: Option<String>,
     pub connector: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub reason_code: Option<Option<storage_enums::PayoutStatusReasonCode>>,
+    pub reason: Option<Option<String>>,
+    pub recalled_amount: Option<MinorUnit>,
 }
 
 impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
@@ -180,12 +244,16 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
                 error_message,
                 error_code,
                 is_eligible,
+                reason_code,
+                reason,
             } => Self {
                 connector_payout_id,
                 status: Some(status),
                 error_message,
                 error_code,
                 is_eligible,
+                reason_code: Some(reason_code),
+                reason: Some(reason),
                 ..Default::default()
             },
             PayoutAttemptUpdate::BusinessUpdate {
@@ -204,6 +272,17 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
                 routing_info,
                 ..Default::default()
             },
+            PayoutAttemptUpdate::RecallUpdate {
+                status,
+                reason,
+                recalled_amount,
+            } => Self {
+                status: Some(status),
+                reason_code: Some(Some(storage_enums::PayoutStatusReasonCode::BankRecalled)),
+                reason: Some(reason),
+                recalled_amount: Some(recalled_amount),
+                ..Default::default()
+            },
         }
     }
 }
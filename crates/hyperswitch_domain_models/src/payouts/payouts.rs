@@ -1,5 +1,6 @@
 use common_enums as storage_enums;
 use common_utils::{id_type, pii, types::MinorUnit};
+use diesel_models::encryption::Encryption;
 use serde::{Deserialize, Serialize};
 use storage_enums::MerchantStorageScheme;
 use time::PrimitiveDateTime;
@@ -39,6 +40,20 @@ pub trait PayoutsInterface {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Option<Payouts>, errors::StorageError>;
 
+    async fn find_optional_payout_by_merchant_id_merchant_reference_id(
+        &self,
+        _merchant_id: &str,
+        _merchant_reference_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<Payouts>, errors::StorageError>;
+
+    async fn find_payouts_by_merchant_id_series_id(
+        &self,
+        _merchant_id: &str,
+        _series_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, errors::StorageError>;
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
@@ -65,6 +80,24 @@ pub trait PayoutsInterface {
         time_range: &api_models::payments::TimeRange,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<Payouts>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn find_earlier_payout_by_fingerprint_for_other_customer(
+        &self,
+        _merchant_id: &str,
+        _payout_method_fingerprint: &str,
+        _customer_id: &id_type::CustomerId,
+        _created_before: PrimitiveDateTime,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<Payouts>, errors::StorageError>;
+
+    async fn get_payout_count_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &id_type::CustomerId,
+        _created_at_or_before: PrimitiveDateTime,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<i64, errors::StorageError>;
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -93,6 +126,28 @@ pub struct Payouts {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub payout_method_fingerprint: Option<String>,
+    pub approved_by: Option<String>,
+    pub approved_at: Option<PrimitiveDateTime>,
+    pub purpose_code: Option<String>,
+    pub webhook_url: Option<String>,
+    pub value_date: Option<time::Date>,
+    pub charge_bearer: Option<storage_enums::PayoutChargeBearer>,
+    pub statement_descriptor: Option<String>,
+    pub merchant_reference_id: Option<String>,
+    pub requires_verification: Option<bool>,
+    pub verified_at: Option<PrimitiveDateTime>,
+    pub verification_reference: Option<String>,
+    pub settlement_network: Option<storage_enums::PayoutSettlementNetwork>,
+    pub routing: Option<serde_json::Value>,
+    pub eligible_connectors: Option<serde_json::Value>,
+    pub created_by: Option<String>,
+    pub fallback_to_standard: Option<bool>,
+    pub originator: Option<pii::SecretSerdeValue>,
+    pub backup_destinations: Option<Encryption>,
+    pub is_merchant_reference_id_unique: Option<bool>,
+    pub series_id: Option<String>,
+    pub currency_conversion: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -121,6 +176,28 @@ pub struct PayoutsNew {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub payout_method_fingerprint: Option<String>,
+    pub approved_by: Option<String>,
+    pub approved_at: Option<PrimitiveDateTime>,
+    pub purpose_code: Option<String>,
+    pub webhook_url: Option<String>,
+    pub value_date: Option<time::Date>,
+    pub charge_bearer: Option<storage_enums::PayoutChargeBearer>,
+    pub statement_descriptor: Option<String>,
+    pub merchant_reference_id: Option<String>,
+    pub requires_verification: Option<bool>,
+    pub verified_at: Option<PrimitiveDateTime>,
+    pub verification_reference: Option<String>,
+    pub settlement_network: Option<storage_enums::PayoutSettlementNetwork>,
+    pub routing: Option<serde_json::Value>,
+    pub eligible_connectors: Option<serde_json::Value>,
+    pub created_by: Option<String>,
+    pub fallback_to_standard: Option<bool>,
+    pub originator: Option<pii::SecretSerdeValue>,
+    pub backup_destinations: Option<Encryption>,
+    pub is_merchant_reference_id_unique: Option<bool>,
+    pub series_id: Option<String>,
+    pub currency_conversion: Option<serde_json::Value>,
 }
 
 impl Default for PayoutsNew {
@@ -152,6 +229,28 @@ impl Default for PayoutsNew {
             payout_link_id: Option::default(),
             client_secret: Option::default(),
             priority: None,
+            payout_method_fingerprint: None,
+            approved_by: None,
+            approved_at: None,
+            purpose_code: None,
+            webhook_url: None,
+            value_date: None,
+            charge_bearer: None,
+            statement_descriptor: None,
+            merchant_reference_id: None,
+            requires_verification: None,
+            verified_at: None,
+            verification_reference: None,
+            settlement_network: None,
+            routing: None,
+            eligible_connectors: None,
+            created_by: None,
+            fallback_to_standard: None,
+            originator: None,
+            backup_destinations: None,
+            is_merchant_reference_id_unique: None,
+            series_id: None,
+            currency_conversion: None,
         }
     }
 }
@@ -172,6 +271,11 @@ pub enum PayoutsUpdate {
         status: Option<storage_enums::PayoutStatus>,
         confirm: Option<bool>,
         payout_type: Option<storage_enums::PayoutType>,
+        fallback_to_standard: Option<bool>,
+        purpose_code: Option<String>,
+        originator: Option<pii::SecretSerdeValue>,
+        backup_destinations: Option<Encryption>,
+        currency_conversion: Option<serde_json::Value>,
     },
     PayoutMethodIdUpdate {
         payout_method_id: String,
@@ -185,6 +289,37 @@ pub enum PayoutsUpdate {
     StatusUpdate {
         status: storage_enums::PayoutStatus,
     },
+    PayoutMethodFingerprintUpdate {
+        payout_method_fingerprint: String,
+    },
+    DescriptionUpdate {
+        description: String,
+    },
+    ApprovalUpdate {
+        approved_by: String,
+        approved_at: PrimitiveDateTime,
+        status: storage_enums::PayoutStatus,
+    },
+    ApprovalRevokeUpdate,
+    VerificationUpdate {
+        verified_at: PrimitiveDateTime,
+        verification_reference: Option<String>,
+        status: storage_enums::PayoutStatus,
+    },
+    PriorityUpdate {
+        priority: storage_enums::PayoutSendPriority,
+    },
+    SettlementNetworkUpdate {
+        settlement_network: storage_enums::PayoutSettlementNetwork,
+        priority: Option<storage_enums::PayoutSendPriority>,
+    },
+    RoutingUpdate {
+        routing: Option<serde_json::Value>,
+        eligible_connectors: Option<serde_json::Value>,
+    },
+    AddressUpdate {
+        address_id: String,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -204,6 +339,21 @@ pub struct PayoutsUpdateInternal {
     pub attempt_count: Option<i16>,
     pub confirm: Option<bool>,
     pub payout_type: Option<common_enums::PayoutType>,
+    pub payout_method_fingerprint: Option<String>,
+    pub approved_by: Option<Option<String>>,
+    pub approved_at: Option<Option<PrimitiveDateTime>>,
+    pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub address_id: Option<String>,
+    pub verified_at: Option<PrimitiveDateTime>,
+    pub verification_reference: Option<String>,
+    pub settlement_network: Option<storage_enums::PayoutSettlementNetwork>,
+    pub routing: Option<serde_json::Value>,
+    pub eligible_connectors: Option<serde_json::Value>,
+    pub fallback_to_standard: Option<bool>,
+    pub purpose_code: Option<String>,
+    pub originator: Option<pii::SecretSerdeValue>,
+    pub backup_destinations: Option<Encryption>,
+    pub currency_conversion: Option<Option<serde_json::Value>>,
 }
 
 impl From<PayoutsUpdate> for PayoutsUpdateInternal {
@@ -223,6 +373,11 @@ impl From<PayoutsUpdate> for PayoutsUpdateInternal {
                 status,
                 confirm,
                 payout_type,
+                fallback_to_standard,
+                purpose_code,
+                originator,
+                backup_destinations,
+                currency_conversion,
             } => Self {
                 amount: Some(amount),
                 destination_currency: Some(destination_currency),
@@ -237,6 +392,11 @@ impl From<PayoutsUpdate> for PayoutsUpdateInternal {
                 status,
                 confirm,
                 payout_type,
+                fallback_to_standard,
+                purpose_code,
+                originator,
+                backup_destinations,
+                currency_conversion: Some(currency_conversion),
                 ..Default::default()
             },
             PayoutsUpdate::PayoutMethodIdUpdate { payout_method_id } => Self {
@@ -255,6 +415,65 @@ impl From<PayoutsUpdate> for PayoutsUpdateInternal {
                 status: Some(status),
                 ..Default::default()
             },
+            PayoutsUpdate::PayoutMethodFingerprintUpdate {
+                payout_method_fingerprint,
+            } => Self {
+                payout_method_fingerprint: Some(payout_method_fingerprint),
+                ..Default::default()
+            },
+            PayoutsUpdate::DescriptionUpdate { description } => Self {
+                description: Some(description),
+                ..Default::default()
+            },
+            PayoutsUpdate::ApprovalUpdate {
+                approved_by,
+                approved_at,
+                status,
+            } => Self {
+                approved_by: Some(Some(approved_by)),
+                approved_at: Some(Some(approved_at)),
+                status: Some(status),
+                ..Default::default()
+            },
+            PayoutsUpdate::ApprovalRevokeUpdate => Self {
+                approved_by: Some(None),
+                approved_at: Some(None),
+                ..Default::default()
+            },
+            PayoutsUpdate::VerificationUpdate {
+                verified_at,
+                verification_reference,
+                status,
+            } => Self {
+                verified_at: Some(verified_at),
+                verification_reference,
+                status: Some(status),
+                ..Default::default()
+            },
+            PayoutsUpdate::PriorityUpdate { priority } => Self {
+                priority: Some(priority),
+                ..Default::default()
+            },
+            PayoutsUpdate::SettlementNetworkUpdate {
+                settlement_network,
+                priority,
+            } => Self {
+                settlement_network: Some(settlement_network),
+                priority,
+                ..Default::default()
+            },
+            PayoutsUpdate::RoutingUpdate {
+                routing,
+                eligible_connectors,
+            } => Self {
+                routing,
+                eligible_connectors,
+                ..Default::default()
+            },
+            PayoutsUpdate::AddressUpdate { address_id } => Self {
+                address_id: Some(address_id),
+                ..Default::default()
+            },
         }
     }
 }
@@ -657,6 +657,15 @@ pub struct PayoutsData {
     // New minor amount for amount framework
     pub minor_amount: MinorUnit,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub purpose_code: Option<String>,
+    pub originator: Option<api_models::payouts::PayoutOriginator>,
+    /// The date on which the funds are to be credited, passed only to connectors supporting it
+    pub value_date: Option<time::Date>,
+    pub charge_bearer: storage_enums::PayoutChargeBearer,
+    /// The statement descriptor of the payout, formatted for the connector. This is not present
+    /// when the connector does not pass descriptors on to the recipient
+    pub statement_descriptor: Option<String>,
+    pub settlement_network: Option<storage_enums::PayoutSettlementNetwork>,
 }
 
 #[derive(Debug, Default, Clone)]
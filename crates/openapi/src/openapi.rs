@@ -80,6 +80,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
+        routes::payment_link::payment_link_retrieve_by_payment_id,
+        routes::payment_link::payment_link_preview,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
 
@@ -163,11 +165,37 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for payouts
         routes::payouts::payouts_create,
         routes::payouts::payouts_retrieve,
+        routes::payouts::payouts_bulk_retrieve,
+        routes::payouts::payouts_batch_create,
+        routes::payouts::payouts_retrieve_by_connector_reference,
+        routes::payouts::payout_templates_create,
+        routes::payouts::payout_templates_list,
+        routes::payouts::payout_templates_retrieve,
+        routes::payouts::payout_templates_delete,
+        routes::payouts::payout_destination_verifications_create,
+        routes::payouts::payout_destination_verifications_retrieve,
+        routes::payouts::payout_destination_verifications_confirm,
+        routes::payouts::payout_holds_create,
+        routes::payouts::payout_holds_retrieve,
+        routes::payouts::payout_holds_capture,
+        routes::payouts::payout_holds_release,
+        routes::payouts::payout_holds_balance,
+        routes::payouts::payouts_admin_retrieve,
+        routes::payouts::payouts_admin_currency_conversions,
         routes::payouts::payouts_update,
         routes::payouts::payouts_cancel,
         routes::payouts::payouts_fulfill,
+        routes::payouts::payouts_approve,
+        routes::payouts::payouts_verify,
+        routes::payouts::payouts_reprocess,
+        routes::payouts::payouts_recall,
+        routes::payouts::payouts_series_cancel,
         routes::payouts::payouts_list,
         routes::payouts::payouts_filter,
+        routes::payouts::payouts_connector_usage,
+        routes::payouts::payouts_export,
+        routes::payouts::payouts_export_job_retrieve,
+        routes::payouts::payouts_export_download,
         routes::payouts::payouts_confirm,
 
         // Routes for api keys
@@ -179,6 +207,7 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
+        routes::webhook_events::retrieve_webhook_delivery_retry_status,
         routes::webhook_events::retry_webhook_delivery_attempt,
 
         // Routes for poll apis
@@ -270,6 +299,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::BusinessPaymentLinkConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
+        api_models::admin::PaymentLinkCustomField,
+        api_models::admin::PaymentLinkCustomFieldType,
+        api_models::admin::PaymentLinkAdjustableAmount,
+        api_models::admin::PaymentLinkLogoDimensions,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
         api_models::gsm::GsmCreateRequest,
@@ -460,30 +493,79 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GiftCardDetails,
         api_models::payments::Address,
         api_models::payouts::CardPayout,
+        api_models::payouts::NetworkTokenPayout,
         api_models::payouts::Wallet,
         api_models::payouts::Paypal,
         api_models::payouts::Venmo,
+        api_models::payouts::StoreCredit,
         api_models::payouts::AchBankTransfer,
         api_models::payouts::BacsBankTransfer,
         api_models::payouts::SepaBankTransfer,
         api_models::payouts::PixBankTransfer,
         api_models::payouts::PayoutRequest,
         api_models::payouts::PayoutAttemptResponse,
+        api_models::payouts::PayoutConnectorDetails,
         api_models::payouts::PayoutActionRequest,
+        api_models::payouts::PayoutApproveRequest,
+        api_models::payouts::PayoutVerificationRequest,
+        api_models::payouts::PayoutVerificationResult,
+        api_models::payouts::PayoutReprocessRequest,
+        api_models::payouts::PayoutRecallRequest,
+        api_models::payouts::PayoutSeriesCancelResponse,
         api_models::payouts::PayoutCreateRequest,
+        api_models::payouts::PayoutRecipient,
+        api_models::payouts::PayoutOriginator,
         api_models::payouts::PayoutCreateResponse,
         api_models::payouts::PayoutListConstraints,
         api_models::payouts::PayoutListFilterConstraints,
         api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutReportingAmount,
+        api_models::payouts::PayoutConnectorUsage,
+        api_models::payouts::PayoutConnectorUsageResponse,
+        api_models::payouts::PayoutExportJobResponse,
+        api_models::payouts::PayoutExportJobStatus,
         api_models::payouts::PayoutRetrieveBody,
         api_models::payouts::PayoutRetrieveRequest,
+        api_models::payouts::PayoutBulkRetrieveRequest,
+        api_models::payouts::PayoutRetrieveByConnectorReferenceRequest,
+        api_models::payouts::PayoutTemplateCreateRequest,
+        api_models::payouts::PayoutTemplateResponse,
+        api_models::payouts::PayoutTemplateListResponse,
+        api_models::payouts::PayoutDestinationVerificationRequest,
+        api_models::payouts::PayoutDestinationVerificationConfirmRequest,
+        api_models::payouts::PayoutDestinationVerificationResponse,
+        api_models::payouts::PayoutHoldCreateRequest,
+        api_models::payouts::PayoutHoldCaptureRequest,
+        api_models::payouts::PayoutHoldResponse,
+        api_models::payouts::PayoutHoldBalanceResponse,
+        api_models::payouts::PayoutHoldBalance,
+        api_models::payouts::PayoutBulkRetrieveResponse,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchCreateResponse,
+        api_models::payouts::PayoutBatchItemResponse,
+        api_models::payouts::PayoutBatchItemError,
+        api_models::payouts::PayoutBankDetails,
+        api_models::payouts::PayoutFeeBreakdown,
+        api_models::payouts::PayoutAdminRetrieveResponse,
+        api_models::payouts::PayoutCurrencyConversionAuditLogResponse,
+        api_models::payouts::CurrencyConversionAuditLog,
         api_models::payouts::PayoutMethodData,
+        api_models::payouts::PayoutMethodValidationRequest,
+        api_models::payouts::PayoutMethodValidationResponse,
+        api_models::payouts::PayoutMethodFieldValidation,
         api_models::payouts::PayoutLinkResponse,
         api_models::payouts::Bank,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
+        api_models::enums::PayoutSettlementNetwork,
+        api_models::enums::PayoutChargeBearer,
         api_models::enums::PayoutStatus,
+        api_models::enums::PayoutStatusReasonCode,
+        api_models::enums::PayoutDestinationVerificationStatus,
+        api_models::enums::PayoutDestinationVerificationMethod,
+        api_models::enums::PayoutHoldStatus,
+        api_models::enums::PayoutConversionRounding,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
@@ -506,6 +588,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
         api_models::payments::RetrievePaymentLinkRequest,
+        api_models::payments::PaymentLinkPreviewRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
         api_models::payments::PaymentLinkInitiateRequest,
@@ -547,6 +630,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::WebhookDeliveryRetryStatusResponse,
+        api_models::webhook_events::WebhookDeliveryRetryStatus,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
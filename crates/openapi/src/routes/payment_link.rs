@@ -17,3 +17,39 @@
     security(("api_key" = []), ("publishable_key" = []))
 )]
 pub async fn payment_link_retrieve() {}
+
+/// Payments Link - Retrieve by Payment ID
+///
+/// To retrieve the properties of the Payment Link associated with a payment
+#[utoipa::path(
+    get,
+    path = "/payment_link/payment/{payment_id}",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for the payment")
+    ),
+    responses(
+        (status = 200, description = "Gets details regarding payment link", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Payment Link by Payment ID",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_retrieve_by_payment_id() {}
+
+/// Payment Link - Preview
+///
+/// To preview the payment link page for sample payment details and a payment link configuration, without creating a payment
+#[utoipa::path(
+    post,
+    path = "/payment_link/preview",
+    request_body=PaymentLinkPreviewRequest,
+    responses(
+        (status = 200, description = "The payment link page was rendered with the sample details"),
+        (status = 400, description = "Invalid payment link configuration")
+    ),
+    tag = "Payment Link",
+    operation_id = "Preview a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_preview() {}
@@ -2,6 +2,9 @@
 #[utoipa::path(
     post,
     path = "/payouts/create",
+    params(
+        ("X-Ignore-Unknown-Fields" = Option<bool>, Header, description = "When set to true, unknown fields in the request body are ignored instead of being rejected")
+    ),
     request_body=PayoutCreateRequest,
     responses(
         (status = 200, description = "Payout created", body = PayoutCreateResponse),
@@ -18,7 +21,8 @@ pub async fn payouts_create() {}
     get,
     path = "/payouts/{payout_id}",
     params(
-        ("payout_id" = String, Path, description = "The identifier for payout]")
+        ("payout_id" = String, Path, description = "The identifier for payout]"),
+        ("timezone" = Option<String>, Query, description = "IANA time zone name in which the timestamps in the response are to be returned, defaults to UTC")
     ),
     responses(
         (status = 200, description = "Payout retrieved", body = PayoutCreateResponse),
@@ -30,12 +34,303 @@ pub async fn payouts_create() {}
 )]
 pub async fn payouts_retrieve() {}
 
+/// Payouts - Bulk Retrieve
+#[utoipa::path(
+    post,
+    path = "/payouts/bulk_retrieve",
+    request_body=PayoutBulkRetrieveRequest,
+    responses(
+        (status = 200, description = "Payouts retrieved", body = PayoutBulkRetrieveResponse),
+        (status = 400, description = "Invalid list of payout ids")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve Payouts in bulk",
+    security(("api_key" = []))
+)]
+pub async fn payouts_bulk_retrieve() {}
+
+/// Payouts - Batch Create
+#[utoipa::path(
+    post,
+    path = "/payouts/batch",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payouts of the batch processed, with the result of each item", body = PayoutBatchCreateResponse),
+        (status = 400, description = "Invalid batch")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a batch of Payouts",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_create() {}
+
+/// Payouts - Retrieve by Connector Reference
+#[utoipa::path(
+    get,
+    path = "/payouts/connector_reference",
+    params(
+        ("connector_payout_id" = String, Query, description = "The identifier assigned to the payout by the connector"),
+        ("connector" = Option<PayoutConnectors>, Query, description = "The connector which processed the payout"),
+        ("timezone" = Option<String>, Query, description = "IANA time zone name in which the timestamps in the response are to be returned"),
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutCreateResponse),
+        (status = 400, description = "Connector reference matches more than one payout"),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout by Connector Reference",
+    security(("api_key" = []))
+)]
+pub async fn payouts_retrieve_by_connector_reference() {}
+
+/// Payout Templates - Create
+#[utoipa::path(
+    post,
+    path = "/payouts/templates",
+    request_body=PayoutTemplateCreateRequest,
+    responses(
+        (status = 200, description = "Payout template created", body = PayoutTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Template",
+    security(("api_key" = []))
+)]
+pub async fn payout_templates_create() {}
+
+/// Payout Templates - List
+#[utoipa::path(
+    get,
+    path = "/payouts/templates",
+    responses(
+        (status = 200, description = "Payout templates retrieved", body = PayoutTemplateListResponse)
+    ),
+    tag = "Payouts",
+    operation_id = "List Payout Templates",
+    security(("api_key" = []))
+)]
+pub async fn payout_templates_list() {}
+
+/// Payout Templates - Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for the payout template")
+    ),
+    responses(
+        (status = 200, description = "Payout template retrieved", body = PayoutTemplateResponse),
+        (status = 404, description = "Payout template does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Template",
+    security(("api_key" = []))
+)]
+pub async fn payout_templates_retrieve() {}
+
+/// Payout Templates - Delete
+#[utoipa::path(
+    delete,
+    path = "/payouts/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for the payout template")
+    ),
+    responses(
+        (status = 200, description = "Payout template deleted", body = PayoutTemplateResponse),
+        (status = 404, description = "Payout template does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Delete a Payout Template",
+    security(("api_key" = []))
+)]
+pub async fn payout_templates_delete() {}
+
+/// Payout Destination Verifications - Create
+///
+/// Initiates the verification of a payout destination of a customer by sending micro-deposits to it, or verifies it instantly through the account check of a connector
+#[utoipa::path(
+    post,
+    path = "/payouts/destination_verifications",
+    request_body=PayoutDestinationVerificationRequest,
+    responses(
+        (status = 200, description = "Payout destination verification initiated", body = PayoutDestinationVerificationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Destination Verification",
+    security(("api_key" = []))
+)]
+pub async fn payout_destination_verifications_create() {}
+
+/// Payout Destination Verifications - Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/destination_verifications/{verification_id}",
+    params(
+        ("verification_id" = String, Path, description = "The identifier for the payout destination verification")
+    ),
+    responses(
+        (status = 200, description = "Payout destination verification retrieved", body = PayoutDestinationVerificationResponse),
+        (status = 404, description = "Payout destination verification does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Destination Verification",
+    security(("api_key" = []))
+)]
+pub async fn payout_destination_verifications_retrieve() {}
+
+/// Payout Destination Verifications - Confirm
+///
+/// Confirms the amounts of the micro-deposits received by the customer. The destination is verified when the amounts match, else an attempt is used up
+#[utoipa::path(
+    post,
+    path = "/payouts/destination_verifications/{verification_id}/confirm",
+    params(
+        ("verification_id" = String, Path, description = "The identifier for the payout destination verification")
+    ),
+    request_body=PayoutDestinationVerificationConfirmRequest,
+    responses(
+        (status = 200, description = "Micro-deposit amounts confirmed", body = PayoutDestinationVerificationResponse),
+        (status = 400, description = "Payout destination verification is not pending")
+    ),
+    tag = "Payouts",
+    operation_id = "Confirm a Payout Destination Verification",
+    security(("api_key" = []))
+)]
+pub async fn payout_destination_verifications_confirm() {}
+
+/// Payout Holds - Create
+///
+/// Holds an amount of the merchant's funds for a future payout, without disbursing it
+#[utoipa::path(
+    post,
+    path = "/payouts/holds",
+    request_body=PayoutHoldCreateRequest,
+    responses(
+        (status = 200, description = "Amount held", body = PayoutHoldResponse),
+        (status = 400, description = "Insufficient funds for the hold")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Hold",
+    security(("api_key" = []))
+)]
+pub async fn payout_holds_create() {}
+
+/// Payout Holds - Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/holds/{hold_id}",
+    params(
+        ("hold_id" = String, Path, description = "The identifier for the payout hold")
+    ),
+    responses(
+        (status = 200, description = "Payout hold retrieved", body = PayoutHoldResponse),
+        (status = 404, description = "Payout hold does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Hold",
+    security(("api_key" = []))
+)]
+pub async fn payout_holds_retrieve() {}
+
+/// Payout Holds - Capture
+///
+/// Converts a hold into a payout of at most the held amount, in the currency of the hold
+#[utoipa::path(
+    post,
+    path = "/payouts/holds/{hold_id}/capture",
+    params(
+        ("hold_id" = String, Path, description = "The identifier for the payout hold")
+    ),
+    request_body=PayoutHoldCaptureRequest,
+    responses(
+        (status = 200, description = "Payout created from the hold", body = PayoutHoldResponse),
+        (status = 400, description = "Payout hold is not held")
+    ),
+    tag = "Payouts",
+    operation_id = "Capture a Payout Hold",
+    security(("api_key" = []))
+)]
+pub async fn payout_holds_capture() {}
+
+/// Payout Holds - Release
+///
+/// Cancels a hold, making the held amount available again
+#[utoipa::path(
+    post,
+    path = "/payouts/holds/{hold_id}/release",
+    params(
+        ("hold_id" = String, Path, description = "The identifier for the payout hold")
+    ),
+    responses(
+        (status = 200, description = "Payout hold released", body = PayoutHoldResponse),
+        (status = 400, description = "Payout hold is not held")
+    ),
+    tag = "Payouts",
+    operation_id = "Release a Payout Hold",
+    security(("api_key" = []))
+)]
+pub async fn payout_holds_release() {}
+
+/// Payout Holds - Balance
+///
+/// Retrieves the amounts held for payouts and the funds still available for holds, per currency
+#[utoipa::path(
+    get,
+    path = "/payouts/holds/balance",
+    responses(
+        (status = 200, description = "Payout hold balances retrieved", body = PayoutHoldBalanceResponse)
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve the Payout Hold Balance",
+    security(("api_key" = []))
+)]
+pub async fn payout_holds_balance() {}
+
+/// Payouts - Admin Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/admin/{merchant_id}/{payout_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutAdminRetrieveResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout as admin",
+    security(("admin_api_key" = []))
+)]
+pub async fn payouts_admin_retrieve() {}
+
+/// Payouts - Admin Currency Conversions
+#[utoipa::path(
+    get,
+    path = "/payouts/admin/{merchant_id}/{payout_id}/currency_conversions",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Currency conversions of the payout retrieved", body = PayoutCurrencyConversionAuditLogResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List the currency conversions of a Payout as admin",
+    security(("admin_api_key" = []))
+)]
+pub async fn payouts_admin_currency_conversions() {}
+
 /// Payouts - Update
 #[utoipa::path(
     post,
     path = "/payouts/{payout_id}",
     params(
-        ("payout_id" = String, Path, description = "The identifier for payout]")
+        ("payout_id" = String, Path, description = "The identifier for payout]"),
+        ("X-Ignore-Unknown-Fields" = Option<bool>, Header, description = "When set to true, unknown fields in the request body are ignored instead of being rejected")
     ),
     request_body=PayoutCreateRequest,
     responses(
@@ -84,6 +379,103 @@ pub async fn payouts_cancel() {}
 )]
 pub async fn payouts_fulfill() {}
 
+/// Payouts - Approve
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/approve",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutApproveRequest,
+    responses(
+        (status = 200, description = "Payout approved", body = PayoutCreateResponse),
+        (status = 400, description = "Payout is not pending approval, or is being approved by its creator")
+    ),
+    tag = "Payouts",
+    operation_id = "Approve a Payout",
+    security(("api_key" = []))
+)]
+pub async fn payouts_approve() {}
+
+/// Payouts - Verify
+///
+/// Posts the result of the document verification of the recipient of a payout held in `pending_verification`. A verified payout resumes its disbursement, a rejected payout fails
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/verify",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutVerificationRequest,
+    responses(
+        (status = 200, description = "Payout verification recorded", body = PayoutCreateResponse),
+        (status = 400, description = "Payout is not pending verification")
+    ),
+    tag = "Payouts",
+    operation_id = "Verify a Payout",
+    security(("api_key" = []))
+)]
+pub async fn payouts_verify() {}
+
+/// Payouts - Reprocess
+///
+/// Reprocesses a failed payout with corrected payout method data or billing address. The payout is retried in a new attempt through the connector of the failed attempt, and the failed attempt is left as is
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/reprocess",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutReprocessRequest,
+    responses(
+        (status = 200, description = "Payout reprocessed", body = PayoutCreateResponse),
+        (status = 400, description = "Payout has not failed")
+    ),
+    tag = "Payouts",
+    operation_id = "Reprocess a Payout",
+    security(("api_key" = []))
+)]
+pub async fn payouts_reprocess() {}
+
+/// Payouts - Recall
+///
+/// Records the recall of a successful payout by the bank of the recipient, after the funds were credited. A webhook is sent to the merchant for the recalled payout
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/recall",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutRecallRequest,
+    responses(
+        (status = 200, description = "Payout recalled", body = PayoutCreateResponse),
+        (status = 400, description = "Payout has not succeeded")
+    ),
+    tag = "Payouts",
+    operation_id = "Recall a Payout",
+    security(("api_key" = []))
+)]
+pub async fn payouts_recall() {}
+
+/// Payouts - Cancel Series
+///
+/// Cancels the runs of a recurring payout series which are still scheduled. Runs which were already disbursed are left as they are, and cancelling a series with no scheduled runs left cancels nothing
+#[utoipa::path(
+    post,
+    path = "/payouts/series/{series_id}/cancel",
+    params(
+        ("series_id" = String, Path, description = "The identifier for the recurring payout series")
+    ),
+    responses(
+        (status = 200, description = "Scheduled runs of the series cancelled", body = PayoutSeriesCancelResponse),
+        (status = 404, description = "Payout series not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout Series",
+    security(("api_key" = []))
+)]
+pub async fn payouts_series_cancel() {}
+
 /// Payouts - List
 #[utoipa::path(
     get,
@@ -112,12 +504,80 @@ pub async fn payouts_list() {}
 )]
 pub async fn payouts_filter() {}
 
+/// Payouts - Connector usage
+///
+/// Lists the connectors used for the payouts created in the time range, with the number of payout attempts made through each of them and the time of the latest one
+#[utoipa::path(
+    post,
+    path = "/payouts/connectors",
+    request_body = TimeRange,
+    responses(
+        (status = 200, description = "Connectors used for payouts", body = PayoutConnectorUsageResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "List connectors used for payouts",
+    security(("api_key" = []))
+)]
+pub async fn payouts_connector_usage() {}
+
+/// Payouts - Export
+///
+/// Exports the payouts matching the constraints as CSV. Large exports are generated asynchronously, in which case an export job is returned instead, which can be polled until the export can be downloaded
+#[utoipa::path(
+    post,
+    path = "/payouts/export",
+    request_body=PayoutListConstraints,
+    responses(
+        (status = 200, description = "Payouts exported as CSV, or export job created", body = PayoutExportJobResponse),
+        (status = 404, description = "Payout not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Export payouts",
+    security(("api_key" = []))
+)]
+pub async fn payouts_export() {}
+
+/// Payouts - Retrieve export job
+#[utoipa::path(
+    get,
+    path = "/payouts/export/{job_id}",
+    params(
+        ("job_id" = String, Path, description = "The identifier for the payouts export job")
+    ),
+    responses(
+        (status = 200, description = "Payouts export job retrieved", body = PayoutExportJobResponse),
+        (status = 404, description = "Payouts export job not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payouts Export Job",
+    security(("api_key" = []))
+)]
+pub async fn payouts_export_job_retrieve() {}
+
+/// Payouts - Download export
+#[utoipa::path(
+    get,
+    path = "/payouts/export/{job_id}/download",
+    params(
+        ("job_id" = String, Path, description = "The identifier for the payouts export job")
+    ),
+    responses(
+        (status = 200, description = "Payouts export downloaded as CSV"),
+        (status = 404, description = "Payouts export job not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Download a Payouts Export",
+    security(("api_key" = []))
+)]
+pub async fn payouts_export_download() {}
+
 /// Payouts - Confirm
 #[utoipa::path(
     post,
     path = "/payouts/{payout_id}/confirm",
     params(
-        ("payout_id" = String, Path, description = "The identifier for payout]")
+        ("payout_id" = String, Path, description = "The identifier for payout]"),
+        ("X-Ignore-Unknown-Fields" = Option<bool>, Header, description = "When set to true, unknown fields in the request body are ignored instead of being rejected")
     ),
     request_body=PayoutCreateRequest,
     responses(
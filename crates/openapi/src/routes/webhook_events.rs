@@ -69,6 +69,25 @@ pub fn list_initial_webhook_delivery_attempts() {}
 )]
 pub fn list_webhook_delivery_attempts() {}
 
+/// Events - Delivery Retry Status
+///
+/// Retrieve the state of the automatic delivery retries for the specified Event.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id_or_profile_id}/{event_id}/retry_status",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+        ("event_id" = String, Path, description = "The unique identifier for the initial delivery attempt of the Event"),
+    ),
+    responses(
+        (status = 200, description = "Delivery retry status retrieved successfully", body = WebhookDeliveryRetryStatusResponse),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve the delivery retry status for an Event",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_delivery_retry_status() {}
+
 /// Events - Manual Retry
 ///
 /// Manually retry the delivery of the specified Event.
@@ -209,6 +209,34 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::DeleteFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(self.add_prefix(key))
+            .await
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
+    /// Increments the counter at the key, creating it with the expiry if it doesn't exist.
+    ///
+    /// The counter is created along with its expiry before it is incremented, so that it never
+    /// lives on without an expiry, even if the increment fails.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_with_expiry(
+        &self,
+        key: &str,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.set_key_if_not_exists_with_expiry(key, 0_i64, Some(seconds))
+            .await?;
+        let count = self.increment_key(key).await?;
+        // The counter expired right before the increment, which created it again without expiry
+        if count == 1 {
+            self.set_expiry(key, seconds).await?;
+        }
+        Ok(count)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key_with_expiry<V>(
         &self,
@@ -6,4 +6,34 @@ fn main() {
 
     #[cfg(feature = "vergen")]
     router_env::vergen::generate_cargo_instructions();
+
+    set_expected_schema_version();
+}
+
+/// Exposes the version of the latest migration in the repository as
+/// `HYPERSWITCH_EXPECTED_SCHEMA_VERSION`, which the deep health check compares against the
+/// migrations applied to the database.
+///
+/// The version is derived from the migration directory name the same way diesel does, for
+/// example `2024-06-24-094512_add_payout_approval` has the version `20240624094512`.
+fn set_expected_schema_version() {
+    let migrations_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../migrations");
+    println!("cargo:rerun-if-changed={}", migrations_dir.display());
+
+    let Ok(entries) = std::fs::read_dir(&migrations_dir) else {
+        return;
+    };
+    let latest_version = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let (version, _) = name.split_once('_')?;
+            Some(version.replace('-', ""))
+        })
+        .max();
+
+    if let Some(version) = latest_version {
+        println!("cargo:rustc-env=HYPERSWITCH_EXPECTED_SCHEMA_VERSION={version}");
+    }
 }
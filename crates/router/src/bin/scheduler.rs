@@ -313,6 +313,66 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::ScheduledPayoutWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            workflows::scheduled_payout_workflow::ScheduledPayoutWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run scheduled payout workflow when payouts feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutVerificationExpiryWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            workflows::payout_verification_expiry_workflow::PayoutVerificationExpiryWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run payout verification expiry workflow when payouts feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutsExportWorkflow => {
+                    #[cfg(all(feature = "payouts", feature = "olap"))]
+                    {
+                        Ok(Box::new(
+                            workflows::payouts_export_workflow::PayoutsExportWorkflow,
+                        ))
+                    }
+                    #[cfg(not(all(feature = "payouts", feature = "olap")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run payouts export workflow when payouts or olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutHoldExpiryWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            workflows::payout_hold_expiry_workflow::PayoutHoldExpiryWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run payout hold expiry workflow when payouts feature is disabled",
+                        )
+                    }
+                }
             }
         };
 
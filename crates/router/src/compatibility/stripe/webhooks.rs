@@ -141,6 +141,7 @@ pub enum StripePayoutStatus {
     PayoutInitiated,
     PayoutExpired,
     PayoutReversed,
+    PayoutRecalled,
 }
 
 #[cfg(feature = "payouts")]
@@ -153,13 +154,17 @@ impl From<common_enums::PayoutStatus> for StripePayoutStatus {
             common_enums::PayoutStatus::Initiated => Self::PayoutInitiated,
             common_enums::PayoutStatus::Expired => Self::PayoutExpired,
             common_enums::PayoutStatus::Reversed => Self::PayoutReversed,
+            common_enums::PayoutStatus::Recalled => Self::PayoutRecalled,
             common_enums::PayoutStatus::Pending
             | common_enums::PayoutStatus::Ineligible
             | common_enums::PayoutStatus::RequiresCreation
             | common_enums::PayoutStatus::RequiresFulfillment
             | common_enums::PayoutStatus::RequiresPayoutMethodData
             | common_enums::PayoutStatus::RequiresVendorAccountCreation
-            | common_enums::PayoutStatus::RequiresConfirmation => Self::PayoutProcessing,
+            | common_enums::PayoutStatus::RequiresConfirmation
+            | common_enums::PayoutStatus::Scheduled
+            | common_enums::PayoutStatus::PendingApproval
+            | common_enums::PayoutStatus::PendingVerification => Self::PayoutProcessing,
         }
     }
 }
@@ -177,7 +182,7 @@ impl From<payout_models::PayoutCreateResponse> for StripePayoutResponse {
             email: res.email.map(|inner| inner.into()),
             phone: res.phone.map(Encryptable::into_inner),
             phone_country_code: res.phone_country_code,
-            created: res.created.map(|t| t.assume_utc().unix_timestamp()),
+            created: res.created.map(|t| t.unix_timestamp()),
             metadata: res.metadata,
             entity_type: res.entity_type,
             recurring: res.recurring,
@@ -289,7 +294,8 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutCancelled => "payout.canceled",
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
-        api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::PayoutReversed
+        | api_models::enums::EventType::PayoutRecalled => "payout.reconciliation_completed",
     }
 }
 
@@ -16,6 +16,7 @@ use crate::{
 };
 
 #[instrument(skip(request, payload, state, func, api_authentication))]
+#[allow(deprecated)]
 pub async fn compatibility_api_wrap<'a, 'b, U, T, Q, F, Fut, S, E, E2>(
     flow: impl router_env::types::FlowMetric,
     state: Arc<AppState>,
@@ -151,34 +152,35 @@ where
             }
         }
 
-        Ok(api::ApplicationResponse::PaymentLinkForm(boxed_payment_link_data)) => {
-            match *boxed_payment_link_data {
-                api::PaymentLinkAction::PaymentLinkFormData(payment_link_data) => {
-                    match api::build_payment_link_html(payment_link_data) {
-                        Ok(rendered_html) => api::http_response_html_data(rendered_html),
-                        Err(_) => api::http_response_err(
-                            r#"{
+        Ok(
+            api::ApplicationResponse::PaymentLinkForm(boxed_payment_link_data)
+            | api::ApplicationResponse::PaymenkLinkForm(boxed_payment_link_data),
+        ) => match *boxed_payment_link_data {
+            api::PaymentLinkAction::PaymentLinkFormData(payment_link_data) => {
+                match api::build_payment_link_html(payment_link_data) {
+                    Ok(rendered_html) => api::http_response_html_data(rendered_html),
+                    Err(_) => api::http_response_err(
+                        r#"{
                                 "error": {
                                     "message": "Error while rendering payment link html page"
                                 }
                             }"#,
-                        ),
-                    }
+                    ),
                 }
-                api::PaymentLinkAction::PaymentLinkStatus(payment_link_data) => {
-                    match api::get_payment_link_status(payment_link_data) {
-                        Ok(rendered_html) => api::http_response_html_data(rendered_html),
-                        Err(_) => api::http_response_err(
-                            r#"{
+            }
+            api::PaymentLinkAction::PaymentLinkStatus(payment_link_data) => {
+                match api::get_payment_link_status(payment_link_data) {
+                    Ok(rendered_html) => api::http_response_html_data(rendered_html),
+                    Err(_) => api::http_response_err(
+                        r#"{
                                 "error": {
                                     "message": "Error while rendering payment link status page"
                                 }
                             }"#,
-                        ),
-                    }
+                    ),
                 }
             }
-        }
+        },
         Err(error) => api::log_and_return_error_response(error),
     };
 
@@ -12,6 +12,7 @@ impl Default for super::settings::Server {
             host: "localhost".into(),
             request_body_limit: 16 * 1024, // POST request body is limited to 16KiB
             shutdown_timeout: 30,
+            shutdown_drain_period: 0,
         }
     }
 }
@@ -70,6 +71,8 @@ impl Default for super::settings::Locker {
             //Time to live for storage entries in locker
             ttl_for_storage_in_secs: 60 * 60 * 24 * 365 * 7,
             decryption_scheme: Default::default(),
+            //Exercise a store, retrieve and delete cycle against the locker in the deep health check
+            health_check_round_trip: false,
         }
     }
 }
@@ -94,6 +97,33 @@ impl Default for super::settings::SupportedConnectors {
     }
 }
 
+impl Default for super::settings::HealthCheckSettings {
+    fn default() -> Self {
+        Self {
+            database_latency_threshold: 200,
+            redis_latency_threshold: 100,
+            locker_latency_threshold: 500,
+            analytics_latency_threshold: 1000,
+            opensearch_latency_threshold: 1000,
+            outgoing_request_latency_threshold: 2000,
+            redis_retry_count: 2,
+            redis_retry_delay: 50,
+            redis_probe_timeout: 1000,
+            file_store_probe_enabled: false,
+            file_store_latency_threshold: 1000,
+            file_store_probe_timeout: 2000,
+            secrets_manager_probe_enabled: false,
+            secrets_manager_probe_secret: None,
+            secrets_manager_latency_threshold: 500,
+            secrets_manager_probe_timeout: 2000,
+            result_cache_ttl: 1000,
+            connector_pings: HashSet::new(),
+            connector_ping_latency_threshold: 2000,
+            simulated_failures: Default::default(),
+        }
+    }
+}
+
 impl Default for super::settings::Refund {
     fn default() -> Self {
         Self {
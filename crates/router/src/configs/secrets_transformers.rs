@@ -90,10 +90,20 @@ impl SecretsHandler for settings::ForexApi {
             secret_management_client.get_secret(forex_api.api_key.clone()),
             secret_management_client.get_secret(forex_api.fallback_api_key.clone()),
         )?;
+        let crypto = match forex_api.crypto.clone() {
+            Some(crypto) => Some(settings::CryptoForexApi {
+                api_key: secret_management_client
+                    .get_secret(crypto.api_key.clone())
+                    .await?,
+                ..crypto
+            }),
+            None => None,
+        };
 
         Ok(value.transition_state(|forex_api| Self {
             api_key,
             fallback_api_key,
+            crypto,
             ..forex_api
         }))
     }
@@ -373,6 +383,7 @@ pub(crate) async fn fetch_raw_secrets(
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        health_check: conf.health_check,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
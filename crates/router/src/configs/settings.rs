@@ -105,6 +105,7 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub health_check: HealthCheckSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -231,6 +232,13 @@ pub struct GenericLinkEnvUiConfig {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct PaymentLink {
     pub sdk_url: String,
+    /// Seconds past the expiry of a payment link for which it is still reported as active, so
+    /// that payments in progress can be completed
+    #[serde(default)]
+    pub expiry_grace_period: u32,
+    /// Maximum number of order details a payment link can be rendered with, defaults to
+    /// `DEFAULT_PAYMENT_LINK_MAX_ORDER_DETAILS` when not set
+    pub max_order_details: Option<usize>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -239,7 +247,7 @@ pub struct ForexApi {
     pub local_fetch_retry_count: u64,
     pub api_key: Secret<String>,
     pub fallback_api_key: Secret<String>,
-    /// in ms
+    /// in seconds
     pub call_delay: i64,
     /// in ms
     pub local_fetch_retry_delay: u64,
@@ -247,6 +255,40 @@ pub struct ForexApi {
     pub api_timeout: u64,
     /// in ms
     pub redis_lock_timeout: u64,
+    /// Limits the forex retrieve requests of a merchant which need to fetch the rates from the
+    /// provider, disabled when unset
+    pub fetch_rate_limit: Option<ForexFetchRateLimit>,
+    /// Currencies for which the rates are loaded and cached, all currencies when unset. The rates
+    /// of the base currency (USD) are always available, conversions between the other currencies
+    /// are triangulated through it
+    pub currencies: Option<HashSet<enums::Currency>>,
+    /// Source of the rates of crypto assets, conversions involving crypto assets are not supported
+    /// when unset
+    pub crypto: Option<CryptoForexApi>,
+    /// Markup applied over the mid-market rate in the conversions of a merchant, in basis points
+    /// by merchant id. Merchants which are not configured do not apply any markup
+    pub markup_bps: HashMap<String, u16>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CryptoForexApi {
+    /// Url of the crypto rate source to which the api key is appended, the source returns the
+    /// units of each asset per USD
+    pub base_url: String,
+    pub api_key: Secret<String>,
+    /// Supported crypto assets, along with the number of digits after the decimal point in their
+    /// minor unit. The codes of the assets are case insensitive
+    pub assets: HashMap<String, u8>,
+    /// in seconds, like the forex `call_delay` and shorter than it as crypto rates are more
+    /// volatile
+    pub call_delay: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ForexFetchRateLimit {
+    pub max_requests: i64,
+    /// in seconds
+    pub window: i64,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -498,6 +540,7 @@ pub struct Locker {
     pub locker_enabled: bool,
     pub ttl_for_storage_in_secs: i64,
     pub decryption_scheme: DecryptionScheme,
+    pub health_check_round_trip: bool,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -509,6 +552,66 @@ pub enum DecryptionScheme {
     RsaOaep256,
 }
 
+/// Latency thresholds (in milliseconds) of the deep health check probes, a probe which succeeds
+/// but exceeds its threshold reports the component as degraded
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct HealthCheckSettings {
+    pub database_latency_threshold: u64,
+    pub redis_latency_threshold: u64,
+    pub locker_latency_threshold: u64,
+    pub analytics_latency_threshold: u64,
+    pub opensearch_latency_threshold: u64,
+    pub outgoing_request_latency_threshold: u64,
+    /// Number of times the redis probe is retried after a failure
+    pub redis_retry_count: u64,
+    /// in ms
+    pub redis_retry_delay: u64,
+    /// Time within which the redis probe, including its retries, must complete (in ms)
+    pub redis_probe_timeout: u64,
+    /// Whether the deep health check writes, reads and deletes a test object in the file store
+    pub file_store_probe_enabled: bool,
+    pub file_store_latency_threshold: u64,
+    /// Time within which the file store probe must complete (in ms)
+    pub file_store_probe_timeout: u64,
+    /// Whether the deep health check decrypts `secrets_manager_probe_secret` with the secrets
+    /// manager (AWS KMS or HashiCorp Vault)
+    pub secrets_manager_probe_enabled: bool,
+    /// Value decrypted by the secrets manager probe, an encrypted value for AWS KMS or the path of
+    /// a secret for HashiCorp Vault. It should not be a secret used by the application
+    pub secrets_manager_probe_secret: Option<String>,
+    /// Time above which a successful secrets manager probe reports the secrets manager as degraded
+    /// (in ms)
+    pub secrets_manager_latency_threshold: u64,
+    /// Time within which the secrets manager probe must complete (in ms)
+    pub secrets_manager_probe_timeout: u64,
+    /// Time for which a deep health check result is reused by subsequent checks, 0 disables
+    /// caching (in ms)
+    pub result_cache_ttl: u64,
+    /// Connectors whose base url is requested when the heavier probes are run, to verify that
+    /// they are reachable. An unreachable connector is reported as down without failing the check
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_pings: HashSet<enums::Connector>,
+    pub connector_ping_latency_threshold: u64,
+    pub simulated_failures: HealthCheckSimulatedFailures,
+}
+
+/// Health check probes which report a failure without probing their component, to verify alerting
+/// and failover in chaos and integration tests. Not allowed in the production environment
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct HealthCheckSimulatedFailures {
+    pub database: bool,
+    pub redis: bool,
+    pub locker: bool,
+}
+
+impl HealthCheckSimulatedFailures {
+    pub fn is_any_enabled(&self) -> bool {
+        self.database || self.redis || self.locker
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Refund {
@@ -547,6 +650,10 @@ pub struct Server {
     pub host: String,
     pub request_body_limit: usize,
     pub shutdown_timeout: u64,
+    /// Time in seconds for which the server keeps serving requests after receiving SIGTERM, while
+    /// its readiness check reports it as draining, before it stops accepting new connections. The
+    /// server is not drained when this is 0
+    pub shutdown_drain_period: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -760,6 +867,10 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
         self.generic_link.payment_method_collect.validate()?;
         self.generic_link.payout_link.validate()?;
+        self.health_check.validate()?;
+        self.forex_api.get_inner().validate()?;
+        #[cfg(feature = "payouts")]
+        self.payouts.validate()?;
         Ok(())
     }
 }
@@ -780,6 +891,117 @@ impl Settings<RawSecret> {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Payouts {
     pub payout_eligibility: bool,
+    /// Destination countries to which payouts are not allowed, can be overridden per merchant
+    #[serde(default, deserialize_with = "deserialize_hashset")]
+    pub denied_countries: HashSet<enums::CountryAlpha2>,
+    /// Purpose of payment codes allowed for payouts, by destination country
+    #[serde(default)]
+    pub purpose_codes: HashMap<enums::CountryAlpha2, PayoutPurposeCodes>,
+    /// Connectors which require the originator of payouts, by connector name
+    #[serde(default)]
+    pub originator_requirements: HashMap<String, PayoutOriginatorRequirement>,
+    /// Priorities with which connectors can send payouts, by connector name. Connectors missing
+    /// from the map only send payouts with the `regular` priority
+    #[serde(default)]
+    pub priority_support: HashMap<String, PayoutPrioritySupport>,
+    /// Currencies supported by connectors for each payout type, by connector name. Connectors
+    /// missing from the matrix are not restricted
+    #[serde(default)]
+    pub currency_support: HashMap<String, PayoutCurrencySupport>,
+    /// Time taken by payouts to arrive, by connector name and payout type
+    #[serde(default)]
+    pub arrival_estimates: HashMap<String, HashMap<enums::PayoutType, PayoutArrivalEstimate>>,
+    /// Bank holidays on which bank payouts are not processed, by payout currency
+    #[serde(default)]
+    pub bank_holidays: HashMap<enums::Currency, PayoutBankHolidays>,
+    /// Time of the day after which payouts are processed on the next business day, by payout
+    /// currency and payout type. Payouts are processed immediately when no cutoff is configured
+    #[serde(default)]
+    pub cutoff_times: HashMap<enums::Currency, HashMap<enums::PayoutType, PayoutCutoffTime>>,
+    /// Time in seconds for which a payout waits for the result of the document verification of
+    /// the recipient, before it is expired
+    pub verification_window: Option<u32>,
+    /// Maximum number of payouts exported synchronously, larger exports are generated by a job
+    pub export_sync_limit: Option<usize>,
+    /// Time in seconds for which the files generated by payouts export jobs are retained
+    pub export_file_retention: Option<u32>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PayoutPurposeCodes {
+    /// Whether payouts to the country must carry a purpose code
+    #[serde(default)]
+    pub required: bool,
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub codes: HashSet<String>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PayoutOriginatorRequirement {
+    /// Destination countries for which the originator is required, it is required for all
+    /// destination countries when empty
+    #[serde(default, deserialize_with = "deserialize_hashset")]
+    pub countries: HashSet<enums::CountryAlpha2>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PayoutPrioritySupport {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub priorities: HashSet<enums::PayoutSendPriority>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PayoutArrivalEstimate {
+    /// Days taken by the payouts to arrive after the day on which they are processed, these are
+    /// business days for bank payouts
+    pub days: u16,
+    /// Minutes taken by `instant` priority payouts to arrive, these are not estimated when unset
+    pub instant_minutes: Option<u32>,
+    /// Hour of the day (UTC) after which bank payouts are processed on the next business day
+    pub cutoff_hour: Option<u8>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+pub struct PayoutCutoffTime {
+    /// Hour of the day (UTC)
+    pub hour: u8,
+    #[serde(default)]
+    pub minute: u8,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PayoutBankHolidays {
+    /// Comma separated list of dates in the `YYYY-MM-DD` format
+    #[serde(deserialize_with = "deserialize_dates")]
+    pub dates: HashSet<time::Date>,
+}
+
+/// Currencies supported by a connector, by payout type. Payout types missing from the map are
+/// not supported by the connector
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Default)]
+pub struct PayoutCurrencySupport(pub HashMap<enums::PayoutType, HashSet<enums::Currency>>);
+
+#[cfg(feature = "payouts")]
+impl<'de> Deserialize<'de> for PayoutCurrencySupport {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        <HashMap<enums::PayoutType, String>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(payout_type, currencies)| {
+                deserialize_hashset_inner(currencies).map(|currencies| (payout_type, currencies))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+            .map_err(D::Error::custom)
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -875,6 +1097,27 @@ where
     deserialize_hashset_inner(<String>::deserialize(deserializer)?).map_err(D::Error::custom)
 }
 
+#[cfg(feature = "payouts")]
+fn deserialize_dates<'a, D>(deserializer: D) -> Result<HashSet<time::Date>, D::Error>
+where
+    D: serde::Deserializer<'a>,
+{
+    use serde::de::Error;
+    use time::format_description::well_known::Iso8601;
+
+    <String>::deserialize(deserializer)?
+        .split(',')
+        .map(|date| {
+            time::Date::parse(date.trim(), &Iso8601::DEFAULT).map_err(|error| {
+                D::Error::custom(format!(
+                    "Unable to deserialize `{}` as a date: {error}",
+                    date.trim()
+                ))
+            })
+        })
+        .collect()
+}
+
 fn deserialize_optional_hashset<'a, D, T>(deserializer: D) -> Result<Option<HashSet<T>>, D::Error>
 where
     D: serde::Deserializer<'a>,
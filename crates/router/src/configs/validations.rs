@@ -181,6 +181,202 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::HealthCheckSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        // A longer TTL could report a dependency as up long after it went down
+        when(self.result_cache_ttl > 5000, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "health check result_cache_ttl must not exceed 5000 milliseconds".into(),
+            ))
+        })?;
+
+        when(
+            self.secrets_manager_probe_enabled && self.secrets_manager_probe_secret.is_none(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "health check secrets_manager_probe_secret must be set when the secrets \
+                     manager probe is enabled"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.simulated_failures.is_any_enabled()
+                && matches!(router_env::env::which(), router_env::env::Env::Production),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "health check simulated_failures must not be enabled in the production \
+                     environment"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ForexApi {
+    /// Forex is optional, deployments without a `forex_api` section or without any api key are
+    /// not validated beyond the markups, and currency conversions are unavailable on them.
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.markup_bps
+            .iter()
+            .try_for_each(|(merchant_id, markup_bps)| {
+                when(*markup_bps > crate::consts::MAX_FOREX_MARKUP_BPS, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "forex markup_bps of {merchant_id} must be at most {}",
+                        crate::consts::MAX_FOREX_MARKUP_BPS
+                    )))
+                })
+            })?;
+
+        if self.api_key.is_default_or_empty() && self.fallback_api_key.is_default_or_empty() {
+            return Ok(());
+        }
+
+        when(self.call_delay <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "forex call_delay must be greater than 0".into(),
+            ))
+        })?;
+
+        when(!(1..=10).contains(&self.local_fetch_retry_count), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "forex local_fetch_retry_count must be between 1 and 10".into(),
+            ))
+        })?;
+
+        when(!(1..=10000).contains(&self.local_fetch_retry_delay), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "forex local_fetch_retry_delay must be between 1 and 10000 milliseconds".into(),
+            ))
+        })?;
+
+        when(self.api_timeout == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "forex api_timeout must be greater than 0".into(),
+            ))
+        })?;
+
+        when(
+            self.currencies
+                .as_ref()
+                .is_some_and(|currencies| currencies.is_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "forex currencies must not be empty when configured".into(),
+                ))
+            },
+        )?;
+
+        match &self.crypto {
+            Some(crypto) => crypto.validate(self.call_delay),
+            None => Ok(()),
+        }
+    }
+}
+
+impl super::settings::CryptoForexApi {
+    pub fn validate(&self, forex_call_delay: i64) -> Result<(), ApplicationError> {
+        use std::str::FromStr;
+
+        use common_utils::fp_utils::when;
+
+        when(self.base_url.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "crypto forex base_url must not be empty".into(),
+            ))
+        })?;
+
+        when(
+            self.call_delay <= 0 || self.call_delay >= forex_call_delay,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "crypto forex call_delay must be greater than 0 and less than the forex \
+                     call_delay"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(self.assets.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "crypto forex assets must not be empty".into(),
+            ))
+        })?;
+
+        let mut asset_codes = std::collections::HashSet::new();
+        self.assets.iter().try_for_each(|(asset, minor_units)| {
+            let asset_code = asset.to_uppercase();
+            when(
+                common_enums::Currency::from_str(&asset_code).is_ok(),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "crypto forex asset {asset} must not be a fiat currency"
+                    )))
+                },
+            )?;
+            when(!asset_codes.insert(asset_code), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "crypto forex asset {asset} is configured more than once"
+                )))
+            })?;
+            when(*minor_units > 18, || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "minor units of the crypto forex asset {asset} must be at most 18"
+                )))
+            })
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl super::settings::Payouts {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.arrival_estimates
+            .iter()
+            .flat_map(|(connector, estimates)| {
+                estimates
+                    .iter()
+                    .map(move |(payout_type, estimate)| (connector, payout_type, estimate))
+            })
+            .try_for_each(|(connector, payout_type, estimate)| {
+                when(estimate.cutoff_hour.is_some_and(|hour| hour > 23), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "cutoff hour of {connector} {payout_type} payouts must be at most 23"
+                    )))
+                })
+            })?;
+
+        self.cutoff_times
+            .iter()
+            .flat_map(|(currency, cutoff_times)| {
+                cutoff_times
+                    .iter()
+                    .map(move |(payout_type, cutoff)| (currency, payout_type, cutoff))
+            })
+            .try_for_each(|(currency, payout_type, cutoff)| {
+                when(cutoff.hour > 23 || cutoff.minute > 59, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "cutoff time of {currency} {payout_type} payouts must be a valid time of the day"
+                    )))
+                })
+            })?;
+
+        when(self.verification_window == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payouts verification window should not be 0".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::GenericLinkEnvConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
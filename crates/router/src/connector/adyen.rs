@@ -1455,15 +1455,19 @@ impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::P
             &req.connector_meta_data,
         )?;
         let payout_type = req.request.get_payout_type()?;
+        let action = match payout_type {
+            storage_enums::PayoutType::Bank | storage_enums::PayoutType::Wallet => {
+                "confirmThirdParty"
+            }
+            storage_enums::PayoutType::Card => "payout",
+            storage_enums::PayoutType::StoreCredit => Err(errors::ConnectorError::NotSupported {
+                message: "Store credit payout fulfillment is not supported".to_string(),
+                connector: "Adyen",
+            })?,
+        };
         Ok(format!(
             "{}pal/servlet/Payout/{}/{}",
-            endpoint,
-            ADYEN_API_VERSION,
-            match payout_type {
-                storage_enums::PayoutType::Bank | storage_enums::PayoutType::Wallet =>
-                    "confirmThirdParty".to_string(),
-                storage_enums::PayoutType::Card => "payout".to_string(),
-            }
+            endpoint, ADYEN_API_VERSION, action
         ))
     }
 
@@ -1494,7 +1498,9 @@ impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::P
                 storage_enums::PayoutType::Bank | storage_enums::PayoutType::Wallet => {
                     auth.review_key.unwrap_or(auth.api_key).into_masked()
                 }
-                storage_enums::PayoutType::Card => auth.api_key.into_masked(),
+                storage_enums::PayoutType::Card | storage_enums::PayoutType::StoreCredit => {
+                    auth.api_key.into_masked()
+                }
             },
         )];
         header.append(&mut api_key);
@@ -1857,6 +1863,19 @@ impl api::IncomingWebhook for Adyen {
         Ok(Box::new(response))
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let notif = get_webhook_object_from_body(request.body)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        // Adyen identifies a notification by its PSP reference, event code and success
+        Ok(Some(format!(
+            "{}_{}_{}",
+            notif.psp_reference, notif.event_code, notif.success
+        )))
+    }
+
     fn get_webhook_api_response(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
@@ -4656,6 +4656,14 @@ impl<F> TryFrom<&AdyenRouterData<&types::PayoutsRouterData<F>>> for AdyenPayoutC
                 message: "Card payout creation is not supported".to_string(),
                 connector: "Adyen",
             })?,
+            PayoutMethodData::StoreCredit(_) => Err(errors::ConnectorError::NotSupported {
+                message: "Store credit payout creation is not supported".to_string(),
+                connector: "Adyen",
+            })?,
+            PayoutMethodData::NetworkToken(_) => Err(errors::ConnectorError::NotSupported {
+                message: "Network token payout creation is not supported".to_string(),
+                connector: "Adyen",
+            })?,
             PayoutMethodData::Bank(bd) => {
                 let bank_details = match bd {
                     payouts::BankPayout::Sepa(b) => PayoutBankDetails {
@@ -4798,6 +4806,10 @@ impl<F> TryFrom<&AdyenRouterData<&types::PayoutsRouterData<F>>> for AdyenPayoutF
                     entity_type: Some(item.router_data.request.entity_type),
                 })))
             }
+            storage_enums::PayoutType::StoreCredit => Err(errors::ConnectorError::NotSupported {
+                message: "Store credit payout fulfillment is not supported".to_string(),
+                connector: "Adyen",
+            })?,
         }
     }
 }
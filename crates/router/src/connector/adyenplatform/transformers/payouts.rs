@@ -39,8 +39,23 @@ pub struct AdyenTransferRequest {
     counterparty: AdyenPayoutMethodDetails,
     priority: AdyenPayoutPriority,
     reference: String,
-    reference_for_beneficiary: String,
+    reference_for_beneficiary: Option<String>,
     description: Option<String>,
+    execution_date: Option<AdyenExecutionDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    charge_bearer: Option<AdyenChargeBearer>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AdyenChargeBearer {
+    PaidByCounterparty,
+    Shared,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenExecutionDate {
+    date: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -122,7 +137,7 @@ pub struct AdyenTransferResponse {
     category_data: AdyenCategoryData,
     direction: AdyenTransactionDirection,
     reference: String,
-    reference_for_beneficiary: String,
+    reference_for_beneficiary: Option<String>,
     status: AdyenTransferStatus,
     #[serde(rename = "type")]
     transaction_type: AdyenTransactionType,
@@ -177,7 +192,10 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenTransferRequest {
     fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
         let request = item.request.to_owned();
         match item.get_payout_method_data()? {
-            payouts::PayoutMethodData::Card(_) | payouts::PayoutMethodData::Wallet(_) => {
+            payouts::PayoutMethodData::Card(_)
+            | payouts::PayoutMethodData::Wallet(_)
+            | payouts::PayoutMethodData::StoreCredit(_)
+            | payouts::PayoutMethodData::NetworkToken(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Adyenplatform"),
                 ))?
@@ -226,12 +244,15 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenTransferRequest {
                     .ok_or(errors::ConnectorError::InvalidConnectorConfig {
                         config: "metadata.source_balance_account",
                     })?;
-                let priority =
-                    request
-                        .priority
-                        .ok_or(errors::ConnectorError::MissingRequiredField {
-                            field_name: "priority",
-                        })?;
+                // Adyen selects the network through the priority of the transfer, so the
+                // settlement network takes precedence over the priority when it is passed
+                let priority = request
+                    .settlement_network
+                    .map(enums::PayoutSettlementNetwork::get_priority)
+                    .or(request.priority)
+                    .ok_or(errors::ConnectorError::MissingRequiredField {
+                        field_name: "priority",
+                    })?;
                 let payout_type = request.get_payout_type()?;
                 Ok(Self {
                     amount: adyen::Amount {
@@ -243,8 +264,20 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenTransferRequest {
                     counterparty,
                     priority: AdyenPayoutPriority::from(priority),
                     reference: request.payout_id.clone(),
-                    reference_for_beneficiary: request.payout_id,
+                    // Payouts without a descriptor, including those created with an empty one,
+                    // are sent without a reference for the beneficiary
+                    reference_for_beneficiary: request.statement_descriptor,
                     description: item.description.clone(),
+                    execution_date: request.value_date.map(|date| AdyenExecutionDate {
+                        date: date.to_string(),
+                    }),
+                    charge_bearer: match request.charge_bearer {
+                        enums::PayoutChargeBearer::Sender => None,
+                        enums::PayoutChargeBearer::Recipient => {
+                            Some(AdyenChargeBearer::PaidByCounterparty)
+                        }
+                        enums::PayoutChargeBearer::Shared => Some(AdyenChargeBearer::Shared),
+                    },
                 })
             }
         }
@@ -315,7 +348,9 @@ impl TryFrom<enums::PayoutType> for AdyenPayoutMethod {
     fn try_from(payout_type: enums::PayoutType) -> Result<Self, Self::Error> {
         match payout_type {
             enums::PayoutType::Bank => Ok(Self::Bank),
-            enums::PayoutType::Card | enums::PayoutType::Wallet => {
+            enums::PayoutType::Card
+            | enums::PayoutType::Wallet
+            | enums::PayoutType::StoreCredit => {
                 Err(report!(errors::ConnectorError::NotSupported {
                     message: "Card or wallet payouts".to_string(),
                     connector: "Adyenplatform",
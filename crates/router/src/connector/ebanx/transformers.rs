@@ -141,12 +141,14 @@ impl TryFrom<&EbanxRouterData<&types::PayoutsRouterData<api::PoCreate>>>
                     payee,
                 })
             }
-            PayoutMethodData::Card(_) | PayoutMethodData::Bank(_) | PayoutMethodData::Wallet(_) => {
-                Err(errors::ConnectorError::NotSupported {
-                    message: "Payment Method Not Supported".to_string(),
-                    connector: "Ebanx",
-                })?
-            }
+            PayoutMethodData::Card(_)
+            | PayoutMethodData::Bank(_)
+            | PayoutMethodData::Wallet(_)
+            | PayoutMethodData::StoreCredit(_)
+            | PayoutMethodData::NetworkToken(_) => Err(errors::ConnectorError::NotSupported {
+                message: "Payment Method Not Supported".to_string(),
+                connector: "Ebanx",
+            })?,
         }
     }
 }
@@ -249,7 +251,9 @@ impl<F> TryFrom<&EbanxRouterData<&types::PayoutsRouterData<F>>> for EbanxPayoutF
                     .to_owned()
                     .ok_or(errors::ConnectorError::MissingRequiredField { field_name: "uid" })?,
             }),
-            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+            storage_enums::PayoutType::Card
+            | storage_enums::PayoutType::Wallet
+            | storage_enums::PayoutType::StoreCredit => {
                 Err(errors::ConnectorError::NotSupported {
                     message: "Payout Method Not Supported".to_string(),
                     connector: "Ebanx",
@@ -340,7 +344,9 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for EbanxPayoutCancelRequest {
                     .to_owned()
                     .ok_or(errors::ConnectorError::MissingRequiredField { field_name: "uid" })?,
             }),
-            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+            storage_enums::PayoutType::Card
+            | storage_enums::PayoutType::Wallet
+            | storage_enums::PayoutType::StoreCredit => {
                 Err(errors::ConnectorError::NotSupported {
                     message: "Payout Method Not Supported".to_string(),
                     connector: "Ebanx",
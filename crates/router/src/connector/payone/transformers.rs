@@ -169,7 +169,10 @@ impl TryFrom<PayoneRouterData<&types::PayoutsRouterData<api::PoFulfill>>>
                             card_data.get_card_issuer()?,
                         )?,
                     },
-                    PayoutMethodData::Bank(_) | PayoutMethodData::Wallet(_) => {
+                    PayoutMethodData::Bank(_)
+                    | PayoutMethodData::Wallet(_)
+                    | PayoutMethodData::StoreCredit(_)
+                    | PayoutMethodData::NetworkToken(_) => {
                         Err(errors::ConnectorError::NotImplemented(
                             get_unimplemented_payment_method_error_message("Payone"),
                         ))?
@@ -180,7 +183,9 @@ impl TryFrom<PayoneRouterData<&types::PayoutsRouterData<api::PoFulfill>>>
                     card_payout_method_specific_input,
                 })
             }
-            storage_enums::PayoutType::Wallet | storage_enums::PayoutType::Bank => {
+            storage_enums::PayoutType::Wallet
+            | storage_enums::PayoutType::Bank
+            | storage_enums::PayoutType::StoreCredit => {
                 Err(errors::ConnectorError::NotImplemented(
                     get_unimplemented_payment_method_error_message("Payone"),
                 ))?
@@ -437,6 +437,20 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for StripeConnectRecipientAccountC
                 }
                 .into())
             }
+            api_models::payouts::PayoutMethodData::StoreCredit(_) => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: "Payouts via store credit are not supported".to_string(),
+                    connector: "stripe",
+                }
+                .into())
+            }
+            api_models::payouts::PayoutMethodData::NetworkToken(_) => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: "Payouts via network tokens are not supported".to_string(),
+                    connector: "stripe",
+                }
+                .into())
+            }
         }
     }
 }
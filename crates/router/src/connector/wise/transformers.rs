@@ -208,6 +208,8 @@ pub struct WisePayoutCreateRequest {
     quote_uuid: String,
     customer_transaction_id: String,
     details: WiseTransferDetails,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    originator: Option<WiseOriginator>,
 }
 
 #[cfg(feature = "payouts")]
@@ -217,6 +219,25 @@ pub struct WiseTransferDetails {
     transfer_purpose: Option<String>,
     source_of_funds: Option<String>,
     transfer_purpose_sub_transfer_purpose: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WiseOriginator {
+    legal_entity_type: LegalType,
+    name: WiseOriginatorName,
+    business_registration_code: Option<Secret<String>>,
+    address: Option<WiseAddressDetails>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WiseOriginatorName {
+    full_name: Option<Secret<String>>,
 }
 
 #[allow(dead_code)]
@@ -367,7 +388,9 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for WiseRecipientCreateRequest {
         }?;
         let payout_type = request.get_payout_type()?;
         match payout_type {
-            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+            storage_enums::PayoutType::Card
+            | storage_enums::PayoutType::Wallet
+            | storage_enums::PayoutType::StoreCredit => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Wise"),
                 ))?
@@ -431,7 +454,9 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for WisePayoutQuoteRequest {
                 target_currency: request.destination_currency.to_string(),
                 pay_out: WisePayOutOption::default(),
             }),
-            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+            storage_enums::PayoutType::Card
+            | storage_enums::PayoutType::Wallet
+            | storage_enums::PayoutType::StoreCredit => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Wise"),
                 ))?
@@ -475,23 +500,45 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for WisePayoutCreateRequest {
                 let connector_customer_id = item.get_connector_customer_id()?;
                 let quote_uuid = item.get_quote_id()?;
                 let wise_transfer_details = WiseTransferDetails {
-                    transfer_purpose: None,
+                    transfer_purpose: request.purpose_code.to_owned(),
                     source_of_funds: None,
                     transfer_purpose_sub_transfer_purpose: None,
+                    reference: request.statement_descriptor.to_owned(),
                 };
                 let target_account: i64 = connector_customer_id.trim().parse().map_err(|_| {
                     errors::ConnectorError::MissingRequiredField {
                         field_name: "profile",
                     }
                 })?;
+                let originator = request.originator.map(|originator| WiseOriginator {
+                    legal_entity_type: originator
+                        .entity_type
+                        .map(LegalType::from)
+                        .unwrap_or(LegalType::Business),
+                    name: WiseOriginatorName {
+                        full_name: originator.name,
+                    },
+                    business_registration_code: originator.identifier,
+                    address: originator.address.map(|address| WiseAddressDetails {
+                        country: address.country,
+                        country_code: address.country,
+                        first_line: address.line1,
+                        post_code: address.zip,
+                        city: address.city,
+                        state: address.state,
+                    }),
+                });
                 Ok(Self {
                     target_account,
                     quote_uuid,
                     customer_transaction_id: request.payout_id,
                     details: wise_transfer_details,
+                    originator,
                 })
             }
-            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+            storage_enums::PayoutType::Card
+            | storage_enums::PayoutType::Wallet
+            | storage_enums::PayoutType::StoreCredit => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Wise"),
                 ))?
@@ -537,7 +584,9 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for WisePayoutFulfillRequest {
             storage_enums::PayoutType::Bank => Ok(Self {
                 fund_type: FundType::default(),
             }),
-            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+            storage_enums::PayoutType::Card
+            | storage_enums::PayoutType::Wallet
+            | storage_enums::PayoutType::StoreCredit => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Wise"),
                 ))?
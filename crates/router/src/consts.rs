@@ -6,6 +6,39 @@ pub use hyperswitch_interfaces::consts::{NO_ERROR_CODE, NO_ERROR_MESSAGE};
 // ID generation
 pub(crate) const ID_LENGTH: usize = 20;
 pub(crate) const MAX_ID_LENGTH: usize = 64;
+/// Length of a client provided payout_id, when merchants require them to be passed
+pub(crate) const PAYOUT_ID_LENGTH: usize = 30;
+/// Maximum length of the purpose of payment code of a payout
+pub(crate) const PAYOUT_PURPOSE_CODE_MAX_LENGTH: usize = 64;
+/// Maximum length of the statement descriptor of a payout
+pub(crate) const PAYOUT_STATEMENT_DESCRIPTOR_MAX_LENGTH: usize = 35;
+/// Maximum length of the identifier of a payout in the systems of the merchant
+pub(crate) const PAYOUT_MERCHANT_REFERENCE_ID_MAX_LENGTH: usize = 64;
+/// Maximum length of the identifier of a recurring payout series
+pub(crate) const PAYOUT_SERIES_ID_MAX_LENGTH: usize = 64;
+/// Maximum length of the identifier of a payout batch, which leaves room for the position of the
+/// item in the identifiers of the payouts of the batch
+pub(crate) const PAYOUT_BATCH_ID_MAX_LENGTH: usize = 60;
+/// Maximum length of the reason reported by the bank for the recall of a payout
+pub(crate) const PAYOUT_RECALL_REASON_MAX_LENGTH: usize = 255;
+/// Maximum length of the reference of a document verification in the systems of the verifier
+pub(crate) const PAYOUT_VERIFICATION_REFERENCE_MAX_LENGTH: usize = 255;
+/// Maximum length of the reason for the rejection of a document verification
+pub(crate) const PAYOUT_VERIFICATION_REASON_MAX_LENGTH: usize = 255;
+/// Maximum number of destinations, including the primary one, which can be passed for a payout
+pub(crate) const PAYOUT_MAX_DESTINATIONS: usize = 5;
+/// Default time in seconds for which a payout waits for the result of a document verification
+pub(crate) const DEFAULT_PAYOUT_VERIFICATION_WINDOW: u32 = 7 * 24 * 60 * 60;
+/// Default maximum number of payouts exported synchronously
+pub(crate) const DEFAULT_PAYOUTS_EXPORT_SYNC_LIMIT: usize = 1000;
+/// Default time in seconds for which the files of payouts export jobs are retained
+pub(crate) const DEFAULT_PAYOUTS_EXPORT_FILE_RETENTION: u32 = 24 * 60 * 60;
+/// Default time in seconds after which a payout hold is released if it is not captured
+pub(crate) const DEFAULT_PAYOUT_HOLD_EXPIRY: u32 = 7 * 24 * 60 * 60;
+/// Maximum time in seconds for which an amount can be held for a payout
+pub(crate) const MAX_PAYOUT_HOLD_EXPIRY: u32 = 30 * 24 * 60 * 60;
+/// Maximum length of the description of a payout hold
+pub(crate) const PAYOUT_HOLD_DESCRIPTION_MAX_LENGTH: usize = 255;
 #[rustfmt::skip]
 pub(crate) const ALPHABETS: [char; 62] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
@@ -28,6 +61,9 @@ pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
 /// Payment intent default client secret expiry (in seconds)
 pub const DEFAULT_SESSION_EXPIRY: i64 = 15 * 60;
 
+/// Default maximum number of order details rendered on a payment link
+pub const DEFAULT_PAYMENT_LINK_MAX_ORDER_DETAILS: usize = 250;
+
 /// The length of a merchant fingerprint secret
 pub const FINGERPRINT_SECRET_LENGTH: usize = 64;
 
@@ -104,6 +140,57 @@ pub const MIN_SESSION_EXPIRY: u32 = 60;
 
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
+/// Identifiers under which the synthetic data of the locker round-trip health check is stored
+pub const LOCKER_HEALTH_CHECK_MERCHANT_ID: &str = "hs_locker_health_check";
+pub const LOCKER_HEALTH_CHECK_CUSTOMER_ID: &str = "hs_locker_health_check";
+
+/// Time to live, in seconds, for the synthetic data stored by the locker round-trip health check
+pub const LOCKER_HEALTH_CHECK_DATA_TTL: i64 = 60;
+
+/// Prefix of the keys of the test objects written to the file store by its health check, each
+/// probe suffixes it with a random id so that concurrent probes don't clobber each other's object
+pub const FILE_STORE_HEALTH_CHECK_KEY_PREFIX: &str = "hs_health_check/test_object";
+
+/// Default number of decimal places of the rate returned in currency conversions
+pub const DEFAULT_FOREX_RATE_PRECISION: u32 = 6;
+
+/// Maximum number of decimal places of the rate returned in currency conversions
+pub const MAX_FOREX_RATE_PRECISION: u32 = 12;
+
+/// Maximum markup a merchant can apply over the mid-market forex rate, in basis points
+pub const MAX_FOREX_MARKUP_BPS: u16 = 1000;
+
+/// Redis key prefix of the per merchant count of forex retrieve requests fetching from the provider
+pub const FOREX_FETCH_RATE_LIMIT_KEY_PREFIX: &str = "forex_fetch_rate_limit";
+
+/// Redis key prefix of the forex conversion quotes
+pub const FOREX_QUOTE_KEY_PREFIX: &str = "forex_quote";
+
+/// Redis key prefix of the markers of the forex conversion quotes which were used or cancelled
+pub const FOREX_QUOTE_SETTLED_KEY_PREFIX: &str = "forex_quote_settled";
+
+/// Time in seconds for which the rate of a forex conversion quote is locked
+pub const FOREX_QUOTE_VALIDITY_IN_SECS: i64 = 5 * 60;
+
+/// Time in seconds for which a forex conversion quote is retained after it expires, so that its
+/// cancellation can report it as expired (1 day)
+pub const FOREX_QUOTE_RETENTION_IN_SECS: i64 = 24 * 60 * 60;
+
+/// Maximum time in seconds after the initial delivery attempt of an event for which its delivery
+/// is retried automatically (3 days), irrespective of the configured number of retries
+pub const OUTGOING_WEBHOOK_MAX_RETRY_DURATION: i64 = 3 * 24 * 60 * 60;
+
+/// Redis key prefix of the payout webhook events which have been processed
+pub const PAYOUT_WEBHOOK_EVENT_KEY_PREFIX: &str = "payout_webhook_event";
+
+/// Time in seconds for which processed payout webhook events are remembered, to ignore them when
+/// they are delivered again by the connector (7 days)
+pub const PAYOUT_WEBHOOK_EVENT_TTL: i64 = 7 * 24 * 60 * 60;
+
+/// Version of the latest migration the application was built with, set by the build script
+pub const EXPECTED_SCHEMA_VERSION: Option<&str> =
+    option_env!("HYPERSWITCH_EXPECTED_SCHEMA_VERSION");
+
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
 
 // URL for checking the outgoing call
@@ -112,6 +199,14 @@ pub const OUTGOING_CALL_URL: &str = "https://api.stripe.com/healthcheck";
 // 15 minutes = 900 seconds
 pub const POLL_ID_TTL: i64 = 900;
 
+// 5 minutes = 300 seconds
+pub const PAYMENT_LINK_CONFIG_CACHE_TTL: i64 = 300;
+
+/// Placeholder payment id and client secret used when previewing a payment link, these never
+/// refer to a real payment
+pub const PAYMENT_LINK_PREVIEW_PAYMENT_ID: &str = "pay_preview";
+pub const PAYMENT_LINK_PREVIEW_CLIENT_SECRET: &str = "pay_preview_secret_preview";
+
 // Default Poll Config
 pub const DEFAULT_POLL_DELAY_IN_SECS: i8 = 2;
 pub const DEFAULT_POLL_FREQUENCY: i8 = 5;
@@ -1,48 +1,984 @@
-use common_utils::errors::CustomResult;
-use error_stack::ResultExt;
+use std::str::FromStr;
+
+use api_models::{
+    currency::{CurrencyMetadata, ForexProvider, ForexQuoteStatus},
+    enums::Currency,
+};
+use common_utils::{errors::CustomResult, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::logger;
+use rust_decimal::Decimal;
 
 use crate::{
+    consts,
     core::errors::ApiErrorResponse,
     services::ApplicationResponse,
-    utils::currency::{self, convert_currency, get_forex_rates},
+    types::storage,
+    utils::{
+        self,
+        currency::{self, convert_currency, get_forex_rates, get_forex_rates_from_provider},
+    },
     SessionState,
 };
 
 pub async fn retrieve_forex(
     state: SessionState,
-) -> CustomResult<ApplicationResponse<currency::FxExchangeRatesCacheEntry>, ApiErrorResponse> {
+    merchant_id: String,
+    params: api_models::currency::ForexRetrieveParams,
+) -> CustomResult<ApplicationResponse<currency::ForexRetrieveResponse>, ApiErrorResponse> {
+    let conversion_request = get_forex_conversion_request(params)?;
     let forex_api = state.conf.forex_api.get_inner();
-    Ok(ApplicationResponse::Json(
-        get_forex_rates(
-            &state,
-            forex_api.call_delay,
-            forex_api.local_fetch_retry_delay,
-            forex_api.local_fetch_retry_count,
-        )
+    // Only the requests which would fetch the rates from the provider are rate limited
+    if !currency::has_fresh_forex_rates(&state, forex_api.call_delay).await {
+        check_forex_fetch_rate_limit(&state, &merchant_id).await?;
+    }
+    let rates = get_forex_rates(
+        &state,
+        forex_api.call_delay,
+        forex_api.local_fetch_retry_delay,
+        forex_api.local_fetch_retry_count,
+    )
+    .await
+    .change_context(ApiErrorResponse::GenericNotFoundError {
+        message: "Unable to fetch forex rates".to_string(),
+    })?;
+
+    let (conversion, conversion_error) = match conversion_request {
+        Some((amount, to_currency, from_currency)) => {
+            // The conversion uses the rates being returned, so that both are consistent
+            match currency::convert_currency_using_rates(
+                &rates,
+                forex_api.currencies.as_ref(),
+                amount.get_amount_as_i64(),
+                to_currency.to_string(),
+                from_currency.to_string(),
+                consts::DEFAULT_FOREX_RATE_PRECISION,
+            ) {
+                Ok(mut conversion) => {
+                    let source_amount = Decimal::new(
+                        amount.get_amount_as_i64(),
+                        u32::from(from_currency.number_of_digits_after_decimal_point()),
+                    );
+                    apply_forex_markup(
+                        &mut conversion,
+                        source_amount,
+                        get_forex_markup_bps(&state, &merchant_id),
+                        consts::DEFAULT_FOREX_RATE_PRECISION,
+                        u32::from(to_currency.number_of_digits_after_decimal_point()),
+                    )?;
+                    insert_forex_quote_audit_log(&state, &merchant_id, &conversion).await;
+                    (Some(conversion), None)
+                }
+                Err(error) => (
+                    None,
+                    Some(format!(
+                        "Unable to convert from {from_currency} to {to_currency}: {}",
+                        error.current_context()
+                    )),
+                ),
+            }
+        }
+        None => (None, None),
+    };
+
+    Ok(ApplicationResponse::Json(currency::ForexRetrieveResponse {
+        rates,
+        conversion,
+        conversion_error,
+    }))
+}
+
+/// Counts the requests of the merchant which need to fetch the forex rates from the provider, and
+/// fails once the configured limit is exceeded within the window.
+///
+/// The rate limit is not enforced when redis is unavailable.
+async fn check_forex_fetch_rate_limit(
+    state: &SessionState,
+    merchant_id: &str,
+) -> CustomResult<(), ApiErrorResponse> {
+    let Some(rate_limit) = state.conf.forex_api.get_inner().fetch_rate_limit.as_ref() else {
+        return Ok(());
+    };
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Unable to check the forex fetch rate limit");
+            return Ok(());
+        }
+    };
+
+    let key = format!(
+        "{}_{merchant_id}",
+        consts::FOREX_FETCH_RATE_LIMIT_KEY_PREFIX
+    );
+    let request_count = match redis_conn
+        .increment_key_with_expiry(&key, rate_limit.window)
         .await
-        .change_context(ApiErrorResponse::GenericNotFoundError {
-            message: "Unable to fetch forex rates".to_string(),
-        })?,
-    ))
+    {
+        Ok(request_count) => request_count,
+        Err(error) => {
+            logger::error!(?error, "Unable to check the forex fetch rate limit");
+            return Ok(());
+        }
+    };
+
+    utils::when(request_count > rate_limit.max_requests, || {
+        logger::warn!(%merchant_id, "Forex fetch rate limit exceeded");
+        Err(report!(ApiErrorResponse::ForexRateLimitExceeded))
+    })
+}
+
+/// Validates the optional conversion params of the forex retrieve request.
+///
+/// Conversion is requested only when any of the params is passed, in which case all of them are
+/// required and the currencies have to be valid ISO 4217 codes.
+fn get_forex_conversion_request(
+    params: api_models::currency::ForexRetrieveParams,
+) -> CustomResult<Option<(MinorUnit, Currency, Currency)>, ApiErrorResponse> {
+    let api_models::currency::ForexRetrieveParams {
+        amount,
+        to_currency,
+        from_currency,
+    } = params;
+    if amount.is_none() && to_currency.is_none() && from_currency.is_none() {
+        return Ok(None);
+    }
+
+    let amount = amount.ok_or(ApiErrorResponse::MissingRequiredField {
+        field_name: "amount",
+    })?;
+    let parse_currency = |currency: Option<String>, field_name: &'static str| {
+        let currency = currency.ok_or(ApiErrorResponse::MissingRequiredField { field_name })?;
+        Currency::from_str(&currency.trim().to_uppercase()).map_err(|_| {
+            report!(ApiErrorResponse::CurrencyNotSupported {
+                message: format!("Unknown currency code: {currency}"),
+            })
+        })
+    };
+    let to_currency = parse_currency(to_currency, "to_currency")?;
+    let from_currency = parse_currency(from_currency, "from_currency")?;
+
+    Ok(Some((amount, to_currency, from_currency)))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn convert_forex(
     state: SessionState,
+    merchant_id: String,
     amount: i64,
     to_currency: String,
     from_currency: String,
+    provider: Option<ForexProvider>,
+    allow_fallback: bool,
+    rate_precision: Option<u8>,
+    locale: Option<String>,
 ) -> CustomResult<
     ApplicationResponse<api_models::currency::CurrencyConversionResponse>,
     ApiErrorResponse,
 > {
+    let from_asset =
+        currency::get_conversion_asset(&state, &from_currency).map_err(get_conversion_error)?;
+    let to_asset =
+        currency::get_conversion_asset(&state, &to_currency).map_err(get_conversion_error)?;
+    // Conversions involving crypto assets always use the configured crypto rate source
+    let is_crypto_conversion = from_asset.is_crypto() || to_asset.is_crypto();
+    utils::when(is_crypto_conversion && provider.is_some(), || {
+        Err(report!(ApiErrorResponse::InvalidRequestData {
+            message: "Forex provider can not be chosen for conversions involving crypto assets"
+                .to_string(),
+        }))
+    })?;
+
+    let provider_rates = match provider {
+        Some(provider) => {
+            let call_delay = state.conf.forex_api.get_inner().call_delay;
+            match get_forex_rates_from_provider(&state, provider, call_delay).await {
+                Ok(rates) => Some(rates),
+                Err(error) if allow_fallback => {
+                    logger::warn!(
+                        ?error,
+                        "Forex provider {provider} unavailable, falling back to the default rates"
+                    );
+                    None
+                }
+                Err(error) => {
+                    let api_error = match error.current_context() {
+                        currency::ForexCacheError::ProviderNotConfigured => {
+                            ApiErrorResponse::InvalidRequestData {
+                                message: format!("Forex provider {provider} is not configured"),
+                            }
+                        }
+                        _ => ApiErrorResponse::GenericNotFoundError {
+                            message: format!("Unable to fetch forex rates from {provider}"),
+                        },
+                    };
+                    return Err(error.change_context(api_error));
+                }
+            }
+        }
+        None => None,
+    };
+
+    let rate_precision = rate_precision
+        .map_or(consts::DEFAULT_FOREX_RATE_PRECISION, u32::from)
+        .min(consts::MAX_FOREX_RATE_PRECISION);
+    let locale_format = locale
+        .as_deref()
+        .map(get_locale_number_format)
+        .transpose()?;
+
+    let conversion = if is_crypto_conversion {
+        currency::convert_crypto_currency(&state, amount, &to_asset, &from_asset, rate_precision)
+            .await
+    } else {
+        match provider_rates {
+            Some(rates) => currency::convert_currency_using_rates(
+                &rates,
+                state.conf.forex_api.get_inner().currencies.as_ref(),
+                amount,
+                to_currency,
+                from_currency,
+                rate_precision,
+            ),
+            None => {
+                Box::pin(convert_currency(
+                    state.clone(),
+                    amount,
+                    to_currency,
+                    from_currency,
+                    rate_precision,
+                ))
+                .await
+            }
+        }
+    };
+    let mut conversion = conversion.map_err(get_conversion_error)?;
+
+    let source_metadata = get_conversion_asset_metadata(&from_asset);
+    let target_metadata = get_conversion_asset_metadata(&to_asset);
+    let source_amount = Decimal::new(amount, u32::from(source_metadata.minor_units));
+    apply_forex_markup(
+        &mut conversion,
+        source_amount,
+        get_forex_markup_bps(&state, &merchant_id),
+        rate_precision,
+        u32::from(target_metadata.minor_units),
+    )?;
+
+    if let Some(locale_format) = locale_format {
+        conversion.formatted_amount = Some(format_amount_for_locale(
+            source_amount,
+            &source_metadata,
+            locale_format.as_ref(),
+        ));
+
+        let converted_amount = Decimal::from_str(&conversion.converted_amount)
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the converted amount")?;
+        conversion.formatted_converted_amount = Some(format_amount_for_locale(
+            converted_amount,
+            &target_metadata,
+            locale_format.as_ref(),
+        ));
+    }
+    insert_forex_quote_audit_log(&state, &merchant_id, &conversion).await;
+
+    Ok(ApplicationResponse::Json(conversion))
+}
+
+/// Records a conversion quoted to the merchant in the currency conversion audit log. Quotes do not
+/// move money, so the record is not binding and failing to record it does not fail the quote.
+async fn insert_forex_quote_audit_log(
+    state: &SessionState,
+    merchant_id: &str,
+    conversion: &api_models::currency::CurrencyConversionResponse,
+) {
+    let exchange_rate_timestamp =
+        match time::OffsetDateTime::from_unix_timestamp(conversion.rates_timestamp) {
+            Ok(timestamp) => time::PrimitiveDateTime::new(timestamp.date(), timestamp.time()),
+            Err(error) => {
+                logger::error!(?error, "Invalid timestamp of the forex rates of the quote");
+                return;
+            }
+        };
+    let audit_log = storage::CurrencyConversionAuditLogNew {
+        audit_id: utils::generate_id(consts::ID_LENGTH, "conv_audit"),
+        merchant_id: merchant_id.to_owned(),
+        payout_id: None,
+        source_amount: conversion.amount,
+        source_currency: conversion.from_currency.clone(),
+        destination_currency: conversion.to_currency.clone(),
+        converted_amount: conversion.converted_amount.clone(),
+        exchange_rate: conversion.rate.clone(),
+        exchange_rate_source: conversion.provider.map(|provider| provider.to_string()),
+        exchange_rate_timestamp,
+        is_binding: false,
+        created_at: common_utils::date_time::now(),
+    };
+    if let Err(error) = state
+        .store
+        .insert_currency_conversion_audit_log(audit_log)
+        .await
+    {
+        logger::error!(?error, "Failed to record the forex quote in the audit log");
+    }
+}
+
+/// Creates a quote locking the rate of the conversion for [`consts::FOREX_QUOTE_VALIDITY_IN_SECS`].
+/// The amount is converted at the default rates of the merchant, including its markup.
+pub async fn create_conversion_quote(
+    state: SessionState,
+    merchant_id: String,
+    req: api_models::currency::ForexQuoteCreateRequest,
+) -> CustomResult<ApplicationResponse<api_models::currency::ForexQuoteResponse>, ApiErrorResponse> {
+    let conversion = match Box::pin(convert_forex(
+        state.clone(),
+        merchant_id.clone(),
+        req.amount.get_amount_as_i64(),
+        req.to_currency,
+        req.from_currency,
+        None,
+        false,
+        None,
+        None,
+    ))
+    .await?
+    {
+        ApplicationResponse::Json(conversion) => conversion,
+        _ => Err(ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response while converting the amount of the quote")?,
+    };
+    let quote = api_models::currency::ForexQuoteResponse {
+        quote_id: utils::generate_id(consts::ID_LENGTH, "fxq"),
+        conversion,
+        expires_at: common_utils::date_time::now_unix_timestamp()
+            + consts::FOREX_QUOTE_VALIDITY_IN_SECS,
+    };
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &get_forex_quote_key(&merchant_id, &quote.quote_id),
+            &quote,
+            consts::FOREX_QUOTE_VALIDITY_IN_SECS + consts::FOREX_QUOTE_RETENTION_IN_SECS,
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the forex quote")?;
+
+    Ok(ApplicationResponse::Json(quote))
+}
+
+/// Cancels the quote so that it can no longer be used. Quotes which were already used, cancelled
+/// or have expired are reported in their state instead of failing the cancellation.
+pub async fn cancel_conversion_quote(
+    state: SessionState,
+    merchant_id: String,
+    quote_id: String,
+) -> CustomResult<
+    ApplicationResponse<api_models::currency::ForexQuoteCancelResponse>,
+    ApiErrorResponse,
+> {
+    let quote = get_conversion_quote(&state, &merchant_id, &quote_id).await?;
+    let status = settle_conversion_quote(&state, &merchant_id, &quote, ForexQuoteStatus::Cancelled)
+        .await?
+        .unwrap_or(ForexQuoteStatus::Cancelled);
+
     Ok(ApplicationResponse::Json(
-        Box::pin(convert_currency(
-            state.clone(),
-            amount,
-            to_currency,
-            from_currency,
-        ))
+        api_models::currency::ForexQuoteCancelResponse { quote_id, status },
+    ))
+}
+
+/// Uses the quote so that it can neither be used again nor cancelled. Fails when the quote was
+/// already used, cancelled or has expired.
+pub async fn consume_conversion_quote(
+    state: &SessionState,
+    merchant_id: &str,
+    quote: &api_models::currency::ForexQuoteResponse,
+) -> CustomResult<(), ApiErrorResponse> {
+    match settle_conversion_quote(state, merchant_id, quote, ForexQuoteStatus::Consumed).await? {
+        None => Ok(()),
+        Some(status) => Err(report!(ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Forex quote {} can no longer be used as it is {status}",
+                quote.quote_id
+            ),
+        })),
+    }
+}
+
+fn get_forex_quote_key(merchant_id: &str, quote_id: &str) -> String {
+    format!(
+        "{}_{merchant_id}_{quote_id}",
+        consts::FOREX_QUOTE_KEY_PREFIX
+    )
+}
+
+/// Fetches the quote of the merchant, including quotes which were used, cancelled or have expired
+/// within the retention period.
+pub async fn get_conversion_quote(
+    state: &SessionState,
+    merchant_id: &str,
+    quote_id: &str,
+) -> CustomResult<api_models::currency::ForexQuoteResponse, ApiErrorResponse> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_and_deserialize_key::<api_models::currency::ForexQuoteResponse>(
+            &get_forex_quote_key(merchant_id, quote_id),
+            "ForexQuoteResponse",
+        )
         .await
-        .change_context(ApiErrorResponse::InternalServerError)?,
+        .map_err(|error| match error.current_context() {
+            redis_interface::errors::RedisError::NotFound => {
+                error.change_context(ApiErrorResponse::GenericNotFoundError {
+                    message: format!("Forex quote {quote_id} not found"),
+                })
+            }
+            _ => error.change_context(ApiErrorResponse::InternalServerError),
+        })
+}
+
+/// Marks the quote as used or cancelled, unless it already was or has expired, in which case the
+/// state the quote is in is returned. The quote is marked only once even when it is used and
+/// cancelled concurrently.
+async fn settle_conversion_quote(
+    state: &SessionState,
+    merchant_id: &str,
+    quote: &api_models::currency::ForexQuoteResponse,
+    status: ForexQuoteStatus,
+) -> CustomResult<Option<ForexQuoteStatus>, ApiErrorResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = format!(
+        "{}_{merchant_id}_{}",
+        consts::FOREX_QUOTE_SETTLED_KEY_PREFIX,
+        quote.quote_id
+    );
+
+    if quote.expires_at <= common_utils::date_time::now_unix_timestamp() {
+        return Ok(Some(
+            get_settled_conversion_quote_status(&redis_conn, &key)
+                .await?
+                .unwrap_or(ForexQuoteStatus::Expired),
+        ));
+    }
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &key,
+            status.to_string(),
+            Some(consts::FOREX_QUOTE_VALIDITY_IN_SECS + consts::FOREX_QUOTE_RETENTION_IN_SECS),
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the state of the forex quote")?
+    {
+        redis_interface::SetnxReply::KeySet => Ok(None),
+        redis_interface::SetnxReply::KeyNotSet => Ok(Some(
+            get_settled_conversion_quote_status(&redis_conn, &key)
+                .await?
+                .unwrap_or(status),
+        )),
+    }
+}
+
+async fn get_settled_conversion_quote_status(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    key: &str,
+) -> CustomResult<Option<ForexQuoteStatus>, ApiErrorResponse> {
+    redis_conn
+        .get_key::<Option<String>>(key)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the state of the forex quote")?
+        .map(|settled_status| ForexQuoteStatus::from_str(&settled_status))
+        .transpose()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid state of the forex quote")
+}
+
+/// Returns the markup the merchant applies over the mid-market rate in its conversions, in basis
+/// points. The markups are validated when the configuration is loaded.
+fn get_forex_markup_bps(state: &SessionState, merchant_id: &str) -> u16 {
+    state
+        .conf
+        .forex_api
+        .get_inner()
+        .markup_bps
+        .get(merchant_id)
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Applies the markup over the mid-market rate of the conversion, reducing the converted amount
+/// and the rate by the markup. The mid-market rate is retained as the base rate of the conversion.
+///
+/// The converted amount is rounded to the minor unit of the target currency, and the markup is
+/// applied to the last leg of triangulated conversions so that the legs compound to the rate.
+fn apply_forex_markup(
+    conversion: &mut api_models::currency::CurrencyConversionResponse,
+    source_amount: Decimal,
+    markup_bps: u16,
+    rate_precision: u32,
+    converted_minor_units: u32,
+) -> CustomResult<(), ApiErrorResponse> {
+    if markup_bps == 0 {
+        return Ok(());
+    }
+    let parse_decimal = |value: &str| {
+        Decimal::from_str(value)
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the conversion")
+    };
+    let converted_amount = parse_decimal(&conversion.converted_amount)?;
+    // The returned rate is rounded, the unrounded rate is derived from the amounts when possible
+    let base_rate = match converted_amount.checked_div(source_amount) {
+        Some(base_rate) => base_rate,
+        None => parse_decimal(&conversion.rate)?,
+    };
+    let markup_factor = Decimal::ONE - Decimal::new(i64::from(markup_bps), 4);
+
+    conversion.base_rate = Some(conversion.rate.clone());
+    conversion.markup_bps = Some(markup_bps);
+    conversion.rate = (base_rate * markup_factor)
+        .round_dp(rate_precision)
+        .to_string();
+    conversion.converted_amount = (converted_amount * markup_factor)
+        .round_dp(converted_minor_units)
+        .to_string();
+    if let Some(last_leg) = conversion
+        .rate_path
+        .as_mut()
+        .and_then(|rate_path| rate_path.legs.last_mut())
+    {
+        last_leg.rate = (parse_decimal(&last_leg.rate)? * markup_factor)
+            .round_dp(rate_precision)
+            .to_string();
+    }
+    Ok(())
+}
+
+fn get_conversion_error(
+    error: error_stack::Report<currency::ForexCacheError>,
+) -> error_stack::Report<ApiErrorResponse> {
+    match error.current_context() {
+        currency::ForexCacheError::CurrencyNotConfigured(_)
+        | currency::ForexCacheError::RateNotAvailable(_)
+        | currency::ForexCacheError::UnsupportedAsset(_) => {
+            let message = error.current_context().to_string();
+            error.change_context(ApiErrorResponse::CurrencyNotSupported { message })
+        }
+        _ => error.change_context(ApiErrorResponse::InternalServerError),
+    }
+}
+
+/// Metadata used for formatting amounts of either side of a conversion, crypto assets are
+/// displayed using their code
+fn get_conversion_asset_metadata(asset: &currency::ConversionAsset) -> CurrencyMetadata {
+    match asset {
+        currency::ConversionAsset::Fiat(currency) => get_currency_metadata(*currency),
+        currency::ConversionAsset::Crypto { code, minor_units } => CurrencyMetadata {
+            iso_code: code.clone(),
+            symbol: code.clone(),
+            minor_units: *minor_units,
+        },
+    }
+}
+
+/// Conventions used for formatting amounts in a locale
+#[derive(Debug, Clone, Copy)]
+struct LocaleNumberFormat {
+    decimal_separator: char,
+    group_separator: char,
+    symbol_before_amount: bool,
+    space_between_symbol_and_amount: bool,
+}
+
+impl LocaleNumberFormat {
+    const fn new(
+        decimal_separator: char,
+        group_separator: char,
+        symbol_before_amount: bool,
+        space_between_symbol_and_amount: bool,
+    ) -> Self {
+        Self {
+            decimal_separator,
+            group_separator,
+            symbol_before_amount,
+            space_between_symbol_and_amount,
+        }
+    }
+}
+
+/// Validates the locale, and returns its formatting conventions if they are known. Locales
+/// without known conventions are formatted in the neutral format.
+fn get_locale_number_format(
+    locale: &str,
+) -> CustomResult<Option<LocaleNumberFormat>, ApiErrorResponse> {
+    let mut subtags = locale.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+    let region = subtags.next().map(str::to_ascii_uppercase);
+    let is_valid_language =
+        (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic());
+    let is_valid_region = match region.as_deref() {
+        Some(region) => {
+            (region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()))
+                || (region.len() == 3 && region.chars().all(|c| c.is_ascii_digit()))
+        }
+        None => true,
+    };
+    utils::when(
+        !is_valid_language || !is_valid_region || subtags.next().is_some(),
+        || {
+            Err(report!(ApiErrorResponse::InvalidDataValue {
+                field_name: "locale",
+            }))
+        },
+    )?;
+
+    // Narrow no-break space used for grouping digits in French
+    const NARROW_NBSP: char = '\u{202F}';
+    const NBSP: char = '\u{00A0}';
+    let format = match (language.as_str(), region.as_deref()) {
+        ("pt", Some("BR")) => Some(LocaleNumberFormat::new(',', '.', true, true)),
+        ("de", Some("CH")) => Some(LocaleNumberFormat::new('.', '\u{2019}', true, true)),
+        ("en" | "ja" | "zh" | "ko" | "th" | "he", _) => {
+            Some(LocaleNumberFormat::new('.', ',', true, false))
+        }
+        ("de" | "es" | "it" | "pt" | "da" | "id" | "tr" | "el", _) => {
+            Some(LocaleNumberFormat::new(',', '.', false, true))
+        }
+        ("nl", _) => Some(LocaleNumberFormat::new(',', '.', true, true)),
+        ("fr", _) => Some(LocaleNumberFormat::new(',', NARROW_NBSP, false, true)),
+        ("sv" | "nb" | "no" | "fi" | "pl" | "cs" | "ru" | "uk", _) => {
+            Some(LocaleNumberFormat::new(',', NBSP, false, true))
+        }
+        _ => None,
+    };
+    if format.is_none() {
+        logger::debug!(%locale, "No formatting conventions known for locale, using neutral format");
+    }
+    Ok(format)
+}
+
+/// Formats an amount in the major unit of its currency for display, rounded to the minor unit of
+/// the currency. The neutral format, used when no locale format is given, places the ISO code
+/// before the amount and does not group digits (for example `EUR 1234.50`).
+fn format_amount_for_locale(
+    amount: Decimal,
+    metadata: &CurrencyMetadata,
+    locale_format: Option<&LocaleNumberFormat>,
+) -> String {
+    let amount = amount.round_dp(u32::from(metadata.minor_units));
+    let digits = format!("{:.*}", usize::from(metadata.minor_units), amount.abs());
+    let (integer_part, fraction_part) = digits.split_once('.').unwrap_or((digits.as_str(), ""));
+    let sign = if amount.is_sign_negative() && !amount.is_zero() {
+        "-"
+    } else {
+        ""
+    };
+
+    let Some(locale_format) = locale_format else {
+        let number = if fraction_part.is_empty() {
+            integer_part.to_string()
+        } else {
+            format!("{integer_part}.{fraction_part}")
+        };
+        return format!("{} {sign}{number}", metadata.iso_code);
+    };
+
+    let mut grouped = String::with_capacity(integer_part.len() * 2);
+    for (index, digit) in integer_part.chars().enumerate() {
+        if index > 0 && (integer_part.len() - index) % 3 == 0 {
+            grouped.push(locale_format.group_separator);
+        }
+        grouped.push(digit);
+    }
+    let number = if fraction_part.is_empty() {
+        grouped
+    } else {
+        format!(
+            "{grouped}{}{fraction_part}",
+            locale_format.decimal_separator
+        )
+    };
+
+    let space = if locale_format.space_between_symbol_and_amount {
+        "\u{00A0}"
+    } else {
+        ""
+    };
+    if locale_format.symbol_before_amount {
+        format!("{sign}{}{space}{number}", metadata.symbol)
+    } else {
+        format!("{sign}{number}{space}{}", metadata.symbol)
+    }
+}
+
+pub async fn retrieve_currency_metadata(
+    params: api_models::currency::CurrencyMetadataParams,
+) -> CustomResult<
+    ApplicationResponse<api_models::currency::CurrencyMetadataResponse>,
+    ApiErrorResponse,
+> {
+    let requested_codes = params
+        .currencies
+        .split(',')
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+        .collect::<Vec<_>>();
+
+    if requested_codes.is_empty() {
+        return Err(report!(ApiErrorResponse::MissingRequiredField {
+            field_name: "currencies",
+        }));
+    }
+
+    let (currencies, unknown_codes): (Vec<_>, Vec<_>) = requested_codes
+        .into_iter()
+        .map(|code| Currency::from_str(code).map_err(|_| code))
+        .partition(Result::is_ok);
+
+    if !unknown_codes.is_empty() {
+        let unknown_codes = unknown_codes
+            .into_iter()
+            .filter_map(Result::err)
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(report!(ApiErrorResponse::CurrencyNotSupported {
+            message: format!("Unknown currency codes: {unknown_codes}"),
+        }));
+    }
+
+    let currencies = currencies
+        .into_iter()
+        .filter_map(Result::ok)
+        .map(get_currency_metadata)
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        api_models::currency::CurrencyMetadataResponse { currencies },
     ))
 }
+
+fn get_currency_metadata(currency: Currency) -> CurrencyMetadata {
+    let iso_code = currency.to_string();
+    // Currencies missing from the ISO 4217 table are displayed using their code
+    let symbol = iso_currency::Currency::from_code(&iso_code)
+        .map(|iso_currency| iso_currency.symbol().to_string())
+        .unwrap_or_else(|| iso_code.clone());
+
+    CurrencyMetadata {
+        minor_units: currency.number_of_digits_after_decimal_point(),
+        symbol,
+        iso_code,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_forex_conversion_request() {
+        assert!(
+            get_forex_conversion_request(api_models::currency::ForexRetrieveParams::default())
+                .unwrap()
+                .is_none()
+        );
+
+        let params = |from_currency: &str| api_models::currency::ForexRetrieveParams {
+            amount: Some(MinorUnit::new(1000)),
+            to_currency: Some("USD".to_string()),
+            from_currency: Some(from_currency.to_string()),
+        };
+        assert_eq!(
+            get_forex_conversion_request(params(" eur ")).unwrap(),
+            Some((MinorUnit::new(1000), Currency::USD, Currency::EUR))
+        );
+        assert!(get_forex_conversion_request(params("XYZ")).is_err());
+        assert!(
+            get_forex_conversion_request(api_models::currency::ForexRetrieveParams {
+                amount: None,
+                ..params("EUR")
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_apply_forex_markup() {
+        let mut conversion = api_models::currency::CurrencyConversionResponse {
+            amount: MinorUnit::new(10000),
+            from_currency: "USD".to_string(),
+            to_currency: "EUR".to_string(),
+            converted_amount: "92.5".to_string(),
+            currency: "EUR".to_string(),
+            rate: "0.925".to_string(),
+            provider: None,
+            formatted_amount: None,
+            formatted_converted_amount: None,
+            base_rate: None,
+            markup_bps: None,
+            rates_timestamp: 1_720_000_000,
+            rate_path: None,
+        };
+        let source_amount = Decimal::new(10000, 2);
+
+        apply_forex_markup(&mut conversion, source_amount, 0, 6, 2).unwrap();
+        assert_eq!(conversion.rate, "0.925");
+        assert!(conversion.base_rate.is_none());
+
+        // 200 basis points reduce the rate and the converted amount by 2%
+        apply_forex_markup(&mut conversion, source_amount, 200, 6, 2).unwrap();
+        assert_eq!(conversion.base_rate.as_deref(), Some("0.925"));
+        assert_eq!(conversion.markup_bps, Some(200));
+        assert_eq!(
+            Decimal::from_str(&conversion.rate).unwrap(),
+            Decimal::from_str("0.9065").unwrap()
+        );
+        assert_eq!(
+            Decimal::from_str(&conversion.converted_amount).unwrap(),
+            Decimal::from_str("90.65").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_apply_forex_markup_to_triangulated_conversion() {
+        let leg = |from_currency: &str, to_currency: &str, rate: &str| {
+            api_models::currency::CurrencyConversionLeg {
+                from_currency: from_currency.to_string(),
+                to_currency: to_currency.to_string(),
+                rate: rate.to_string(),
+            }
+        };
+        let mut conversion = api_models::currency::CurrencyConversionResponse {
+            amount: MinorUnit::new(1234),
+            from_currency: "EUR".to_string(),
+            to_currency: "JPY".to_string(),
+            converted_amount: "1974.4".to_string(),
+            currency: "JPY".to_string(),
+            rate: "160".to_string(),
+            provider: None,
+            formatted_amount: None,
+            formatted_converted_amount: None,
+            base_rate: None,
+            markup_bps: None,
+            rates_timestamp: 1_720_000_000,
+            rate_path: Some(api_models::currency::CurrencyConversionRatePath {
+                pivot_currency: "USD".to_string(),
+                legs: vec![leg("EUR", "USD", "1.25"), leg("USD", "JPY", "128")],
+            }),
+        };
+
+        // The amount is rounded to the minor unit of JPY, and only the last leg carries the markup
+        apply_forex_markup(&mut conversion, Decimal::new(1234, 2), 100, 6, 0).unwrap();
+        assert_eq!(
+            Decimal::from_str(&conversion.converted_amount).unwrap(),
+            Decimal::new(1955, 0)
+        );
+        assert_eq!(
+            Decimal::from_str(&conversion.rate).unwrap(),
+            Decimal::from_str("158.4").unwrap()
+        );
+        let legs = conversion.rate_path.unwrap().legs;
+        assert_eq!(
+            Decimal::from_str(&legs[0].rate).unwrap(),
+            Decimal::from_str("1.25").unwrap()
+        );
+        assert_eq!(
+            Decimal::from_str(&legs[1].rate).unwrap(),
+            Decimal::from_str("126.72").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_format_amount_for_locale() {
+        let eur = get_currency_metadata(Currency::EUR);
+        let amount = Decimal::new(-123456789, 3);
+
+        let de = get_locale_number_format("de-DE").unwrap();
+        assert_eq!(
+            format_amount_for_locale(amount, &eur, de.as_ref()),
+            "-123.456,79\u{00A0}€"
+        );
+        let en = get_locale_number_format("en_US").unwrap();
+        assert_eq!(
+            format_amount_for_locale(amount, &eur, en.as_ref()),
+            "-€123,456.79"
+        );
+
+        // Unknown locales fall back to the neutral format, malformed ones are rejected
+        let neutral = get_locale_number_format("xx-YY").unwrap();
+        assert!(neutral.is_none());
+        assert_eq!(
+            format_amount_for_locale(amount, &eur, neutral.as_ref()),
+            "EUR -123456.79"
+        );
+        assert!(get_locale_number_format("english").is_err());
+        assert!(get_locale_number_format("en-US-x").is_err());
+
+        let jpy = get_currency_metadata(Currency::JPY);
+        assert_eq!(
+            format_amount_for_locale(Decimal::new(1500, 0), &jpy, en.as_ref()),
+            format!("{}1,500", jpy.symbol)
+        );
+    }
+
+    #[test]
+    fn test_forex_quote_is_stored_with_its_conversion() {
+        let quote = api_models::currency::ForexQuoteResponse {
+            quote_id: "fxq_1".to_string(),
+            conversion: api_models::currency::CurrencyConversionResponse {
+                amount: MinorUnit::new(10000),
+                from_currency: "USD".to_string(),
+                to_currency: "EUR".to_string(),
+                converted_amount: "92.5".to_string(),
+                currency: "EUR".to_string(),
+                rate: "0.925".to_string(),
+                provider: Some(ForexProvider::OpenExchangeRates),
+                formatted_amount: None,
+                formatted_converted_amount: None,
+                base_rate: None,
+                markup_bps: None,
+                rates_timestamp: 1_720_000_000,
+                rate_path: None,
+            },
+            expires_at: 1_720_000_300,
+        };
+
+        let stored = serde_json::to_value(&quote).unwrap();
+        assert_eq!(stored["quote_id"], "fxq_1");
+        assert_eq!(stored["converted_amount"], "92.5");
+
+        let restored =
+            serde_json::from_value::<api_models::currency::ForexQuoteResponse>(stored).unwrap();
+        assert_eq!(restored.conversion.amount, quote.conversion.amount);
+        assert_eq!(restored.conversion.rate, quote.conversion.rate);
+        assert_eq!(restored.conversion.provider, quote.conversion.provider);
+        assert_eq!(restored.expires_at, quote.expires_at);
+    }
+
+    #[test]
+    fn test_forex_quote_status_round_trips_through_its_marker() {
+        for status in [
+            ForexQuoteStatus::Cancelled,
+            ForexQuoteStatus::Consumed,
+            ForexQuoteStatus::Expired,
+        ] {
+            assert_eq!(
+                ForexQuoteStatus::from_str(&status.to_string()).unwrap(),
+                status
+            );
+        }
+    }
+}
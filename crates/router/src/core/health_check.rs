@@ -1,25 +1,46 @@
+use std::time::Duration;
+
 #[cfg(feature = "olap")]
 use analytics::health_check::HealthCheck;
-use api_models::health_check::HealthState;
+use api_models::{enums as api_enums, health_check::HealthState};
+use common_utils::{generate_id, id_type};
 use error_stack::ResultExt;
+use masking::PeekInterface;
 use router_env::logger;
 
 use crate::{
     consts,
-    core::errors::{self, CustomResult},
+    core::{
+        errors::{self, CustomResult},
+        payment_methods::{cards, transformers as payment_methods},
+    },
     routes::app,
     services::api as services,
+    types::api::{self, ConnectorCommon},
 };
 
 #[async_trait::async_trait]
 pub trait HealthCheckInterface {
     async fn health_check_db(&self) -> CustomResult<HealthState, errors::HealthCheckDBError>;
+    async fn health_check_db_schema(&self)
+        -> CustomResult<HealthState, errors::HealthCheckDBError>;
     async fn health_check_redis(&self) -> CustomResult<HealthState, errors::HealthCheckRedisError>;
     async fn health_check_locker(
         &self,
+        round_trip: bool,
     ) -> CustomResult<HealthState, errors::HealthCheckLockerError>;
+    async fn health_check_file_store(
+        &self,
+    ) -> CustomResult<HealthState, errors::HealthCheckFileStoreError>;
+    async fn health_check_secrets_manager(
+        &self,
+    ) -> CustomResult<HealthState, errors::HealthCheckSecretsManagerError>;
     async fn health_check_outgoing(&self)
         -> CustomResult<HealthState, errors::HealthCheckOutGoing>;
+    async fn health_check_connector(
+        &self,
+        connector: api_enums::Connector,
+    ) -> CustomResult<HealthState, errors::HealthCheckOutGoing>;
     #[cfg(feature = "olap")]
     async fn health_check_analytics(&self)
         -> CustomResult<HealthState, errors::HealthCheckDBError>;
@@ -33,44 +54,98 @@ pub trait HealthCheckInterface {
 #[async_trait::async_trait]
 impl HealthCheckInterface for app::SessionState {
     async fn health_check_db(&self) -> CustomResult<HealthState, errors::HealthCheckDBError> {
+        if self.conf.health_check.simulated_failures.database {
+            logger::warn!("Reporting a simulated failure of the database health check");
+            return Err(error_stack::report!(
+                errors::HealthCheckDBError::SimulatedFailure
+            ));
+        }
         let db = &*self.store;
         db.health_check_db().await?;
         Ok(HealthState::Running)
     }
 
+    /// Verifies that the migrations the application was built with are applied to the database,
+    /// to catch deployments where the application is rolled out ahead of its migrations.
+    async fn health_check_db_schema(
+        &self,
+    ) -> CustomResult<HealthState, errors::HealthCheckDBError> {
+        let Some(expected_version) = consts::EXPECTED_SCHEMA_VERSION else {
+            return Ok(HealthState::NotApplicable);
+        };
+        let applied_version = self.store.get_applied_schema_version().await?;
+
+        // Migration versions are timestamps of the same width, and compare lexicographically
+        if applied_version
+            .as_deref()
+            .map_or(true, |applied_version| applied_version < expected_version)
+        {
+            return Err(error_stack::report!(
+                errors::HealthCheckDBError::SchemaOutOfDate
+            ))
+            .attach_printable(format!(
+                "Expected schema version {expected_version}, applied schema version {}",
+                applied_version.as_deref().unwrap_or("none")
+            ));
+        }
+
+        Ok(HealthState::Running)
+    }
+
     async fn health_check_redis(&self) -> CustomResult<HealthState, errors::HealthCheckRedisError> {
+        if self.conf.health_check.simulated_failures.redis {
+            logger::warn!("Reporting a simulated failure of the redis health check");
+            return Err(error_stack::report!(
+                errors::HealthCheckRedisError::SimulatedFailure
+            ));
+        }
         let db = &*self.store;
         let redis_conn = db
             .get_redis_conn()
             .change_context(errors::HealthCheckRedisError::RedisConnectionError)?;
+        let health_check_conf = &self.conf.health_check;
 
-        redis_conn
-            .serialize_and_set_key_with_expiry("test_key", "test_value", 30)
-            .await
-            .change_context(errors::HealthCheckRedisError::SetFailed)?;
-
-        logger::debug!("Redis set_key was successful");
-
-        redis_conn
-            .get_key("test_key")
-            .await
-            .change_context(errors::HealthCheckRedisError::GetFailed)?;
-
-        logger::debug!("Redis get_key was successful");
-
-        redis_conn
-            .delete_key("test_key")
-            .await
-            .change_context(errors::HealthCheckRedisError::DeleteFailed)?;
-
-        logger::debug!("Redis delete_key was successful");
+        // Retry the probe on failures so that a transient blip doesn't mark the instance as down,
+        // while bounding the retries by the overall probe timeout
+        let probe_with_retries = async {
+            let mut retries_left = health_check_conf.redis_retry_count;
+            loop {
+                match health_check_redis_round_trip(&redis_conn).await {
+                    Ok(()) => break Ok(()),
+                    Err(error) if retries_left > 0 => {
+                        retries_left -= 1;
+                        logger::warn!(?error, retries_left, "Redis health check failed, retrying");
+                        tokio::time::sleep(Duration::from_millis(
+                            health_check_conf.redis_retry_delay,
+                        ))
+                        .await;
+                    }
+                    Err(error) => break Err(error),
+                }
+            }
+        };
+        tokio::time::timeout(
+            Duration::from_millis(health_check_conf.redis_probe_timeout),
+            probe_with_retries,
+        )
+        .await
+        .map_err(|_| error_stack::report!(errors::HealthCheckRedisError::ProbeTimedOut))??;
 
         Ok(HealthState::Running)
     }
 
+    /// Calls the health endpoint of the locker, and additionally stores and retrieves synthetic
+    /// data when `round_trip` is set or the locker is configured to always do so.
     async fn health_check_locker(
         &self,
+        round_trip: bool,
     ) -> CustomResult<HealthState, errors::HealthCheckLockerError> {
+        if self.conf.health_check.simulated_failures.locker {
+            logger::warn!("Reporting a simulated failure of the locker health check");
+            return Err(error_stack::report!(
+                errors::HealthCheckLockerError::SimulatedFailure
+            ));
+        }
         let locker = &self.conf.locker;
         if !locker.mock_locker {
             let mut url = locker.host_rs.to_owned();
@@ -82,12 +157,62 @@ impl HealthCheckInterface for app::SessionState {
                 .map_err(|_| {
                     error_stack::report!(errors::HealthCheckLockerError::FailedToCallLocker)
                 })?;
+
+            if round_trip || locker.health_check_round_trip {
+                health_check_locker_round_trip(self).await?;
+            }
             Ok(HealthState::Running)
         } else {
             Ok(HealthState::NotApplicable)
         }
     }
 
+    async fn health_check_file_store(
+        &self,
+    ) -> CustomResult<HealthState, errors::HealthCheckFileStoreError> {
+        let health_check_conf = &self.conf.health_check;
+        if !health_check_conf.file_store_probe_enabled {
+            return Ok(HealthState::NotApplicable);
+        }
+
+        tokio::time::timeout(
+            Duration::from_millis(health_check_conf.file_store_probe_timeout),
+            self.store
+                .health_check_file_store(self.file_storage_client.as_ref()),
+        )
+        .await
+        .map_err(|_| error_stack::report!(errors::HealthCheckFileStoreError::ProbeTimedOut))??;
+
+        Ok(HealthState::Running)
+    }
+
+    async fn health_check_secrets_manager(
+        &self,
+    ) -> CustomResult<HealthState, errors::HealthCheckSecretsManagerError> {
+        let health_check_conf = &self.conf.health_check;
+        if !health_check_conf.secrets_manager_probe_enabled {
+            return Ok(HealthState::NotApplicable);
+        }
+        let probe_secret = health_check_conf
+            .secrets_manager_probe_secret
+            .as_deref()
+            .ok_or(errors::HealthCheckSecretsManagerError::ProbeSecretNotConfigured)?;
+
+        tokio::time::timeout(
+            Duration::from_millis(health_check_conf.secrets_manager_probe_timeout),
+            self.store.health_check_secrets_manager(
+                self.secrets_management_client.as_ref(),
+                probe_secret,
+            ),
+        )
+        .await
+        .map_err(|_| {
+            error_stack::report!(errors::HealthCheckSecretsManagerError::ProbeTimedOut)
+        })??;
+
+        Ok(HealthState::Running)
+    }
+
     #[cfg(feature = "olap")]
     async fn health_check_analytics(
         &self,
@@ -158,4 +283,119 @@ impl HealthCheckInterface for app::SessionState {
         logger::debug!("Outgoing request successful");
         Ok(HealthState::Running)
     }
+
+    /// Requests the base url of the connector. Any response shows that the connector is reachable,
+    /// whatever its status, as connectors do not serve their base url.
+    async fn health_check_connector(
+        &self,
+        connector: api_enums::Connector,
+    ) -> CustomResult<HealthState, errors::HealthCheckOutGoing> {
+        let connectors = &self.conf.connectors;
+        let base_url = api::ConnectorData::convert_connector(connectors, &connector.to_string())
+            .map_err(|err| errors::HealthCheckOutGoing::OutGoingFailed {
+                message: err.to_string(),
+            })?
+            .base_url(connectors)
+            .to_string();
+        let request = services::Request::new(services::Method::Get, &base_url);
+        services::call_connector_api(self, request, "connector_health_check")
+            .await
+            .map_err(|err| errors::HealthCheckOutGoing::OutGoingFailed {
+                message: err.to_string(),
+            })?;
+
+        logger::debug!(%connector, "Connector ping successful");
+        Ok(HealthState::Running)
+    }
+}
+
+/// Sets a test key in redis, reads it back and deletes it.
+async fn health_check_redis_round_trip(
+    redis_conn: &redis_interface::RedisConnectionPool,
+) -> CustomResult<(), errors::HealthCheckRedisError> {
+    redis_conn
+        .serialize_and_set_key_with_expiry("test_key", "test_value", 30)
+        .await
+        .change_context(errors::HealthCheckRedisError::SetFailed)?;
+
+    logger::debug!("Redis set_key was successful");
+
+    redis_conn
+        .get_key("test_key")
+        .await
+        .change_context(errors::HealthCheckRedisError::GetFailed)?;
+
+    logger::debug!("Redis get_key was successful");
+
+    redis_conn
+        .delete_key("test_key")
+        .await
+        .change_context(errors::HealthCheckRedisError::DeleteFailed)?;
+
+    logger::debug!("Redis delete_key was successful");
+
+    Ok(())
+}
+
+/// Stores synthetic data in the locker, reads it back and deletes it, to verify that the locker
+/// is able to encrypt and decrypt data and not just respond to requests.
+async fn health_check_locker_round_trip(
+    state: &app::SessionState,
+) -> CustomResult<(), errors::HealthCheckLockerError> {
+    let merchant_id = consts::LOCKER_HEALTH_CHECK_MERCHANT_ID;
+    let customer_id = id_type::CustomerId::from(consts::LOCKER_HEALTH_CHECK_CUSTOMER_ID.into())
+        .change_context(errors::HealthCheckLockerError::FailedToStoreData)?;
+    let data = generate_id(consts::ID_LENGTH, "health_check");
+
+    let store_request =
+        payment_methods::StoreLockerReq::LockerGeneric(payment_methods::StoreGenericReq {
+            merchant_id,
+            merchant_customer_id: customer_id.clone(),
+            enc_data: data.clone(),
+            ttl: consts::LOCKER_HEALTH_CHECK_DATA_TTL,
+        });
+    let stored_data = cards::call_to_locker_hs(
+        state,
+        &store_request,
+        &customer_id,
+        api_enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::HealthCheckLockerError::FailedToStoreData)?;
+
+    logger::debug!("Locker store was successful");
+
+    let retrieved_data = cards::get_enc_data_from_hs_locker(
+        state,
+        &customer_id,
+        merchant_id,
+        &stored_data.card_reference,
+        Some(api_enums::LockerChoice::HyperswitchCardVault),
+    )
+    .await
+    .change_context(errors::HealthCheckLockerError::FailedToRetrieveData);
+
+    // Delete the synthetic data irrespective of the retrieval outcome
+    let delete_result = cards::delete_card_from_hs_locker(
+        state,
+        &customer_id,
+        merchant_id,
+        &stored_data.card_reference,
+    )
+    .await
+    .change_context(errors::HealthCheckLockerError::FailedToDeleteData);
+
+    let retrieved_data = retrieved_data?;
+    logger::debug!("Locker retrieve was successful");
+
+    if retrieved_data.peek() != &data {
+        return Err(error_stack::report!(
+            errors::HealthCheckLockerError::RoundTripDataMismatch
+        ));
+    }
+
+    delete_result?;
+    logger::debug!("Locker delete was successful");
+
+    Ok(())
 }
@@ -1,25 +1,44 @@
+use std::{collections::HashSet, str::FromStr};
+
 use api_models::{admin as admin_types, payments::PaymentLinkStatusWrap};
 use common_utils::{
     consts::{
         DEFAULT_BACKGROUND_COLOR, DEFAULT_DISPLAY_SDK_ONLY, DEFAULT_ENABLE_SAVED_PAYMENT_METHOD,
         DEFAULT_MERCHANT_LOGO, DEFAULT_PRODUCT_IMG, DEFAULT_SDK_LAYOUT, DEFAULT_SESSION_EXPIRY,
+        PAYMENT_LINK_ALLOWED_FONT_FAMILIES, PAYMENT_LINK_GENERIC_FONT_FAMILIES,
+        PAYMENT_LINK_MAX_BORDER_RADIUS, PAYMENT_LINK_MAX_CUSTOM_FIELDS,
+        PAYMENT_LINK_MAX_CUSTOM_FIELD_KEY_LENGTH, PAYMENT_LINK_MAX_LOGO_DIMENSION,
+        PAYMENT_LINK_MAX_SUGGESTED_TIPS,
     },
-    ext_traits::{OptionExt, ValueExt},
+    ext_traits::{Encode, OptionExt, ValueExt},
+    pii,
+    types::MinorUnit,
 };
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use futures::future;
 use masking::{PeekInterface, Secret};
+use router_env::logger;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use storage_impl::redis::kv_store::RedisConnInterface;
 use time::PrimitiveDateTime;
 
-use super::errors::{self, RouterResult, StorageErrorExt};
+use super::{
+    errors::{self, RouterResult, StorageErrorExt},
+    payments::helpers as payments_helpers,
+};
 use crate::{
+    consts,
+    db::business_profile::BusinessProfileInterface,
     errors::RouterResponse,
     routes::SessionState,
     services,
     types::{
-        api::payment_link::PaymentLinkResponseExt, domain, storage::enums as storage_enums,
+        api::payment_link::PaymentLinkResponseExt,
+        domain,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
     },
+    utils::{self, currency},
 };
 
 pub async fn retrieve_payment_link(
@@ -37,7 +56,8 @@ pub async fn retrieve_payment_link(
             .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
     });
 
-    let status = check_payment_link_status(session_expiry);
+    let status =
+        check_payment_link_status(session_expiry, get_payment_link_expiry_grace_period(&state));
 
     let response = api_models::payments::RetrievePaymentLinkResponse::foreign_from((
         payment_link_config,
@@ -46,6 +66,44 @@ pub async fn retrieve_payment_link(
     Ok(services::ApplicationResponse::Json(response))
 }
 
+pub async fn retrieve_payment_link_by_payment_id(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_link_id = payment_intent.payment_link_id.ok_or_else(|| {
+        report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("No payment link is associated with the payment {payment_id}"),
+        })
+    })?;
+
+    retrieve_payment_link(state, payment_link_id).await
+}
+
+/// Misspelled name of [`initiate_payment_link_flow`], kept for one release for its callers
+#[deprecated(note = "use `initiate_payment_link_flow` instead")]
+pub async fn intiate_payment_link_flow(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    merchant_id: String,
+    payment_id: String,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    initiate_payment_link_flow(state, merchant_account, key_store, merchant_id, payment_id).await
+}
+
 pub async fn initiate_payment_link_flow(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
@@ -80,51 +138,35 @@ pub async fn initiate_payment_link_flow(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
-    let payment_link_config = if let Some(pl_config_value) = payment_link.payment_link_config {
-        extract_payment_link_config(pl_config_value)?
-    } else {
-        admin_types::PaymentLinkConfig {
-            theme: DEFAULT_BACKGROUND_COLOR.to_string(),
-            logo: DEFAULT_MERCHANT_LOGO.to_string(),
-            seller_name: merchant_name_from_merchant_account,
-            sdk_layout: DEFAULT_SDK_LAYOUT.to_owned(),
-            display_sdk_only: DEFAULT_DISPLAY_SDK_ONLY,
-            enabled_saved_payment_method: DEFAULT_ENABLE_SAVED_PAYMENT_METHOD,
-        }
-    };
-
-    let profile_id = payment_link
-        .profile_id
-        .or(payment_intent.profile_id)
-        .ok_or(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Profile id missing in payment link and payment intent")?;
-
-    let business_profile = db
-        .find_business_profile_by_profile_id(&profile_id)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-            id: profile_id.to_string(),
-        })?;
-
-    let return_url = if let Some(payment_create_return_url) = payment_intent.return_url.clone() {
-        payment_create_return_url
-    } else {
-        business_profile
-            .return_url
-            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
-                field_name: "return_url",
-            })?
-    };
+    let PaymentLinkRenderConfig {
+        payment_link_config,
+        return_url,
+    } = get_payment_link_render_config(
+        db,
+        &payment_link,
+        payment_intent.profile_id.clone(),
+        payment_intent.return_url.clone(),
+        merchant_name_from_merchant_account,
+    )
+    .await?;
+    let return_url = render_return_url_template(
+        &return_url,
+        &payment_intent.payment_id,
+        payment_intent.status,
+    )?;
+    validate_return_url(&return_url)?;
 
     let (pub_key, currency, client_secret) = validate_sdk_requirements(
         merchant_account.publishable_key,
         payment_intent.currency,
         payment_intent.client_secret.clone(),
     )?;
+    validate_display_currency(payment_link_config.display_currency, currency)?;
     let amount = currency
         .to_currency_base_unit(payment_intent.amount.get_amount_as_i64())
         .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)?;
-    let order_details = validate_order_details(payment_intent.order_details.clone(), currency)?;
+    let order_details =
+        validate_order_details(&state, payment_intent.order_details.clone(), currency).await?;
 
     let session_expiry = payment_link.fulfilment_time.unwrap_or_else(|| {
         payment_intent
@@ -135,7 +177,9 @@ pub async fn initiate_payment_link_flow(
     // converting first letter of merchant name to upperCase
     let merchant_name = capitalize_first_char(&payment_link_config.seller_name);
     let css_script = get_color_scheme_css(payment_link_config.clone());
-    let payment_link_status = check_payment_link_status(session_expiry);
+    // New sessions can not be started past the expiry of the payment link, the grace period only
+    // applies to payments which are already in progress
+    let payment_link_status = check_payment_link_status(session_expiry, time::Duration::ZERO);
 
     let is_terminal_state = check_payment_link_invalid_conditions(
         &payment_intent.status,
@@ -211,14 +255,18 @@ pub async fn initiate_payment_link_flow(
         return_url,
         session_expiry,
         pub_key,
-        client_secret,
+        client_secret: Secret::new(client_secret),
         merchant_logo: payment_link_config.logo.clone(),
+        logo_dimensions: payment_link_config.logo_dimensions.clone(),
         max_items_visible_after_collapse: 3,
         theme: payment_link_config.theme.clone(),
         merchant_description: payment_intent.description,
         sdk_layout: payment_link_config.sdk_layout.clone(),
         display_sdk_only: payment_link_config.display_sdk_only,
         enabled_saved_payment_method: payment_link_config.enabled_saved_payment_method,
+        custom_fields: payment_link_config.custom_fields.clone(),
+        adjustable_amount: payment_link_config.adjustable_amount.clone(),
+        allowed_payment_methods: payment_link_config.allowed_payment_methods.clone(),
     };
 
     let js_script = get_js_script(&api_models::payments::PaymentLinkData::PaymentLinkDetails(
@@ -238,6 +286,115 @@ pub async fn initiate_payment_link_flow(
     )))
 }
 
+/// Renders the payment link page for the sample payment details and configuration passed in the
+/// request, without creating a payment. The page is marked as a preview, carries no publishable
+/// key and a placeholder client secret, and does not initialize the SDK, so no payment can be
+/// made from it.
+pub async fn preview_payment_link(
+    state: SessionState,
+    req: api_models::payments::PaymentLinkPreviewRequest,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    let payment_link_config = req.payment_link_config;
+    validate_custom_fields_config(&payment_link_config.custom_fields)?;
+    payment_link_config
+        .logo_dimensions
+        .as_ref()
+        .map(validate_logo_dimensions_config)
+        .transpose()?;
+    payment_link_config
+        .adjustable_amount
+        .as_ref()
+        .map(validate_adjustable_amount_config)
+        .transpose()?;
+    payment_link_config
+        .allowed_payment_methods
+        .as_deref()
+        .map(validate_allowed_payment_methods_config)
+        .transpose()?;
+    if let Some(return_url) = &req.return_url {
+        validate_return_url(return_url)?;
+    }
+
+    let amount = req
+        .currency
+        .to_currency_base_unit(req.amount.get_amount_as_i64())
+        .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)?;
+    let order_details = req
+        .order_details
+        .map(|order_details| {
+            order_details
+                .iter()
+                .map(|order| order.encode_to_value().map(Secret::new))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the sample order details")?;
+    let order_details = validate_order_details(&state, order_details, req.currency).await?;
+
+    let css_script = get_color_scheme_css(payment_link_config.clone());
+    let payment_details = get_preview_payment_link_details(
+        &payment_link_config,
+        req.currency,
+        amount,
+        order_details,
+        req.description,
+        req.return_url,
+    );
+
+    let js_script = get_preview_js_script(&get_js_script(
+        &api_models::payments::PaymentLinkData::PaymentLinkDetails(&payment_details),
+    )?);
+
+    let html_meta_tags = get_meta_tags_html(payment_details);
+
+    let payment_link_data = services::PaymentLinkFormData {
+        js_script,
+        sdk_url: state.conf.payment_link.sdk_url.clone(),
+        css_script,
+        html_meta_tags,
+    };
+    Ok(services::ApplicationResponse::PaymentLinkForm(Box::new(
+        services::api::PaymentLinkAction::PaymentLinkFormData(payment_link_data),
+    )))
+}
+
+/// Returns the payment details rendered on the preview of a payment link page. The publishable key
+/// is left out and the client secret is a placeholder, so that the page cannot reach the payments
+/// API of the merchant.
+fn get_preview_payment_link_details(
+    payment_link_config: &admin_types::PaymentLinkConfig,
+    currency: api_models::enums::Currency,
+    amount: String,
+    order_details: Option<Vec<api_models::payments::OrderDetailsWithStringAmount>>,
+    description: Option<String>,
+    return_url: Option<String>,
+) -> api_models::payments::PaymentLinkDetails {
+    api_models::payments::PaymentLinkDetails {
+        amount,
+        currency,
+        payment_id: consts::PAYMENT_LINK_PREVIEW_PAYMENT_ID.to_string(),
+        merchant_name: capitalize_first_char(&payment_link_config.seller_name),
+        order_details,
+        return_url: return_url.unwrap_or_default(),
+        session_expiry: common_utils::date_time::now()
+            .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY)),
+        pub_key: String::new(),
+        client_secret: Secret::new(consts::PAYMENT_LINK_PREVIEW_CLIENT_SECRET.to_string()),
+        merchant_logo: payment_link_config.logo.clone(),
+        logo_dimensions: payment_link_config.logo_dimensions.clone(),
+        max_items_visible_after_collapse: 3,
+        theme: payment_link_config.theme.clone(),
+        merchant_description: description,
+        sdk_layout: payment_link_config.sdk_layout.clone(),
+        display_sdk_only: payment_link_config.display_sdk_only,
+        enabled_saved_payment_method: payment_link_config.enabled_saved_payment_method,
+        custom_fields: payment_link_config.custom_fields.clone(),
+        adjustable_amount: payment_link_config.adjustable_amount.clone(),
+        allowed_payment_methods: payment_link_config.allowed_payment_methods.clone(),
+    }
+}
+
 /*
 The get_js_script function is used to inject dynamic value to payment_link sdk, which is unique to every payment.
 */
@@ -251,15 +408,57 @@ fn get_js_script(
     Ok(format!("window.__PAYMENT_DETAILS = {payment_details_str};"))
 }
 
+/// Marks the payment link page as a preview, on which the page shows a preview banner in place of
+/// the SDK, which is not initialized with the placeholder client secret
+fn get_preview_js_script(js_script: &str) -> String {
+    format!("{js_script}\nwindow.__PAYMENT_LINK_PREVIEW = true;")
+}
+
 fn get_color_scheme_css(payment_link_config: api_models::admin::PaymentLinkConfig) -> String {
     let background_primary_color = payment_link_config.theme;
+    let mut css_variables = format!("--primary-color: {background_primary_color};");
+
+    // Only the allowlisted values are ever written into the stylesheet, anything else is dropped
+    // so that the defaults in the stylesheet apply
+    if let Some(font_family) = payment_link_config
+        .font_family
+        .as_deref()
+        .and_then(get_allowed_font_family)
+    {
+        css_variables.push_str(&format!(
+            "\n      --font-family: {};",
+            get_font_family_css_value(font_family)
+        ));
+    }
+    if let Some(border_radius) = payment_link_config
+        .border_radius
+        .filter(|border_radius| *border_radius <= PAYMENT_LINK_MAX_BORDER_RADIUS)
+    {
+        css_variables.push_str(&format!("\n      --border-radius: {border_radius}px;"));
+    }
+
     format!(
         ":root {{
-      --primary-color: {background_primary_color};
+      {css_variables}
     }}"
     )
 }
 
+fn get_allowed_font_family(font_family: &str) -> Option<&'static str> {
+    PAYMENT_LINK_ALLOWED_FONT_FAMILIES
+        .into_iter()
+        .find(|allowed_font_family| allowed_font_family.eq_ignore_ascii_case(font_family.trim()))
+}
+
+/// Generic font families are CSS keywords and would be read as a family name if quoted
+fn get_font_family_css_value(font_family: &str) -> String {
+    if PAYMENT_LINK_GENERIC_FONT_FAMILIES.contains(&font_family) {
+        font_family.to_string()
+    } else {
+        format!("\"{font_family}\"")
+    }
+}
+
 fn get_meta_tags_html(payment_details: api_models::payments::PaymentLinkDetails) -> String {
     format!(
         r#"<meta property="og:title" content="Payment request from {0}"/>
@@ -288,6 +487,25 @@ fn validate_sdk_requirements(
     Ok((pub_key, currency, client_secret))
 }
 
+/// Validates that the currency in which the payment link displays amounts is the currency of the
+/// payment, so that the customer is not shown amounts in a different currency than the one they
+/// are charged in.
+pub fn validate_display_currency(
+    display_currency: Option<api_models::enums::Currency>,
+    currency: api_models::enums::Currency,
+) -> Result<(), errors::ApiErrorResponse> {
+    match display_currency {
+        Some(display_currency) if display_currency != currency => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "payment_link_config.display_currency {display_currency} does not match the currency {currency} of the payment"
+                ),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 pub async fn list_payment_link(
     state: SessionState,
     merchant: domain::MerchantAccount,
@@ -299,32 +517,54 @@ pub async fn list_payment_link(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to retrieve payment link")?;
+    let grace_period = get_payment_link_expiry_grace_period(&state);
     let payment_link_list = future::try_join_all(payment_link.into_iter().map(|payment_link| {
-        api_models::payments::RetrievePaymentLinkResponse::from_db_payment_link(payment_link)
+        api_models::payments::RetrievePaymentLinkResponse::from_db_payment_link(
+            payment_link,
+            grace_period,
+        )
     }))
     .await?;
     Ok(services::ApplicationResponse::Json(payment_link_list))
 }
 
+/// Returns the configured duration past its expiry for which a payment link is reported as active.
+pub fn get_payment_link_expiry_grace_period(state: &SessionState) -> time::Duration {
+    time::Duration::seconds(i64::from(state.conf.payment_link.expiry_grace_period))
+}
+
 pub fn check_payment_link_status(
     payment_link_expiry: PrimitiveDateTime,
+    grace_period: time::Duration,
 ) -> api_models::payments::PaymentLinkStatus {
     let curr_time = common_utils::date_time::now();
 
-    if curr_time > payment_link_expiry {
+    if curr_time > payment_link_expiry.saturating_add(grace_period) {
         api_models::payments::PaymentLinkStatus::Expired
     } else {
         api_models::payments::PaymentLinkStatus::Active
     }
 }
 
-fn validate_order_details(
+/// Validates the order details of the payment and converts the amount of each product into the
+/// currency of the payment, for products whose amount is specified in a different currency.
+async fn validate_order_details(
+    state: &SessionState,
     order_details: Option<Vec<Secret<serde_json::Value>>>,
     currency: api_models::enums::Currency,
 ) -> Result<
     Option<Vec<api_models::payments::OrderDetailsWithStringAmount>>,
     error_stack::Report<errors::ApiErrorResponse>,
 > {
+    if let Some(order_details) = &order_details {
+        let max_order_details = state
+            .conf
+            .payment_link
+            .max_order_details
+            .unwrap_or(consts::DEFAULT_PAYMENT_LINK_MAX_ORDER_DETAILS);
+        validate_order_details_count(order_details.len(), max_order_details)?;
+    }
+
     let order_details = order_details
         .map(|order_details| {
             order_details
@@ -356,10 +596,23 @@ fn validate_order_details(
                         .product_img_link
                         .clone_from(&order.product_img_link)
                 };
-                order_details_amount_string.amount =
-                    currency
-                        .to_currency_base_unit(order.amount)
-                        .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)?;
+                let amount = match order.currency {
+                    Some(order_currency) if order_currency != currency => {
+                        order_details_amount_string.original_amount = Some(
+                            order_currency
+                                .to_currency_base_unit(order.amount)
+                                .change_context(
+                                    errors::ApiErrorResponse::CurrencyConversionFailed,
+                                )?,
+                        );
+                        order_details_amount_string.original_currency = Some(order_currency);
+                        convert_order_amount(state, order.amount, order_currency, currency).await?
+                    }
+                    _ => order.amount,
+                };
+                order_details_amount_string.amount = currency
+                    .to_currency_base_unit(amount)
+                    .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)?;
                 order_details_amount_string.product_name =
                     capitalize_first_char(&order.product_name.clone());
                 order_details_amount_string.quantity = order.quantity;
@@ -372,6 +625,69 @@ fn validate_order_details(
     Ok(updated_order_details)
 }
 
+fn validate_order_details_count(count: usize, max_order_details: usize) -> RouterResult<()> {
+    utils::when(count > max_order_details, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "order_details has {count} entries, a payment link can have at most \
+                 {max_order_details}"
+            ),
+        }))
+    })
+}
+
+async fn convert_order_amount(
+    state: &SessionState,
+    amount: i64,
+    from_currency: api_models::enums::Currency,
+    to_currency: api_models::enums::Currency,
+) -> RouterResult<i64> {
+    let conversion = Box::pin(currency::convert_currency(
+        state.clone(),
+        amount,
+        to_currency.to_string(),
+        from_currency.to_string(),
+        consts::DEFAULT_FOREX_RATE_PRECISION,
+    ))
+    .await
+    .map_err(|error| match error.current_context() {
+        currency::ForexCacheError::CurrencyNotAcceptable
+        | currency::ForexCacheError::ConversionError => {
+            error.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "unknown currency {from_currency} in order details, unable to convert it to \
+                     {to_currency}"
+                ),
+            })
+        }
+        currency::ForexCacheError::CurrencyNotConfigured(_) => {
+            let message = error.current_context().to_string();
+            error.change_context(errors::ApiErrorResponse::InvalidRequestData { message })
+        }
+        _ => error.change_context(errors::ApiErrorResponse::CurrencyConversionFailed),
+    })?;
+    get_order_amount_in_minor_units(&conversion.converted_amount, to_currency)
+}
+
+/// Converts an amount returned by a currency conversion, which is in the major unit of the
+/// currency, into the minor unit of the currency
+fn get_order_amount_in_minor_units(
+    converted_amount: &str,
+    currency: api_models::enums::Currency,
+) -> RouterResult<i64> {
+    let converted_amount = converted_amount
+        .parse::<Decimal>()
+        .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)
+        .attach_printable("Failed to parse the converted order details amount")?;
+    let minor_unit_factor =
+        Decimal::from(10_i64.pow(u32::from(currency.number_of_digits_after_decimal_point())));
+    converted_amount
+        .checked_mul(minor_unit_factor)
+        .and_then(|converted_amount| converted_amount.round().to_i64())
+        .ok_or(errors::ApiErrorResponse::CurrencyConversionFailed)
+        .attach_printable("Converted order details amount is out of range")
+}
+
 pub fn extract_payment_link_config(
     pl_config: serde_json::Value,
 ) -> Result<api_models::admin::PaymentLinkConfig, error_stack::Report<errors::ApiErrorResponse>> {
@@ -381,6 +697,147 @@ pub fn extract_payment_link_config(
         })
 }
 
+/// The config and the return url a payment link page is rendered with
+#[derive(Debug)]
+struct PaymentLinkRenderConfig {
+    payment_link_config: admin_types::PaymentLinkConfig,
+    return_url: String,
+}
+
+/// Resolves the config and the return url of a payment link page from a single read of the
+/// business profile, so that a render racing with an update of the profile uses either the old or
+/// the new profile in full, never the config of one with the return url of the other. The config
+/// stored against the payment link when it was created takes priority over the config of the
+/// profile, as does the return url of the payment over the one of the profile.
+///
+/// Payment links created without a stored config are rendered with the payment link config of the
+/// profile, falling back to the default config for the fields the profile does not configure.
+async fn get_payment_link_render_config<D>(
+    db: &D,
+    payment_link: &storage::PaymentLink,
+    payment_profile_id: Option<String>,
+    payment_return_url: Option<String>,
+    merchant_name: String,
+) -> RouterResult<PaymentLinkRenderConfig>
+where
+    D: BusinessProfileInterface + RedisConnInterface + Sync + ?Sized,
+{
+    let profile_id = payment_link
+        .profile_id
+        .clone()
+        .or(payment_profile_id)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile id missing in payment link and payment intent")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    let payment_link_config =
+        get_cached_payment_link_config(db, payment_link, &business_profile, merchant_name).await?;
+
+    let return_url = payment_return_url.or(business_profile.return_url).ok_or(
+        errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "return_url",
+        },
+    )?;
+
+    Ok(PaymentLinkRenderConfig {
+        payment_link_config,
+        return_url,
+    })
+}
+
+/// A payment link config resolved for rendering, cached along with the version of the business
+/// profile it was resolved with
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CachedPaymentLinkConfig {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    profile_modified_at: PrimitiveDateTime,
+    payment_link_config: admin_types::PaymentLinkConfig,
+}
+
+fn get_payment_link_config_cache_key(payment_link_id: &str) -> String {
+    format!("payment_link_config_{payment_link_id}")
+}
+
+/// Resolves the config a payment link page is rendered with, caching it in redis per payment link
+/// for a short while as it is resolved on every render of the payment link.
+///
+/// Cached configs are stamped with the `modified_at` of the business profile they were resolved
+/// with, which changes whenever the payment link config of the profile is updated. A config cached
+/// before an update of the profile is therefore treated as a cache miss and resolved again.
+/// Failures while reading or writing the cache are logged and the config is resolved without it.
+async fn get_cached_payment_link_config<D>(
+    db: &D,
+    payment_link: &storage::PaymentLink,
+    business_profile: &storage::BusinessProfile,
+    merchant_name: String,
+) -> RouterResult<admin_types::PaymentLinkConfig>
+where
+    D: RedisConnInterface + Sync + ?Sized,
+{
+    let key = get_payment_link_config_cache_key(&payment_link.payment_link_id);
+    let redis_conn = db
+        .get_redis_conn()
+        .map_err(|error| {
+            logger::warn!(
+                ?error,
+                "Failed to get redis connection for payment link config"
+            )
+        })
+        .ok();
+
+    if let Some(redis_conn) = &redis_conn {
+        match redis_conn
+            .get_and_deserialize_key::<CachedPaymentLinkConfig>(&key, "CachedPaymentLinkConfig")
+            .await
+        {
+            Ok(cached_config)
+                if cached_config.profile_modified_at == business_profile.modified_at =>
+            {
+                return Ok(cached_config.payment_link_config)
+            }
+            Ok(_) => logger::debug!("Cached payment link config is stale"),
+            Err(error) => logger::debug!(?error, "Payment link config not found in cache"),
+        }
+    }
+
+    let payment_link_config = match payment_link.payment_link_config.clone() {
+        Some(pl_config_value) => extract_payment_link_config(pl_config_value)?,
+        // The domain name is only needed when a payment link is created
+        None => {
+            get_payment_link_config_based_on_priority(
+                None,
+                business_profile.payment_link_config.clone(),
+                merchant_name,
+                String::new(),
+            )?
+            .0
+        }
+    };
+
+    if let Some(redis_conn) = redis_conn {
+        let cached_config = CachedPaymentLinkConfig {
+            profile_modified_at: business_profile.modified_at,
+            payment_link_config: payment_link_config.clone(),
+        };
+        redis_conn
+            .serialize_and_set_key_with_expiry(
+                &key,
+                cached_config,
+                consts::PAYMENT_LINK_CONFIG_CACHE_TTL,
+            )
+            .await
+            .map_err(|error| logger::warn!(?error, "Failed to cache payment link config"))
+            .ok();
+    }
+    Ok(payment_link_config)
+}
+
 pub fn get_payment_link_config_based_on_priority(
     payment_create_link_config: Option<api_models::payments::PaymentCreatePaymentLinkConfig>,
     business_link_config: Option<serde_json::Value>,
@@ -470,18 +927,431 @@ pub fn get_payment_link_config_based_on_priority(
         })
         .unwrap_or(DEFAULT_ENABLE_SAVED_PAYMENT_METHOD);
 
+    let font_family = payment_create_link_config
+        .as_ref()
+        .and_then(|pc_config| pc_config.config.font_family.clone())
+        .or_else(|| {
+            business_config
+                .as_ref()
+                .and_then(|business_config| business_config.font_family.clone())
+        });
+
+    let border_radius = payment_create_link_config
+        .as_ref()
+        .and_then(|pc_config| pc_config.config.border_radius)
+        .or_else(|| {
+            business_config
+                .as_ref()
+                .and_then(|business_config| business_config.border_radius)
+        });
+
+    let logo_dimensions = payment_create_link_config
+        .as_ref()
+        .and_then(|pc_config| pc_config.config.logo_dimensions.clone())
+        .or_else(|| {
+            business_config
+                .as_ref()
+                .and_then(|business_config| business_config.logo_dimensions.clone())
+        });
+    logo_dimensions
+        .as_ref()
+        .map(validate_logo_dimensions_config)
+        .transpose()?;
+
+    let custom_fields = payment_create_link_config
+        .as_ref()
+        .and_then(|pc_config| pc_config.config.custom_fields.clone())
+        .or_else(|| {
+            business_config
+                .as_ref()
+                .and_then(|business_config| business_config.custom_fields.clone())
+        })
+        .unwrap_or_default();
+    validate_custom_fields_config(&custom_fields)?;
+
+    let adjustable_amount = payment_create_link_config
+        .as_ref()
+        .and_then(|pc_config| pc_config.config.adjustable_amount.clone())
+        .or_else(|| {
+            business_config
+                .as_ref()
+                .and_then(|business_config| business_config.adjustable_amount.clone())
+        });
+    adjustable_amount
+        .as_ref()
+        .map(validate_adjustable_amount_config)
+        .transpose()?;
+
+    let allowed_payment_methods = payment_create_link_config
+        .as_ref()
+        .and_then(|pc_config| pc_config.config.allowed_payment_methods.clone())
+        .or_else(|| {
+            business_config
+                .as_ref()
+                .and_then(|business_config| business_config.allowed_payment_methods.clone())
+        });
+    allowed_payment_methods
+        .as_deref()
+        .map(validate_allowed_payment_methods_config)
+        .transpose()?;
+
+    let display_currency = payment_create_link_config
+        .as_ref()
+        .and_then(|pc_config| pc_config.config.display_currency)
+        .or_else(|| {
+            business_config
+                .as_ref()
+                .and_then(|business_config| business_config.display_currency)
+        });
+
     let payment_link_config = admin_types::PaymentLinkConfig {
         theme,
         logo,
+        logo_dimensions,
         seller_name,
         sdk_layout,
         display_sdk_only,
         enabled_saved_payment_method,
+        font_family,
+        border_radius,
+        custom_fields,
+        adjustable_amount,
+        allowed_payment_methods,
+        display_currency,
     };
 
     Ok((payment_link_config, domain_name))
 }
 
+fn validate_allowed_payment_methods_config(
+    allowed_payment_methods: &[storage_enums::PaymentMethod],
+) -> RouterResult<()> {
+    utils::when(allowed_payment_methods.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "allowed_payment_methods must not be empty".to_string(),
+        })
+    })?;
+    let mut payment_methods = HashSet::new();
+    utils::when(
+        !allowed_payment_methods
+            .iter()
+            .all(|payment_method| payment_methods.insert(payment_method)),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "allowed_payment_methods must not contain duplicates".to_string(),
+            })
+        },
+    )
+}
+
+/// Validates that the payment methods accepted on a payment link are enabled on at least one of
+/// the payment connectors of the business profile
+pub async fn validate_allowed_payment_methods_enabled(
+    state: &SessionState,
+    merchant_id: &str,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+    allowed_payment_methods: &[storage_enums::PaymentMethod],
+) -> RouterResult<()> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_connector_accounts = payments_helpers::filter_mca_based_on_connector_type(
+        payments_helpers::filter_mca_based_on_business_profile(
+            merchant_connector_accounts,
+            Some(profile_id.to_owned()),
+        ),
+        storage_enums::ConnectorType::PaymentProcessor,
+    );
+
+    let enabled_payment_methods = merchant_connector_accounts
+        .iter()
+        .filter_map(|mca| mca.payment_methods_enabled.as_ref())
+        .flatten()
+        .filter_map(|payment_methods_enabled| {
+            serde_json::from_value::<admin_types::PaymentMethodsEnabled>(
+                payment_methods_enabled.clone(),
+            )
+            .ok()
+        })
+        .map(|payment_methods_enabled| payment_methods_enabled.payment_method)
+        .collect::<HashSet<_>>();
+
+    validate_allowed_payment_methods(allowed_payment_methods, &enabled_payment_methods)
+}
+
+fn validate_allowed_payment_methods(
+    allowed_payment_methods: &[storage_enums::PaymentMethod],
+    enabled_payment_methods: &HashSet<storage_enums::PaymentMethod>,
+) -> RouterResult<()> {
+    let disabled_payment_methods = allowed_payment_methods
+        .iter()
+        .filter(|payment_method| !enabled_payment_methods.contains(payment_method))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    utils::when(!disabled_payment_methods.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payment methods {} are not enabled for the merchant",
+                disabled_payment_methods.join(", ")
+            ),
+        })
+    })
+}
+
+/// Validates that the payment method used to pay through a payment link is accepted on it
+pub async fn validate_payment_link_payment_method(
+    state: &SessionState,
+    payment_link_id: &str,
+    payment_method: storage_enums::PaymentMethod,
+) -> RouterResult<()> {
+    let payment_link = state
+        .store
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    let allowed_payment_methods = payment_link
+        .payment_link_config
+        .map(extract_payment_link_config)
+        .transpose()?
+        .and_then(|payment_link_config| payment_link_config.allowed_payment_methods);
+
+    match allowed_payment_methods {
+        Some(allowed_payment_methods) => {
+            utils::when(!allowed_payment_methods.contains(&payment_method), || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "payment method {payment_method} is not accepted on this payment link"
+                    ),
+                })
+            })
+        }
+        None => Ok(()),
+    }
+}
+
+fn validate_logo_dimensions_config(
+    logo_dimensions: &admin_types::PaymentLinkLogoDimensions,
+) -> RouterResult<()> {
+    utils::when(
+        [logo_dimensions.width, logo_dimensions.height]
+            .into_iter()
+            .flatten()
+            .any(|dimension| dimension == 0 || dimension > PAYMENT_LINK_MAX_LOGO_DIMENSION),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "width and height of the logo must be between 1 and \
+                     {PAYMENT_LINK_MAX_LOGO_DIMENSION} px"
+                ),
+            })
+        },
+    )?;
+    Ok(())
+}
+
+fn validate_adjustable_amount_config(
+    adjustable_amount: &admin_types::PaymentLinkAdjustableAmount,
+) -> RouterResult<()> {
+    utils::when(
+        adjustable_amount.min_amount <= MinorUnit::zero()
+            || adjustable_amount.min_amount > adjustable_amount.max_amount,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "min_amount of the adjustable amount must be positive and at most its \
+                          max_amount"
+                    .to_string(),
+            })
+        },
+    )?;
+    utils::when(
+        adjustable_amount.suggested_tips.len() > PAYMENT_LINK_MAX_SUGGESTED_TIPS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "at most {PAYMENT_LINK_MAX_SUGGESTED_TIPS} tips can be suggested on a payment \
+                     link"
+                ),
+            })
+        },
+    )?;
+    utils::when(
+        adjustable_amount
+            .suggested_tips
+            .iter()
+            .any(|tip| *tip <= MinorUnit::zero()),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "suggested tips must be positive amounts".to_string(),
+            })
+        },
+    )?;
+    Ok(())
+}
+
+/// Validates that the amount of a payment is within the bounds within which the customer can
+/// adjust it on the payment link
+pub fn validate_adjusted_amount(
+    adjustable_amount: &admin_types::PaymentLinkAdjustableAmount,
+    amount: MinorUnit,
+) -> RouterResult<()> {
+    utils::when(
+        amount < adjustable_amount.min_amount || amount > adjustable_amount.max_amount,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "amount must be between {} and {}",
+                    adjustable_amount.min_amount.get_amount_as_i64(),
+                    adjustable_amount.max_amount.get_amount_as_i64()
+                ),
+            })
+        },
+    )
+}
+
+/// Returns the amount submitted by the customer on the payment link of a payment, if the payment
+/// link allows adjusting the amount, and fails if it is outside the configured bounds.
+pub async fn get_payment_link_adjusted_amount(
+    state: &SessionState,
+    payment_link_id: &str,
+    amount: MinorUnit,
+) -> RouterResult<Option<MinorUnit>> {
+    let payment_link = state
+        .store
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    let adjustable_amount = payment_link
+        .payment_link_config
+        .map(extract_payment_link_config)
+        .transpose()?
+        .and_then(|payment_link_config| payment_link_config.adjustable_amount);
+
+    adjustable_amount
+        .map(|adjustable_amount| {
+            validate_adjusted_amount(&adjustable_amount, amount).map(|()| amount)
+        })
+        .transpose()
+}
+
+fn validate_custom_fields_config(
+    custom_fields: &[admin_types::PaymentLinkCustomField],
+) -> RouterResult<()> {
+    utils::when(custom_fields.len() > PAYMENT_LINK_MAX_CUSTOM_FIELDS, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "at most {PAYMENT_LINK_MAX_CUSTOM_FIELDS} custom fields can be configured for a \
+                 payment link"
+            ),
+        })
+    })?;
+
+    let mut keys = HashSet::new();
+    for custom_field in custom_fields {
+        let is_valid_key = !custom_field.key.is_empty()
+            && custom_field.key.len() <= PAYMENT_LINK_MAX_CUSTOM_FIELD_KEY_LENGTH
+            && custom_field
+                .key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        utils::when(!is_valid_key, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "custom field key `{}` must be at most \
+                     {PAYMENT_LINK_MAX_CUSTOM_FIELD_KEY_LENGTH} characters consisting of \
+                     alphanumerics or '_'",
+                    custom_field.key
+                ),
+            })
+        })?;
+        utils::when(custom_field.label.trim().is_empty(), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("custom field `{}` must have a label", custom_field.key),
+            })
+        })?;
+        utils::when(!keys.insert(custom_field.key.as_str()), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("custom field key `{}` is duplicated", custom_field.key),
+            })
+        })?;
+    }
+    Ok(())
+}
+
+/// Validates the values of the custom fields collected on the payment link of a payment, which
+/// are submitted in the metadata of the payment under the key of each field.
+pub async fn validate_payment_link_custom_field_values(
+    state: &SessionState,
+    payment_link_id: &str,
+    metadata: Option<&serde_json::Value>,
+) -> RouterResult<()> {
+    let payment_link = state
+        .store
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    let custom_fields = payment_link
+        .payment_link_config
+        .map(extract_payment_link_config)
+        .transpose()?
+        .map(|payment_link_config| payment_link_config.custom_fields)
+        .unwrap_or_default();
+
+    validate_custom_field_values(&custom_fields, metadata)
+}
+
+fn validate_custom_field_values(
+    custom_fields: &[admin_types::PaymentLinkCustomField],
+    metadata: Option<&serde_json::Value>,
+) -> RouterResult<()> {
+    for custom_field in custom_fields {
+        let value = metadata
+            .and_then(|metadata| metadata.get(&custom_field.key))
+            .filter(|value| match value {
+                serde_json::Value::Null => false,
+                serde_json::Value::String(value) => !value.trim().is_empty(),
+                _ => true,
+            });
+        let Some(value) = value else {
+            utils::when(custom_field.required, || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("`{}` is required", custom_field.label),
+                })
+            })?;
+            continue;
+        };
+
+        let is_valid_value = match custom_field.field_type {
+            admin_types::PaymentLinkCustomFieldType::Text => value.is_string(),
+            admin_types::PaymentLinkCustomFieldType::Number => {
+                value.is_number()
+                    || value
+                        .as_str()
+                        .is_some_and(|value| value.trim().parse::<f64>().is_ok())
+            }
+            admin_types::PaymentLinkCustomFieldType::Email => value
+                .as_str()
+                .is_some_and(|value| pii::Email::from_str(value).is_ok()),
+        };
+        utils::when(!is_valid_value, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`{}` must be a valid {}",
+                    custom_field.label, custom_field.field_type
+                ),
+            })
+        })?;
+    }
+    Ok(())
+}
+
 fn capitalize_first_char(s: &str) -> String {
     if let Some(first_char) = s.chars().next() {
         let capitalized = first_char.to_uppercase();
@@ -495,6 +1365,86 @@ fn capitalize_first_char(s: &str) -> String {
     }
 }
 
+/*
+Merchants can configure templated return URLs such as `https://merchant.com/orders/{payment_id}?status={status}`.
+The placeholders are substituted at redirect time, and the rendered URL is required to stay on the
+same origin as the configured template so that the substituted values cannot be used for open redirects.
+*/
+fn render_return_url_template(
+    return_url: &str,
+    payment_id: &str,
+    status: storage_enums::IntentStatus,
+) -> RouterResult<String> {
+    if !return_url.contains('{') {
+        return Ok(return_url.to_owned());
+    }
+
+    let invalid_template = || errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("return_url template '{return_url}' is invalid"),
+    };
+
+    // The scheme and authority of the template must be static, only the path, query and fragment
+    // can carry placeholders
+    let (scheme, rest) = return_url.split_once("://").ok_or_else(invalid_template)?;
+    let (authority, template) = rest.split_at(rest.find(['/', '?', '#']).unwrap_or(rest.len()));
+    let base = format!("{scheme}://{authority}");
+    if base.contains(['{', '}']) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "return_url template cannot contain placeholders in the host".to_string(),
+        }
+        .into());
+    }
+    let configured_url = url::Url::parse(&base)
+        .change_context(invalid_template())
+        .attach_printable("Failed to parse the origin of the return_url template")?;
+
+    let mut rendered = base;
+    let mut remaining = template;
+    while let Some((prefix, rest)) = remaining.split_once('{') {
+        let (placeholder, rest) = rest.split_once('}').ok_or_else(invalid_template)?;
+        let value = match placeholder {
+            "payment_id" => payment_id.to_owned(),
+            "status" => status.to_string(),
+            placeholder => {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Unsupported placeholder '{{{placeholder}}}' in return_url"),
+                }
+                .into())
+            }
+        };
+        rendered.push_str(prefix);
+        rendered.extend(url::form_urlencoded::byte_serialize(value.as_bytes()));
+        remaining = rest;
+    }
+    rendered.push_str(remaining);
+
+    let rendered_url = url::Url::parse(&rendered)
+        .change_context(invalid_template())
+        .attach_printable("Failed to parse the rendered return_url")?;
+    utils::when(rendered_url.origin() != configured_url.origin(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "return_url template must redirect to the configured host".to_string(),
+        })
+    })?;
+
+    Ok(rendered_url.to_string())
+}
+
+/// The customer is redirected to the return URL once the payment completes, so it is required to
+/// be an absolute https URL. This rejects `javascript:`, `data:` and plain http redirects.
+fn validate_return_url(return_url: &str) -> RouterResult<()> {
+    let invalid_return_url = || errors::ApiErrorResponse::InvalidRequestData {
+        message: "return_url must be an absolute https URL".to_string(),
+    };
+    let url = url::Url::parse(return_url)
+        .change_context(invalid_return_url())
+        .attach_printable("Failed to parse the return_url")?;
+    utils::when(url.scheme() != "https" || url.host().is_none(), || {
+        Err(invalid_return_url())
+    })?;
+    Ok(())
+}
+
 fn check_payment_link_invalid_conditions(
     intent_status: &storage_enums::IntentStatus,
     not_allowed_statuses: &[storage_enums::IntentStatus],
@@ -547,18 +1497,17 @@ pub async fn get_payment_link_status(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
-    let payment_link_config = if let Some(pl_config_value) = payment_link.payment_link_config {
-        extract_payment_link_config(pl_config_value)?
-    } else {
-        admin_types::PaymentLinkConfig {
-            theme: DEFAULT_BACKGROUND_COLOR.to_string(),
-            logo: DEFAULT_MERCHANT_LOGO.to_string(),
-            seller_name: merchant_name_from_merchant_account,
-            sdk_layout: DEFAULT_SDK_LAYOUT.to_owned(),
-            display_sdk_only: DEFAULT_DISPLAY_SDK_ONLY,
-            enabled_saved_payment_method: DEFAULT_ENABLE_SAVED_PAYMENT_METHOD,
-        }
-    };
+    let PaymentLinkRenderConfig {
+        payment_link_config,
+        return_url,
+    } = get_payment_link_render_config(
+        db,
+        &payment_link,
+        payment_intent.profile_id.clone(),
+        payment_intent.return_url.clone(),
+        merchant_name_from_merchant_account,
+    )
+    .await?;
 
     let currency =
         payment_intent
@@ -575,28 +1524,12 @@ pub async fn get_payment_link_status(
     let merchant_name = capitalize_first_char(&payment_link_config.seller_name);
     let css_script = get_color_scheme_css(payment_link_config.clone());
 
-    let profile_id = payment_link
-        .profile_id
-        .or(payment_intent.profile_id)
-        .ok_or(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Profile id missing in payment link and payment intent")?;
-
-    let business_profile = db
-        .find_business_profile_by_profile_id(&profile_id)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-            id: profile_id.to_string(),
-        })?;
-
-    let return_url = if let Some(payment_create_return_url) = payment_intent.return_url.clone() {
-        payment_create_return_url
-    } else {
-        business_profile
-            .return_url
-            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
-                field_name: "return_url",
-            })?
-    };
+    let return_url = render_return_url_template(
+        &return_url,
+        &payment_intent.payment_id,
+        payment_intent.status,
+    )?;
+    validate_return_url(&return_url)?;
 
     let payment_details = api_models::payments::PaymentLinkStatusDetails {
         amount,
@@ -623,3 +1556,656 @@ pub async fn get_payment_link_status(
         services::api::PaymentLinkAction::PaymentLinkStatus(payment_link_status_data),
     )))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::db::MockDb;
+
+    fn get_business_profile_update(
+        theme: &str,
+        return_url: &str,
+    ) -> storage::BusinessProfileUpdate {
+        storage::BusinessProfileUpdate::Update {
+            profile_name: None,
+            modified_at: Some(common_utils::date_time::now()),
+            return_url: Some(return_url.to_string()),
+            enable_payment_response_hash: None,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: None,
+            webhook_details: None,
+            metadata: None,
+            routing_algorithm: None,
+            intent_fulfillment_time: None,
+            frm_routing_algorithm: None,
+            payout_routing_algorithm: None,
+            is_recon_enabled: None,
+            applepay_verified_domains: None,
+            payment_link_config: Some(serde_json::json!({ "theme": theme })),
+            session_expiry: None,
+            authentication_connector_details: None,
+            payout_link_config: None,
+            extended_card_info_config: None,
+            use_billing_as_payment_method_billing: None,
+            collect_shipping_details_from_wallet_connector: None,
+            is_connector_agnostic_mit_enabled: None,
+        }
+    }
+
+    /// Applies an update of the business profile right after the profile is read, as if the
+    /// update had been made concurrently with the read. Any later read of the profile by the same
+    /// render then sees the updated profile.
+    struct ProfileUpdatedAfterReadDb {
+        db: MockDb,
+        pending_update: std::sync::Mutex<Option<storage::BusinessProfileUpdate>>,
+    }
+
+    #[async_trait::async_trait]
+    impl BusinessProfileInterface for ProfileUpdatedAfterReadDb {
+        async fn insert_business_profile(
+            &self,
+            business_profile: storage::BusinessProfileNew,
+        ) -> errors::CustomResult<storage::BusinessProfile, errors::StorageError> {
+            self.db.insert_business_profile(business_profile).await
+        }
+
+        async fn find_business_profile_by_profile_id(
+            &self,
+            profile_id: &str,
+        ) -> errors::CustomResult<storage::BusinessProfile, errors::StorageError> {
+            let business_profile = self
+                .db
+                .find_business_profile_by_profile_id(profile_id)
+                .await?;
+            let pending_update = self.pending_update.lock().unwrap().take();
+            if let Some(business_profile_update) = pending_update {
+                self.db
+                    .update_business_profile_by_profile_id(
+                        business_profile.clone(),
+                        business_profile_update,
+                    )
+                    .await?;
+            }
+            Ok(business_profile)
+        }
+
+        async fn find_business_profile_by_profile_name_merchant_id(
+            &self,
+            profile_name: &str,
+            merchant_id: &str,
+        ) -> errors::CustomResult<storage::BusinessProfile, errors::StorageError> {
+            self.db
+                .find_business_profile_by_profile_name_merchant_id(profile_name, merchant_id)
+                .await
+        }
+
+        async fn update_business_profile_by_profile_id(
+            &self,
+            current_state: storage::BusinessProfile,
+            business_profile_update: storage::BusinessProfileUpdate,
+        ) -> errors::CustomResult<storage::BusinessProfile, errors::StorageError> {
+            self.db
+                .update_business_profile_by_profile_id(current_state, business_profile_update)
+                .await
+        }
+
+        async fn delete_business_profile_by_profile_id_merchant_id(
+            &self,
+            profile_id: &str,
+            merchant_id: &str,
+        ) -> errors::CustomResult<bool, errors::StorageError> {
+            self.db
+                .delete_business_profile_by_profile_id_merchant_id(profile_id, merchant_id)
+                .await
+        }
+
+        async fn list_business_profile_by_merchant_id(
+            &self,
+            merchant_id: &str,
+        ) -> errors::CustomResult<Vec<storage::BusinessProfile>, errors::StorageError> {
+            self.db
+                .list_business_profile_by_merchant_id(merchant_id)
+                .await
+        }
+    }
+
+    impl RedisConnInterface for ProfileUpdatedAfterReadDb {
+        fn get_redis_conn(
+            &self,
+        ) -> error_stack::Result<
+            std::sync::Arc<redis_interface::RedisConnectionPool>,
+            redis_interface::errors::RedisError,
+        > {
+            self.db.get_redis_conn()
+        }
+    }
+
+    fn get_business_profile_new(
+        profile_id: &str,
+        payment_link_config: Option<serde_json::Value>,
+    ) -> storage::BusinessProfileNew {
+        let now = common_utils::date_time::now();
+        storage::BusinessProfileNew {
+            profile_id: profile_id.to_string(),
+            merchant_id: "merchant_123".to_string(),
+            profile_name: profile_id.to_string(),
+            created_at: now,
+            modified_at: now,
+            return_url: Some("https://old.example.com".to_string()),
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            webhook_details: None,
+            metadata: None,
+            routing_algorithm: None,
+            intent_fulfillment_time: None,
+            frm_routing_algorithm: None,
+            payout_routing_algorithm: None,
+            is_recon_enabled: false,
+            applepay_verified_domains: None,
+            payment_link_config,
+            session_expiry: None,
+            authentication_connector_details: None,
+            payout_link_config: None,
+            is_extended_card_info_enabled: None,
+            extended_card_info_config: None,
+            is_connector_agnostic_mit_enabled: None,
+            use_billing_as_payment_method_billing: None,
+            collect_shipping_details_from_wallet_connector: None,
+        }
+    }
+
+    fn get_payment_link(payment_link_id: &str, profile_id: &str) -> storage::PaymentLink {
+        let now = common_utils::date_time::now();
+        storage::PaymentLink {
+            payment_link_id: payment_link_id.to_string(),
+            payment_id: "pay_123".to_string(),
+            link_to_pay: "https://example.com/payment_link/merchant_123/pay_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            amount: MinorUnit::new(1000),
+            currency: Some(storage_enums::Currency::USD),
+            created_at: now,
+            last_modified_at: now,
+            fulfilment_time: None,
+            custom_merchant_name: None,
+            payment_link_config: None,
+            description: None,
+            profile_id: Some(profile_id.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_payment_link_render_config_is_consistent_under_concurrent_profile_update() {
+        let db = ProfileUpdatedAfterReadDb {
+            db: MockDb::new(&redis_interface::RedisSettings::default())
+                .await
+                .unwrap(),
+            pending_update: std::sync::Mutex::new(None),
+        };
+        let profile_id = "pro_123".to_string();
+        db.insert_business_profile(get_business_profile_new(
+            &profile_id,
+            Some(serde_json::json!({ "theme": "#000000" })),
+        ))
+        .await
+        .unwrap();
+        let payment_link = get_payment_link("plink_123", &profile_id);
+
+        // The profile is updated right after the render reads it. A render reading the profile
+        // again for the return url would pair the old theme with the new return url.
+        *db.pending_update.lock().unwrap() = Some(get_business_profile_update(
+            "#ffffff",
+            "https://new.example.com",
+        ));
+        let render_config =
+            get_payment_link_render_config(&db, &payment_link, None, None, "merchant".to_string())
+                .await
+                .unwrap();
+        assert!(db.pending_update.lock().unwrap().is_none());
+        assert_eq!(render_config.payment_link_config.theme, "#000000");
+        assert_eq!(render_config.return_url, "https://old.example.com");
+
+        // The next render uses the updated profile in full, the config cached for the old profile
+        // is not used
+        let render_config =
+            get_payment_link_render_config(&db, &payment_link, None, None, "merchant".to_string())
+                .await
+                .unwrap();
+        assert_eq!(render_config.payment_link_config.theme, "#ffffff");
+        assert_eq!(render_config.return_url, "https://new.example.com");
+
+        // The config stored against the payment link takes priority over the one of the profile
+        let payment_link = storage::PaymentLink {
+            payment_link_config: Some(
+                get_payment_link_config_based_on_priority(
+                    None,
+                    Some(serde_json::json!({ "theme": "#123456" })),
+                    "merchant".to_string(),
+                    String::new(),
+                )
+                .unwrap()
+                .0
+                .encode_to_value()
+                .unwrap(),
+            ),
+            ..get_payment_link("plink_456", &profile_id)
+        };
+        let render_config =
+            get_payment_link_render_config(&db, &payment_link, None, None, "merchant".to_string())
+                .await
+                .unwrap();
+        assert_eq!(render_config.payment_link_config.theme, "#123456");
+        assert_eq!(render_config.return_url, "https://new.example.com");
+    }
+
+    #[tokio::test]
+    async fn test_payment_link_render_config_without_stored_config() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let profile_id = "pro_default_config".to_string();
+        db.insert_business_profile(get_business_profile_new(&profile_id, None))
+            .await
+            .unwrap();
+        let configured_profile_id = "pro_profile_config".to_string();
+        db.insert_business_profile(get_business_profile_new(
+            &configured_profile_id,
+            Some(serde_json::json!({ "theme": "#abcdef", "seller_name": "Seller" })),
+        ))
+        .await
+        .unwrap();
+
+        // Without a payment link config on the profile either, the default config is used
+        let payment_link = get_payment_link("plink_default_config", &profile_id);
+        let render_config =
+            get_payment_link_render_config(&db, &payment_link, None, None, "merchant".to_string())
+                .await
+                .unwrap();
+        assert_eq!(
+            render_config.payment_link_config.theme,
+            DEFAULT_BACKGROUND_COLOR
+        );
+        assert_eq!(
+            render_config.payment_link_config.logo,
+            DEFAULT_MERCHANT_LOGO
+        );
+        assert_eq!(render_config.payment_link_config.seller_name, "merchant");
+
+        // Otherwise the payment link config of the profile is used, with the defaults for the
+        // fields it does not configure
+        let payment_link = get_payment_link("plink_profile_config", &configured_profile_id);
+        let render_config =
+            get_payment_link_render_config(&db, &payment_link, None, None, "merchant".to_string())
+                .await
+                .unwrap();
+        assert_eq!(render_config.payment_link_config.theme, "#abcdef");
+        assert_eq!(render_config.payment_link_config.seller_name, "Seller");
+        assert_eq!(
+            render_config.payment_link_config.logo,
+            DEFAULT_MERCHANT_LOGO
+        );
+    }
+
+    #[test]
+    fn test_cached_payment_link_config_keeps_profile_version() {
+        let profile_modified_at = common_utils::date_time::now();
+        let cached_config = CachedPaymentLinkConfig {
+            profile_modified_at,
+            payment_link_config: get_payment_link_config_based_on_priority(
+                None,
+                None,
+                "merchant".to_string(),
+                String::new(),
+            )
+            .unwrap()
+            .0,
+        };
+        let cached_config: CachedPaymentLinkConfig = cached_config
+            .encode_to_value()
+            .unwrap()
+            .parse_value("CachedPaymentLinkConfig")
+            .unwrap();
+
+        // The cached config is only used for the exact version of the profile it was resolved with
+        assert_eq!(cached_config.profile_modified_at, profile_modified_at);
+        assert_eq!(
+            cached_config.payment_link_config.theme,
+            DEFAULT_BACKGROUND_COLOR
+        );
+        assert_eq!(
+            get_payment_link_config_cache_key("plink_123"),
+            "payment_link_config_plink_123"
+        );
+    }
+
+    #[test]
+    fn test_get_order_amount_in_minor_units_for_fractional_amounts() {
+        assert_eq!(
+            get_order_amount_in_minor_units("12.3456", api_models::enums::Currency::USD).unwrap(),
+            1235
+        );
+        assert_eq!(
+            get_order_amount_in_minor_units("0.07", api_models::enums::Currency::EUR).unwrap(),
+            7
+        );
+        assert_eq!(
+            get_order_amount_in_minor_units("1845.6", api_models::enums::Currency::JPY).unwrap(),
+            1846
+        );
+        assert!(
+            get_order_amount_in_minor_units("not an amount", api_models::enums::Currency::USD)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_preview_payment_link_script_omits_publishable_key_and_skips_sdk() {
+        let payment_link_config: admin_types::PaymentLinkConfig =
+            serde_json::from_value(serde_json::json!({
+                "theme": "#4E6ADD",
+                "logo": "https://hyperswitch.io/favicon.ico",
+                "seller_name": "hyperswitch",
+                "sdk_layout": "tabs",
+                "display_sdk_only": false,
+                "enabled_saved_payment_method": false,
+                "font_family": null,
+                "border_radius": null,
+            }))
+            .unwrap();
+        let payment_details = get_preview_payment_link_details(
+            &payment_link_config,
+            api_models::enums::Currency::USD,
+            "65.40".to_string(),
+            None,
+            None,
+            None,
+        );
+        let js_script = get_preview_js_script(
+            &get_js_script(&api_models::payments::PaymentLinkData::PaymentLinkDetails(
+                &payment_details,
+            ))
+            .unwrap(),
+        );
+
+        let (details, preview) = js_script
+            .strip_prefix("window.__PAYMENT_DETAILS = ")
+            .and_then(|script| script.split_once(";\n"))
+            .unwrap();
+        let details: serde_json::Value = serde_json::from_str(details).unwrap();
+        assert_eq!(details["pub_key"], "");
+        assert_eq!(
+            details["client_secret"],
+            consts::PAYMENT_LINK_PREVIEW_CLIENT_SECRET
+        );
+        assert_eq!(preview, "window.__PAYMENT_LINK_PREVIEW = true;");
+
+        // The page reads the flag before the SDK is initialized, both on boot and once the SDK is
+        // loaded
+        let page_script = include_str!("payment_link/payment_link_initiate/payment_link.js");
+        let (_, initialize_sdk) = page_script
+            .split_once("function initializeSDK() {")
+            .unwrap();
+        let preview_check = initialize_sdk
+            .find("window.__PAYMENT_LINK_PREVIEW")
+            .unwrap();
+        assert!(preview_check < initialize_sdk.find("window.Hyper(").unwrap());
+        let (_, boot) = page_script.split_once("function boot() {").unwrap();
+        assert!(
+            boot.find("window.__PAYMENT_LINK_PREVIEW").unwrap()
+                < boot.find("initializeSDK();").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_check_payment_link_status_within_grace_period() {
+        let expiry = common_utils::date_time::now().saturating_sub(time::Duration::seconds(30));
+        assert_eq!(
+            check_payment_link_status(expiry, time::Duration::seconds(60)),
+            api_models::payments::PaymentLinkStatus::Active
+        );
+        assert_eq!(
+            check_payment_link_status(expiry, time::Duration::seconds(10)),
+            api_models::payments::PaymentLinkStatus::Expired
+        );
+        assert_eq!(
+            check_payment_link_status(expiry, time::Duration::ZERO),
+            api_models::payments::PaymentLinkStatus::Expired
+        );
+    }
+
+    #[test]
+    fn test_validate_order_details_count() {
+        assert!(validate_order_details_count(3, 3).is_ok());
+        assert!(validate_order_details_count(4, 3).is_err());
+    }
+
+    #[test]
+    fn test_render_return_url_template_substitutes_placeholders() {
+        let return_url = render_return_url_template(
+            "https://merchant.com/orders/{payment_id}?status={status}",
+            "pay_123",
+            storage_enums::IntentStatus::Succeeded,
+        )
+        .unwrap();
+        assert_eq!(
+            return_url,
+            "https://merchant.com/orders/pay_123?status=succeeded"
+        );
+    }
+
+    #[test]
+    fn test_render_return_url_template_rejects_cross_origin() {
+        assert!(render_return_url_template(
+            "https://{payment_id}/orders",
+            "evil.com",
+            storage_enums::IntentStatus::Succeeded,
+        )
+        .is_err());
+        assert!(render_return_url_template(
+            "https://merchant.com{payment_id}",
+            "@evil.com",
+            storage_enums::IntentStatus::Succeeded,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_display_currency() {
+        use api_models::enums::Currency;
+
+        assert!(validate_display_currency(None, Currency::USD).is_ok());
+        assert!(validate_display_currency(Some(Currency::USD), Currency::USD).is_ok());
+        assert!(matches!(
+            validate_display_currency(Some(Currency::EUR), Currency::USD),
+            Err(errors::ApiErrorResponse::InvalidRequestData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_return_url_allows_only_https() {
+        assert!(validate_return_url("https://merchant.com/orders?status=succeeded").is_ok());
+        assert!(validate_return_url("http://merchant.com/orders").is_err());
+        assert!(validate_return_url("javascript:alert(1)").is_err());
+    }
+
+    #[test]
+    fn test_get_color_scheme_css_drops_invalid_values() {
+        let payment_link_config = admin_types::PaymentLinkConfig {
+            theme: DEFAULT_BACKGROUND_COLOR.to_string(),
+            logo: DEFAULT_MERCHANT_LOGO.to_string(),
+            logo_dimensions: None,
+            seller_name: "merchant".to_string(),
+            sdk_layout: DEFAULT_SDK_LAYOUT.to_string(),
+            display_sdk_only: DEFAULT_DISPLAY_SDK_ONLY,
+            enabled_saved_payment_method: DEFAULT_ENABLE_SAVED_PAYMENT_METHOD,
+            font_family: Some("arial".to_string()),
+            border_radius: Some(12),
+            custom_fields: Vec::new(),
+            adjustable_amount: None,
+            allowed_payment_methods: None,
+            display_currency: None,
+        };
+        let css = get_color_scheme_css(payment_link_config.clone());
+        assert!(css.contains("--font-family: \"Arial\";"));
+        assert!(css.contains("--border-radius: 12px;"));
+
+        let css = get_color_scheme_css(admin_types::PaymentLinkConfig {
+            font_family: Some("System-UI".to_string()),
+            ..payment_link_config.clone()
+        });
+        assert!(css.contains("--font-family: system-ui;"));
+
+        let css = get_color_scheme_css(admin_types::PaymentLinkConfig {
+            font_family: Some("Arial;} body { display: none".to_string()),
+            border_radius: Some(PAYMENT_LINK_MAX_BORDER_RADIUS + 1),
+            ..payment_link_config
+        });
+        assert!(!css.contains("--font-family"));
+        assert!(!css.contains("--border-radius"));
+    }
+
+    #[test]
+    fn test_validate_logo_dimensions_config() {
+        assert!(
+            validate_logo_dimensions_config(&admin_types::PaymentLinkLogoDimensions {
+                width: Some(120),
+                height: None,
+            })
+            .is_ok()
+        );
+        assert!(
+            validate_logo_dimensions_config(&admin_types::PaymentLinkLogoDimensions {
+                width: Some(120),
+                height: Some(0),
+            })
+            .is_err()
+        );
+        assert!(
+            validate_logo_dimensions_config(&admin_types::PaymentLinkLogoDimensions {
+                width: Some(PAYMENT_LINK_MAX_LOGO_DIMENSION + 1),
+                height: Some(48),
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_adjusted_amount() {
+        let adjustable_amount = admin_types::PaymentLinkAdjustableAmount {
+            min_amount: MinorUnit::new(1000),
+            max_amount: MinorUnit::new(5000),
+            suggested_tips: vec![MinorUnit::new(100), MinorUnit::new(500)],
+        };
+        assert!(validate_adjustable_amount_config(&adjustable_amount).is_ok());
+        assert!(validate_adjusted_amount(&adjustable_amount, MinorUnit::new(1500)).is_ok());
+        assert!(validate_adjusted_amount(&adjustable_amount, MinorUnit::new(999)).is_err());
+        assert!(validate_adjusted_amount(&adjustable_amount, MinorUnit::new(5001)).is_err());
+
+        assert!(
+            validate_adjustable_amount_config(&admin_types::PaymentLinkAdjustableAmount {
+                min_amount: MinorUnit::new(6000),
+                ..adjustable_amount.clone()
+            })
+            .is_err()
+        );
+        assert!(
+            validate_adjustable_amount_config(&admin_types::PaymentLinkAdjustableAmount {
+                suggested_tips: vec![MinorUnit::new(0)],
+                ..adjustable_amount
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_custom_field_values() {
+        let custom_fields = vec![
+            admin_types::PaymentLinkCustomField {
+                label: "Invoice number".to_string(),
+                key: "invoice_number".to_string(),
+                required: true,
+                field_type: admin_types::PaymentLinkCustomFieldType::Number,
+            },
+            admin_types::PaymentLinkCustomField {
+                label: "Billing email".to_string(),
+                key: "billing_email".to_string(),
+                required: false,
+                field_type: admin_types::PaymentLinkCustomFieldType::Email,
+            },
+        ];
+        assert!(validate_custom_fields_config(&custom_fields).is_ok());
+
+        let metadata = serde_json::json!({ "invoice_number": "1024" });
+        assert!(validate_custom_field_values(&custom_fields, Some(&metadata)).is_ok());
+
+        let metadata = serde_json::json!({ "invoice_number": "", "billing_email": "a@b.com" });
+        assert!(validate_custom_field_values(&custom_fields, Some(&metadata)).is_err());
+
+        let metadata = serde_json::json!({ "invoice_number": 1024, "billing_email": "invalid" });
+        assert!(validate_custom_field_values(&custom_fields, Some(&metadata)).is_err());
+        assert!(validate_custom_field_values(&custom_fields, None).is_err());
+    }
+
+    #[test]
+    fn test_validate_allowed_payment_methods() {
+        let enabled_payment_methods = HashSet::from([
+            storage_enums::PaymentMethod::Card,
+            storage_enums::PaymentMethod::Wallet,
+        ]);
+        assert!(validate_allowed_payment_methods(
+            &[storage_enums::PaymentMethod::Card],
+            &enabled_payment_methods
+        )
+        .is_ok());
+        assert!(validate_allowed_payment_methods(
+            &[
+                storage_enums::PaymentMethod::Card,
+                storage_enums::PaymentMethod::BankRedirect
+            ],
+            &enabled_payment_methods
+        )
+        .is_err());
+
+        assert!(validate_allowed_payment_methods_config(&[]).is_err());
+        assert!(validate_allowed_payment_methods_config(&[
+            storage_enums::PaymentMethod::Card,
+            storage_enums::PaymentMethod::Card
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_payment_link_client_secret_is_not_logged() {
+        let payment_details = api_models::payments::PaymentLinkDetails {
+            amount: "10.00".to_string(),
+            currency: api_models::enums::Currency::USD,
+            pub_key: "pk_dev_123".to_string(),
+            client_secret: Secret::new("pay_123_secret_abc".to_string()),
+            payment_id: "pay_123".to_string(),
+            session_expiry: common_utils::date_time::now(),
+            merchant_logo: String::new(),
+            logo_dimensions: None,
+            return_url: "https://merchant.com".to_string(),
+            merchant_name: "Merchant".to_string(),
+            order_details: None,
+            max_items_visible_after_collapse: 3,
+            theme: String::new(),
+            merchant_description: None,
+            sdk_layout: String::new(),
+            display_sdk_only: false,
+            enabled_saved_payment_method: false,
+            custom_fields: Vec::new(),
+            adjustable_amount: None,
+            allowed_payment_methods: None,
+        };
+        let payment_link_data =
+            api_models::payments::PaymentLinkData::PaymentLinkDetails(&payment_details);
+
+        // The payment details are logged in `Debug` form when rendering the page fails
+        assert!(!format!("{payment_link_data:?}").contains("pay_123_secret_abc"));
+        assert!(get_js_script(&payment_link_data)
+            .unwrap()
+            .contains(r#""client_secret":"pay_123_secret_abc""#));
+    }
+}
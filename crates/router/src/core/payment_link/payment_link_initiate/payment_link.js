@@ -249,9 +249,11 @@ function boot() {
   // Add event listeners
   initializeEventListeners(paymentDetails);
 
-  // Initialize SDK
+  // Initialize SDK, except on previews which carry a placeholder client secret
   // @ts-ignore
-  if (window.Hyper) {
+  if (window.__PAYMENT_LINK_PREVIEW) {
+    renderPreviewBanner(paymentDetails);
+  } else if (window.Hyper) {
     initializeSDK();
   }
 
@@ -389,6 +391,11 @@ function showSDK(display_sdk_only) {
 function initializeSDK() {
   // @ts-ignore
   var paymentDetails = window.__PAYMENT_DETAILS;
+  // @ts-ignore
+  if (window.__PAYMENT_LINK_PREVIEW) {
+    renderPreviewBanner(paymentDetails);
+    return;
+  }
   var client_secret = paymentDetails.client_secret;
   var appearance = {
     variables: {
@@ -448,6 +455,36 @@ function initializeSDK() {
 }
 
 
+/**
+ * Trigger - previews of the payment link page
+ * Uses
+ *  - Show a banner in place of the SDK, as no payment can be made from a preview
+ *  - Reveal the page without initializing the SDK
+ * @param {PaymentDetails} paymentDetails
+ **/
+function renderPreviewBanner(paymentDetails) {
+  if (document.getElementById("payment-link-preview-banner")) {
+    return;
+  }
+  var banner = document.createElement("div");
+  banner.id = "payment-link-preview-banner";
+  banner.className = "payment-link-preview-banner";
+  banner.innerText = "Preview - payments cannot be made from this page";
+  var paymentFormWrap = document.getElementById("payment-form-wrap");
+  paymentFormWrap.insertBefore(banner, paymentFormWrap.firstChild);
+
+  if (!paymentDetails.display_sdk_only) {
+    show("#hyper-checkout-details");
+  }
+  show("#hyper-checkout-sdk");
+  hide("#sdk-spinner");
+
+  var shimmer = document.getElementById("payment-details-shimmer");
+  if (shimmer) {
+    document.body.removeChild(shimmer);
+  }
+}
+
 /**
  * Use - mount payment widget on the passed element
  * @param {String} id
@@ -640,8 +677,19 @@ function renderPaymentDetails(paymentDetails) {
   // Create merchant logo's node
   var merchantLogoNode = document.createElement("img");
   merchantLogoNode.src = paymentDetails.merchant_logo;
-  merchantLogoNode.setAttribute("width", "48"); // Set width to 100 pixels
-  merchantLogoNode.setAttribute("height", "48");
+  var logoDimensions = paymentDetails.logo_dimensions;
+  if (logoDimensions && (logoDimensions.width || logoDimensions.height)) {
+    // A dimension which is not configured is scaled to keep the aspect ratio of the logo
+    if (logoDimensions.width) {
+      merchantLogoNode.setAttribute("width", String(logoDimensions.width));
+    }
+    if (logoDimensions.height) {
+      merchantLogoNode.setAttribute("height", String(logoDimensions.height));
+    }
+  } else {
+    merchantLogoNode.setAttribute("width", "48"); // Set width to 100 pixels
+    merchantLogoNode.setAttribute("height", "48");
+  }
 
   // Create expiry node
   var paymentExpiryNode = document.createElement("div");
@@ -793,6 +841,14 @@ function renderCartItem(
   var priceNode = document.createElement("div");
   priceNode.className = "hyper-checkout-card-item-price";
   priceNode.innerText = paymentDetails.currency + " " + item.amount;
+  // Price in the currency the product was listed in, if it was converted
+  if (item.original_currency && item.original_amount) {
+    var originalPriceNode = document.createElement("div");
+    originalPriceNode.className = "hyper-checkout-card-item-quantity";
+    originalPriceNode.innerText =
+      "(" + item.original_currency + " " + item.original_amount + ")";
+    priceNode.append(originalPriceNode);
+  }
   // Append items
 
   nameAndQuantityWrapperNode.append(productNameNode);
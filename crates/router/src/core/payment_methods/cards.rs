@@ -1303,53 +1303,15 @@ pub async fn get_payment_method_from_hs_locker<'a>(
     payment_method_reference: &'a str,
     locker_choice: Option<api_enums::LockerChoice>,
 ) -> errors::CustomResult<Secret<String>, errors::VaultError> {
-    let locker = &state.conf.locker;
-    let jwekey = state.conf.jwekey.get_inner();
-
-    let payment_method_data = if !locker.mock_locker {
-        let request = payment_methods::mk_get_card_request_hs(
-            jwekey,
-            locker,
+    let payment_method_data = if !state.conf.locker.mock_locker {
+        let enc_card_data = get_enc_data_from_hs_locker(
+            state,
             customer_id,
             merchant_id,
             payment_method_reference,
             locker_choice,
         )
-        .await
-        .change_context(errors::VaultError::FetchPaymentMethodFailed)
-        .attach_printable("Making get payment method request failed")?;
-        let response = services::call_connector_api(state, request, "add_card_to_locker")
-            .await
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)
-            .attach_printable("Failed while executing call_connector_api for get_card");
-        let jwe_body: services::JweBody = response
-            .get_response_inner("JweBody")
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
-        let decrypted_payload = payment_methods::get_decrypted_response_payload(
-            jwekey,
-            jwe_body,
-            locker_choice,
-            locker.decryption_scheme.clone(),
-        )
-        .await
-        .change_context(errors::VaultError::FetchPaymentMethodFailed)
-        .attach_printable("Error getting decrypted response payload for get card")?;
-        let get_card_resp: payment_methods::RetrieveCardResp = decrypted_payload
-            .parse_struct("RetrieveCardResp")
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)
-            .attach_printable("Failed to parse struct to RetrieveCardResp")?;
-        let retrieve_card_resp = get_card_resp
-            .payload
-            .get_required_value("RetrieveCardRespPayload")
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)
-            .attach_printable("Failed to retrieve field - payload from RetrieveCardResp")?;
-        let enc_card_data = retrieve_card_resp
-            .enc_card_data
-            .get_required_value("enc_card_data")
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)
-            .attach_printable(
-                "Failed to retrieve field - enc_card_data from RetrieveCardRespPayload",
-            )?;
+        .await?;
         decode_and_decrypt_locker_data(key_store, enc_card_data.peek().to_string()).await?
     } else {
         mock_get_payment_method(&*state.store, key_store, payment_method_reference)
@@ -1360,6 +1322,62 @@ pub async fn get_payment_method_from_hs_locker<'a>(
     Ok(payment_method_data)
 }
 
+/// Retrieves the raw data stored against `payment_method_reference` in the Hyperswitch locker,
+/// without decrypting it with the merchant's key.
+#[instrument(skip_all)]
+pub async fn get_enc_data_from_hs_locker(
+    state: &routes::SessionState,
+    customer_id: &id_type::CustomerId,
+    merchant_id: &str,
+    payment_method_reference: &str,
+    locker_choice: Option<api_enums::LockerChoice>,
+) -> errors::CustomResult<Secret<String>, errors::VaultError> {
+    let locker = &state.conf.locker;
+    let jwekey = state.conf.jwekey.get_inner();
+
+    let request = payment_methods::mk_get_card_request_hs(
+        jwekey,
+        locker,
+        customer_id,
+        merchant_id,
+        payment_method_reference,
+        locker_choice,
+    )
+    .await
+    .change_context(errors::VaultError::FetchPaymentMethodFailed)
+    .attach_printable("Making get payment method request failed")?;
+    let response = services::call_connector_api(state, request, "add_card_to_locker")
+        .await
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)
+        .attach_printable("Failed while executing call_connector_api for get_card");
+    let jwe_body: services::JweBody = response
+        .get_response_inner("JweBody")
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
+    let decrypted_payload = payment_methods::get_decrypted_response_payload(
+        jwekey,
+        jwe_body,
+        locker_choice,
+        locker.decryption_scheme.clone(),
+    )
+    .await
+    .change_context(errors::VaultError::FetchPaymentMethodFailed)
+    .attach_printable("Error getting decrypted response payload for get card")?;
+    let get_card_resp: payment_methods::RetrieveCardResp = decrypted_payload
+        .parse_struct("RetrieveCardResp")
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)
+        .attach_printable("Failed to parse struct to RetrieveCardResp")?;
+    let retrieve_card_resp = get_card_resp
+        .payload
+        .get_required_value("RetrieveCardRespPayload")
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)
+        .attach_printable("Failed to retrieve field - payload from RetrieveCardResp")?;
+    retrieve_card_resp
+        .enc_card_data
+        .get_required_value("enc_card_data")
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)
+        .attach_printable("Failed to retrieve field - enc_card_data from RetrieveCardRespPayload")
+}
+
 #[instrument(skip_all)]
 pub async fn call_to_locker_hs<'a>(
     state: &routes::SessionState,
@@ -4149,14 +4167,22 @@ pub async fn get_bank_from_hs_locker(
             .attach_printable("Error storing payout method data in temporary locker")?;
             Ok(bank.to_owned())
         }
-        api::PayoutMethodData::Card(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
-            message: "Expected bank details, found card details instead".to_string(),
+        api::PayoutMethodData::Card(_) | api::PayoutMethodData::NetworkToken(_) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Expected bank details, found card details instead".to_string(),
+            }
+            .into())
         }
-        .into()),
         api::PayoutMethodData::Wallet(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
             message: "Expected bank details, found wallet details instead".to_string(),
         }
         .into()),
+        api::PayoutMethodData::StoreCredit(_) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Expected bank details, found store credit details instead".to_string(),
+            }
+            .into())
+        }
     }
 }
 
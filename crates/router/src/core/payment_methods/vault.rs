@@ -545,6 +545,144 @@ impl Vaultable for api::WalletPayout {
     }
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedStoreCreditValues {
+    pub credited_customer_id: id_type::CustomerId,
+    pub credit_account_id: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+impl Vaultable for api::StoreCreditPayout {
+    fn get_value1(
+        &self,
+        _customer_id: Option<id_type::CustomerId>,
+    ) -> CustomResult<String, errors::VaultError> {
+        let value1 = TokenizedStoreCreditValues {
+            credited_customer_id: self.customer_id.clone(),
+            credit_account_id: self.credit_account_id.clone(),
+        };
+
+        value1
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode store credit data - TokenizedStoreCreditValues")
+    }
+
+    fn get_value2(
+        &self,
+        customer_id: Option<id_type::CustomerId>,
+    ) -> CustomResult<String, errors::VaultError> {
+        let value2 = TokenizedWalletInsensitiveValues { customer_id };
+
+        value2
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode data - TokenizedWalletInsensitiveValues")
+    }
+
+    fn from_values(
+        value1: String,
+        value2: String,
+    ) -> CustomResult<(Self, SupplementaryVaultData), errors::VaultError> {
+        let value1: TokenizedStoreCreditValues = value1
+            .parse_struct("TokenizedStoreCreditValues")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into store credit data")?;
+
+        let value2: TokenizedWalletInsensitiveValues = value2
+            .parse_struct("TokenizedWalletInsensitiveValues")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into store credit insensitive data")?;
+
+        let store_credit = Self {
+            customer_id: value1.credited_customer_id,
+            credit_account_id: value1.credit_account_id,
+        };
+        let supp_data = SupplementaryVaultData {
+            customer_id: value2.customer_id,
+            payment_method_id: None,
+        };
+
+        Ok((store_credit, supp_data))
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedNetworkTokenSensitiveValues {
+    pub network_token: masking::Secret<String>,
+    pub token_expiry_month: masking::Secret<String>,
+    pub token_expiry_year: masking::Secret<String>,
+    pub token_cryptogram: masking::Secret<String>,
+    pub eci: Option<String>,
+    pub card_holder_name: Option<masking::Secret<String>>,
+}
+
+#[cfg(feature = "payouts")]
+impl Vaultable for api::NetworkTokenPayout {
+    fn get_value1(
+        &self,
+        _customer_id: Option<id_type::CustomerId>,
+    ) -> CustomResult<String, errors::VaultError> {
+        let value1 = TokenizedNetworkTokenSensitiveValues {
+            network_token: self.network_token.clone(),
+            token_expiry_month: self.token_expiry_month.clone(),
+            token_expiry_year: self.token_expiry_year.clone(),
+            token_cryptogram: self.token_cryptogram.clone(),
+            eci: self.eci.clone(),
+            card_holder_name: self.card_holder_name.clone(),
+        };
+
+        value1
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable(
+                "Failed to encode network token data - TokenizedNetworkTokenSensitiveValues",
+            )
+    }
+
+    fn get_value2(
+        &self,
+        customer_id: Option<id_type::CustomerId>,
+    ) -> CustomResult<String, errors::VaultError> {
+        let value2 = TokenizedWalletInsensitiveValues { customer_id };
+
+        value2
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode data - TokenizedWalletInsensitiveValues")
+    }
+
+    fn from_values(
+        value1: String,
+        value2: String,
+    ) -> CustomResult<(Self, SupplementaryVaultData), errors::VaultError> {
+        let value1: TokenizedNetworkTokenSensitiveValues = value1
+            .parse_struct("TokenizedNetworkTokenSensitiveValues")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into network token data")?;
+
+        let value2: TokenizedWalletInsensitiveValues = value2
+            .parse_struct("TokenizedWalletInsensitiveValues")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into network token insensitive data")?;
+
+        let network_token = Self {
+            network_token: value1.network_token,
+            token_expiry_month: value1.token_expiry_month,
+            token_expiry_year: value1.token_expiry_year,
+            token_cryptogram: value1.token_cryptogram,
+            eci: value1.eci,
+            card_holder_name: value1.card_holder_name,
+        };
+        let supp_data = SupplementaryVaultData {
+            customer_id: value2.customer_id,
+            payment_method_id: None,
+        };
+
+        Ok((network_token, supp_data))
+    }
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct TokenizedBankSensitiveValues {
     pub bank_account_number: Option<masking::Secret<String>>,
@@ -738,6 +876,8 @@ pub enum VaultPayoutMethod {
     Card(String),
     Bank(String),
     Wallet(String),
+    StoreCredit(String),
+    NetworkToken(String),
 }
 
 #[cfg(feature = "payouts")]
@@ -750,6 +890,12 @@ impl Vaultable for api::PayoutMethodData {
             Self::Card(card) => VaultPayoutMethod::Card(card.get_value1(customer_id)?),
             Self::Bank(bank) => VaultPayoutMethod::Bank(bank.get_value1(customer_id)?),
             Self::Wallet(wallet) => VaultPayoutMethod::Wallet(wallet.get_value1(customer_id)?),
+            Self::StoreCredit(store_credit) => {
+                VaultPayoutMethod::StoreCredit(store_credit.get_value1(customer_id)?)
+            }
+            Self::NetworkToken(network_token) => {
+                VaultPayoutMethod::NetworkToken(network_token.get_value1(customer_id)?)
+            }
         };
 
         value1
@@ -766,6 +912,12 @@ impl Vaultable for api::PayoutMethodData {
             Self::Card(card) => VaultPayoutMethod::Card(card.get_value2(customer_id)?),
             Self::Bank(bank) => VaultPayoutMethod::Bank(bank.get_value2(customer_id)?),
             Self::Wallet(wallet) => VaultPayoutMethod::Wallet(wallet.get_value2(customer_id)?),
+            Self::StoreCredit(store_credit) => {
+                VaultPayoutMethod::StoreCredit(store_credit.get_value2(customer_id)?)
+            }
+            Self::NetworkToken(network_token) => {
+                VaultPayoutMethod::NetworkToken(network_token.get_value2(customer_id)?)
+            }
         };
 
         value2
@@ -801,6 +953,19 @@ impl Vaultable for api::PayoutMethodData {
                 let (wallet, supp_data) = api::WalletPayout::from_values(mvalue1, mvalue2)?;
                 Ok((Self::Wallet(wallet), supp_data))
             }
+            (VaultPayoutMethod::StoreCredit(mvalue1), VaultPayoutMethod::StoreCredit(mvalue2)) => {
+                let (store_credit, supp_data) =
+                    api::StoreCreditPayout::from_values(mvalue1, mvalue2)?;
+                Ok((Self::StoreCredit(store_credit), supp_data))
+            }
+            (
+                VaultPayoutMethod::NetworkToken(mvalue1),
+                VaultPayoutMethod::NetworkToken(mvalue2),
+            ) => {
+                let (network_token, supp_data) =
+                    api::NetworkTokenPayout::from_values(mvalue1, mvalue2)?;
+                Ok((Self::NetworkToken(network_token), supp_data))
+            }
             _ => Err(errors::VaultError::PayoutMethodNotSupported)
                 .attach_printable("Payout method not supported"),
         }
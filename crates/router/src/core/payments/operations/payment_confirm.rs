@@ -7,7 +7,10 @@ use api_models::{
     payments::{AdditionalPaymentData, ExtendedCardInfo},
 };
 use async_trait::async_trait;
-use common_utils::ext_traits::{AsyncExt, Encode, StringExt, ValueExt};
+use common_utils::{
+    ext_traits::{AsyncExt, Encode, StringExt, ValueExt},
+    types::MinorUnit,
+};
 use error_stack::{report, ResultExt};
 use futures::FutureExt;
 use masking::{ExposeInterface, PeekInterface};
@@ -22,6 +25,7 @@ use crate::{
         blocklist::utils as blocklist_utils,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
+        payment_link,
         payments::{
             self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
             PaymentData,
@@ -358,6 +362,25 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         payment_attempt.capture_method = request.capture_method.or(payment_attempt.capture_method);
 
+        // The customer may adjust the amount on the payment link within the configured bounds
+        if let (Some(payment_link_id), Some(requested_amount)) =
+            (&payment_intent.payment_link_id, request.amount)
+        {
+            let requested_amount = MinorUnit::from(requested_amount);
+            if requested_amount != payment_intent.amount {
+                if let Some(adjusted_amount) = payment_link::get_payment_link_adjusted_amount(
+                    state,
+                    payment_link_id,
+                    requested_amount,
+                )
+                .await?
+                {
+                    payment_intent.amount = adjusted_amount;
+                    payment_attempt.amount = adjusted_amount;
+                }
+            }
+        }
+
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
 
@@ -402,6 +425,19 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .or(payment_intent.feature_metadata);
         payment_intent.metadata = request.metadata.clone().or(payment_intent.metadata);
         payment_intent.frm_metadata = request.frm_metadata.clone().or(payment_intent.frm_metadata);
+
+        // Custom fields collected on the payment link are submitted in the metadata
+        if let Some(payment_link_id) = &payment_intent.payment_link_id {
+            payment_link::validate_payment_link_custom_field_values(
+                state,
+                payment_link_id,
+                payment_intent
+                    .metadata
+                    .as_ref()
+                    .map(|metadata| metadata.peek()),
+            )
+            .await?;
+        }
         payment_intent.request_incremental_authorization = request
             .request_incremental_authorization
             .map(|request_incremental_authorization| {
@@ -529,6 +565,19 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         payment_attempt.payment_method = payment_method.or(payment_attempt.payment_method);
 
+        // Only the payment methods accepted on the payment link can be used to pay through it
+        if let (Some(payment_link_id), Some(payment_method)) = (
+            &payment_intent.payment_link_id,
+            payment_attempt.payment_method,
+        ) {
+            payment_link::validate_payment_link_payment_method(
+                state,
+                payment_link_id,
+                payment_method,
+            )
+            .await?;
+        }
+
         payment_attempt.payment_method_type = payment_method_type
             .or(payment_attempt.payment_method_type)
             .or(payment_method_info
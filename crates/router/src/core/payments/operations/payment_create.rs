@@ -226,6 +226,24 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                         merchant_name,
                         default_domain_name,
                     )?;
+                if let Some(adjustable_amount) = &payment_link_config.adjustable_amount {
+                    payment_link::validate_adjusted_amount(adjustable_amount, amount.into())?;
+                }
+                payment_link::validate_display_currency(
+                    payment_link_config.display_currency,
+                    currency,
+                )?;
+                if let Some(allowed_payment_methods) = &payment_link_config.allowed_payment_methods
+                {
+                    payment_link::validate_allowed_payment_methods_enabled(
+                        state,
+                        merchant_id,
+                        merchant_key_store,
+                        &profile_id,
+                        allowed_payment_methods,
+                    )
+                    .await?;
+                }
                 create_payment_link(
                     request,
                     payment_link_config,
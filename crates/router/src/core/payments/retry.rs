@@ -155,7 +155,9 @@ where
                         ),
                     }))?
                 }
-                api_models::gsm::GsmDecision::DoDefault => break,
+                // Payments have a single destination, so there is no destination to fall back to
+                api_models::gsm::GsmDecision::DoDefault
+                | api_models::gsm::GsmDecision::RetryWithNextDestination => break,
             }
             initial_gsm = None;
         }
@@ -145,7 +145,7 @@ pub fn make_dsl_input_for_payouts(
         payment_method_type: payout_data
             .payout_method_data
             .clone()
-            .map(api_enums::PaymentMethodType::foreign_from),
+            .and_then(Option::<api_enums::PaymentMethodType>::foreign_from),
         card_network: None,
     };
     Ok(dsl_inputs::BackendInput {
@@ -1024,12 +1024,31 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
     }
 }
 
+#[cfg(feature = "payouts")]
+impl ForeignFrom<&storage::PayoutAttempt> for api_models::payouts::PayoutConnectorDetails {
+    fn foreign_from(payout_attempt: &storage::PayoutAttempt) -> Self {
+        Self {
+            connector: payout_attempt.connector.clone(),
+            connector_payout_id: payout_attempt.connector_payout_id.clone(),
+            error_code: payout_attempt.error_code.clone(),
+            error_message: payout_attempt.error_message.clone(),
+        }
+    }
+}
+
 #[cfg(feature = "payouts")]
 impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt, domain::Customer)>
     for api::PayoutCreateResponse
 {
     fn foreign_from(item: (storage::Payouts, storage::PayoutAttempt, domain::Customer)) -> Self {
         let (payout, payout_attempt, customer) = item;
+        let connector_details =
+            api_models::payouts::PayoutConnectorDetails::foreign_from(&payout_attempt);
+        let statement_descriptor =
+            crate::core::payouts::helpers::get_effective_payout_statement_descriptor(
+                &payout,
+                &payout_attempt,
+            );
         let attempt = PayoutAttemptResponse {
             attempt_id: payout_attempt.payout_attempt_id,
             status: payout_attempt.status,
@@ -1045,6 +1064,11 @@ impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt, domain::Customer)>
             unified_code: None,
             unified_message: None,
         };
+        let time_to_fulfillment =
+            crate::core::payouts::helpers::get_payout_time_to_fulfillment(&payout);
+        let fee_breakdown = crate::core::payouts::helpers::get_payout_fee_breakdown(&payout);
+        let currency_conversion =
+            crate::core::payouts::helpers::get_payout_currency_conversion(&payout);
         Self {
             payout_id: payout.payout_id,
             merchant_id: payout.merchant_id,
@@ -1069,13 +1093,54 @@ impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt, domain::Customer)>
             error_message: payout_attempt.error_message,
             error_code: payout_attempt.error_code,
             profile_id: payout.profile_id,
-            created: Some(payout.created_at),
+            created: Some(payout.created_at.assume_utc()),
             connector_transaction_id: attempt.connector_transaction_id.clone(),
+            connector_details: Some(connector_details),
             priority: payout.priority,
+            settlement_network: payout.settlement_network,
+            purpose_code: payout.purpose_code,
+            webhook_url: payout.webhook_url,
+            fee_breakdown,
+            statement_descriptor,
+            merchant_reference_id: payout.merchant_reference_id,
+            series_id: payout.series_id,
+            source_amount: currency_conversion
+                .as_ref()
+                .map(|conversion| conversion.source_amount),
+            source_currency: currency_conversion.as_ref().map(|_| payout.source_currency),
+            exchange_rate: currency_conversion
+                .as_ref()
+                .map(|conversion| conversion.exchange_rate.clone()),
+            exchange_rate_source: currency_conversion
+                .as_ref()
+                .and_then(|conversion| conversion.exchange_rate_source),
+            exchange_rate_timestamp: currency_conversion
+                .as_ref()
+                .map(|conversion| conversion.exchange_rate_timestamp.assume_utc()),
+            conversion_rounding: currency_conversion
+                .as_ref()
+                .map(|conversion| conversion.rounding),
+            conversion_residual: currency_conversion
+                .as_ref()
+                .and_then(|conversion| conversion.residual_amount.clone()),
+            value_date: payout.value_date,
+            time_to_fulfillment,
             attempts: Some(vec![attempt]),
             billing: None,
             client_secret: None,
             payout_link: None,
+            approved_by: payout.approved_by,
+            approved_at: payout.approved_at.map(time::PrimitiveDateTime::assume_utc),
+            verified_at: payout.verified_at.map(time::PrimitiveDateTime::assume_utc),
+            verification_reference: payout.verification_reference,
+            reason_code: payout_attempt.reason_code,
+            reason: payout_attempt.reason,
+            recalled_amount: payout_attempt.recalled_amount,
+            destination_index: payout_attempt.destination_index,
+            payout_method_masked: None,
+            bank_details: None,
+            estimated_arrival: None,
+            processing_date: None,
         }
     }
 }
@@ -1151,6 +1216,7 @@ pub fn change_order_details_to_new_type(
         product_name: order_details.product_name,
         quantity: order_details.quantity,
         amount: order_amount,
+        currency: None,
         product_img_link: order_details.product_img_link,
         requires_shipping: order_details.requires_shipping,
         product_id: order_details.product_id,
@@ -1,9 +1,16 @@
 pub mod access_token;
+pub mod destination_verification;
+#[cfg(feature = "olap")]
+pub mod export;
 pub mod helpers;
+pub mod holds;
+#[cfg(feature = "olap")]
+pub mod reporting;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
+pub mod templates;
 pub mod validator;
-use std::vec::IntoIter;
+use std::{collections::HashSet, vec::IntoIter};
 
 use api_models::{self, enums as api_enums, payouts::PayoutLinkResponse};
 use common_utils::{
@@ -20,11 +27,10 @@ use error_stack::{report, ResultExt};
 use futures::future::join_all;
 #[cfg(feature = "olap")]
 use hyperswitch_domain_models::errors::StorageError;
+use masking::PeekInterface;
 #[cfg(feature = "payout_retry")]
 use retry::GsmValidation;
-#[cfg(feature = "olap")]
-use router_env::logger;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use scheduler::utils as pt_utils;
 use serde_json;
 
@@ -270,6 +276,44 @@ pub async fn payouts_core(
     routing_algorithm: Option<serde_json::Value>,
     eligible_connectors: Option<Vec<api_models::enums::PayoutConnectors>>,
 ) -> RouterResult<()> {
+    // Disbursements deferred to later are routed as requested when the payout was created or last
+    // updated
+    let (routing_algorithm, eligible_connectors) = match (routing_algorithm, eligible_connectors) {
+        (None, None) => helpers::get_payout_routing(&payout_data.payouts)?,
+        requested => requested,
+    };
+
+    // Merchants can require payouts to be made only to destinations verified for the customer
+    destination_verification::validate_payout_destination_verified(state, payout_data).await?;
+
+    // Payouts to recipients whose documents are to be verified are held until they are verified
+    if helpers::is_payout_verification_required(&payout_data.payouts) {
+        return mark_payout_pending_verification(state, merchant_account, payout_data).await;
+    }
+
+    // Payouts above the merchant's approval threshold are held until they are approved
+    if helpers::is_payout_approval_required(&*state.store, payout_data).await? {
+        return mark_payout_pending_approval(state, merchant_account, payout_data).await;
+    }
+
+    helpers::resolve_payout_description(state, merchant_account, payout_data).await?;
+
+    // Store credit is settled on the merchant's internal ledger, no connector is involved
+    if payout_data.payouts.payout_type == Some(storage_enums::PayoutType::StoreCredit)
+        || matches!(
+            payout_data.payout_method_data,
+            Some(payouts::PayoutMethodData::StoreCredit(_))
+        )
+    {
+        return credit_store_credit_payout(state, merchant_account, key_store, payout_data).await;
+    }
+
+    // Payouts can only be routed to connectors which support their method, currency and charge
+    // bearer
+    let eligible_connectors =
+        helpers::get_eligible_payout_connectors(state, key_store, payout_data, eligible_connectors)
+            .await?;
+
     let payout_attempt = &payout_data.payout_attempt;
 
     // Form connector data
@@ -285,23 +329,143 @@ pub async fn payouts_core(
     .await?;
 
     // Call connector steps
-    Box::pin(make_connector_decision(
+    let connector_decision = Box::pin(make_connector_decision(
         state,
         merchant_account,
         key_store,
         connector_call_type,
         payout_data,
     ))
-    .await
+    .await;
+    if let Err(error) = &connector_decision {
+        if !helpers::is_connector_payout_failure(
+            error.current_context(),
+            payout_data.payout_attempt.status,
+        ) {
+            return connector_decision;
+        }
+    }
+
+    // Payouts failed by the connector are retried with their backup destinations before the
+    // failure is returned
+    let failed_payout_attempt_id = payout_data.payout_attempt.payout_attempt_id.clone();
+    Box::pin(failover_payout_destination(
+        state,
+        merchant_account,
+        key_store,
+        payout_data,
+    ))
+    .await?;
+
+    let payout_attempt = &payout_data.payout_attempt;
+    match connector_decision {
+        Err(error) if helpers::is_payout_err_state(payout_attempt.status) => {
+            if payout_attempt.payout_attempt_id == failed_payout_attempt_id {
+                return Err(error);
+            }
+            // The failure of the last backup destination is returned
+            Err(report!(errors::ApiErrorResponse::PayoutFailed {
+                data: Some(
+                    serde_json::json!({"payout_status": payout_attempt.status.to_string(), "error_message": payout_attempt.error_message.as_ref(), "error_code": payout_attempt.error_code.as_ref()})
+                ),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Retries a payout which failed because of its destination, for example a closed account, with
+/// its backup destinations in order, until one of them does not fail because of the destination
+/// or all of them are exhausted. Each destination is tried in a new attempt through the connector
+/// of the failed attempt. This is run both after calling the connector and when a failure is
+/// reported by a connector webhook. A backup destination failed by the connector is not returned as
+/// an error, the payout is left with the status of its last attempt.
+#[instrument(skip_all)]
+pub async fn failover_payout_destination(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let backup_destinations =
+        helpers::get_payout_backup_destinations(key_store, &payout_data.payouts).await?;
+    if backup_destinations.is_empty() {
+        return Ok(());
+    }
+
+    loop {
+        let Some(connector) = payout_data.payout_attempt.connector.clone() else {
+            break;
+        };
+        if !helpers::is_payout_destination_failure(state, &connector, &payout_data.payout_attempt)
+            .await
+        {
+            break;
+        }
+        let Some(destination) = helpers::get_next_payout_destination(
+            &backup_destinations,
+            payout_data.payout_attempt.destination_index,
+        ) else {
+            logger::info!("backup destinations exhausted for payout");
+            break;
+        };
+
+        let connector_data = api::ConnectorData::get_payout_connector_by_name(
+            &state.conf.connectors,
+            &connector,
+            api::GetToken::Connector,
+            payout_data.payout_attempt.merchant_connector_id.clone(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the connector data")?;
+
+        helpers::create_payout_destination_attempt(
+            state,
+            merchant_account,
+            &connector_data,
+            payout_data,
+            destination,
+        )
+        .await?;
+
+        let result = Box::pin(call_connector_payout(
+            state,
+            merchant_account,
+            key_store,
+            &connector_data,
+            payout_data,
+        ))
+        .await;
+        if let Err(error) = result {
+            if !helpers::is_connector_payout_failure(
+                error.current_context(),
+                payout_data.payout_attempt.status,
+            ) {
+                return Err(error);
+            }
+            logger::info!("backup destination of payout failed");
+        }
+    }
+
+    Ok(())
 }
 
+/// Creates a payout on behalf of `created_by`, the user or API key creating it, which can not
+/// approve the payout later.
 #[instrument(skip_all)]
 pub async fn payouts_create_core(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    req: payouts::PayoutCreateRequest,
+    mut req: payouts::PayoutCreateRequest,
+    created_by: Option<String>,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    templates::apply_payout_template(&state, &merchant_account.merchant_id, &mut req).await?;
+    req.connector = validator::validate_payout_connectors(req.connector)?;
+    req.recipient = Some(validator::get_payout_recipient(&req)?);
+    validator::validate_payout_destinations(&state, &merchant_account.merchant_id, &mut req)
+        .await?;
+
     // Validate create request
     let (payout_id, payout_method_data, profile_id) =
         validator::validate_create_request(&state, &merchant_account, &req, &key_store).await?;
@@ -315,6 +479,7 @@ pub async fn payouts_create_core(
         &payout_id,
         &profile_id,
         payout_method_data.as_ref(),
+        created_by,
     )
     .await?;
 
@@ -336,18 +501,26 @@ pub async fn payouts_create_core(
     .await?;
 
     if let Some(true) = payout_data.payouts.confirm {
-        payouts_core(
-            &state,
-            &merchant_account,
-            &key_store,
-            &mut payout_data,
-            req.routing.clone(),
-            req.connector.clone(),
-        )
-        .await?
+        match req.scheduled_at {
+            // Defer the disbursement to the scheduler
+            Some(scheduled_at) => {
+                schedule_payout(&state, &merchant_account, &mut payout_data, scheduled_at).await?
+            }
+            None => {
+                payouts_core(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    &mut payout_data,
+                    req.routing.clone(),
+                    req.connector.clone(),
+                )
+                .await?
+            }
+        }
     };
 
-    response_handler(&merchant_account, &payout_data).await
+    response_handler(&state, &merchant_account, &payout_data).await
 }
 
 #[instrument(skip_all)]
@@ -355,8 +528,20 @@ pub async fn payouts_confirm_core(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    req: payouts::PayoutCreateRequest,
+    mut req: payouts::PayoutCreateRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    req.connector = validator::validate_payout_connectors(req.connector)?;
+    let recipient = validator::get_payout_recipient(&req)?;
+    validator::validate_payout_recipient(&recipient)?;
+    req.recipient = Some(recipient);
+    validator::validate_payout_destinations(&state, &merchant_account.merchant_id, &mut req)
+        .await?;
+    utils::when(req.template_id.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "template_id can only be passed while creating a payout".to_string(),
+        }))
+    })?;
+
     let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
@@ -367,8 +552,20 @@ pub async fn payouts_confirm_core(
     let payout_attempt = payout_data.payout_attempt.to_owned();
     let status = payout_attempt.status;
 
-    helpers::update_payouts_and_payout_attempt(&mut payout_data, &merchant_account, &req, &state)
-        .await?;
+    validator::validate_payout_purpose_code_for_update(
+        &req,
+        payout_data.payouts.purpose_code.as_deref(),
+        payout_data.payout_method_data.as_ref(),
+        &state.conf.payouts.purpose_codes,
+    )?;
+    helpers::update_payouts_and_payout_attempt(
+        &mut payout_data,
+        &merchant_account,
+        &key_store,
+        &req,
+        &state,
+    )
+    .await?;
     helpers::validate_payout_status_against_not_allowed_statuses(
         &status,
         &[
@@ -380,6 +577,8 @@ pub async fn payouts_confirm_core(
             storage_enums::PayoutStatus::RequiresFulfillment,
             storage_enums::PayoutStatus::RequiresVendorAccountCreation,
             storage_enums::PayoutStatus::RequiresVendorAccountCreation,
+            storage_enums::PayoutStatus::PendingApproval,
+            storage_enums::PayoutStatus::PendingVerification,
         ],
         "confirm",
     )?;
@@ -413,15 +612,31 @@ pub async fn payouts_confirm_core(
     )
     .await?;
 
-    response_handler(&merchant_account, &payout_data).await
+    response_handler(&state, &merchant_account, &payout_data).await
 }
 
 pub async fn payouts_update_core(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    req: payouts::PayoutCreateRequest,
+    mut req: payouts::PayoutCreateRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    req.connector = validator::validate_payout_connectors(req.connector)?;
+    let recipient = validator::get_payout_recipient(&req)?;
+    validator::validate_payout_recipient(&recipient)?;
+    req.recipient = Some(recipient);
+    validator::validate_payout_destinations(&state, &merchant_account.merchant_id, &mut req)
+        .await?;
+
+    if let Some(description) = &req.description {
+        validator::validate_payout_description(description)?;
+    }
+    utils::when(req.template_id.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "template_id can only be passed while creating a payout".to_string(),
+        }))
+    })?;
+
     let payout_id = req.payout_id.clone().get_required_value("payout_id")?;
     let mut payout_data = make_payout_data(
         &state,
@@ -443,8 +658,20 @@ pub async fn payouts_update_core(
             ),
         }));
     }
-    helpers::update_payouts_and_payout_attempt(&mut payout_data, &merchant_account, &req, &state)
-        .await?;
+    validator::validate_payout_purpose_code_for_update(
+        &req,
+        payout_data.payouts.purpose_code.as_deref(),
+        payout_data.payout_method_data.as_ref(),
+        &state.conf.payouts.purpose_codes,
+    )?;
+    helpers::update_payouts_and_payout_attempt(
+        &mut payout_data,
+        &merchant_account,
+        &key_store,
+        &req,
+        &state,
+    )
+    .await?;
     let payout_attempt = payout_data.payout_attempt.to_owned();
 
     if (req.connector.is_none(), payout_attempt.connector.is_some()) != (true, true) {
@@ -477,7 +704,7 @@ pub async fn payouts_update_core(
     )
     .await?;
 
-    response_handler(&merchant_account, &payout_data).await
+    response_handler(&state, &merchant_account, &payout_data).await
 }
 
 #[instrument(skip_all)]
@@ -487,6 +714,7 @@ pub async fn payouts_retrieve_core(
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutRetrieveRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let timezone = helpers::get_payout_response_timezone(req.timezone.as_deref())?;
     let payout_data = make_payout_data(
         &state,
         &merchant_account,
@@ -495,102 +723,1049 @@ pub async fn payouts_retrieve_core(
     )
     .await?;
 
-    response_handler(&merchant_account, &payout_data).await
+    let response = response_handler(&state, &merchant_account, &payout_data).await?;
+    Ok(match response {
+        services::ApplicationResponse::Json(payout_response) => {
+            services::ApplicationResponse::Json(helpers::convert_payout_response_timezone(
+                payout_response,
+                timezone,
+            ))
+        }
+        response => response,
+    })
 }
 
+/// Retrieves a payout along with the fingerprint of its destination, for internal use by admins.
+///
+/// The fingerprint is also matched against the earlier payouts of the merchant, to flag payouts
+/// made to a destination which was previously used for a different customer.
+#[cfg(feature = "olap")]
 #[instrument(skip_all)]
-pub async fn payouts_cancel_core(
+pub async fn payouts_admin_retrieve_core(
     state: SessionState,
-    merchant_account: domain::MerchantAccount,
-    key_store: domain::MerchantKeyStore,
-    req: payouts::PayoutActionRequest,
-) -> RouterResponse<payouts::PayoutCreateResponse> {
-    let mut payout_data = make_payout_data(
+    merchant_id: String,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutAdminRetrieveResponse> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
+
+    let payout_data = make_payout_data(
         &state,
         &merchant_account,
         &key_store,
-        &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
+        &payouts::PayoutRequest::PayoutRetrieveRequest(payouts::PayoutRetrieveRequest {
+            payout_id,
+            force_sync: None,
+            merchant_id: Some(merchant_id.clone()),
+            timezone: None,
+        }),
     )
     .await?;
 
-    let payout_attempt = payout_data.payout_attempt.to_owned();
-    let status = payout_attempt.status;
-
-    // Verify if cancellation can be triggered
-    if helpers::is_payout_terminal_state(status) {
-        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
-            message: format!(
-                "Payout {} cannot be cancelled for status {}",
-                payout_attempt.payout_id, status
-            ),
-        }));
-
-    // Make local cancellation
-    } else if helpers::is_eligible_for_local_payout_cancellation(status) {
-        let status = storage_enums::PayoutStatus::Cancelled;
-        let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
-            connector_payout_id: payout_attempt.connector_payout_id.to_owned(),
-            status,
-            error_message: Some("Cancelled by user".to_string()),
-            error_code: None,
-            is_eligible: None,
-        };
-        payout_data.payout_attempt = state
-            .store
-            .update_payout_attempt(
-                &payout_attempt,
-                updated_payout_attempt,
-                &payout_data.payouts,
+    let payout_method_fingerprint = payout_data.payouts.payout_method_fingerprint.clone();
+    let payout_method_fingerprint_match = match payout_method_fingerprint.as_ref() {
+        Some(fingerprint) => db
+            .find_earlier_payout_by_fingerprint_for_other_customer(
+                &merchant_id,
+                fingerprint,
+                &payout_data.payouts.customer_id,
+                payout_data.payouts.created_at,
                 merchant_account.storage_scheme,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Error updating payout_attempt in db")?;
-        payout_data.payouts = state
-            .store
-            .update_payout(
-                &payout_data.payouts,
-                storage::PayoutsUpdate::StatusUpdate { status },
-                &payout_data.payout_attempt,
+            .attach_printable("Error while matching the payout method fingerprint")?
+            .is_some(),
+        None => false,
+    };
+
+    let payout = match response_handler(&state, &merchant_account, &payout_data).await? {
+        services::ApplicationResponse::Json(payout) => payout,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response while retrieving payout")?,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutAdminRetrieveResponse {
+            payout,
+            payout_method_fingerprint,
+            payout_method_fingerprint_match,
+        },
+    ))
+}
+
+/// Lists the currency conversions applied to the amount of a payout, for reconciliation by admins.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn payouts_admin_currency_conversions_core(
+    state: SessionState,
+    merchant_id: String,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutCurrencyConversionAuditLogResponse> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
+    db.find_payout_by_merchant_id_payout_id(
+        &merchant_id,
+        &payout_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    let data = db
+        .list_currency_conversion_audit_logs_by_payout_id(&merchant_id, &payout_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the currency conversions of the payout")?
+        .into_iter()
+        .map(|audit_log| payouts::CurrencyConversionAuditLog {
+            audit_id: audit_log.audit_id,
+            source_amount: audit_log.source_amount,
+            source_currency: audit_log.source_currency,
+            destination_currency: audit_log.destination_currency,
+            converted_amount: audit_log.converted_amount,
+            exchange_rate: audit_log.exchange_rate,
+            exchange_rate_source: audit_log.exchange_rate_source,
+            exchange_rate_timestamp: audit_log.exchange_rate_timestamp,
+            is_binding: audit_log.is_binding,
+            created_at: audit_log.created_at,
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutCurrencyConversionAuditLogResponse { payout_id, data },
+    ))
+}
+
+/// Validates the payout method data without creating a payout, the data is neither stored in the
+/// locker nor persisted in the database.
+#[instrument(skip_all)]
+pub fn validate_payout_method(
+    req: payouts::PayoutMethodValidationRequest,
+) -> RouterResponse<payouts::PayoutMethodValidationResponse> {
+    Ok(services::ApplicationResponse::Json(
+        validator::validate_payout_method_data(&req.payout_method_data),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payouts_bulk_retrieve_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutBulkRetrieveRequest,
+) -> RouterResponse<payouts::PayoutBulkRetrieveResponse> {
+    validator::validate_payout_bulk_retrieve_request(&req)?;
+    let db = &*state.store;
+    let force_sync = req.force_sync.unwrap_or(false);
+
+    let mut payout_ids = req.payout_ids;
+    let mut seen_payout_ids = HashSet::new();
+    payout_ids.retain(|payout_id| seen_payout_ids.insert(payout_id.clone()));
+
+    let mut data = Vec::with_capacity(payout_ids.len());
+    let mut not_found = Vec::new();
+    for payout_id in payout_ids {
+        let payout = match db
+            .find_payout_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &payout_id,
                 merchant_account.storage_scheme,
             )
             .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Error updating payouts in db")?;
-
-    // Trigger connector's cancellation
-    } else {
-        // Form connector data
-        let connector_data = match &payout_attempt.connector {
-            Some(connector) => api::ConnectorData::get_payout_connector_by_name(
-                &state.conf.connectors,
-                connector,
-                api::GetToken::Connector,
-                payout_attempt.merchant_connector_id.clone(),
-            )
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to get the connector data")?,
-            _ => Err(errors::ApplicationError::InvalidConfigurationValueError(
-                "Connector not found in payout_attempt - should not reach here".to_string(),
-            ))
-            .change_context(errors::ApiErrorResponse::MissingRequiredField {
-                field_name: "connector",
-            })
-            .attach_printable("Connector not found for payout cancellation")?,
+        {
+            Ok(payout) => payout,
+            Err(error) if error.current_context().is_db_not_found() => {
+                not_found.push(payout_id);
+                continue;
+            }
+            Err(error) => {
+                return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+            }
         };
 
-        cancel_payout(
+        // Payouts in a terminal state can no longer change at the connector, so only the rest
+        // are synced when requested
+        let retrieve_request = payouts::PayoutRetrieveRequest {
+            payout_id: payout.payout_id,
+            force_sync: Some(force_sync && !helpers::is_payout_terminal_state(payout.status)),
+            merchant_id: req.merchant_id.clone(),
+            timezone: None,
+        };
+        let payout_data = match make_payout_data(
             &state,
             &merchant_account,
             &key_store,
-            &connector_data,
-            &mut payout_data,
+            &payouts::PayoutRequest::PayoutRetrieveRequest(retrieve_request),
         )
         .await
-        .attach_printable("Payout cancellation failed for given Payout request")?;
+        {
+            Ok(payout_data) => payout_data,
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::PayoutNotFound
+                ) =>
+            {
+                not_found.push(payout_id);
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+
+        match response_handler(&state, &merchant_account, &payout_data).await? {
+            services::ApplicationResponse::Json(payout_response) => data.push(payout_response),
+            _ => {
+                return Err(report!(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unexpected response while retrieving payouts in bulk"))
+            }
+        }
     }
 
-    response_handler(&merchant_account, &payout_data).await
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBulkRetrieveResponse { data, not_found },
+    ))
+}
+
+/// Creates the payouts of a batch, each item independently of the others, so that an item which
+/// fails validation or creation is reported with its error without failing the other items.
+///
+/// The payout of an item is identified by the batch and the position of the item, unless the item
+/// passes its own payout id. Retrying a batch returns the payouts already created for its items
+/// instead of creating them again.
+#[instrument(skip_all)]
+pub async fn payouts_batch_create_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutBatchCreateRequest,
+    created_by: Option<String>,
+) -> RouterResponse<payouts::PayoutBatchCreateResponse> {
+    validator::validate_payout_batch_create_request(&req)?;
+    let payouts::PayoutBatchCreateRequest { batch_id, items } = req;
+    let db = &*state.store;
+
+    let mut item_responses = Vec::with_capacity(items.len());
+    for (index, mut item) in items.into_iter().enumerate() {
+        let payout_id = item
+            .payout_id
+            .clone()
+            .unwrap_or_else(|| format!("{batch_id}_{index}"));
+        item.payout_id = Some(payout_id.clone());
+        let currency = item.currency;
+
+        let existing_payout = db
+            .find_optional_payout_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to look up the payout of the batch item");
+        let result = match existing_payout {
+            Ok(Some(_)) => {
+                match make_payout_data(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    &payouts::PayoutRequest::PayoutRetrieveRequest(
+                        payouts::PayoutRetrieveRequest {
+                            payout_id: payout_id.clone(),
+                            force_sync: None,
+                            merchant_id: None,
+                            timezone: None,
+                        },
+                    ),
+                )
+                .await
+                {
+                    Ok(payout_data) => {
+                        response_handler(&state, &merchant_account, &payout_data).await
+                    }
+                    Err(error) => Err(error),
+                }
+            }
+            Ok(None) => {
+                Box::pin(payouts_create_core(
+                    state.clone(),
+                    merchant_account.clone(),
+                    key_store.clone(),
+                    item,
+                    created_by.clone(),
+                ))
+                .await
+            }
+            Err(error) => Err(error),
+        };
+        item_responses.push(get_payout_batch_item_response(
+            index, payout_id, currency, result,
+        ));
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBatchCreateResponse {
+            batch_id,
+            items: item_responses,
+        },
+    ))
+}
+
+fn get_payout_batch_item_response(
+    index: usize,
+    payout_id: String,
+    currency: Option<api_enums::Currency>,
+    result: RouterResponse<payouts::PayoutCreateResponse>,
+) -> payouts::PayoutBatchItemResponse {
+    let error = match result {
+        Ok(services::ApplicationResponse::Json(payout)) => {
+            return payouts::PayoutBatchItemResponse {
+                index,
+                payout_id,
+                currency: Some(payout.currency),
+                payout: Some(payout),
+                error: None,
+            }
+        }
+        Ok(_) => errors::ApiErrorResponse::InternalServerError,
+        Err(error) => {
+            logger::warn!(?error, "Failed to create payout {payout_id} of the batch");
+            error.current_context().clone()
+        }
+    };
+    payouts::PayoutBatchItemResponse {
+        index,
+        payout_id,
+        currency,
+        payout: None,
+        error: Some(payouts::PayoutBatchItemError {
+            code: error.error_code(),
+            message: error.error_message(),
+        }),
+    }
+}
+
+/// Resolves a payout from the identifier assigned to it by the connector. A connector reference
+/// is only unique per connector, so the request is rejected if it matches payouts across more
+/// than one connector and no connector was specified.
+#[instrument(skip_all)]
+pub async fn payouts_retrieve_by_connector_reference_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutRetrieveByConnectorReferenceRequest,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let db = &*state.store;
+    let connector = req.connector.map(|connector| connector.to_string());
+
+    let payout_attempts = db
+        .list_payout_attempts_by_merchant_id_connector_payout_id(
+            &merchant_account.merchant_id,
+            &req.connector_payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout attempts by connector payout id")?
+        .into_iter()
+        .filter(|payout_attempt| {
+            connector.is_none() || payout_attempt.connector.as_ref() == connector.as_ref()
+        })
+        .collect::<Vec<_>>();
+
+    let payout_ids = payout_attempts
+        .iter()
+        .map(|payout_attempt| payout_attempt.payout_id.as_str())
+        .collect::<HashSet<_>>();
+
+    // Attempts are ordered by most recent first
+    let payout_id = match payout_attempts.first() {
+        Some(payout_attempt) if payout_ids.len() == 1 => payout_attempt.payout_id.clone(),
+        Some(_) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "connector_payout_id `{}` matches more than one payout, specify the connector",
+                req.connector_payout_id
+            ),
+        }))?,
+        None => Err(report!(errors::ApiErrorResponse::PayoutNotFound))?,
+    };
+
+    payouts_retrieve_core(
+        state,
+        merchant_account,
+        key_store,
+        payouts::PayoutRetrieveRequest {
+            payout_id,
+            force_sync: None,
+            merchant_id: None,
+            timezone: req.timezone,
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn payouts_receipt_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutRetrieveRequest,
+) -> RouterResponse<api_models::payouts::PayoutReceiptResponse> {
+    let payout_data = make_payout_data(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payouts::PayoutRequest::PayoutRetrieveRequest(req.to_owned()),
+    )
+    .await?;
+
+    let payouts = payout_data.payouts;
+    let payout_attempt = payout_data.payout_attempt;
+    let status = payout_attempt.status;
+
+    // Receipts can only be generated once the payout has been disbursed
+    if !helpers::is_payout_terminal_state(status) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Receipt for payout {} is not available yet as it is in {} status",
+                payouts.payout_id, status
+            ),
+        }));
+    } else if status != storage_enums::PayoutStatus::Success {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Receipt is only available for successful payouts, payout {} is in {} status",
+                payouts.payout_id, status
+            ),
+        }));
+    }
+
+    let response = api_models::payouts::PayoutReceiptResponse {
+        payout_id: payouts.payout_id,
+        merchant_id: merchant_account.merchant_id,
+        connector: payout_attempt.connector,
+        connector_payout_id: payout_attempt.connector_payout_id,
+        payout_type: payouts.payout_type,
+        masked_destination: payout_data
+            .payout_method_data
+            .as_ref()
+            .and_then(helpers::get_masked_payout_destination),
+        amount: payouts.amount,
+        currency: payouts.destination_currency,
+        completed_at: payout_attempt.last_modified_at,
+    };
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn payouts_cancel_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutActionRequest,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let mut payout_data = make_payout_data(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
+    )
+    .await?;
+
+    let payout_attempt = payout_data.payout_attempt.to_owned();
+    let status = payout_attempt.status;
+
+    // Verify if cancellation can be triggered
+    if helpers::is_payout_terminal_state(status) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout {} cannot be cancelled for status {}",
+                payout_attempt.payout_id, status
+            ),
+        }));
+
+    // Make local cancellation
+    } else if helpers::is_eligible_for_local_payout_cancellation(status) {
+        if !cancel_payout_locally(&state, &merchant_account, &mut payout_data).await? {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Payout {} is already being processed and cannot be cancelled",
+                    payout_attempt.payout_id
+                ),
+            }));
+        }
+
+    // Trigger connector's cancellation
+    } else {
+        // Form connector data
+        let connector_data = match &payout_attempt.connector {
+            Some(connector) => api::ConnectorData::get_payout_connector_by_name(
+                &state.conf.connectors,
+                connector,
+                api::GetToken::Connector,
+                payout_attempt.merchant_connector_id.clone(),
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get the connector data")?,
+            _ => Err(errors::ApplicationError::InvalidConfigurationValueError(
+                "Connector not found in payout_attempt - should not reach here".to_string(),
+            ))
+            .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "connector",
+            })
+            .attach_printable("Connector not found for payout cancellation")?,
+        };
+
+        cancel_payout(
+            &state,
+            &merchant_account,
+            &key_store,
+            &connector_data,
+            &mut payout_data,
+        )
+        .await
+        .attach_printable("Payout cancellation failed for given Payout request")?;
+    }
+
+    response_handler(&state, &merchant_account, &payout_data).await
+}
+
+/// Cancels a payout which has not been sent to a connector yet, revoking any of its pending tasks.
+/// Returns whether the payout was cancelled, which it is not if it was moved out of its status
+/// concurrently.
+async fn cancel_payout_locally(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+) -> RouterResult<bool> {
+    let payout_attempt = payout_data.payout_attempt.to_owned();
+    let status = storage_enums::PayoutStatus::Cancelled;
+    let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
+        connector_payout_id: payout_attempt.connector_payout_id.to_owned(),
+        status,
+        error_message: Some("Cancelled by user".to_string()),
+        error_code: None,
+        is_eligible: None,
+        reason_code: Some(storage_enums::PayoutStatusReasonCode::MerchantCancelled),
+        reason: None,
+    };
+    if !helpers::update_payout_status_if(
+        state,
+        merchant_account,
+        payout_data,
+        payout_attempt.status,
+        updated_payout_attempt,
+        storage::PayoutsUpdate::StatusUpdate { status },
+    )
+    .await?
+    {
+        return Ok(false);
+    }
+
+    // Scheduled payouts also need their pending disbursement task to be revoked
+    if payout_attempt.status == storage_enums::PayoutStatus::Scheduled {
+        revoke_scheduled_payout_task(&*state.store, payout_data)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while revoking scheduled payout task")?;
+    }
+    // Payouts awaiting a verification also need their expiry task to be revoked
+    if payout_attempt.status == storage_enums::PayoutStatus::PendingVerification {
+        revoke_payout_verification_expiry_task(&*state.store, payout_data)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while revoking payout verification expiry task")?;
+    }
+    Ok(true)
+}
+
+/// Cancels the runs of a recurring payout series which are still scheduled. Runs which were
+/// already disbursed, or are being disbursed, are left as they are, so cancelling a series with
+/// no scheduled runs left cancels nothing.
+#[instrument(skip_all)]
+pub async fn payouts_series_cancel_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutSeriesCancelRequest,
+) -> RouterResponse<payouts::PayoutSeriesCancelResponse> {
+    let series_payouts = state
+        .store
+        .find_payouts_by_merchant_id_series_id(
+            &merchant_account.merchant_id,
+            &req.series_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payouts by series_id")?;
+    if series_payouts.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::PayoutNotFound))
+            .attach_printable_lazy(|| format!("No payouts found for series {}", req.series_id));
+    }
+
+    let mut cancelled_count = 0;
+    for payout in series_payouts
+        .into_iter()
+        .filter(|payout| payout.status == storage_enums::PayoutStatus::Scheduled)
+    {
+        let mut payout_data = make_payout_data(
+            &state,
+            &merchant_account,
+            &key_store,
+            &payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest {
+                payout_id: payout.payout_id,
+            }),
+        )
+        .await?;
+        // The run may have been disbursed since the series was read
+        if payout_data.payout_attempt.status != storage_enums::PayoutStatus::Scheduled {
+            continue;
+        }
+        if cancel_payout_locally(&state, &merchant_account, &mut payout_data).await? {
+            cancelled_count += 1;
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutSeriesCancelResponse {
+            series_id: req.series_id,
+            cancelled_count,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payouts_approve_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutApproveRequest,
+    approved_by: String,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let mut payout_data = make_payout_data(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest {
+            payout_id: req.payout_id.clone(),
+        }),
+    )
+    .await?;
+
+    // Verify if the payout is awaiting an approval
+    let status = payout_data.payout_attempt.status;
+    if status != storage_enums::PayoutStatus::PendingApproval {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout {} cannot be approved for status {}",
+                req.payout_id, status
+            ),
+        }));
+    }
+
+    // Payouts are approved by someone other than their creator
+    helpers::validate_payout_approver(&payout_data.payouts, &approved_by)?;
+
+    // Record the approval and resume the disbursement. The payout is only approved if it is still
+    // pending approval, so that concurrent approvals disburse it at most once.
+    let status = storage_enums::PayoutStatus::RequiresCreation;
+    let updated_payout_attempt = helpers::get_payout_status_attempt_update(
+        payout_data.payout_attempt.connector_payout_id.to_owned(),
+        status,
+        None,
+        None,
+    );
+    let updated_payouts = storage::PayoutsUpdate::ApprovalUpdate {
+        approved_by,
+        approved_at: common_utils::date_time::now(),
+        status,
+    };
+    let is_approved = helpers::update_payout_status_if(
+        &state,
+        &merchant_account,
+        &mut payout_data,
+        storage_enums::PayoutStatus::PendingApproval,
+        updated_payout_attempt,
+        updated_payouts,
+    )
+    .await?;
+    if !is_approved {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Payout {} is already approved or being processed",
+                req.payout_id
+            ),
+        }));
+    }
+
+    payouts_core(
+        &state,
+        &merchant_account,
+        &key_store,
+        &mut payout_data,
+        None,
+        None,
+    )
+    .await?;
+
+    response_handler(&state, &merchant_account, &payout_data).await
+}
+
+/// Records the result of the document verification of the recipient of a payout. A verified
+/// payout resumes its disbursement, which may still be held for an approval, while a rejected
+/// payout fails.
+#[instrument(skip_all)]
+pub async fn payouts_verify_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutVerificationRequest,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    validator::validate_payout_verification_request(&req)?;
+
+    let mut payout_data = make_payout_data(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest {
+            payout_id: req.payout_id.clone(),
+        }),
+    )
+    .await?;
+
+    // Verify if the payout is awaiting a verification
+    let status = payout_data.payout_attempt.status;
+    if status != storage_enums::PayoutStatus::PendingVerification {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout {} cannot be verified for status {}",
+                req.payout_id, status
+            ),
+        }));
+    }
+
+    // The payout may have expired since it was fetched
+    let is_resolved = resolve_payout_verification(
+        &state,
+        &merchant_account,
+        &mut payout_data,
+        Some(req.result),
+        req.verification_reference,
+        req.reason,
+    )
+    .await?;
+    if !is_resolved {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Payout {} is no longer pending verification", req.payout_id),
+        }));
+    }
+
+    revoke_payout_verification_expiry_task(&*state.store, &payout_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while revoking payout verification expiry task")?;
+
+    match req.result {
+        payouts::PayoutVerificationResult::Verified => {
+            payouts_core(
+                &state,
+                &merchant_account,
+                &key_store,
+                &mut payout_data,
+                None,
+                None,
+            )
+            .await?;
+
+            response_handler(&state, &merchant_account, &payout_data).await
+        }
+        payouts::PayoutVerificationResult::Rejected => {
+            let response = response_handler(&state, &merchant_account, &payout_data).await?;
+            if let (Some(event_type), services::ApplicationResponse::Json(payout_response)) = (
+                Option::<storage_enums::EventType>::foreign_from(payout_data.payouts.status),
+                &response,
+            ) {
+                crate::core::webhooks::create_event_and_trigger_outgoing_webhook(
+                    state,
+                    merchant_account,
+                    payout_data.business_profile,
+                    &key_store,
+                    event_type,
+                    storage_enums::EventClass::Payouts,
+                    payout_data.payouts.payout_id,
+                    storage_enums::EventObjectType::PayoutDetails,
+                    api::OutgoingWebhookContent::PayoutDetails(payout_response.clone()),
+                    Some(payout_data.payout_attempt.created_at),
+                )
+                .await?;
+            }
+            Ok(response)
+        }
+    }
+}
+
+/// Moves a payout held in `pending_verification` to the status for the posted verification
+/// result, or expires it when no result is given. The attempt is only updated if it is still
+/// pending verification, so that a result posted while the payout expires is applied at most
+/// once. Returns whether the payout was moved.
+pub async fn resolve_payout_verification(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+    result: Option<payouts::PayoutVerificationResult>,
+    verification_reference: Option<String>,
+    reason: Option<String>,
+) -> RouterResult<bool> {
+    let updated_payout_attempt = helpers::get_payout_verification_attempt_update(
+        payout_data.payout_attempt.connector_payout_id.to_owned(),
+        result,
+        reason,
+    );
+    let status = helpers::get_payout_verification_status(result);
+    let updated_payouts = match result {
+        Some(payouts::PayoutVerificationResult::Verified) => {
+            storage::PayoutsUpdate::VerificationUpdate {
+                verified_at: common_utils::date_time::now(),
+                verification_reference,
+                status,
+            }
+        }
+        _ => storage::PayoutsUpdate::StatusUpdate { status },
+    };
+    helpers::update_payout_status_if(
+        state,
+        merchant_account,
+        payout_data,
+        storage_enums::PayoutStatus::PendingVerification,
+        updated_payout_attempt,
+        updated_payouts,
+    )
+    .await
+}
+
+/// Reprocesses a failed payout with the corrected payout method data or billing address, in a new
+/// attempt through the connector of the failed attempt.
+#[instrument(skip_all)]
+pub async fn payouts_reprocess_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutReprocessRequest,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    utils::when(
+        req.payout_method_data.is_none() && req.billing.is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payout_method_data or billing is required to reprocess a payout"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    let mut payout_data = make_payout_data(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest {
+            payout_id: req.payout_id.clone(),
+        }),
+    )
+    .await?;
+
+    // Only failed payouts are reprocessed, successful payouts in particular are never disbursed
+    // again
+    let status = payout_data.payout_attempt.status;
+    if status != storage_enums::PayoutStatus::Failed {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout {} cannot be reprocessed for status {}",
+                req.payout_id, status
+            ),
+        }));
+    }
+
+    if let Some(payout_method_data) = req.payout_method_data.as_ref() {
+        validator::validate_reprocess_payout_method_data(
+            payout_method_data,
+            payout_data.payouts.payout_type,
+            &payout_data.payouts.customer_id,
+        )?;
+    }
+
+    let db = &*state.store;
+    if let Some(billing) = req.billing.as_ref() {
+        let billing_address = payment_helpers::create_or_find_address_for_payment_by_request(
+            db,
+            Some(billing),
+            None,
+            &merchant_account.merchant_id,
+            Some(&payout_data.payouts.customer_id),
+            &key_store,
+            &payout_data.payouts.payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await?
+        .get_required_value("billing")?;
+        payout_data.payouts = db
+            .update_payout(
+                &payout_data.payouts,
+                storage::PayoutsUpdate::AddressUpdate {
+                    address_id: billing_address.address_id.clone(),
+                },
+                &payout_data.payout_attempt,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating the billing address of payouts")?;
+        payout_data.billing_address = Some(billing_address);
+    }
+
+    helpers::create_payout_reprocess_attempt(
+        &state,
+        &merchant_account,
+        &mut payout_data,
+        req.payout_method_data.is_none(),
+    )
+    .await?;
+
+    let payout_attempt = payout_data.payout_attempt.to_owned();
+    payout_data.payout_method_data = helpers::make_payout_method_data(
+        &state,
+        req.payout_method_data.as_ref(),
+        payout_attempt.payout_token.as_deref(),
+        &payout_attempt.customer_id,
+        &payout_attempt.merchant_id,
+        payout_data.payouts.payout_type,
+        &key_store,
+        Some(&mut payout_data),
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    payouts_core(
+        &state,
+        &merchant_account,
+        &key_store,
+        &mut payout_data,
+        None,
+        None,
+    )
+    .await?;
+
+    response_handler(&state, &merchant_account, &payout_data).await
+}
+
+/// Records the recall of a successful payout by the bank of the recipient, and notifies the
+/// merchant of it. Recalls are clawbacks of funds which were credited to the recipient, unlike
+/// reversals which are reported by the connector when the funds could not be delivered.
+#[instrument(skip_all)]
+pub async fn payouts_recall_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutRecallRequest,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    validator::validate_payout_recall_reason(req.reason.as_deref())?;
+
+    let mut payout_data = make_payout_data(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest {
+            payout_id: req.payout_id.clone(),
+        }),
+    )
+    .await?;
+
+    let status = payout_data.payout_attempt.status;
+    if status != storage_enums::PayoutStatus::Success {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout {} cannot be recalled for status {}",
+                req.payout_id, status
+            ),
+        }));
+    }
+    let recalled_amount = req.amount.unwrap_or(payout_data.payouts.amount);
+    utils::when(
+        recalled_amount <= MinorUnit::zero() || recalled_amount > payout_data.payouts.amount,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount must be greater than 0 and not exceed the amount of the payout"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    let db = &*state.store;
+    let status = storage_enums::PayoutStatus::Recalled;
+    payout_data.payout_attempt = db
+        .update_payout_attempt(
+            &payout_data.payout_attempt,
+            storage::PayoutAttemptUpdate::RecallUpdate {
+                status,
+                reason: req.reason,
+                recalled_amount,
+            },
+            &payout_data.payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?;
+    payout_data.payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+    helpers::record_payout_time_to_fulfillment(&payout_data.payouts, &payout_data.payout_attempt);
+
+    let response = response_handler(&state, &merchant_account, &payout_data).await?;
+    if let (Some(event_type), services::ApplicationResponse::Json(payout_response)) = (
+        Option::<storage_enums::EventType>::foreign_from(status),
+        &response,
+    ) {
+        crate::core::webhooks::create_event_and_trigger_outgoing_webhook(
+            state,
+            merchant_account,
+            payout_data.business_profile,
+            &key_store,
+            event_type,
+            storage_enums::EventClass::Payouts,
+            payout_data.payouts.payout_id,
+            storage_enums::EventObjectType::PayoutDetails,
+            api::OutgoingWebhookContent::PayoutDetails(payout_response.clone()),
+            Some(payout_data.payout_attempt.created_at),
+        )
+        .await?;
+    }
+    Ok(response)
 }
 
 #[instrument(skip_all)]
@@ -676,7 +1851,7 @@ pub async fn payouts_fulfill_core(
         }));
     }
 
-    response_handler(&merchant_account, &payout_data).await
+    response_handler(&state, &merchant_account, &payout_data).await
 }
 
 #[cfg(feature = "olap")]
@@ -687,6 +1862,7 @@ pub async fn payouts_list_core(
     constraints: payouts::PayoutListConstraints,
 ) -> RouterResponse<payouts::PayoutListResponse> {
     validator::validate_payout_list_request(&constraints)?;
+    let timezone = helpers::get_payout_response_timezone(constraints.timezone.as_deref())?;
     let merchant_id = &merchant_account.merchant_id;
     let db = state.store.as_ref();
     let payouts = helpers::filter_by_constraints(
@@ -766,12 +1942,20 @@ pub async fn payouts_list_core(
         .change_context(errors::ApiErrorResponse::InternalServerError)?
         .into_iter()
         .map(ForeignFrom::foreign_from)
+        .map(|payout_response| helpers::convert_payout_response_timezone(payout_response, timezone))
         .collect();
+    let reporting_amounts = constraints
+        .reporting_currency
+        .async_map(|reporting_currency| {
+            reporting::convert_payouts_to_reporting_currency(&state, &data, reporting_currency)
+        })
+        .await;
 
     Ok(services::ApplicationResponse::Json(
         api::PayoutListResponse {
             size: data.len(),
             data,
+            reporting_amounts,
         },
     ))
 }
@@ -785,6 +1969,7 @@ pub async fn payouts_filtered_list_core(
 ) -> RouterResponse<payouts::PayoutListResponse> {
     let limit = &filters.limit;
     validator::validate_payout_list_request_for_joins(*limit)?;
+    let timezone = helpers::get_payout_response_timezone(filters.timezone.as_deref())?;
     let db = state.store.as_ref();
     let list: Vec<(
         storage::Payouts,
@@ -816,12 +2001,20 @@ pub async fn payouts_filtered_list_core(
     .into_iter()
     .flatten()
     .map(ForeignFrom::foreign_from)
+    .map(|payout_response| helpers::convert_payout_response_timezone(payout_response, timezone))
     .collect();
+    let reporting_amounts = filters
+        .reporting_currency
+        .async_map(|reporting_currency| {
+            reporting::convert_payouts_to_reporting_currency(&state, &data, reporting_currency)
+        })
+        .await;
 
     Ok(services::ApplicationResponse::Json(
         api::PayoutListResponse {
             size: data.len(),
             data,
+            reporting_amounts,
         },
     ))
 }
@@ -861,6 +2054,40 @@ pub async fn payouts_list_available_filters_core(
     ))
 }
 
+/// Lists the connectors used for the payouts of the merchant created in the time range, with the
+/// number of payout attempts made through each of them, most used first.
+#[cfg(feature = "olap")]
+pub async fn payouts_connector_usage_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    time_range: api::TimeRange,
+) -> RouterResponse<api::PayoutConnectorUsageResponse> {
+    let db = state.store.as_ref();
+    let mut usage = db
+        .get_connector_usage_for_payouts(
+            &merchant_account.merchant_id,
+            &time_range.into(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the connector usage of payouts")?;
+    usage.sort_by(|a, b| b.count.cmp(&a.count));
+
+    Ok(services::ApplicationResponse::Json(
+        api::PayoutConnectorUsageResponse {
+            data: usage
+                .into_iter()
+                .map(|usage| api::PayoutConnectorUsage {
+                    connector: usage.connector,
+                    count: usage.count,
+                    last_used_at: usage.last_used_at,
+                })
+                .collect(),
+        },
+    ))
+}
+
 // ********************************************** HELPERS **********************************************
 pub async fn call_connector_payout(
     state: &SessionState,
@@ -894,6 +2121,24 @@ pub async fn call_connector_payout(
             .attach_printable("Error updating routing info in payout_attempt")?;
     };
 
+    // Validate the priority against the connector's capabilities
+    helpers::validate_payout_priority_for_connector(
+        state,
+        merchant_account,
+        connector_data,
+        payout_data,
+    )
+    .await?;
+
+    // Validate the settlement network against the connector's capabilities
+    helpers::validate_payout_settlement_network_for_connector(
+        state,
+        merchant_account,
+        connector_data,
+        payout_data,
+    )
+    .await?;
+
     // Fetch / store payout_method_data
     if payout_data.payout_method_data.is_none() || payout_attempt.payout_token.is_none() {
         payout_data.payout_method_data = Some(
@@ -912,6 +2157,24 @@ pub async fn call_connector_payout(
             .get_required_value("payout_method_data")?,
         );
     }
+
+    helpers::validate_payout_charge_bearer_for_connector(
+        connector_data.connector_name,
+        payouts.charge_bearer.unwrap_or_default(),
+    )?;
+
+    if payouts.value_date.is_some() && !connector_data.connector_name.supports_payout_value_date() {
+        logger::warn!(
+            "{} does not support value dates, the value date of the payout is ignored",
+            connector_data.connector_name
+        );
+    }
+
+    // Validate the payout method against the connector's capabilities
+    helpers::validate_payout_method_for_connector(
+        connector_data,
+        payout_data.payout_method_data.as_ref(),
+    )?;
     // Eligibility flow
     complete_payout_eligibility(
         state,
@@ -1100,6 +2363,8 @@ pub async fn create_recipient(
                         error_code: None,
                         error_message: None,
                         is_eligible: recipient_create_data.payout_eligible,
+                        reason_code: helpers::get_connector_status_reason_code(status),
+                        reason: None,
                     };
                     payout_data.payout_attempt = db
                         .update_payout_attempt(
@@ -1121,6 +2386,10 @@ pub async fn create_recipient(
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
                         .attach_printable("Error updating payouts in db")?;
+                    helpers::record_payout_time_to_fulfillment(
+                        &payout_data.payouts,
+                        &payout_data.payout_attempt,
+                    );
 
                     // Helps callee functions skip the execution
                     payout_data.should_terminate = true;
@@ -1143,6 +2412,13 @@ pub async fn complete_payout_eligibility(
 ) -> RouterResult<()> {
     let payout_attempt = &payout_data.payout_attempt.to_owned();
 
+    helpers::validate_payout_currency_for_connector(
+        &state.conf.payouts.currency_support,
+        connector_data.connector_name,
+        payout_data.payouts.payout_type,
+        payout_data.payouts.destination_currency,
+    )?;
+
     if !payout_data.should_terminate
         && payout_attempt.is_eligible.is_none()
         && connector_data
@@ -1226,6 +2502,8 @@ pub async fn check_payout_eligibility(
                 error_code: None,
                 error_message: None,
                 is_eligible: payout_response_data.payout_eligible,
+                reason_code: helpers::get_connector_status_reason_code(status),
+                reason: None,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1247,6 +2525,10 @@ pub async fn check_payout_eligibility(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            helpers::record_payout_time_to_fulfillment(
+                &payout_data.payouts,
+                &payout_data.payout_attempt,
+            );
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1263,6 +2545,8 @@ pub async fn check_payout_eligibility(
                 error_code: Some(err.code),
                 error_message: Some(err.message),
                 is_eligible: Some(false),
+                reason_code: Some(storage_enums::PayoutStatusReasonCode::ConnectorError),
+                reason: err.reason,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1284,6 +2568,10 @@ pub async fn check_payout_eligibility(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            helpers::record_payout_time_to_fulfillment(
+                &payout_data.payouts,
+                &payout_data.payout_attempt,
+            );
         }
     };
 
@@ -1318,6 +2606,8 @@ pub async fn complete_create_payout(
                 error_code: None,
                 error_message: None,
                 is_eligible: None,
+                reason_code: None,
+                reason: None,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1419,6 +2709,8 @@ pub async fn create_payout(
                 error_code: None,
                 error_message: None,
                 is_eligible: payout_response_data.payout_eligible,
+                reason_code: helpers::get_connector_status_reason_code(status),
+                reason: None,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1440,6 +2732,10 @@ pub async fn create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            helpers::record_payout_time_to_fulfillment(
+                &payout_data.payouts,
+                &payout_data.payout_attempt,
+            );
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1456,6 +2752,8 @@ pub async fn create_payout(
                 error_code: Some(err.code),
                 error_message: Some(err.message),
                 is_eligible: None,
+                reason_code: Some(storage_enums::PayoutStatusReasonCode::ConnectorError),
+                reason: err.reason,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1477,6 +2775,10 @@ pub async fn create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            helpers::record_payout_time_to_fulfillment(
+                &payout_data.payouts,
+                &payout_data.payout_attempt,
+            );
         }
     };
 
@@ -1597,6 +2899,8 @@ pub async fn create_recipient_disburse_account(
                 error_code: None,
                 error_message: None,
                 is_eligible: payout_response_data.payout_eligible,
+                reason_code: helpers::get_connector_status_reason_code(status),
+                reason: None,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1616,6 +2920,8 @@ pub async fn create_recipient_disburse_account(
                 error_code: Some(err.code),
                 error_message: Some(err.message),
                 is_eligible: None,
+                reason_code: Some(storage_enums::PayoutStatusReasonCode::ConnectorError),
+                reason: err.reason,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1629,6 +2935,7 @@ pub async fn create_recipient_disburse_account(
                 .attach_printable("Error updating payout_attempt in db")?;
         }
     };
+    helpers::record_payout_time_to_fulfillment(&payout_data.payouts, &payout_data.payout_attempt);
 
     Ok(())
 }
@@ -1675,12 +2982,21 @@ pub async fn cancel_payout(
             let status = payout_response_data
                 .status
                 .unwrap_or(payout_data.payout_attempt.status.to_owned());
+            // Cancellations confirmed by the connector were initiated by the merchant
+            let reason_code = match status {
+                storage_enums::PayoutStatus::Cancelled => {
+                    Some(storage_enums::PayoutStatusReasonCode::MerchantCancelled)
+                }
+                _ => helpers::get_connector_status_reason_code(status),
+            };
             let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
                 connector_payout_id: payout_response_data.connector_payout_id,
                 status,
                 error_code: None,
                 error_message: None,
                 is_eligible: payout_response_data.payout_eligible,
+                reason_code,
+                reason: None,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1702,6 +3018,10 @@ pub async fn cancel_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            helpers::record_payout_time_to_fulfillment(
+                &payout_data.payouts,
+                &payout_data.payout_attempt,
+            );
         }
         Err(err) => {
             let status = storage_enums::PayoutStatus::Failed;
@@ -1711,6 +3031,8 @@ pub async fn cancel_payout(
                 error_code: Some(err.code),
                 error_message: Some(err.message),
                 is_eligible: None,
+                reason_code: Some(storage_enums::PayoutStatusReasonCode::ConnectorError),
+                reason: err.reason,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1732,6 +3054,10 @@ pub async fn cancel_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            helpers::record_payout_time_to_fulfillment(
+                &payout_data.payouts,
+                &payout_data.payout_attempt,
+            );
         }
     };
 
@@ -1813,6 +3139,8 @@ pub async fn fulfill_payout(
                 error_code: None,
                 error_message: None,
                 is_eligible: payout_response_data.payout_eligible,
+                reason_code: helpers::get_connector_status_reason_code(status),
+                reason: None,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1834,6 +3162,10 @@ pub async fn fulfill_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            helpers::record_payout_time_to_fulfillment(
+                &payout_data.payouts,
+                &payout_data.payout_attempt,
+            );
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1850,6 +3182,8 @@ pub async fn fulfill_payout(
                 error_code: Some(err.code),
                 error_message: Some(err.message),
                 is_eligible: None,
+                reason_code: Some(storage_enums::PayoutStatusReasonCode::ConnectorError),
+                reason: err.reason,
             };
             payout_data.payout_attempt = db
                 .update_payout_attempt(
@@ -1871,13 +3205,91 @@ pub async fn fulfill_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            helpers::record_payout_time_to_fulfillment(
+                &payout_data.payouts,
+                &payout_data.payout_attempt,
+            );
         }
     };
 
     Ok(())
 }
 
+/// Settles a store credit payout against the merchant's internal ledger.
+///
+/// Since there is no external connector involved, connector routing and eligibility checks are
+/// skipped. The payout is marked as successful once the credit is recorded on the ledger, and a
+/// payout is credited at most once even if it is processed again.
+#[instrument(skip_all)]
+pub async fn credit_store_credit_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+
+    if payout_data.payout_method_data.is_none() {
+        let payout_attempt = payout_data.payout_attempt.to_owned();
+        payout_data.payout_method_data = helpers::make_payout_method_data(
+            state,
+            None,
+            payout_attempt.payout_token.as_deref(),
+            &payout_attempt.customer_id,
+            &payout_attempt.merchant_id,
+            payout_data.payouts.payout_type,
+            key_store,
+            Some(payout_data),
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    }
+    let store_credit = validator::validate_store_credit_payout(
+        payout_data.payouts.payout_type,
+        payout_data.payout_method_data.as_ref(),
+        Some(&payout_data.payouts.customer_id),
+    )?
+    .get_required_value("payout_method_data.store_credit")?
+    .to_owned();
+
+    let ledger_entry = storage::StoreCreditLedgerEntryNew {
+        entry_id: utils::generate_id(consts::ID_LENGTH, "store_credit"),
+        merchant_id: merchant_account.merchant_id.to_owned(),
+        customer_id: store_credit.customer_id,
+        credit_account_id: store_credit.credit_account_id,
+        payout_id: payout_data.payouts.payout_id.to_owned(),
+        amount: payout_data.payouts.amount,
+        currency: payout_data.payouts.destination_currency,
+        created_at: common_utils::date_time::now(),
+    };
+    match db.insert_store_credit_ledger_entry(ledger_entry).await {
+        Ok(_) => Ok(()),
+        // The payout was credited by an earlier attempt which failed to update its status
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::info!(
+                "Store credit for payout {} is already recorded on the ledger",
+                payout_data.payouts.payout_id
+            );
+            Ok(())
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record store credit on the ledger"),
+    }?;
+
+    helpers::update_payout_status(
+        state,
+        merchant_account,
+        payout_data,
+        storage_enums::PayoutStatus::Success,
+        None,
+        None,
+    )
+    .await
+}
+
 pub async fn response_handler(
+    state: &SessionState,
     merchant_account: &domain::MerchantAccount,
     payout_data: &PayoutData,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
@@ -1887,6 +3299,7 @@ pub async fn response_handler(
     let billing_address = payout_data.billing_address.to_owned();
     let customer_details = payout_data.customer_details.to_owned();
     let customer_id = payouts.customer_id;
+    let scheduled_at = helpers::get_payout_scheduled_at(state, payout_data).await;
 
     let (email, name, phone, phone_country_code) = customer_details
         .map_or((None, None, None, None), |c| {
@@ -1916,6 +3329,9 @@ pub async fn response_handler(
         }
     });
 
+    let connector_details = payouts::PayoutConnectorDetails::foreign_from(&payout_attempt);
+    let currency_conversion = helpers::get_payout_currency_conversion(&payout_data.payouts);
+
     let response = api::PayoutCreateResponse {
         payout_id: payouts.payout_id.to_owned(),
         merchant_id: merchant_account.merchant_id.to_owned(),
@@ -1942,14 +3358,70 @@ pub async fn response_handler(
         error_message: payout_attempt.error_message.to_owned(),
         error_code: payout_attempt.error_code,
         profile_id: payout_attempt.profile_id,
-        created: Some(payouts.created_at),
+        created: Some(payouts.created_at.assume_utc()),
         connector_transaction_id: payout_attempt.connector_payout_id,
+        connector_details: Some(connector_details),
         priority: payouts.priority,
+        settlement_network: payouts.settlement_network,
+        purpose_code: payouts.purpose_code.to_owned(),
+        webhook_url: payouts.webhook_url.to_owned(),
+        value_date: helpers::get_effective_payout_value_date(
+            &payout_data.payouts,
+            &payout_data.payout_attempt,
+        ),
+        fee_breakdown: helpers::get_payout_fee_breakdown(&payout_data.payouts),
+        statement_descriptor: helpers::get_effective_payout_statement_descriptor(
+            &payout_data.payouts,
+            &payout_data.payout_attempt,
+        ),
+        merchant_reference_id: payouts.merchant_reference_id.clone(),
+        series_id: payouts.series_id.clone(),
+        source_amount: currency_conversion
+            .as_ref()
+            .map(|conversion| conversion.source_amount),
+        source_currency: currency_conversion
+            .as_ref()
+            .map(|_| payouts.source_currency),
+        exchange_rate: currency_conversion
+            .as_ref()
+            .map(|conversion| conversion.exchange_rate.clone()),
+        exchange_rate_source: currency_conversion
+            .as_ref()
+            .and_then(|conversion| conversion.exchange_rate_source),
+        exchange_rate_timestamp: currency_conversion
+            .as_ref()
+            .map(|conversion| conversion.exchange_rate_timestamp.assume_utc()),
+        conversion_rounding: currency_conversion
+            .as_ref()
+            .map(|conversion| conversion.rounding),
+        conversion_residual: currency_conversion
+            .as_ref()
+            .and_then(|conversion| conversion.residual_amount.clone()),
+        time_to_fulfillment: helpers::get_payout_time_to_fulfillment(&payout_data.payouts),
         attempts: None,
         payout_link: payout_link.map(|payout_link| PayoutLinkResponse {
             payout_link_id: payout_link.link_id.clone(),
             link: payout_link.url,
         }),
+        approved_by: payouts.approved_by.to_owned(),
+        approved_at: payouts.approved_at.map(time::PrimitiveDateTime::assume_utc),
+        verified_at: payouts.verified_at.map(time::PrimitiveDateTime::assume_utc),
+        verification_reference: payouts.verification_reference.to_owned(),
+        reason_code: payout_attempt.reason_code,
+        reason: payout_attempt.reason,
+        recalled_amount: payout_attempt.recalled_amount,
+        destination_index: payout_attempt.destination_index,
+        payout_method_masked: payout_data
+            .payout_method_data
+            .as_ref()
+            .and_then(helpers::get_masked_payout_destination),
+        bank_details: payout_data
+            .payout_method_data
+            .as_ref()
+            .and_then(helpers::get_masked_payout_bank_details),
+        estimated_arrival: helpers::get_payout_estimated_arrival(state, payout_data, scheduled_at)
+            .map(time::PrimitiveDateTime::assume_utc),
+        processing_date: helpers::get_payout_processing_date(state, payout_data, scheduled_at),
     };
     Ok(services::ApplicationResponse::Json(response))
 }
@@ -1964,17 +3436,20 @@ pub async fn payout_create_db_entries(
     payout_id: &String,
     profile_id: &String,
     stored_payout_method_data: Option<&payouts::PayoutMethodData>,
+    created_by: Option<String>,
 ) -> RouterResult<PayoutData> {
     let db = &*state.store;
     let merchant_id = &merchant_account.merchant_id;
 
+    let recipient = req.recipient.to_owned().unwrap_or_default();
+
     // Get or create customer
     let customer_details = payments::CustomerDetails {
         customer_id: req.customer_id.to_owned(),
-        name: req.name.to_owned(),
-        email: req.email.to_owned(),
-        phone: req.phone.to_owned(),
-        phone_country_code: req.phone_country_code.to_owned(),
+        name: recipient.name.to_owned(),
+        email: recipient.email.to_owned(),
+        phone: recipient.phone.to_owned(),
+        phone_country_code: recipient.phone_country_code.to_owned(),
     };
     let customer = helpers::get_or_create_customer_details(
         state,
@@ -2014,7 +3489,7 @@ pub async fn payout_create_db_entries(
     // Get or create address
     let billing_address = payment_helpers::create_or_find_address_for_payment_by_request(
         db,
-        req.billing.as_ref(),
+        recipient.billing.as_ref(),
         None,
         merchant_id,
         Some(&customer_id.to_owned()),
@@ -2045,7 +3520,27 @@ pub async fn payout_create_db_entries(
         consts::ID_LENGTH,
         format!("payout_{payout_id}_secret").as_str(),
     );
-    let amount = MinorUnit::from(req.amount.unwrap_or(api::Amount::Zero));
+    let source_currency = req.source_currency.unwrap_or(currency);
+    let (amount, currency_conversion) = helpers::convert_payout_amount(
+        state,
+        merchant_id,
+        MinorUnit::from(req.amount.unwrap_or(api::Amount::Zero)),
+        source_currency,
+        currency,
+        req.forex_quote_id.as_deref(),
+        req.conversion_rounding.unwrap_or_default(),
+    )
+    .await?;
+    let merchant_name = merchant_account
+        .merchant_name
+        .clone()
+        .map(|merchant_name| merchant_name.into_inner().peek().to_owned());
+    let is_merchant_reference_id_unique = match req.merchant_reference_id {
+        Some(_) => {
+            Some(helpers::config_should_enforce_unique_merchant_reference_id(db, merchant_id).await)
+        }
+        None => None,
+    };
     let payouts_req = storage::PayoutsNew {
         payout_id: payout_id.to_string(),
         merchant_id: merchant_id.to_string(),
@@ -2054,12 +3549,12 @@ pub async fn payout_create_db_entries(
         payout_type,
         amount,
         destination_currency: currency,
-        source_currency: currency,
+        source_currency,
         description: req.description.to_owned(),
         recurring: req.recurring.unwrap_or(false),
         auto_fulfill: req.auto_fulfill.unwrap_or(false),
         return_url: req.return_url.to_owned(),
-        entity_type: req.entity_type.unwrap_or_default(),
+        entity_type: recipient.entity_type.unwrap_or_default(),
         payout_method_id,
         profile_id: profile_id.to_string(),
         attempt_count: 1,
@@ -2070,15 +3565,60 @@ pub async fn payout_create_db_entries(
             .map(|link_data| link_data.link_id.clone()),
         client_secret: Some(client_secret),
         priority: req.priority,
+        purpose_code: req.purpose_code.to_owned(),
+        webhook_url: req.webhook_url.to_owned(),
+        value_date: req.value_date,
+        charge_bearer: Some(req.charge_bearer.unwrap_or_default()),
+        settlement_network: req.settlement_network,
+        statement_descriptor: helpers::get_payout_statement_descriptor(
+            req.statement_descriptor.as_deref(),
+            merchant_name.as_deref(),
+        ),
+        merchant_reference_id: req.merchant_reference_id.to_owned(),
+        is_merchant_reference_id_unique,
+        requires_verification: req.requires_verification,
+        routing: req.routing.to_owned(),
+        eligible_connectors: helpers::encode_payout_eligible_connectors(req.connector.as_ref())?,
+        created_by,
+        fallback_to_standard: req.fallback_to_standard,
+        originator: helpers::encode_payout_originator(req.originator.as_ref())?,
+        backup_destinations: helpers::encrypt_payout_backup_destinations(
+            key_store,
+            req.payout_destinations.as_ref(),
+        )
+        .await?,
+        series_id: req.series_id.to_owned(),
+        currency_conversion: helpers::encode_payout_currency_conversion(
+            currency_conversion.as_ref(),
+        )?,
         ..Default::default()
     };
-    let payouts = db
+    let payouts = match db
         .insert_payout(payouts_req, merchant_account.storage_scheme)
         .await
-        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout {
-            payout_id: payout_id.to_owned(),
-        })
-        .attach_printable("Error inserting payouts in db")?;
+    {
+        Ok(payouts) => payouts,
+        Err(error) => {
+            let unique_merchant_reference_id = is_merchant_reference_id_unique
+                .unwrap_or(false)
+                .then_some(req.merchant_reference_id.as_deref())
+                .flatten();
+            let duplicate_response = helpers::get_payout_duplicate_error(
+                db,
+                merchant_account,
+                payout_id,
+                unique_merchant_reference_id,
+            )
+            .await;
+            return Err(error)
+                .to_duplicate_response(duplicate_response)
+                .attach_printable("Error inserting payouts in db");
+        }
+    };
+    if let Some(currency_conversion) = currency_conversion.as_ref() {
+        helpers::insert_payout_currency_conversion_audit_log(state, &payouts, currency_conversion)
+            .await?;
+    }
     // Make payout_attempt entry
     let status = if req.payout_method_data.is_some()
         || req.payout_token.is_some()
@@ -2104,6 +3644,7 @@ pub async fn payout_create_db_entries(
         business_label: req.business_label.to_owned(),
         payout_token: req.payout_token.to_owned(),
         profile_id: profile_id.to_string(),
+        destination_index: req.payout_destinations.as_ref().map(|_| 0),
         ..Default::default()
     };
     let payout_attempt = db
@@ -2270,6 +3811,7 @@ pub async fn add_external_account_addition_task(
         payout_id: payout_data.payouts.payout_id.to_owned(),
         force_sync: None,
         merchant_id: Some(payout_data.payouts.merchant_id.to_owned()),
+        timezone: None,
     };
     let process_tracker_entry = storage::ProcessTrackerNew::new(
         process_tracker_id,
@@ -2285,6 +3827,184 @@ pub async fn add_external_account_addition_task(
     Ok(())
 }
 
+pub async fn schedule_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+    scheduled_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    add_scheduled_payout_task(db, payout_data, scheduled_at)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding scheduled_payout_workflow to process tracker")?;
+
+    helpers::update_payout_status(
+        state,
+        merchant_account,
+        payout_data,
+        storage_enums::PayoutStatus::Scheduled,
+        None,
+        None,
+    )
+    .await
+}
+
+pub async fn mark_payout_pending_approval(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    helpers::update_payout_status(
+        state,
+        merchant_account,
+        payout_data,
+        storage_enums::PayoutStatus::PendingApproval,
+        None,
+        None,
+    )
+    .await
+}
+
+/// Holds the payout until the result of the document verification of its recipient is posted,
+/// and schedules its expiry for when the result is not posted within the verification window.
+pub async fn mark_payout_pending_verification(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let verification_window = state
+        .conf
+        .payouts
+        .verification_window
+        .unwrap_or(crate::consts::DEFAULT_PAYOUT_VERIFICATION_WINDOW);
+    let expires_at = common_utils::date_time::now()
+        .saturating_add(time::Duration::seconds(i64::from(verification_window)));
+    add_payout_verification_expiry_task(db, payout_data, expires_at)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding payout verification expiry task")?;
+
+    helpers::update_payout_status(
+        state,
+        merchant_account,
+        payout_data,
+        storage_enums::PayoutStatus::PendingVerification,
+        None,
+        None,
+    )
+    .await
+}
+
+const PAYOUT_VERIFICATION_EXPIRY_TASK: &str = "PAYOUT_VERIFICATION_EXPIRY";
+
+fn get_payout_verification_expiry_process_tracker_id(payout_data: &PayoutData) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PayoutVerificationExpiryWorkflow,
+        PAYOUT_VERIFICATION_EXPIRY_TASK,
+        &payout_data.payout_attempt.payout_attempt_id,
+        &payout_data.payout_attempt.merchant_id,
+    )
+}
+
+pub async fn add_payout_verification_expiry_task(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutVerificationExpiryWorkflow;
+    let tag = ["PAYOUTS", "VERIFICATION"];
+    let process_tracker_id = get_payout_verification_expiry_process_tracker_id(payout_data);
+    let tracking_data = api::PayoutRetrieveRequest {
+        payout_id: payout_data.payouts.payout_id.to_owned(),
+        force_sync: None,
+        merchant_id: Some(payout_data.payouts.merchant_id.to_owned()),
+        timezone: None,
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYOUT_VERIFICATION_EXPIRY_TASK,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+pub async fn revoke_payout_verification_expiry_task(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+) -> CustomResult<(), errors::StorageError> {
+    let task_ids = vec![get_payout_verification_expiry_process_tracker_id(
+        payout_data,
+    )];
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::StatusUpdate {
+        status: storage_enums::ProcessTrackerStatus::Finish,
+        business_status: Some(String::from(diesel_models::business_status::REVOKED)),
+    };
+    db.process_tracker_update_process_status_by_ids(task_ids, updated_process_tracker_data)
+        .await?;
+    Ok(())
+}
+
+const SCHEDULED_PAYOUT_TASK: &str = "PAYOUT_SCHEDULED_DISBURSEMENT";
+
+pub fn get_scheduled_payout_process_tracker_id(payout_data: &PayoutData) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::ScheduledPayoutWorkflow,
+        SCHEDULED_PAYOUT_TASK,
+        &payout_data.payout_attempt.payout_attempt_id,
+        &payout_data.payout_attempt.merchant_id,
+    )
+}
+
+pub async fn add_scheduled_payout_task(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::ScheduledPayoutWorkflow;
+    let tag = ["PAYOUTS", "SCHEDULED"];
+    let process_tracker_id = get_scheduled_payout_process_tracker_id(payout_data);
+    let tracking_data = api::PayoutRetrieveRequest {
+        payout_id: payout_data.payouts.payout_id.to_owned(),
+        force_sync: None,
+        merchant_id: Some(payout_data.payouts.merchant_id.to_owned()),
+        timezone: None,
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SCHEDULED_PAYOUT_TASK,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+pub async fn revoke_scheduled_payout_task(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+) -> CustomResult<(), errors::StorageError> {
+    let task_ids = vec![get_scheduled_payout_process_tracker_id(payout_data)];
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::StatusUpdate {
+        status: storage_enums::ProcessTrackerStatus::Finish,
+        business_status: Some(String::from(diesel_models::business_status::REVOKED)),
+    };
+    db.process_tracker_update_process_status_by_ids(task_ids, updated_process_tracker_data)
+        .await?;
+    Ok(())
+}
+
 async fn validate_and_get_business_profile(
     state: &SessionState,
     profile_id: &String,
@@ -0,0 +1,744 @@
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, ResultExt};
+use rand::Rng;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::{helpers, PayoutData};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, enums as api_enums, payouts},
+        domain, storage,
+    },
+    utils,
+};
+
+const MICRO_DEPOSIT_COUNT: usize = 2;
+const MICRO_DEPOSIT_CONFIRMATION_ATTEMPTS: i32 = 3;
+/// Pending verifications expire after this many days, long enough for the micro-deposits to
+/// reach the destination
+const PAYOUT_DESTINATION_VERIFICATION_EXPIRY_IN_DAYS: i64 = 7;
+
+/// A micro-deposit sent for verifying a payout destination, stored along with the verification
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct MicroDeposit {
+    payout_id: String,
+    amount: MinorUnit,
+}
+
+/// Initiates the verification of a payout destination of a customer, by sending micro-deposits of
+/// random amounts to it. The verification fails when any of the micro-deposits can not be sent.
+/// Instant verifications are instead completed right away, through the account check of a
+/// connector.
+#[instrument(skip_all)]
+pub async fn create_payout_destination_verification(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutDestinationVerificationRequest,
+) -> RouterResponse<payouts::PayoutDestinationVerificationResponse> {
+    let merchant_id = &merchant_account.merchant_id;
+    // The fingerprint is computed the same way as for the payouts to the destination
+    let payout_method_data = helpers::normalize_payout_method_data(req.payout_method_data.clone());
+    let payout_method_fingerprint =
+        helpers::get_payout_method_fingerprint(&state, merchant_id, &payout_method_data)
+            .await?
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The destination of the payout_method_data can not be verified"
+                        .to_string(),
+                })
+            })?;
+
+    if req.method == api_enums::PayoutDestinationVerificationMethod::Instant {
+        return create_instant_payout_destination_verification(
+            state,
+            merchant_account,
+            key_store,
+            req,
+            payout_method_fingerprint,
+        )
+        .await;
+    }
+
+    // The ids of the micro-deposits are of the length of client provided payout ids, so that they
+    // are accepted when the merchant requires payout ids to be passed
+    let micro_deposits = {
+        let mut rng = rand::thread_rng();
+        (0..MICRO_DEPOSIT_COUNT)
+            .map(|_| MicroDeposit {
+                payout_id: utils::generate_id(consts::ID_LENGTH, "payout_md"),
+                amount: MinorUnit::new(rng.gen_range(1..100)),
+            })
+            .collect::<Vec<_>>()
+    };
+    let now = common_utils::date_time::now();
+    let verification = state
+        .store
+        .insert_payout_destination_verification(storage::PayoutDestinationVerificationNew {
+            verification_id: utils::generate_id(consts::ID_LENGTH, "payout_verification"),
+            merchant_id: merchant_id.to_owned(),
+            customer_id: req.customer_id.clone(),
+            payout_method_fingerprint,
+            status: storage_enums::PayoutDestinationVerificationStatus::Pending,
+            micro_deposits: micro_deposits
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the micro-deposits")?,
+            remaining_attempts: MICRO_DEPOSIT_CONFIRMATION_ATTEMPTS,
+            created_at: now,
+            modified_at: now,
+            verified_at: None,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Payout destination verification already exists".to_string(),
+        })?;
+
+    for micro_deposit in &micro_deposits {
+        let payout_req = payouts::PayoutCreateRequest {
+            payout_id: Some(micro_deposit.payout_id.clone()),
+            amount: Some(micro_deposit.amount.into()),
+            currency: Some(req.currency),
+            connector: req.connector.clone(),
+            confirm: Some(true),
+            auto_fulfill: Some(true),
+            payout_type: Some(req.payout_type),
+            payout_method_data: Some(req.payout_method_data.clone()),
+            customer_id: Some(req.customer_id.clone()),
+            description: Some("Account verification".to_string()),
+            ..Default::default()
+        };
+        let result = Box::pin(super::payouts_create_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            payout_req,
+            None,
+        ))
+        .await;
+        let is_sent = match &result {
+            Ok(services::ApplicationResponse::Json(payout)) => {
+                !helpers::is_payout_err_state(payout.status)
+            }
+            Ok(_) => true,
+            Err(error) => {
+                logger::error!(?error, "Failed to send micro-deposit");
+                false
+            }
+        };
+        if !is_sent {
+            let verification_id = verification.verification_id.clone();
+            let verification = match state
+                .store
+                .update_payout_destination_verification_if_unchanged(
+                    verification,
+                    storage::PayoutDestinationVerificationUpdate::StatusUpdate {
+                        status: storage_enums::PayoutDestinationVerificationStatus::Failed,
+                        remaining_attempts: 0,
+                        verified_at: None,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the payout destination verification")?
+            {
+                Some(verification) => verification,
+                None => {
+                    find_payout_destination_verification(&state, merchant_id, &verification_id)
+                        .await?
+                }
+            };
+            return Ok(services::ApplicationResponse::Json(
+                get_payout_destination_verification_response(verification)?,
+            ));
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_destination_verification_response(verification)?,
+    ))
+}
+
+/// Verifies a payout destination through the account check of the first connector of the request
+/// which supports it. The verification is stored as verified or failed, and has no micro-deposits
+/// to be confirmed.
+async fn create_instant_payout_destination_verification(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutDestinationVerificationRequest,
+    payout_method_fingerprint: String,
+) -> RouterResponse<payouts::PayoutDestinationVerificationResponse> {
+    let connector = get_payout_account_check_connector(req.connector.as_deref(), req.payout_type)
+        .ok_or_else(|| {
+        report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "None of the connectors can verify {} destinations instantly, they can be \
+                     verified with micro-deposits instead",
+                req.payout_type
+            ),
+        })
+    })?;
+    let is_verified =
+        check_payout_destination_account(&state, &merchant_account, &key_store, &req, connector)
+            .await?;
+
+    let now = common_utils::date_time::now();
+    let (status, verified_at) = if is_verified {
+        (
+            storage_enums::PayoutDestinationVerificationStatus::Verified,
+            Some(now),
+        )
+    } else {
+        (
+            storage_enums::PayoutDestinationVerificationStatus::Failed,
+            None,
+        )
+    };
+    let verification = state
+        .store
+        .insert_payout_destination_verification(storage::PayoutDestinationVerificationNew {
+            verification_id: utils::generate_id(consts::ID_LENGTH, "payout_verification"),
+            merchant_id: merchant_account.merchant_id.to_owned(),
+            customer_id: req.customer_id,
+            payout_method_fingerprint,
+            status,
+            micro_deposits: Vec::<MicroDeposit>::new()
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the micro-deposits")?,
+            remaining_attempts: 0,
+            created_at: now,
+            modified_at: now,
+            verified_at,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Payout destination verification already exists".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_destination_verification_response(verification)?,
+    ))
+}
+
+/// Returns the first of the connectors which supports account checks for the payout type
+fn get_payout_account_check_connector(
+    connectors: Option<&[api_enums::PayoutConnectors]>,
+    payout_type: api_enums::PayoutType,
+) -> Option<api_enums::Connector> {
+    connectors
+        .unwrap_or_default()
+        .iter()
+        .map(|connector| api_enums::Connector::from(*connector))
+        .find(|connector| connector.supports_payout_account_check(Some(payout_type)))
+}
+
+/// Checks the destination of the request through the payout eligibility API of the connector,
+/// which does not move any funds. The check is made for an unconfirmed payout of zero amount to
+/// the destination, which is cancelled once the check is done so that it is never disbursed.
+async fn check_payout_destination_account(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &payouts::PayoutDestinationVerificationRequest,
+    connector: api_enums::Connector,
+) -> RouterResult<bool> {
+    let payout_req = payouts::PayoutCreateRequest {
+        amount: Some(api::Amount::Zero),
+        currency: Some(req.currency),
+        connector: req.connector.clone(),
+        confirm: Some(false),
+        payout_type: Some(req.payout_type),
+        payout_method_data: Some(req.payout_method_data.clone()),
+        customer_id: Some(req.customer_id.clone()),
+        description: Some("Account verification".to_string()),
+        ..Default::default()
+    };
+    let payout_id = match Box::pin(super::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payout_req,
+        None,
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(payout) => payout.payout_id,
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while creating the account check payout")?,
+    };
+    let mut payout_data = super::make_payout_data(
+        state,
+        merchant_account,
+        key_store,
+        &payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest { payout_id }),
+    )
+    .await?;
+    let connector_data = api::ConnectorData::get_payout_connector_by_name(
+        &state.conf.connectors,
+        &connector.to_string(),
+        api::GetToken::Connector,
+        None,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector data")?;
+
+    let result = super::check_payout_eligibility(
+        state,
+        merchant_account,
+        key_store,
+        &connector_data,
+        &mut payout_data,
+    )
+    .await;
+    if !helpers::is_payout_terminal_state(payout_data.payout_attempt.status) {
+        super::cancel_payout_locally(state, merchant_account, &mut payout_data).await?;
+    }
+    match result {
+        Ok(()) => Ok(payout_data.payout_attempt.is_eligible == Some(true)),
+        Err(error)
+            if helpers::is_connector_payout_failure(
+                error.current_context(),
+                payout_data.payout_attempt.status,
+            ) =>
+        {
+            logger::info!(?error, "Account check of the payout destination failed");
+            Ok(false)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Confirms the amounts of the micro-deposits of a pending verification. The destination is
+/// verified when the amounts match, else an attempt is used up and the verification fails once
+/// there are no attempts left. A confirmation racing with another one for the same attempt is
+/// rejected, as is the confirmation of an expired verification.
+#[instrument(skip_all)]
+pub async fn confirm_payout_destination_verification(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutDestinationVerificationConfirmRequest,
+) -> RouterResponse<payouts::PayoutDestinationVerificationResponse> {
+    let verification = find_payout_destination_verification(
+        &state,
+        &merchant_account.merchant_id,
+        &req.verification_id,
+    )
+    .await?;
+    let now = common_utils::date_time::now();
+    let status = get_payout_destination_verification_status(&verification, now);
+    if status != verification.status {
+        // Persisting the expiry is best effort, as the status is derived again on every read
+        let _ = state
+            .store
+            .update_payout_destination_verification_if_unchanged(
+                verification.clone(),
+                storage::PayoutDestinationVerificationUpdate::StatusUpdate {
+                    status,
+                    remaining_attempts: verification.remaining_attempts,
+                    verified_at: None,
+                },
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to expire the verification"));
+    }
+    utils::when(
+        status != storage_enums::PayoutDestinationVerificationStatus::Pending,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The verification is {}, only pending verifications can be confirmed",
+                    status
+                ),
+            }))
+        },
+    )?;
+
+    let micro_deposits = get_micro_deposits(verification.micro_deposits.clone())?;
+    let update = get_payout_destination_verification_confirmation_update(
+        &verification,
+        micro_deposit_amounts_match(&micro_deposits, &req.amounts),
+        now,
+    );
+    let verification = state
+        .store
+        .update_payout_destination_verification_if_unchanged(verification, update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payout destination verification")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The verification was updated by another confirmation, the amounts were \
+                          not accepted"
+                    .to_string(),
+            })
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_destination_verification_response(verification)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payout_destination_verification(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutDestinationVerificationId,
+) -> RouterResponse<payouts::PayoutDestinationVerificationResponse> {
+    let verification = find_payout_destination_verification(
+        &state,
+        &merchant_account.merchant_id,
+        &req.verification_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_destination_verification_response(verification)?,
+    ))
+}
+
+/// Errors when the merchant requires payouts to be made only to verified destinations, and the
+/// destination of the payout has not been verified for its customer. Micro-deposits of pending
+/// verifications are the only payouts allowed to unverified destinations, and payouts whose
+/// destination can not be fingerprinted are not allowed at all.
+pub async fn validate_payout_destination_verified(
+    state: &SessionState,
+    payout_data: &PayoutData,
+) -> RouterResult<()> {
+    let payouts = &payout_data.payouts;
+    if payouts.payout_type == Some(storage_enums::PayoutType::StoreCredit)
+        || !is_payout_destination_verification_required(&*state.store, &payouts.merchant_id).await
+    {
+        return Ok(());
+    }
+
+    let verifications = match payouts.payout_method_fingerprint.as_deref() {
+        Some(fingerprint) => state
+            .store
+            .list_payout_destination_verifications_by_fingerprint(
+                &payouts.merchant_id,
+                &payouts.customer_id,
+                fingerprint,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payout destination verifications")?,
+        None => Vec::new(),
+    };
+    let now = common_utils::date_time::now();
+    let mut is_verified = false;
+    for verification in verifications {
+        is_verified = match get_payout_destination_verification_status(&verification, now) {
+            storage_enums::PayoutDestinationVerificationStatus::Verified => true,
+            storage_enums::PayoutDestinationVerificationStatus::Pending => {
+                get_micro_deposits(verification.micro_deposits)?
+                    .iter()
+                    .any(|micro_deposit| micro_deposit.payout_id == payouts.payout_id)
+            }
+            storage_enums::PayoutDestinationVerificationStatus::Failed
+            | storage_enums::PayoutDestinationVerificationStatus::Expired => false,
+        };
+        if is_verified {
+            break;
+        }
+    }
+
+    utils::when(!is_verified, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The destination of the payout is not verified for the customer".to_string(),
+        }))
+    })
+}
+
+/// Checks whether the merchant requires payouts to be made only to verified destinations, which
+/// is configured with the `payout_destination_verification_required_{merchant_id}` config.
+async fn is_payout_destination_verification_required(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> bool {
+    let key = format!("payout_destination_verification_required_{}", merchant_id);
+    let config = db
+        .find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(err) => {
+            logger::error!("{err}");
+            false
+        }
+    }
+}
+
+/// Returns the status of the verification, pending verifications being expired once
+/// [`PAYOUT_DESTINATION_VERIFICATION_EXPIRY_IN_DAYS`] have passed since they were created
+fn get_payout_destination_verification_status(
+    verification: &storage::PayoutDestinationVerification,
+    now: time::PrimitiveDateTime,
+) -> storage_enums::PayoutDestinationVerificationStatus {
+    let expires_at = verification.created_at
+        + time::Duration::days(PAYOUT_DESTINATION_VERIFICATION_EXPIRY_IN_DAYS);
+    match verification.status {
+        storage_enums::PayoutDestinationVerificationStatus::Pending if now >= expires_at => {
+            storage_enums::PayoutDestinationVerificationStatus::Expired
+        }
+        status => status,
+    }
+}
+
+/// Returns the update of a pending verification for a confirmation, which verifies the destination
+/// when the amounts match, and otherwise uses up an attempt, failing the verification once there
+/// are no attempts left
+fn get_payout_destination_verification_confirmation_update(
+    verification: &storage::PayoutDestinationVerification,
+    amounts_match: bool,
+    now: time::PrimitiveDateTime,
+) -> storage::PayoutDestinationVerificationUpdate {
+    if amounts_match {
+        storage::PayoutDestinationVerificationUpdate::StatusUpdate {
+            status: storage_enums::PayoutDestinationVerificationStatus::Verified,
+            remaining_attempts: verification.remaining_attempts,
+            verified_at: Some(now),
+        }
+    } else {
+        let remaining_attempts = verification.remaining_attempts.saturating_sub(1);
+        storage::PayoutDestinationVerificationUpdate::StatusUpdate {
+            status: if remaining_attempts > 0 {
+                storage_enums::PayoutDestinationVerificationStatus::Pending
+            } else {
+                storage_enums::PayoutDestinationVerificationStatus::Failed
+            },
+            remaining_attempts,
+            verified_at: None,
+        }
+    }
+}
+
+/// Checks whether the confirmed amounts are the amounts of the micro-deposits, in any order
+fn micro_deposit_amounts_match(micro_deposits: &[MicroDeposit], amounts: &[MinorUnit]) -> bool {
+    let mut expected = micro_deposits
+        .iter()
+        .map(|micro_deposit| micro_deposit.amount.get_amount_as_i64())
+        .collect::<Vec<_>>();
+    let mut confirmed = amounts
+        .iter()
+        .map(MinorUnit::get_amount_as_i64)
+        .collect::<Vec<_>>();
+    expected.sort();
+    confirmed.sort();
+    expected == confirmed
+}
+
+async fn find_payout_destination_verification(
+    state: &SessionState,
+    merchant_id: &str,
+    verification_id: &str,
+) -> RouterResult<storage::PayoutDestinationVerification> {
+    state
+        .store
+        .find_payout_destination_verification_by_merchant_id_verification_id(
+            merchant_id,
+            verification_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout destination verification {verification_id} not found"),
+        })
+}
+
+fn get_micro_deposits(micro_deposits: serde_json::Value) -> RouterResult<Vec<MicroDeposit>> {
+    micro_deposits
+        .parse_value("MicroDeposits")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the micro-deposits of the verification")
+}
+
+fn get_payout_destination_verification_response(
+    verification: storage::PayoutDestinationVerification,
+) -> RouterResult<payouts::PayoutDestinationVerificationResponse> {
+    let status =
+        get_payout_destination_verification_status(&verification, common_utils::date_time::now());
+    Ok(payouts::PayoutDestinationVerificationResponse {
+        micro_deposit_payout_ids: get_micro_deposits(verification.micro_deposits)?
+            .into_iter()
+            .map(|micro_deposit| micro_deposit.payout_id)
+            .collect(),
+        verification_id: verification.verification_id,
+        customer_id: verification.customer_id,
+        status,
+        remaining_attempts: verification.remaining_attempts,
+        created_at: verification.created_at,
+        verified_at: verification.verified_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::id_type;
+
+    use super::*;
+
+    fn get_pending_verification(
+        created_at: time::PrimitiveDateTime,
+    ) -> storage::PayoutDestinationVerification {
+        storage::PayoutDestinationVerification {
+            verification_id: "payout_verification_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            customer_id: id_type::CustomerId::from("customer_1".into()).unwrap(),
+            payout_method_fingerprint: "fingerprint_1".to_string(),
+            status: storage_enums::PayoutDestinationVerificationStatus::Pending,
+            micro_deposits: serde_json::json!([]),
+            remaining_attempts: MICRO_DEPOSIT_CONFIRMATION_ATTEMPTS,
+            created_at,
+            modified_at: created_at,
+            verified_at: None,
+        }
+    }
+
+    fn apply_update(
+        verification: &mut storage::PayoutDestinationVerification,
+        update: storage::PayoutDestinationVerificationUpdate,
+    ) {
+        let update = storage::PayoutDestinationVerificationUpdateInternal::from(update);
+        verification.status = update.status.unwrap();
+        verification.remaining_attempts = update.remaining_attempts.unwrap();
+        verification.verified_at = update.verified_at;
+    }
+
+    #[test]
+    fn test_confirmation_attempts_exhaustion() {
+        let now = common_utils::date_time::now();
+        let mut verification = get_pending_verification(now);
+
+        for remaining_attempts in (0..MICRO_DEPOSIT_CONFIRMATION_ATTEMPTS).rev() {
+            assert_eq!(
+                verification.status,
+                storage_enums::PayoutDestinationVerificationStatus::Pending
+            );
+            let update =
+                get_payout_destination_verification_confirmation_update(&verification, false, now);
+            apply_update(&mut verification, update);
+            assert_eq!(verification.remaining_attempts, remaining_attempts);
+        }
+        assert_eq!(
+            verification.status,
+            storage_enums::PayoutDestinationVerificationStatus::Failed
+        );
+        assert_eq!(verification.verified_at, None);
+    }
+
+    #[test]
+    fn test_confirmation_with_matching_amounts() {
+        let now = common_utils::date_time::now();
+        let mut verification = get_pending_verification(now);
+
+        let update =
+            get_payout_destination_verification_confirmation_update(&verification, false, now);
+        apply_update(&mut verification, update);
+        let update =
+            get_payout_destination_verification_confirmation_update(&verification, true, now);
+        apply_update(&mut verification, update);
+
+        assert_eq!(
+            verification.status,
+            storage_enums::PayoutDestinationVerificationStatus::Verified
+        );
+        assert_eq!(
+            verification.remaining_attempts,
+            MICRO_DEPOSIT_CONFIRMATION_ATTEMPTS - 1
+        );
+        assert_eq!(verification.verified_at, Some(now));
+    }
+
+    #[test]
+    fn test_get_payout_destination_verification_status() {
+        let now = common_utils::date_time::now();
+        let expiry = time::Duration::days(PAYOUT_DESTINATION_VERIFICATION_EXPIRY_IN_DAYS);
+
+        let verification = get_pending_verification(now - expiry + time::Duration::minutes(1));
+        assert_eq!(
+            get_payout_destination_verification_status(&verification, now),
+            storage_enums::PayoutDestinationVerificationStatus::Pending
+        );
+
+        let mut verification = get_pending_verification(now - expiry);
+        assert_eq!(
+            get_payout_destination_verification_status(&verification, now),
+            storage_enums::PayoutDestinationVerificationStatus::Expired
+        );
+
+        // Only pending verifications expire
+        verification.status = storage_enums::PayoutDestinationVerificationStatus::Verified;
+        assert_eq!(
+            get_payout_destination_verification_status(&verification, now),
+            storage_enums::PayoutDestinationVerificationStatus::Verified
+        );
+    }
+
+    #[test]
+    fn test_get_payout_account_check_connector() {
+        let connectors = [
+            api_enums::PayoutConnectors::Wise,
+            api_enums::PayoutConnectors::Adyen,
+        ];
+
+        assert_eq!(
+            get_payout_account_check_connector(Some(&connectors), api_enums::PayoutType::Card),
+            Some(api_enums::Connector::Adyen)
+        );
+        // Bank destinations can only be verified with micro-deposits
+        assert_eq!(
+            get_payout_account_check_connector(Some(&connectors), api_enums::PayoutType::Bank),
+            None
+        );
+        assert_eq!(
+            get_payout_account_check_connector(
+                Some(&[api_enums::PayoutConnectors::Wise]),
+                api_enums::PayoutType::Card
+            ),
+            None
+        );
+        assert_eq!(
+            get_payout_account_check_connector(None, api_enums::PayoutType::Card),
+            None
+        );
+    }
+
+    #[test]
+    fn test_micro_deposit_amounts_match() {
+        let micro_deposits = vec![
+            MicroDeposit {
+                payout_id: "payout_md_1".to_string(),
+                amount: MinorUnit::new(32),
+            },
+            MicroDeposit {
+                payout_id: "payout_md_2".to_string(),
+                amount: MinorUnit::new(45),
+            },
+        ];
+
+        assert!(micro_deposit_amounts_match(
+            &micro_deposits,
+            &[MinorUnit::new(45), MinorUnit::new(32)]
+        ));
+        assert!(!micro_deposit_amounts_match(
+            &micro_deposits,
+            &[MinorUnit::new(32), MinorUnit::new(32)]
+        ));
+        assert!(!micro_deposit_amounts_match(
+            &micro_deposits,
+            &[MinorUnit::new(32)]
+        ));
+    }
+}
@@ -0,0 +1,1283 @@
+use std::{collections::HashMap, fmt::Write};
+
+use common_utils::{ext_traits::ValueExt, types::MinorUnit};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payouts::PayoutFetchConstraints;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use rust_decimal::Decimal;
+use scheduler::utils as pt_utils;
+use time::format_description::well_known::Iso8601;
+
+use super::{validator, PayoutData};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{
+        api::{enums as api_enums, payouts},
+        domain, storage,
+    },
+    utils,
+};
+
+/// Columns emitted in the payouts reconciliation export.
+///
+/// PII (customer email, name, phone and destination account details) is intentionally not part
+/// of the export. Fees are not persisted against payouts yet, so the column is emitted empty to
+/// keep the file layout stable once they are.
+const PAYOUTS_EXPORT_HEADER: [&str; 9] = [
+    "payout_id",
+    "connector",
+    "connector_payout_id",
+    "amount",
+    "currency",
+    "status",
+    "created_at",
+    "last_modified_at",
+    "fees",
+];
+
+/// Task name of the scheduler jobs which generate large payouts exports.
+const PAYOUTS_EXPORT_TASK: &str = "PAYOUTS_EXPORT";
+
+/// Business status of export jobs whose file is generated and can be downloaded. The job is
+/// rescheduled to delete the file once its retention period is over.
+pub(crate) const EXPORT_FILE_AVAILABLE: &str = "FILE_AVAILABLE";
+
+/// Business status of export jobs whose file was deleted after its retention period.
+pub(crate) const EXPORT_FILE_EXPIRED: &str = "FILE_EXPIRED";
+
+/// Tracking data of the scheduler jobs which generate large payouts exports.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PayoutsExportTrackingData {
+    pub export_id: String,
+    pub merchant_id: String,
+    pub constraints: payouts::PayoutListConstraints,
+    /// Number of files the export was stored as, one per page of payouts, set once the export is
+    /// generated. Jobs which stored the export as a single file have none.
+    #[serde(default)]
+    pub parts_count: Option<usize>,
+}
+
+/// Exports the payouts matching the given constraints as CSV.
+///
+/// Exports of up to `export_sync_limit` payouts are returned in the response. Larger exports are
+/// generated by a scheduler job instead, whose status is returned so that it can be polled until
+/// the export can be downloaded.
+#[instrument(skip_all)]
+pub async fn payouts_export_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: payouts::PayoutListConstraints,
+) -> RouterResponse<payouts::PayoutExportJobResponse> {
+    validator::validate_payout_list_request(&constraints)?;
+    let sync_limit = state
+        .conf
+        .payouts
+        .export_sync_limit
+        .unwrap_or(consts::DEFAULT_PAYOUTS_EXPORT_SYNC_LIMIT);
+
+    if let Some(csv) =
+        generate_payouts_csv_export(&state, &merchant_account, constraints.clone(), sync_limit)
+            .await?
+    {
+        return Ok(services::ApplicationResponse::FileData((
+            csv,
+            mime::TEXT_CSV,
+        )));
+    }
+
+    let export_id = common_utils::generate_id(consts::ID_LENGTH, "payout_export");
+    let tracking_data = PayoutsExportTrackingData {
+        export_id: export_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        constraints,
+        parts_count: None,
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_payouts_export_process_tracker_id(&merchant_account.merchant_id, &export_id),
+        PAYOUTS_EXPORT_TASK,
+        storage::ProcessTrackerRunner::PayoutsExportWorkflow,
+        ["PAYOUTS", "EXPORT"],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payouts export task")?;
+    let process = state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add payouts export task")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payouts_export_job_response(&state, export_id, &process),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payouts_export_job(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutExportJobId,
+) -> RouterResponse<payouts::PayoutExportJobResponse> {
+    let process =
+        find_payouts_export_process(&state, &merchant_account.merchant_id, &req.job_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payouts_export_job_response(&state, req.job_id, &process),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn download_payouts_export(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutExportJobId,
+) -> RouterResponse<()> {
+    let process =
+        find_payouts_export_process(&state, &merchant_account.merchant_id, &req.job_id).await?;
+    utils::when(
+        get_payouts_export_job_status(&process) != payouts::PayoutExportJobStatus::Completed,
+        || Err(report!(errors::ApiErrorResponse::FileNotAvailable)),
+    )?;
+
+    let tracking_data: PayoutsExportTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("PayoutsExportTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let mut csv = Vec::new();
+    for file_key in get_payouts_export_file_keys(&tracking_data) {
+        let part = state
+            .file_storage_client
+            .retrieve_file(&file_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve the file of the payouts export")?;
+        csv.extend(part);
+    }
+
+    Ok(services::ApplicationResponse::FileData((
+        csv,
+        mime::TEXT_CSV,
+    )))
+}
+
+pub fn get_payouts_export_process_tracker_id(merchant_id: &str, export_id: &str) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PayoutsExportWorkflow,
+        PAYOUTS_EXPORT_TASK,
+        export_id,
+        merchant_id,
+    )
+}
+
+/// Returns the keys of the files an export is stored as in the file store, in the order in which
+/// they are concatenated into the CSV.
+pub fn get_payouts_export_file_keys(tracking_data: &PayoutsExportTrackingData) -> Vec<String> {
+    let PayoutsExportTrackingData {
+        merchant_id,
+        export_id,
+        ..
+    } = tracking_data;
+    match tracking_data.parts_count {
+        Some(parts_count) => (0..parts_count)
+            .map(|part| get_payouts_export_part_file_key(merchant_id, export_id, part))
+            .collect(),
+        None => vec![format!("{merchant_id}/payout_exports/{export_id}.csv")],
+    }
+}
+
+fn get_payouts_export_part_file_key(merchant_id: &str, export_id: &str, part: usize) -> String {
+    format!("{merchant_id}/payout_exports/{export_id}/part-{part}.csv")
+}
+
+/// Generates the export of a job into the file store, one file per page of payouts, so that only
+/// a single page of the export is held in memory. Returns the number of files written.
+pub async fn store_payouts_csv_export(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    tracking_data: &PayoutsExportTrackingData,
+) -> RouterResult<usize> {
+    let mut export = PayoutsCsvExport::new(tracking_data.constraints.clone());
+    let mut parts_count = 0;
+    while let Some(chunk) = export.next_chunk(state, merchant_account).await? {
+        state
+            .file_storage_client
+            .upload_file(
+                &get_payouts_export_part_file_key(
+                    &tracking_data.merchant_id,
+                    &tracking_data.export_id,
+                    parts_count,
+                ),
+                chunk,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to upload a file of the payouts export")?;
+        parts_count += 1;
+    }
+
+    Ok(parts_count)
+}
+
+async fn find_payouts_export_process(
+    state: &SessionState,
+    merchant_id: &str,
+    job_id: &str,
+) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .find_process_by_id(&get_payouts_export_process_tracker_id(merchant_id, job_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payouts export task")?
+        .ok_or(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payouts export job {job_id} not found"),
+        }))
+}
+
+fn get_payouts_export_job_status(
+    process: &storage::ProcessTracker,
+) -> payouts::PayoutExportJobStatus {
+    match process.business_status.as_str() {
+        business_status::PENDING => payouts::PayoutExportJobStatus::Pending,
+        EXPORT_FILE_AVAILABLE => payouts::PayoutExportJobStatus::Completed,
+        EXPORT_FILE_EXPIRED => payouts::PayoutExportJobStatus::Expired,
+        _ => payouts::PayoutExportJobStatus::Failed,
+    }
+}
+
+fn get_payouts_export_job_response(
+    state: &SessionState,
+    job_id: String,
+    process: &storage::ProcessTracker,
+) -> payouts::PayoutExportJobResponse {
+    let status = get_payouts_export_job_status(process);
+    let is_completed = status == payouts::PayoutExportJobStatus::Completed;
+    payouts::PayoutExportJobResponse {
+        download_url: is_completed
+            .then(|| format!("{}/payouts/export/{job_id}/download", state.base_url)),
+        // The file is deleted when the job runs again at the end of the retention period
+        expires_at: is_completed.then_some(process.schedule_time).flatten(),
+        job_id,
+        status,
+        created_at: process.created_at,
+    }
+}
+
+/// Builds a CSV export of all the payouts matching the given constraints in memory, for exports
+/// which are returned in the response. `None` is returned as soon as the payouts matching the
+/// constraints are found to be more than `max_records`, larger exports are stored by a job instead.
+pub async fn generate_payouts_csv_export(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    constraints: payouts::PayoutListConstraints,
+    max_records: usize,
+) -> RouterResult<Option<Vec<u8>>> {
+    let mut export = PayoutsCsvExport::new(constraints);
+    let mut csv = Vec::new();
+    while let Some(chunk) = export.next_chunk(state, merchant_account).await? {
+        if export.records_count() > max_records {
+            return Ok(None);
+        }
+        csv.extend(chunk);
+    }
+
+    Ok(Some(csv))
+}
+
+/// CSV export of the payouts matching the given constraints, generated one page at a time.
+///
+/// Payouts are fetched from the database one page at a time (`limit` in the constraints is used as
+/// the page size), along with the attempts of all the payouts of the page in a single query. Each
+/// page is returned as a chunk of the CSV as soon as it is read, so that callers can write the
+/// export out incrementally. The header is part of the first chunk.
+pub struct PayoutsCsvExport {
+    fetch_constraints: PayoutFetchConstraints,
+    page_size: u32,
+    records_count: usize,
+    is_header_written: bool,
+    is_completed: bool,
+}
+
+impl PayoutsCsvExport {
+    pub fn new(constraints: payouts::PayoutListConstraints) -> Self {
+        let fetch_constraints = PayoutFetchConstraints::from(constraints);
+        // The limit in the constraints is capped while converting them
+        let page_size = match &fetch_constraints {
+            PayoutFetchConstraints::List(params) => params.limit.unwrap_or_default(),
+            PayoutFetchConstraints::Single { .. } => 1,
+        };
+        Self {
+            fetch_constraints,
+            page_size,
+            records_count: 0,
+            is_header_written: false,
+            is_completed: false,
+        }
+    }
+
+    /// Number of payouts written into the chunks returned so far.
+    pub fn records_count(&self) -> usize {
+        self.records_count
+    }
+
+    /// Returns the next chunk of the CSV, or `None` once all the payouts have been exported.
+    pub async fn next_chunk(
+        &mut self,
+        state: &SessionState,
+        merchant_account: &domain::MerchantAccount,
+    ) -> RouterResult<Option<Vec<u8>>> {
+        if self.is_completed {
+            return Ok(None);
+        }
+        let db = state.store.as_ref();
+        let merchant_id = &merchant_account.merchant_id;
+
+        let payouts = db
+            .filter_payouts_by_constraints(
+                merchant_id,
+                &self.fetch_constraints,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+        match &mut self.fetch_constraints {
+            PayoutFetchConstraints::List(params)
+                if !payouts.is_empty()
+                    && u32::try_from(payouts.len()).unwrap_or(u32::MAX) >= self.page_size =>
+            {
+                params.offset += self.page_size;
+            }
+            _ => self.is_completed = true,
+        }
+
+        let payout_attempt_ids = payouts
+            .iter()
+            .map(|payout| utils::get_payment_attempt_id(&payout.payout_id, payout.attempt_count))
+            .collect::<Vec<_>>();
+        let payout_attempts = if payout_attempt_ids.is_empty() {
+            Vec::new()
+        } else {
+            db.list_payout_attempts_by_merchant_id_payout_attempt_ids(
+                merchant_id,
+                &payout_attempt_ids,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payout attempts of the payouts export page")?
+        };
+
+        let mut chunk = String::new();
+        if !self.is_header_written {
+            write_csv_record(
+                &mut chunk,
+                PAYOUTS_EXPORT_HEADER.iter().map(|h| h.to_string()),
+            );
+            self.is_header_written = true;
+        }
+        for record in get_export_records(&payouts, &payout_attempts)? {
+            self.records_count += 1;
+            write_csv_record(&mut chunk, record);
+        }
+
+        Ok(Some(chunk.into_bytes()))
+    }
+}
+
+/// XML namespace of the ISO 20022 customer credit transfer initiation message.
+const PAIN_001_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:pain.001.001.03";
+
+/// Maximum length of `Max35Text` identifiers in pain.001.
+const PAIN_001_MAX_ID_LENGTH: usize = 35;
+
+/// Maximum length of `Max140Text` names in pain.001.
+const PAIN_001_MAX_NAME_LENGTH: usize = 140;
+
+/// A payout along with the destination details required for bank file exports.
+#[derive(Clone, Debug)]
+pub struct ExportablePayout {
+    pub payout_id: String,
+    pub amount: MinorUnit,
+    pub currency: api_enums::Currency,
+    pub creditor_name: Option<Secret<String>>,
+    pub payout_method_data: Option<payouts::PayoutMethodData>,
+}
+
+impl From<&PayoutData> for ExportablePayout {
+    fn from(payout_data: &PayoutData) -> Self {
+        Self {
+            payout_id: payout_data.payouts.payout_id.clone(),
+            amount: payout_data.payouts.amount,
+            currency: payout_data.payouts.destination_currency,
+            creditor_name: payout_data
+                .customer_details
+                .as_ref()
+                .and_then(|customer| customer.name.clone())
+                .map(|name| name.into_inner()),
+            payout_method_data: payout_data.payout_method_data.clone(),
+        }
+    }
+}
+
+/// The account of the merchant from which the exported payouts are to be debited.
+#[derive(Clone, Debug)]
+pub struct Pain001Debtor {
+    pub name: String,
+    pub iban: Secret<String>,
+    pub bic: Option<Secret<String>>,
+}
+
+/// A payout which was left out of a bank file export, along with the reason.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExcludedPayout {
+    pub payout_id: String,
+    pub reason: String,
+}
+
+/// A bank file generated from a batch of payouts.
+#[derive(Clone, Debug)]
+pub struct PayoutFileExport {
+    pub file: Vec<u8>,
+    pub included_payout_ids: Vec<String>,
+    pub excluded_payouts: Vec<ExcludedPayout>,
+}
+
+/// Identification of the creditor's account and bank within a pain.001 transaction.
+enum Pain001CreditorAccount {
+    Iban {
+        iban: String,
+        bic: Option<String>,
+    },
+    ClearingSystem {
+        clearing_system_code: &'static str,
+        member_id: String,
+        account_number: String,
+    },
+}
+
+struct Pain001Transaction {
+    payout_id: String,
+    amount: Decimal,
+    currency: api_enums::Currency,
+    creditor_name: String,
+    account: Pain001CreditorAccount,
+}
+
+/// Serializes a batch of bank payouts into an ISO 20022 pain.001.001.03 credit transfer
+/// initiation message, to be submitted by the merchant to their own bank.
+///
+/// Payouts which are not made to a bank account, or to a bank account which cannot be identified
+/// in pain.001 (Pix), are excluded from the file and reported separately. All the fields required
+/// by the standard are validated for the included payouts before the file is generated. The
+/// control sums of the file add up the amounts of all the transactions, so the included payouts
+/// must all be in the same currency.
+pub fn generate_pain001_export(
+    message_id: &str,
+    debtor: &Pain001Debtor,
+    payouts: &[ExportablePayout],
+) -> RouterResult<PayoutFileExport> {
+    validate_pain001_text("message_id", message_id, PAIN_001_MAX_ID_LENGTH)?;
+    validate_pain001_text("debtor.name", &debtor.name, PAIN_001_MAX_NAME_LENGTH)?;
+    validator::validate_iban("debtor.iban", debtor.iban.peek())?;
+    if let Some(bic) = &debtor.bic {
+        validator::validate_bic("debtor.bic", bic.peek())?;
+    }
+
+    let mut transactions = Vec::new();
+    let mut excluded_payouts = Vec::new();
+    for payout in payouts {
+        let bank = match &payout.payout_method_data {
+            Some(payouts::PayoutMethodData::Bank(bank)) => bank,
+            _ => {
+                excluded_payouts.push(ExcludedPayout {
+                    payout_id: payout.payout_id.clone(),
+                    reason: "payout method is not a bank transfer".to_string(),
+                });
+                continue;
+            }
+        };
+        let account = get_pain001_creditor_account(bank)
+            .attach_printable_lazy(|| format!("Invalid payout_id: {}", payout.payout_id))?;
+        match account {
+            Some(account) => transactions.push(get_pain001_transaction(payout, account)?),
+            None => excluded_payouts.push(ExcludedPayout {
+                payout_id: payout.payout_id.clone(),
+                reason: "bank transfer is not supported in pain.001".to_string(),
+            }),
+        }
+    }
+    utils::when(transactions.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "No bank payouts to export in pain.001".to_string(),
+        }))
+    })?;
+    if let Some(transaction) = transactions
+        .iter()
+        .find(|transaction| transaction.currency != transactions[0].currency)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout {} is in {}, all the bank payouts exported in a pain.001 file must be in \
+                 {}",
+                transaction.payout_id, transaction.currency, transactions[0].currency
+            ),
+        }));
+    }
+
+    let created_at = common_utils::date_time::now();
+    let created_at_iso = created_at
+        .assume_utc()
+        .format(&Iso8601::DEFAULT)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to format pain.001 creation time")?;
+    let number_of_transactions = transactions.len().to_string();
+    let control_sum = transactions
+        .iter()
+        .map(|transaction| transaction.amount)
+        .sum::<Decimal>()
+        .to_string();
+
+    let mut xml = String::new();
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str(&format!(r#"<Document xmlns="{PAIN_001_NAMESPACE}">"#));
+    xml.push_str("<CstmrCdtTrfInitn><GrpHdr>");
+    write_xml_element(&mut xml, "MsgId", message_id);
+    write_xml_element(&mut xml, "CreDtTm", &created_at_iso);
+    write_xml_element(&mut xml, "NbOfTxs", &number_of_transactions);
+    write_xml_element(&mut xml, "CtrlSum", &control_sum);
+    xml.push_str("<InitgPty>");
+    write_xml_element(&mut xml, "Nm", &debtor.name);
+    xml.push_str("</InitgPty></GrpHdr><PmtInf>");
+    write_xml_element(&mut xml, "PmtInfId", message_id);
+    write_xml_element(&mut xml, "PmtMtd", "TRF");
+    write_xml_element(&mut xml, "NbOfTxs", &number_of_transactions);
+    write_xml_element(&mut xml, "CtrlSum", &control_sum);
+    write_xml_element(&mut xml, "ReqdExctnDt", &created_at.date().to_string());
+    xml.push_str("<Dbtr>");
+    write_xml_element(&mut xml, "Nm", &debtor.name);
+    xml.push_str("</Dbtr><DbtrAcct><Id>");
+    write_xml_element(&mut xml, "IBAN", debtor.iban.peek());
+    xml.push_str("</Id></DbtrAcct><DbtrAgt><FinInstnId>");
+    match &debtor.bic {
+        Some(bic) => write_xml_element(&mut xml, "BIC", bic.peek()),
+        None => xml.push_str("<Othr><Id>NOTPROVIDED</Id></Othr>"),
+    }
+    xml.push_str("</FinInstnId></DbtrAgt>");
+    for transaction in &transactions {
+        write_pain001_transaction(&mut xml, transaction);
+    }
+    xml.push_str("</PmtInf></CstmrCdtTrfInitn></Document>");
+
+    Ok(PayoutFileExport {
+        file: xml.into_bytes(),
+        included_payout_ids: transactions
+            .into_iter()
+            .map(|transaction| transaction.payout_id)
+            .collect(),
+        excluded_payouts,
+    })
+}
+
+/// Returns the creditor account for the bank transfer, or `None` if the bank transfer cannot be
+/// represented in pain.001.
+fn get_pain001_creditor_account(
+    bank: &payouts::Bank,
+) -> RouterResult<Option<Pain001CreditorAccount>> {
+    match bank {
+        payouts::Bank::Sepa(sepa) => {
+            validator::validate_iban("bank.iban", sepa.iban.peek())?;
+            if let Some(bic) = &sepa.bic {
+                validator::validate_bic("bank.bic", bic.peek())?;
+            }
+            Ok(Some(Pain001CreditorAccount::Iban {
+                iban: sepa.iban.peek().to_owned(),
+                bic: sepa.bic.as_ref().map(|bic| bic.peek().to_owned()),
+            }))
+        }
+        payouts::Bank::Ach(ach) => {
+            validator::validate_numeric_code(
+                "bank.bank_routing_number",
+                ach.bank_routing_number.peek(),
+                9,
+            )?;
+            validate_pain001_text(
+                "bank.bank_account_number",
+                ach.bank_account_number.peek(),
+                PAIN_001_MAX_ID_LENGTH,
+            )?;
+            Ok(Some(Pain001CreditorAccount::ClearingSystem {
+                clearing_system_code: "USABA",
+                member_id: ach.bank_routing_number.peek().to_owned(),
+                account_number: ach.bank_account_number.peek().to_owned(),
+            }))
+        }
+        payouts::Bank::Bacs(bacs) => {
+            let sort_code = bacs.bank_sort_code.peek().replace('-', "");
+            validator::validate_numeric_code("bank.bank_sort_code", &sort_code, 6)?;
+            validate_pain001_text(
+                "bank.bank_account_number",
+                bacs.bank_account_number.peek(),
+                PAIN_001_MAX_ID_LENGTH,
+            )?;
+            Ok(Some(Pain001CreditorAccount::ClearingSystem {
+                clearing_system_code: "GBDSC",
+                member_id: sort_code,
+                account_number: bacs.bank_account_number.peek().to_owned(),
+            }))
+        }
+        payouts::Bank::Pix(_) => Ok(None),
+    }
+}
+
+fn get_pain001_transaction(
+    payout: &ExportablePayout,
+    account: Pain001CreditorAccount,
+) -> RouterResult<Pain001Transaction> {
+    let creditor_name = payout
+        .creditor_name
+        .as_ref()
+        .map(|name| name.peek().trim().to_owned())
+        .unwrap_or_default();
+    validate_pain001_text("creditor_name", &creditor_name, PAIN_001_MAX_NAME_LENGTH)
+        .attach_printable_lazy(|| format!("Invalid payout_id: {}", payout.payout_id))?;
+    utils::when(payout.amount <= MinorUnit::new(0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Payout {} has no amount to transfer", payout.payout_id),
+        }))
+    })?;
+
+    Ok(Pain001Transaction {
+        payout_id: payout.payout_id.clone(),
+        amount: Decimal::new(
+            payout.amount.get_amount_as_i64(),
+            u32::from(payout.currency.number_of_digits_after_decimal_point()),
+        ),
+        currency: payout.currency,
+        creditor_name,
+        account,
+    })
+}
+
+/// Writes a single `CdtTrfTxInf` block.
+///
+/// `EndToEndId` is limited to 35 characters, longer payout IDs are carried in the unstructured
+/// remittance information instead, which is passed back to the creditor by the bank.
+fn write_pain001_transaction(xml: &mut String, transaction: &Pain001Transaction) {
+    let end_to_end_id = if transaction.payout_id.len() <= PAIN_001_MAX_ID_LENGTH {
+        transaction.payout_id.as_str()
+    } else {
+        "NOTPROVIDED"
+    };
+
+    xml.push_str("<CdtTrfTxInf><PmtId>");
+    write_xml_element(xml, "EndToEndId", end_to_end_id);
+    xml.push_str(&format!(
+        r#"</PmtId><Amt><InstdAmt Ccy="{}">{}</InstdAmt></Amt>"#,
+        transaction.currency, transaction.amount
+    ));
+    match &transaction.account {
+        Pain001CreditorAccount::Iban { iban, bic } => {
+            if let Some(bic) = bic {
+                xml.push_str("<CdtrAgt><FinInstnId>");
+                write_xml_element(xml, "BIC", bic);
+                xml.push_str("</FinInstnId></CdtrAgt>");
+            }
+            xml.push_str("<Cdtr>");
+            write_xml_element(xml, "Nm", &transaction.creditor_name);
+            xml.push_str("</Cdtr><CdtrAcct><Id>");
+            write_xml_element(xml, "IBAN", iban);
+            xml.push_str("</Id></CdtrAcct>");
+        }
+        Pain001CreditorAccount::ClearingSystem {
+            clearing_system_code,
+            member_id,
+            account_number,
+        } => {
+            xml.push_str("<CdtrAgt><FinInstnId><ClrSysMmbId><ClrSysId>");
+            write_xml_element(xml, "Cd", clearing_system_code);
+            xml.push_str("</ClrSysId>");
+            write_xml_element(xml, "MmbId", member_id);
+            xml.push_str("</ClrSysMmbId></FinInstnId></CdtrAgt><Cdtr>");
+            write_xml_element(xml, "Nm", &transaction.creditor_name);
+            xml.push_str("</Cdtr><CdtrAcct><Id><Othr>");
+            write_xml_element(xml, "Id", account_number);
+            xml.push_str("</Othr></Id></CdtrAcct>");
+        }
+    }
+    xml.push_str("<RmtInf>");
+    write_xml_element(xml, "Ustrd", &transaction.payout_id);
+    xml.push_str("</RmtInf></CdtTrfTxInf>");
+}
+
+fn validate_pain001_text(field_name: &str, value: &str, max_length: usize) -> RouterResult<()> {
+    utils::when(value.trim().is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field_name} is required in pain.001"),
+        }))
+    })?;
+    utils::when(value.chars().count() > max_length, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: field_name.to_string(),
+            expected_format: format!("at most {max_length} characters"),
+        }))
+    })
+}
+
+fn write_xml_element(xml: &mut String, tag: &str, value: &str) {
+    // Writing to a `String` never fails
+    let _ = write!(xml, "<{tag}>{}</{tag}>", quick_xml::escape::escape(value));
+}
+
+/// Length of every record in a NACHA file.
+const NACHA_RECORD_LENGTH: usize = 94;
+
+/// Number of records in a NACHA block, the file is padded with `9` filled records to a multiple
+/// of it.
+const NACHA_BLOCKING_FACTOR: usize = 10;
+
+/// Largest amount, in cents, which fits the 10 digit amount field of a NACHA entry.
+const NACHA_MAX_ENTRY_AMOUNT: i64 = 9_999_999_999;
+
+/// The merchant and their originating bank (ODFI) on whose behalf the ACH file is submitted.
+#[derive(Clone, Debug)]
+pub struct NachaOriginator {
+    pub odfi_routing_number: String,
+    pub odfi_name: String,
+    pub company_name: String,
+    pub company_identification: String,
+}
+
+struct NachaEntry {
+    payout_id: String,
+    routing_number: String,
+    account_number: String,
+    amount: i64,
+    individual_name: String,
+}
+
+/// Generates a NACHA formatted ACH file, with a single batch of credit entries, from the US ACH
+/// payouts in the batch.
+///
+/// Payouts which are not ACH bank transfers are excluded from the file and reported separately,
+/// while ACH payouts in a currency other than USD fail the export.
+pub fn generate_nacha_export(
+    originator: &NachaOriginator,
+    effective_entry_date: time::Date,
+    payouts: &[ExportablePayout],
+) -> RouterResult<PayoutFileExport> {
+    validator::validate_aba_routing_number("odfi_routing_number", &originator.odfi_routing_number)?;
+    validate_nacha_text("odfi_name", &originator.odfi_name, 23)?;
+    validate_nacha_text("company_name", &originator.company_name, 16)?;
+    validate_nacha_text(
+        "company_identification",
+        &originator.company_identification,
+        10,
+    )?;
+
+    let mut entries = Vec::new();
+    let mut excluded_payouts = Vec::new();
+    for payout in payouts {
+        let ach = match &payout.payout_method_data {
+            Some(payouts::PayoutMethodData::Bank(payouts::Bank::Ach(ach))) => ach,
+            _ => {
+                excluded_payouts.push(ExcludedPayout {
+                    payout_id: payout.payout_id.clone(),
+                    reason: "payout method is not an ACH bank transfer".to_string(),
+                });
+                continue;
+            }
+        };
+        entries.push(
+            get_nacha_entry(payout, ach)
+                .attach_printable_lazy(|| format!("Invalid payout_id: {}", payout.payout_id))?,
+        );
+    }
+    utils::when(entries.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "No ACH payouts to export in NACHA file".to_string(),
+        }))
+    })?;
+
+    let created_at = common_utils::date_time::now();
+    let creation_date = format_nacha_date(created_at.date());
+    let creation_time = format!("{:02}{:02}", created_at.hour(), created_at.minute());
+    let odfi_identification = originator.odfi_routing_number.get(..8).unwrap_or_default();
+    let entry_count = entries.len();
+    let entry_hash = entries
+        .iter()
+        .map(|entry| {
+            entry
+                .routing_number
+                .get(..8)
+                .and_then(|rdfi| rdfi.parse::<u64>().ok())
+                .unwrap_or_default()
+        })
+        .sum::<u64>()
+        % 10_000_000_000;
+    let total_credit = entries.iter().map(|entry| entry.amount).sum::<i64>();
+
+    let mut records = vec![format!(
+        "101 {}{}{}{}A094101{}{}{}",
+        originator.odfi_routing_number,
+        nacha_alpha(&originator.company_identification, 10),
+        creation_date,
+        creation_time,
+        nacha_alpha(&originator.odfi_name, 23),
+        nacha_alpha(&originator.company_name, 23),
+        nacha_alpha("", 8),
+    )];
+    records.push(format!(
+        "5220{}{}{}PPD{}{}{}   1{}0000001",
+        nacha_alpha(&originator.company_name, 16),
+        nacha_alpha("", 20),
+        nacha_alpha(&originator.company_identification, 10),
+        nacha_alpha("PAYOUT", 10),
+        creation_date,
+        format_nacha_date(effective_entry_date),
+        odfi_identification,
+    ));
+    for (sequence, entry) in (1..).zip(entries.iter()) {
+        records.push(format!(
+            "622{}{}{:010}{}{}  0{}{:07}",
+            entry.routing_number,
+            nacha_alpha(&entry.account_number, 17),
+            entry.amount,
+            nacha_alpha(&entry.payout_id, 15),
+            nacha_alpha(&entry.individual_name, 22),
+            odfi_identification,
+            sequence,
+        ));
+    }
+    records.push(format!(
+        "8220{:06}{:010}{:012}{:012}{}{}{}{}0000001",
+        entry_count,
+        entry_hash,
+        0,
+        total_credit,
+        nacha_alpha(&originator.company_identification, 10),
+        nacha_alpha("", 19),
+        nacha_alpha("", 6),
+        odfi_identification,
+    ));
+    let block_count = (records.len() + 1).div_ceil(NACHA_BLOCKING_FACTOR);
+    records.push(format!(
+        "9{:06}{:06}{:08}{:010}{:012}{:012}{}",
+        1,
+        block_count,
+        entry_count,
+        entry_hash,
+        0,
+        total_credit,
+        nacha_alpha("", 39),
+    ));
+    records.resize(
+        block_count * NACHA_BLOCKING_FACTOR,
+        "9".repeat(NACHA_RECORD_LENGTH),
+    );
+
+    let mut file = records.join("\n");
+    file.push('\n');
+    Ok(PayoutFileExport {
+        file: file.into_bytes(),
+        included_payout_ids: entries.into_iter().map(|entry| entry.payout_id).collect(),
+        excluded_payouts,
+    })
+}
+
+fn get_nacha_entry(
+    payout: &ExportablePayout,
+    ach: &payouts::AchBankTransfer,
+) -> RouterResult<NachaEntry> {
+    utils::when(payout.currency != api_enums::Currency::USD, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout {} is in {}, only USD payouts can be exported in a NACHA file",
+                payout.payout_id, payout.currency
+            ),
+        }))
+    })?;
+    let amount = payout.amount.get_amount_as_i64();
+    utils::when(amount <= 0 || amount > NACHA_MAX_ENTRY_AMOUNT, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout {} amount cannot be represented in a NACHA entry",
+                payout.payout_id
+            ),
+        }))
+    })?;
+    validator::validate_aba_routing_number(
+        "bank.bank_routing_number",
+        ach.bank_routing_number.peek(),
+    )?;
+    validate_nacha_text(
+        "bank.bank_account_number",
+        ach.bank_account_number.peek(),
+        17,
+    )?;
+    let individual_name = payout
+        .creditor_name
+        .as_ref()
+        .map(|name| name.peek().trim().to_owned())
+        .unwrap_or_default();
+    utils::when(individual_name.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "creditor_name is required in a NACHA entry".to_string(),
+        }))
+    })?;
+
+    Ok(NachaEntry {
+        payout_id: payout.payout_id.clone(),
+        routing_number: ach.bank_routing_number.peek().to_owned(),
+        account_number: ach.bank_account_number.peek().trim().to_owned(),
+        amount,
+        individual_name,
+    })
+}
+
+fn validate_nacha_text(field_name: &str, value: &str, max_length: usize) -> RouterResult<()> {
+    utils::when(value.trim().is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field_name} is required in a NACHA file"),
+        }))
+    })?;
+    utils::when(!value.is_ascii() || value.len() > max_length, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: field_name.to_string(),
+            expected_format: format!("at most {max_length} ASCII characters"),
+        }))
+    })
+}
+
+/// Formats an alphanumeric NACHA field, left justified, space padded and truncated to `length`.
+fn nacha_alpha(value: &str, length: usize) -> String {
+    let value = value
+        .chars()
+        .map(|c| {
+            if c.is_ascii() && !c.is_ascii_control() {
+                c
+            } else {
+                ' '
+            }
+        })
+        .take(length)
+        .collect::<String>()
+        .to_uppercase();
+    format!("{value:<length$}")
+}
+
+fn format_nacha_date(date: time::Date) -> String {
+    format!(
+        "{:02}{:02}{:02}",
+        date.year() % 100,
+        u8::from(date.month()),
+        date.day()
+    )
+}
+
+/// Builds the export records of a page of payouts. Payouts whose attempt is missing are skipped.
+fn get_export_records(
+    payouts: &[storage::Payouts],
+    payout_attempts: &[storage::PayoutAttempt],
+) -> RouterResult<Vec<Vec<String>>> {
+    let payout_attempts = payout_attempts
+        .iter()
+        .map(|payout_attempt| (payout_attempt.payout_attempt_id.as_str(), payout_attempt))
+        .collect::<HashMap<_, _>>();
+
+    payouts
+        .iter()
+        .filter_map(|payout| {
+            let payout_attempt_id =
+                utils::get_payment_attempt_id(&payout.payout_id, payout.attempt_count);
+            let payout_attempt = payout_attempts.get(payout_attempt_id.as_str());
+            if payout_attempt.is_none() {
+                logger::warn!(
+                    "payout_attempt missing for payout_id : {}, skipping from export",
+                    payout.payout_id,
+                );
+            }
+            payout_attempt.map(|payout_attempt| get_export_record(payout, payout_attempt))
+        })
+        .collect()
+}
+
+fn get_export_record(
+    payout: &storage::Payouts,
+    payout_attempt: &storage::PayoutAttempt,
+) -> RouterResult<Vec<String>> {
+    let format_time = |time: time::PrimitiveDateTime| {
+        time.assume_utc()
+            .format(&Iso8601::DEFAULT)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to format payout timestamp for export")
+    };
+
+    Ok(vec![
+        payout.payout_id.clone(),
+        payout_attempt.connector.clone().unwrap_or_default(),
+        payout_attempt
+            .connector_payout_id
+            .clone()
+            .unwrap_or_default(),
+        payout.amount.get_amount_as_i64().to_string(),
+        payout.destination_currency.to_string(),
+        payout_attempt.status.to_string(),
+        format_time(payout.created_at)?,
+        format_time(payout.last_modified_at)?,
+        String::new(),
+    ])
+}
+
+/// Appends a single CSV record, quoting fields as described in RFC 4180.
+fn write_csv_record(csv: &mut String, fields: impl IntoIterator<Item = String>) {
+    let record = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    // Writing to a `String` never fails
+    let _ = writeln!(csv, "{record}");
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_payouts_export_file_keys() {
+        // Jobs created before exports were stored in parts have no count of parts
+        let tracking_data: PayoutsExportTrackingData = serde_json::from_value(serde_json::json!({
+            "export_id": "payout_export_1",
+            "merchant_id": "merchant_1",
+            "constraints": {}
+        }))
+        .unwrap();
+        assert_eq!(
+            get_payouts_export_file_keys(&tracking_data),
+            vec!["merchant_1/payout_exports/payout_export_1.csv"]
+        );
+
+        let tracking_data = PayoutsExportTrackingData {
+            parts_count: Some(3),
+            ..tracking_data
+        };
+        assert_eq!(
+            get_payouts_export_file_keys(&tracking_data),
+            vec![
+                "merchant_1/payout_exports/payout_export_1/part-0.csv",
+                "merchant_1/payout_exports/payout_export_1/part-1.csv",
+                "merchant_1/payout_exports/payout_export_1/part-2.csv",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_csv_record_escapes_fields() {
+        let mut csv = String::new();
+        write_csv_record(
+            &mut csv,
+            PAYOUTS_EXPORT_HEADER.iter().map(|h| h.to_string()),
+        );
+        write_csv_record(
+            &mut csv,
+            [
+                "payout_1",
+                "adyen",
+                "ref,with,commas",
+                "1050",
+                "EUR",
+                "success",
+                "2024-01-01T00:00:00.000000000Z",
+                "say \"hi\"",
+                "line\nbreak",
+            ]
+            .map(String::from),
+        );
+
+        assert_eq!(
+            csv,
+            "payout_id,connector,connector_payout_id,amount,currency,status,created_at,\
+             last_modified_at,fees\n\
+             payout_1,adyen,\"ref,with,commas\",1050,EUR,success,\
+             2024-01-01T00:00:00.000000000Z,\"say \"\"hi\"\"\",\"line\nbreak\"\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_pain001_export_skips_non_bank_payouts() {
+        let debtor = Pain001Debtor {
+            name: "Acme Inc".to_string(),
+            iban: Secret::new("DE89370400440532013000".to_string()),
+            bic: Some(Secret::new("COBADEFFXXX".to_string())),
+        };
+        let sepa_payout = ExportablePayout {
+            payout_id: "payout_sepa".to_string(),
+            amount: MinorUnit::new(1050),
+            currency: api_enums::Currency::EUR,
+            creditor_name: Some(Secret::new("John & Jane Doe".to_string())),
+            payout_method_data: Some(payouts::PayoutMethodData::Bank(payouts::Bank::Sepa(
+                payouts::SepaBankTransfer {
+                    iban: Secret::new("NL91ABNA0417164300".to_string()),
+                    ..Default::default()
+                },
+            ))),
+        };
+        let card_payout = ExportablePayout {
+            payout_id: "payout_card".to_string(),
+            payout_method_data: None,
+            ..sepa_payout.clone()
+        };
+
+        let export =
+            generate_pain001_export("batch_1", &debtor, &[sepa_payout.clone(), card_payout])
+                .unwrap();
+        let xml = String::from_utf8(export.file).unwrap();
+        assert_eq!(export.included_payout_ids, vec!["payout_sepa".to_string()]);
+        assert_eq!(export.excluded_payouts.len(), 1);
+        assert!(xml.contains("<NbOfTxs>1</NbOfTxs><CtrlSum>10.50</CtrlSum>"));
+        assert!(xml.contains(r#"<InstdAmt Ccy="EUR">10.50</InstdAmt>"#));
+        assert!(xml.contains("<Nm>John &amp; Jane Doe</Nm>"));
+        assert!(xml.contains("<IBAN>NL91ABNA0417164300</IBAN>"));
+
+        let unnamed_payout = ExportablePayout {
+            creditor_name: None,
+            ..sepa_payout
+        };
+        assert!(generate_pain001_export("batch_2", &debtor, &[unnamed_payout]).is_err());
+    }
+
+    #[test]
+    fn test_generate_pain001_export_rejects_mixed_currencies() {
+        let debtor = Pain001Debtor {
+            name: "Acme Inc".to_string(),
+            iban: Secret::new("DE89370400440532013000".to_string()),
+            bic: None,
+        };
+        let eur_payout = ExportablePayout {
+            payout_id: "payout_eur".to_string(),
+            amount: MinorUnit::new(1050),
+            currency: api_enums::Currency::EUR,
+            creditor_name: Some(Secret::new("John Doe".to_string())),
+            payout_method_data: Some(payouts::PayoutMethodData::Bank(payouts::Bank::Sepa(
+                payouts::SepaBankTransfer {
+                    iban: Secret::new("NL91ABNA0417164300".to_string()),
+                    ..Default::default()
+                },
+            ))),
+        };
+        let gbp_payout = ExportablePayout {
+            payout_id: "payout_gbp".to_string(),
+            currency: api_enums::Currency::GBP,
+            ..eur_payout.clone()
+        };
+
+        assert!(
+            generate_pain001_export("batch_1", &debtor, &[eur_payout.clone(), gbp_payout]).is_err()
+        );
+        let export = generate_pain001_export(
+            "batch_2",
+            &debtor,
+            &[
+                eur_payout.clone(),
+                ExportablePayout {
+                    payout_id: "payout_eur_2".to_string(),
+                    amount: MinorUnit::new(200),
+                    ..eur_payout
+                },
+            ],
+        )
+        .unwrap();
+        let xml = String::from_utf8(export.file).unwrap();
+        assert!(xml.contains("<NbOfTxs>2</NbOfTxs><CtrlSum>12.50</CtrlSum>"));
+    }
+
+    #[test]
+    fn test_generate_nacha_export() {
+        let originator = NachaOriginator {
+            odfi_routing_number: "021000021".to_string(),
+            odfi_name: "JPMORGAN CHASE".to_string(),
+            company_name: "Acme Inc".to_string(),
+            company_identification: "1234567890".to_string(),
+        };
+        let ach_payout = ExportablePayout {
+            payout_id: "payout_ach".to_string(),
+            amount: MinorUnit::new(1050),
+            currency: api_enums::Currency::USD,
+            creditor_name: Some(Secret::new("John Doe".to_string())),
+            payout_method_data: Some(payouts::PayoutMethodData::Bank(payouts::Bank::Ach(
+                payouts::AchBankTransfer {
+                    bank_account_number: Secret::new("000123456789".to_string()),
+                    bank_routing_number: Secret::new("110000000".to_string()),
+                    ..Default::default()
+                },
+            ))),
+        };
+        let card_payout = ExportablePayout {
+            payout_id: "payout_card".to_string(),
+            payout_method_data: None,
+            ..ach_payout.clone()
+        };
+        let effective_entry_date = common_utils::date_time::now().date();
+
+        let export = generate_nacha_export(
+            &originator,
+            effective_entry_date,
+            &[ach_payout.clone(), card_payout],
+        )
+        .unwrap();
+        let file = String::from_utf8(export.file).unwrap();
+        let records = file.lines().collect::<Vec<_>>();
+        assert_eq!(export.included_payout_ids, vec!["payout_ach".to_string()]);
+        assert_eq!(export.excluded_payouts.len(), 1);
+        assert_eq!(records.len(), NACHA_BLOCKING_FACTOR);
+        assert!(records
+            .iter()
+            .all(|record| record.len() == NACHA_RECORD_LENGTH));
+        assert!(records
+            .get(2)
+            .unwrap()
+            .starts_with("622110000000000123456789     0000001050"));
+        assert!(records
+            .get(3)
+            .unwrap()
+            .starts_with("82200000010011000000000000000000000000001050"));
+
+        let eur_payout = ExportablePayout {
+            currency: api_enums::Currency::EUR,
+            ..ach_payout.clone()
+        };
+        assert!(generate_nacha_export(&originator, effective_entry_date, &[eur_payout]).is_err());
+
+        let invalid_routing_payout = ExportablePayout {
+            payout_method_data: Some(payouts::PayoutMethodData::Bank(payouts::Bank::Ach(
+                payouts::AchBankTransfer {
+                    bank_account_number: Secret::new("000123456789".to_string()),
+                    bank_routing_number: Secret::new("11000000".to_string()),
+                    ..Default::default()
+                },
+            ))),
+            ..ach_payout
+        };
+        assert!(generate_nacha_export(
+            &originator,
+            effective_entry_date,
+            &[invalid_routing_payout]
+        )
+        .is_err());
+    }
+}
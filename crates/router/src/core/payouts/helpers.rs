@@ -1,18 +1,30 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
 use api_models::{enums, payment_methods::Card, payouts};
 use common_utils::{
+    crypto::{self, GenerateDigest, SignMessage},
     errors::CustomResult,
-    ext_traits::{AsyncExt, StringExt},
-    fp_utils, generate_customer_id_of_default_length, id_type,
+    ext_traits::{AsyncExt, Encode, StringExt, ValueExt},
+    fp_utils, generate_customer_id_of_default_length, id_type, pii,
     types::MinorUnit,
 };
 use diesel_models::encryption::Encryption;
 use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, PeekInterface, Secret};
-use router_env::logger;
+use router_env::{logger, metrics::add_attributes};
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+use time::PrimitiveDateTime;
 
 use super::PayoutData;
 use crate::{
+    configs::settings,
+    consts,
     core::{
+        blocklist::utils as blocklist_utils,
+        currency as core_currency,
         errors::{self, RouterResult, StorageErrorExt},
         payment_methods::{
             cards,
@@ -20,8 +32,8 @@ use crate::{
             vault,
         },
         payments::{
-            customers::get_connector_customer_details_if_present, route_connector_v1, routing,
-            CustomerDetails,
+            customers::get_connector_customer_details_if_present, helpers as payment_helpers,
+            route_connector_v1, routing, CustomerDetails,
         },
         routing::TransactionData,
     },
@@ -34,10 +46,10 @@ use crate::{
             self,
             types::{self as domain_types, AsyncLift},
         },
-        storage,
+        storage::{self, payouts::PayoutCurrencyConversion},
         transformers::ForeignFrom,
     },
-    utils::{self, OptionExt},
+    utils::{self, currency, OptionExt},
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -153,6 +165,9 @@ pub async fn make_payout_method_data<'a>(
 
         // Create / Update operation
         (Some(payout_method), payout_token, Some(payout_data)) => {
+            // The request is validated as passed, the normalized details are the ones stored and
+            // passed to the connector
+            let payout_method = &normalize_payout_method_data(payout_method.clone());
             let lookup_key = vault::Vault::store_payout_method_data_in_locker(
                 state,
                 payout_token.to_owned(),
@@ -162,6 +177,29 @@ pub async fn make_payout_method_data<'a>(
             )
             .await?;
 
+            // Update the destination's fingerprint in payouts table
+            let payout_method_fingerprint =
+                get_payout_method_fingerprint(state, merchant_id, payout_method).await?;
+            if let Some(payout_method_fingerprint) =
+                payout_method_fingerprint.filter(|fingerprint| {
+                    payout_data.payouts.payout_method_fingerprint.as_ref() != Some(fingerprint)
+                })
+            {
+                let updated_payouts = storage::PayoutsUpdate::PayoutMethodFingerprintUpdate {
+                    payout_method_fingerprint,
+                };
+                payout_data.payouts = db
+                    .update_payout(
+                        &payout_data.payouts,
+                        updated_payouts,
+                        &payout_data.payout_attempt,
+                        storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Error updating payout method fingerprint in payouts")?;
+            }
+
             // Update payout_token in payout_attempt table
             if payout_token.is_none() {
                 let updated_payout_attempt = storage::PayoutAttemptUpdate::PayoutTokenUpdate {
@@ -193,6 +231,11 @@ pub async fn save_payout_data_to_locker(
     merchant_account: &domain::MerchantAccount,
     key_store: &domain::MerchantKeyStore,
 ) -> RouterResult<()> {
+    // Network token cryptograms are single use, so the payout method can not be saved for reuse
+    if let payouts::PayoutMethodData::NetworkToken(_) = payout_method_data {
+        return Ok(());
+    }
+
     let payout_attempt = &payout_data.payout_attempt;
     let (mut locker_req, card_details, bank_details, wallet_details, payment_method_type) =
         match payout_method_data {
@@ -274,7 +317,9 @@ pub async fn save_payout_data_to_locker(
                         Some(wallet.to_owned()),
                         api_enums::PaymentMethodType::foreign_from(wallet.to_owned()),
                     ),
-                    payouts::PayoutMethodData::Card(_) => {
+                    payouts::PayoutMethodData::Card(_)
+                    | payouts::PayoutMethodData::StoreCredit(_)
+                    | payouts::PayoutMethodData::NetworkToken(_) => {
                         Err(errors::ApiErrorResponse::InternalServerError)?
                     }
                 }
@@ -879,6 +924,236 @@ pub async fn get_gsm_record(
         .ok()
 }
 
+/// Whether the payout attempt failed because of its destination. Failures with an error code are
+/// attributed to the destination as configured in the gateway status mapping of the connector's
+/// error. Failures without one, which are reported as a status by the connector's response or its
+/// webhooks, are always attributed to the destination.
+pub async fn is_payout_destination_failure(
+    state: &SessionState,
+    connector: &str,
+    payout_attempt: &storage::PayoutAttempt,
+) -> bool {
+    if !is_payout_destination_failure_status(payout_attempt.status) {
+        return false;
+    }
+    let gsm_decision = match payout_attempt.error_code.as_ref() {
+        Some(_) => get_gsm_record(
+            state,
+            payout_attempt.error_code.to_owned(),
+            payout_attempt.error_message.to_owned(),
+            Some(connector.to_string()),
+            "payout_flow".to_string(),
+        )
+        .await
+        .and_then(|gsm| gsm.decision.parse::<api_models::gsm::GsmDecision>().ok()),
+        None => None,
+    };
+    is_destination_failure(payout_attempt.error_code.as_deref(), gsm_decision)
+}
+
+/// Terminal failure statuses after which a payout can be retried with another destination
+fn is_payout_destination_failure_status(status: api_enums::PayoutStatus) -> bool {
+    matches!(
+        status,
+        api_enums::PayoutStatus::Failed
+            | api_enums::PayoutStatus::Ineligible
+            | api_enums::PayoutStatus::Reversed
+    )
+}
+
+fn is_destination_failure(
+    error_code: Option<&str>,
+    gsm_decision: Option<api_models::gsm::GsmDecision>,
+) -> bool {
+    error_code.is_none()
+        || gsm_decision == Some(api_models::gsm::GsmDecision::RetryWithNextDestination)
+}
+
+/// Whether an error returned while processing a payout with a connector is the connector failing
+/// the payout, which leaves the attempt in a failed status and can be failed over to a backup
+/// destination, rather than an error which stops the processing of the payout.
+pub fn is_connector_payout_failure(
+    error: &errors::ApiErrorResponse,
+    status: api_enums::PayoutStatus,
+) -> bool {
+    matches!(error, errors::ApiErrorResponse::PayoutFailed { .. }) && is_payout_err_state(status)
+}
+
+/// Returns the backup destination to retry a payout with after the destination at
+/// `destination_index` failed. The backup destinations follow the primary one, whose index is 0,
+/// so the backup at the index of the failed destination is the next one.
+pub fn get_next_payout_destination(
+    backup_destinations: &[api::PayoutMethodData],
+    destination_index: Option<i16>,
+) -> Option<api::PayoutMethodData> {
+    usize::try_from(destination_index.unwrap_or(0))
+        .ok()
+        .and_then(|index| backup_destinations.get(index))
+        .cloned()
+}
+
+/// Encrypts the backup destinations of a payout, which are all of its destinations but the
+/// primary one, to be stored with the payout.
+pub async fn encrypt_payout_backup_destinations(
+    key_store: &domain::MerchantKeyStore,
+    destinations: Option<&Vec<api::PayoutMethodData>>,
+) -> RouterResult<Option<Encryption>> {
+    let backup_destinations = destinations
+        .map(|destinations| {
+            destinations
+                .iter()
+                .skip(1)
+                .collect::<Vec<_>>()
+                .encode_to_value()
+                .map(Secret::new)
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the backup destinations of the payout")?;
+    domain_types::encrypt_optional(backup_destinations, key_store.key.get_inner().peek())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the backup destinations of the payout")
+        .map(|encrypted| encrypted.map(Encryption::from))
+}
+
+/// Returns the backup destinations stored with the payout, in the order they are to be tried.
+pub async fn get_payout_backup_destinations(
+    key_store: &domain::MerchantKeyStore,
+    payouts: &storage::Payouts,
+) -> RouterResult<Vec<api::PayoutMethodData>> {
+    domain_types::decrypt::<serde_json::Value, masking::WithType>(
+        payouts.backup_destinations.clone(),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the backup destinations of the payout")?
+    .map(|destinations| {
+        destinations
+            .into_inner()
+            .expose()
+            .parse_value::<Vec<api::PayoutMethodData>>("PayoutBackupDestinations")
+    })
+    .transpose()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse the backup destinations of the payout")
+    .map(Option::unwrap_or_default)
+}
+
+/// Creates a new attempt of the payout for its next destination, the payout method data of the
+/// destination is stored in the locker when the attempt is processed.
+pub async fn create_payout_destination_attempt(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector: &api::ConnectorData,
+    payout_data: &mut PayoutData,
+    destination: api::PayoutMethodData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::AttemptCountUpdate {
+                attempt_count: payout_data.payouts.attempt_count + 1,
+            },
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts")?;
+
+    let payout_id = payouts.payout_id.to_owned();
+    let payout_attempt_req = storage::PayoutAttemptNew {
+        payout_attempt_id: utils::get_payment_attempt_id(&payout_id, payouts.attempt_count),
+        payout_id: payout_id.to_owned(),
+        customer_id: payout_data.payout_attempt.customer_id.to_owned(),
+        connector: Some(connector.connector_name.to_string()),
+        merchant_id: payout_data.payout_attempt.merchant_id.to_owned(),
+        address_id: payout_data.payout_attempt.address_id.to_owned(),
+        business_country: payout_data.payout_attempt.business_country.to_owned(),
+        business_label: payout_data.payout_attempt.business_label.to_owned(),
+        profile_id: payout_data.payout_attempt.profile_id.to_string(),
+        destination_index: Some(payout_data.payout_attempt.destination_index.unwrap_or(0) + 1),
+        ..Default::default()
+    };
+    payout_data.payout_attempt = db
+        .insert_payout_attempt(
+            payout_attempt_req,
+            &payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
+        .attach_printable("Error inserting payout_attempt in db")?;
+    payout_data.payouts = payouts;
+    payout_data.payout_method_data = Some(destination);
+    payout_data.merchant_connector_account = None;
+
+    Ok(())
+}
+
+/// Creates a new attempt of a failed payout to reprocess it, through the connector of the failed
+/// attempt. The failed attempt is left as is, so that the attempts of the payout record the
+/// history of its reprocessing. The payout token of the failed attempt is reused unless
+/// `reuse_payout_token` is unset, in which case the corrected payout method data is stored in the
+/// locker for the new attempt.
+pub async fn create_payout_reprocess_attempt(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+    reuse_payout_token: bool,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::AttemptCountUpdate {
+                attempt_count: payout_data.payouts.attempt_count + 1,
+            },
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts")?;
+
+    let payout_id = payouts.payout_id.to_owned();
+    let failed_attempt = &payout_data.payout_attempt;
+    let payout_attempt_req = storage::PayoutAttemptNew {
+        payout_attempt_id: utils::get_payment_attempt_id(&payout_id, payouts.attempt_count),
+        payout_id: payout_id.to_owned(),
+        customer_id: failed_attempt.customer_id.to_owned(),
+        connector: failed_attempt.connector.to_owned(),
+        merchant_connector_id: failed_attempt.merchant_connector_id.to_owned(),
+        merchant_id: failed_attempt.merchant_id.to_owned(),
+        address_id: payouts.address_id.to_owned(),
+        business_country: failed_attempt.business_country.to_owned(),
+        business_label: failed_attempt.business_label.to_owned(),
+        payout_token: failed_attempt
+            .payout_token
+            .to_owned()
+            .filter(|_| reuse_payout_token),
+        profile_id: failed_attempt.profile_id.to_string(),
+        routing_info: failed_attempt.routing_info.to_owned(),
+        ..Default::default()
+    };
+    payout_data.payout_attempt = db
+        .insert_payout_attempt(
+            payout_attempt_req,
+            &payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
+        .attach_printable("Error inserting payout_attempt in db")?;
+    payout_data.payouts = payouts;
+    payout_data.merchant_connector_account = None;
+
+    Ok(())
+}
+
 pub fn is_payout_initiated(status: api_enums::PayoutStatus) -> bool {
     matches!(
         status,
@@ -900,6 +1175,366 @@ pub(crate) fn validate_payout_status_against_not_allowed_statuses(
     })
 }
 
+/// Returns whether the connector is able to send payouts with the given priority, as per the
+/// configured priority support. Connectors which don't support selecting the rail always send
+/// payouts over their regular rail.
+pub fn is_payout_priority_supported(
+    priority_support: &HashMap<String, settings::PayoutPrioritySupport>,
+    connector: enums::Connector,
+    priority: enums::PayoutSendPriority,
+) -> bool {
+    priority == enums::PayoutSendPriority::Regular
+        || priority_support
+            .get(&connector.to_string())
+            .is_some_and(|support| support.priorities.contains(&priority))
+}
+
+/// Reason for which a connector is excluded from the connectors eligible for a payout. These are
+/// returned to the merchant, so they never carry any details of the payout itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+pub enum PayoutConnectorIneligibilityReason {
+    #[strum(serialize = "method unsupported")]
+    MethodUnsupported,
+    #[strum(serialize = "currency unsupported")]
+    CurrencyUnsupported,
+    #[strum(serialize = "charge bearer unsupported")]
+    ChargeBearerUnsupported,
+}
+
+/// Returns the reason for which the connector can not be used for the payout, if any, as per the
+/// capabilities of the connector and its currency support matrix.
+pub fn get_payout_connector_ineligibility_reason(
+    currency_support: &HashMap<String, settings::PayoutCurrencySupport>,
+    connector: api_enums::Connector,
+    payout_type: Option<api_enums::PayoutType>,
+    currency: api_enums::Currency,
+    charge_bearer: api_enums::PayoutChargeBearer,
+    is_network_token: bool,
+) -> Option<PayoutConnectorIneligibilityReason> {
+    if is_network_token && !connector.supports_network_token_payout() {
+        return Some(PayoutConnectorIneligibilityReason::MethodUnsupported);
+    }
+    if let (Some(support), Some(payout_type)) =
+        (currency_support.get(&connector.to_string()), payout_type)
+    {
+        match support.0.get(&payout_type) {
+            None => return Some(PayoutConnectorIneligibilityReason::MethodUnsupported),
+            Some(currencies) if !currencies.contains(&currency) => {
+                return Some(PayoutConnectorIneligibilityReason::CurrencyUnsupported)
+            }
+            Some(_) => {}
+        }
+    }
+    if !connector.supports_payout_charge_bearer(charge_bearer) {
+        return Some(PayoutConnectorIneligibilityReason::ChargeBearerUnsupported);
+    }
+    None
+}
+
+/// Excludes the connectors which can not be used for the payout from the eligible connectors. When
+/// none are specified, the candidates are the connectors of the merchant's payout connector
+/// accounts under the profile of the payout. The eligible connectors are returned as is when no
+/// connector is excluded, and when every candidate is excluded, the error lists the reason for
+/// which each of them was excluded.
+pub async fn get_eligible_payout_connectors(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payout_data: &PayoutData,
+    eligible_connectors: Option<Vec<enums::PayoutConnectors>>,
+) -> RouterResult<Option<Vec<enums::PayoutConnectors>>> {
+    let payouts = &payout_data.payouts;
+    let is_network_token = matches!(
+        payout_data.payout_method_data,
+        Some(api::PayoutMethodData::NetworkToken(_))
+    );
+    let candidates = match &eligible_connectors {
+        Some(eligible_connectors) => eligible_connectors.clone(),
+        None => get_merchant_payout_connectors(state, key_store, &payouts.profile_id).await?,
+    };
+    let mut exclusions = Vec::new();
+    let connectors = candidates
+        .into_iter()
+        .filter(|connector| {
+            match get_payout_connector_ineligibility_reason(
+                &state.conf.payouts.currency_support,
+                api_enums::Connector::from(*connector),
+                payouts.payout_type,
+                payouts.destination_currency,
+                payouts.charge_bearer.unwrap_or_default(),
+                is_network_token,
+            ) {
+                Some(reason) => {
+                    exclusions.push(format!("{connector}: {reason}"));
+                    false
+                }
+                None => true,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if exclusions.is_empty() {
+        return Ok(eligible_connectors);
+    }
+    logger::info!(excluded_connectors = ?exclusions, "connectors excluded from payout");
+    utils::when(connectors.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "No connector is eligible for the payout ({})",
+                exclusions.join(", ")
+            ),
+        }))
+    })?;
+    Ok(Some(connectors))
+}
+
+/// Returns the connectors of the merchant's enabled payout connector accounts under the profile.
+async fn get_merchant_payout_connectors(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+) -> RouterResult<Vec<enums::PayoutConnectors>> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &key_store.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?;
+    let merchant_connector_accounts = payment_helpers::filter_mca_based_on_connector_type(
+        payment_helpers::filter_mca_based_on_business_profile(
+            merchant_connector_accounts,
+            Some(profile_id.to_string()),
+        ),
+        api_enums::ConnectorType::PayoutProcessor,
+    );
+
+    Ok(get_payout_connectors_of_accounts(
+        merchant_connector_accounts
+            .iter()
+            .map(|merchant_connector_account| merchant_connector_account.connector_name.as_str()),
+    ))
+}
+
+/// Returns the payout connectors among the connectors of the connector accounts, without
+/// duplicates when the merchant has several accounts of a connector.
+fn get_payout_connectors_of_accounts<'a>(
+    connector_names: impl IntoIterator<Item = &'a str>,
+) -> Vec<enums::PayoutConnectors> {
+    let mut connectors = Vec::new();
+    for connector in connector_names
+        .into_iter()
+        .filter_map(|connector_name| enums::PayoutConnectors::from_str(connector_name).ok())
+    {
+        if !connectors.contains(&connector) {
+            connectors.push(connector);
+        }
+    }
+    connectors
+}
+
+/// Errors when the payout method can not be sent through the connector, as network tokens are
+/// only accepted by some connectors.
+pub fn validate_payout_method_for_connector(
+    connector_data: &api::ConnectorData,
+    payout_method_data: Option<&api::PayoutMethodData>,
+) -> RouterResult<()> {
+    let is_network_token = matches!(
+        payout_method_data,
+        Some(api::PayoutMethodData::NetworkToken(_))
+    );
+    let is_supported = connector_data
+        .connector_name
+        .supports_network_token_payout();
+    utils::when(is_network_token && !is_supported, || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Connector {} does not support network token payouts",
+                connector_data.connector_name
+            ),
+        }))
+    })
+}
+
+/// Errors when the connector does not support the requested bearer of the fees of the payout.
+pub fn validate_payout_charge_bearer_for_connector(
+    connector: api_enums::Connector,
+    charge_bearer: api_enums::PayoutChargeBearer,
+) -> RouterResult<()> {
+    utils::when(
+        !connector.supports_payout_charge_bearer(charge_bearer),
+        || {
+            Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Connector {connector} does not support {charge_bearer} as the charge_bearer of payouts"
+            ),
+        }))
+        },
+    )
+}
+
+/// Errors when the connector does not support payouts of the payout type in the destination
+/// currency, as per the currency support matrix of the connector, if configured.
+pub fn validate_payout_currency_for_connector(
+    currency_support: &HashMap<String, settings::PayoutCurrencySupport>,
+    connector: api_enums::Connector,
+    payout_type: Option<api_enums::PayoutType>,
+    currency: api_enums::Currency,
+) -> RouterResult<()> {
+    let (Some(support), Some(payout_type)) =
+        (currency_support.get(&connector.to_string()), payout_type)
+    else {
+        return Ok(());
+    };
+    let currencies = support.0.get(&payout_type).ok_or_else(|| {
+        report!(errors::ApiErrorResponse::NotSupported {
+            message: format!("Connector {connector} does not support {payout_type} payouts"),
+        })
+    })?;
+    utils::when(!currencies.contains(&currency), || {
+        Err(report!(errors::ApiErrorResponse::CurrencyNotSupported {
+            message: format!(
+                "Connector {connector} does not support {payout_type} payouts in {currency}"
+            ),
+        }))
+    })
+}
+
+/// Returns the priority with which the payout is sent by the connector. If the connector doesn't
+/// support the requested priority, this is the `regular` priority when `fallback_to_standard` was
+/// requested, and the payout fails otherwise.
+pub fn get_payout_priority_for_connector(
+    priority_support: &HashMap<String, settings::PayoutPrioritySupport>,
+    connector: enums::Connector,
+    priority: enums::PayoutSendPriority,
+    fallback_to_standard: bool,
+) -> RouterResult<enums::PayoutSendPriority> {
+    if is_payout_priority_supported(priority_support, connector, priority) {
+        return Ok(priority);
+    }
+    utils::when(!fallback_to_standard, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{connector} does not support {priority} payouts, set fallback_to_standard to \
+                 send the payout using the regular priority"
+            ),
+        }))
+    })?;
+    logger::info!(
+        "{connector} does not support {priority} payouts, falling back to regular priority"
+    );
+    Ok(enums::PayoutSendPriority::Regular)
+}
+
+/// Validates the priority of the payout against the connector it is being sent through,
+/// recording the priority the payout falls back to, if any.
+pub async fn validate_payout_priority_for_connector(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector_data: &api::ConnectorData,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let Some(priority) = payout_data.payouts.priority else {
+        return Ok(());
+    };
+    let effective_priority = get_payout_priority_for_connector(
+        &state.conf.payouts.priority_support,
+        connector_data.connector_name,
+        priority,
+        payout_data.payouts.fallback_to_standard.unwrap_or(false),
+    )?;
+    if effective_priority == priority {
+        return Ok(());
+    }
+
+    let updated_payouts = storage::PayoutsUpdate::PriorityUpdate {
+        priority: effective_priority,
+    };
+    payout_data.payouts = state
+        .store
+        .update_payout(
+            &payout_data.payouts,
+            updated_payouts,
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout priority")?;
+    Ok(())
+}
+
+/// Returns the settlement network the payout is sent over by the connector. If the connector
+/// doesn't support the requested network, this is the standard network of the destination when
+/// `fallback_to_standard` was requested, and the payout fails otherwise.
+pub fn get_payout_settlement_network_for_connector(
+    connector: api_enums::Connector,
+    settlement_network: api_enums::PayoutSettlementNetwork,
+    fallback_to_standard: bool,
+) -> RouterResult<api_enums::PayoutSettlementNetwork> {
+    if connector.supports_payout_settlement_network(settlement_network) {
+        return Ok(settlement_network);
+    }
+    let standard_network = settlement_network.get_standard_network();
+    utils::when(!fallback_to_standard, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{connector} does not support the {settlement_network} settlement network, set \
+                 fallback_to_standard to send the payout over {standard_network}"
+            ),
+        }))
+    })?;
+    logger::info!(
+        "{connector} does not support the {settlement_network} settlement network, falling back \
+         to {standard_network}"
+    );
+    Ok(standard_network)
+}
+
+/// Validates the settlement network of the payout against the connector it is being sent through,
+/// recording the network the payout falls back to, if any. The priority of the payout is reset
+/// to the priority of that network, so that only the rail which is actually selected is recorded.
+pub async fn validate_payout_settlement_network_for_connector(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector_data: &api::ConnectorData,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let Some(settlement_network) = payout_data.payouts.settlement_network else {
+        return Ok(());
+    };
+    let effective_network = get_payout_settlement_network_for_connector(
+        connector_data.connector_name,
+        settlement_network,
+        payout_data.payouts.fallback_to_standard.unwrap_or(false),
+    )?;
+    if effective_network == settlement_network {
+        return Ok(());
+    }
+
+    let updated_payouts = storage::PayoutsUpdate::SettlementNetworkUpdate {
+        settlement_network: effective_network,
+        priority: payout_data
+            .payouts
+            .priority
+            .map(|_| effective_network.get_priority()),
+    };
+    payout_data.payouts = state
+        .store
+        .update_payout(
+            &payout_data.payouts,
+            updated_payouts,
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout settlement network")?;
+    Ok(())
+}
+
 pub fn is_payout_terminal_state(status: api_enums::PayoutStatus) -> bool {
     !matches!(
         status,
@@ -907,9 +1542,109 @@ pub fn is_payout_terminal_state(status: api_enums::PayoutStatus) -> bool {
             | api_enums::PayoutStatus::RequiresCreation
             | api_enums::PayoutStatus::RequiresFulfillment
             | api_enums::PayoutStatus::RequiresPayoutMethodData
+            | api_enums::PayoutStatus::Scheduled
+            | api_enums::PayoutStatus::PendingApproval
+            | api_enums::PayoutStatus::PendingVerification
     )
 }
 
+/// Returns whether a status received in a payout webhook is to be applied over the current status
+/// of the payout attempt. A status which was already applied is not applied again, and a payout
+/// in a final status is never moved to another status, except a successful payout being reversed
+/// or recalled, so that webhooks delivered out of order do not regress the payout.
+pub fn should_apply_payout_webhook_status(
+    current_status: api_enums::PayoutStatus,
+    received_status: api_enums::PayoutStatus,
+) -> bool {
+    if current_status == received_status {
+        return false;
+    }
+    match current_status {
+        api_enums::PayoutStatus::Success => matches!(
+            received_status,
+            api_enums::PayoutStatus::Reversed | api_enums::PayoutStatus::Recalled
+        ),
+        api_enums::PayoutStatus::Failed
+        | api_enums::PayoutStatus::Cancelled
+        | api_enums::PayoutStatus::Expired
+        | api_enums::PayoutStatus::Reversed
+        | api_enums::PayoutStatus::Recalled
+        | api_enums::PayoutStatus::Ineligible => false,
+        api_enums::PayoutStatus::Initiated
+        | api_enums::PayoutStatus::Pending
+        | api_enums::PayoutStatus::RequiresCreation
+        | api_enums::PayoutStatus::RequiresConfirmation
+        | api_enums::PayoutStatus::RequiresPayoutMethodData
+        | api_enums::PayoutStatus::RequiresFulfillment
+        | api_enums::PayoutStatus::RequiresVendorAccountCreation
+        | api_enums::PayoutStatus::Scheduled
+        | api_enums::PayoutStatus::PendingApproval
+        | api_enums::PayoutStatus::PendingVerification => true,
+    }
+}
+
+/// Returns the key under which a payout webhook is recorded once processed. The event is
+/// identified by the id assigned to it by the connector when available, otherwise by the digest of
+/// the webhook body, which is the same when the webhook is delivered again.
+pub fn get_payout_webhook_event_key(
+    merchant_id: &str,
+    connector_name: &str,
+    connector_event_id: Option<String>,
+    body: &[u8],
+) -> RouterResult<String> {
+    let event_id = match connector_event_id {
+        Some(event_id) => event_id,
+        None => crypto::Sha256
+            .generate_digest(body)
+            .map(hex::encode)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate digest of the payout webhook body")?,
+    };
+    Ok(format!(
+        "{}_{merchant_id}_{connector_name}_{event_id}",
+        consts::PAYOUT_WEBHOOK_EVENT_KEY_PREFIX
+    ))
+}
+
+/// Claims the payout webhook event for processing, returns `false` if it was already claimed by
+/// another delivery of the same event. The key is set only if it does not exist in a single
+/// command, so that concurrent deliveries cannot both claim the event. The event is claimed when
+/// redis is unavailable.
+pub async fn claim_payout_webhook_event(state: &SessionState, event_key: &str) -> bool {
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_if_not_exists_with_expiry(
+                    event_key,
+                    "",
+                    Some(consts::PAYOUT_WEBHOOK_EVENT_TTL),
+                )
+                .await
+        }
+        Err(error) => Err(error),
+    };
+    match result {
+        Ok(redis_interface::SetnxReply::KeySet) => true,
+        Ok(redis_interface::SetnxReply::KeyNotSet) => false,
+        Err(error) => {
+            logger::error!(?error, "Unable to claim the payout webhook for processing");
+            true
+        }
+    }
+}
+
+/// Releases a claimed payout webhook event which failed to be processed, so that a delivery of
+/// the event which is retried by the connector is processed again.
+pub async fn release_payout_webhook_event(state: &SessionState, event_key: &str) {
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn.delete_key(event_key).await,
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        logger::error!(?error, "Unable to release the payout webhook");
+    }
+}
+
 pub fn is_payout_err_state(status: api_enums::PayoutStatus) -> bool {
     matches!(
         status,
@@ -923,35 +1658,706 @@ pub fn is_eligible_for_local_payout_cancellation(status: api_enums::PayoutStatus
     matches!(
         status,
         api_enums::PayoutStatus::RequiresCreation
-            | api_enums::PayoutStatus::RequiresPayoutMethodData,
+            | api_enums::PayoutStatus::RequiresPayoutMethodData
+            | api_enums::PayoutStatus::Scheduled
+            | api_enums::PayoutStatus::PendingApproval
+            | api_enums::PayoutStatus::PendingVerification,
     )
 }
 
-#[cfg(feature = "olap")]
-pub(super) async fn filter_by_constraints(
-    db: &dyn StorageInterface,
-    constraints: &api::PayoutListConstraints,
-    merchant_id: &str,
-    storage_scheme: storage::enums::MerchantStorageScheme,
-) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
-    let result = db
-        .filter_payouts_by_constraints(merchant_id, &constraints.clone().into(), storage_scheme)
-        .await?;
-    Ok(result)
+/// Returns the update applied to the payout attempt for a status received in a payout webhook.
+/// Recalls reported by webhooks carry no amount, so the whole amount of the payout is considered
+/// recalled, as is the case for recalls recorded through the API without an amount.
+pub fn get_payout_webhook_attempt_update(
+    connector_payout_id: Option<String>,
+    is_eligible: Option<bool>,
+    payout_amount: MinorUnit,
+    status: api_enums::PayoutStatus,
+) -> storage::PayoutAttemptUpdate {
+    match status {
+        api_enums::PayoutStatus::Recalled => storage::PayoutAttemptUpdate::RecallUpdate {
+            status,
+            reason: None,
+            recalled_amount: payout_amount,
+        },
+        _ => storage::PayoutAttemptUpdate::StatusUpdate {
+            connector_payout_id,
+            status,
+            error_message: None,
+            error_code: None,
+            is_eligible,
+            reason_code: get_connector_status_reason_code(status),
+            reason: None,
+        },
+    }
 }
 
-pub async fn update_payouts_and_payout_attempt(
-    payout_data: &mut PayoutData,
-    merchant_account: &domain::MerchantAccount,
-    req: &payouts::PayoutCreateRequest,
+/// Returns the update moving the attempt of a payout to `status` for a transition made by the
+/// router rather than reported by a connector.
+pub fn get_payout_status_attempt_update(
+    connector_payout_id: Option<String>,
+    status: api_enums::PayoutStatus,
+    reason_code: Option<api_enums::PayoutStatusReasonCode>,
+    reason: Option<String>,
+) -> storage::PayoutAttemptUpdate {
+    storage::PayoutAttemptUpdate::StatusUpdate {
+        connector_payout_id,
+        status,
+        error_code: None,
+        error_message: None,
+        is_eligible: None,
+        reason_code,
+        reason,
+    }
+}
+
+/// Moves the attempt of a payout and the payout to `status`.
+pub async fn update_payout_status(
     state: &SessionState,
-) -> CustomResult<(), errors::ApiErrorResponse> {
-    let payout_attempt = payout_data.payout_attempt.to_owned();
-    let status = payout_attempt.status;
-    let payout_id = payout_attempt.payout_id.clone();
-    // Verify update feasibility
-    if is_payout_terminal_state(status) || is_payout_initiated(status) {
-        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+    status: api_enums::PayoutStatus,
+    reason_code: Option<api_enums::PayoutStatusReasonCode>,
+    reason: Option<String>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let updated_payout_attempt = get_payout_status_attempt_update(
+        payout_data.payout_attempt.connector_payout_id.to_owned(),
+        status,
+        reason_code,
+        reason,
+    );
+    payout_data.payout_attempt = db
+        .update_payout_attempt(
+            &payout_data.payout_attempt,
+            updated_payout_attempt,
+            &payout_data.payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?;
+    payout_data.payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+    record_payout_time_to_fulfillment(&payout_data.payouts, &payout_data.payout_attempt);
+    Ok(())
+}
+
+/// Applies the updates of a status transition to the attempt of a payout and the payout, only if
+/// the attempt is still in `current_status`. Of concurrent requests moving a payout out of a
+/// status, only the first one applies its transition. Returns whether the payout was moved.
+pub async fn update_payout_status_if(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+    current_status: api_enums::PayoutStatus,
+    updated_payout_attempt: storage::PayoutAttemptUpdate,
+    updated_payouts: storage::PayoutsUpdate,
+) -> RouterResult<bool> {
+    let db = &*state.store;
+    let Some(payout_attempt) = db
+        .update_payout_attempt_if_status(
+            &payout_data.payout_attempt,
+            current_status,
+            updated_payout_attempt,
+            &payout_data.payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?
+    else {
+        logger::info!(
+            "Payout {} was moved out of {} concurrently",
+            payout_data.payouts.payout_id,
+            current_status
+        );
+        return Ok(false);
+    };
+    payout_data.payout_attempt = payout_attempt;
+    payout_data.payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            updated_payouts,
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+    record_payout_time_to_fulfillment(&payout_data.payouts, &payout_data.payout_attempt);
+    Ok(true)
+}
+
+/// Returns the status a payout held in `pending_verification` is moved to for the posted result of
+/// the document verification, or for its expiry when no result was posted.
+pub fn get_payout_verification_status(
+    result: Option<payouts::PayoutVerificationResult>,
+) -> api_enums::PayoutStatus {
+    match result {
+        Some(payouts::PayoutVerificationResult::Verified) => {
+            api_enums::PayoutStatus::RequiresCreation
+        }
+        Some(payouts::PayoutVerificationResult::Rejected) => api_enums::PayoutStatus::Failed,
+        None => api_enums::PayoutStatus::Expired,
+    }
+}
+
+/// Returns the update applied to the attempt of a payout held in `pending_verification`, for the
+/// posted result of the document verification, or for its expiry when no result was posted within
+/// the verification window.
+pub fn get_payout_verification_attempt_update(
+    connector_payout_id: Option<String>,
+    result: Option<payouts::PayoutVerificationResult>,
+    reason: Option<String>,
+) -> storage::PayoutAttemptUpdate {
+    let status = get_payout_verification_status(result);
+    let (error_message, reason_code, reason) = match result {
+        Some(payouts::PayoutVerificationResult::Verified) => (None, None, None),
+        Some(payouts::PayoutVerificationResult::Rejected) => (
+            Some("Recipient verification rejected"),
+            Some(api_enums::PayoutStatusReasonCode::VerificationRejected),
+            reason,
+        ),
+        None => (
+            Some("Recipient verification not received in time"),
+            Some(api_enums::PayoutStatusReasonCode::VerificationExpired),
+            None,
+        ),
+    };
+    storage::PayoutAttemptUpdate::StatusUpdate {
+        connector_payout_id,
+        status,
+        error_code: None,
+        error_message: error_message.map(str::to_string),
+        is_eligible: None,
+        reason_code,
+        reason,
+    }
+}
+
+/// Returns the reason code for a payout status reported by the connector, if the status is a
+/// terminal one which needs an explanation.
+pub fn get_connector_status_reason_code(
+    status: api_enums::PayoutStatus,
+) -> Option<api_enums::PayoutStatusReasonCode> {
+    match status {
+        api_enums::PayoutStatus::Failed => {
+            Some(api_enums::PayoutStatusReasonCode::ConnectorDeclined)
+        }
+        api_enums::PayoutStatus::Cancelled => {
+            Some(api_enums::PayoutStatusReasonCode::ConnectorCancelled)
+        }
+        api_enums::PayoutStatus::Reversed => {
+            Some(api_enums::PayoutStatusReasonCode::ConnectorReversed)
+        }
+        api_enums::PayoutStatus::Recalled => Some(api_enums::PayoutStatusReasonCode::BankRecalled),
+        api_enums::PayoutStatus::Expired => Some(api_enums::PayoutStatusReasonCode::Expired),
+        api_enums::PayoutStatus::Ineligible => {
+            Some(api_enums::PayoutStatusReasonCode::RecipientIneligible)
+        }
+        api_enums::PayoutStatus::Success
+        | api_enums::PayoutStatus::Initiated
+        | api_enums::PayoutStatus::Pending
+        | api_enums::PayoutStatus::RequiresCreation
+        | api_enums::PayoutStatus::RequiresConfirmation
+        | api_enums::PayoutStatus::RequiresPayoutMethodData
+        | api_enums::PayoutStatus::RequiresFulfillment
+        | api_enums::PayoutStatus::RequiresVendorAccountCreation
+        | api_enums::PayoutStatus::Scheduled
+        | api_enums::PayoutStatus::PendingApproval
+        | api_enums::PayoutStatus::PendingVerification => None,
+    }
+}
+
+/// Returns a masked representation of the payout destination, retaining only the last four
+/// characters of the account identifier. Identifiers of at most four characters are masked
+/// entirely.
+pub fn get_masked_payout_destination(payout_method_data: &api::PayoutMethodData) -> Option<String> {
+    let destination = match payout_method_data {
+        api::PayoutMethodData::Card(card) => card.card_number.get_card_no(),
+        api::PayoutMethodData::NetworkToken(token) => token.network_token.peek().to_owned(),
+        api::PayoutMethodData::Bank(bank) => match bank {
+            payouts::Bank::Ach(ach) => ach.bank_account_number.peek().to_owned(),
+            payouts::Bank::Bacs(bacs) => bacs.bank_account_number.peek().to_owned(),
+            payouts::Bank::Sepa(sepa) => sepa.iban.peek().to_owned(),
+            payouts::Bank::Pix(pix) => pix.bank_account_number.peek().to_owned(),
+        },
+        api::PayoutMethodData::Wallet(wallet) => match wallet {
+            payouts::Wallet::Paypal(paypal) => paypal
+                .email
+                .as_ref()
+                .map(|email| email.peek().to_owned())
+                .or_else(|| {
+                    paypal
+                        .telephone_number
+                        .as_ref()
+                        .map(|phone| phone.peek().to_owned())
+                })?,
+            payouts::Wallet::Venmo(venmo) => venmo.telephone_number.as_ref()?.peek().to_owned(),
+        },
+        api::PayoutMethodData::StoreCredit(store_credit) => {
+            store_credit.credit_account_id.clone()?
+        }
+    };
+
+    Some(mask_value(&destination, 0, 4))
+}
+
+/// Masks all the characters of the value other than the first `visible_prefix` and the last
+/// `visible_suffix` characters. Values which are not longer than the visible characters are masked
+/// entirely.
+fn mask_value(value: &str, visible_prefix: usize, visible_suffix: usize) -> String {
+    let length = value.chars().count();
+    let visible_chars = visible_prefix + visible_suffix;
+    value
+        .chars()
+        .enumerate()
+        .map(|(index, c)| {
+            let is_visible = length > visible_chars
+                && (index < visible_prefix || index >= length - visible_suffix);
+            if is_visible {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// Normalizes the bank details of the payout method into the form passed to connectors: IBANs,
+/// BICs and account numbers are stripped of whitespace and uppercased, and sort codes and routing
+/// numbers are reduced to their digits.
+pub fn normalize_payout_method_data(
+    payout_method_data: api::PayoutMethodData,
+) -> api::PayoutMethodData {
+    let normalize_identifier = |value: &Secret<String>| {
+        Secret::new(
+            value
+                .peek()
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .to_uppercase(),
+        )
+    };
+    let normalize_code = |value: &Secret<String>| {
+        Secret::new(
+            value
+                .peek()
+                .chars()
+                .filter(char::is_ascii_digit)
+                .collect::<String>(),
+        )
+    };
+    match payout_method_data {
+        api::PayoutMethodData::Bank(bank) => api::PayoutMethodData::Bank(match bank {
+            payouts::Bank::Ach(ach) => payouts::Bank::Ach(payouts::AchBankTransfer {
+                bank_account_number: normalize_identifier(&ach.bank_account_number),
+                bank_routing_number: normalize_code(&ach.bank_routing_number),
+                ..ach
+            }),
+            payouts::Bank::Bacs(bacs) => payouts::Bank::Bacs(payouts::BacsBankTransfer {
+                bank_account_number: normalize_identifier(&bacs.bank_account_number),
+                bank_sort_code: normalize_code(&bacs.bank_sort_code),
+                ..bacs
+            }),
+            payouts::Bank::Sepa(sepa) => payouts::Bank::Sepa(payouts::SepaBankTransfer {
+                iban: normalize_identifier(&sepa.iban),
+                bic: sepa.bic.as_ref().map(normalize_identifier),
+                ..sepa
+            }),
+            payouts::Bank::Pix(pix) => payouts::Bank::Pix(payouts::PixBankTransfer {
+                bank_account_number: normalize_identifier(&pix.bank_account_number),
+                ..pix
+            }),
+        }),
+        payout_method_data => payout_method_data,
+    }
+}
+
+/// Returns the normalized bank details of a bank payout method, with the account identifiers
+/// masked.
+pub fn get_masked_payout_bank_details(
+    payout_method_data: &api::PayoutMethodData,
+) -> Option<payouts::PayoutBankDetails> {
+    let api::PayoutMethodData::Bank(bank) =
+        normalize_payout_method_data(payout_method_data.clone())
+    else {
+        return None;
+    };
+    let mask_account_number = |value: &Secret<String>| Some(mask_value(value.peek(), 0, 4));
+    let bank_details = match bank {
+        payouts::Bank::Ach(ach) => payouts::PayoutBankDetails {
+            bank_name: ach.bank_name,
+            bank_country_code: ach.bank_country_code,
+            bank_account_number: mask_account_number(&ach.bank_account_number),
+            bank_routing_number: Some(ach.bank_routing_number.expose()),
+            ..Default::default()
+        },
+        payouts::Bank::Bacs(bacs) => payouts::PayoutBankDetails {
+            bank_name: bacs.bank_name,
+            bank_country_code: bacs.bank_country_code,
+            bank_account_number: mask_account_number(&bacs.bank_account_number),
+            bank_sort_code: Some(bacs.bank_sort_code.expose()),
+            ..Default::default()
+        },
+        payouts::Bank::Sepa(sepa) => payouts::PayoutBankDetails {
+            bank_name: sepa.bank_name,
+            bank_country_code: sepa.bank_country_code,
+            iban: Some(mask_value(sepa.iban.peek(), 4, 4)),
+            bic: sepa.bic.map(ExposeInterface::expose),
+            ..Default::default()
+        },
+        payouts::Bank::Pix(pix) => payouts::PayoutBankDetails {
+            bank_name: pix.bank_name,
+            bank_account_number: mask_account_number(&pix.bank_account_number),
+            ..Default::default()
+        },
+    };
+    Some(bank_details)
+}
+
+/// Computes a stable fingerprint of the destination of a payout method, used for detecting
+/// multiple payouts being made to the same destination.
+///
+/// The destination is hashed with HMAC-SHA256 keyed with the merchant's fingerprint secret, so the
+/// destination can not be derived from the fingerprint and fingerprints can not be correlated
+/// across merchants.
+pub async fn get_payout_method_fingerprint(
+    state: &SessionState,
+    merchant_id: &str,
+    payout_method_data: &api::PayoutMethodData,
+) -> RouterResult<Option<String>> {
+    let Some(destination) = get_payout_destination_identifier(payout_method_data) else {
+        return Ok(None);
+    };
+    let fingerprint_secret =
+        blocklist_utils::get_merchant_fingerprint_secret(state, merchant_id).await?;
+    let fingerprint = crypto::HmacSha256
+        .sign_message(fingerprint_secret.as_bytes(), destination.peek().as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate payout method fingerprint")?;
+
+    Ok(Some(hex::encode(fingerprint)))
+}
+
+/// Returns the details which uniquely identify the destination of a payout method, normalized so
+/// that the same destination always yields the same identifier.
+fn get_payout_destination_identifier(
+    payout_method_data: &api::PayoutMethodData,
+) -> Option<Secret<String>> {
+    let normalize = |value: &str| {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase()
+    };
+    let identifier = match payout_method_data {
+        api::PayoutMethodData::Card(card) => format!("card_{}", card.card_number.get_card_no()),
+        api::PayoutMethodData::NetworkToken(token) => {
+            format!("network_token_{}", normalize(token.network_token.peek()))
+        }
+        api::PayoutMethodData::Bank(bank) => match bank {
+            payouts::Bank::Ach(ach) => format!(
+                "ach_{}_{}",
+                normalize(ach.bank_routing_number.peek()),
+                normalize(ach.bank_account_number.peek())
+            ),
+            payouts::Bank::Bacs(bacs) => format!(
+                "bacs_{}_{}",
+                normalize(bacs.bank_sort_code.peek()),
+                normalize(bacs.bank_account_number.peek())
+            ),
+            payouts::Bank::Sepa(sepa) => format!("sepa_{}", normalize(sepa.iban.peek())),
+            payouts::Bank::Pix(pix) => format!("pix_{}", normalize(pix.pix_key.peek())),
+        },
+        api::PayoutMethodData::Wallet(wallet) => match wallet {
+            payouts::Wallet::Paypal(paypal) => paypal
+                .paypal_id
+                .as_ref()
+                .map(|paypal_id| format!("paypal_id_{}", paypal_id.peek()))
+                .or_else(|| {
+                    paypal
+                        .email
+                        .as_ref()
+                        .map(|email| format!("paypal_email_{}", normalize(email.peek())))
+                })
+                .or_else(|| {
+                    paypal
+                        .telephone_number
+                        .as_ref()
+                        .map(|phone| format!("paypal_phone_{}", normalize(phone.peek())))
+                })?,
+            payouts::Wallet::Venmo(venmo) => format!(
+                "venmo_phone_{}",
+                normalize(venmo.telephone_number.as_ref()?.peek())
+            ),
+        },
+        api::PayoutMethodData::StoreCredit(store_credit) => format!(
+            "store_credit_{}_{}",
+            store_credit.customer_id.get_string_repr(),
+            store_credit
+                .credit_account_id
+                .as_deref()
+                .unwrap_or_default()
+        ),
+    };
+
+    Some(Secret::new(identifier))
+}
+
+pub async fn config_should_require_payout_id(db: &dyn StorageInterface, merchant_id: &str) -> bool {
+    let key = format!("should_require_payout_id_{}", merchant_id);
+    let config = db
+        .find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(err) => {
+            logger::error!("{err}");
+            false
+        }
+    }
+}
+
+/// Returns whether the merchant has opted into unique merchant reference IDs across its payouts.
+pub async fn config_should_enforce_unique_merchant_reference_id(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> bool {
+    let key = format!("should_enforce_unique_merchant_reference_id_{merchant_id}");
+    let config = db
+        .find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(err) => {
+            logger::error!("{err}");
+            false
+        }
+    }
+}
+
+/// Returns the error for a payout insert which ran into a unique constraint.
+///
+/// For merchants enforcing unique merchant reference IDs, the constraint may be on the merchant
+/// reference ID instead of the payout ID, when a concurrent create got past the validator check.
+pub async fn get_payout_duplicate_error(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payout_id: &str,
+    unique_merchant_reference_id: Option<&str>,
+) -> errors::ApiErrorResponse {
+    let existing_payout = match unique_merchant_reference_id {
+        Some(merchant_reference_id) => db
+            .find_optional_payout_by_merchant_id_merchant_reference_id(
+                &merchant_account.merchant_id,
+                merchant_reference_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .map_err(|err| logger::error!(merchant_reference_id_lookup_error=?err))
+            .ok()
+            .flatten(),
+        None => None,
+    };
+    match existing_payout {
+        Some(existing_payout) if existing_payout.payout_id != payout_id => {
+            errors::ApiErrorResponse::GenericDuplicateError {
+                message: format!(
+                    "merchant_reference_id {} is already used by payout {}",
+                    existing_payout.merchant_reference_id.unwrap_or_default(),
+                    existing_payout.payout_id
+                ),
+            }
+        }
+        _ => errors::ApiErrorResponse::DuplicatePayout {
+            payout_id: payout_id.to_owned(),
+        },
+    }
+}
+
+/// Returns the amounts above which payouts of the merchant need to be approved before they are
+/// disbursed, if the merchant has configured them.
+///
+/// The thresholds are configured per currency, in the lowest denomination of the currency, as a
+/// JSON object such as `{"USD": 100000, "EUR": 90000}`.
+pub async fn get_payout_approval_thresholds(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<HashMap<enums::Currency, MinorUnit>>> {
+    let key = format!("payout_approval_threshold_{}", merchant_id);
+    match db.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct::<HashMap<enums::Currency, MinorUnit>>("PayoutApprovalThresholds")
+            .map(Some)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse payout approval threshold config"),
+        Err(err) if err.current_context().is_db_not_found() => Ok(None),
+        Err(err) => Err(err)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payout approval threshold config"),
+    }
+}
+
+/// Fetches the destination countries to which payouts are blocked for the merchant.
+///
+/// Merchants can override the list in `[payouts]` settings with a JSON array of country codes
+/// stored in the `payout_denied_countries_{merchant_id}` config.
+pub async fn get_payout_denied_countries(
+    state: &SessionState,
+    merchant_id: &str,
+) -> RouterResult<HashSet<enums::CountryAlpha2>> {
+    let key = format!("payout_denied_countries_{}", merchant_id);
+    match state.store.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("PayoutDeniedCountries")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse payout denied countries config"),
+        Err(err) if err.current_context().is_db_not_found() => {
+            Ok(state.conf.payouts.denied_countries.clone())
+        }
+        Err(err) => Err(err)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payout denied countries config"),
+    }
+}
+
+/// Fetches the keys which the metadata of every payout of the merchant must carry, configured as a
+/// JSON array of keys in the `payout_required_metadata_keys_{merchant_id}` config.
+pub async fn get_payout_required_metadata_keys(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Vec<String>> {
+    let key = format!("payout_required_metadata_keys_{}", merchant_id);
+    match db.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("PayoutRequiredMetadataKeys")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse payout required metadata keys config"),
+        Err(err) if err.current_context().is_db_not_found() => Ok(Vec::new()),
+        Err(err) => Err(err)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payout required metadata keys config"),
+    }
+}
+
+/// Checks whether the payout is above the merchant's approval threshold and is yet to be approved.
+pub async fn is_payout_approval_required(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+) -> RouterResult<bool> {
+    if payout_data.payouts.approved_at.is_some() {
+        return Ok(false);
+    }
+    let thresholds = get_payout_approval_thresholds(db, &payout_data.payouts.merchant_id).await?;
+    Ok(thresholds.is_some_and(|thresholds| {
+        is_above_payout_approval_threshold(
+            &thresholds,
+            payout_data.payouts.amount,
+            payout_data.payouts.destination_currency,
+        )
+    }))
+}
+
+/// Amounts can only be compared with the threshold of their own currency. Once the merchant has
+/// configured thresholds, payouts in currencies without a threshold always need an approval.
+pub fn is_above_payout_approval_threshold(
+    thresholds: &HashMap<enums::Currency, MinorUnit>,
+    amount: MinorUnit,
+    currency: enums::Currency,
+) -> bool {
+    thresholds
+        .get(&currency)
+        .map_or(true, |threshold| amount > *threshold)
+}
+
+/// Whether the update changed the amount of an approved payout, which then needs to be approved
+/// again.
+pub fn is_payout_approval_revoked_by_update(
+    payouts: &storage::Payouts,
+    updated_payouts: &storage::Payouts,
+) -> bool {
+    payouts.approved_at.is_some()
+        && is_payout_amount_changed(
+            (payouts.amount, payouts.destination_currency),
+            (updated_payouts.amount, updated_payouts.destination_currency),
+        )
+}
+
+fn is_payout_amount_changed(
+    (amount, currency): (MinorUnit, enums::Currency),
+    (updated_amount, updated_currency): (MinorUnit, enums::Currency),
+) -> bool {
+    amount != updated_amount || currency != updated_currency
+}
+
+/// Payouts are to be approved by a user or API key other than the one which created them.
+pub fn validate_payout_approver(payouts: &storage::Payouts, approved_by: &str) -> RouterResult<()> {
+    validate_payout_approver_is_not_creator(
+        &payouts.payout_id,
+        payouts.created_by.as_deref(),
+        approved_by,
+    )
+}
+
+fn validate_payout_approver_is_not_creator(
+    payout_id: &str,
+    created_by: Option<&str>,
+    approved_by: &str,
+) -> RouterResult<()> {
+    utils::when(created_by == Some(approved_by), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Payout {payout_id} can not be approved by its creator"),
+        }))
+    })
+}
+
+/// Checks whether the payout requires the documents of the recipient to be verified and is yet to
+/// be verified.
+pub fn is_payout_verification_required(payouts: &storage::Payouts) -> bool {
+    payouts.requires_verification == Some(true) && payouts.verified_at.is_none()
+}
+
+#[cfg(feature = "olap")]
+pub(super) async fn filter_by_constraints(
+    db: &dyn StorageInterface,
+    constraints: &api::PayoutListConstraints,
+    merchant_id: &str,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
+    let result = db
+        .filter_payouts_by_constraints(merchant_id, &constraints.clone().into(), storage_scheme)
+        .await?;
+    Ok(result)
+}
+
+pub async fn update_payouts_and_payout_attempt(
+    payout_data: &mut PayoutData,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &payouts::PayoutCreateRequest,
+    state: &SessionState,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let payout_attempt = payout_data.payout_attempt.to_owned();
+    let status = payout_attempt.status;
+    let payout_id = payout_attempt.payout_id.clone();
+    // Verify update feasibility
+    if is_payout_terminal_state(status) || is_payout_initiated(status) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
             message: format!(
                 "Payout {} cannot be updated for status {}",
                 payout_id, status
@@ -961,14 +2367,86 @@ pub async fn update_payouts_and_payout_attempt(
 
     // Update DB with new data
     let payouts = payout_data.payouts.to_owned();
-    let amount = MinorUnit::from(req.amount.unwrap_or(payouts.amount.into()));
+    let destination_currency = req
+        .currency
+        .to_owned()
+        .unwrap_or(payouts.destination_currency);
+    // The amount is converted again only when the update changes it or either of its currencies
+    let (amount, source_currency, currency_conversion, applied_currency_conversion) =
+        if req.amount.is_some()
+            || req.currency.is_some()
+            || req.source_currency.is_some()
+            || req.forex_quote_id.is_some()
+        {
+            let currency_conversion = get_payout_currency_conversion(&payouts);
+            let source_amount = req.amount.map(MinorUnit::from).unwrap_or(
+                currency_conversion
+                    .as_ref()
+                    .map_or(payouts.amount, |conversion| conversion.source_amount),
+            );
+            // The source currency of a payout which was not converted follows its currency
+            let source_currency = req
+                .source_currency
+                .unwrap_or(if currency_conversion.is_some() {
+                    payouts.source_currency
+                } else {
+                    destination_currency
+                });
+            let rounding = req.conversion_rounding.unwrap_or(
+                currency_conversion
+                    .as_ref()
+                    .map(|conversion| conversion.rounding)
+                    .unwrap_or_default(),
+            );
+            let (amount, currency_conversion) = convert_payout_amount(
+                state,
+                &payouts.merchant_id,
+                source_amount,
+                source_currency,
+                destination_currency,
+                req.forex_quote_id.as_deref(),
+                rounding,
+            )
+            .await?;
+            (
+                amount,
+                source_currency,
+                encode_payout_currency_conversion(currency_conversion.as_ref())?,
+                currency_conversion,
+            )
+        } else if let Some((rounding, currency_conversion)) = req
+            .conversion_rounding
+            .zip(get_payout_currency_conversion(&payouts))
+        {
+            // Changing only the rounding keeps the rate the amount was converted at
+            let (amount, residual_amount) = get_payout_converted_minor_amount(
+                &currency_conversion.converted_amount,
+                destination_currency,
+                rounding,
+            )?;
+            let currency_conversion = PayoutCurrencyConversion {
+                rounding,
+                residual_amount: Some(residual_amount.normalize().to_string()),
+                ..currency_conversion
+            };
+            (
+                amount,
+                payouts.source_currency,
+                encode_payout_currency_conversion(Some(&currency_conversion))?,
+                Some(currency_conversion),
+            )
+        } else {
+            (
+                payouts.amount,
+                payouts.source_currency,
+                payouts.currency_conversion.clone(),
+                None,
+            )
+        };
     let updated_payouts = storage::PayoutsUpdate::Update {
         amount,
-        destination_currency: req
-            .currency
-            .to_owned()
-            .unwrap_or(payouts.destination_currency),
-        source_currency: req.currency.to_owned().unwrap_or(payouts.source_currency),
+        destination_currency,
+        source_currency,
         description: req
             .description
             .to_owned()
@@ -981,7 +2459,11 @@ pub async fn update_payouts_and_payout_attempt(
             .to_owned()
             .clone()
             .or(payouts.return_url.clone()),
-        entity_type: req.entity_type.to_owned().unwrap_or(payouts.entity_type),
+        entity_type: req
+            .recipient
+            .as_ref()
+            .and_then(|recipient| recipient.entity_type)
+            .unwrap_or(payouts.entity_type),
         metadata: req.metadata.clone().or(payouts.metadata.clone()),
         status: Some(status),
         profile_id: Some(payout_attempt.profile_id.clone()),
@@ -990,6 +2472,15 @@ pub async fn update_payouts_and_payout_attempt(
             .payout_type
             .to_owned()
             .or(payouts.payout_type.to_owned()),
+        fallback_to_standard: req.fallback_to_standard,
+        purpose_code: req.purpose_code.clone(),
+        originator: encode_payout_originator(req.originator.as_ref())?,
+        backup_destinations: encrypt_payout_backup_destinations(
+            key_store,
+            req.payout_destinations.as_ref(),
+        )
+        .await?,
+        currency_conversion,
     };
     let db = &*state.store;
     payout_data.payouts = db
@@ -1002,6 +2493,43 @@ pub async fn update_payouts_and_payout_attempt(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error updating payouts")?;
+    if let Some(currency_conversion) = applied_currency_conversion.as_ref() {
+        insert_payout_currency_conversion_audit_log(
+            state,
+            &payout_data.payouts,
+            currency_conversion,
+        )
+        .await?;
+    }
+    // An approval only holds for the amount which was approved
+    if is_payout_approval_revoked_by_update(&payouts, &payout_data.payouts) {
+        payout_data.payouts = db
+            .update_payout(
+                &payout_data.payouts,
+                storage::PayoutsUpdate::ApprovalRevokeUpdate,
+                &payout_attempt,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error revoking the approval of payouts")?;
+    }
+    if req.routing.is_some() || req.connector.is_some() {
+        let updated_payouts = storage::PayoutsUpdate::RoutingUpdate {
+            routing: req.routing.clone(),
+            eligible_connectors: encode_payout_eligible_connectors(req.connector.as_ref())?,
+        };
+        payout_data.payouts = db
+            .update_payout(
+                &payout_data.payouts,
+                updated_payouts,
+                &payout_attempt,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating the routing of payouts")?;
+    }
     let updated_business_country =
         payout_attempt
             .business_country
@@ -1040,3 +2568,1873 @@ pub async fn update_payouts_and_payout_attempt(
         }
     }
 }
+
+pub fn encode_payout_eligible_connectors(
+    connectors: Option<&Vec<api_enums::PayoutConnectors>>,
+) -> RouterResult<Option<serde_json::Value>> {
+    connectors
+        .map(|connectors| connectors.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the connectors of the payout")
+}
+
+pub fn encode_payout_originator(
+    originator: Option<&payouts::PayoutOriginator>,
+) -> RouterResult<Option<pii::SecretSerdeValue>> {
+    originator
+        .map(|originator| originator.encode_to_value().map(Secret::new))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the originator of the payout")
+}
+
+/// Returns the routing algorithm and the connectors requested for a payout when it was created or
+/// last updated. These are persisted so that disbursements deferred to later, such as scheduled
+/// payouts and payouts held for an approval or a verification, are routed as requested.
+pub fn get_payout_routing(
+    payouts: &storage::Payouts,
+) -> RouterResult<(
+    Option<serde_json::Value>,
+    Option<Vec<api_enums::PayoutConnectors>>,
+)> {
+    let eligible_connectors = payouts
+        .eligible_connectors
+        .clone()
+        .map(|connectors| connectors.parse_value("Vec<PayoutConnectors>"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connectors of the payout")?;
+    Ok((payouts.routing.clone(), eligible_connectors))
+}
+
+/// Whether the scheduled disbursement of a payout in the given status is to be made. A payout
+/// whose disbursement was started, but failed before reaching the connector, is left in
+/// `requires_creation` and is disbursed again when the task is retried.
+pub fn should_disburse_scheduled_payout(status: api_enums::PayoutStatus) -> bool {
+    matches!(
+        status,
+        api_enums::PayoutStatus::Scheduled | api_enums::PayoutStatus::RequiresCreation
+    )
+}
+
+/// Variables which can be used in a payout `description`, in the form `{variable}`.
+pub const PAYOUT_DESCRIPTION_TEMPLATE_VARIABLES: [&str; 3] = ["date", "customer_id", "sequence"];
+
+/// Renders a payout description template, replacing every `{variable}` with the value returned by
+/// `resolve_variable`. Descriptions without a `{` are returned unchanged.
+///
+/// Fails with a human readable message on an unterminated variable, or when `resolve_variable`
+/// does not know the variable.
+pub fn render_payout_description(
+    description: &str,
+    mut resolve_variable: impl FnMut(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut rendered = String::with_capacity(description.len());
+    let mut remaining = description;
+    while let Some((literal, variable_and_rest)) = remaining.split_once('{') {
+        rendered.push_str(literal);
+        let (variable, rest) = variable_and_rest.split_once('}').ok_or_else(|| {
+            format!("unterminated template variable in description at \"{{{variable_and_rest}\"")
+        })?;
+        let value = resolve_variable(variable).ok_or_else(|| {
+            format!(
+                "unknown template variable {{{variable}}} in description, supported: {}",
+                PAYOUT_DESCRIPTION_TEMPLATE_VARIABLES
+                    .iter()
+                    .map(|variable| format!("{{{variable}}}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+        rendered.push_str(&value);
+        remaining = rest;
+    }
+    rendered.push_str(remaining);
+    Ok(rendered)
+}
+
+/// Resolves the template variables in the description of a payout which is about to be disbursed
+/// and persists the rendered description.
+///
+/// `{date}` is the current UTC date, `{customer_id}` the payout's customer and `{sequence}` the
+/// position of this payout among all the payouts created for the customer.
+pub async fn resolve_payout_description(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let payouts = &payout_data.payouts;
+    let Some(description) = payouts.description.as_ref().filter(|d| d.contains('{')) else {
+        return Ok(());
+    };
+
+    let sequence = if description.contains("{sequence}") {
+        let count = state
+            .store
+            .get_payout_count_by_merchant_id_customer_id(
+                &payouts.merchant_id,
+                &payouts.customer_id,
+                payouts.created_at,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get the count of payouts for customer")?;
+        Some(count.to_string())
+    } else {
+        None
+    };
+    let date = common_utils::date_time::now().date().to_string();
+
+    let rendered = render_payout_description(description, |variable| match variable {
+        "date" => Some(date.clone()),
+        "customer_id" => Some(payouts.customer_id.get_string_repr().to_string()),
+        "sequence" => sequence.clone(),
+        _ => None,
+    })
+    .map_err(|message| {
+        report!(errors::ApiErrorResponse::InternalServerError).attach_printable(message)
+    })?;
+
+    let updated_payouts = storage::PayoutsUpdate::DescriptionUpdate {
+        description: rendered,
+    };
+    payout_data.payouts = state
+        .store
+        .update_payout(
+            &payout_data.payouts,
+            updated_payouts,
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout description")?;
+    Ok(())
+}
+
+/// Parses the IANA time zone name in which the timestamps of a payout response are requested.
+pub fn get_payout_response_timezone(timezone: Option<&str>) -> RouterResult<Option<chrono_tz::Tz>> {
+    timezone
+        .map(|timezone| {
+            timezone.parse::<chrono_tz::Tz>().map_err(|_| {
+                report!(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "timezone".to_string(),
+                    expected_format: "IANA time zone name, e.g. Asia/Kolkata".to_string(),
+                })
+            })
+        })
+        .transpose()
+}
+
+/// Converts the timestamps in a payout response to the given time zone. These are `created`,
+/// `exchange_rate_timestamp`, `approved_at`, `verified_at` and `estimated_arrival`. The
+/// `value_date` and `processing_date` are calendar dates without a time, and are left as they are.
+pub fn convert_payout_response_timezone(
+    mut response: api::PayoutCreateResponse,
+    timezone: Option<chrono_tz::Tz>,
+) -> api::PayoutCreateResponse {
+    if let Some(timezone) = timezone {
+        for timestamp in [
+            &mut response.created,
+            &mut response.exchange_rate_timestamp,
+            &mut response.approved_at,
+            &mut response.verified_at,
+            &mut response.estimated_arrival,
+        ] {
+            *timestamp = timestamp.map(|date_time| convert_to_timezone(date_time, timezone));
+        }
+    }
+    response
+}
+
+fn convert_to_timezone(
+    date_time: time::OffsetDateTime,
+    timezone: chrono_tz::Tz,
+) -> time::OffsetDateTime {
+    use chrono::{Offset, TimeZone};
+
+    // The offset of a time zone varies with daylight saving time, so it is resolved for the
+    // instant being converted
+    let offset = chrono::DateTime::from_timestamp(date_time.unix_timestamp(), 0)
+        .map(|utc| {
+            timezone
+                .offset_from_utc_datetime(&utc.naive_utc())
+                .fix()
+                .local_minus_utc()
+        })
+        .and_then(|offset_seconds| time::UtcOffset::from_whole_seconds(offset_seconds).ok())
+        .unwrap_or(time::UtcOffset::UTC);
+    date_time.to_offset(offset)
+}
+
+/// Parses a payout request body. Unknown fields are rejected, unless `ignore_unknown_fields` is
+/// set, in which case they are logged and dropped from the body before parsing it.
+pub fn parse_payout_create_request(
+    mut payload: serde_json::Value,
+    ignore_unknown_fields: bool,
+) -> Result<api::PayoutCreateRequest, serde_json::Error> {
+    loop {
+        let error = match serde_path_to_error::deserialize(&payload) {
+            Ok(request) => return Ok(request),
+            Err(error) => error,
+        };
+        let unknown_field = ignore_unknown_fields
+            .then(|| find_unknown_field(&payload))
+            .flatten();
+        match unknown_field {
+            Some((field, path)) if remove_field_at_path(&mut payload, &path, &field) => {
+                logger::warn!(
+                    unknown_field = %field,
+                    path = %path,
+                    "Ignoring unknown field in payout request"
+                )
+            }
+            _ => return Err(error.into_inner()),
+        }
+    }
+}
+
+/// Returns the name and path of an unknown field in the payout request body, if any.
+///
+/// `serde_json` only reports the kind of a deserialization error through its message, so the body
+/// is deserialized once more through `serde_value`, whose error type carries the kind.
+fn find_unknown_field(payload: &serde_json::Value) -> Option<(String, serde_path_to_error::Path)> {
+    let value = serde_value::to_value(payload).ok()?;
+    let error = serde_path_to_error::deserialize::<_, api::PayoutCreateRequest>(value).err()?;
+    match error.inner() {
+        serde_value::DeserializerError::UnknownField(field, _) => {
+            Some((field.clone(), error.path().clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Removes the field from the object at the path, or from its parent object, since the path of an
+/// unknown field may or may not include the field itself.
+fn remove_field_at_path(
+    payload: &mut serde_json::Value,
+    path: &serde_path_to_error::Path,
+    field: &str,
+) -> bool {
+    let segments = path.iter().collect::<Vec<_>>();
+    let parent_segments = segments.split_last().map(|(_, parent)| parent);
+    [Some(segments.as_slice()), parent_segments]
+        .into_iter()
+        .flatten()
+        .any(|segments| {
+            segments
+                .iter()
+                .try_fold(&mut *payload, |value, segment| match segment {
+                    serde_path_to_error::Segment::Map { key }
+                    | serde_path_to_error::Segment::Enum { variant: key } => {
+                        value.get_mut(key.as_str())
+                    }
+                    serde_path_to_error::Segment::Seq { index } => value.get_mut(*index),
+                    serde_path_to_error::Segment::Unknown => None,
+                })
+                .and_then(serde_json::Value::as_object_mut)
+                .and_then(|object| object.remove(field))
+                .is_some()
+        })
+}
+
+/// Returns the time taken by the payout to reach a terminal state since its creation in seconds,
+/// or `None` if it is yet to reach one.
+pub fn get_payout_time_to_fulfillment(payouts: &storage::Payouts) -> Option<i64> {
+    is_payout_terminal_state(payouts.status)
+        .then(|| (payouts.last_modified_at - payouts.created_at).whole_seconds())
+}
+
+/// Estimates the time by which the payout arrives at the recipient, as per the arrival estimates
+/// configured for the connector of the payout and its payout type. Payouts sent over an instant
+/// settlement network are estimated as instant payouts.
+pub fn get_payout_estimated_arrival(
+    state: &SessionState,
+    payout_data: &PayoutData,
+    scheduled_at: Option<PrimitiveDateTime>,
+) -> Option<PrimitiveDateTime> {
+    let payouts = &payout_data.payouts;
+    let payout_attempt = &payout_data.payout_attempt;
+    if is_payout_err_state(payouts.status) {
+        return None;
+    }
+    let payout_type = payouts.payout_type?;
+    let estimate = state
+        .conf
+        .payouts
+        .arrival_estimates
+        .get(payout_attempt.connector.as_ref()?)?
+        .get(&payout_type)?;
+    let (cutoff, holidays) = get_payout_cutoff_and_holidays(state, payouts, payout_type);
+
+    estimate_payout_arrival(
+        estimate,
+        cutoff,
+        holidays,
+        payout_type,
+        get_payout_rail_priority(payouts.priority, payouts.settlement_network),
+        get_payout_submitted_at(payout_attempt.created_at, scheduled_at),
+    )
+}
+
+/// Returns the time at which the payout was scheduled to be submitted, from its scheduled payout
+/// task. Only confirmed payouts can be scheduled, and a failure to read the task is not fatal as
+/// the time is used only for estimates.
+pub async fn get_payout_scheduled_at(
+    state: &SessionState,
+    payout_data: &PayoutData,
+) -> Option<PrimitiveDateTime> {
+    if payout_data.payouts.confirm != Some(true) {
+        return None;
+    }
+    let process_tracker_id = super::get_scheduled_payout_process_tracker_id(payout_data);
+    match state.store.find_process_by_id(&process_tracker_id).await {
+        Ok(process) => process.and_then(|process| process.schedule_time),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the scheduled payout task");
+            None
+        }
+    }
+}
+
+/// Scheduled payouts are submitted at their scheduled time, and the others when the attempt is
+/// created
+fn get_payout_submitted_at(
+    attempt_created_at: PrimitiveDateTime,
+    scheduled_at: Option<PrimitiveDateTime>,
+) -> PrimitiveDateTime {
+    scheduled_at.map_or(attempt_created_at, |scheduled_at| {
+        scheduled_at.max(attempt_created_at)
+    })
+}
+
+/// Returns the priority corresponding to the rail the payout is sent over. The settlement network
+/// selects the rail when no priority is passed, and both are consistent otherwise.
+pub fn get_payout_rail_priority(
+    priority: Option<api_enums::PayoutSendPriority>,
+    settlement_network: Option<api_enums::PayoutSettlementNetwork>,
+) -> Option<api_enums::PayoutSendPriority> {
+    priority.or(settlement_network.map(api_enums::PayoutSettlementNetwork::get_priority))
+}
+
+/// Returns the date on which the payout is processed, as per the cutoff time configured for its
+/// currency and payout type.
+pub fn get_payout_processing_date(
+    state: &SessionState,
+    payout_data: &PayoutData,
+    scheduled_at: Option<PrimitiveDateTime>,
+) -> Option<time::Date> {
+    let payouts = &payout_data.payouts;
+    let payout_type = payouts.payout_type?;
+    let (cutoff, holidays) = get_payout_cutoff_and_holidays(state, payouts, payout_type);
+
+    get_processing_date(
+        cutoff,
+        holidays,
+        get_payout_submitted_at(payout_data.payout_attempt.created_at, scheduled_at),
+    )
+}
+
+fn get_payout_cutoff_and_holidays<'a>(
+    state: &'a SessionState,
+    payouts: &storage::Payouts,
+    payout_type: api_enums::PayoutType,
+) -> (
+    Option<&'a settings::PayoutCutoffTime>,
+    Option<&'a HashSet<time::Date>>,
+) {
+    let payouts_conf = &state.conf.payouts;
+    let cutoff = payouts_conf
+        .cutoff_times
+        .get(&payouts.destination_currency)
+        .and_then(|cutoff_times| cutoff_times.get(&payout_type));
+    let holidays = payouts_conf
+        .bank_holidays
+        .get(&payouts.destination_currency)
+        .map(|holidays| &holidays.dates);
+    (cutoff, holidays)
+}
+
+fn is_business_day(date: time::Date, holidays: Option<&HashSet<time::Date>>) -> bool {
+    !matches!(
+        date.weekday(),
+        time::Weekday::Saturday | time::Weekday::Sunday
+    ) && !holidays.is_some_and(|holidays| holidays.contains(&date))
+}
+
+fn next_business_day(
+    mut date: time::Date,
+    holidays: Option<&HashSet<time::Date>>,
+) -> Option<time::Date> {
+    loop {
+        date = date.next_day()?;
+        if is_business_day(date, holidays) {
+            break Some(date);
+        }
+    }
+}
+
+/// Payouts submitted after the cutoff time or on a non business day are processed on the next
+/// business day, and immediately when there is no cutoff time.
+fn get_processing_date(
+    cutoff: Option<&settings::PayoutCutoffTime>,
+    holidays: Option<&HashSet<time::Date>>,
+    submitted_at: PrimitiveDateTime,
+) -> Option<time::Date> {
+    let date = submitted_at.date();
+    let Some(cutoff) = cutoff else {
+        return Some(date);
+    };
+    let is_after_cutoff =
+        (submitted_at.hour(), submitted_at.minute()) >= (cutoff.hour, cutoff.minute);
+    if is_after_cutoff || !is_business_day(date, holidays) {
+        next_business_day(date, holidays)
+    } else {
+        Some(date)
+    }
+}
+
+/// The cutoff time of the currency takes precedence over the cutoff hour of the connector. The
+/// estimate is never earlier than the submission of the payout, which is when payouts taking no
+/// days arrive.
+fn estimate_payout_arrival(
+    estimate: &settings::PayoutArrivalEstimate,
+    cutoff: Option<&settings::PayoutCutoffTime>,
+    holidays: Option<&HashSet<time::Date>>,
+    payout_type: api_enums::PayoutType,
+    priority: Option<api_enums::PayoutSendPriority>,
+    submitted_at: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    if priority == Some(api_enums::PayoutSendPriority::Instant) {
+        return estimate
+            .instant_minutes
+            .map(|minutes| submitted_at + time::Duration::minutes(minutes.into()));
+    }
+    if payout_type != api_enums::PayoutType::Bank {
+        return submitted_at.checked_add(time::Duration::days(estimate.days.into()));
+    }
+
+    let connector_cutoff = estimate
+        .cutoff_hour
+        .map(|hour| settings::PayoutCutoffTime { hour, minute: 0 });
+    let mut date =
+        get_processing_date(cutoff.or(connector_cutoff.as_ref()), holidays, submitted_at)?;
+    // Bank payouts processed immediately on a non business day reach the bank on the next one
+    if !is_business_day(date, holidays) {
+        date = next_business_day(date, holidays)?;
+    }
+    for _ in 0..estimate.days {
+        date = next_business_day(date, holidays)?;
+    }
+    Some(date.midnight().max(submitted_at))
+}
+
+/// Returns the value date of the payout, unless the connector of the payout does not support value
+/// dates. The requested value date is returned for payouts which are yet to be routed.
+pub fn get_effective_payout_value_date(
+    payouts: &storage::Payouts,
+    payout_attempt: &storage::PayoutAttempt,
+) -> Option<time::Date> {
+    payouts.value_date.filter(|_| {
+        payout_attempt
+            .connector
+            .as_deref()
+            .map_or(true, |connector| {
+                api_enums::Connector::from_str(connector)
+                    .is_ok_and(|connector| connector.supports_payout_value_date())
+            })
+    })
+}
+
+/// Returns the statement descriptor to be stored for a payout. The merchant name, transliterated to
+/// ASCII and stripped of the characters not allowed in descriptors, is used when no descriptor is
+/// passed, while an empty descriptor means the payout has no descriptor.
+pub fn get_payout_statement_descriptor(
+    statement_descriptor: Option<&str>,
+    merchant_name: Option<&str>,
+) -> Option<String> {
+    match statement_descriptor {
+        Some(statement_descriptor) => {
+            (!statement_descriptor.is_empty()).then(|| statement_descriptor.to_string())
+        }
+        None => merchant_name.and_then(|merchant_name| {
+            let statement_descriptor = merchant_name
+                .split_whitespace()
+                .map(|word| {
+                    unidecode::unidecode(word)
+                        .chars()
+                        .filter(char::is_ascii_alphanumeric)
+                        .collect::<String>()
+                })
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            truncate_payout_statement_descriptor(
+                &statement_descriptor,
+                consts::PAYOUT_STATEMENT_DESCRIPTOR_MAX_LENGTH,
+            )
+        }),
+    }
+}
+
+/// Returns the statement descriptor of the payout as passed to its connector. The stored
+/// descriptor is returned for payouts which are yet to be routed.
+pub fn get_effective_payout_statement_descriptor(
+    payouts: &storage::Payouts,
+    payout_attempt: &storage::PayoutAttempt,
+) -> Option<String> {
+    let statement_descriptor = payouts.statement_descriptor.as_deref()?;
+    match payout_attempt.connector.as_deref() {
+        Some(connector) => api_enums::Connector::from_str(connector)
+            .ok()
+            .and_then(|connector| {
+                get_connector_payout_statement_descriptor(statement_descriptor, connector)
+            }),
+        None => Some(statement_descriptor.to_string()),
+    }
+}
+
+/// Formats the statement descriptor for the connector, by shortening it to the length supported
+/// by the connector. This is `None` for connectors which do not pass descriptors on to the
+/// recipient.
+pub fn get_connector_payout_statement_descriptor(
+    statement_descriptor: &str,
+    connector: api_enums::Connector,
+) -> Option<String> {
+    let max_length = connector.get_payout_statement_descriptor_max_length()?;
+    if connector.supports_spaces_in_payout_statement_descriptor() {
+        truncate_payout_statement_descriptor(statement_descriptor, max_length)
+    } else {
+        truncate_payout_statement_descriptor(&statement_descriptor.replace(' ', ""), max_length)
+    }
+}
+
+fn truncate_payout_statement_descriptor(
+    statement_descriptor: &str,
+    max_length: usize,
+) -> Option<String> {
+    let statement_descriptor = statement_descriptor
+        .chars()
+        .take(max_length)
+        .collect::<String>();
+    let statement_descriptor = statement_descriptor.trim_end();
+    (!statement_descriptor.is_empty()).then(|| statement_descriptor.to_string())
+}
+
+/// Returns the breakdown of the fees of the payout. The amount credited to the recipient is known
+/// only when the sender bears all fees, as the fees deducted on the way are not reported upfront.
+pub fn get_payout_fee_breakdown(payouts: &storage::Payouts) -> payouts::PayoutFeeBreakdown {
+    let charge_bearer = payouts.charge_bearer.unwrap_or_default();
+    payouts::PayoutFeeBreakdown {
+        charge_bearer,
+        net_amount: (charge_bearer == api_enums::PayoutChargeBearer::Sender)
+            .then_some(payouts.amount),
+    }
+}
+
+/// Returns the amount of the payout in its destination currency, along with the conversion applied
+/// when the amount is passed in a different source currency. The amount is converted at the rate
+/// locked by the forex quote when one is passed, or at the current forex rates otherwise, and
+/// brought to the minor unit of the destination currency as per `rounding`.
+pub async fn convert_payout_amount(
+    state: &SessionState,
+    merchant_id: &str,
+    source_amount: MinorUnit,
+    source_currency: api_enums::Currency,
+    destination_currency: api_enums::Currency,
+    forex_quote_id: Option<&str>,
+    rounding: api_enums::PayoutConversionRounding,
+) -> RouterResult<(MinorUnit, Option<PayoutCurrencyConversion>)> {
+    let conversion = match forex_quote_id {
+        Some(forex_quote_id) => {
+            let quote =
+                core_currency::get_conversion_quote(state, merchant_id, forex_quote_id).await?;
+            utils::when(
+                quote.conversion.amount != source_amount
+                    || quote.conversion.from_currency != source_currency.to_string()
+                    || quote.conversion.to_currency != destination_currency.to_string(),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "Forex quote {forex_quote_id} is not for converting the amount of the payout from {source_currency} to {destination_currency}"
+                        ),
+                    }))
+                },
+            )?;
+            core_currency::consume_conversion_quote(state, merchant_id, &quote).await?;
+            quote.conversion
+        }
+        None if source_currency == destination_currency => return Ok((source_amount, None)),
+        None => {
+            convert_payout_amount_at_current_rates(
+                state,
+                source_amount,
+                source_currency,
+                destination_currency,
+            )
+            .await?
+        }
+    };
+    let (amount, residual_amount) = get_payout_converted_minor_amount(
+        &conversion.converted_amount,
+        destination_currency,
+        rounding,
+    )?;
+    let exchange_rate_timestamp =
+        time::OffsetDateTime::from_unix_timestamp(conversion.rates_timestamp)
+            .map(|timestamp| PrimitiveDateTime::new(timestamp.date(), timestamp.time()))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid timestamp of the forex rates")?;
+    Ok((
+        amount,
+        Some(PayoutCurrencyConversion {
+            source_amount,
+            exchange_rate: conversion.rate,
+            exchange_rate_source: conversion.provider,
+            exchange_rate_timestamp,
+            converted_amount: conversion.converted_amount,
+            rounding,
+            residual_amount: Some(residual_amount.normalize().to_string()),
+        }),
+    ))
+}
+
+async fn convert_payout_amount_at_current_rates(
+    state: &SessionState,
+    source_amount: MinorUnit,
+    source_currency: api_enums::Currency,
+    destination_currency: api_enums::Currency,
+) -> RouterResult<api_models::currency::CurrencyConversionResponse> {
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = currency::get_forex_rates(
+        state,
+        forex_api.call_delay,
+        forex_api.local_fetch_retry_delay,
+        forex_api.local_fetch_retry_count,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the forex rates for converting the payout amount")?;
+    currency::convert_currency_using_rates(
+        &rates,
+        forex_api.currencies.as_ref(),
+        source_amount.get_amount_as_i64(),
+        destination_currency.to_string(),
+        source_currency.to_string(),
+        consts::MAX_FOREX_RATE_PRECISION,
+    )
+    .map_err(|error| {
+        let message = format!(
+            "Unable to convert the payout amount from {source_currency} to {destination_currency}: {}",
+            error.current_context()
+        );
+        error.change_context(errors::ApiErrorResponse::InvalidRequestData { message })
+    })
+}
+
+/// Returns the amount paid for the converted amount, in the minor unit of the currency, along with
+/// the residual which is the converted amount less the amount paid. The converted amount and the
+/// residual are in the major unit of the currency.
+fn get_payout_converted_minor_amount(
+    converted_amount: &str,
+    currency: api_enums::Currency,
+    rounding: api_enums::PayoutConversionRounding,
+) -> RouterResult<(MinorUnit, Decimal)> {
+    let decimal_places = u32::from(currency.number_of_digits_after_decimal_point());
+    let strategy = match rounding {
+        api_enums::PayoutConversionRounding::Round => RoundingStrategy::MidpointAwayFromZero,
+        api_enums::PayoutConversionRounding::PreserveExact => RoundingStrategy::ToZero,
+    };
+    Decimal::from_str(converted_amount)
+        .ok()
+        .and_then(|converted_amount| {
+            let amount = converted_amount.round_dp_with_strategy(decimal_places, strategy);
+            let minor_amount = amount
+                .checked_mul(Decimal::from(10_i64.pow(decimal_places)))?
+                .to_i64()?;
+            Some((
+                MinorUnit::new(minor_amount),
+                converted_amount.checked_sub(amount)?,
+            ))
+        })
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable_lazy(|| {
+            format!("Invalid converted amount {converted_amount} {currency} of the payout")
+        })
+}
+
+pub fn encode_payout_currency_conversion(
+    currency_conversion: Option<&PayoutCurrencyConversion>,
+) -> RouterResult<Option<serde_json::Value>> {
+    currency_conversion
+        .map(|currency_conversion| currency_conversion.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the currency conversion of the payout")
+}
+
+/// Records the conversion applied to the amount of the payout in the currency conversion audit log,
+/// so that the rate the payout was made at can be reconciled later. The record is binding, as the
+/// payout is made for the converted amount.
+pub async fn insert_payout_currency_conversion_audit_log(
+    state: &SessionState,
+    payouts: &storage::Payouts,
+    currency_conversion: &PayoutCurrencyConversion,
+) -> RouterResult<()> {
+    let audit_log = storage::CurrencyConversionAuditLogNew {
+        audit_id: utils::generate_id(consts::ID_LENGTH, "conv_audit"),
+        merchant_id: payouts.merchant_id.clone(),
+        payout_id: Some(payouts.payout_id.clone()),
+        source_amount: currency_conversion.source_amount,
+        source_currency: payouts.source_currency.to_string(),
+        destination_currency: payouts.destination_currency.to_string(),
+        converted_amount: currency_conversion.converted_amount.clone(),
+        exchange_rate: currency_conversion.exchange_rate.clone(),
+        exchange_rate_source: currency_conversion
+            .exchange_rate_source
+            .map(|provider| provider.to_string()),
+        exchange_rate_timestamp: currency_conversion.exchange_rate_timestamp,
+        is_binding: true,
+        created_at: common_utils::date_time::now(),
+    };
+    state
+        .store
+        .insert_currency_conversion_audit_log(audit_log)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the currency conversion of the payout")?;
+    Ok(())
+}
+
+/// Returns the conversion applied to the amount of the payout, when it was passed in a different
+/// currency than the one the payout is made in.
+pub fn get_payout_currency_conversion(
+    payouts: &storage::Payouts,
+) -> Option<PayoutCurrencyConversion> {
+    payouts
+        .currency_conversion
+        .clone()
+        .map(|currency_conversion| {
+            currency_conversion.parse_value::<PayoutCurrencyConversion>("PayoutCurrencyConversion")
+        })
+        .transpose()
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to parse the currency conversion of payout {}",
+                payouts.payout_id
+            )
+        })
+        .ok()
+        .flatten()
+}
+
+/// Records the time taken by the payout to reach a terminal state, if its attempt has reached one.
+/// The status of the attempt is used since some flows, such as connector webhooks, only update
+/// the attempt.
+pub fn record_payout_time_to_fulfillment(
+    payouts: &storage::Payouts,
+    payout_attempt: &storage::PayoutAttempt,
+) {
+    if is_payout_terminal_state(payout_attempt.status) {
+        metrics::PAYOUT_TIME_TO_FULFILLMENT.record(
+            &metrics::CONTEXT,
+            (payout_attempt.last_modified_at - payouts.created_at).as_seconds_f64(),
+            &add_attributes([("status", payout_attempt.status.to_string())]),
+        );
+    }
+}
+
+/// Returns the originator of the payout stored on creation or update, which defaults to the
+/// merchant's registered name and address. Fails when the connector requires the originator for
+/// the recipient's country and its name or address is missing.
+pub fn get_payout_originator(
+    state: &SessionState,
+    connector_name: &api_enums::Connector,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &PayoutData,
+) -> RouterResult<Option<payouts::PayoutOriginator>> {
+    let originator = payout_data
+        .payouts
+        .originator
+        .clone()
+        .map(|originator| {
+            originator
+                .expose()
+                .parse_value::<payouts::PayoutOriginator>("PayoutOriginator")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the originator of the payout")?;
+    let originator = originator.or_else(|| {
+        let name = merchant_account
+            .merchant_name
+            .clone()
+            .map(|name| name.into_inner());
+        let address = merchant_account
+            .merchant_details
+            .as_ref()
+            .and_then(|details| {
+                details
+                    .get_inner()
+                    .peek()
+                    .clone()
+                    .parse_value::<api_models::admin::MerchantDetails>("MerchantDetails")
+                    .ok()
+            })
+            .and_then(|details| details.address);
+        (name.is_some() || address.is_some()).then_some(payouts::PayoutOriginator {
+            entity_type: Some(api_enums::PayoutEntityType::Company),
+            name,
+            address,
+            identifier: None,
+        })
+    });
+
+    let recipient_country = payout_data
+        .billing_address
+        .as_ref()
+        .and_then(|address| address.country);
+    let is_originator_required = state
+        .conf
+        .payouts
+        .originator_requirements
+        .get(&connector_name.to_string())
+        .is_some_and(|requirement| {
+            requirement.countries.is_empty()
+                || recipient_country.is_some_and(|country| requirement.countries.contains(&country))
+        });
+    if is_originator_required {
+        fp_utils::when(
+            originator
+                .as_ref()
+                .and_then(|originator| originator.name.as_ref())
+                .is_none(),
+            || {
+                Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "originator.name"
+                }))
+            },
+        )?;
+        fp_utils::when(
+            originator
+                .as_ref()
+                .and_then(|originator| originator.address.as_ref())
+                .is_none(),
+            || {
+                Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "originator.address"
+                }))
+            },
+        )?;
+    }
+
+    Ok(originator)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_is_above_payout_approval_threshold() {
+        let thresholds = HashMap::from([
+            (enums::Currency::USD, MinorUnit::new(100_000)),
+            (enums::Currency::JPY, MinorUnit::new(15_000_000)),
+        ]);
+
+        assert!(!is_above_payout_approval_threshold(
+            &thresholds,
+            MinorUnit::new(100_000),
+            enums::Currency::USD
+        ));
+        assert!(is_above_payout_approval_threshold(
+            &thresholds,
+            MinorUnit::new(100_001),
+            enums::Currency::USD
+        ));
+        // The same amount is compared against the threshold of its own currency
+        assert!(!is_above_payout_approval_threshold(
+            &thresholds,
+            MinorUnit::new(100_001),
+            enums::Currency::JPY
+        ));
+        // Currencies without a threshold always need an approval
+        assert!(is_above_payout_approval_threshold(
+            &thresholds,
+            MinorUnit::new(1),
+            enums::Currency::EUR
+        ));
+    }
+
+    #[test]
+    fn test_validate_payout_approver_is_not_creator() {
+        assert!(
+            validate_payout_approver_is_not_creator("payout_1", Some("dev_key_1"), "user_1")
+                .is_ok()
+        );
+        assert!(validate_payout_approver_is_not_creator("payout_1", None, "user_1").is_ok());
+        assert!(validate_payout_approver_is_not_creator(
+            "payout_1",
+            Some("dev_key_1"),
+            "dev_key_1"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_is_payout_amount_changed() {
+        let amount = (MinorUnit::new(100_000), enums::Currency::USD);
+        assert!(!is_payout_amount_changed(amount, amount));
+        assert!(is_payout_amount_changed(
+            amount,
+            (MinorUnit::new(100_001), enums::Currency::USD)
+        ));
+        assert!(is_payout_amount_changed(
+            amount,
+            (MinorUnit::new(100_000), enums::Currency::EUR)
+        ));
+    }
+
+    #[test]
+    fn test_should_disburse_scheduled_payout_on_retry() {
+        assert!(should_disburse_scheduled_payout(
+            api_enums::PayoutStatus::Scheduled
+        ));
+        // A retry of a disbursement which failed after the status was updated
+        assert!(should_disburse_scheduled_payout(
+            api_enums::PayoutStatus::RequiresCreation
+        ));
+        assert!(!should_disburse_scheduled_payout(
+            api_enums::PayoutStatus::Cancelled
+        ));
+        assert!(!should_disburse_scheduled_payout(
+            api_enums::PayoutStatus::Success
+        ));
+    }
+
+    #[test]
+    fn test_get_payout_statement_descriptor() {
+        assert_eq!(
+            get_payout_statement_descriptor(
+                None,
+                Some("  Acme & Sons,  Intl. Trading Company Ltd")
+            ),
+            Some("Acme Sons Intl Trading Company Ltd".to_string())
+        );
+        assert_eq!(
+            get_payout_statement_descriptor(None, Some("Café Müller")),
+            Some("Cafe Muller".to_string())
+        );
+        assert_eq!(
+            get_payout_statement_descriptor(Some(""), Some("Acme")),
+            None
+        );
+        assert_eq!(
+            get_payout_statement_descriptor(Some("Invoice 42"), Some("Acme")),
+            Some("Invoice 42".to_string())
+        );
+        assert_eq!(
+            get_connector_payout_statement_descriptor(
+                "Acme Sons Intl",
+                api_enums::Connector::Adyenplatform
+            ),
+            Some("AcmeSonsIntl".to_string())
+        );
+        assert_eq!(
+            get_connector_payout_statement_descriptor("Acme Sons Intl", api_enums::Connector::Wise),
+            Some("Acme Sons".to_string())
+        );
+        assert_eq!(
+            get_connector_payout_statement_descriptor("Acme", api_enums::Connector::Adyen),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_payout_webhook_attempt_update() {
+        let payout_amount = MinorUnit::new(6540);
+
+        let update = get_payout_webhook_attempt_update(
+            Some("po_connector_1".to_string()),
+            Some(true),
+            payout_amount,
+            api_enums::PayoutStatus::Recalled,
+        );
+        assert!(matches!(
+            update,
+            storage::PayoutAttemptUpdate::RecallUpdate {
+                status: api_enums::PayoutStatus::Recalled,
+                reason: None,
+                recalled_amount,
+            } if recalled_amount == payout_amount
+        ));
+
+        let update = get_payout_webhook_attempt_update(
+            Some("po_connector_1".to_string()),
+            Some(true),
+            payout_amount,
+            api_enums::PayoutStatus::Reversed,
+        );
+        assert!(matches!(
+            update,
+            storage::PayoutAttemptUpdate::StatusUpdate {
+                status: api_enums::PayoutStatus::Reversed,
+                reason_code: Some(api_enums::PayoutStatusReasonCode::ConnectorReversed),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_get_payout_verification_attempt_update() {
+        let update = get_payout_verification_attempt_update(
+            None,
+            Some(payouts::PayoutVerificationResult::Verified),
+            Some("ignored".to_string()),
+        );
+        assert!(matches!(
+            update,
+            storage::PayoutAttemptUpdate::StatusUpdate {
+                status: api_enums::PayoutStatus::RequiresCreation,
+                error_message: None,
+                reason_code: None,
+                reason: None,
+                ..
+            }
+        ));
+
+        let update = get_payout_verification_attempt_update(
+            None,
+            Some(payouts::PayoutVerificationResult::Rejected),
+            Some("Identity document has expired".to_string()),
+        );
+        assert!(matches!(
+            update,
+            storage::PayoutAttemptUpdate::StatusUpdate {
+                status: api_enums::PayoutStatus::Failed,
+                reason_code: Some(api_enums::PayoutStatusReasonCode::VerificationRejected),
+                reason: Some(ref reason),
+                ..
+            } if reason == "Identity document has expired"
+        ));
+
+        let update = get_payout_verification_attempt_update(None, None, None);
+        assert!(matches!(
+            update,
+            storage::PayoutAttemptUpdate::StatusUpdate {
+                status: api_enums::PayoutStatus::Expired,
+                reason_code: Some(api_enums::PayoutStatusReasonCode::VerificationExpired),
+                ..
+            }
+        ));
+        // An expired payout has reached a terminal state, so its time to fulfillment is recorded
+        assert!(is_payout_terminal_state(api_enums::PayoutStatus::Expired));
+    }
+
+    #[test]
+    fn test_should_apply_payout_webhook_status_for_duplicate_delivery() {
+        let success = api_enums::PayoutStatus::Success;
+        assert!(should_apply_payout_webhook_status(
+            api_enums::PayoutStatus::Pending,
+            success
+        ));
+        // A replayed success must not be applied, and notified, again
+        assert!(!should_apply_payout_webhook_status(success, success));
+    }
+
+    #[test]
+    fn test_should_apply_payout_webhook_status_for_out_of_order_delivery() {
+        assert!(!should_apply_payout_webhook_status(
+            api_enums::PayoutStatus::Success,
+            api_enums::PayoutStatus::Pending
+        ));
+        assert!(!should_apply_payout_webhook_status(
+            api_enums::PayoutStatus::Failed,
+            api_enums::PayoutStatus::Initiated
+        ));
+        assert!(should_apply_payout_webhook_status(
+            api_enums::PayoutStatus::Success,
+            api_enums::PayoutStatus::Reversed
+        ));
+        assert!(should_apply_payout_webhook_status(
+            api_enums::PayoutStatus::Success,
+            api_enums::PayoutStatus::Recalled
+        ));
+        assert!(!should_apply_payout_webhook_status(
+            api_enums::PayoutStatus::Recalled,
+            api_enums::PayoutStatus::Success
+        ));
+        assert!(should_apply_payout_webhook_status(
+            api_enums::PayoutStatus::Initiated,
+            api_enums::PayoutStatus::Failed
+        ));
+    }
+
+    #[test]
+    fn test_get_payout_webhook_event_key() {
+        let with_event_id =
+            get_payout_webhook_event_key("merchant", "adyen", Some("evt_1".to_string()), b"{}")
+                .unwrap();
+        assert_eq!(with_event_id, "payout_webhook_event_merchant_adyen_evt_1");
+
+        let body = br#"{"status":"success"}"#;
+        assert_eq!(
+            get_payout_webhook_event_key("merchant", "wise", None, body).unwrap(),
+            get_payout_webhook_event_key("merchant", "wise", None, body).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_get_masked_payout_destination_for_card() {
+        let card = api::PayoutMethodData::Card(api::CardPayout {
+            card_number: ::cards::CardNumber::from_str("4111111111111111").unwrap(),
+            ..Default::default()
+        });
+        assert_eq!(
+            get_masked_payout_destination(&card),
+            Some("************1111".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_masked_payout_destination_for_bank() {
+        let sepa = api::PayoutMethodData::Bank(payouts::Bank::Sepa(payouts::SepaBankTransfer {
+            iban: Secret::new("NL91ABNA0417164300".to_string()),
+            ..Default::default()
+        }));
+        let masked = get_masked_payout_destination(&sepa);
+        assert_eq!(masked, Some("**************4300".to_string()));
+        assert!(!masked.unwrap().contains("NL91ABNA0417164300"));
+
+        let ach = api::PayoutMethodData::Bank(payouts::Bank::Ach(payouts::AchBankTransfer {
+            bank_account_number: Secret::new("1234".to_string()),
+            ..Default::default()
+        }));
+        assert_eq!(
+            get_masked_payout_destination(&ach),
+            Some("****".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate_payout_currency_for_connector() {
+        let currency_support = HashMap::from([(
+            "wise".to_string(),
+            settings::PayoutCurrencySupport(HashMap::from([(
+                api_enums::PayoutType::Bank,
+                HashSet::from([api_enums::Currency::EUR]),
+            )])),
+        )]);
+        let validate = |connector, payout_type, currency| {
+            validate_payout_currency_for_connector(
+                &currency_support,
+                connector,
+                Some(payout_type),
+                currency,
+            )
+        };
+
+        assert!(validate(
+            api_enums::Connector::Wise,
+            api_enums::PayoutType::Bank,
+            api_enums::Currency::EUR
+        )
+        .is_ok());
+        assert!(validate(
+            api_enums::Connector::Wise,
+            api_enums::PayoutType::Bank,
+            api_enums::Currency::USD
+        )
+        .is_err());
+        assert!(validate(
+            api_enums::Connector::Wise,
+            api_enums::PayoutType::Card,
+            api_enums::Currency::EUR
+        )
+        .is_err());
+        // Connectors missing from the matrix are not restricted
+        assert!(validate(
+            api_enums::Connector::Adyen,
+            api_enums::PayoutType::Card,
+            api_enums::Currency::USD
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_get_payout_connectors_of_accounts() {
+        assert_eq!(
+            get_payout_connectors_of_accounts(["wise", "stripe", "wise", "checkout"]),
+            vec![
+                api_enums::PayoutConnectors::Wise,
+                api_enums::PayoutConnectors::Stripe,
+            ]
+        );
+        assert!(get_payout_connectors_of_accounts([]).is_empty());
+    }
+
+    #[test]
+    fn test_get_payout_connector_ineligibility_reason() {
+        let currency_support = HashMap::from([(
+            "wise".to_string(),
+            settings::PayoutCurrencySupport(HashMap::from([(
+                api_enums::PayoutType::Bank,
+                HashSet::from([api_enums::Currency::EUR]),
+            )])),
+        )]);
+        let reason = |connector, payout_type, currency, is_network_token| {
+            get_payout_connector_ineligibility_reason(
+                &currency_support,
+                connector,
+                Some(payout_type),
+                currency,
+                api_enums::PayoutChargeBearer::Sender,
+                is_network_token,
+            )
+        };
+
+        assert_eq!(
+            reason(
+                api_enums::Connector::Wise,
+                api_enums::PayoutType::Bank,
+                api_enums::Currency::USD,
+                false
+            ),
+            Some(PayoutConnectorIneligibilityReason::CurrencyUnsupported)
+        );
+        assert_eq!(
+            reason(
+                api_enums::Connector::Wise,
+                api_enums::PayoutType::Card,
+                api_enums::Currency::EUR,
+                false
+            ),
+            Some(PayoutConnectorIneligibilityReason::MethodUnsupported)
+        );
+        assert_eq!(
+            reason(
+                api_enums::Connector::Adyen,
+                api_enums::PayoutType::Card,
+                api_enums::Currency::USD,
+                true
+            ),
+            Some(PayoutConnectorIneligibilityReason::MethodUnsupported)
+        );
+        assert_eq!(
+            reason(
+                api_enums::Connector::Wise,
+                api_enums::PayoutType::Bank,
+                api_enums::Currency::EUR,
+                false
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate_payout_charge_bearer_for_connector() {
+        assert!(validate_payout_charge_bearer_for_connector(
+            api_enums::Connector::Wise,
+            api_enums::PayoutChargeBearer::Sender,
+        )
+        .is_ok());
+        assert!(validate_payout_charge_bearer_for_connector(
+            api_enums::Connector::Adyenplatform,
+            api_enums::PayoutChargeBearer::Shared,
+        )
+        .is_ok());
+        assert!(validate_payout_charge_bearer_for_connector(
+            api_enums::Connector::Wise,
+            api_enums::PayoutChargeBearer::Recipient,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_masked_payout_bank_details_is_normalized() {
+        let sepa = api::PayoutMethodData::Bank(payouts::Bank::Sepa(payouts::SepaBankTransfer {
+            iban: Secret::new("de89 3704 0044 0532 0130 00".to_string()),
+            bic: Some(Secret::new("cobadeffxxx".to_string())),
+            ..Default::default()
+        }));
+        let bank_details = get_masked_payout_bank_details(&sepa).unwrap();
+        assert_eq!(
+            bank_details.iban,
+            Some("DE89**************3000".to_string())
+        );
+        assert_eq!(bank_details.bic, Some("COBADEFFXXX".to_string()));
+
+        let bacs = api::PayoutMethodData::Bank(payouts::Bank::Bacs(payouts::BacsBankTransfer {
+            bank_account_number: Secret::new("12345678".to_string()),
+            bank_sort_code: Secret::new("98-76-54".to_string()),
+            ..Default::default()
+        }));
+        let bank_details = get_masked_payout_bank_details(&bacs).unwrap();
+        assert_eq!(bank_details.bank_sort_code, Some("987654".to_string()));
+        assert_eq!(
+            bank_details.bank_account_number,
+            Some("****5678".to_string())
+        );
+    }
+
+    #[test]
+    fn test_estimate_payout_arrival_for_bank_payouts() {
+        let estimate = settings::PayoutArrivalEstimate {
+            days: 1,
+            instant_minutes: Some(30),
+            cutoff_hour: Some(15),
+        };
+        // Friday, 2024-12-20
+        let friday = time::Date::from_calendar_date(2024, time::Month::December, 20).unwrap();
+        let holidays = HashSet::from([
+            time::Date::from_calendar_date(2024, time::Month::December, 25).unwrap(),
+            time::Date::from_calendar_date(2024, time::Month::December, 26).unwrap(),
+        ]);
+        let estimate_arrival = |submitted_at, priority| {
+            estimate_payout_arrival(
+                &estimate,
+                None,
+                Some(&holidays),
+                api_enums::PayoutType::Bank,
+                priority,
+                submitted_at,
+            )
+        };
+
+        // Before the cutoff, the payout arrives on the next business day
+        assert_eq!(
+            estimate_arrival(friday.with_hms(10, 0, 0).unwrap(), None),
+            Some(
+                time::Date::from_calendar_date(2024, time::Month::December, 23)
+                    .unwrap()
+                    .midnight()
+            )
+        );
+        // After the cutoff, the payout is processed on Monday, and Christmas is skipped
+        assert_eq!(
+            estimate_arrival(friday.with_hms(16, 0, 0).unwrap(), None),
+            Some(
+                time::Date::from_calendar_date(2024, time::Month::December, 24)
+                    .unwrap()
+                    .midnight()
+            )
+        );
+        let tuesday = time::Date::from_calendar_date(2024, time::Month::December, 24).unwrap();
+        assert_eq!(
+            estimate_arrival(tuesday.with_hms(10, 0, 0).unwrap(), None),
+            Some(
+                time::Date::from_calendar_date(2024, time::Month::December, 27)
+                    .unwrap()
+                    .midnight()
+            )
+        );
+        assert_eq!(
+            estimate_arrival(
+                friday.with_hms(16, 0, 0).unwrap(),
+                Some(api_enums::PayoutSendPriority::Instant)
+            ),
+            Some(friday.with_hms(16, 30, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_estimate_payout_arrival_without_days() {
+        let estimate = settings::PayoutArrivalEstimate {
+            days: 0,
+            instant_minutes: None,
+            cutoff_hour: Some(15),
+        };
+        // Friday, 2024-12-20
+        let friday = time::Date::from_calendar_date(2024, time::Month::December, 20).unwrap();
+        let estimate_arrival = |payout_type, submitted_at| {
+            estimate_payout_arrival(&estimate, None, None, payout_type, None, submitted_at)
+        };
+
+        // Payouts taking no days arrive when submitted, not at the start of the day
+        assert_eq!(
+            estimate_arrival(
+                api_enums::PayoutType::Bank,
+                friday.with_hms(10, 0, 0).unwrap()
+            ),
+            Some(friday.with_hms(10, 0, 0).unwrap())
+        );
+        assert_eq!(
+            estimate_arrival(
+                api_enums::PayoutType::Card,
+                friday.with_hms(10, 0, 0).unwrap()
+            ),
+            Some(friday.with_hms(10, 0, 0).unwrap())
+        );
+        // Bank payouts after the cutoff arrive at the start of the next business day
+        assert_eq!(
+            estimate_arrival(
+                api_enums::PayoutType::Bank,
+                friday.with_hms(16, 0, 0).unwrap()
+            ),
+            Some(
+                time::Date::from_calendar_date(2024, time::Month::December, 23)
+                    .unwrap()
+                    .midnight()
+            )
+        );
+    }
+
+    #[test]
+    fn test_get_payout_submitted_at_for_scheduled_payouts() {
+        let created_at = time::Date::from_calendar_date(2024, time::Month::December, 20)
+            .unwrap()
+            .with_hms(10, 0, 0)
+            .unwrap();
+        let scheduled_at = created_at + time::Duration::days(3);
+
+        assert_eq!(get_payout_submitted_at(created_at, None), created_at);
+        assert_eq!(
+            get_payout_submitted_at(created_at, Some(scheduled_at)),
+            scheduled_at
+        );
+    }
+
+    #[test]
+    fn test_get_payout_priority_for_connector() {
+        let priority_support = HashMap::from([(
+            "wise".to_string(),
+            settings::PayoutPrioritySupport {
+                priorities: HashSet::from([api_enums::PayoutSendPriority::Instant]),
+            },
+        )]);
+
+        // Connectors are not assumed to support any priority other than regular
+        assert!(get_payout_priority_for_connector(
+            &HashMap::new(),
+            api_enums::Connector::Adyenplatform,
+            api_enums::PayoutSendPriority::Instant,
+            false,
+        )
+        .is_err());
+        assert_eq!(
+            get_payout_priority_for_connector(
+                &priority_support,
+                api_enums::Connector::Wise,
+                api_enums::PayoutSendPriority::Instant,
+                false,
+            )
+            .unwrap(),
+            api_enums::PayoutSendPriority::Instant
+        );
+        assert_eq!(
+            get_payout_priority_for_connector(
+                &priority_support,
+                api_enums::Connector::Adyen,
+                api_enums::PayoutSendPriority::Regular,
+                false,
+            )
+            .unwrap(),
+            api_enums::PayoutSendPriority::Regular
+        );
+        assert!(get_payout_priority_for_connector(
+            &priority_support,
+            api_enums::Connector::Wise,
+            api_enums::PayoutSendPriority::Wire,
+            false,
+        )
+        .is_err());
+        assert_eq!(
+            get_payout_priority_for_connector(
+                &priority_support,
+                api_enums::Connector::Wise,
+                api_enums::PayoutSendPriority::Wire,
+                true,
+            )
+            .unwrap(),
+            api_enums::PayoutSendPriority::Regular
+        );
+    }
+
+    #[test]
+    fn test_get_payout_settlement_network_for_connector() {
+        assert_eq!(
+            get_payout_settlement_network_for_connector(
+                api_enums::Connector::Adyenplatform,
+                api_enums::PayoutSettlementNetwork::SepaInstant,
+                false,
+            )
+            .unwrap(),
+            api_enums::PayoutSettlementNetwork::SepaInstant
+        );
+        // Adyen Platform can not tell the instant networks in the US apart, so the network which
+        // is actually selected is recorded
+        assert!(get_payout_settlement_network_for_connector(
+            api_enums::Connector::Adyenplatform,
+            api_enums::PayoutSettlementNetwork::Rtp,
+            false,
+        )
+        .is_err());
+        assert_eq!(
+            get_payout_settlement_network_for_connector(
+                api_enums::Connector::Adyenplatform,
+                api_enums::PayoutSettlementNetwork::FedNow,
+                true,
+            )
+            .unwrap(),
+            api_enums::PayoutSettlementNetwork::Ach
+        );
+        assert_eq!(
+            get_payout_settlement_network_for_connector(
+                api_enums::Connector::Wise,
+                api_enums::PayoutSettlementNetwork::Ach,
+                false,
+            )
+            .unwrap(),
+            api_enums::PayoutSettlementNetwork::Ach
+        );
+        assert!(get_payout_settlement_network_for_connector(
+            api_enums::Connector::Wise,
+            api_enums::PayoutSettlementNetwork::FedNow,
+            false,
+        )
+        .is_err());
+        assert_eq!(
+            get_payout_settlement_network_for_connector(
+                api_enums::Connector::Wise,
+                api_enums::PayoutSettlementNetwork::FasterPayments,
+                true,
+            )
+            .unwrap(),
+            api_enums::PayoutSettlementNetwork::Bacs
+        );
+    }
+
+    #[test]
+    fn test_get_payout_rail_priority() {
+        assert_eq!(
+            get_payout_rail_priority(None, Some(api_enums::PayoutSettlementNetwork::SepaInstant)),
+            Some(api_enums::PayoutSendPriority::Instant)
+        );
+        assert_eq!(
+            get_payout_rail_priority(None, Some(api_enums::PayoutSettlementNetwork::Ach)),
+            Some(api_enums::PayoutSendPriority::Regular)
+        );
+        assert_eq!(get_payout_rail_priority(None, None), None);
+    }
+
+    #[test]
+    fn test_get_processing_date_rolls_over_after_cutoff() {
+        let cutoff = settings::PayoutCutoffTime {
+            hour: 14,
+            minute: 30,
+        };
+        // Friday, 2024-12-20
+        let friday = time::Date::from_calendar_date(2024, time::Month::December, 20).unwrap();
+        let monday = time::Date::from_calendar_date(2024, time::Month::December, 23).unwrap();
+
+        assert_eq!(
+            get_processing_date(Some(&cutoff), None, friday.with_hms(14, 29, 0).unwrap()),
+            Some(friday)
+        );
+        assert_eq!(
+            get_processing_date(Some(&cutoff), None, friday.with_hms(14, 30, 0).unwrap()),
+            Some(monday)
+        );
+        // Without a cutoff time, payouts are processed immediately
+        assert_eq!(
+            get_processing_date(None, None, friday.with_hms(23, 0, 0).unwrap()),
+            Some(friday)
+        );
+    }
+
+    #[test]
+    fn test_is_destination_failure() {
+        // Failures reported only as a status are attributed to the destination
+        assert!(is_destination_failure(None, None));
+        // Failures with an error code are attributed as per the gateway status mapping
+        assert!(is_destination_failure(
+            Some("AC04"),
+            Some(api_models::gsm::GsmDecision::RetryWithNextDestination)
+        ));
+        assert!(!is_destination_failure(
+            Some("AC04"),
+            Some(api_models::gsm::GsmDecision::Retry)
+        ));
+        assert!(!is_destination_failure(Some("AC04"), None));
+
+        assert!(is_payout_destination_failure_status(
+            api_enums::PayoutStatus::Failed
+        ));
+        assert!(is_payout_destination_failure_status(
+            api_enums::PayoutStatus::Ineligible
+        ));
+        assert!(is_payout_destination_failure_status(
+            api_enums::PayoutStatus::Reversed
+        ));
+        assert!(!is_payout_destination_failure_status(
+            api_enums::PayoutStatus::Cancelled
+        ));
+        assert!(!is_payout_destination_failure_status(
+            api_enums::PayoutStatus::Success
+        ));
+    }
+
+    #[test]
+    fn test_payout_fails_over_from_failed_primary_to_backup_destination() {
+        let backup_destinations = vec![
+            api::PayoutMethodData::Bank(payouts::Bank::Bacs(payouts::BacsBankTransfer {
+                bank_account_number: Secret::new("12345678".to_string()),
+                bank_sort_code: Secret::new("987654".to_string()),
+                ..Default::default()
+            })),
+            api::PayoutMethodData::Bank(payouts::Bank::Sepa(payouts::SepaBankTransfer {
+                iban: Secret::new("DE89370400440532013000".to_string()),
+                ..Default::default()
+            })),
+        ];
+        let payout_failed = errors::ApiErrorResponse::PayoutFailed { data: None };
+
+        // The connector fails the primary destination synchronously, the payout is failed over
+        // to the first backup destination instead of returning the failure
+        assert!(is_connector_payout_failure(
+            &payout_failed,
+            api_enums::PayoutStatus::Failed
+        ));
+        assert!(is_destination_failure(None, None));
+        assert!(matches!(
+            get_next_payout_destination(&backup_destinations, None),
+            Some(api::PayoutMethodData::Bank(payouts::Bank::Bacs(_)))
+        ));
+
+        // The first backup destination succeeds, so the payout is not failed over any further
+        assert!(!is_connector_payout_failure(
+            &payout_failed,
+            api_enums::PayoutStatus::Success
+        ));
+        assert!(!is_payout_destination_failure_status(
+            api_enums::PayoutStatus::Success
+        ));
+
+        // Had the first backup destination failed as well, the next one would be tried
+        assert!(is_connector_payout_failure(
+            &payout_failed,
+            api_enums::PayoutStatus::Ineligible
+        ));
+        assert!(matches!(
+            get_next_payout_destination(&backup_destinations, Some(1)),
+            Some(api::PayoutMethodData::Bank(payouts::Bank::Sepa(_)))
+        ));
+        assert!(get_next_payout_destination(&backup_destinations, Some(2)).is_none());
+
+        // Errors other than the connector failing the payout stop the processing of the payout
+        assert!(!is_connector_payout_failure(
+            &errors::ApiErrorResponse::InternalServerError,
+            api_enums::PayoutStatus::Failed
+        ));
+    }
+
+    #[test]
+    fn test_get_payout_converted_minor_amount() {
+        let round = api_enums::PayoutConversionRounding::Round;
+        assert_eq!(
+            get_payout_converted_minor_amount("108.10815", api_enums::Currency::USD, round)
+                .unwrap()
+                .0,
+            MinorUnit::new(10811)
+        );
+        assert_eq!(
+            get_payout_converted_minor_amount("108.105", api_enums::Currency::USD, round)
+                .unwrap()
+                .0,
+            MinorUnit::new(10811)
+        );
+        assert_eq!(
+            get_payout_converted_minor_amount("15644.5", api_enums::Currency::JPY, round)
+                .unwrap()
+                .0,
+            MinorUnit::new(15645)
+        );
+        assert!(
+            get_payout_converted_minor_amount("rate", api_enums::Currency::USD, round).is_err()
+        );
+    }
+
+    #[test]
+    fn test_get_payout_converted_minor_amount_residual_usd_to_jpy() {
+        // 100.00 USD at 161.2367 JPY per USD is 16123.67 JPY, which has no minor unit
+        let (amount, residual) = get_payout_converted_minor_amount(
+            "16123.67",
+            api_enums::Currency::JPY,
+            api_enums::PayoutConversionRounding::Round,
+        )
+        .unwrap();
+        assert_eq!(amount, MinorUnit::new(16124));
+        assert_eq!(residual.normalize().to_string(), "-0.33");
+
+        let (amount, residual) = get_payout_converted_minor_amount(
+            "16123.67",
+            api_enums::Currency::JPY,
+            api_enums::PayoutConversionRounding::PreserveExact,
+        )
+        .unwrap();
+        assert_eq!(amount, MinorUnit::new(16123));
+        assert_eq!(residual.normalize().to_string(), "0.67");
+
+        // The amount paid and the residual always add up to the converted amount
+        for rounding in [
+            api_enums::PayoutConversionRounding::Round,
+            api_enums::PayoutConversionRounding::PreserveExact,
+        ] {
+            let (amount, residual) =
+                get_payout_converted_minor_amount("16123.5", api_enums::Currency::JPY, rounding)
+                    .unwrap();
+            assert_eq!(
+                Decimal::from(amount.get_amount_as_i64()) + residual,
+                Decimal::from_str("16123.5").unwrap()
+            );
+        }
+
+        // Whole yen are paid as they are under either rounding
+        let (amount, residual) = get_payout_converted_minor_amount(
+            "16123",
+            api_enums::Currency::JPY,
+            api_enums::PayoutConversionRounding::PreserveExact,
+        )
+        .unwrap();
+        assert_eq!(amount, MinorUnit::new(16123));
+        assert!(residual.is_zero());
+    }
+
+    fn get_payout_response(created: time::OffsetDateTime) -> api::PayoutCreateResponse {
+        api::PayoutCreateResponse {
+            payout_id: "payout_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            amount: MinorUnit::new(6540),
+            currency: api_enums::Currency::USD,
+            connector: None,
+            payout_type: None,
+            billing: None,
+            customer_id: id_type::CustomerId::from("customer_123".into()).unwrap(),
+            auto_fulfill: false,
+            email: None,
+            name: None,
+            phone: None,
+            phone_country_code: None,
+            client_secret: None,
+            return_url: None,
+            business_country: None,
+            business_label: None,
+            description: None,
+            entity_type: api_enums::PayoutEntityType::Individual,
+            recurring: false,
+            metadata: None,
+            status: api_enums::PayoutStatus::Success,
+            error_message: None,
+            error_code: None,
+            profile_id: "pro_123".to_string(),
+            created: Some(created),
+            connector_transaction_id: None,
+            connector_details: None,
+            priority: None,
+            settlement_network: None,
+            purpose_code: None,
+            webhook_url: None,
+            value_date: Some(created.date()),
+            fee_breakdown: payouts::PayoutFeeBreakdown::default(),
+            statement_descriptor: None,
+            merchant_reference_id: None,
+            series_id: None,
+            source_amount: None,
+            source_currency: None,
+            exchange_rate: None,
+            exchange_rate_source: None,
+            exchange_rate_timestamp: Some(created - time::Duration::hours(1)),
+            conversion_rounding: None,
+            conversion_residual: None,
+            time_to_fulfillment: None,
+            attempts: None,
+            payout_link: None,
+            approved_by: None,
+            approved_at: Some(created + time::Duration::hours(1)),
+            verified_at: Some(created + time::Duration::hours(2)),
+            verification_reference: None,
+            reason_code: None,
+            reason: None,
+            recalled_amount: None,
+            destination_index: None,
+            payout_method_masked: None,
+            bank_details: None,
+            estimated_arrival: Some(created + time::Duration::days(1)),
+            processing_date: Some(created.date()),
+        }
+    }
+
+    #[test]
+    fn test_convert_payout_response_timezone_converts_every_timestamp() {
+        let created = time::Date::from_calendar_date(2024, time::Month::December, 20)
+            .unwrap()
+            .with_hms(20, 0, 0)
+            .unwrap()
+            .assume_utc();
+        let kolkata = time::UtcOffset::from_hms(5, 30, 0).unwrap();
+
+        let response = convert_payout_response_timezone(
+            get_payout_response(created),
+            get_payout_response_timezone(Some("Asia/Kolkata")).unwrap(),
+        );
+        let timestamps = [
+            (response.created, created),
+            (
+                response.exchange_rate_timestamp,
+                created - time::Duration::hours(1),
+            ),
+            (response.approved_at, created + time::Duration::hours(1)),
+            (response.verified_at, created + time::Duration::hours(2)),
+            (
+                response.estimated_arrival,
+                created + time::Duration::days(1),
+            ),
+        ];
+        for (converted, original) in timestamps {
+            let converted = converted.unwrap();
+            // The instant is kept, only the offset it is represented in changes
+            assert_eq!(converted, original);
+            assert_eq!(converted.offset(), kolkata);
+        }
+        // Dates are not converted, even when the time zone is on the next day already
+        assert_eq!(response.value_date, Some(created.date()));
+        assert_eq!(response.processing_date, Some(created.date()));
+
+        // Without a time zone, the timestamps stay in UTC
+        let response = convert_payout_response_timezone(get_payout_response(created), None);
+        assert_eq!(response.created.unwrap().offset(), time::UtcOffset::UTC);
+        assert_eq!(
+            response.estimated_arrival.unwrap().offset(),
+            time::UtcOffset::UTC
+        );
+    }
+
+    #[test]
+    fn test_convert_payout_response_timezone_resolves_daylight_saving_per_timestamp() {
+        // The payout is created in winter, and estimated to arrive after daylight saving starts
+        let created = time::Date::from_calendar_date(2024, time::Month::March, 9)
+            .unwrap()
+            .with_hms(12, 0, 0)
+            .unwrap()
+            .assume_utc();
+
+        let response = convert_payout_response_timezone(
+            get_payout_response(created),
+            get_payout_response_timezone(Some("America/New_York")).unwrap(),
+        );
+        assert_eq!(
+            response.created.unwrap().offset(),
+            time::UtcOffset::from_hms(-5, 0, 0).unwrap()
+        );
+        assert_eq!(
+            response.estimated_arrival.unwrap().offset(),
+            time::UtcOffset::from_hms(-4, 0, 0).unwrap()
+        );
+    }
+}
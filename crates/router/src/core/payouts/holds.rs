@@ -0,0 +1,650 @@
+use std::collections::{BTreeMap, HashMap};
+
+use common_utils::{ext_traits::StringExt, types::MinorUnit};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::{
+        api_locking,
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::StorageInterface,
+    routes::{lock_utils, SessionState},
+    services,
+    types::{api::payouts, domain, storage},
+    utils,
+};
+
+const PAYOUT_HOLD_EXPIRY_TASK: &str = "PAYOUT_HOLD_EXPIRY";
+/// Holds are created under a lock on this key for the merchant, so that concurrent holds cannot
+/// together hold more than the available funds
+const PAYOUT_HOLDS_LOCKING_KEY: &str = "payout_holds";
+
+/// The hold whose expiry is tracked by a payout hold expiry task
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PayoutHoldExpiryTrackingData {
+    pub merchant_id: String,
+    pub hold_id: String,
+}
+
+/// Holds an amount of the merchant's funds for a future payout. When the merchant has configured
+/// its funds for payouts, the hold is rejected if the amount is more than the funds which are not
+/// already held.
+#[instrument(skip_all)]
+pub async fn create_payout_hold(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutHoldCreateRequest,
+) -> RouterResponse<payouts::PayoutHoldResponse> {
+    let merchant_id = &merchant_account.merchant_id;
+    validate_payout_hold_create_request(&req)?;
+
+    let balance = get_payout_hold_balances(&state, merchant_id)
+        .await?
+        .into_iter()
+        .find(|balance| balance.currency == req.currency);
+    if let Some(available_amount) = balance.and_then(|balance| balance.available_amount) {
+        utils::when(req.amount > available_amount, || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Insufficient funds for the hold, {} {} is available",
+                    available_amount, req.currency
+                ),
+            }))
+        })?;
+    }
+
+    let now = common_utils::date_time::now();
+    let expires_in = req.expires_in.unwrap_or(consts::DEFAULT_PAYOUT_HOLD_EXPIRY);
+    let hold = state
+        .store
+        .insert_payout_hold(storage::PayoutHoldNew {
+            hold_id: utils::generate_id(consts::ID_LENGTH, "payout_hold"),
+            merchant_id: merchant_id.to_owned(),
+            amount: req.amount,
+            currency: req.currency,
+            status: storage_enums::PayoutHoldStatus::Held,
+            payout_id: None,
+            description: req.description,
+            expires_at: now.saturating_add(time::Duration::seconds(i64::from(expires_in))),
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Payout hold already exists".to_string(),
+        })?;
+
+    add_payout_hold_expiry_task(&*state.store, &hold)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding payout hold expiry task")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_hold_response(hold),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payout_hold(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutHoldId,
+) -> RouterResponse<payouts::PayoutHoldResponse> {
+    let hold = find_payout_hold(&state, &merchant_account.merchant_id, &req.hold_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_hold_response(hold),
+    ))
+}
+
+/// Converts a hold into a payout of at most the held amount, in the currency of the hold. The hold
+/// is captured once the payout is created, whatever the status of the payout, and the part of the
+/// held amount which was not paid out is no longer held.
+#[instrument(skip_all)]
+pub async fn capture_payout_hold(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutHoldCaptureRequest,
+    created_by: Option<String>,
+) -> RouterResponse<payouts::PayoutHoldResponse> {
+    let merchant_id = &merchant_account.merchant_id;
+    let hold = find_payout_hold(&state, merchant_id, &req.hold_id).await?;
+    validate_payout_hold_is_held(&hold)?;
+    let payout_req = get_payout_hold_capture_payout_request(&hold, req.payout)?;
+
+    let payout = match Box::pin(super::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store,
+        payout_req,
+        created_by,
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(payout) => payout,
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while creating the payout of the hold")?,
+    };
+
+    let hold = state
+        .store
+        .update_payout_hold_if_held(
+            hold,
+            storage::PayoutHoldUpdate::StatusUpdate {
+                status: storage_enums::PayoutHoldStatus::Captured,
+                payout_id: Some(payout.payout_id.clone()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payout hold")?
+        .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable_lazy(|| {
+            format!(
+                "The hold was updated while payout {} was created from it",
+                payout.payout_id
+            )
+        })?;
+    revoke_payout_hold_expiry_task_if_possible(&*state.store, &hold).await;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_hold_response(hold),
+    ))
+}
+
+/// Cancels a hold, making the held amount available again
+#[instrument(skip_all)]
+pub async fn release_payout_hold(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutHoldId,
+) -> RouterResponse<payouts::PayoutHoldResponse> {
+    let hold = find_payout_hold(&state, &merchant_account.merchant_id, &req.hold_id).await?;
+    validate_payout_hold_is_held(&hold)?;
+
+    let hold = state
+        .store
+        .update_payout_hold_if_held(
+            hold,
+            storage::PayoutHoldUpdate::StatusUpdate {
+                status: storage_enums::PayoutHoldStatus::Released,
+                payout_id: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payout hold")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The hold was captured or expired while it was being released".to_string(),
+            })
+        })?;
+    revoke_payout_hold_expiry_task_if_possible(&*state.store, &hold).await;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_hold_response(hold),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payout_hold_balance(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<payouts::PayoutHoldBalanceResponse> {
+    let balances = get_payout_hold_balances(&state, &merchant_account.merchant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutHoldBalanceResponse { balances },
+    ))
+}
+
+/// Marks a hold which was neither captured nor released as expired, making the held amount
+/// available again. Returns whether the hold was expired.
+pub async fn expire_payout_hold(
+    state: &SessionState,
+    merchant_id: &str,
+    hold_id: &str,
+) -> RouterResult<bool> {
+    let hold = find_payout_hold(state, merchant_id, hold_id).await?;
+    if hold.status != storage_enums::PayoutHoldStatus::Held {
+        return Ok(false);
+    }
+
+    Ok(state
+        .store
+        .update_payout_hold_if_held(
+            hold,
+            storage::PayoutHoldUpdate::StatusUpdate {
+                status: storage_enums::PayoutHoldStatus::Expired,
+                payout_id: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payout hold")?
+        .is_some())
+}
+
+/// The lock under which a hold is captured, released or expired
+pub fn get_payout_hold_lock_action(hold_id: &str) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: hold_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Payouts,
+            override_lock_retries: None,
+        },
+    }
+}
+
+/// The lock under which the holds of a merchant are created
+pub fn get_payout_holds_create_lock_action() -> api_locking::LockAction {
+    get_payout_hold_lock_action(PAYOUT_HOLDS_LOCKING_KEY)
+}
+
+fn validate_payout_hold_create_request(req: &payouts::PayoutHoldCreateRequest) -> RouterResult<()> {
+    utils::when(req.amount <= MinorUnit::new(0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero".to_string(),
+        }))
+    })?;
+    utils::when(
+        req.expires_in.is_some_and(|expires_in| {
+            expires_in == 0 || expires_in > consts::MAX_PAYOUT_HOLD_EXPIRY
+        }),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "expires_in must be between 1 and {} seconds",
+                    consts::MAX_PAYOUT_HOLD_EXPIRY
+                ),
+            }))
+        },
+    )?;
+    utils::when(
+        req.description.as_ref().is_some_and(|description| {
+            description.len() > consts::PAYOUT_HOLD_DESCRIPTION_MAX_LENGTH
+        }),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "description must be at most {} characters long",
+                    consts::PAYOUT_HOLD_DESCRIPTION_MAX_LENGTH
+                ),
+            }))
+        },
+    )
+}
+
+fn validate_payout_hold_is_held(hold: &storage::PayoutHold) -> RouterResult<()> {
+    let status = get_payout_hold_status(hold, common_utils::date_time::now());
+    utils::when(status != storage_enums::PayoutHoldStatus::Held, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("The hold is {status}, only held amounts can be captured or released"),
+        }))
+    })
+}
+
+/// Returns the request for the payout of a hold, which is in the currency of the hold and of the
+/// held amount unless a smaller amount is passed
+fn get_payout_hold_capture_payout_request(
+    hold: &storage::PayoutHold,
+    mut payout_req: payouts::PayoutCreateRequest,
+) -> RouterResult<payouts::PayoutCreateRequest> {
+    let currency = *payout_req.currency.get_or_insert(hold.currency);
+    utils::when(currency != hold.currency, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "currency must be {}, the currency of the hold",
+                hold.currency
+            ),
+        }))
+    })?;
+    let amount = MinorUnit::from(*payout_req.amount.get_or_insert(hold.amount.into()));
+    utils::when(amount > hold.amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("amount must be at most {}, the held amount", hold.amount),
+        }))
+    })?;
+    Ok(payout_req)
+}
+
+/// Returns the status of the hold, holds which are still held being expired once their expiry
+/// time has passed, even if the expiry task has not run yet
+fn get_payout_hold_status(
+    hold: &storage::PayoutHold,
+    now: time::PrimitiveDateTime,
+) -> storage_enums::PayoutHoldStatus {
+    match hold.status {
+        storage_enums::PayoutHoldStatus::Held if now >= hold.expires_at => {
+            storage_enums::PayoutHoldStatus::Expired
+        }
+        status => status,
+    }
+}
+
+async fn get_payout_hold_balances(
+    state: &SessionState,
+    merchant_id: &str,
+) -> RouterResult<Vec<payouts::PayoutHoldBalance>> {
+    let funding_amounts = get_payout_funding_amounts(&*state.store, merchant_id).await?;
+    let holds = state
+        .store
+        .list_payout_holds_by_merchant_id_status(merchant_id, storage_enums::PayoutHoldStatus::Held)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payout holds")?;
+
+    Ok(compute_payout_hold_balances(
+        funding_amounts.as_ref(),
+        &holds,
+        common_utils::date_time::now(),
+    ))
+}
+
+/// Returns the held and available amounts per currency, for the currencies in which funds are
+/// configured or amounts are held. The available amount is what is left of the funds after the
+/// active holds, and is never negative.
+fn compute_payout_hold_balances(
+    funding_amounts: Option<&HashMap<storage_enums::Currency, MinorUnit>>,
+    holds: &[storage::PayoutHold],
+    now: time::PrimitiveDateTime,
+) -> Vec<payouts::PayoutHoldBalance> {
+    let mut held_amounts = BTreeMap::<String, (storage_enums::Currency, i64)>::new();
+    for currency in funding_amounts.into_iter().flat_map(HashMap::keys) {
+        held_amounts.insert(currency.to_string(), (*currency, 0));
+    }
+    for hold in holds
+        .iter()
+        .filter(|hold| get_payout_hold_status(hold, now) == storage_enums::PayoutHoldStatus::Held)
+    {
+        let (_, held_amount) = held_amounts
+            .entry(hold.currency.to_string())
+            .or_insert((hold.currency, 0));
+        *held_amount = held_amount.saturating_add(hold.amount.get_amount_as_i64());
+    }
+
+    held_amounts
+        .into_values()
+        .map(|(currency, held_amount)| {
+            let funding_amount = funding_amounts
+                .map(|funding_amounts| funding_amounts.get(&currency).copied().unwrap_or_default());
+            payouts::PayoutHoldBalance {
+                currency,
+                funding_amount,
+                held_amount: MinorUnit::new(held_amount),
+                available_amount: funding_amount.map(|funding_amount| {
+                    MinorUnit::new(
+                        funding_amount
+                            .get_amount_as_i64()
+                            .saturating_sub(held_amount)
+                            .max(0),
+                    )
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Returns the funds of the merchant for payouts, if the merchant has configured them.
+///
+/// The funds are configured per currency, in the lowest denomination of the currency, as a JSON
+/// object such as `{"USD": 1000000, "EUR": 500000}` in the `payout_funding_balance_{merchant_id}`
+/// config. Once configured, amounts can only be held in the configured currencies.
+async fn get_payout_funding_amounts(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<HashMap<storage_enums::Currency, MinorUnit>>> {
+    let key = format!("payout_funding_balance_{}", merchant_id);
+    match db.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct::<HashMap<storage_enums::Currency, MinorUnit>>("PayoutFundingBalance")
+            .map(Some)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse payout funding balance config"),
+        Err(err) if err.current_context().is_db_not_found() => Ok(None),
+        Err(err) => Err(err)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payout funding balance config"),
+    }
+}
+
+async fn find_payout_hold(
+    state: &SessionState,
+    merchant_id: &str,
+    hold_id: &str,
+) -> RouterResult<storage::PayoutHold> {
+    state
+        .store
+        .find_payout_hold_by_merchant_id_hold_id(merchant_id, hold_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout hold {hold_id} not found"),
+        })
+}
+
+fn get_payout_hold_response(hold: storage::PayoutHold) -> payouts::PayoutHoldResponse {
+    let status = get_payout_hold_status(&hold, common_utils::date_time::now());
+    payouts::PayoutHoldResponse {
+        hold_id: hold.hold_id,
+        amount: hold.amount,
+        currency: hold.currency,
+        status,
+        payout_id: hold.payout_id,
+        description: hold.description,
+        expires_at: hold.expires_at,
+        created_at: hold.created_at,
+    }
+}
+
+fn get_payout_hold_expiry_process_tracker_id(hold: &storage::PayoutHold) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PayoutHoldExpiryWorkflow,
+        PAYOUT_HOLD_EXPIRY_TASK,
+        &hold.hold_id,
+        &hold.merchant_id,
+    )
+}
+
+async fn add_payout_hold_expiry_task(
+    db: &dyn StorageInterface,
+    hold: &storage::PayoutHold,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutHoldExpiryWorkflow;
+    let tag = ["PAYOUTS", "HOLD"];
+    let process_tracker_id = get_payout_hold_expiry_process_tracker_id(hold);
+    let tracking_data = PayoutHoldExpiryTrackingData {
+        merchant_id: hold.merchant_id.to_owned(),
+        hold_id: hold.hold_id.to_owned(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYOUT_HOLD_EXPIRY_TASK,
+        runner,
+        tag,
+        tracking_data,
+        hold.expires_at,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Revokes the expiry task of a hold which is no longer held. Failing to do so is only logged, as
+/// the task leaves holds which are no longer held as they are.
+async fn revoke_payout_hold_expiry_task_if_possible(
+    db: &dyn StorageInterface,
+    hold: &storage::PayoutHold,
+) {
+    let task_ids = vec![get_payout_hold_expiry_process_tracker_id(hold)];
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::StatusUpdate {
+        status: storage_enums::ProcessTrackerStatus::Finish,
+        business_status: Some(String::from(diesel_models::business_status::REVOKED)),
+    };
+    let _ = db
+        .process_tracker_update_process_status_by_ids(task_ids, updated_process_tracker_data)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to revoke the payout hold expiry task"));
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_hold(
+        hold_id: &str,
+        amount: i64,
+        currency: storage_enums::Currency,
+        expires_at: time::PrimitiveDateTime,
+    ) -> storage::PayoutHold {
+        storage::PayoutHold {
+            hold_id: hold_id.to_string(),
+            merchant_id: "merchant_1".to_string(),
+            amount: MinorUnit::new(amount),
+            currency,
+            status: storage_enums::PayoutHoldStatus::Held,
+            payout_id: None,
+            description: None,
+            expires_at,
+            created_at: expires_at - time::Duration::days(1),
+            modified_at: expires_at - time::Duration::days(1),
+        }
+    }
+
+    #[test]
+    fn test_compute_payout_hold_balances() {
+        let now = common_utils::date_time::now();
+        let holds = vec![
+            get_hold(
+                "payout_hold_1",
+                3000,
+                storage_enums::Currency::USD,
+                now + time::Duration::hours(1),
+            ),
+            get_hold(
+                "payout_hold_2",
+                2000,
+                storage_enums::Currency::USD,
+                now + time::Duration::hours(1),
+            ),
+            // Expired holds no longer count against the funds, even before the expiry task runs
+            get_hold(
+                "payout_hold_3",
+                5000,
+                storage_enums::Currency::USD,
+                now - time::Duration::hours(1),
+            ),
+            get_hold(
+                "payout_hold_4",
+                700,
+                storage_enums::Currency::EUR,
+                now + time::Duration::hours(1),
+            ),
+        ];
+        let funding_amounts = HashMap::from([
+            (storage_enums::Currency::USD, MinorUnit::new(10000)),
+            (storage_enums::Currency::GBP, MinorUnit::new(4000)),
+        ]);
+
+        assert_eq!(
+            compute_payout_hold_balances(Some(&funding_amounts), &holds, now),
+            vec![
+                payouts::PayoutHoldBalance {
+                    currency: storage_enums::Currency::EUR,
+                    funding_amount: Some(MinorUnit::new(0)),
+                    held_amount: MinorUnit::new(700),
+                    available_amount: Some(MinorUnit::new(0)),
+                },
+                payouts::PayoutHoldBalance {
+                    currency: storage_enums::Currency::GBP,
+                    funding_amount: Some(MinorUnit::new(4000)),
+                    held_amount: MinorUnit::new(0),
+                    available_amount: Some(MinorUnit::new(4000)),
+                },
+                payouts::PayoutHoldBalance {
+                    currency: storage_enums::Currency::USD,
+                    funding_amount: Some(MinorUnit::new(10000)),
+                    held_amount: MinorUnit::new(5000),
+                    available_amount: Some(MinorUnit::new(5000)),
+                },
+            ]
+        );
+        assert_eq!(
+            compute_payout_hold_balances(None, &holds[3..], now),
+            vec![payouts::PayoutHoldBalance {
+                currency: storage_enums::Currency::EUR,
+                funding_amount: None,
+                held_amount: MinorUnit::new(700),
+                available_amount: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_get_payout_hold_capture_payout_request() {
+        let now = common_utils::date_time::now();
+        let hold = get_hold("payout_hold_1", 3000, storage_enums::Currency::USD, now);
+
+        let payout_req =
+            get_payout_hold_capture_payout_request(&hold, payouts::PayoutCreateRequest::default())
+                .unwrap();
+        assert_eq!(payout_req.currency, Some(storage_enums::Currency::USD));
+        assert_eq!(
+            payout_req.amount.map(MinorUnit::from),
+            Some(MinorUnit::new(3000))
+        );
+
+        let partial = payouts::PayoutCreateRequest {
+            amount: Some(MinorUnit::new(1000).into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            get_payout_hold_capture_payout_request(&hold, partial)
+                .unwrap()
+                .amount
+                .map(MinorUnit::from),
+            Some(MinorUnit::new(1000))
+        );
+
+        let above_hold = payouts::PayoutCreateRequest {
+            amount: Some(MinorUnit::new(3001).into()),
+            ..Default::default()
+        };
+        assert!(get_payout_hold_capture_payout_request(&hold, above_hold).is_err());
+
+        let other_currency = payouts::PayoutCreateRequest {
+            currency: Some(storage_enums::Currency::EUR),
+            ..Default::default()
+        };
+        assert!(get_payout_hold_capture_payout_request(&hold, other_currency).is_err());
+    }
+
+    #[test]
+    fn test_get_payout_hold_status() {
+        let now = common_utils::date_time::now();
+        let mut hold = get_hold("payout_hold_1", 3000, storage_enums::Currency::USD, now);
+
+        assert_eq!(
+            get_payout_hold_status(&hold, now - time::Duration::seconds(1)),
+            storage_enums::PayoutHoldStatus::Held
+        );
+        assert_eq!(
+            get_payout_hold_status(&hold, now),
+            storage_enums::PayoutHoldStatus::Expired
+        );
+        hold.status = storage_enums::PayoutHoldStatus::Captured;
+        assert_eq!(
+            get_payout_hold_status(&hold, now),
+            storage_enums::PayoutHoldStatus::Captured
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use common_utils::types::MinorUnit;
+use futures::future::join_all;
+use router_env::{instrument, logger, tracing};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::{
+    consts,
+    routes::SessionState,
+    types::api::{enums as api_enums, payouts},
+    utils::currency,
+};
+
+/// Converts the amount of each of the listed payouts to the reporting currency, using the rates
+/// as of the date on which the payout was created.
+///
+/// The rates of a date are the last rates fetched on that date, in UTC. Payouts created on a date
+/// for which no rates were fetched are reported without an amount in the reporting currency, and
+/// payouts already in the reporting currency are reported with their own amount.
+#[instrument(skip_all)]
+pub async fn convert_payouts_to_reporting_currency(
+    state: &SessionState,
+    payouts: &[payouts::PayoutCreateResponse],
+    reporting_currency: api_enums::Currency,
+) -> Vec<payouts::PayoutReportingAmount> {
+    let dates = payouts
+        .iter()
+        .filter(|payout| payout.currency != reporting_currency)
+        .filter_map(|payout| get_creation_date(payout.created))
+        .collect::<HashSet<_>>();
+    let rates = join_all(dates.into_iter().map(|date| async move {
+        currency::get_forex_rates_snapshot(state, date)
+            .await
+            .map_err(|error| {
+                logger::warn!(
+                    ?error,
+                    "Failed to fetch the forex rates of {date} for payouts report"
+                )
+            })
+            .ok()
+            .flatten()
+            .map(|rates| (date, rates))
+    }))
+    .await
+    .into_iter()
+    .flatten()
+    .collect::<HashMap<_, _>>();
+
+    payouts
+        .iter()
+        .map(|payout| {
+            let conversion = get_creation_date(payout.created)
+                .and_then(|date| rates.get(&date))
+                .filter(|_| payout.currency != reporting_currency)
+                .and_then(|rates| {
+                    currency::convert_currency_using_rates(
+                        rates,
+                        None,
+                        payout.amount.get_amount_as_i64(),
+                        reporting_currency.to_string(),
+                        payout.currency.to_string(),
+                        consts::DEFAULT_FOREX_RATE_PRECISION,
+                    )
+                    .map_err(|error| {
+                        logger::warn!(
+                            ?error,
+                            "Failed to convert the amount of payout {} for payouts report",
+                            payout.payout_id
+                        )
+                    })
+                    .ok()
+                });
+            let (reporting_amount, rate) = get_reporting_amount(
+                payout.amount,
+                payout.currency,
+                reporting_currency,
+                conversion.as_ref(),
+            );
+            payouts::PayoutReportingAmount {
+                payout_id: payout.payout_id.clone(),
+                amount: payout.amount,
+                currency: payout.currency,
+                reporting_currency,
+                reporting_amount,
+                rate,
+            }
+        })
+        .collect()
+}
+
+fn get_creation_date(created: Option<time::OffsetDateTime>) -> Option<time::Date> {
+    created.map(|created| created.to_offset(time::UtcOffset::UTC).date())
+}
+
+/// Returns the amount in the reporting currency along with the rate it was converted at.
+fn get_reporting_amount(
+    amount: MinorUnit,
+    currency: api_enums::Currency,
+    reporting_currency: api_enums::Currency,
+    conversion: Option<&api_models::currency::CurrencyConversionResponse>,
+) -> (Option<MinorUnit>, Option<String>) {
+    if currency == reporting_currency {
+        return (Some(amount), None);
+    }
+    conversion
+        .and_then(|conversion| {
+            get_converted_minor_amount(&conversion.converted_amount, reporting_currency)
+                .map(|reporting_amount| (Some(reporting_amount), Some(conversion.rate.clone())))
+        })
+        .unwrap_or_default()
+}
+
+/// The converted amount is in the major unit of the currency
+fn get_converted_minor_amount(
+    converted_amount: &str,
+    currency: api_enums::Currency,
+) -> Option<MinorUnit> {
+    let minor_unit_factor =
+        Decimal::from(10_i64.pow(u32::from(currency.number_of_digits_after_decimal_point())));
+    Decimal::from_str(converted_amount)
+        .ok()?
+        .checked_mul(minor_unit_factor)?
+        .round()
+        .to_i64()
+        .map(MinorUnit::new)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_reporting_amount() {
+        let conversion = api_models::currency::CurrencyConversionResponse {
+            amount: MinorUnit::new(10000),
+            from_currency: "EUR".to_string(),
+            to_currency: "USD".to_string(),
+            converted_amount: "108.1081".to_string(),
+            currency: "USD".to_string(),
+            rate: "1.081081".to_string(),
+            provider: None,
+            formatted_amount: None,
+            formatted_converted_amount: None,
+            base_rate: None,
+            markup_bps: None,
+            rates_timestamp: 1_720_000_000,
+            rate_path: None,
+        };
+        let amount = MinorUnit::new(10000);
+
+        assert_eq!(
+            get_reporting_amount(
+                amount,
+                api_enums::Currency::EUR,
+                api_enums::Currency::USD,
+                Some(&conversion),
+            ),
+            (Some(MinorUnit::new(10811)), Some("1.081081".to_string()))
+        );
+        assert_eq!(
+            get_reporting_amount(
+                amount,
+                api_enums::Currency::EUR,
+                api_enums::Currency::USD,
+                None,
+            ),
+            (None, None)
+        );
+        assert_eq!(
+            get_reporting_amount(
+                amount,
+                api_enums::Currency::USD,
+                api_enums::Currency::USD,
+                None,
+            ),
+            (Some(amount), None)
+        );
+    }
+
+    #[test]
+    fn test_get_creation_date_is_in_utc() {
+        // 2024-07-03T23:30:00+05:30 is 2024-07-03T18:00:00Z
+        let created = time::OffsetDateTime::from_unix_timestamp(1_720_029_600)
+            .unwrap()
+            .to_offset(time::UtcOffset::from_hms(5, 30, 0).unwrap());
+        // 2024-07-03T23:30:00-05:00 is 2024-07-04T04:30:00Z
+        let created_behind_utc = time::OffsetDateTime::from_unix_timestamp(1_720_067_400)
+            .unwrap()
+            .to_offset(time::UtcOffset::from_hms(-5, 0, 0).unwrap());
+
+        assert_eq!(
+            get_creation_date(Some(created)),
+            Some(time::macros::date!(2024 - 07 - 03))
+        );
+        assert_eq!(
+            get_creation_date(Some(created_behind_utc)),
+            Some(time::macros::date!(2024 - 07 - 04))
+        );
+        assert_eq!(get_creation_date(None), None);
+    }
+}
@@ -86,7 +86,10 @@ pub async fn do_gsm_multiple_connector_actions(
                     ),
                 }))?
             }
-            api_models::gsm::GsmDecision::DoDefault => break,
+            // Destination failures are not retried with the connectors, they are handled by the
+            // destination failover of the payout
+            api_models::gsm::GsmDecision::DoDefault
+            | api_models::gsm::GsmDecision::RetryWithNextDestination => break,
         }
     }
     Ok(())
@@ -150,7 +153,10 @@ pub async fn do_gsm_single_connector_actions(
                     ),
                 }))?
             }
-            api_models::gsm::GsmDecision::DoDefault => break,
+            // Destination failures are not retried with the connectors, they are handled by the
+            // destination failover of the payout
+            api_models::gsm::GsmDecision::DoDefault
+            | api_models::gsm::GsmDecision::RetryWithNextDestination => break,
         }
     }
     Ok(())
@@ -356,12 +362,16 @@ impl GsmValidation for PayoutData {
             | common_enums::PayoutStatus::Pending
             | common_enums::PayoutStatus::Initiated
             | common_enums::PayoutStatus::Reversed
+            | common_enums::PayoutStatus::Recalled
             | common_enums::PayoutStatus::Expired
             | common_enums::PayoutStatus::Ineligible
             | common_enums::PayoutStatus::RequiresCreation
             | common_enums::PayoutStatus::RequiresPayoutMethodData
             | common_enums::PayoutStatus::RequiresVendorAccountCreation
-            | common_enums::PayoutStatus::RequiresFulfillment => false,
+            | common_enums::PayoutStatus::RequiresFulfillment
+            | common_enums::PayoutStatus::Scheduled
+            | common_enums::PayoutStatus::PendingApproval
+            | common_enums::PayoutStatus::PendingVerification => false,
             common_enums::PayoutStatus::Failed => true,
         }
     }
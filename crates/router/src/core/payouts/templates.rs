@@ -0,0 +1,258 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::validator;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{
+        api::{enums as api_enums, payouts},
+        domain, storage,
+    },
+    utils,
+};
+
+const PAYOUT_TEMPLATE_NAME_MAX_LENGTH: usize = 64;
+
+#[instrument(skip_all)]
+pub async fn create_payout_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutTemplateCreateRequest,
+) -> RouterResponse<payouts::PayoutTemplateResponse> {
+    let name = req.name.trim();
+    utils::when(
+        name.is_empty() || name.len() > PAYOUT_TEMPLATE_NAME_MAX_LENGTH,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "name must be between 1 and {PAYOUT_TEMPLATE_NAME_MAX_LENGTH} characters long"
+                ),
+            }))
+        },
+    )?;
+    if let Some(description) = &req.description {
+        validator::validate_payout_description(description)?;
+    }
+    let connector = validator::validate_payout_connectors(req.connector)?
+        .map(|connector| connector.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the connectors of the payout template")?;
+
+    let template = storage::PayoutTemplateNew {
+        template_id: common_utils::generate_id(consts::ID_LENGTH, "payout_template"),
+        merchant_id: merchant_account.merchant_id,
+        name: name.to_string(),
+        currency: req.currency,
+        payout_type: req.payout_type,
+        entity_type: req.entity_type,
+        connector,
+        description: req.description,
+        priority: req.priority,
+        created_at: common_utils::date_time::now(),
+    };
+
+    let template = state
+        .store
+        .insert_payout_template(template)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Payout template already exists".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_template_response(template)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payout_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutTemplateId,
+) -> RouterResponse<payouts::PayoutTemplateResponse> {
+    let template =
+        find_payout_template(&state, &merchant_account.merchant_id, &req.template_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_template_response(template)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_payout_templates(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<payouts::PayoutTemplateListResponse> {
+    let data = state
+        .store
+        .list_payout_templates_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payout templates of the merchant")?
+        .into_iter()
+        .map(get_payout_template_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutTemplateListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_payout_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutTemplateId,
+) -> RouterResponse<payouts::PayoutTemplateResponse> {
+    let template = state
+        .store
+        .delete_payout_template_by_merchant_id_template_id(
+            &merchant_account.merchant_id,
+            &req.template_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout template {} not found", req.template_id),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_template_response(template)?,
+    ))
+}
+
+/// Fills in the fields of a payout create request which were not passed, from the template
+/// referenced by `template_id`. Fields passed in the request always take precedence, and the
+/// connectors of the template are used only when neither `connector` nor `routing` is passed.
+#[instrument(skip_all)]
+pub async fn apply_payout_template(
+    state: &SessionState,
+    merchant_id: &str,
+    req: &mut payouts::PayoutCreateRequest,
+) -> RouterResult<()> {
+    let Some(template_id) = req.template_id.as_deref() else {
+        return Ok(());
+    };
+    let template = find_payout_template(state, merchant_id, template_id).await?;
+    let template_connector = get_template_connectors(template.connector)?;
+    merge_payout_template(
+        req,
+        payouts::PayoutTemplateCreateRequest {
+            name: template.name,
+            currency: template.currency,
+            connector: template_connector,
+            payout_type: template.payout_type,
+            entity_type: template.entity_type,
+            description: template.description,
+            priority: template.priority,
+        },
+    );
+    Ok(())
+}
+
+fn merge_payout_template(
+    req: &mut payouts::PayoutCreateRequest,
+    template: payouts::PayoutTemplateCreateRequest,
+) {
+    req.currency = req.currency.or(template.currency);
+    req.payout_type = req.payout_type.or(template.payout_type);
+    req.priority = req.priority.or(template.priority);
+    if req.description.is_none() {
+        req.description = template.description;
+    }
+    if req.connector.is_none() && req.routing.is_none() {
+        req.connector = template.connector;
+    }
+    let has_entity_type = req.entity_type.is_some()
+        || req
+            .recipient
+            .as_ref()
+            .is_some_and(|recipient| recipient.entity_type.is_some());
+    if !has_entity_type {
+        req.entity_type = template.entity_type;
+    }
+}
+
+async fn find_payout_template(
+    state: &SessionState,
+    merchant_id: &str,
+    template_id: &str,
+) -> RouterResult<storage::PayoutTemplate> {
+    state
+        .store
+        .find_payout_template_by_merchant_id_template_id(merchant_id, template_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout template {template_id} not found"),
+        })
+}
+
+fn get_template_connectors(
+    connector: Option<serde_json::Value>,
+) -> RouterResult<Option<Vec<api_enums::PayoutConnectors>>> {
+    connector
+        .map(|connector| connector.parse_value("PayoutConnectors"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connectors of the payout template")
+}
+
+fn get_payout_template_response(
+    template: storage::PayoutTemplate,
+) -> RouterResult<payouts::PayoutTemplateResponse> {
+    Ok(payouts::PayoutTemplateResponse {
+        connector: get_template_connectors(template.connector)?,
+        template_id: template.template_id,
+        merchant_id: template.merchant_id,
+        name: template.name,
+        currency: template.currency,
+        payout_type: template.payout_type,
+        entity_type: template.entity_type,
+        description: template.description,
+        priority: template.priority,
+        created_at: template.created_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_merge_payout_template_keeps_request_fields() {
+        let mut req = payouts::PayoutCreateRequest {
+            currency: Some(api_enums::Currency::EUR),
+            routing: Some(serde_json::json!({"type": "single", "data": "adyen"})),
+            recipient: Some(payouts::PayoutRecipient {
+                entity_type: Some(api_enums::PayoutEntityType::Individual),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let template = payouts::PayoutTemplateCreateRequest {
+            name: "vendors".to_string(),
+            currency: Some(api_enums::Currency::USD),
+            connector: Some(vec![api_enums::PayoutConnectors::Wise]),
+            payout_type: Some(api_enums::PayoutType::Bank),
+            entity_type: Some(api_enums::PayoutEntityType::Company),
+            description: Some("Vendor payment".to_string()),
+            priority: None,
+        };
+
+        merge_payout_template(&mut req, template);
+
+        assert_eq!(req.currency, Some(api_enums::Currency::EUR));
+        assert_eq!(req.payout_type, Some(api_enums::PayoutType::Bank));
+        assert_eq!(req.description.as_deref(), Some("Vendor payment"));
+        assert!(req.connector.is_none());
+        assert!(req.entity_type.is_none());
+    }
+}
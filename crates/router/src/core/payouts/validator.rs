@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use api_models::admin;
 #[cfg(feature = "olap")]
 use common_utils::errors::CustomResult;
@@ -5,6 +7,7 @@ use common_utils::{
     ext_traits::ValueExt,
     id_type::CustomerId,
     link_utils::{GenericLinkStatus, GenericLinkUiConfig, PayoutLinkData, PayoutLinkStatus},
+    pii,
     types::MinorUnit,
 };
 use diesel_models::{
@@ -13,20 +16,26 @@ use diesel_models::{
 };
 use error_stack::{report, ResultExt};
 pub use hyperswitch_domain_models::errors::StorageError;
-use masking::Secret;
-use router_env::{instrument, tracing};
-use time::Duration;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use time::{Duration, PrimitiveDateTime};
 
 use super::helpers;
 use crate::{
+    configs::settings::PayoutPurposeCodes,
     consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        payments::helpers as payment_helpers,
         utils as core_utils,
+        webhooks::utils as webhooks_utils,
     },
     db::StorageInterface,
     routes::SessionState,
-    types::{api::payouts, domain, storage},
+    types::{
+        api::{enums as api_enums, payouts},
+        domain, storage,
+    },
     utils::{self, OptionExt},
 };
 
@@ -58,6 +67,12 @@ pub async fn validate_uniqueness_of_payout_id_against_merchant_id(
 /// - merchant_id passed is same as the one in merchant_account table
 /// - payout_id is unique against merchant_id
 /// - payout_token provided is legitimate
+/// - network token details are present and well formed
+/// - destination country is not denied for payouts
+/// - purpose code is allowed for the destination country
+/// - settlement network serves the destination currency and country
+/// - metadata carries the keys required by the merchant
+/// - value date is a business day which is not in the past
 pub async fn validate_create_request(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
@@ -72,6 +87,33 @@ pub async fn validate_create_request(
         }
     };
 
+    if let Some(scheduled_at) = req.scheduled_at {
+        validate_payout_schedule(scheduled_at, req.confirm)?;
+    }
+
+    if let Some(description) = &req.description {
+        validate_payout_description(description)?;
+    }
+
+    if let Some(value_date) = req.value_date {
+        let holidays = req
+            .currency
+            .and_then(|currency| state.conf.payouts.bank_holidays.get(&currency))
+            .map(|holidays| &holidays.dates);
+        validate_payout_value_date(
+            value_date,
+            req.payout_type,
+            common_utils::date_time::now().date(),
+            holidays,
+        )?;
+    }
+
+    if let Some(recipient) = &req.recipient {
+        validate_payout_recipient(recipient)?;
+    }
+
+    validate_payout_amount_and_currency(req)?;
+
     // Merchant ID
     let predicate = req.merchant_id.as_ref().map(|mid| mid != merchant_id);
     utils::when(predicate.unwrap_or(false), || {
@@ -84,7 +126,11 @@ pub async fn validate_create_request(
 
     // Payout ID
     let db: &dyn StorageInterface = &*state.store;
-    let payout_id = core_utils::get_or_generate_uuid("payout_id", req.payout_id.as_ref())?;
+    let payout_id = if helpers::config_should_require_payout_id(db, merchant_id).await {
+        validate_client_provided_payout_id(req.payout_id.as_ref())?
+    } else {
+        core_utils::get_or_generate_uuid("payout_id", req.payout_id.as_ref())?
+    };
     match validate_uniqueness_of_payout_id_against_merchant_id(
         db,
         &payout_id,
@@ -105,6 +151,71 @@ pub async fn validate_create_request(
         None => Ok(()),
     }?;
 
+    // Merchant reference ID
+    if let Some(merchant_reference_id) = &req.merchant_reference_id {
+        validate_payout_merchant_reference_id(merchant_reference_id)?;
+        if helpers::config_should_enforce_unique_merchant_reference_id(db, merchant_id).await {
+            let existing_payout = db
+                .find_optional_payout_by_merchant_id_merchant_reference_id(
+                    merchant_id,
+                    merchant_reference_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find payout by merchant_reference_id")?;
+            if let Some(existing_payout) = existing_payout {
+                return Err(report!(errors::ApiErrorResponse::GenericDuplicateError {
+                    message: format!(
+                        "merchant_reference_id {merchant_reference_id} is already used by payout {}",
+                        existing_payout.payout_id
+                    ),
+                }));
+            }
+        }
+    }
+
+    // Series ID
+    req.series_id
+        .as_deref()
+        .map(validate_payout_series_id)
+        .transpose()?;
+
+    // Metadata keys required by the merchant
+    let required_metadata_keys =
+        helpers::get_payout_required_metadata_keys(db, merchant_id).await?;
+    validate_required_metadata_keys(req.metadata.as_ref(), &required_metadata_keys)?;
+
+    // Store credit is disbursed to the customer of the payout, which must exist
+    let store_credit = req
+        .payout_method_data
+        .as_ref()
+        .map(|payout_method_data| {
+            validate_store_credit_payout(
+                req.payout_type,
+                Some(payout_method_data),
+                req.customer_id.as_ref(),
+            )
+        })
+        .transpose()?
+        .flatten();
+    if let Some(store_credit) = store_credit {
+        db.find_customer_by_customer_id_merchant_id(
+            &store_credit.customer_id,
+            merchant_id,
+            merchant_key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
+        .attach_printable("customer for store credit payout not found")?;
+    }
+
+    // Network token
+    if let Some(payouts::PayoutMethodData::NetworkToken(network_token)) = &req.payout_method_data {
+        validate_network_token_payout(network_token)?;
+    }
+
     // Payout token
     let payout_method_data = match req.payout_token.to_owned() {
         Some(payout_token) => {
@@ -128,6 +239,56 @@ pub async fn validate_create_request(
         None => None,
     };
 
+    // Destination country
+    let destination_payout_method_data = payout_method_data
+        .as_ref()
+        .or(req.payout_method_data.as_ref());
+    let denied_countries = helpers::get_payout_denied_countries(state, merchant_id).await?;
+    let denied_country = req.recipient.as_ref().and_then(|recipient| {
+        get_denied_destination_country(recipient, destination_payout_method_data, &denied_countries)
+    });
+    if let Some(country) = denied_country {
+        logger::warn!(
+            "Payout to denied destination country {} blocked for merchant_id: {}",
+            country,
+            merchant_id
+        );
+        return Err(report!(
+            errors::ApiErrorResponse::PayoutDestinationCountryNotAllowed {
+                country: country.to_string(),
+            }
+        ));
+    }
+
+    // Purpose code
+    let destination_countries = req
+        .recipient
+        .as_ref()
+        .map(|recipient| get_destination_countries(recipient, destination_payout_method_data))
+        .unwrap_or_default();
+    validate_payout_purpose_code(
+        req.purpose_code.as_deref(),
+        &destination_countries,
+        &state.conf.payouts.purpose_codes,
+    )?;
+
+    // Settlement network
+    if let Some(settlement_network) = req.settlement_network {
+        validate_payout_settlement_network(
+            settlement_network,
+            req.payout_type,
+            req.currency,
+            req.priority,
+            &destination_countries,
+        )?;
+    }
+
+    // Webhook URL override
+    validate_payout_webhook_url(req.webhook_url.as_deref())?;
+
+    // Statement descriptor
+    validate_payout_statement_descriptor(req.statement_descriptor.as_deref())?;
+
     // Profile ID
     let profile_id = core_utils::get_profile_id_from_business_details(
         req.business_country,
@@ -142,6 +303,352 @@ pub async fn validate_create_request(
     Ok((payout_id, payout_method_data, profile_id))
 }
 
+/// Returns the first destination country of the payout, among the recipient's billing country
+/// and the bank's country, which is present in the denylist.
+pub fn get_denied_destination_country(
+    recipient: &payouts::PayoutRecipient,
+    payout_method_data: Option<&payouts::PayoutMethodData>,
+    denied_countries: &HashSet<api_enums::CountryAlpha2>,
+) -> Option<api_enums::CountryAlpha2> {
+    get_destination_countries(recipient, payout_method_data)
+        .into_iter()
+        .find(|country| denied_countries.contains(country))
+}
+
+/// Validates the purpose code of a payout being updated or confirmed, which is the one in the
+/// request or else the one stored against the payout, against the destination countries of the
+/// payout once the request is applied.
+pub fn validate_payout_purpose_code_for_update(
+    req: &payouts::PayoutCreateRequest,
+    stored_purpose_code: Option<&str>,
+    payout_method_data: Option<&payouts::PayoutMethodData>,
+    purpose_codes: &HashMap<api_enums::CountryAlpha2, PayoutPurposeCodes>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let destination_payout_method_data = req.payout_method_data.as_ref().or(payout_method_data);
+    let destination_countries = req
+        .recipient
+        .as_ref()
+        .map(|recipient| get_destination_countries(recipient, destination_payout_method_data))
+        .unwrap_or_default();
+    validate_payout_purpose_code(
+        req.purpose_code.as_deref().or(stored_purpose_code),
+        &destination_countries,
+        purpose_codes,
+    )
+}
+
+/// Validates the purpose code of the payout against the codes allowed for each of its
+/// destination countries. Countries without allowed codes accept any purpose code.
+pub fn validate_payout_purpose_code(
+    purpose_code: Option<&str>,
+    destination_countries: &[api_enums::CountryAlpha2],
+    purpose_codes: &HashMap<api_enums::CountryAlpha2, PayoutPurposeCodes>,
+) -> Result<(), errors::ApiErrorResponse> {
+    if let Some(purpose_code) = purpose_code {
+        utils::when(
+            purpose_code.is_empty() || purpose_code.len() > consts::PAYOUT_PURPOSE_CODE_MAX_LENGTH,
+            || {
+                Err(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "purpose_code".to_string(),
+                    expected_format: format!(
+                        "between 1 and {} characters",
+                        consts::PAYOUT_PURPOSE_CODE_MAX_LENGTH
+                    ),
+                })
+            },
+        )?;
+    }
+    for country in destination_countries {
+        let Some(allowed_codes) = purpose_codes.get(country) else {
+            continue;
+        };
+        match purpose_code {
+            Some(purpose_code) => utils::when(!allowed_codes.codes.contains(purpose_code), || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "purpose_code {purpose_code} is not allowed for payouts to {country}"
+                    ),
+                })
+            })?,
+            None => utils::when(allowed_codes.required, || {
+                Err(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "purpose_code",
+                })
+            })?,
+        }
+    }
+    Ok(())
+}
+
+/// Validates that the settlement network is able to carry the payout, which requires a bank payout
+/// in the currency of the network to a country served by the network. A priority passed alongside
+/// the network must select the same rail as the network.
+pub fn validate_payout_settlement_network(
+    settlement_network: api_enums::PayoutSettlementNetwork,
+    payout_type: Option<api_enums::PayoutType>,
+    currency: Option<api_enums::Currency>,
+    priority: Option<api_enums::PayoutSendPriority>,
+    destination_countries: &[api_enums::CountryAlpha2],
+) -> Result<(), errors::ApiErrorResponse> {
+    utils::when(
+        payout_type.is_some_and(|payout_type| payout_type != api_enums::PayoutType::Bank),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "settlement_network is supported only for bank payouts".to_string(),
+            })
+        },
+    )?;
+    utils::when(
+        currency.is_some_and(|currency| currency != settlement_network.currency()),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The {settlement_network} settlement network supports only payouts in {}",
+                    settlement_network.currency()
+                ),
+            })
+        },
+    )?;
+    if let Some(country) = destination_countries
+        .iter()
+        .find(|country| !settlement_network.serves_country(**country))
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The {settlement_network} settlement network does not serve payouts to {country}"
+            ),
+        });
+    }
+    utils::when(
+        priority.is_some_and(|priority| {
+            (priority == api_enums::PayoutSendPriority::Instant) != settlement_network.is_instant()
+        }),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "priority does not match the {settlement_network} settlement network"
+                ),
+            })
+        },
+    )
+}
+
+/// Validates that the webhook URL override of the payout, if provided, is an absolute https URL
+/// whose host is not a loopback, link-local or private address. Domain names are resolved and
+/// checked again when the webhook is sent.
+pub fn validate_payout_webhook_url(
+    webhook_url: Option<&str>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let Some(webhook_url) = webhook_url else {
+        return Ok(());
+    };
+    let url = url::Url::parse(webhook_url)
+        .ok()
+        .filter(|url| url.scheme() == "https")
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "webhook_url must be an absolute https URL".to_string(),
+        })?;
+    let host = url
+        .host()
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "webhook_url must be an absolute https URL".to_string(),
+        })?;
+    utils::when(webhooks_utils::is_non_public_host(&host), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "webhook_url must point to a public host".to_string(),
+        })
+    })
+}
+
+/// Validates the statement descriptor of the payout, if provided. An empty descriptor is allowed,
+/// as it means the payout has no descriptor.
+pub fn validate_payout_statement_descriptor(
+    statement_descriptor: Option<&str>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let Some(statement_descriptor) = statement_descriptor else {
+        return Ok(());
+    };
+    utils::when(
+        statement_descriptor.len() > consts::PAYOUT_STATEMENT_DESCRIPTOR_MAX_LENGTH
+            || !statement_descriptor
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == ' '),
+        || {
+            Err(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "statement_descriptor".to_string(),
+                expected_format: format!(
+                    "at most {} letters, digits and spaces",
+                    consts::PAYOUT_STATEMENT_DESCRIPTOR_MAX_LENGTH
+                ),
+            })
+        },
+    )
+}
+
+/// Validates the reason reported by the bank for the recall of a payout, if provided.
+pub fn validate_payout_recall_reason(reason: Option<&str>) -> Result<(), errors::ApiErrorResponse> {
+    utils::when(
+        reason.is_some_and(|reason| reason.len() > consts::PAYOUT_RECALL_REASON_MAX_LENGTH),
+        || {
+            Err(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "reason".to_string(),
+                expected_format: format!(
+                    "at most {} characters",
+                    consts::PAYOUT_RECALL_REASON_MAX_LENGTH
+                ),
+            })
+        },
+    )
+}
+
+/// Validates the lengths of the verification reference and of the rejection reason in the result
+/// of a document verification.
+pub fn validate_payout_verification_request(
+    req: &payouts::PayoutVerificationRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    [
+        (
+            "verification_reference",
+            req.verification_reference.as_deref(),
+            consts::PAYOUT_VERIFICATION_REFERENCE_MAX_LENGTH,
+        ),
+        (
+            "reason",
+            req.reason.as_deref(),
+            consts::PAYOUT_VERIFICATION_REASON_MAX_LENGTH,
+        ),
+    ]
+    .into_iter()
+    .try_for_each(|(field_name, value, max_length)| {
+        utils::when(value.is_some_and(|value| value.len() > max_length), || {
+            Err(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: field_name.to_string(),
+                expected_format: format!("at most {max_length} characters"),
+            })
+        })
+    })
+}
+
+/// Validates the identifier of the payout in the systems of the merchant, which is made up of
+/// letters, digits, hyphens and underscores.
+pub fn validate_payout_merchant_reference_id(
+    merchant_reference_id: &str,
+) -> Result<(), errors::ApiErrorResponse> {
+    utils::when(
+        merchant_reference_id.is_empty()
+            || merchant_reference_id.len() > consts::PAYOUT_MERCHANT_REFERENCE_ID_MAX_LENGTH
+            || !merchant_reference_id.chars().all(|character| {
+                character.is_ascii_alphanumeric() || character == '-' || character == '_'
+            }),
+        || {
+            Err(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "merchant_reference_id".to_string(),
+                expected_format: format!(
+                    "between 1 and {} letters, digits, hyphens and underscores",
+                    consts::PAYOUT_MERCHANT_REFERENCE_ID_MAX_LENGTH
+                ),
+            })
+        },
+    )
+}
+
+/// Validates the identifier of the recurring series of a payout, which is made up of letters,
+/// digits, hyphens and underscores.
+pub fn validate_payout_series_id(series_id: &str) -> Result<(), errors::ApiErrorResponse> {
+    utils::when(
+        series_id.is_empty()
+            || series_id.len() > consts::PAYOUT_SERIES_ID_MAX_LENGTH
+            || !series_id.chars().all(|character| {
+                character.is_ascii_alphanumeric() || character == '-' || character == '_'
+            }),
+        || {
+            Err(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "series_id".to_string(),
+                expected_format: format!(
+                    "between 1 and {} letters, digits, hyphens and underscores",
+                    consts::PAYOUT_SERIES_ID_MAX_LENGTH
+                ),
+            })
+        },
+    )
+}
+
+/// Returns the destination countries of the payout, which are the recipient's billing country and
+/// the bank's country.
+fn get_destination_countries(
+    recipient: &payouts::PayoutRecipient,
+    payout_method_data: Option<&payouts::PayoutMethodData>,
+) -> Vec<api_enums::CountryAlpha2> {
+    let billing_country = recipient
+        .billing
+        .as_ref()
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country);
+    let bank_country = match payout_method_data {
+        Some(payouts::PayoutMethodData::Bank(bank)) => match bank {
+            payouts::Bank::Ach(ach) => ach.bank_country_code,
+            payouts::Bank::Bacs(bacs) => bacs.bank_country_code,
+            payouts::Bank::Sepa(sepa) => sepa.bank_country_code,
+            payouts::Bank::Pix(_) => None,
+        },
+        _ => None,
+    };
+    [billing_country, bank_country]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Validates the `payout_id` for merchants that require client provided ids, instead of
+/// generating one when it's missing in the request.
+pub fn validate_client_provided_payout_id(
+    payout_id: Option<&String>,
+) -> Result<String, errors::ApiErrorResponse> {
+    let payout_id = payout_id.ok_or(errors::ApiErrorResponse::MissingRequiredField {
+        field_name: "payout_id",
+    })?;
+    let is_valid_format = payout_id.len() == consts::PAYOUT_ID_LENGTH
+        && payout_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    utils::when(!is_valid_format, || {
+        Err(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "payout_id".to_string(),
+            expected_format: format!(
+                "{} characters consisting of alphanumerics, '_' or '-'",
+                consts::PAYOUT_ID_LENGTH
+            ),
+        })
+    })?;
+    Ok(payout_id.to_owned())
+}
+
+/// Removes duplicate connectors from the list passed in the request while preserving the order
+/// in which they were passed, since the order decides the connector failover sequence.
+/// An explicitly empty list is rejected, omitting the field is allowed.
+pub fn validate_payout_connectors(
+    connectors: Option<Vec<api_enums::PayoutConnectors>>,
+) -> Result<Option<Vec<api_enums::PayoutConnectors>>, errors::ApiErrorResponse> {
+    connectors
+        .map(|connectors| {
+            utils::when(connectors.is_empty(), || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message:
+                        "connector list cannot be empty, omit the field to use routing instead"
+                            .to_string(),
+                })
+            })?;
+            let mut deduped_connectors = Vec::with_capacity(connectors.len());
+            for connector in connectors {
+                if !deduped_connectors.contains(&connector) {
+                    deduped_connectors.push(connector);
+                }
+            }
+            Ok(deduped_connectors)
+        })
+        .transpose()
+}
+
 pub fn validate_payout_link_request(confirm: Option<bool>) -> Result<(), errors::ApiErrorResponse> {
     if let Some(cnf) = confirm {
         if cnf {
@@ -155,6 +662,122 @@ pub fn validate_payout_link_request(confirm: Option<bool>) -> Result<(), errors:
     Ok(())
 }
 
+pub fn validate_payout_schedule(
+    scheduled_at: PrimitiveDateTime,
+    confirm: Option<bool>,
+) -> Result<(), errors::ApiErrorResponse> {
+    utils::when(scheduled_at <= common_utils::date_time::now(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "scheduled_at must be a time in the future".to_string(),
+        })
+    })?;
+    utils::when(confirm != Some(true), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "scheduled_at can only be set for payouts created with confirm as true"
+                .to_string(),
+        })
+    })
+}
+
+/// Validates that `amount` and `currency` are either both present or both absent in the request.
+pub fn validate_payout_amount_and_currency(
+    req: &payouts::PayoutCreateRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    match (req.amount.as_ref(), req.currency) {
+        (Some(_), None) => Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "currency",
+        }),
+        (None, Some(_)) => Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "amount",
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Validates that a payout description only uses the supported template variables, which are
+/// resolved when the payout is disbursed.
+pub fn validate_payout_description(description: &str) -> Result<(), errors::ApiErrorResponse> {
+    helpers::render_payout_description(description, |variable| {
+        helpers::PAYOUT_DESCRIPTION_TEMPLATE_VARIABLES
+            .contains(&variable)
+            .then(String::new)
+    })
+    .map(|_| ())
+    .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })
+}
+
+/// Consolidates the recipient details passed in the request into `recipient`.
+/// The flat `name`, `email`, `phone`, `phone_country_code`, `entity_type` and `billing` fields are
+/// deprecated, but are still accepted and mapped into `recipient` as long as the same detail isn't
+/// passed in both places.
+pub fn get_payout_recipient(
+    req: &payouts::PayoutCreateRequest,
+) -> Result<payouts::PayoutRecipient, errors::ApiErrorResponse> {
+    fn merge<T: Clone>(
+        field_name: &str,
+        deprecated_value: &Option<T>,
+        value: &Option<T>,
+    ) -> Result<Option<T>, errors::ApiErrorResponse> {
+        match (deprecated_value, value) {
+            (Some(_), Some(_)) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`{field_name}` is deprecated, pass only `recipient.{field_name}` instead"
+                ),
+            }),
+            _ => Ok(value.clone().or_else(|| deprecated_value.clone())),
+        }
+    }
+
+    let recipient = req.recipient.clone().unwrap_or_default();
+    Ok(payouts::PayoutRecipient {
+        name: merge("name", &req.name, &recipient.name)?,
+        email: merge("email", &req.email, &recipient.email)?,
+        phone: merge("phone", &req.phone, &recipient.phone)?,
+        phone_country_code: merge(
+            "phone_country_code",
+            &req.phone_country_code,
+            &recipient.phone_country_code,
+        )?,
+        entity_type: merge("entity_type", &req.entity_type, &recipient.entity_type)?,
+        billing: merge("billing", &req.billing, &recipient.billing)?,
+    })
+}
+
+/// Validates the recipient details based on the type of entity the recipient is.
+/// Recipients other than individuals are organizations, which must be identified by their
+/// registered name.
+pub fn validate_payout_recipient(
+    recipient: &payouts::PayoutRecipient,
+) -> Result<(), errors::ApiErrorResponse> {
+    utils::when(
+        recipient.phone_country_code.is_some() && recipient.phone.is_none(),
+        || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "recipient.phone",
+            })
+        },
+    )?;
+    match recipient.entity_type.unwrap_or_default() {
+        api_enums::PayoutEntityType::Individual
+        | api_enums::PayoutEntityType::NaturalPerson
+        | api_enums::PayoutEntityType::Personal => Ok(()),
+        api_enums::PayoutEntityType::Company
+        | api_enums::PayoutEntityType::NonProfit
+        | api_enums::PayoutEntityType::PublicSector
+        | api_enums::PayoutEntityType::Business => utils::when(
+            recipient
+                .name
+                .as_ref()
+                .map_or(true, |name| name.peek().trim().is_empty()),
+            || {
+                Err(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "recipient.name",
+                })
+            },
+        ),
+    }
+}
+
 #[cfg(feature = "olap")]
 pub(super) fn validate_payout_list_request(
     req: &payouts::PayoutListConstraints,
@@ -192,6 +815,503 @@ pub(super) fn validate_payout_list_request_for_joins(
     Ok(())
 }
 
+/// Validates each of the destinations of the payout independently, and sets the primary
+/// destination as the payout method data of the request.
+pub async fn validate_payout_destinations(
+    state: &SessionState,
+    merchant_id: &str,
+    req: &mut payouts::PayoutCreateRequest,
+) -> RouterResult<()> {
+    let Some(destinations) = &req.payout_destinations else {
+        return Ok(());
+    };
+    utils::when(
+        req.payout_method_data.is_some() || req.payout_token.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payout_destinations cannot be passed along with payout_method_data or payout_token".to_string(),
+            }))
+        },
+    )?;
+    validate_payout_destination_list(destinations)?;
+
+    let denied_countries = helpers::get_payout_denied_countries(state, merchant_id).await?;
+    let denied_country = req.recipient.as_ref().and_then(|recipient| {
+        destinations.iter().find_map(|destination| {
+            get_denied_destination_country(recipient, Some(destination), &denied_countries)
+        })
+    });
+    if let Some(country) = denied_country {
+        return Err(report!(
+            errors::ApiErrorResponse::PayoutDestinationCountryNotAllowed {
+                country: country.to_string(),
+            }
+        ));
+    }
+
+    req.payout_method_data = destinations.first().cloned();
+    Ok(())
+}
+
+fn validate_payout_destination_list(
+    destinations: &[payouts::PayoutMethodData],
+) -> RouterResult<()> {
+    let primary = destinations.first().ok_or_else(|| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout_destinations cannot be empty".to_string(),
+        })
+    })?;
+    utils::when(destinations.len() > consts::PAYOUT_MAX_DESTINATIONS, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payout_destinations cannot contain more than {} destinations",
+                consts::PAYOUT_MAX_DESTINATIONS
+            ),
+        }))
+    })?;
+
+    for (index, destination) in destinations.iter().enumerate() {
+        utils::when(
+            std::mem::discriminant(destination) != std::mem::discriminant(primary),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "payout_destinations[{index}] must be of the same payout method as the primary destination"
+                    ),
+                }))
+            },
+        )?;
+        if let Some(field) = validate_payout_method_data(destination)
+            .fields
+            .into_iter()
+            .find(|field| !field.is_valid)
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "payout_destinations[{index}].{} is invalid: {}",
+                    field.field_name,
+                    field.error_message.unwrap_or_default()
+                ),
+            }));
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn validate_payout_bulk_retrieve_request(
+    req: &payouts::PayoutBulkRetrieveRequest,
+) -> RouterResult<()> {
+    use common_utils::consts::PAYOUTS_BULK_RETRIEVE_MAX_LIMIT;
+
+    utils::when(
+        req.payout_ids.is_empty() || req.payout_ids.len() > PAYOUTS_BULK_RETRIEVE_MAX_LIMIT,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "payout_ids should contain between 1 and {} entries",
+                    PAYOUTS_BULK_RETRIEVE_MAX_LIMIT
+                ),
+            }))
+        },
+    )
+}
+
+/// Validates the identifier and the number of items of a payout batch, and that the payout ids
+/// passed by its items are unique.
+pub(super) fn validate_payout_batch_create_request(
+    req: &payouts::PayoutBatchCreateRequest,
+) -> RouterResult<()> {
+    use common_utils::consts::PAYOUTS_BATCH_MAX_ITEMS;
+
+    utils::when(
+        req.batch_id.is_empty()
+            || req.batch_id.len() > consts::PAYOUT_BATCH_ID_MAX_LENGTH
+            || !req.batch_id.chars().all(|character| {
+                character.is_ascii_alphanumeric() || character == '-' || character == '_'
+            }),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "batch_id".to_string(),
+                expected_format: format!(
+                    "between 1 and {} letters, digits, hyphens and underscores",
+                    consts::PAYOUT_BATCH_ID_MAX_LENGTH
+                ),
+            }))
+        },
+    )?;
+    utils::when(
+        req.items.is_empty() || req.items.len() > PAYOUTS_BATCH_MAX_ITEMS,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "items should contain between 1 and {PAYOUTS_BATCH_MAX_ITEMS} entries"
+                ),
+            }))
+        },
+    )?;
+    let mut payout_ids = HashSet::new();
+    utils::when(
+        !req.items
+            .iter()
+            .filter_map(|item| item.payout_id.as_deref())
+            .all(|payout_id| payout_ids.insert(payout_id)),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payout_id should be unique across the items of the batch".to_string(),
+            }))
+        },
+    )
+}
+
+/// Validates that the value date is passed for a bank payout, and that it is a business day which
+/// is not before `today`.
+fn validate_payout_value_date(
+    value_date: time::Date,
+    payout_type: Option<api_enums::PayoutType>,
+    today: time::Date,
+    holidays: Option<&HashSet<time::Date>>,
+) -> RouterResult<()> {
+    utils::when(payout_type != Some(api_enums::PayoutType::Bank), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "value_date can only be set for bank payouts".to_string(),
+        }))
+    })?;
+    utils::when(value_date < today, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "value_date must not be in the past".to_string(),
+        }))
+    })?;
+    let is_weekend = matches!(
+        value_date.weekday(),
+        time::Weekday::Saturday | time::Weekday::Sunday
+    );
+    let is_holiday = holidays.is_some_and(|holidays| holidays.contains(&value_date));
+    utils::when(is_weekend || is_holiday, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("value_date {value_date} is not a business day"),
+        }))
+    })
+}
+
+/// Errors naming the first of the required keys which is missing in the metadata, keys with a null
+/// value are considered missing.
+fn validate_required_metadata_keys(
+    metadata: Option<&pii::SecretSerdeValue>,
+    required_keys: &[String],
+) -> RouterResult<()> {
+    let missing_key = required_keys.iter().find(|key| {
+        metadata
+            .and_then(|metadata| metadata.peek().get(key.as_str()))
+            .map_or(true, serde_json::Value::is_null)
+    });
+    match missing_key {
+        Some(key) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("metadata.{key} is required for the payouts of the merchant"),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Runs the field level validations on the payout method data, without creating a payout or
+/// storing the data in the locker.
+pub fn validate_payout_method_data(
+    payout_method_data: &payouts::PayoutMethodData,
+) -> payouts::PayoutMethodValidationResponse {
+    let fields = match payout_method_data {
+        payouts::PayoutMethodData::Card(card) => {
+            let is_valid_card_number =
+                ::cards::validate::sanitize_card_number(&card.card_number.get_card_no())
+                    .unwrap_or(false);
+            vec![
+                get_field_validation(
+                    "card.card_number",
+                    utils::when(!is_valid_card_number, || {
+                        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                            field_name: "card_number",
+                        }))
+                    }),
+                ),
+                get_field_validation(
+                    "card.expiry",
+                    payment_helpers::validate_card_expiry(&card.expiry_month, &card.expiry_year),
+                ),
+            ]
+        }
+        payouts::PayoutMethodData::Bank(payouts::Bank::Ach(ach)) => vec![
+            get_field_validation(
+                "bank.bank_routing_number",
+                validate_aba_routing_number(
+                    "bank.bank_routing_number",
+                    ach.bank_routing_number.peek(),
+                ),
+            ),
+            get_field_validation(
+                "bank.bank_account_number",
+                validate_non_empty("bank.bank_account_number", ach.bank_account_number.peek()),
+            ),
+        ],
+        payouts::PayoutMethodData::Bank(payouts::Bank::Bacs(bacs)) => vec![
+            get_field_validation(
+                "bank.bank_sort_code",
+                validate_numeric_code(
+                    "bank.bank_sort_code",
+                    &bacs.bank_sort_code.peek().replace('-', ""),
+                    6,
+                ),
+            ),
+            get_field_validation(
+                "bank.bank_account_number",
+                validate_non_empty("bank.bank_account_number", bacs.bank_account_number.peek()),
+            ),
+        ],
+        payouts::PayoutMethodData::Bank(payouts::Bank::Sepa(sepa)) => {
+            let mut fields = vec![get_field_validation(
+                "bank.iban",
+                validate_iban("bank.iban", sepa.iban.peek()),
+            )];
+            if let Some(bic) = &sepa.bic {
+                fields.push(get_field_validation(
+                    "bank.bic",
+                    validate_bic("bank.bic", bic.peek()),
+                ));
+            }
+            fields
+        }
+        payouts::PayoutMethodData::Bank(payouts::Bank::Pix(pix)) => vec![
+            get_field_validation(
+                "bank.bank_account_number",
+                validate_non_empty("bank.bank_account_number", pix.bank_account_number.peek()),
+            ),
+            get_field_validation(
+                "bank.pix_key",
+                validate_non_empty("bank.pix_key", pix.pix_key.peek()),
+            ),
+        ],
+        payouts::PayoutMethodData::NetworkToken(network_token) => vec![
+            get_field_validation(
+                "network_token.network_token",
+                validate_network_token_number(network_token.network_token.peek()),
+            ),
+            get_field_validation(
+                "network_token.token_cryptogram",
+                validate_non_empty(
+                    "network_token.token_cryptogram",
+                    network_token.token_cryptogram.peek(),
+                ),
+            ),
+            get_field_validation(
+                "network_token.token_expiry",
+                payment_helpers::validate_card_expiry(
+                    &network_token.token_expiry_month,
+                    &network_token.token_expiry_year,
+                ),
+            ),
+        ],
+        // Wallet and store credit identifiers are validated by their types on deserialization
+        payouts::PayoutMethodData::Wallet(_) | payouts::PayoutMethodData::StoreCredit(_) => {
+            vec![]
+        }
+    };
+
+    payouts::PayoutMethodValidationResponse {
+        is_valid: fields.iter().all(|field| field.is_valid),
+        fields,
+    }
+}
+
+fn get_field_validation(
+    field_name: &str,
+    result: RouterResult<()>,
+) -> payouts::PayoutMethodFieldValidation {
+    payouts::PayoutMethodFieldValidation {
+        field_name: field_name.to_string(),
+        is_valid: result.is_ok(),
+        error_message: result
+            .err()
+            .map(|err| err.current_context().error_message()),
+    }
+}
+
+/// Store credit is credited to the customer of the payout, so a store credit payout must carry
+/// store credit method data for that customer, and store credit method data is only accepted for
+/// store credit payouts.
+pub fn validate_store_credit_payout<'a>(
+    payout_type: Option<api_enums::PayoutType>,
+    payout_method_data: Option<&'a payouts::PayoutMethodData>,
+    customer_id: Option<&CustomerId>,
+) -> RouterResult<Option<&'a payouts::StoreCreditPayout>> {
+    match (payout_type, payout_method_data) {
+        (
+            Some(api_enums::PayoutType::StoreCredit),
+            Some(payouts::PayoutMethodData::StoreCredit(store_credit)),
+        ) => {
+            utils::when(customer_id != Some(&store_credit.customer_id), || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "store credit can only be credited to the customer of the payout"
+                        .to_string(),
+                }))
+            })?;
+            Ok(Some(store_credit))
+        }
+        (Some(api_enums::PayoutType::StoreCredit), _) => {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "payout_method_data.store_credit",
+            }))
+        }
+        (_, Some(payouts::PayoutMethodData::StoreCredit(_))) => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "store credit payout method data requires payout_type store_credit"
+                    .to_string(),
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Validates that the token fields required for a network token payout are present and well
+/// formed. Unlike cards, the token is not Luhn validated as network tokens need not pass it.
+pub fn validate_network_token_payout(
+    network_token: &payouts::NetworkTokenPayout,
+) -> RouterResult<()> {
+    validate_network_token_number(network_token.network_token.peek())?;
+    validate_non_empty(
+        "network_token.token_cryptogram",
+        network_token.token_cryptogram.peek(),
+    )?;
+    payment_helpers::validate_card_expiry(
+        &network_token.token_expiry_month,
+        &network_token.token_expiry_year,
+    )
+}
+
+/// Validates the corrected payout method data of a payout being reprocessed with the same checks
+/// which are run on it when a payout is created.
+pub fn validate_reprocess_payout_method_data(
+    payout_method_data: &payouts::PayoutMethodData,
+    payout_type: Option<api_enums::PayoutType>,
+    customer_id: &CustomerId,
+) -> RouterResult<()> {
+    validate_store_credit_payout(payout_type, Some(payout_method_data), Some(customer_id))?;
+    if let payouts::PayoutMethodData::NetworkToken(network_token) = payout_method_data {
+        validate_network_token_payout(network_token)?;
+    }
+    if let Some(field) = validate_payout_method_data(payout_method_data)
+        .fields
+        .into_iter()
+        .find(|field| !field.is_valid)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payout_method_data.{} is invalid: {}",
+                field.field_name,
+                field.error_message.unwrap_or_default()
+            ),
+        }));
+    }
+    Ok(())
+}
+
+fn validate_network_token_number(network_token: &str) -> RouterResult<()> {
+    let is_valid = (13..=19).contains(&network_token.len())
+        && network_token.chars().all(|c| c.is_ascii_digit());
+    utils::when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "network_token.network_token".to_string(),
+            expected_format: "13 to 19 digits".to_string(),
+        }))
+    })
+}
+
+fn validate_non_empty(field_name: &str, value: &str) -> RouterResult<()> {
+    utils::when(value.trim().is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field_name} cannot be empty"),
+        }))
+    })
+}
+
+pub(super) fn validate_numeric_code(
+    field_name: &str,
+    value: &str,
+    length: usize,
+) -> RouterResult<()> {
+    let is_valid = value.len() == length && value.chars().all(|c| c.is_ascii_digit());
+    utils::when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: field_name.to_string(),
+            expected_format: format!("{length} digits"),
+        }))
+    })
+}
+
+/// Validates the format and the mod-97 check digits of an IBAN.
+pub(super) fn validate_iban(field_name: &str, iban: &str) -> RouterResult<()> {
+    let is_valid_format = (5..=34).contains(&iban.len())
+        && iban.chars().take(2).all(|c| c.is_ascii_uppercase())
+        && iban.chars().skip(2).take(2).all(|c| c.is_ascii_digit())
+        && iban
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+    utils::when(!is_valid_format, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: field_name.to_string(),
+            expected_format: "IBAN without spaces".to_string(),
+        }))
+    })?;
+
+    // Move the country code and check digits to the end, and read letters as 10 to 35
+    let remainder = iban
+        .chars()
+        .skip(4)
+        .chain(iban.chars().take(4))
+        .filter_map(|c| c.to_digit(36))
+        .fold(0, |remainder, value| {
+            let multiplier = if value < 10 { 10 } else { 100 };
+            (remainder * multiplier + value) % 97
+        });
+    utils::when(remainder != 1, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: field_name.to_string(),
+            expected_format: "IBAN with valid check digits".to_string(),
+        }))
+    })
+}
+
+pub(super) fn validate_bic(field_name: &str, bic: &str) -> RouterResult<()> {
+    let is_valid = matches!(bic.len(), 8 | 11)
+        && bic.chars().take(6).all(|c| c.is_ascii_uppercase())
+        && bic
+            .chars()
+            .skip(6)
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+    utils::when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: field_name.to_string(),
+            expected_format: "8 or 11 character BIC".to_string(),
+        }))
+    })
+}
+
+/// Validates the length and the check digit of an ABA routing number.
+pub(super) fn validate_aba_routing_number(
+    field_name: &str,
+    routing_number: &str,
+) -> RouterResult<()> {
+    validate_numeric_code(field_name, routing_number, 9)?;
+    let checksum = routing_number
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .zip([3, 7, 1].into_iter().cycle())
+        .map(|(digit, weight)| digit * weight)
+        .sum::<u32>();
+    utils::when(checksum % 10 != 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: field_name.to_string(),
+            expected_format: "ABA routing number with a valid check digit".to_string(),
+        }))
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn create_payout_link(
     state: &SessionState,
@@ -265,6 +1385,8 @@ pub async fn create_payout_link(
         .as_ref()
         .get_required_value("currency")
         .attach_printable("currency is a required value when creating payout links")?;
+    // The amount of the request is in the source currency when one is passed
+    let currency = req.source_currency.as_ref().unwrap_or(currency);
 
     let data = PayoutLinkData {
         payout_link_id: payout_link_id.clone(),
@@ -314,3 +1436,643 @@ pub async fn create_payout_link_db_entry(
             message: "payout link already exists".to_string(),
         })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_validate_payout_connectors_removes_duplicates_preserving_order() {
+        let connectors = validate_payout_connectors(Some(vec![
+            api_enums::PayoutConnectors::Stripe,
+            api_enums::PayoutConnectors::Stripe,
+            api_enums::PayoutConnectors::Adyen,
+        ]))
+        .unwrap();
+        assert_eq!(
+            connectors,
+            Some(vec![
+                api_enums::PayoutConnectors::Stripe,
+                api_enums::PayoutConnectors::Adyen
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_payout_connectors_rejects_empty_list() {
+        assert!(validate_payout_connectors(Some(vec![])).is_err());
+        assert_eq!(validate_payout_connectors(None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_validate_payout_description_templates() {
+        assert!(validate_payout_description("Monthly payout").is_ok());
+        assert!(validate_payout_description("Payout } for {customer_id} on {date}").is_ok());
+        assert!(validate_payout_description("Payout #{sequence}").is_ok());
+        assert!(validate_payout_description("Payout for {month}").is_err());
+        assert!(validate_payout_description("Payout on {date").is_err());
+    }
+
+    #[test]
+    fn test_get_payout_recipient_maps_deprecated_fields() {
+        let req = payouts::PayoutCreateRequest {
+            name: Some(Secret::new("Acme Inc".to_string())),
+            entity_type: Some(api_enums::PayoutEntityType::Company),
+            ..Default::default()
+        };
+        let recipient = get_payout_recipient(&req).unwrap();
+        assert_eq!(
+            recipient.entity_type,
+            Some(api_enums::PayoutEntityType::Company)
+        );
+        assert!(validate_payout_recipient(&recipient).is_ok());
+
+        let conflicting_req = payouts::PayoutCreateRequest {
+            recipient: Some(payouts::PayoutRecipient {
+                name: Some(Secret::new("Acme Corp".to_string())),
+                ..Default::default()
+            }),
+            ..req
+        };
+        assert!(get_payout_recipient(&conflicting_req).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_recipient_by_entity_type() {
+        let individual = payouts::PayoutRecipient::default();
+        assert!(validate_payout_recipient(&individual).is_ok());
+
+        let company = payouts::PayoutRecipient {
+            entity_type: Some(api_enums::PayoutEntityType::Company),
+            ..Default::default()
+        };
+        assert!(validate_payout_recipient(&company).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_amount_without_currency() {
+        let req = payouts::PayoutCreateRequest {
+            amount: Some(api_models::payments::Amount::from(MinorUnit::new(1000))),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_payout_amount_and_currency(&req),
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "currency"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_validate_payout_currency_without_amount() {
+        let req = payouts::PayoutCreateRequest {
+            currency: Some(api_enums::Currency::USD),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_payout_amount_and_currency(&req),
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "amount"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_get_denied_destination_country() {
+        let denied_countries = HashSet::from([api_enums::CountryAlpha2::KP]);
+        let recipient = payouts::PayoutRecipient {
+            billing: Some(api_models::payments::Address {
+                address: Some(api_models::payments::AddressDetails {
+                    country: Some(api_enums::CountryAlpha2::US),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            get_denied_destination_country(&recipient, None, &denied_countries),
+            None
+        );
+
+        let bank = payouts::PayoutMethodData::Bank(payouts::Bank::Ach(payouts::AchBankTransfer {
+            bank_country_code: Some(api_enums::CountryAlpha2::KP),
+            ..Default::default()
+        }));
+        assert_eq!(
+            get_denied_destination_country(&recipient, Some(&bank), &denied_countries),
+            Some(api_enums::CountryAlpha2::KP)
+        );
+    }
+
+    #[test]
+    fn test_validate_store_credit_payout() {
+        use std::str::FromStr;
+
+        let customer_id = CustomerId::from("cus_store_credit".into()).unwrap();
+        let other_customer_id = CustomerId::from("cus_other".into()).unwrap();
+        let store_credit = payouts::PayoutMethodData::StoreCredit(payouts::StoreCreditPayout {
+            customer_id: customer_id.clone(),
+            credit_account_id: None,
+        });
+        let store_credit_payout_type = Some(api_enums::PayoutType::StoreCredit);
+
+        assert!(validate_store_credit_payout(
+            store_credit_payout_type,
+            Some(&store_credit),
+            Some(&customer_id)
+        )
+        .unwrap()
+        .is_some());
+
+        // Credited customer is not the customer of the payout
+        assert!(validate_store_credit_payout(
+            store_credit_payout_type,
+            Some(&store_credit),
+            Some(&other_customer_id)
+        )
+        .is_err());
+        assert!(
+            validate_store_credit_payout(store_credit_payout_type, Some(&store_credit), None)
+                .is_err()
+        );
+
+        // Store credit payouts without store credit method data
+        assert!(
+            validate_store_credit_payout(store_credit_payout_type, None, Some(&customer_id))
+                .is_err()
+        );
+        let card = payouts::PayoutMethodData::Card(payouts::CardPayout {
+            card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+            expiry_month: Secret::new("12".to_string()),
+            expiry_year: Secret::new("2099".to_string()),
+            card_holder_name: None,
+        });
+        assert!(validate_store_credit_payout(
+            store_credit_payout_type,
+            Some(&card),
+            Some(&customer_id)
+        )
+        .is_err());
+
+        // Store credit method data for other payout types
+        assert!(validate_store_credit_payout(
+            Some(api_enums::PayoutType::Card),
+            Some(&store_credit),
+            Some(&customer_id)
+        )
+        .is_err());
+        assert!(validate_store_credit_payout(
+            Some(api_enums::PayoutType::Card),
+            Some(&card),
+            Some(&customer_id)
+        )
+        .unwrap()
+        .is_none());
+    }
+
+    #[test]
+    fn test_validate_network_token_payout() {
+        let network_token = payouts::NetworkTokenPayout {
+            network_token: Secret::new("4895370012003478".to_string()),
+            token_expiry_month: Secret::new("12".to_string()),
+            token_expiry_year: Secret::new("2099".to_string()),
+            token_cryptogram: Secret::new("AgAAAAAABk4DWZ4C28yUQAAAAAA=".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_network_token_payout(&network_token).is_ok());
+
+        let without_cryptogram = payouts::NetworkTokenPayout {
+            token_cryptogram: Secret::new(" ".to_string()),
+            ..network_token.clone()
+        };
+        assert!(validate_network_token_payout(&without_cryptogram).is_err());
+
+        let malformed_token = payouts::NetworkTokenPayout {
+            network_token: Secret::new("4895-3700".to_string()),
+            ..network_token
+        };
+        assert!(validate_network_token_payout(&malformed_token).is_err());
+    }
+
+    #[test]
+    fn test_validate_reprocess_payout_method_data() {
+        let customer_id = CustomerId::from("cus_reprocess".into()).unwrap();
+        let sepa = |iban: &str| {
+            payouts::PayoutMethodData::Bank(payouts::Bank::Sepa(payouts::SepaBankTransfer {
+                iban: Secret::new(iban.to_string()),
+                bic: Some(Secret::new("ABNANL2A".to_string())),
+                ..Default::default()
+            }))
+        };
+        let bank_payout_type = Some(api_enums::PayoutType::Bank);
+
+        assert!(validate_reprocess_payout_method_data(
+            &sepa("NL91ABNA0417164300"),
+            bank_payout_type,
+            &customer_id
+        )
+        .is_ok());
+        // Corrected IBAN which fails the checksum
+        assert!(validate_reprocess_payout_method_data(
+            &sepa("NL92ABNA0417164300"),
+            bank_payout_type,
+            &customer_id
+        )
+        .is_err());
+
+        let network_token = payouts::PayoutMethodData::NetworkToken(payouts::NetworkTokenPayout {
+            network_token: Secret::new("4895370012003478".to_string()),
+            token_expiry_month: Secret::new("12".to_string()),
+            token_expiry_year: Secret::new("2099".to_string()),
+            token_cryptogram: Secret::new(" ".to_string()),
+            ..Default::default()
+        });
+        assert!(validate_reprocess_payout_method_data(
+            &network_token,
+            Some(api_enums::PayoutType::Card),
+            &customer_id
+        )
+        .is_err());
+
+        // Store credit method data for a bank payout
+        let store_credit = payouts::PayoutMethodData::StoreCredit(payouts::StoreCreditPayout {
+            customer_id: customer_id.clone(),
+            credit_account_id: None,
+        });
+        assert!(validate_reprocess_payout_method_data(
+            &store_credit,
+            bank_payout_type,
+            &customer_id
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_method_data_reports_each_field() {
+        let sepa =
+            payouts::PayoutMethodData::Bank(payouts::Bank::Sepa(payouts::SepaBankTransfer {
+                iban: Secret::new("NL91ABNA0417164300".to_string()),
+                bic: Some(Secret::new("ABNANL2A".to_string())),
+                ..Default::default()
+            }));
+        let response = validate_payout_method_data(&sepa);
+        assert!(response.is_valid);
+        assert_eq!(response.fields.len(), 2);
+
+        let ach = payouts::PayoutMethodData::Bank(payouts::Bank::Ach(payouts::AchBankTransfer {
+            bank_routing_number: Secret::new("011000016".to_string()),
+            bank_account_number: Secret::new("000123456789".to_string()),
+            ..Default::default()
+        }));
+        let response = validate_payout_method_data(&ach);
+        assert!(!response.is_valid);
+        let routing_number = response.fields.first().unwrap();
+        assert_eq!(routing_number.field_name, "bank.bank_routing_number");
+        assert!(!routing_number.is_valid);
+        assert!(routing_number.error_message.is_some());
+        assert!(response.fields.get(1).unwrap().is_valid);
+
+        assert!(validate_iban("iban", "NL91ABNA0417164300").is_ok());
+        assert!(validate_iban("iban", "NL92ABNA0417164300").is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_destination_list() {
+        let sepa = |iban: &str| {
+            payouts::PayoutMethodData::Bank(payouts::Bank::Sepa(payouts::SepaBankTransfer {
+                iban: Secret::new(iban.to_string()),
+                ..Default::default()
+            }))
+        };
+        assert!(validate_payout_destination_list(&[
+            sepa("NL91ABNA0417164300"),
+            sepa("DE89370400440532013000")
+        ])
+        .is_ok());
+        assert!(validate_payout_destination_list(&[]).is_err());
+
+        // Backups are validated independently of the primary destination
+        assert!(validate_payout_destination_list(&[
+            sepa("NL91ABNA0417164300"),
+            sepa("NL92ABNA0417164300")
+        ])
+        .is_err());
+
+        let card = payouts::PayoutMethodData::Card(payouts::CardPayout::default());
+        assert!(validate_payout_destination_list(&[sepa("NL91ABNA0417164300"), card]).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_purpose_code() {
+        let purpose_codes = HashMap::from([(
+            api_enums::CountryAlpha2::IN,
+            PayoutPurposeCodes {
+                required: true,
+                codes: HashSet::from(["P1301".to_string()]),
+            },
+        )]);
+        let india = [api_enums::CountryAlpha2::IN];
+        assert!(validate_payout_purpose_code(Some("P1301"), &india, &purpose_codes).is_ok());
+        assert!(matches!(
+            validate_payout_purpose_code(None, &india, &purpose_codes),
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "purpose_code"
+            })
+        ));
+        assert!(validate_payout_purpose_code(Some("S0001"), &india, &purpose_codes).is_err());
+
+        let us = [api_enums::CountryAlpha2::US];
+        assert!(validate_payout_purpose_code(None, &us, &purpose_codes).is_ok());
+        assert!(validate_payout_purpose_code(Some("S0001"), &us, &purpose_codes).is_ok());
+    }
+
+    #[test]
+    fn test_validate_payout_purpose_code_for_update() {
+        let purpose_codes = HashMap::from([(
+            api_enums::CountryAlpha2::IN,
+            PayoutPurposeCodes {
+                required: true,
+                codes: HashSet::from(["P1301".to_string()]),
+            },
+        )]);
+        let bank_in =
+            payouts::PayoutMethodData::Bank(payouts::Bank::Sepa(payouts::SepaBankTransfer {
+                bank_country_code: Some(api_enums::CountryAlpha2::IN),
+                ..Default::default()
+            }));
+        let req = payouts::PayoutCreateRequest {
+            recipient: Some(payouts::PayoutRecipient::default()),
+            ..Default::default()
+        };
+
+        // The stored purpose code applies when the request doesn't carry one
+        assert!(validate_payout_purpose_code_for_update(&req, None, None, &purpose_codes).is_ok());
+        assert!(validate_payout_purpose_code_for_update(
+            &req,
+            None,
+            Some(&bank_in),
+            &purpose_codes
+        )
+        .is_err());
+        assert!(validate_payout_purpose_code_for_update(
+            &req,
+            Some("P1301"),
+            Some(&bank_in),
+            &purpose_codes
+        )
+        .is_ok());
+
+        // Updating the destination validates the stored purpose code against the new destination
+        let update_req = payouts::PayoutCreateRequest {
+            payout_method_data: Some(bank_in),
+            ..req.clone()
+        };
+        assert!(validate_payout_purpose_code_for_update(
+            &update_req,
+            Some("S0001"),
+            None,
+            &purpose_codes
+        )
+        .is_err());
+        assert!(validate_payout_purpose_code_for_update(
+            &payouts::PayoutCreateRequest {
+                purpose_code: Some("P1301".to_string()),
+                ..update_req
+            },
+            Some("S0001"),
+            None,
+            &purpose_codes
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_payout_settlement_network() {
+        let us = [api_enums::CountryAlpha2::US];
+        let validate = |payout_type, currency, priority, countries: &[api_enums::CountryAlpha2]| {
+            validate_payout_settlement_network(
+                api_enums::PayoutSettlementNetwork::Rtp,
+                payout_type,
+                currency,
+                priority,
+                countries,
+            )
+        };
+        assert!(validate(
+            Some(api_enums::PayoutType::Bank),
+            Some(api_enums::Currency::USD),
+            Some(api_enums::PayoutSendPriority::Instant),
+            &us,
+        )
+        .is_ok());
+        assert!(validate(None, None, None, &[]).is_ok());
+        assert!(validate(Some(api_enums::PayoutType::Card), None, None, &us).is_err());
+        assert!(validate(None, Some(api_enums::Currency::EUR), None, &us).is_err());
+        assert!(validate(None, None, None, &[api_enums::CountryAlpha2::CA]).is_err());
+        assert!(validate(
+            None,
+            None,
+            Some(api_enums::PayoutSendPriority::Regular),
+            &us
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_settlement_network_sepa_zone() {
+        let validate = |countries: &[api_enums::CountryAlpha2]| {
+            validate_payout_settlement_network(
+                api_enums::PayoutSettlementNetwork::SepaInstant,
+                Some(api_enums::PayoutType::Bank),
+                Some(api_enums::Currency::EUR),
+                None,
+                countries,
+            )
+        };
+        assert!(validate(&[api_enums::CountryAlpha2::DE]).is_ok());
+        assert!(validate(&[api_enums::CountryAlpha2::CH, api_enums::CountryAlpha2::NO]).is_ok());
+        assert!(validate(&[api_enums::CountryAlpha2::US]).is_err());
+        assert!(validate(&[api_enums::CountryAlpha2::FR, api_enums::CountryAlpha2::TR]).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_webhook_url() {
+        assert!(validate_payout_webhook_url(None).is_ok());
+        assert!(validate_payout_webhook_url(Some("https://merchant.example.com/webhooks")).is_ok());
+        assert!(validate_payout_webhook_url(Some("http://merchant.example.com/webhooks")).is_err());
+        assert!(validate_payout_webhook_url(Some("merchant.example.com/webhooks")).is_err());
+        assert!(validate_payout_webhook_url(Some("https://localhost/webhooks")).is_err());
+        assert!(validate_payout_webhook_url(Some("https://api.localhost./webhooks")).is_err());
+        assert!(validate_payout_webhook_url(Some("https://127.0.0.1/webhooks")).is_err());
+        assert!(validate_payout_webhook_url(Some("https://169.254.169.254/latest")).is_err());
+        assert!(validate_payout_webhook_url(Some("https://10.0.0.5:8443/webhooks")).is_err());
+        assert!(validate_payout_webhook_url(Some("https://[::1]/webhooks")).is_err());
+        assert!(
+            validate_payout_webhook_url(Some("https://[::ffff:192.168.1.1]/webhooks")).is_err()
+        );
+        assert!(validate_payout_webhook_url(Some("https://93.184.216.34/webhooks")).is_ok());
+    }
+
+    #[test]
+    fn test_validate_payout_merchant_reference_id() {
+        assert!(validate_payout_merchant_reference_id("INV-2024_0042").is_ok());
+        assert!(validate_payout_merchant_reference_id("").is_err());
+        assert!(validate_payout_merchant_reference_id("INV 2024/0042").is_err());
+        assert!(validate_payout_merchant_reference_id(&"a".repeat(65)).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_series_id() {
+        assert!(validate_payout_series_id("payroll-2024_monthly").is_ok());
+        assert!(validate_payout_series_id("").is_err());
+        assert!(validate_payout_series_id("payroll 2024/monthly").is_err());
+        assert!(validate_payout_series_id(&"a".repeat(65)).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_batch_create_request() {
+        let request =
+            |batch_id: &str, payout_ids: Vec<Option<&str>>| payouts::PayoutBatchCreateRequest {
+                batch_id: batch_id.to_string(),
+                items: payout_ids
+                    .into_iter()
+                    .map(|payout_id| payouts::PayoutCreateRequest {
+                        payout_id: payout_id.map(ToString::to_string),
+                        ..Default::default()
+                    })
+                    .collect(),
+            };
+
+        assert!(validate_payout_batch_create_request(&request(
+            "vendor_run-2024",
+            vec![None, None]
+        ))
+        .is_ok());
+        assert!(validate_payout_batch_create_request(&request(
+            "vendor_run",
+            vec![Some("payout_1"), None, Some("payout_2")]
+        ))
+        .is_ok());
+        assert!(validate_payout_batch_create_request(&request(
+            "vendor_run",
+            vec![Some("payout_1"), Some("payout_1")]
+        ))
+        .is_err());
+        assert!(validate_payout_batch_create_request(&request("", vec![None])).is_err());
+        assert!(validate_payout_batch_create_request(&request("vendor run", vec![None])).is_err());
+        assert!(
+            validate_payout_batch_create_request(&request(&"a".repeat(61), vec![None])).is_err()
+        );
+        assert!(validate_payout_batch_create_request(&request("vendor_run", vec![])).is_err());
+        assert!(
+            validate_payout_batch_create_request(&request("vendor_run", vec![None; 101])).is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_payout_recall_reason() {
+        assert!(validate_payout_recall_reason(None).is_ok());
+        assert!(validate_payout_recall_reason(Some("Fraudulent credit")).is_ok());
+        assert!(validate_payout_recall_reason(Some(&"a".repeat(255))).is_ok());
+        assert!(validate_payout_recall_reason(Some(&"a".repeat(256))).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_verification_request() {
+        let request = |verification_reference: Option<String>, reason: Option<String>| {
+            payouts::PayoutVerificationRequest {
+                payout_id: "payout_mbabizu24mvu3mela5njyhpit4".to_string(),
+                result: api_models::payouts::PayoutVerificationResult::Rejected,
+                verification_reference,
+                reason,
+            }
+        };
+
+        assert!(validate_payout_verification_request(&request(None, None)).is_ok());
+        assert!(validate_payout_verification_request(&request(
+            Some("a".repeat(255)),
+            Some("Identity document has expired".to_string())
+        ))
+        .is_ok());
+        assert!(
+            validate_payout_verification_request(&request(Some("a".repeat(256)), None)).is_err()
+        );
+        assert!(
+            validate_payout_verification_request(&request(None, Some("a".repeat(256)))).is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_payout_create_request_with_unknown_fields() {
+        let payload = serde_json::json!({
+            "amount": 100,
+            "currency": "USD",
+            "future_field": true,
+            "recipient": { "name": "John Doe", "future_field": "value" }
+        });
+
+        assert!(helpers::parse_payout_create_request(payload.clone(), false).is_err());
+
+        let request = helpers::parse_payout_create_request(payload, true).unwrap();
+        assert_eq!(request.currency, Some(api_enums::Currency::USD));
+        assert!(request.recipient.is_some());
+    }
+
+    #[test]
+    fn test_render_payout_description() {
+        let rendered =
+            helpers::render_payout_description("{customer_id} #{sequence}", |v| match v {
+                "customer_id" => Some("cus_123".to_string()),
+                "sequence" => Some("4".to_string()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(rendered, "cus_123 #4");
+    }
+
+    #[test]
+    fn test_validate_required_metadata_keys() {
+        let required_keys = vec!["invoice_id".to_string()];
+        let metadata = |value: serde_json::Value| Some(Secret::new(value));
+
+        assert!(validate_required_metadata_keys(
+            metadata(serde_json::json!({"invoice_id": "inv_123"})).as_ref(),
+            &required_keys
+        )
+        .is_ok());
+        assert!(validate_required_metadata_keys(None, &[]).is_ok());
+
+        let error = validate_required_metadata_keys(
+            metadata(serde_json::json!({"invoice_id": null, "udf1": "value"})).as_ref(),
+            &required_keys,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "metadata.invoice_id is required for the payouts of the merchant"
+        ));
+        assert!(validate_required_metadata_keys(None, &required_keys).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_value_date() {
+        // Friday, 2024-12-20
+        let today = time::Date::from_calendar_date(2024, time::Month::December, 20).unwrap();
+        let christmas = time::Date::from_calendar_date(2024, time::Month::December, 25).unwrap();
+        let holidays = HashSet::from([christmas]);
+        let bank = Some(api_enums::PayoutType::Bank);
+        let validate = |value_date, payout_type| {
+            validate_payout_value_date(value_date, payout_type, today, Some(&holidays))
+        };
+
+        assert!(validate(today, bank).is_ok());
+        assert!(validate(today.previous_day().unwrap(), bank).is_err());
+        // Saturday
+        assert!(validate(today.next_day().unwrap(), bank).is_err());
+        assert!(validate(christmas, bank).is_err());
+        assert!(validate(today, Some(api_enums::PayoutType::Card)).is_err());
+    }
+}
@@ -17,7 +17,7 @@ use uuid::Uuid;
 
 use super::payments::helpers;
 #[cfg(feature = "payouts")]
-use super::payouts::PayoutData;
+use super::payouts::{helpers as payout_helpers, PayoutData};
 #[cfg(feature = "payouts")]
 use crate::core::payments;
 use crate::{
@@ -148,6 +148,13 @@ pub async fn construct_payout_router_data<'a, F>(
             _ => None,
         };
 
+    let originator = payout_helpers::get_payout_originator(
+        state,
+        connector_name,
+        merchant_account,
+        payout_data,
+    )?;
+
     let router_data = types::RouterData {
         flow: PhantomData,
         merchant_id: merchant_account.merchant_id.to_owned(),
@@ -179,6 +186,21 @@ pub async fn construct_payout_router_data<'a, F>(
             payout_type: payouts.payout_type,
             vendor_details,
             priority: payouts.priority,
+            purpose_code: payouts.purpose_code.to_owned(),
+            originator,
+            value_date: payouts
+                .value_date
+                .filter(|_| connector_name.supports_payout_value_date()),
+            charge_bearer: payouts.charge_bearer.unwrap_or_default(),
+            settlement_network: payouts.settlement_network,
+            statement_descriptor: payouts.statement_descriptor.as_deref().and_then(
+                |statement_descriptor| {
+                    payout_helpers::get_connector_payout_statement_descriptor(
+                        statement_descriptor,
+                        *connector_name,
+                    )
+                },
+            ),
             customer_details: customer_details
                 .to_owned()
                 .map(|c| payments::CustomerDetails {
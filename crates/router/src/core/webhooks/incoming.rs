@@ -13,6 +13,8 @@ use masking::ExposeInterface;
 use router_env::{instrument, metrics::add_attributes, tracing, tracing_actix_web::RequestId};
 
 use super::{types, utils, MERCHANT_ID};
+#[cfg(feature = "payouts")]
+use crate::core::payouts;
 use crate::{
     consts,
     core::{
@@ -39,8 +41,6 @@ use crate::{
     },
     utils::{self as helper_utils, generate_id, OptionExt},
 };
-#[cfg(feature = "payouts")]
-use crate::{core::payouts, types::storage::PayoutAttemptUpdate};
 
 #[allow(clippy::too_many_arguments)]
 pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
@@ -441,17 +441,30 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             .attach_printable("Incoming webhook flow for fraud check failed")?,
 
             #[cfg(feature = "payouts")]
-            api::WebhookFlow::Payout => Box::pin(payouts_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                event_type,
-                source_verified,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for payouts failed")?,
+            api::WebhookFlow::Payout => {
+                let connector_event_id = connector
+                    .get_webhook_event_id(&request_details)
+                    .switch()
+                    .attach_printable("Could not find event id in incoming webhook body")?;
+                let event_key = payouts::helpers::get_payout_webhook_event_key(
+                    &merchant_account.merchant_id,
+                    connector_name.as_str(),
+                    connector_event_id,
+                    request_details.body,
+                )?;
+                Box::pin(payouts_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    event_type,
+                    source_verified,
+                    event_key,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for payouts failed")?
+            }
 
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
@@ -623,6 +636,7 @@ async fn payments_incoming_webhook_flow(
 
 #[cfg(feature = "payouts")]
 #[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
 async fn payouts_incoming_webhook_flow(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
@@ -631,6 +645,7 @@ async fn payouts_incoming_webhook_flow(
     webhook_details: api::IncomingWebhookDetails,
     event_type: webhooks::IncomingWebhookEvent,
     source_verified: bool,
+    event_key: String,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
     metrics::INCOMING_PAYOUT_WEBHOOK_METRIC.add(&metrics::CONTEXT, 1, &[]);
     if source_verified {
@@ -671,73 +686,122 @@ async fn payouts_incoming_webhook_flow(
             .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
             .attach_printable("Failed to fetch the payout")?;
 
-        let payout_attempt_update = PayoutAttemptUpdate::StatusUpdate {
-            connector_payout_id: payout_attempt.connector_payout_id.clone(),
-            status: common_enums::PayoutStatus::foreign_try_from(event_type)
-                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                .attach_printable("failed payout status mapping from event type")?,
-            error_message: None,
-            error_code: None,
-            is_eligible: payout_attempt.is_eligible,
-        };
-
-        let action_req =
-            payout_models::PayoutRequest::PayoutActionRequest(payout_models::PayoutActionRequest {
-                payout_id: payouts.payout_id.clone(),
+        let status = common_enums::PayoutStatus::foreign_try_from(event_type)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("failed payout status mapping from event type")?;
+
+        // Events delivered again by the connector, and statuses which would regress a payout
+        // from a final status, are acknowledged without being applied. The event is claimed
+        // atomically, so that concurrent deliveries of the same event are only processed once.
+        let should_apply_status =
+            payouts::helpers::should_apply_payout_webhook_status(payout_attempt.status, status);
+        let is_duplicate_event = should_apply_status
+            && !payouts::helpers::claim_payout_webhook_event(&state, &event_key).await;
+        if is_duplicate_event || !should_apply_status {
+            logger::info!(
+                payout_id = %payout_attempt.payout_id,
+                current_status = %payout_attempt.status,
+                received_status = %status,
+                is_duplicate_event,
+                "Ignoring payout webhook"
+            );
+            return Ok(WebhookResponseTracker::Payout {
+                payout_id: payout_attempt.payout_id,
+                status: payout_attempt.status,
             });
+        }
 
-        let payout_data =
-            payouts::make_payout_data(&state, &merchant_account, &key_store, &action_req).await?;
-
-        let updated_payout_attempt = db
-            .update_payout_attempt(
-                &payout_attempt,
-                payout_attempt_update,
-                &payout_data.payouts,
-                merchant_account.storage_scheme,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
-            .attach_printable_lazy(|| {
-                format!(
-                    "Failed while updating payout attempt: payout_attempt_id: {}",
-                    payout_attempt.payout_attempt_id
-                )
-            })?;
+        let result = async {
+            let payout_attempt_update = payouts::helpers::get_payout_webhook_attempt_update(
+                payout_attempt.connector_payout_id.clone(),
+                payout_attempt.is_eligible,
+                payouts.amount,
+                status,
+            );
 
-        let event_type: Option<enums::EventType> = updated_payout_attempt.status.foreign_into();
+            let action_req = payout_models::PayoutRequest::PayoutActionRequest(
+                payout_models::PayoutActionRequest {
+                    payout_id: payouts.payout_id.clone(),
+                },
+            );
 
-        // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
-        if let Some(outgoing_event_type) = event_type {
-            let router_response =
-                payouts::response_handler(&merchant_account, &payout_data).await?;
+            let mut payout_data =
+                payouts::make_payout_data(&state, &merchant_account, &key_store, &action_req)
+                    .await?;
 
-            let payout_create_response: payout_models::PayoutCreateResponse = match router_response
-            {
-                services::ApplicationResponse::Json(response) => response,
-                _ => Err(errors::ApiErrorResponse::WebhookResourceNotFound)
-                    .attach_printable("Failed to fetch the payout create response")?,
-            };
+            let updated_payout_attempt = db
+                .update_payout_attempt(
+                    &payout_attempt,
+                    payout_attempt_update,
+                    &payout_data.payouts,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while updating payout attempt: payout_attempt_id: {}",
+                        payout_attempt.payout_attempt_id
+                    )
+                })?;
+            payouts::helpers::record_payout_time_to_fulfillment(
+                &payout_data.payouts,
+                &updated_payout_attempt,
+            );
+            payout_data.payout_attempt = updated_payout_attempt;
 
-            super::create_event_and_trigger_outgoing_webhook(
-                state,
-                merchant_account,
-                business_profile,
+            // Failures reported asynchronously are retried with the backup destinations as well
+            Box::pin(payouts::failover_payout_destination(
+                &state,
+                &merchant_account,
                 &key_store,
-                outgoing_event_type,
-                enums::EventClass::Payouts,
-                updated_payout_attempt.payout_id.clone(),
-                enums::EventObjectType::PayoutDetails,
-                api::OutgoingWebhookContent::PayoutDetails(payout_create_response),
-                Some(updated_payout_attempt.created_at),
-            )
+                &mut payout_data,
+            ))
             .await?;
-        }
+            let updated_payout_attempt = payout_data.payout_attempt.clone();
 
-        Ok(WebhookResponseTracker::Payout {
-            payout_id: updated_payout_attempt.payout_id,
-            status: updated_payout_attempt.status,
-        })
+            let event_type: Option<enums::EventType> = updated_payout_attempt.status.foreign_into();
+
+            // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
+            if let Some(outgoing_event_type) = event_type {
+                let router_response =
+                    payouts::response_handler(&state, &merchant_account, &payout_data).await?;
+
+                let payout_create_response: payout_models::PayoutCreateResponse =
+                    match router_response {
+                        services::ApplicationResponse::Json(response) => response,
+                        _ => Err(errors::ApiErrorResponse::WebhookResourceNotFound)
+                            .attach_printable("Failed to fetch the payout create response")?,
+                    };
+
+                super::create_event_and_trigger_outgoing_webhook(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    &key_store,
+                    outgoing_event_type,
+                    enums::EventClass::Payouts,
+                    updated_payout_attempt.payout_id.clone(),
+                    enums::EventObjectType::PayoutDetails,
+                    api::OutgoingWebhookContent::PayoutDetails(payout_create_response),
+                    Some(updated_payout_attempt.created_at),
+                )
+                .await?;
+            }
+
+            let response = WebhookResponseTracker::Payout {
+                payout_id: updated_payout_attempt.payout_id,
+                status: updated_payout_attempt.status,
+            };
+            Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(response)
+        }
+        .await;
+        // A delivery which failed to be processed is released, so that it can be retried by the
+        // connector
+        if result.is_err() {
+            payouts::helpers::release_payout_webhook_event(&state, &event_key).await;
+        }
+        result
     } else {
         metrics::INCOMING_PAYOUT_WEBHOOK_SIGNATURE_FAILURE_METRIC.add(&metrics::CONTEXT, 1, &[]);
         Err(report!(
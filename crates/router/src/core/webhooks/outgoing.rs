@@ -39,6 +39,9 @@ use crate::{
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
 
+/// Task name of the process tracker entries retrying the delivery of outgoing webhooks.
+pub(crate) const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub(crate) async fn create_event_and_trigger_outgoing_webhook(
@@ -56,7 +59,8 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
     let idempotent_event_id =
         utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    let event_metadata = storage::EventMetadata::foreign_from((&content, &primary_object_id));
+    let webhook_url_result = get_webhook_url(&business_profile, Some(&event_metadata));
 
     if !state.conf.webhooks.outgoing_enabled
         || webhook_url_result.is_err()
@@ -91,8 +95,6 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
     .attach_printable("Failed to construct outgoing webhook request content")?;
 
-    let event_metadata = storage::EventMetadata::foreign_from((&content, &primary_object_id));
-
     let new_event = domain::Event {
         event_id: event_id.clone(),
         event_type,
@@ -226,7 +228,7 @@ async fn trigger_webhook_to_merchant(
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+        get_validated_webhook_url(&business_profile, event.metadata.as_ref()).await,
         process_tracker.clone(),
     ) {
         (Ok(webhook_url), _) => Ok(webhook_url),
@@ -487,7 +489,7 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     };
 
     let runner = storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow;
-    let task = "OUTGOING_WEBHOOK_RETRY";
+    let task = OUTGOING_WEBHOOK_RETRY_TASK;
     let tag = ["OUTGOING_WEBHOOKS"];
     let process_tracker_id = scheduler::utils::get_process_tracker_id(
         runner,
@@ -525,6 +527,70 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+/// Returns the webhook URL override of the event's primary object if one was provided, falling
+/// back to the webhook URL configured in the business profile.
+fn get_webhook_url(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event_metadata: Option<&storage::EventMetadata>,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    match event_metadata {
+        Some(storage::EventMetadata::Payout {
+            webhook_url: Some(webhook_url),
+            ..
+        }) => Ok(webhook_url.clone()),
+        _ => get_webhook_url_from_business_profile(business_profile),
+    }
+}
+
+/// Returns the webhook URL of the event, after verifying that a webhook URL override of the event's
+/// primary object resolves only to public addresses. The override is provided with the object by
+/// the API caller, so it is resolved right before sending the webhook, as the records of its
+/// domain may have changed since it was validated.
+async fn get_validated_webhook_url(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event_metadata: Option<&storage::EventMetadata>,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let webhook_url = get_webhook_url(business_profile, event_metadata)?;
+    if let Some(storage::EventMetadata::Payout {
+        webhook_url: Some(_),
+        ..
+    }) = event_metadata
+    {
+        validate_webhook_url_resolves_to_public_host(&webhook_url).await?;
+    }
+    Ok(webhook_url)
+}
+
+async fn validate_webhook_url_resolves_to_public_host(
+    webhook_url: &str,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let url = url::Url::parse(webhook_url)
+        .change_context(errors::WebhooksFlowError::WebhookUrlNotAllowed)
+        .attach_printable("Failed to parse webhook URL")?;
+    let host = url
+        .host()
+        .ok_or(errors::WebhooksFlowError::WebhookUrlNotAllowed)
+        .attach_printable("Webhook URL has no host")?;
+    if utils::is_non_public_host(&host) {
+        return Err(report!(errors::WebhooksFlowError::WebhookUrlNotAllowed))
+            .attach_printable("Webhook URL host is not public");
+    }
+    let url::Host::Domain(domain) = host else {
+        return Ok(());
+    };
+
+    let port = url.port_or_known_default().unwrap_or(443);
+    let mut addresses = tokio::net::lookup_host((domain, port))
+        .await
+        .change_context(errors::WebhooksFlowError::CallToMerchantFailed)
+        .attach_printable("Failed to resolve webhook URL host")?;
+    if addresses.any(|address| utils::is_non_public_ip_address(address.ip())) {
+        return Err(report!(errors::WebhooksFlowError::WebhookUrlNotAllowed))
+            .attach_printable("Webhook URL host resolves to a non public address");
+    }
+    Ok(())
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &diesel_models::business_profile::BusinessProfile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -844,6 +910,7 @@ impl ForeignFrom<(&api::OutgoingWebhookContent, &str)> for storage::EventMetadat
             #[cfg(feature = "payouts")]
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
+                webhook_url: payout_response.webhook_url.clone(),
             },
         }
     }
@@ -859,10 +926,12 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
                 content: serde_json::Value::Null,
             }
         }
-        diesel_models::EventMetadata::Payout { payout_id } => OutgoingWebhookEventContent::Payout {
-            payout_id,
-            content: serde_json::Value::Null,
-        },
+        diesel_models::EventMetadata::Payout { payout_id, .. } => {
+            OutgoingWebhookEventContent::Payout {
+                payout_id,
+                content: serde_json::Value::Null,
+            }
+        }
         diesel_models::EventMetadata::Refund {
             payment_id,
             refund_id,
@@ -1,4 +1,4 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, net::IpAddr};
 
 use common_utils::{errors::CustomResult, ext_traits::ValueExt};
 use error_stack::ResultExt;
@@ -146,3 +146,74 @@ pub(crate) fn get_idempotent_event_id(
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
 }
+
+/// Returns whether the IP address is not reachable on the public internet, being a loopback,
+/// link-local, private, shared (carrier-grade NAT) or unspecified address. Webhooks are never sent
+/// to such addresses, as they would reach the internal network of the application instead.
+pub fn is_non_public_ip_address(ip_address: IpAddr) -> bool {
+    match ip_address {
+        IpAddr::V4(ip_address) => {
+            let [first_octet, second_octet, ..] = ip_address.octets();
+            ip_address.is_loopback()
+                || ip_address.is_private()
+                || ip_address.is_link_local()
+                || ip_address.is_unspecified()
+                || ip_address.is_broadcast()
+                // 100.64.0.0/10
+                || (first_octet == 100 && second_octet & 0b1100_0000 == 0b0100_0000)
+        }
+        IpAddr::V6(ip_address) => {
+            let first_segment = ip_address.segments()[0];
+            ip_address.is_loopback()
+                || ip_address.is_unspecified()
+                // Unique local addresses, fc00::/7
+                || first_segment & 0xfe00 == 0xfc00
+                // Link-local unicast addresses, fe80::/10
+                || first_segment & 0xffc0 == 0xfe80
+                || ip_address
+                    .to_ipv4_mapped()
+                    .is_some_and(|ip_address| is_non_public_ip_address(IpAddr::V4(ip_address)))
+        }
+    }
+}
+
+/// Returns whether the host of a webhook URL is known not to be reachable on the public internet,
+/// without resolving domain names.
+pub fn is_non_public_host(host: &url::Host<&str>) -> bool {
+    match host {
+        url::Host::Domain(domain) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+        url::Host::Ipv4(ip_address) => is_non_public_ip_address(IpAddr::V4(*ip_address)),
+        url::Host::Ipv6(ip_address) => is_non_public_ip_address(IpAddr::V6(*ip_address)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_is_non_public_ip_address() {
+        let is_non_public =
+            |ip_address: &str| is_non_public_ip_address(ip_address.parse().unwrap());
+
+        assert!(is_non_public("127.0.0.1"));
+        assert!(is_non_public("10.1.2.3"));
+        assert!(is_non_public("172.16.0.1"));
+        assert!(is_non_public("192.168.0.1"));
+        assert!(is_non_public("169.254.169.254"));
+        assert!(is_non_public("100.64.0.1"));
+        assert!(is_non_public("0.0.0.0"));
+        assert!(is_non_public("::1"));
+        assert!(is_non_public("fd00::1"));
+        assert!(is_non_public("fe80::1"));
+        assert!(is_non_public("::ffff:10.0.0.1"));
+
+        assert!(!is_non_public("93.184.216.34"));
+        assert!(!is_non_public("100.128.0.1"));
+        assert!(!is_non_public("2606:2800:220:1:248:1893:25c8:1946"));
+    }
+}
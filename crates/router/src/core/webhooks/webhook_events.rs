@@ -4,6 +4,7 @@ use router_env::{instrument, tracing};
 
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
     routes::SessionState,
     services::ApplicationResponse,
     types::{api, domain, storage, transformers::ForeignTryFrom},
@@ -117,28 +118,8 @@ pub async fn list_delivery_attempts(
     let (account, key_store) =
         determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
 
-    let events = match account {
-        MerchantAccountOrBusinessProfile::MerchantAccount(merchant_account) => {
-            store
-                .list_events_by_merchant_id_initial_attempt_id(
-                    &merchant_account.merchant_id,
-                    &initial_attempt_id,
-                    &key_store,
-                )
-                .await
-        }
-        MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) => {
-            store
-                .list_events_by_profile_id_initial_attempt_id(
-                    &business_profile.profile_id,
-                    &initial_attempt_id,
-                    &key_store,
-                )
-                .await
-        }
-    }
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Failed to list delivery attempts for initial event")?;
+    let events =
+        list_events_by_initial_attempt_id(store, &account, &initial_attempt_id, &key_store).await?;
 
     if events.is_empty() {
         Err(error_stack::report!(
@@ -155,6 +136,76 @@ pub async fn list_delivery_attempts(
     }
 }
 
+#[instrument(skip(state))]
+pub async fn retrieve_delivery_retry_status(
+    state: SessionState,
+    merchant_id_or_profile_id: String,
+    initial_attempt_id: String,
+) -> RouterResponse<api::webhook_events::WebhookDeliveryRetryStatusResponse> {
+    let store = state.store.as_ref();
+
+    let (account, key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let events =
+        list_events_by_initial_attempt_id(store, &account, &initial_attempt_id, &key_store).await?;
+
+    let attempt_count = events.len();
+    let is_delivered = events.iter().any(|event| event.is_webhook_notified);
+    let last_attempt = events
+        .into_iter()
+        .max_by_key(|event| event.created_at)
+        .ok_or(errors::ApiErrorResponse::EventNotFound)
+        .attach_printable("No delivery attempts found with the specified `initial_attempt_id`")?;
+    let last_attempted_at = last_attempt.created_at;
+    let last_status_code = api::webhook_events::EventRetrieveResponse::try_from(last_attempt)?
+        .response
+        .status_code;
+
+    // Automatic retries are tracked by the outgoing webhook retry task of the initial attempt
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+        super::outgoing::OUTGOING_WEBHOOK_RETRY_TASK,
+        &initial_attempt_id,
+        &key_store.merchant_id,
+    );
+    let retry_task = store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve webhook retry task from process tracker")?;
+
+    let (retry_status, next_retry_at) = match retry_task {
+        _ if is_delivered => (
+            api::webhook_events::WebhookDeliveryRetryStatus::Delivered,
+            None,
+        ),
+        Some(task) if task.business_status == diesel_models::business_status::RETRIES_EXCEEDED => (
+            api::webhook_events::WebhookDeliveryRetryStatus::Exhausted,
+            None,
+        ),
+        Some(task) if task.status != storage::enums::ProcessTrackerStatus::Finish => (
+            api::webhook_events::WebhookDeliveryRetryStatus::Retrying,
+            task.schedule_time,
+        ),
+        Some(_) | None => (
+            api::webhook_events::WebhookDeliveryRetryStatus::NotScheduled,
+            None,
+        ),
+    };
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookDeliveryRetryStatusResponse {
+            initial_attempt_id,
+            attempt_count,
+            last_status_code,
+            last_attempted_at,
+            retry_status,
+            next_retry_at,
+        },
+    ))
+}
+
 #[instrument(skip(state))]
 pub async fn retry_delivery_attempt(
     state: SessionState,
@@ -254,6 +305,36 @@ pub async fn retry_delivery_attempt(
     ))
 }
 
+async fn list_events_by_initial_attempt_id(
+    store: &dyn StorageInterface,
+    account: &MerchantAccountOrBusinessProfile,
+    initial_attempt_id: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<Vec<domain::Event>> {
+    match account {
+        MerchantAccountOrBusinessProfile::MerchantAccount(merchant_account) => {
+            store
+                .list_events_by_merchant_id_initial_attempt_id(
+                    &merchant_account.merchant_id,
+                    initial_attempt_id,
+                    key_store,
+                )
+                .await
+        }
+        MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) => {
+            store
+                .list_events_by_profile_id_initial_attempt_id(
+                    &business_profile.profile_id,
+                    initial_attempt_id,
+                    key_store,
+                )
+                .await
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to list delivery attempts for initial event")
+}
+
 async fn determine_identifier_and_get_key_store(
     state: SessionState,
     merchant_id_or_profile_id: String,
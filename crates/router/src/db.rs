@@ -9,6 +9,7 @@ pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod currency_conversion_audit_log;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -28,10 +29,14 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payout_destination_verification;
+pub mod payout_hold;
+pub mod payout_template;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod store_credit_ledger_entry;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
@@ -82,6 +87,7 @@ pub trait StorageInterface:
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
+    + currency_conversion_audit_log::CurrencyConversionAuditLogInterface
     + customers::CustomerInterface
     + dashboard_metadata::DashboardMetadataInterface
     + dispute::DisputeInterface
@@ -102,6 +108,10 @@ pub trait StorageInterface:
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface
     + PayoutsInterface
+    + payout_destination_verification::PayoutDestinationVerificationInterface
+    + payout_hold::PayoutHoldInterface
+    + payout_template::PayoutTemplateInterface
+    + store_credit_ledger_entry::StoreCreditLedgerEntryInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
@@ -0,0 +1,207 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CurrencyConversionAuditLogInterface {
+    async fn insert_currency_conversion_audit_log(
+        &self,
+        audit_log: storage::CurrencyConversionAuditLogNew,
+    ) -> CustomResult<storage::CurrencyConversionAuditLog, errors::StorageError>;
+
+    async fn list_currency_conversion_audit_logs_by_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Vec<storage::CurrencyConversionAuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CurrencyConversionAuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_currency_conversion_audit_log(
+        &self,
+        audit_log: storage::CurrencyConversionAuditLogNew,
+    ) -> CustomResult<storage::CurrencyConversionAuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit_log
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_currency_conversion_audit_logs_by_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Vec<storage::CurrencyConversionAuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CurrencyConversionAuditLog::list_by_merchant_id_payout_id(
+            &conn,
+            merchant_id,
+            payout_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CurrencyConversionAuditLogInterface for MockDb {
+    async fn insert_currency_conversion_audit_log(
+        &self,
+        audit_log: storage::CurrencyConversionAuditLogNew,
+    ) -> CustomResult<storage::CurrencyConversionAuditLog, errors::StorageError> {
+        let mut audit_logs = self.currency_conversion_audit_logs.lock().await;
+        if audit_logs
+            .iter()
+            .any(|log| log.audit_id == audit_log.audit_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "currency_conversion_audit_log",
+                key: Some(audit_log.audit_id.clone()),
+            })?
+        }
+        let audit_log = storage::CurrencyConversionAuditLog {
+            audit_id: audit_log.audit_id,
+            merchant_id: audit_log.merchant_id,
+            payout_id: audit_log.payout_id,
+            source_amount: audit_log.source_amount,
+            source_currency: audit_log.source_currency,
+            destination_currency: audit_log.destination_currency,
+            converted_amount: audit_log.converted_amount,
+            exchange_rate: audit_log.exchange_rate,
+            exchange_rate_source: audit_log.exchange_rate_source,
+            exchange_rate_timestamp: audit_log.exchange_rate_timestamp,
+            is_binding: audit_log.is_binding,
+            created_at: audit_log.created_at,
+        };
+        audit_logs.push(audit_log.clone());
+        Ok(audit_log)
+    }
+
+    async fn list_currency_conversion_audit_logs_by_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Vec<storage::CurrencyConversionAuditLog>, errors::StorageError> {
+        let mut audit_logs = self
+            .currency_conversion_audit_logs
+            .lock()
+            .await
+            .iter()
+            .filter(|log| {
+                log.merchant_id == merchant_id && log.payout_id.as_deref() == Some(payout_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        audit_logs.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(audit_logs)
+    }
+}
+
+#[async_trait::async_trait]
+impl CurrencyConversionAuditLogInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_currency_conversion_audit_log(
+        &self,
+        audit_log: storage::CurrencyConversionAuditLogNew,
+    ) -> CustomResult<storage::CurrencyConversionAuditLog, errors::StorageError> {
+        self.diesel_store
+            .insert_currency_conversion_audit_log(audit_log)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_currency_conversion_audit_logs_by_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Vec<storage::CurrencyConversionAuditLog>, errors::StorageError> {
+        self.diesel_store
+            .list_currency_conversion_audit_logs_by_payout_id(merchant_id, payout_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    mod mockdb_currency_conversion_audit_log_interface {
+        use common_utils::types::MinorUnit;
+        use redis_interface::RedisSettings;
+
+        use crate::{
+            db::{currency_conversion_audit_log::CurrencyConversionAuditLogInterface, MockDb},
+            types::storage,
+        };
+
+        fn create_audit_log_new(
+            audit_id: &str,
+            payout_id: Option<&str>,
+        ) -> storage::CurrencyConversionAuditLogNew {
+            let now = common_utils::date_time::now();
+            storage::CurrencyConversionAuditLogNew {
+                audit_id: audit_id.to_string(),
+                merchant_id: "merchant_1".to_string(),
+                payout_id: payout_id.map(ToString::to_string),
+                source_amount: MinorUnit::new(1000),
+                source_currency: "EUR".to_string(),
+                destination_currency: "USD".to_string(),
+                converted_amount: "10.86957".to_string(),
+                exchange_rate: "1.086957".to_string(),
+                exchange_rate_source: None,
+                exchange_rate_timestamp: now,
+                is_binding: payout_id.is_some(),
+                created_at: now,
+            }
+        }
+
+        #[tokio::test]
+        async fn test_list_currency_conversion_audit_logs_by_payout_id() {
+            #[allow(clippy::expect_used)]
+            let mockdb = MockDb::new(&RedisSettings::default())
+                .await
+                .expect("Failed to create a mock DB");
+
+            for audit_log in [
+                create_audit_log_new("conv_audit_1", Some("payout_1")),
+                create_audit_log_new("conv_audit_2", None),
+                create_audit_log_new("conv_audit_3", Some("payout_2")),
+                create_audit_log_new("conv_audit_4", Some("payout_1")),
+            ] {
+                mockdb
+                    .insert_currency_conversion_audit_log(audit_log)
+                    .await
+                    .unwrap();
+            }
+
+            let audit_logs = mockdb
+                .list_currency_conversion_audit_logs_by_payout_id("merchant_1", "payout_1")
+                .await
+                .unwrap();
+            assert_eq!(
+                audit_logs
+                    .iter()
+                    .map(|audit_log| audit_log.audit_id.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["conv_audit_1", "conv_audit_4"]
+            );
+            assert!(audit_logs.iter().all(|audit_log| audit_log.is_binding));
+
+            assert!(mockdb
+                .insert_currency_conversion_audit_log(create_audit_log_new("conv_audit_1", None))
+                .await
+                .is_err());
+        }
+    }
+}
@@ -1,18 +1,41 @@
 use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
 use diesel_models::ConfigNew;
 use error_stack::ResultExt;
+use external_services::file_storage::FileStorageInterface;
+use hyperswitch_interfaces::secrets_interface::SecretManagementInterface;
+use masking::Secret;
 use router_env::{instrument, logger, tracing};
 
 use super::{MockDb, Store};
 use crate::{
-    connection,
+    connection, consts,
     core::errors::{self, CustomResult},
     types::storage,
+    utils,
 };
 
 #[async_trait::async_trait]
 pub trait HealthCheckDbInterface {
     async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError>;
+
+    /// Returns the version of the latest migration applied to the database
+    async fn get_applied_schema_version(
+        &self,
+    ) -> CustomResult<Option<String>, errors::HealthCheckDBError>;
+
+    /// Uploads a test object to the file store, reads it back and deletes it
+    async fn health_check_file_store(
+        &self,
+        file_storage_client: &dyn FileStorageInterface,
+    ) -> CustomResult<(), errors::HealthCheckFileStoreError>;
+
+    /// Decrypts the probe secret with the secrets manager, to verify that it is reachable and
+    /// that the application is authorized to use it
+    async fn health_check_secrets_manager(
+        &self,
+        secrets_management_client: &dyn SecretManagementInterface,
+        probe_secret: &str,
+    ) -> CustomResult<(), errors::HealthCheckSecretsManagerError>;
 }
 
 #[async_trait::async_trait]
@@ -59,6 +82,93 @@ impl HealthCheckDbInterface for Store {
 
         Ok(())
     }
+
+    #[instrument(skip_all)]
+    async fn get_applied_schema_version(
+        &self,
+    ) -> CustomResult<Option<String>, errors::HealthCheckDBError> {
+        let conn = connection::pg_connection_read(self)
+            .await
+            .change_context(errors::HealthCheckDBError::DBError)?;
+
+        let query = diesel::select(diesel::dsl::sql::<
+            diesel::sql_types::Nullable<diesel::sql_types::Text>,
+        >(
+            "(SELECT MAX(version) FROM __diesel_schema_migrations)"
+        ));
+        query.get_result_async(&conn).await.map_err(|err| {
+            logger::error!(read_err=?err, "Error while reading the applied migrations");
+            error_stack::report!(errors::HealthCheckDBError::DBReadError)
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn health_check_file_store(
+        &self,
+        file_storage_client: &dyn FileStorageInterface,
+    ) -> CustomResult<(), errors::HealthCheckFileStoreError> {
+        let file_key = utils::generate_id(
+            consts::ID_LENGTH,
+            consts::FILE_STORE_HEALTH_CHECK_KEY_PREFIX,
+        );
+        let test_object = file_key.as_bytes().to_vec();
+
+        file_storage_client
+            .upload_file(&file_key, test_object.clone())
+            .await
+            .change_context(errors::HealthCheckFileStoreError::UploadFailed)?;
+
+        logger::debug!("File store upload was successful");
+
+        let retrieved_object = file_storage_client
+            .retrieve_file(&file_key)
+            .await
+            .change_context(errors::HealthCheckFileStoreError::RetrieveFailed);
+
+        // Delete the test object irrespective of the retrieval outcome
+        let delete_result = file_storage_client
+            .delete_file(&file_key)
+            .await
+            .change_context(errors::HealthCheckFileStoreError::DeleteFailed);
+
+        let retrieved_object = retrieved_object?;
+        logger::debug!("File store retrieve was successful");
+
+        if retrieved_object != test_object {
+            return Err(error_stack::report!(
+                errors::HealthCheckFileStoreError::RoundTripDataMismatch
+            ));
+        }
+
+        delete_result?;
+        logger::debug!("File store delete was successful");
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn health_check_secrets_manager(
+        &self,
+        secrets_management_client: &dyn SecretManagementInterface,
+        probe_secret: &str,
+    ) -> CustomResult<(), errors::HealthCheckSecretsManagerError> {
+        decrypt_probe_secret(secrets_management_client, probe_secret).await
+    }
+}
+
+async fn decrypt_probe_secret(
+    secrets_management_client: &dyn SecretManagementInterface,
+    probe_secret: &str,
+) -> CustomResult<(), errors::HealthCheckSecretsManagerError> {
+    // The decrypted value is dropped right away, it must never be logged
+    let _decrypted_secret = secrets_management_client
+        .get_secret(Secret::new(probe_secret.to_string()))
+        .await
+        .change_context(errors::HealthCheckSecretsManagerError::DecryptFailed)?;
+
+    logger::debug!("Secrets manager decrypt was successful");
+
+    Ok(())
 }
 
 #[async_trait::async_trait]
@@ -66,4 +176,73 @@ impl HealthCheckDbInterface for MockDb {
     async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError> {
         Ok(())
     }
+
+    async fn get_applied_schema_version(
+        &self,
+    ) -> CustomResult<Option<String>, errors::HealthCheckDBError> {
+        // The mock database has no migrations, its schema always matches the application
+        Ok(consts::EXPECTED_SCHEMA_VERSION.map(ToString::to_string))
+    }
+
+    async fn health_check_file_store(
+        &self,
+        _file_storage_client: &dyn FileStorageInterface,
+    ) -> CustomResult<(), errors::HealthCheckFileStoreError> {
+        Ok(())
+    }
+
+    async fn health_check_secrets_manager(
+        &self,
+        _secrets_management_client: &dyn SecretManagementInterface,
+        _probe_secret: &str,
+    ) -> CustomResult<(), errors::HealthCheckSecretsManagerError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use hyperswitch_interfaces::secrets_interface::SecretsManagementError;
+
+    use super::*;
+
+    struct TestSecretsManager {
+        is_reachable: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl SecretManagementInterface for TestSecretsManager {
+        async fn get_secret(
+            &self,
+            input: Secret<String>,
+        ) -> CustomResult<Secret<String>, SecretsManagementError> {
+            if self.is_reachable {
+                Ok(input)
+            } else {
+                Err(error_stack::report!(
+                    SecretsManagementError::FetchSecretFailed
+                ))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_probe_secret() {
+        let reachable = TestSecretsManager { is_reachable: true };
+        assert!(decrypt_probe_secret(&reachable, "health_check_probe")
+            .await
+            .is_ok());
+
+        let unreachable = TestSecretsManager {
+            is_reachable: false,
+        };
+        let error = decrypt_probe_secret(&unreachable, "health_check_probe")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::HealthCheckSecretsManagerError::DecryptFailed
+        ));
+    }
 }
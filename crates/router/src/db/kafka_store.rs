@@ -9,6 +9,7 @@ use diesel_models::{
     reverse_lookup::{ReverseLookup, ReverseLookupNew},
     user_role as user_storage,
 };
+use external_services::file_storage::FileStorageInterface;
 use hyperswitch_domain_models::payments::{
     payment_attempt::PaymentAttemptInterface, payment_intent::PaymentIntentInterface,
 };
@@ -18,6 +19,7 @@ use hyperswitch_domain_models::payouts::{
 };
 #[cfg(not(feature = "payouts"))]
 use hyperswitch_domain_models::{PayoutAttemptInterface, PayoutsInterface};
+use hyperswitch_interfaces::secrets_interface::SecretManagementInterface;
 use masking::Secret;
 use redis_interface::{errors::RedisError, RedisConnectionPool, RedisEntryId};
 use router_env::logger;
@@ -1604,6 +1606,36 @@ impl PayoutAttemptInterface for KafkaStore {
             .await
     }
 
+    async fn list_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        merchant_id: &str,
+        payout_attempt_ids: &[String],
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PayoutAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .list_payout_attempts_by_merchant_id_payout_attempt_ids(
+                merchant_id,
+                payout_attempt_ids,
+                storage_scheme,
+            )
+            .await
+    }
+
+    async fn list_payout_attempts_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PayoutAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .list_payout_attempts_by_merchant_id_connector_payout_id(
+                merchant_id,
+                connector_payout_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn update_payout_attempt(
         &self,
         this: &storage::PayoutAttempt,
@@ -1630,6 +1662,41 @@ impl PayoutAttemptInterface for KafkaStore {
         Ok(updated_payout_attempt)
     }
 
+    async fn update_payout_attempt_if_status(
+        &self,
+        this: &storage::PayoutAttempt,
+        expected_status: enums::PayoutStatus,
+        payout_attempt_update: storage::PayoutAttemptUpdate,
+        payouts: &storage::Payouts,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Option<storage::PayoutAttempt>, errors::DataStorageError> {
+        let updated_payout_attempt = self
+            .diesel_store
+            .update_payout_attempt_if_status(
+                this,
+                expected_status,
+                payout_attempt_update,
+                payouts,
+                storage_scheme,
+            )
+            .await?;
+        if let Some(updated_payout_attempt) = &updated_payout_attempt {
+            if let Err(err) = self
+                .kafka_producer
+                .log_payout(
+                    &KafkaPayout::from_storage(payouts, updated_payout_attempt),
+                    Some(KafkaPayout::from_storage(payouts, this)),
+                    self.tenant_id.clone(),
+                )
+                .await
+            {
+                logger::error!(message="Failed to update analytics entry for Payouts {payouts:?}\n{updated_payout_attempt:?}", error_message=?err);
+            };
+        }
+
+        Ok(updated_payout_attempt)
+    }
+
     async fn insert_payout_attempt(
         &self,
         payout_attempt: storage::PayoutAttemptNew,
@@ -1668,6 +1735,21 @@ impl PayoutAttemptInterface for KafkaStore {
             .get_filters_for_payouts(payouts, merchant_id, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "olap")]
+    async fn get_connector_usage_for_payouts(
+        &self,
+        merchant_id: &str,
+        filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<
+        Vec<hyperswitch_domain_models::payouts::payout_attempt::PayoutConnectorUsage>,
+        errors::DataStorageError,
+    > {
+        self.diesel_store
+            .get_connector_usage_for_payouts(merchant_id, filters, storage_scheme)
+            .await
+    }
 }
 
 #[cfg(not(feature = "payouts"))]
@@ -1733,6 +1815,32 @@ impl PayoutsInterface for KafkaStore {
             .await
     }
 
+    async fn find_optional_payout_by_merchant_id_merchant_reference_id(
+        &self,
+        merchant_id: &str,
+        merchant_reference_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Option<storage::Payouts>, errors::DataStorageError> {
+        self.diesel_store
+            .find_optional_payout_by_merchant_id_merchant_reference_id(
+                merchant_id,
+                merchant_reference_id,
+                storage_scheme,
+            )
+            .await
+    }
+
+    async fn find_payouts_by_merchant_id_series_id(
+        &self,
+        merchant_id: &str,
+        series_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payouts_by_merchant_id_series_id(merchant_id, series_id, storage_scheme)
+            .await
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
@@ -1775,6 +1883,43 @@ impl PayoutsInterface for KafkaStore {
             .filter_payouts_by_time_range_constraints(merchant_id, time_range, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "olap")]
+    async fn find_earlier_payout_by_fingerprint_for_other_customer(
+        &self,
+        merchant_id: &str,
+        payout_method_fingerprint: &str,
+        customer_id: &id_type::CustomerId,
+        created_before: PrimitiveDateTime,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Option<storage::Payouts>, errors::DataStorageError> {
+        self.diesel_store
+            .find_earlier_payout_by_fingerprint_for_other_customer(
+                merchant_id,
+                payout_method_fingerprint,
+                customer_id,
+                created_before,
+                storage_scheme,
+            )
+            .await
+    }
+
+    async fn get_payout_count_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        created_at_or_before: PrimitiveDateTime,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::DataStorageError> {
+        self.diesel_store
+            .get_payout_count_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                created_at_or_before,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2821,6 +2966,31 @@ impl HealthCheckDbInterface for KafkaStore {
     async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError> {
         self.diesel_store.health_check_db().await
     }
+
+    async fn get_applied_schema_version(
+        &self,
+    ) -> CustomResult<Option<String>, errors::HealthCheckDBError> {
+        self.diesel_store.get_applied_schema_version().await
+    }
+
+    async fn health_check_file_store(
+        &self,
+        file_storage_client: &dyn FileStorageInterface,
+    ) -> CustomResult<(), errors::HealthCheckFileStoreError> {
+        self.diesel_store
+            .health_check_file_store(file_storage_client)
+            .await
+    }
+
+    async fn health_check_secrets_manager(
+        &self,
+        secrets_management_client: &dyn SecretManagementInterface,
+        probe_secret: &str,
+    ) -> CustomResult<(), errors::HealthCheckSecretsManagerError> {
+        self.diesel_store
+            .health_check_secrets_manager(secrets_management_client, probe_secret)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -0,0 +1,346 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutDestinationVerificationInterface {
+    async fn insert_payout_destination_verification(
+        &self,
+        verification: storage::PayoutDestinationVerificationNew,
+    ) -> CustomResult<storage::PayoutDestinationVerification, errors::StorageError>;
+
+    async fn find_payout_destination_verification_by_merchant_id_verification_id(
+        &self,
+        merchant_id: &str,
+        verification_id: &str,
+    ) -> CustomResult<storage::PayoutDestinationVerification, errors::StorageError>;
+
+    async fn list_payout_destination_verifications_by_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        payout_method_fingerprint: &str,
+    ) -> CustomResult<Vec<storage::PayoutDestinationVerification>, errors::StorageError>;
+
+    /// Updates the verification only if it is unchanged since it was read, returning `None` when
+    /// it has been updated in the meantime.
+    async fn update_payout_destination_verification_if_unchanged(
+        &self,
+        this: storage::PayoutDestinationVerification,
+        update: storage::PayoutDestinationVerificationUpdate,
+    ) -> CustomResult<Option<storage::PayoutDestinationVerification>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutDestinationVerificationInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_destination_verification(
+        &self,
+        verification: storage::PayoutDestinationVerificationNew,
+    ) -> CustomResult<storage::PayoutDestinationVerification, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        verification
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_destination_verification_by_merchant_id_verification_id(
+        &self,
+        merchant_id: &str,
+        verification_id: &str,
+    ) -> CustomResult<storage::PayoutDestinationVerification, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutDestinationVerification::find_by_merchant_id_verification_id(
+            &conn,
+            merchant_id,
+            verification_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_destination_verifications_by_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        payout_method_fingerprint: &str,
+    ) -> CustomResult<Vec<storage::PayoutDestinationVerification>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutDestinationVerification::list_by_merchant_id_customer_id_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            payout_method_fingerprint,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_destination_verification_if_unchanged(
+        &self,
+        this: storage::PayoutDestinationVerification,
+        update: storage::PayoutDestinationVerificationUpdate,
+    ) -> CustomResult<Option<storage::PayoutDestinationVerification>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_if_unchanged(&conn, update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutDestinationVerificationInterface for MockDb {
+    async fn insert_payout_destination_verification(
+        &self,
+        verification: storage::PayoutDestinationVerificationNew,
+    ) -> CustomResult<storage::PayoutDestinationVerification, errors::StorageError> {
+        let mut verifications = self.payout_destination_verifications.lock().await;
+        if verifications
+            .iter()
+            .any(|v| v.verification_id == verification.verification_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payout_destination_verification",
+                key: Some(verification.verification_id.clone()),
+            })?
+        }
+        let verification = storage::PayoutDestinationVerification {
+            verification_id: verification.verification_id,
+            merchant_id: verification.merchant_id,
+            customer_id: verification.customer_id,
+            payout_method_fingerprint: verification.payout_method_fingerprint,
+            status: verification.status,
+            micro_deposits: verification.micro_deposits,
+            remaining_attempts: verification.remaining_attempts,
+            created_at: verification.created_at,
+            modified_at: verification.modified_at,
+            verified_at: verification.verified_at,
+        };
+        verifications.push(verification.clone());
+        Ok(verification)
+    }
+
+    async fn find_payout_destination_verification_by_merchant_id_verification_id(
+        &self,
+        merchant_id: &str,
+        verification_id: &str,
+    ) -> CustomResult<storage::PayoutDestinationVerification, errors::StorageError> {
+        self.payout_destination_verifications
+            .lock()
+            .await
+            .iter()
+            .find(|v| v.merchant_id == merchant_id && v.verification_id == verification_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout destination verification available for verification_id = {verification_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn list_payout_destination_verifications_by_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        payout_method_fingerprint: &str,
+    ) -> CustomResult<Vec<storage::PayoutDestinationVerification>, errors::StorageError> {
+        let mut verifications = self
+            .payout_destination_verifications
+            .lock()
+            .await
+            .iter()
+            .filter(|v| {
+                v.merchant_id == merchant_id
+                    && &v.customer_id == customer_id
+                    && v.payout_method_fingerprint == payout_method_fingerprint
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        verifications.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(verifications)
+    }
+
+    async fn update_payout_destination_verification_if_unchanged(
+        &self,
+        this: storage::PayoutDestinationVerification,
+        update: storage::PayoutDestinationVerificationUpdate,
+    ) -> CustomResult<Option<storage::PayoutDestinationVerification>, errors::StorageError> {
+        let update = storage::PayoutDestinationVerificationUpdateInternal::from(update);
+        Ok(self
+            .payout_destination_verifications
+            .lock()
+            .await
+            .iter_mut()
+            .find(|v| {
+                v.verification_id == this.verification_id
+                    && v.status == this.status
+                    && v.remaining_attempts == this.remaining_attempts
+            })
+            .map(|verification| {
+                if let Some(status) = update.status {
+                    verification.status = status;
+                }
+                if let Some(remaining_attempts) = update.remaining_attempts {
+                    verification.remaining_attempts = remaining_attempts;
+                }
+                verification.verified_at = update.verified_at.or(verification.verified_at);
+                verification.modified_at = update.modified_at;
+                verification.clone()
+            }))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutDestinationVerificationInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payout_destination_verification(
+        &self,
+        verification: storage::PayoutDestinationVerificationNew,
+    ) -> CustomResult<storage::PayoutDestinationVerification, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_destination_verification(verification)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_destination_verification_by_merchant_id_verification_id(
+        &self,
+        merchant_id: &str,
+        verification_id: &str,
+    ) -> CustomResult<storage::PayoutDestinationVerification, errors::StorageError> {
+        self.diesel_store
+            .find_payout_destination_verification_by_merchant_id_verification_id(
+                merchant_id,
+                verification_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_destination_verifications_by_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        payout_method_fingerprint: &str,
+    ) -> CustomResult<Vec<storage::PayoutDestinationVerification>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_destination_verifications_by_fingerprint(
+                merchant_id,
+                customer_id,
+                payout_method_fingerprint,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_destination_verification_if_unchanged(
+        &self,
+        this: storage::PayoutDestinationVerification,
+        update: storage::PayoutDestinationVerificationUpdate,
+    ) -> CustomResult<Option<storage::PayoutDestinationVerification>, errors::StorageError> {
+        self.diesel_store
+            .update_payout_destination_verification_if_unchanged(this, update)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    mod mockdb_payout_destination_verification_interface {
+        use common_utils::id_type;
+        use diesel_models::enums::PayoutDestinationVerificationStatus;
+        use redis_interface::RedisSettings;
+
+        use crate::{
+            db::{payout_destination_verification::PayoutDestinationVerificationInterface, MockDb},
+            types::storage,
+        };
+
+        fn create_verification_new(
+            verification_id: &str,
+        ) -> storage::PayoutDestinationVerificationNew {
+            let now = common_utils::date_time::now();
+            storage::PayoutDestinationVerificationNew {
+                verification_id: verification_id.to_string(),
+                merchant_id: "merchant_1".to_string(),
+                customer_id: id_type::CustomerId::from("customer_1".into()).unwrap(),
+                payout_method_fingerprint: "fingerprint_1".to_string(),
+                status: PayoutDestinationVerificationStatus::Pending,
+                micro_deposits: serde_json::json!([]),
+                remaining_attempts: 3,
+                created_at: now,
+                modified_at: now,
+                verified_at: None,
+            }
+        }
+
+        #[tokio::test]
+        async fn test_update_payout_destination_verification_if_unchanged() {
+            #[allow(clippy::expect_used)]
+            let mockdb = MockDb::new(&RedisSettings::default())
+                .await
+                .expect("Failed to create a mock DB");
+
+            let verification = mockdb
+                .insert_payout_destination_verification(create_verification_new(
+                    "payout_verification_1",
+                ))
+                .await
+                .unwrap();
+
+            // Two confirmations which read the verification concurrently use up the same attempt,
+            // so only the first of them is applied
+            let failed_attempt = storage::PayoutDestinationVerificationUpdate::StatusUpdate {
+                status: PayoutDestinationVerificationStatus::Pending,
+                remaining_attempts: verification.remaining_attempts - 1,
+                verified_at: None,
+            };
+            let updated = mockdb
+                .update_payout_destination_verification_if_unchanged(
+                    verification.clone(),
+                    failed_attempt,
+                )
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(updated.remaining_attempts, 2);
+
+            let verified = storage::PayoutDestinationVerificationUpdate::StatusUpdate {
+                status: PayoutDestinationVerificationStatus::Verified,
+                remaining_attempts: verification.remaining_attempts,
+                verified_at: Some(common_utils::date_time::now()),
+            };
+            assert!(mockdb
+                .update_payout_destination_verification_if_unchanged(verification, verified)
+                .await
+                .unwrap()
+                .is_none());
+
+            let found = mockdb
+                .find_payout_destination_verification_by_merchant_id_verification_id(
+                    "merchant_1",
+                    "payout_verification_1",
+                )
+                .await
+                .unwrap();
+            assert_eq!(found.status, PayoutDestinationVerificationStatus::Pending);
+            assert_eq!(found.remaining_attempts, 2);
+            assert_eq!(found.verified_at, None);
+        }
+    }
+}
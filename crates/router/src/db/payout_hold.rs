@@ -0,0 +1,314 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait PayoutHoldInterface {
+    async fn insert_payout_hold(
+        &self,
+        hold: storage::PayoutHoldNew,
+    ) -> CustomResult<storage::PayoutHold, errors::StorageError>;
+
+    async fn find_payout_hold_by_merchant_id_hold_id(
+        &self,
+        merchant_id: &str,
+        hold_id: &str,
+    ) -> CustomResult<storage::PayoutHold, errors::StorageError>;
+
+    async fn list_payout_holds_by_merchant_id_status(
+        &self,
+        merchant_id: &str,
+        status: enums::PayoutHoldStatus,
+    ) -> CustomResult<Vec<storage::PayoutHold>, errors::StorageError>;
+
+    /// Updates the hold only if it is still held, returning `None` when it has been captured,
+    /// released or expired in the meantime.
+    async fn update_payout_hold_if_held(
+        &self,
+        this: storage::PayoutHold,
+        update: storage::PayoutHoldUpdate,
+    ) -> CustomResult<Option<storage::PayoutHold>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutHoldInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_hold(
+        &self,
+        hold: storage::PayoutHoldNew,
+    ) -> CustomResult<storage::PayoutHold, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        hold.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_hold_by_merchant_id_hold_id(
+        &self,
+        merchant_id: &str,
+        hold_id: &str,
+    ) -> CustomResult<storage::PayoutHold, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutHold::find_by_merchant_id_hold_id(&conn, merchant_id, hold_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_holds_by_merchant_id_status(
+        &self,
+        merchant_id: &str,
+        status: enums::PayoutHoldStatus,
+    ) -> CustomResult<Vec<storage::PayoutHold>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutHold::list_by_merchant_id_status(&conn, merchant_id, status)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_hold_if_held(
+        &self,
+        this: storage::PayoutHold,
+        update: storage::PayoutHoldUpdate,
+    ) -> CustomResult<Option<storage::PayoutHold>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_if_held(&conn, update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutHoldInterface for MockDb {
+    async fn insert_payout_hold(
+        &self,
+        hold: storage::PayoutHoldNew,
+    ) -> CustomResult<storage::PayoutHold, errors::StorageError> {
+        let mut holds = self.payout_holds.lock().await;
+        if holds.iter().any(|h| h.hold_id == hold.hold_id) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payout_hold",
+                key: Some(hold.hold_id.clone()),
+            })?
+        }
+        let hold = storage::PayoutHold {
+            hold_id: hold.hold_id,
+            merchant_id: hold.merchant_id,
+            amount: hold.amount,
+            currency: hold.currency,
+            status: hold.status,
+            payout_id: hold.payout_id,
+            description: hold.description,
+            expires_at: hold.expires_at,
+            created_at: hold.created_at,
+            modified_at: hold.modified_at,
+        };
+        holds.push(hold.clone());
+        Ok(hold)
+    }
+
+    async fn find_payout_hold_by_merchant_id_hold_id(
+        &self,
+        merchant_id: &str,
+        hold_id: &str,
+    ) -> CustomResult<storage::PayoutHold, errors::StorageError> {
+        self.payout_holds
+            .lock()
+            .await
+            .iter()
+            .find(|h| h.merchant_id == merchant_id && h.hold_id == hold_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout hold available for hold_id = {hold_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn list_payout_holds_by_merchant_id_status(
+        &self,
+        merchant_id: &str,
+        status: enums::PayoutHoldStatus,
+    ) -> CustomResult<Vec<storage::PayoutHold>, errors::StorageError> {
+        let mut holds = self
+            .payout_holds
+            .lock()
+            .await
+            .iter()
+            .filter(|h| h.merchant_id == merchant_id && h.status == status)
+            .cloned()
+            .collect::<Vec<_>>();
+        holds.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(holds)
+    }
+
+    async fn update_payout_hold_if_held(
+        &self,
+        this: storage::PayoutHold,
+        update: storage::PayoutHoldUpdate,
+    ) -> CustomResult<Option<storage::PayoutHold>, errors::StorageError> {
+        let update = storage::PayoutHoldUpdateInternal::from(update);
+        Ok(self
+            .payout_holds
+            .lock()
+            .await
+            .iter_mut()
+            .find(|h| h.hold_id == this.hold_id && h.status == enums::PayoutHoldStatus::Held)
+            .map(|hold| {
+                if let Some(status) = update.status {
+                    hold.status = status;
+                }
+                hold.payout_id = update.payout_id.or(hold.payout_id.take());
+                hold.modified_at = update.modified_at;
+                hold.clone()
+            }))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutHoldInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payout_hold(
+        &self,
+        hold: storage::PayoutHoldNew,
+    ) -> CustomResult<storage::PayoutHold, errors::StorageError> {
+        self.diesel_store.insert_payout_hold(hold).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_hold_by_merchant_id_hold_id(
+        &self,
+        merchant_id: &str,
+        hold_id: &str,
+    ) -> CustomResult<storage::PayoutHold, errors::StorageError> {
+        self.diesel_store
+            .find_payout_hold_by_merchant_id_hold_id(merchant_id, hold_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_holds_by_merchant_id_status(
+        &self,
+        merchant_id: &str,
+        status: enums::PayoutHoldStatus,
+    ) -> CustomResult<Vec<storage::PayoutHold>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_holds_by_merchant_id_status(merchant_id, status)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_hold_if_held(
+        &self,
+        this: storage::PayoutHold,
+        update: storage::PayoutHoldUpdate,
+    ) -> CustomResult<Option<storage::PayoutHold>, errors::StorageError> {
+        self.diesel_store
+            .update_payout_hold_if_held(this, update)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    mod mockdb_payout_hold_interface {
+        use common_utils::types::MinorUnit;
+        use redis_interface::RedisSettings;
+
+        use crate::{
+            db::{payout_hold::PayoutHoldInterface, MockDb},
+            types::storage::{self, enums},
+        };
+
+        fn create_hold_new(hold_id: &str) -> storage::PayoutHoldNew {
+            let now = common_utils::date_time::now();
+            storage::PayoutHoldNew {
+                hold_id: hold_id.to_string(),
+                merchant_id: "merchant_1".to_string(),
+                amount: MinorUnit::new(10000),
+                currency: enums::Currency::USD,
+                status: enums::PayoutHoldStatus::Held,
+                payout_id: None,
+                description: None,
+                expires_at: now + time::Duration::days(1),
+                created_at: now,
+                modified_at: now,
+            }
+        }
+
+        #[tokio::test]
+        async fn test_update_payout_hold_if_held() {
+            #[allow(clippy::expect_used)]
+            let mockdb = MockDb::new(&RedisSettings::default())
+                .await
+                .expect("Failed to create a mock DB");
+
+            let hold = mockdb
+                .insert_payout_hold(create_hold_new("payout_hold_1"))
+                .await
+                .unwrap();
+            mockdb
+                .insert_payout_hold(create_hold_new("payout_hold_2"))
+                .await
+                .unwrap();
+
+            let captured = mockdb
+                .update_payout_hold_if_held(
+                    hold.clone(),
+                    storage::PayoutHoldUpdate::StatusUpdate {
+                        status: enums::PayoutHoldStatus::Captured,
+                        payout_id: Some("payout_1".to_string()),
+                    },
+                )
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(captured.status, enums::PayoutHoldStatus::Captured);
+            assert_eq!(captured.payout_id.as_deref(), Some("payout_1"));
+
+            // A captured hold can no longer be released
+            assert!(mockdb
+                .update_payout_hold_if_held(
+                    hold,
+                    storage::PayoutHoldUpdate::StatusUpdate {
+                        status: enums::PayoutHoldStatus::Released,
+                        payout_id: None,
+                    },
+                )
+                .await
+                .unwrap()
+                .is_none());
+
+            let held = mockdb
+                .list_payout_holds_by_merchant_id_status(
+                    "merchant_1",
+                    enums::PayoutHoldStatus::Held,
+                )
+                .await
+                .unwrap();
+            assert_eq!(
+                held.iter()
+                    .map(|hold| hold.hold_id.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["payout_hold_2"]
+            );
+
+            assert!(mockdb
+                .insert_payout_hold(create_hold_new("payout_hold_1"))
+                .await
+                .is_err());
+        }
+    }
+}
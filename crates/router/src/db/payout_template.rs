@@ -0,0 +1,164 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutTemplateInterface {
+    async fn insert_payout_template(
+        &self,
+        payout_template: storage::PayoutTemplateNew,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError>;
+
+    async fn find_payout_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError>;
+
+    async fn list_payout_templates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutTemplate>, errors::StorageError>;
+
+    async fn delete_payout_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutTemplateInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_template(
+        &self,
+        payout_template: storage::PayoutTemplateNew,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_template
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutTemplate::find_by_merchant_id_template_id(&conn, merchant_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_templates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutTemplate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutTemplate::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_payout_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutTemplate::delete_by_merchant_id_template_id(&conn, merchant_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutTemplateInterface for MockDb {
+    async fn insert_payout_template(
+        &self,
+        _payout_template: storage::PayoutTemplateNew,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &str,
+        _template_id: &str,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payout_templates_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutTemplate>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_payout_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &str,
+        _template_id: &str,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutTemplateInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payout_template(
+        &self,
+        payout_template: storage::PayoutTemplateNew,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_template(payout_template)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError> {
+        self.diesel_store
+            .find_payout_template_by_merchant_id_template_id(merchant_id, template_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_templates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutTemplate>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_templates_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_payout_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::PayoutTemplate, errors::StorageError> {
+        self.diesel_store
+            .delete_payout_template_by_merchant_id_template_id(merchant_id, template_id)
+            .await
+    }
+}
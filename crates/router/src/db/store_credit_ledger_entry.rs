@@ -0,0 +1,98 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait StoreCreditLedgerEntryInterface {
+    async fn insert_store_credit_ledger_entry(
+        &self,
+        entry: storage::StoreCreditLedgerEntryNew,
+    ) -> CustomResult<storage::StoreCreditLedgerEntry, errors::StorageError>;
+
+    async fn find_store_credit_ledger_entry_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::StoreCreditLedgerEntry, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl StoreCreditLedgerEntryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_store_credit_ledger_entry(
+        &self,
+        entry: storage::StoreCreditLedgerEntryNew,
+    ) -> CustomResult<storage::StoreCreditLedgerEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        entry
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_store_credit_ledger_entry_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::StoreCreditLedgerEntry, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::StoreCreditLedgerEntry::find_by_merchant_id_payout_id(
+            &conn,
+            merchant_id,
+            payout_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl StoreCreditLedgerEntryInterface for MockDb {
+    async fn insert_store_credit_ledger_entry(
+        &self,
+        _entry: storage::StoreCreditLedgerEntryNew,
+    ) -> CustomResult<storage::StoreCreditLedgerEntry, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_store_credit_ledger_entry_by_merchant_id_payout_id(
+        &self,
+        _merchant_id: &str,
+        _payout_id: &str,
+    ) -> CustomResult<storage::StoreCreditLedgerEntry, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl StoreCreditLedgerEntryInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_store_credit_ledger_entry(
+        &self,
+        entry: storage::StoreCreditLedgerEntryNew,
+    ) -> CustomResult<storage::StoreCreditLedgerEntry, errors::StorageError> {
+        self.diesel_store
+            .insert_store_credit_ledger_entry(entry)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_store_credit_ledger_entry_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::StoreCreditLedgerEntry, errors::StorageError> {
+        self.diesel_store
+            .find_store_credit_ledger_entry_by_merchant_id_payout_id(merchant_id, payout_id)
+            .await
+    }
+}
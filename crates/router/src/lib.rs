@@ -79,6 +79,7 @@ pub mod headers {
     pub const CONTENT_LENGTH: &str = "Content-Length";
     pub const BROWSER_NAME: &str = "browsername";
     pub const X_CLIENT_PLATFORM: &str = "x-client-platform";
+    pub const X_IGNORE_UNKNOWN_FIELDS: &str = "X-Ignore-Unknown-Fields";
 }
 
 pub mod pii {
@@ -187,6 +188,12 @@ pub fn mk_app(
 #[allow(clippy::expect_used, clippy::unwrap_used)]
 pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> ApplicationResult<Server> {
     logger::debug!(startup_config=?conf);
+    if conf.health_check.simulated_failures.is_any_enabled() {
+        logger::warn!(
+            simulated_failures = ?conf.health_check.simulated_failures,
+            "Simulated health check failures are enabled"
+        );
+    }
     let server = conf.server.clone();
     let (tx, rx) = oneshot::channel();
     let api_client = Box::new(
@@ -200,15 +207,110 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
     );
     let state = Box::pin(AppState::new(conf, tx, api_client)).await;
     let request_body_limit = server.request_body_limit;
+    #[cfg(not(target_os = "windows"))]
+    let shutdown_drain_period = server.shutdown_drain_period;
     let server = actix_web::HttpServer::new(move || mk_app(state.clone(), request_body_limit))
         .bind((server.host.as_str(), server.port))?
         .workers(server.workers)
-        .shutdown_timeout(server.shutdown_timeout)
-        .run();
+        .shutdown_timeout(server.shutdown_timeout);
+    // With a drain period, the shutdown signals are handled by `drain_on_shutdown_signal` instead
+    // of actix, so that the server keeps accepting requests while it drains. Otherwise, and on
+    // windows, actix stops the server on the signals as usual
+    #[cfg(not(target_os = "windows"))]
+    let shutdown_signals = (shutdown_drain_period > 0)
+        .then(ShutdownSignals::new)
+        .transpose()?;
+    #[cfg(not(target_os = "windows"))]
+    let server = if shutdown_signals.is_some() {
+        server.disable_signals()
+    } else {
+        server
+    };
+    let server = server.run();
     let _task_handle = tokio::spawn(receiver_for_error(rx, server.handle()).in_current_span());
+    #[cfg(not(target_os = "windows"))]
+    let _drain_task_handle = shutdown_signals.map(|shutdown_signals| {
+        tokio::spawn(
+            drain_on_shutdown_signal(shutdown_signals, server.handle(), shutdown_drain_period)
+                .in_current_span(),
+        )
+    });
     Ok(server)
 }
 
+/// Signals on which actix stops the server, gracefully on SIGTERM and right away on SIGINT and
+/// SIGQUIT
+#[cfg(not(target_os = "windows"))]
+struct ShutdownSignals {
+    terminate: actix_rt::signal::unix::Signal,
+    interrupt: actix_rt::signal::unix::Signal,
+    quit: actix_rt::signal::unix::Signal,
+}
+
+#[cfg(not(target_os = "windows"))]
+impl ShutdownSignals {
+    fn new() -> std::io::Result<Self> {
+        use actix_rt::signal::unix::{signal, SignalKind};
+
+        Ok(Self {
+            terminate: signal(SignalKind::terminate())?,
+            interrupt: signal(SignalKind::interrupt())?,
+            quit: signal(SignalKind::quit())?,
+        })
+    }
+
+    /// Waits for the next signal, returning whether it asks for the server to be stopped
+    /// gracefully
+    async fn recv(&mut self) -> bool {
+        tokio::select! {
+            _ = self.terminate.recv() => {
+                logger::info!("Received signal: SIGTERM");
+                true
+            }
+            _ = self.interrupt.recv() => {
+                logger::info!("Received signal: SIGINT");
+                false
+            }
+            _ = self.quit.recv() => {
+                logger::info!("Received signal: SIGQUIT");
+                false
+            }
+        }
+    }
+}
+
+/// Enters the draining state on receiving SIGTERM, in which the readiness check fails while
+/// requests continue to be served, and gracefully stops the server once the drain period is over.
+/// The server then stops accepting new connections and waits for the requests in flight to
+/// complete, for up to the shutdown timeout.
+///
+/// As with actix, SIGINT and SIGQUIT stop the server right away, without draining it. Any signal
+/// received while draining also stops the server right away.
+#[cfg(not(target_os = "windows"))]
+async fn drain_on_shutdown_signal(
+    mut signals: ShutdownSignals,
+    server: ServerHandle,
+    shutdown_drain_period: u64,
+) {
+    if !signals.recv().await {
+        server.stop(false).await;
+        return;
+    }
+    routes::health::start_shutdown_draining();
+    logger::info!(
+        shutdown_drain_period,
+        "Draining the server before shutting it down"
+    );
+    let graceful = tokio::select! {
+        () = tokio::time::sleep(std::time::Duration::from_secs(shutdown_drain_period)) => true,
+        _ = signals.recv() => {
+            logger::info!("Stopping the server without waiting for the drain period to end");
+            false
+        }
+    };
+    server.stop(graceful).await;
+}
+
 pub async fn receiver_for_error(rx: oneshot::Receiver<()>, mut server: impl Stop) {
     match rx.await {
         Ok(_) => {
@@ -10,7 +10,10 @@ use external_services::email::{ses::AwsSes, EmailService};
 use external_services::file_storage::FileStorageInterface;
 use hyperswitch_interfaces::{
     encryption_interface::EncryptionManagementInterface,
-    secrets_interface::secret_state::{RawSecret, SecuredSecret},
+    secrets_interface::{
+        secret_state::{RawSecret, SecuredSecret},
+        SecretManagementInterface,
+    },
 };
 use router_env::tracing_actix_web::RequestId;
 use scheduler::SchedulerInterface;
@@ -85,6 +88,7 @@ pub struct SessionState {
     #[cfg(feature = "olap")]
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub secrets_management_client: Arc<dyn SecretManagementInterface>,
     pub request_id: Option<RequestId>,
     pub base_url: String,
     pub tenant: Tenant,
@@ -148,6 +152,7 @@ pub struct AppState {
     pub request_id: Option<RequestId>,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
+    pub secrets_management_client: Arc<dyn SecretManagementInterface>,
 }
 impl scheduler::SchedulerAppState for AppState {
     fn get_tenants(&self) -> Vec<String> {
@@ -218,13 +223,14 @@ impl AppState {
         api_client: Box<dyn crate::services::ApiClient>,
     ) -> Self {
         #[allow(clippy::expect_used)]
-        let secret_management_client = conf
+        let secrets_management_client: Arc<dyn SecretManagementInterface> = conf
             .secrets_management
             .get_secret_management_client()
             .await
-            .expect("Failed to create secret management client");
+            .expect("Failed to create secret management client")
+            .into();
 
-        let conf = secrets_transformers::fetch_raw_secrets(conf, &*secret_management_client).await;
+        let conf = secrets_transformers::fetch_raw_secrets(conf, &*secrets_management_client).await;
 
         #[allow(clippy::expect_used)]
         let encryption_client = conf
@@ -307,6 +313,7 @@ impl AppState {
                 request_id: None,
                 file_storage_client,
                 encryption_client,
+                secrets_management_client,
             }
         })
         .await
@@ -382,6 +389,7 @@ impl AppState {
             #[cfg(feature = "olap")]
             pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
             file_storage_client: self.file_storage_client.clone(),
+            secrets_management_client: Arc::clone(&self.secrets_management_client),
             request_id: self.request_id,
             base_url: tenant_conf.base_url.clone(),
             tenant: tenant_conf.clone(),
@@ -553,6 +561,15 @@ impl Forex {
             .service(
                 web::resource("/convert_from_minor").route(web::get().to(currency::convert_forex)),
             )
+            .service(
+                web::resource("/currency_metadata")
+                    .route(web::get().to(currency::retrieve_currency_metadata)),
+            )
+            .service(web::resource("/quotes").route(web::post().to(currency::create_forex_quote)))
+            .service(
+                web::resource("/quotes/{quote_id}/cancel")
+                    .route(web::post().to(currency::cancel_forex_quote)),
+            )
     }
 }
 
@@ -875,7 +892,51 @@ pub struct Payouts;
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
         let mut route = web::scope("/payouts").app_data(web::Data::new(state));
-        route = route.service(web::resource("/create").route(web::post().to(payouts_create)));
+        route = route
+            .service(web::resource("/create").route(web::post().to(payouts_create)))
+            .service(web::resource("/bulk_retrieve").route(web::post().to(payouts_bulk_retrieve)))
+            .service(web::resource("/batch").route(web::post().to(payouts_batch_create)))
+            .service(
+                web::resource("/connector_reference")
+                    .route(web::get().to(payouts_retrieve_by_connector_reference)),
+            )
+            .service(
+                web::resource("/templates")
+                    .route(web::post().to(payout_templates_create))
+                    .route(web::get().to(payout_templates_list)),
+            )
+            .service(
+                web::resource("/templates/{template_id}")
+                    .route(web::get().to(payout_templates_retrieve))
+                    .route(web::delete().to(payout_templates_delete)),
+            )
+            .service(
+                web::resource("/destination_verifications")
+                    .route(web::post().to(payout_destination_verifications_create)),
+            )
+            .service(
+                web::resource("/destination_verifications/{verification_id}")
+                    .route(web::get().to(payout_destination_verifications_retrieve)),
+            )
+            .service(
+                web::resource("/destination_verifications/{verification_id}/confirm")
+                    .route(web::post().to(payout_destination_verifications_confirm)),
+            )
+            .service(web::resource("/holds").route(web::post().to(payout_holds_create)))
+            .service(web::resource("/holds/balance").route(web::get().to(payout_holds_balance)))
+            .service(web::resource("/holds/{hold_id}").route(web::get().to(payout_holds_retrieve)))
+            .service(
+                web::resource("/holds/{hold_id}/capture")
+                    .route(web::post().to(payout_holds_capture)),
+            )
+            .service(
+                web::resource("/holds/{hold_id}/release")
+                    .route(web::post().to(payout_holds_release)),
+            )
+            .service(
+                web::resource("/series/{series_id}/cancel")
+                    .route(web::post().to(payouts_series_cancel)),
+            );
 
         #[cfg(feature = "olap")]
         {
@@ -887,6 +948,26 @@ impl Payouts {
                 )
                 .service(
                     web::resource("/filter").route(web::post().to(payouts_list_available_filters)),
+                )
+                .service(web::resource("/export").route(web::post().to(payouts_export)))
+                .service(
+                    web::resource("/export/{job_id}")
+                        .route(web::get().to(payouts_export_job_retrieve)),
+                )
+                .service(
+                    web::resource("/export/{job_id}/download")
+                        .route(web::get().to(payouts_export_download)),
+                )
+                .service(
+                    web::resource("/connectors").route(web::post().to(payouts_connector_usage)),
+                )
+                .service(
+                    web::resource("/admin/{merchant_id}/{payout_id}")
+                        .route(web::get().to(payouts_admin_retrieve)),
+                )
+                .service(
+                    web::resource("/admin/{merchant_id}/{payout_id}/currency_conversions")
+                        .route(web::get().to(payouts_admin_currency_conversions)),
                 );
         }
         route = route
@@ -897,7 +978,13 @@ impl Payouts {
             )
             .service(web::resource("/{payout_id}/confirm").route(web::post().to(payouts_confirm)))
             .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
-            .service(web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)));
+            .service(web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)))
+            .service(web::resource("/{payout_id}/approve").route(web::post().to(payouts_approve)))
+            .service(web::resource("/{payout_id}/verify").route(web::post().to(payouts_verify)))
+            .service(
+                web::resource("/{payout_id}/reprocess").route(web::post().to(payouts_reprocess)),
+            )
+            .service(web::resource("/{payout_id}/recall").route(web::post().to(payouts_recall)));
         route
     }
 }
@@ -1248,9 +1335,14 @@ impl PaymentLink {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::post().to(payments_link_list)))
+            .service(web::resource("/preview").route(web::post().to(payment_link_preview)))
             .service(
                 web::resource("/{payment_link_id}").route(web::get().to(payment_link_retrieve)),
             )
+            .service(
+                web::resource("/payment/{payment_id}")
+                    .route(web::get().to(payment_link_retrieve_by_payment_id)),
+            )
             .service(
                 web::resource("{merchant_id}/{payment_id}")
                     .route(web::get().to(initiate_payment_link)),
@@ -1532,6 +1624,10 @@ impl WebhookEvents {
                     .service(
                         web::resource("retry")
                             .route(web::post().to(retry_webhook_delivery_attempt)),
+                    )
+                    .service(
+                        web::resource("retry_status")
+                            .route(web::get().to(retrieve_webhook_delivery_retry_status)),
                     ),
             )
     }
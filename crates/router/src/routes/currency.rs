@@ -7,14 +7,20 @@ use crate::{
     services::{api, authentication as auth},
 };
 
-pub async fn retrieve_forex(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+pub async fn retrieve_forex(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<api_models::currency::ForexRetrieveParams>,
+) -> HttpResponse {
     let flow = Flow::RetrieveForexFlow;
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        (),
-        |state, _auth: auth::AuthenticationData, _, _| currency::retrieve_forex(state),
+        params.into_inner(),
+        |state, auth: auth::AuthenticationData, params, _| {
+            currency::retrieve_forex(state, auth.merchant_account.merchant_id, params)
+        },
         auth::auth_type(
             &auth::ApiKeyAuth,
             &auth::DashboardNoPermissionAuth,
@@ -34,17 +40,26 @@ pub async fn convert_forex(
     let amount = params.amount;
     let to_currency = &params.to_currency;
     let from_currency = &params.from_currency;
+    let provider = params.provider;
+    let allow_fallback = params.allow_fallback;
+    let rate_precision = params.rate_precision;
+    let locale = &params.locale;
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
         &req,
         (),
-        |state, _, _, _| {
+        |state, auth, _, _| {
             currency::convert_forex(
                 state,
+                auth.merchant_account.merchant_id,
                 amount.get_amount_as_i64(),
                 to_currency.to_string(),
                 from_currency.to_string(),
+                provider,
+                allow_fallback,
+                rate_precision,
+                locale.clone(),
             )
         },
         auth::auth_type(
@@ -56,3 +71,82 @@ pub async fn convert_forex(
     ))
     .await
 }
+
+pub async fn create_forex_quote(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::currency::ForexQuoteCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ForexQuoteCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            currency::create_conversion_quote(state, auth.merchant_account.merchant_id, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn cancel_forex_quote(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ForexQuoteCancel;
+    let quote_id = api_models::currency::ForexQuoteId {
+        quote_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        quote_id,
+        |state, auth: auth::AuthenticationData, req, _| {
+            currency::cancel_conversion_quote(
+                state,
+                auth.merchant_account.merchant_id,
+                req.quote_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn retrieve_currency_metadata(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<api_models::currency::CurrencyMetadataParams>,
+) -> HttpResponse {
+    let flow = Flow::RetrieveCurrencyMetadata;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        params.into_inner(),
+        |_state, _: auth::AuthenticationData, params, _| {
+            currency::retrieve_currency_metadata(params)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,14 +1,58 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
 use actix_web::{web, HttpRequest};
-use api_models::health_check::RouterHealthCheckResponse;
+use api_models::health_check::{
+    BuildInfo, ComponentHealth, ComponentHealthStatus, DeepHealthCheckQuery,
+    HealthCheckResponseFormat, HealthState, RouterHealthCheckResponse,
+};
+use once_cell::sync::Lazy;
 use router_env::{instrument, logger, tracing, Flow};
+use tokio::sync::Mutex;
 
 use super::app;
 use crate::{
     core::{api_locking, health_check::HealthCheckInterface},
-    errors::{self, RouterResponse},
+    errors::{self, RouterResponse, RouterResult},
     routes::metrics,
     services::{api, authentication as auth},
 };
+
+/// Most recent successful deep health check result of a tenant and mode (whether the heavier
+/// probes were run), along with when its probe started. The lock of the entry is held while
+/// probing, so that concurrent checks for the same tenant and mode wait for the probe in flight.
+type DeepHealthCheckCacheEntry = Arc<Mutex<Option<(Instant, RouterHealthCheckResponse)>>>;
+
+/// Cache entry of each tenant and mode. The map is only locked to look up an entry and never
+/// across a probe, so checks for different tenants and modes do not wait on each other.
+static DEEP_HEALTH_CHECK_CACHE: Lazy<
+    std::sync::Mutex<HashMap<(String, bool), DeepHealthCheckCacheEntry>>,
+> = Lazy::new(|| std::sync::Mutex::new(HashMap::new()));
+
+/// Whether the server is draining for shutdown, see [`start_shutdown_draining`]
+static SHUTDOWN_DRAINING: AtomicBool = AtomicBool::new(false);
+
+/// Puts the server into the draining state, which is entered on receiving SIGTERM when a drain
+/// period is configured, and lasts until the server stops. While draining, the readiness check
+/// fails with the router reported as down, without probing its dependencies, regardless of their
+/// status or any cached result, so that load balancers stop routing new requests to the instance.
+/// The liveness check is not affected, and requests continue to be served until the drain period
+/// is over.
+pub fn start_shutdown_draining() {
+    SHUTDOWN_DRAINING.store(true, Ordering::SeqCst);
+}
+
+pub fn is_shutdown_draining() -> bool {
+    SHUTDOWN_DRAINING.load(Ordering::SeqCst)
+}
+
 /// .
 // #[logger::instrument(skip_all, name = "name1", level = "warn", fields( key1 = "val1" ))]
 #[instrument(skip_all, fields(flow = ?Flow::HealthCheck))]
@@ -24,106 +68,722 @@ pub async fn health() -> impl actix_web::Responder {
 pub async fn deep_health_check(
     state: web::Data<app::AppState>,
     request: HttpRequest,
+    query_params: web::Query<DeepHealthCheckQuery>,
 ) -> impl actix_web::Responder {
     metrics::HEALTH_METRIC.add(&metrics::CONTEXT, 1, &[]);
 
     let flow = Flow::DeepHealthCheck;
+    let api_auth: &dyn auth::AuthenticateAndFetch<(), app::SessionState> =
+        if requires_admin_auth(&query_params) {
+            &auth::AdminApiAuth
+        } else {
+            &auth::NoAuth
+        };
+    let shutdown_draining = is_shutdown_draining();
 
-    Box::pin(api::server_wrap(
+    let mut response = Box::pin(api::server_wrap(
         flow,
         state,
         &request,
-        (),
-        |state, _: (), _, _| deep_health_check_func(state),
-        &auth::NoAuth,
+        query_params.into_inner(),
+        |state, _: (), query_params, _| {
+            deep_health_check_func(
+                state,
+                query_params.force_refresh.unwrap_or(false),
+                query_params.deep.unwrap_or(false),
+                query_params.format.unwrap_or_default(),
+                shutdown_draining,
+            )
+        },
+        api_auth,
         api_locking::LockAction::NotApplicable,
     ))
-    .await
+    .await;
+    // The body reports the router as down while draining, and the status fails the check
+    if shutdown_draining && response.status().is_success() {
+        *response.status_mut() = actix_web::http::StatusCode::SERVICE_UNAVAILABLE;
+    }
+    response
 }
 
+/// Skipping the cache and running the heavier probes are restricted to admins, since they let the
+/// caller put load on the dependencies of the router and on the connectors
+fn requires_admin_auth(query_params: &DeepHealthCheckQuery) -> bool {
+    query_params.force_refresh.unwrap_or(false) || query_params.deep.unwrap_or(false)
+}
+
+/// Reuses a result whose probe started within the configured cache TTL, so that bursts of scrapes
+/// do not each hit the dependencies. Concurrent checks for the same tenant and mode wait for the
+/// one in flight instead of probing again. Failed probes, and results with a component down, are
+/// never cached, and `force_refresh` always probes the dependencies. While the server is draining
+/// for shutdown, the router is reported as down without probing the dependencies.
 async fn deep_health_check_func(
     state: app::SessionState,
+    force_refresh: bool,
+    deep: bool,
+    format: HealthCheckResponseFormat,
+    shutdown_draining: bool,
 ) -> RouterResponse<RouterHealthCheckResponse> {
-    logger::info!("Deep health check was called");
+    if shutdown_draining {
+        logger::info!("Deep health check reported the router as draining for shutdown");
+        return Ok(get_deep_health_check_response(
+            get_shutdown_draining_response(deep),
+            format,
+        ));
+    }
 
-    logger::debug!("Database health check begin");
+    // A scrape of the Prometheus exposition must still succeed when a dependency is unreachable,
+    // so that the gauges can report it as down
+    let tolerate_errors = format == HealthCheckResponseFormat::Prometheus;
+    let cache_ttl = Duration::from_millis(state.conf.health_check.result_cache_ttl);
+    let cache_key = (state.tenant.name.clone(), deep);
+
+    if cache_ttl.is_zero() {
+        return probe_dependencies(state, deep, tolerate_errors)
+            .await
+            .map(|response| get_deep_health_check_response(response, format));
+    }
+
+    let cache_entry = DEEP_HEALTH_CHECK_CACHE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(cache_key)
+        .or_default()
+        .clone();
+    let mut cached_result = cache_entry.lock().await;
+    if let Some((_, response)) = cached_result
+        .as_ref()
+        .filter(|(probed_at, _)| !force_refresh && probed_at.elapsed() < cache_ttl)
+    {
+        logger::debug!("Deep health check served from cache");
+        return Ok(get_deep_health_check_response(response.clone(), format));
+    }
 
-    let db_status = state.health_check_db().await.map_err(|err| {
-        error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
-            component: "Database",
-            message: err.to_string()
+    let started_at = Instant::now();
+    let result = probe_dependencies(state, deep, tolerate_errors).await;
+    *cached_result = result
+        .as_ref()
+        .ok()
+        .filter(|response| response.status != ComponentHealthStatus::Down)
+        .map(|response| (started_at, response.clone()));
+    result.map(|response| get_deep_health_check_response(response, format))
+}
+
+/// Reports the router as down, and none of the dependencies as probed
+fn get_shutdown_draining_response(deep: bool) -> RouterHealthCheckResponse {
+    RouterHealthCheckResponse {
+        status: ComponentHealthStatus::Down,
+        deep,
+        components: BTreeMap::from([(
+            "router".to_string(),
+            ComponentHealth::new(HealthState::Error, 0, 0),
+        )]),
+        database: false,
+        redis: false,
+        vault: None,
+        file_store: None,
+        secrets_manager: None,
+        #[cfg(feature = "olap")]
+        analytics: false,
+        #[cfg(feature = "olap")]
+        opensearch: false,
+        outgoing_request: false,
+        build: get_build_info(),
+    }
+}
+
+fn get_deep_health_check_response(
+    response: RouterHealthCheckResponse,
+    format: HealthCheckResponseFormat,
+) -> api::ApplicationResponse<RouterHealthCheckResponse> {
+    match format {
+        HealthCheckResponseFormat::Json => api::ApplicationResponse::Json(response),
+        HealthCheckResponseFormat::Prometheus => api::ApplicationResponse::FileData((
+            get_prometheus_health_check_response(&response).into_bytes(),
+            "text/plain; version=0.0.4; charset=utf-8"
+                .parse()
+                .unwrap_or(mime::TEXT_PLAIN_UTF_8),
+        )),
+    }
+}
+
+/// Renders the result of the deep health check in the Prometheus text exposition format, as a
+/// set of gauges labelled by component. Components which are not applicable or were not probed are
+/// left out, and latencies are reported in seconds as per the Prometheus naming conventions.
+fn get_prometheus_health_check_response(response: &RouterHealthCheckResponse) -> String {
+    let components = response
+        .components
+        .iter()
+        .filter(|(_, health)| {
+            !matches!(
+                health.status,
+                ComponentHealthStatus::NotApplicable | ComponentHealthStatus::Skipped
+            )
         })
-    })?;
+        .collect::<Vec<_>>();
+    let gauges: [(&str, &str, fn(&ComponentHealth) -> f64); 4] = [
+        (
+            "hyperswitch_health_check_component_up",
+            "Whether the component is serving requests (1) or not (0)",
+            |health| f64::from(u8::from(health.status != ComponentHealthStatus::Down)),
+        ),
+        (
+            "hyperswitch_health_check_component_degraded",
+            "Whether the probe of the component exceeded its latency threshold",
+            |health| f64::from(u8::from(health.status == ComponentHealthStatus::Degraded)),
+        ),
+        (
+            "hyperswitch_health_check_component_latency_seconds",
+            "Time taken by the probe of the component",
+            |health| Duration::from_millis(health.latency_ms).as_secs_f64(),
+        ),
+        (
+            "hyperswitch_health_check_component_latency_threshold_seconds",
+            "Latency beyond which the component is reported as degraded",
+            |health| Duration::from_millis(health.latency_threshold_ms).as_secs_f64(),
+        ),
+    ];
+
+    let mut text = String::new();
+    for (name, help, value) in gauges {
+        let _ = writeln!(text, "# HELP {name} {help}");
+        let _ = writeln!(text, "# TYPE {name} gauge");
+        for (component, health) in &components {
+            let _ = writeln!(
+                text,
+                "{name}{{component=\"{component}\"}} {}",
+                value(health)
+            );
+        }
+    }
+
+    let _ = writeln!(
+        text,
+        "# HELP hyperswitch_build_info Version of the running build, always 1"
+    );
+    let _ = writeln!(text, "# TYPE hyperswitch_build_info gauge");
+    let _ = writeln!(
+        text,
+        "hyperswitch_build_info{{version=\"{}\",commit=\"{}\"}} 1",
+        response.build.version,
+        response.build.commit.as_deref().unwrap_or_default()
+    );
+    text
+}
+
+/// Probes the database and its schema, redis, the locker, the file store, the secrets manager, the
+/// analytics stores and outgoing requests. The heavier probes, namely the database schema check,
+/// the locker round trip and the connector pings, are run only if `deep` is set, and the schema is
+/// reported as skipped otherwise. A failing probe fails the check, unless `tolerate_errors` is
+/// set, in which case the component is reported as down.
+async fn probe_dependencies(
+    state: app::SessionState,
+    deep: bool,
+    tolerate_errors: bool,
+) -> RouterResult<RouterHealthCheckResponse> {
+    logger::info!(deep, "Deep health check was called");
+
+    let latency_thresholds = state.conf.health_check.clone();
+    let mut components = BTreeMap::new();
+
+    logger::debug!("Database health check begin");
+
+    let started_at = Instant::now();
+    let db_status =
+        get_probe_health_state("Database", state.health_check_db().await, tolerate_errors)?;
+    components.insert(
+        "database".to_string(),
+        get_component_health(
+            db_status,
+            started_at,
+            latency_thresholds.database_latency_threshold,
+        ),
+    );
 
     logger::debug!("Database health check end");
 
+    if deep {
+        logger::debug!("Database schema health check begin");
+
+        let started_at = Instant::now();
+        let db_schema_status = get_probe_health_state(
+            "Database Schema",
+            state.health_check_db_schema().await,
+            tolerate_errors,
+        )?;
+        components.insert(
+            "database_schema".to_string(),
+            get_component_health(
+                db_schema_status,
+                started_at,
+                latency_thresholds.database_latency_threshold,
+            ),
+        );
+
+        logger::debug!("Database schema health check end");
+    } else {
+        components.insert(
+            "database_schema".to_string(),
+            ComponentHealth::skipped(latency_thresholds.database_latency_threshold),
+        );
+    }
+
     logger::debug!("Redis health check begin");
 
-    let redis_status = state.health_check_redis().await.map_err(|err| {
-        error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
-            component: "Redis",
-            message: err.to_string()
-        })
-    })?;
+    let started_at = Instant::now();
+    let redis_status =
+        get_probe_health_state("Redis", state.health_check_redis().await, tolerate_errors)?;
+    components.insert(
+        "redis".to_string(),
+        get_component_health(
+            redis_status,
+            started_at,
+            latency_thresholds.redis_latency_threshold,
+        ),
+    );
 
     logger::debug!("Redis health check end");
 
     logger::debug!("Locker health check begin");
 
-    let locker_status = state.health_check_locker().await.map_err(|err| {
-        error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
-            component: "Locker",
-            message: err.to_string()
-        })
-    })?;
+    let started_at = Instant::now();
+    let locker_status = get_probe_health_state(
+        "Locker",
+        state.health_check_locker(deep).await,
+        tolerate_errors,
+    )?;
+    components.insert(
+        "vault".to_string(),
+        get_component_health(
+            locker_status,
+            started_at,
+            latency_thresholds.locker_latency_threshold,
+        ),
+    );
 
     logger::debug!("Locker health check end");
 
+    logger::debug!("File store health check begin");
+
+    let started_at = Instant::now();
+    let file_store_status = get_probe_health_state(
+        "File Store",
+        state.health_check_file_store().await,
+        tolerate_errors,
+    )?;
+    components.insert(
+        "file_store".to_string(),
+        get_component_health(
+            file_store_status,
+            started_at,
+            latency_thresholds.file_store_latency_threshold,
+        ),
+    );
+
+    logger::debug!("File store health check end");
+
+    logger::debug!("Secrets manager health check begin");
+
+    let started_at = Instant::now();
+    let secrets_manager_status = get_probe_health_state(
+        "Secrets Manager",
+        state.health_check_secrets_manager().await,
+        tolerate_errors,
+    )?;
+    components.insert(
+        "secrets_manager".to_string(),
+        get_component_health(
+            secrets_manager_status,
+            started_at,
+            latency_thresholds.secrets_manager_latency_threshold,
+        ),
+    );
+
+    logger::debug!("Secrets manager health check end");
+
     logger::debug!("Analytics health check begin");
 
     #[cfg(feature = "olap")]
-    let analytics_status = state.health_check_analytics().await.map_err(|err| {
-        error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
-            component: "Analytics",
-            message: err.to_string()
-        })
-    })?;
+    let analytics_status = {
+        let started_at = Instant::now();
+        let analytics_status = get_probe_health_state(
+            "Analytics",
+            state.health_check_analytics().await,
+            tolerate_errors,
+        )?;
+        components.insert(
+            "analytics".to_string(),
+            get_component_health(
+                analytics_status,
+                started_at,
+                latency_thresholds.analytics_latency_threshold,
+            ),
+        );
+        analytics_status
+    };
 
     logger::debug!("Analytics health check end");
 
     logger::debug!("Opensearch health check begin");
 
     #[cfg(feature = "olap")]
-    let opensearch_status = state.health_check_opensearch().await.map_err(|err| {
-        error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
-            component: "Opensearch",
-            message: err.to_string()
-        })
-    })?;
+    let opensearch_status = {
+        let started_at = Instant::now();
+        let opensearch_status = get_probe_health_state(
+            "Opensearch",
+            state.health_check_opensearch().await,
+            tolerate_errors,
+        )?;
+        components.insert(
+            "opensearch".to_string(),
+            get_component_health(
+                opensearch_status,
+                started_at,
+                latency_thresholds.opensearch_latency_threshold,
+            ),
+        );
+        opensearch_status
+    };
 
     logger::debug!("Opensearch health check end");
 
     logger::debug!("Outgoing Request health check begin");
 
-    let outgoing_check = state.health_check_outgoing().await.map_err(|err| {
-        error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
-            component: "Outgoing Request",
-            message: err.to_string()
-        })
-    })?;
+    let started_at = Instant::now();
+    let outgoing_check = get_probe_health_state(
+        "Outgoing Request",
+        state.health_check_outgoing().await,
+        tolerate_errors,
+    )?;
+    components.insert(
+        "outgoing_request".to_string(),
+        get_component_health(
+            outgoing_check,
+            started_at,
+            latency_thresholds.outgoing_request_latency_threshold,
+        ),
+    );
 
     logger::debug!("Outgoing Request health check end");
 
+    if deep {
+        logger::debug!("Connector health check begin");
+
+        // The connectors are pinged concurrently, as each of them can take up to the request
+        // timeout
+        let connector_pings = latency_thresholds
+            .connector_pings
+            .iter()
+            .map(|connector| async {
+                let started_at = Instant::now();
+                let connector_status = state
+                    .health_check_connector(*connector)
+                    .await
+                    .unwrap_or_else(|error| {
+                        logger::warn!(?error, %connector, "Connector ping failed");
+                        HealthState::Error
+                    });
+                (
+                    format!("connector_{connector}"),
+                    get_component_health(
+                        connector_status,
+                        started_at,
+                        latency_thresholds.connector_ping_latency_threshold,
+                    ),
+                )
+            });
+        components.extend(futures::future::join_all(connector_pings).await);
+
+        logger::debug!("Connector health check end");
+    }
+
+    let status = get_overall_health_status(&components);
+
     let response = RouterHealthCheckResponse {
+        status,
+        deep,
+        components,
         database: db_status.into(),
         redis: redis_status.into(),
         vault: locker_status.into(),
+        file_store: file_store_status.into(),
+        secrets_manager: secrets_manager_status.into(),
         #[cfg(feature = "olap")]
         analytics: analytics_status.into(),
         #[cfg(feature = "olap")]
         opensearch: opensearch_status.into(),
         outgoing_request: outgoing_check.into(),
+        build: get_build_info(),
     };
 
-    Ok(api::ApplicationResponse::Json(response))
+    Ok(response)
+}
+
+fn get_probe_health_state<E: std::fmt::Display>(
+    component: &'static str,
+    result: Result<HealthState, E>,
+    tolerate_errors: bool,
+) -> RouterResult<HealthState> {
+    result.or_else(|err| {
+        if tolerate_errors {
+            logger::warn!(error = %err, component, "Health check probe failed");
+            Ok(HealthState::Error)
+        } else {
+            Err(error_stack::report!(
+                errors::ApiErrorResponse::HealthCheckError {
+                    component,
+                    message: err.to_string()
+                }
+            ))
+        }
+    })
+}
+
+/// Components which are degraded are still serving requests, so the check passes with a warning.
+/// The connectors can be reported as down without failing the check, and they degrade it. Any
+/// other component is only reported as down when probe errors are tolerated, and fails the check.
+fn get_overall_health_status(
+    components: &BTreeMap<String, ComponentHealth>,
+) -> ComponentHealthStatus {
+    if components.iter().any(|(component, health)| {
+        health.status == ComponentHealthStatus::Down && !component.starts_with("connector_")
+    }) {
+        return ComponentHealthStatus::Down;
+    }
+
+    let degraded_components = components
+        .iter()
+        .filter(|(_, health)| {
+            matches!(
+                health.status,
+                ComponentHealthStatus::Degraded | ComponentHealthStatus::Down
+            )
+        })
+        .map(|(component, _)| component.as_str())
+        .collect::<Vec<_>>();
+    if degraded_components.is_empty() {
+        ComponentHealthStatus::Up
+    } else {
+        logger::warn!(
+            ?degraded_components,
+            "Deep health check found components which are down or exceed their latency thresholds"
+        );
+        ComponentHealthStatus::Degraded
+    }
+}
+
+fn get_build_info() -> BuildInfo {
+    #[cfg(feature = "vergen")]
+    let (commit, commit_timestamp) = (
+        Some(router_env::commit!().to_string()),
+        Some(env!("VERGEN_GIT_COMMIT_TIMESTAMP").to_string()),
+    );
+    #[cfg(not(feature = "vergen"))]
+    let (commit, commit_timestamp) = (None, None);
+
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        commit,
+        commit_timestamp,
+    }
+}
+
+fn get_component_health(
+    state: HealthState,
+    started_at: Instant,
+    latency_threshold: u64,
+) -> ComponentHealth {
+    let latency = u64::try_from(started_at.elapsed().as_millis()).unwrap_or(u64::MAX);
+    ComponentHealth::new(state, latency, latency_threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::sync::Arc;
+
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, services};
+
+    #[test]
+    fn test_requires_admin_auth() {
+        let query_params = |force_refresh, deep| DeepHealthCheckQuery {
+            force_refresh,
+            deep,
+            format: None,
+        };
+
+        assert!(!requires_admin_auth(&query_params(None, None)));
+        assert!(!requires_admin_auth(&query_params(
+            Some(false),
+            Some(false)
+        )));
+        assert!(requires_admin_auth(&query_params(Some(true), None)));
+        assert!(requires_admin_auth(&query_params(None, Some(true))));
+    }
+
+    #[test]
+    fn test_get_overall_health_status() {
+        let component = |status| ComponentHealth {
+            status,
+            latency_ms: 10,
+            latency_threshold_ms: 100,
+        };
+        let mut components = BTreeMap::from([
+            ("database".to_string(), component(ComponentHealthStatus::Up)),
+            (
+                "file_store".to_string(),
+                component(ComponentHealthStatus::NotApplicable),
+            ),
+            (
+                "database_schema".to_string(),
+                component(ComponentHealthStatus::Skipped),
+            ),
+        ]);
+        assert_eq!(
+            get_overall_health_status(&components),
+            ComponentHealthStatus::Up
+        );
+
+        // An unreachable connector degrades the check instead of failing it
+        components.insert(
+            "connector_adyen".to_string(),
+            component(ComponentHealthStatus::Down),
+        );
+        assert_eq!(
+            get_overall_health_status(&components),
+            ComponentHealthStatus::Degraded
+        );
+
+        // Any other component being down fails the check
+        components.insert("redis".to_string(), component(ComponentHealthStatus::Down));
+        assert_eq!(
+            get_overall_health_status(&components),
+            ComponentHealthStatus::Down
+        );
+    }
+
+    #[test]
+    fn test_get_probe_health_state() {
+        let failed_probe = || Err::<HealthState, _>("connection refused");
+
+        assert!(matches!(
+            get_probe_health_state("Redis", failed_probe(), true),
+            Ok(HealthState::Error)
+        ));
+        assert!(get_probe_health_state("Redis", failed_probe(), false).is_err());
+        assert!(matches!(
+            get_probe_health_state("Redis", Ok::<_, &str>(HealthState::Running), false),
+            Ok(HealthState::Running)
+        ));
+    }
+
+    #[test]
+    fn test_get_prometheus_health_check_response_with_component_down() {
+        let components = BTreeMap::from([
+            (
+                "database".to_string(),
+                ComponentHealth::new(HealthState::Running, 5, 100),
+            ),
+            (
+                "redis".to_string(),
+                ComponentHealth::new(HealthState::Error, 2500, 100),
+            ),
+            (
+                "file_store".to_string(),
+                ComponentHealth::new(HealthState::NotApplicable, 0, 100),
+            ),
+            ("database_schema".to_string(), ComponentHealth::skipped(100)),
+        ]);
+        let response = RouterHealthCheckResponse {
+            status: get_overall_health_status(&components),
+            deep: false,
+            components,
+            database: true,
+            redis: false,
+            vault: None,
+            file_store: None,
+            secrets_manager: None,
+            #[cfg(feature = "olap")]
+            analytics: true,
+            #[cfg(feature = "olap")]
+            opensearch: true,
+            outgoing_request: true,
+            build: BuildInfo {
+                version: "1.0.0".to_string(),
+                commit: None,
+                commit_timestamp: None,
+            },
+        };
+
+        let text = get_prometheus_health_check_response(&response);
+
+        assert!(text.contains("hyperswitch_health_check_component_up{component=\"database\"} 1\n"));
+        assert!(text.contains("hyperswitch_health_check_component_up{component=\"redis\"} 0\n"));
+        assert!(
+            text.contains("hyperswitch_health_check_component_degraded{component=\"redis\"} 0\n")
+        );
+        assert!(text.contains(
+            "hyperswitch_health_check_component_latency_seconds{component=\"redis\"} 2.5\n"
+        ));
+        assert!(!text.contains("component=\"file_store\""));
+        assert!(!text.contains("component=\"database_schema\""));
+        assert!(text.contains("hyperswitch_build_info{version=\"1.0.0\",commit=\"\"} 1\n"));
+    }
+
+    #[actix_rt::test]
+    async fn test_deep_health_check_func_while_shutdown_draining() {
+        let conf = Settings::new().unwrap();
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let app_state = Box::pin(app::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = Arc::new(app_state)
+            .get_session_state("public", || {})
+            .unwrap();
+
+        let response = deep_health_check_func(
+            state.clone(),
+            false,
+            false,
+            HealthCheckResponseFormat::Json,
+            true,
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            response,
+            api::ApplicationResponse::Json(RouterHealthCheckResponse {
+                status: ComponentHealthStatus::Down,
+                database: false,
+                ref components,
+                ..
+            }) if components.get("router").map(|health| health.status)
+                == Some(ComponentHealthStatus::Down)
+        ));
+
+        // The dependencies are not probed, so only the router is reported
+        let response = deep_health_check_func(
+            state,
+            false,
+            false,
+            HealthCheckResponseFormat::Prometheus,
+            true,
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            response,
+            api::ApplicationResponse::FileData((ref body, _))
+                if String::from_utf8_lossy(body)
+                    .contains("hyperswitch_health_check_component_up{component=\"router\"} 0\n")
+                    && !String::from_utf8_lossy(body).contains("component=\"database\"")
+        ));
+    }
 }
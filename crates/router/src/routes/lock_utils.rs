@@ -61,7 +61,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DecisionManagerRetrieveConfig
             | Flow::DecisionManagerUpsertConfig => Self::Routing,
 
-            Flow::RetrieveForexFlow => Self::Forex,
+            Flow::RetrieveForexFlow
+            | Flow::RetrieveCurrencyMetadata
+            | Flow::ForexQuoteCreate
+            | Flow::ForexQuoteCancel => Self::Forex,
 
             Flow::AddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
@@ -129,11 +132,37 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
+            | Flow::PayoutsBulkRetrieve
+            | Flow::PayoutsBatchCreate
+            | Flow::PayoutsRetrieveByConnectorReference
+            | Flow::PayoutTemplatesCreate
+            | Flow::PayoutTemplatesRetrieve
+            | Flow::PayoutTemplatesList
+            | Flow::PayoutTemplatesDelete
+            | Flow::PayoutDestinationVerificationsCreate
+            | Flow::PayoutDestinationVerificationsRetrieve
+            | Flow::PayoutDestinationVerificationsConfirm
+            | Flow::PayoutHoldsCreate
+            | Flow::PayoutHoldsRetrieve
+            | Flow::PayoutHoldsCapture
+            | Flow::PayoutHoldsRelease
+            | Flow::PayoutHoldsBalance
+            | Flow::PayoutsAdminRetrieve
+            | Flow::PayoutsAdminCurrencyConversions
             | Flow::PayoutsUpdate
             | Flow::PayoutsCancel
             | Flow::PayoutsFulfill
+            | Flow::PayoutsApprove
+            | Flow::PayoutsVerify
+            | Flow::PayoutsReprocess
+            | Flow::PayoutsRecall
+            | Flow::PayoutsSeriesCancel
             | Flow::PayoutsList
             | Flow::PayoutsFilter
+            | Flow::PayoutsExport
+            | Flow::PayoutsExportRetrieve
+            | Flow::PayoutsExportDownload
+            | Flow::PayoutsConnectorUsage
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
             | Flow::PayoutLinkInitiate => Self::Payouts,
@@ -149,7 +178,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventDeliveryRetryStatus => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
@@ -181,7 +211,8 @@ impl From<Flow> for ApiIdentifier {
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::PaymentLinkPreview => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
@@ -37,6 +37,7 @@ pub fn status_code_metrics(status_code: String, flow: String, merchant_id: Strin
     )
 }
 
+#[allow(deprecated)]
 pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
     match response {
         ApplicationResponse::Json(_)
@@ -45,6 +46,7 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::Form(_)
         | ApplicationResponse::GenericLinkForm(_)
         | ApplicationResponse::PaymentLinkForm(_)
+        | ApplicationResponse::PaymenkLinkForm(_)
         | ApplicationResponse::FileData(_)
         | ApplicationResponse::JsonWithHeaders(_) => 200,
         ApplicationResponse::JsonForRedirection(_) => 302,
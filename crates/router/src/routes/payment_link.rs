@@ -51,6 +51,50 @@ pub async fn payment_link_retrieve(
     .await
 }
 
+/// Payments Link - Retrieve by Payment ID
+///
+/// To retrieve the properties of the Payment Link associated with a payment
+#[utoipa::path(
+    get,
+    path = "/payment_link/payment/{payment_id}",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for the payment")
+    ),
+    responses(
+        (status = 200, description = "Gets details regarding payment link", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Payment Link by Payment ID",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkRetrieve))]
+pub async fn payment_link_retrieve_by_payment_id(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkRetrieve;
+    let payment_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth, payment_id, _| {
+            retrieve_payment_link_by_payment_id(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn initiate_payment_link(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -125,6 +169,40 @@ pub async fn payments_link_list(
     .await
 }
 
+/// Payment Link - Preview
+///
+/// To preview the payment link page for sample payment details and a payment link configuration, without creating a payment
+#[utoipa::path(
+    post,
+    path = "/payment_link/preview",
+    request_body=PaymentLinkPreviewRequest,
+    responses(
+        (status = 200, description = "The payment link page was rendered with the sample details"),
+        (status = 400, description = "Invalid payment link configuration")
+    ),
+    tag = "Payment Link",
+    operation_id = "Preview a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkPreview))]
+pub async fn payment_link_preview(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::PaymentLinkPreviewRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkPreview;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _: auth::AuthenticationData, payload, _| preview_payment_link(state, payload),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn payment_link_status(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
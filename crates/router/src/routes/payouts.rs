@@ -1,5 +1,6 @@
 use actix_web::{
     body::{BoxBody, MessageBody},
+    error::JsonPayloadError,
     web, HttpRequest, HttpResponse, Responder,
 };
 use router_env::{instrument, tracing, Flow};
@@ -8,15 +9,23 @@ use super::app::AppState;
 #[cfg(feature = "olap")]
 use crate::types::api::payments as payment_types;
 use crate::{
-    core::{api_locking, payouts::*},
+    core::{
+        api_locking,
+        payouts::{helpers as payout_helpers, *},
+    },
+    headers,
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::payouts as payout_types,
+    utils,
 };
 
 /// Payouts - Create
 #[utoipa::path(
     post,
     path = "/payouts/create",
+    params(
+        ("X-Ignore-Unknown-Fields" = Option<bool>, Header, description = "When set to true, unknown fields in the request body are ignored instead of being rejected")
+    ),
     request_body=PayoutCreateRequest,
     responses(
         (status = 200, description = "Payout created", body = PayoutCreateResponse),
@@ -30,126 +39,1048 @@ use crate::{
 pub async fn payouts_create(
     state: web::Data<AppState>,
     req: HttpRequest,
-    json_payload: web::Json<payout_types::PayoutCreateRequest>,
+    json_payload: web::Json<serde_json::Value>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsCreate;
+    let payload = match parse_payout_request(&req, json_payload.into_inner()) {
+        Ok(payload) => payload,
+        Err(response) => return response,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, (auth, created_by): auth::AuthenticationDataWithUserId, req, _| {
+            payouts_create_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                Some(created_by),
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout]")
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutCreateResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
+pub async fn payouts_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_params: web::Query<payout_types::PayoutRetrieveBody>,
+) -> HttpResponse {
+    let payout_retrieve_request = payout_types::PayoutRetrieveRequest {
+        payout_id: path.into_inner(),
+        force_sync: query_params.force_sync.to_owned(),
+        merchant_id: query_params.merchant_id.to_owned(),
+        timezone: query_params.timezone.to_owned(),
+    };
+    let flow = Flow::PayoutsRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payout_retrieve_request,
+        |state, auth, req, _| {
+            payouts_retrieve_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Bulk Retrieve
+#[utoipa::path(
+    post,
+    path = "/payouts/bulk_retrieve",
+    request_body=PayoutBulkRetrieveRequest,
+    responses(
+        (status = 200, description = "Payouts retrieved", body = PayoutBulkRetrieveResponse),
+        (status = 400, description = "Invalid list of payout ids")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve Payouts in bulk",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBulkRetrieve))]
+pub async fn payouts_bulk_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBulkRetrieveRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBulkRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            payouts_bulk_retrieve_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Batch Create
+#[utoipa::path(
+    post,
+    path = "/payouts/batch",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payouts of the batch processed, with the result of each item", body = PayoutBatchCreateResponse),
+        (status = 400, description = "Invalid batch")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a batch of Payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCreate))]
+pub async fn payouts_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, (auth, created_by): auth::AuthenticationDataWithUserId, req, _| {
+            payouts_batch_create_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                Some(created_by),
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Retrieve by Connector Reference
+#[utoipa::path(
+    get,
+    path = "/payouts/connector_reference",
+    params(
+        ("connector_payout_id" = String, Query, description = "The identifier assigned to the payout by the connector"),
+        ("connector" = Option<PayoutConnectors>, Query, description = "The connector which processed the payout"),
+        ("timezone" = Option<String>, Query, description = "IANA time zone name in which the timestamps in the response are to be returned"),
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutCreateResponse),
+        (status = 400, description = "Connector reference matches more than one payout"),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout by Connector Reference",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieveByConnectorReference))]
+pub async fn payouts_retrieve_by_connector_reference(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<payout_types::PayoutRetrieveByConnectorReferenceRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsRetrieveByConnectorReference;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth, req, _| {
+            payouts_retrieve_by_connector_reference_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payout Templates - Create
+#[utoipa::path(
+    post,
+    path = "/payouts/templates",
+    request_body=PayoutTemplateCreateRequest,
+    responses(
+        (status = 200, description = "Payout template created", body = PayoutTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Template",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutTemplatesCreate))]
+pub async fn payout_templates_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutTemplateCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutTemplatesCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| templates::create_payout_template(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payout Templates - List
+#[utoipa::path(
+    get,
+    path = "/payouts/templates",
+    responses(
+        (status = 200, description = "Payout templates retrieved", body = PayoutTemplateListResponse)
+    ),
+    tag = "Payouts",
+    operation_id = "List Payout Templates",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutTemplatesList))]
+pub async fn payout_templates_list(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::PayoutTemplatesList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _, _| templates::list_payout_templates(state, auth.merchant_account),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payout Templates - Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for the payout template")
+    ),
+    responses(
+        (status = 200, description = "Payout template retrieved", body = PayoutTemplateResponse),
+        (status = 404, description = "Payout template does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Template",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutTemplatesRetrieve))]
+pub async fn payout_templates_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutTemplatesRetrieve;
+    let payload = payout_types::PayoutTemplateId {
+        template_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            templates::retrieve_payout_template(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payout Templates - Delete
+#[utoipa::path(
+    delete,
+    path = "/payouts/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for the payout template")
+    ),
+    responses(
+        (status = 200, description = "Payout template deleted", body = PayoutTemplateResponse),
+        (status = 404, description = "Payout template does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Delete a Payout Template",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutTemplatesDelete))]
+pub async fn payout_templates_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutTemplatesDelete;
+    let payload = payout_types::PayoutTemplateId {
+        template_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| templates::delete_payout_template(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payout Destination Verifications - Create
+///
+/// Initiates the verification of a payout destination of a customer by sending micro-deposits to it
+#[utoipa::path(
+    post,
+    path = "/payouts/destination_verifications",
+    request_body=PayoutDestinationVerificationRequest,
+    responses(
+        (status = 200, description = "Payout destination verification initiated", body = PayoutDestinationVerificationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Destination Verification",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutDestinationVerificationsCreate))]
+pub async fn payout_destination_verifications_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutDestinationVerificationRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutDestinationVerificationsCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            destination_verification::create_payout_destination_verification(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payout Destination Verifications - Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/destination_verifications/{verification_id}",
+    params(
+        ("verification_id" = String, Path, description = "The identifier for the payout destination verification")
+    ),
+    responses(
+        (status = 200, description = "Payout destination verification retrieved", body = PayoutDestinationVerificationResponse),
+        (status = 404, description = "Payout destination verification does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Destination Verification",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutDestinationVerificationsRetrieve))]
+pub async fn payout_destination_verifications_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutDestinationVerificationsRetrieve;
+    let payload = payout_types::PayoutDestinationVerificationId {
+        verification_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            destination_verification::retrieve_payout_destination_verification(
+                state,
+                auth.merchant_account,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payout Destination Verifications - Confirm
+///
+/// Confirms the amounts of the micro-deposits received by the customer. The destination is verified when the amounts match, else an attempt is used up
+#[utoipa::path(
+    post,
+    path = "/payouts/destination_verifications/{verification_id}/confirm",
+    params(
+        ("verification_id" = String, Path, description = "The identifier for the payout destination verification")
+    ),
+    request_body=PayoutDestinationVerificationConfirmRequest,
+    responses(
+        (status = 200, description = "Micro-deposit amounts confirmed", body = PayoutDestinationVerificationResponse),
+        (status = 400, description = "Payout destination verification is not pending")
+    ),
+    tag = "Payouts",
+    operation_id = "Confirm a Payout Destination Verification",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutDestinationVerificationsConfirm))]
+pub async fn payout_destination_verifications_confirm(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutDestinationVerificationConfirmRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutDestinationVerificationsConfirm;
+    let mut payload = json_payload.into_inner();
+    payload.verification_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            destination_verification::confirm_payout_destination_verification(
+                state,
+                auth.merchant_account,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payout Holds - Create
+///
+/// Holds an amount of the merchant's funds for a future payout, without disbursing it
+#[utoipa::path(
+    post,
+    path = "/payouts/holds",
+    request_body=PayoutHoldCreateRequest,
+    responses(
+        (status = 200, description = "Amount held", body = PayoutHoldResponse),
+        (status = 400, description = "Insufficient funds for the hold")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Hold",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutHoldsCreate))]
+pub async fn payout_holds_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutHoldCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutHoldsCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| holds::create_payout_hold(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
+        holds::get_payout_holds_create_lock_action(),
+    ))
+    .await
+}
+/// Payout Holds - Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/holds/{hold_id}",
+    params(
+        ("hold_id" = String, Path, description = "The identifier for the payout hold")
+    ),
+    responses(
+        (status = 200, description = "Payout hold retrieved", body = PayoutHoldResponse),
+        (status = 404, description = "Payout hold does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Hold",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutHoldsRetrieve))]
+pub async fn payout_holds_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutHoldsRetrieve;
+    let payload = payout_types::PayoutHoldId {
+        hold_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| holds::retrieve_payout_hold(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payout Holds - Capture
+///
+/// Converts a hold into a payout of at most the held amount, in the currency of the hold
+#[utoipa::path(
+    post,
+    path = "/payouts/holds/{hold_id}/capture",
+    params(
+        ("hold_id" = String, Path, description = "The identifier for the payout hold")
+    ),
+    request_body=PayoutHoldCaptureRequest,
+    responses(
+        (status = 200, description = "Payout created from the hold", body = PayoutHoldResponse),
+        (status = 400, description = "Payout hold is not held")
+    ),
+    tag = "Payouts",
+    operation_id = "Capture a Payout Hold",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutHoldsCapture))]
+pub async fn payout_holds_capture(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutHoldCaptureRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutHoldsCapture;
+    let mut payload = json_payload.into_inner();
+    payload.hold_id = path.into_inner();
+    let lock_action = holds::get_payout_hold_lock_action(&payload.hold_id);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, (auth, created_by): auth::AuthenticationDataWithUserId, req, _| {
+            holds::capture_payout_hold(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                Some(created_by),
+            )
+        },
+        &auth::ApiKeyAuth,
+        lock_action,
+    ))
+    .await
+}
+/// Payout Holds - Release
+///
+/// Cancels a hold, making the held amount available again
+#[utoipa::path(
+    post,
+    path = "/payouts/holds/{hold_id}/release",
+    params(
+        ("hold_id" = String, Path, description = "The identifier for the payout hold")
+    ),
+    responses(
+        (status = 200, description = "Payout hold released", body = PayoutHoldResponse),
+        (status = 400, description = "Payout hold is not held")
+    ),
+    tag = "Payouts",
+    operation_id = "Release a Payout Hold",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutHoldsRelease))]
+pub async fn payout_holds_release(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutHoldsRelease;
+    let payload = payout_types::PayoutHoldId {
+        hold_id: path.into_inner(),
+    };
+    let lock_action = holds::get_payout_hold_lock_action(&payload.hold_id);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| holds::release_payout_hold(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
+        lock_action,
+    ))
+    .await
+}
+/// Payout Holds - Balance
+///
+/// Retrieves the amounts held for payouts and the funds still available for holds, per currency
+#[utoipa::path(
+    get,
+    path = "/payouts/holds/balance",
+    responses(
+        (status = 200, description = "Payout hold balances retrieved", body = PayoutHoldBalanceResponse)
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve the Payout Hold Balance",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutHoldsBalance))]
+pub async fn payout_holds_balance(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::PayoutHoldsBalance;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _, _| holds::retrieve_payout_hold_balance(state, auth.merchant_account),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Admin Retrieve
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    get,
+    path = "/payouts/admin/{merchant_id}/{payout_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutAdminRetrieveResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout as admin",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsAdminRetrieve))]
+pub async fn payouts_admin_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsAdminRetrieve;
+    let (merchant_id, payout_id) = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, payout_id),
+        |state, _, (merchant_id, payout_id), _| {
+            payouts_admin_retrieve_core(state, merchant_id, payout_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Admin Currency Conversions
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    get,
+    path = "/payouts/admin/{merchant_id}/{payout_id}/currency_conversions",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Currency conversions of the payout retrieved", body = PayoutCurrencyConversionAuditLogResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List the currency conversions of a Payout as admin",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsAdminCurrencyConversions))]
+pub async fn payouts_admin_currency_conversions(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsAdminCurrencyConversions;
+    let (merchant_id, payout_id) = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, payout_id),
+        |state, _, (merchant_id, payout_id), _| {
+            payouts_admin_currency_conversions_core(state, merchant_id, payout_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Update
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout]"),
+        ("X-Ignore-Unknown-Fields" = Option<bool>, Header, description = "When set to true, unknown fields in the request body are ignored instead of being rejected")
+    ),
+    request_body=PayoutCreateRequest,
+    responses(
+        (status = 200, description = "Payout updated", body = PayoutCreateResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Update a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
+pub async fn payouts_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<serde_json::Value>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsUpdate;
+    let payout_id = path.into_inner();
+    let mut payout_update_payload = match parse_payout_request(&req, json_payload.into_inner()) {
+        Ok(payload) => payload,
+        Err(response) => return response,
+    };
+    payout_update_payload.payout_id = Some(payout_id);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payout_update_payload,
+        |state, auth, req, _| {
+            payouts_update_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsConfirm))]
+pub async fn payouts_confirm(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<serde_json::Value>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsConfirm;
+    let mut payload = match parse_payout_request(&req, json_payload.into_inner()) {
+        Ok(payload) => payload,
+        Err(response) => return response,
+    };
+    let payout_id = path.into_inner();
+    tracing::Span::current().record("payout_id", &payout_id);
+    payload.payout_id = Some(payout_id);
+    payload.confirm = Some(true);
+    let (auth_type, _auth_flow) =
+        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+            Ok(auth) => auth,
+            Err(e) => return api::log_and_return_error_response(e),
+        };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            payouts_confirm_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        &*auth_type,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Cancel
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/cancel",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutActionRequest,
+    responses(
+        (status = 200, description = "Payout cancelled", body = PayoutCreateResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
+pub async fn payouts_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutActionRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsCancel;
+    let mut payload = json_payload.into_inner();
+    payload.payout_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            payouts_cancel_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Fulfill
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/fulfill",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutActionRequest,
+    responses(
+        (status = 200, description = "Payout fulfilled", body = PayoutCreateResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Fulfill a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsFulfill))]
+pub async fn payouts_fulfill(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutActionRequest>,
+    path: web::Path<String>,
 ) -> HttpResponse {
-    let flow = Flow::PayoutsCreate;
+    let flow = Flow::PayoutsFulfill;
+    let mut payload = json_payload.into_inner();
+    payload.payout_id = path.into_inner();
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth, req, _| {
-            payouts_create_core(state, auth.merchant_account, auth.key_store, req)
+            payouts_fulfill_core(state, auth.merchant_account, auth.key_store, req)
         },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
-/// Payouts - Retrieve
+
+/// Payouts - Approve
 #[utoipa::path(
-    get,
-    path = "/payouts/{payout_id}",
+    post,
+    path = "/payouts/{payout_id}/approve",
     params(
-        ("payout_id" = String, Path, description = "The identifier for payout]")
+        ("payout_id" = String, Path, description = "The identifier for payout")
     ),
+    request_body=PayoutApproveRequest,
     responses(
-        (status = 200, description = "Payout retrieved", body = PayoutCreateResponse),
-        (status = 404, description = "Payout does not exist in our records")
+        (status = 200, description = "Payout approved", body = PayoutCreateResponse),
+        (status = 400, description = "Payout is not pending approval, or is being approved by its creator")
     ),
     tag = "Payouts",
-    operation_id = "Retrieve a Payout",
+    operation_id = "Approve a Payout",
     security(("api_key" = []))
 )]
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
-pub async fn payouts_retrieve(
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsApprove))]
+pub async fn payouts_approve(
     state: web::Data<AppState>,
     req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutApproveRequest>,
     path: web::Path<String>,
-    query_params: web::Query<payout_types::PayoutRetrieveBody>,
 ) -> HttpResponse {
-    let payout_retrieve_request = payout_types::PayoutRetrieveRequest {
-        payout_id: path.into_inner(),
-        force_sync: query_params.force_sync.to_owned(),
-        merchant_id: query_params.merchant_id.to_owned(),
-    };
-    let flow = Flow::PayoutsRetrieve;
+    let flow = Flow::PayoutsApprove;
+    let mut payload = json_payload.into_inner();
+    payload.payout_id = path.into_inner();
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        payout_retrieve_request,
-        |state, auth, req, _| {
-            payouts_retrieve_core(state, auth.merchant_account, auth.key_store, req)
+        payload,
+        |state, (auth, approved_by): auth::AuthenticationDataWithUserId, req, _| {
+            payouts_approve_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                approved_by,
+            )
         },
         auth::auth_type(
             &auth::ApiKeyAuth,
-            &auth::JWTAuth(Permission::PayoutRead),
+            &auth::JWTAuth(Permission::PayoutWrite),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
-/// Payouts - Update
+
+/// Payouts - Verify
+///
+/// Posts the result of the document verification of the recipient of a payout held in `pending_verification`. A verified payout resumes its disbursement, a rejected payout fails
 #[utoipa::path(
     post,
-    path = "/payouts/{payout_id}",
+    path = "/payouts/{payout_id}/verify",
     params(
-        ("payout_id" = String, Path, description = "The identifier for payout]")
+        ("payout_id" = String, Path, description = "The identifier for payout")
     ),
-    request_body=PayoutCreateRequest,
+    request_body=PayoutVerificationRequest,
     responses(
-        (status = 200, description = "Payout updated", body = PayoutCreateResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 200, description = "Payout verification recorded", body = PayoutCreateResponse),
+        (status = 400, description = "Payout is not pending verification")
     ),
     tag = "Payouts",
-    operation_id = "Update a Payout",
+    operation_id = "Verify a Payout",
     security(("api_key" = []))
 )]
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
-pub async fn payouts_update(
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsVerify))]
+pub async fn payouts_verify(
     state: web::Data<AppState>,
     req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutVerificationRequest>,
     path: web::Path<String>,
-    json_payload: web::Json<payout_types::PayoutCreateRequest>,
 ) -> HttpResponse {
-    let flow = Flow::PayoutsUpdate;
-    let payout_id = path.into_inner();
-    let mut payout_update_payload = json_payload.into_inner();
-    payout_update_payload.payout_id = Some(payout_id);
+    let flow = Flow::PayoutsVerify;
+    let mut payload = json_payload.into_inner();
+    payload.payout_id = path.into_inner();
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        payout_update_payload,
+        payload,
         |state, auth, req, _| {
-            payouts_update_core(state, auth.merchant_account, auth.key_store, req)
+            payouts_verify_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
 
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsConfirm))]
-pub async fn payouts_confirm(
+/// Payouts - Reprocess
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/reprocess",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutReprocessRequest,
+    responses(
+        (status = 200, description = "Payout reprocessed", body = PayoutCreateResponse),
+        (status = 400, description = "Payout has not failed")
+    ),
+    tag = "Payouts",
+    operation_id = "Reprocess a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsReprocess))]
+pub async fn payouts_reprocess(
     state: web::Data<AppState>,
     req: HttpRequest,
-    json_payload: web::Json<payout_types::PayoutCreateRequest>,
+    json_payload: web::Json<payout_types::PayoutReprocessRequest>,
     path: web::Path<String>,
 ) -> HttpResponse {
-    let flow = Flow::PayoutsConfirm;
+    let flow = Flow::PayoutsReprocess;
     let mut payload = json_payload.into_inner();
-    let payout_id = path.into_inner();
-    tracing::Span::current().record("payout_id", &payout_id);
-    payload.payout_id = Some(payout_id);
-    payload.confirm = Some(true);
-    let (auth_type, _auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(e) => return api::log_and_return_error_response(e),
-        };
+    payload.payout_id = path.into_inner();
 
     Box::pin(api::server_wrap(
         flow,
@@ -157,37 +1088,42 @@ pub async fn payouts_confirm(
         &req,
         payload,
         |state, auth, req, _| {
-            payouts_confirm_core(state, auth.merchant_account, auth.key_store, req)
+            payouts_reprocess_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &*auth_type,
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
-/// Payouts - Cancel
+
+/// Payouts - Recall
 #[utoipa::path(
     post,
-    path = "/payouts/{payout_id}/cancel",
+    path = "/payouts/{payout_id}/recall",
     params(
         ("payout_id" = String, Path, description = "The identifier for payout")
     ),
-    request_body=PayoutActionRequest,
+    request_body=PayoutRecallRequest,
     responses(
-        (status = 200, description = "Payout cancelled", body = PayoutCreateResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 200, description = "Payout recalled", body = PayoutCreateResponse),
+        (status = 400, description = "Payout has not succeeded")
     ),
     tag = "Payouts",
-    operation_id = "Cancel a Payout",
+    operation_id = "Recall a Payout",
     security(("api_key" = []))
 )]
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
-pub async fn payouts_cancel(
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsRecall))]
+pub async fn payouts_recall(
     state: web::Data<AppState>,
     req: HttpRequest,
-    json_payload: web::Json<payout_types::PayoutActionRequest>,
+    json_payload: web::Json<payout_types::PayoutRecallRequest>,
     path: web::Path<String>,
 ) -> HttpResponse {
-    let flow = Flow::PayoutsCancel;
+    let flow = Flow::PayoutsRecall;
     let mut payload = json_payload.into_inner();
     payload.payout_id = path.into_inner();
 
@@ -197,39 +1133,43 @@ pub async fn payouts_cancel(
         &req,
         payload,
         |state, auth, req, _| {
-            payouts_cancel_core(state, auth.merchant_account, auth.key_store, req)
+            payouts_recall_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
-/// Payouts - Fulfill
+
+/// Payouts - Cancel Series
 #[utoipa::path(
     post,
-    path = "/payouts/{payout_id}/fulfill",
+    path = "/payouts/series/{series_id}/cancel",
     params(
-        ("payout_id" = String, Path, description = "The identifier for payout")
+        ("series_id" = String, Path, description = "The identifier for the recurring payout series")
     ),
-    request_body=PayoutActionRequest,
     responses(
-        (status = 200, description = "Payout fulfilled", body = PayoutCreateResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 200, description = "Scheduled runs of the series cancelled", body = PayoutSeriesCancelResponse),
+        (status = 404, description = "Payout series not found")
     ),
     tag = "Payouts",
-    operation_id = "Fulfill a Payout",
+    operation_id = "Cancel a Payout Series",
     security(("api_key" = []))
 )]
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsFulfill))]
-pub async fn payouts_fulfill(
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsSeriesCancel))]
+pub async fn payouts_series_cancel(
     state: web::Data<AppState>,
     req: HttpRequest,
-    json_payload: web::Json<payout_types::PayoutActionRequest>,
     path: web::Path<String>,
 ) -> HttpResponse {
-    let flow = Flow::PayoutsFulfill;
-    let mut payload = json_payload.into_inner();
-    payload.payout_id = path.into_inner();
+    let flow = Flow::PayoutsSeriesCancel;
+    let payload = payout_types::PayoutSeriesCancelRequest {
+        series_id: path.into_inner(),
+    };
 
     Box::pin(api::server_wrap(
         flow,
@@ -237,9 +1177,13 @@ pub async fn payouts_fulfill(
         &req,
         payload,
         |state, auth, req, _| {
-            payouts_fulfill_core(state, auth.merchant_account, auth.key_store, req)
+            payouts_series_cancel_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -363,6 +1307,172 @@ pub async fn payouts_list_available_filters(
     .await
 }
 
+/// Payouts - Export
+///
+/// Exports the payouts matching the constraints as CSV. Large exports are generated asynchronously, in which case an export job is returned instead, which can be polled until the export can be downloaded
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    post,
+    path = "/payouts/export",
+    request_body=PayoutListConstraints,
+    responses(
+        (status = 200, description = "Payouts exported as CSV, or export job created", body = PayoutExportJobResponse),
+        (status = 404, description = "Payout not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Export payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsExport))]
+pub async fn payouts_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsExport;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| export::payouts_export_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Retrieve export job
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    get,
+    path = "/payouts/export/{job_id}",
+    params(
+        ("job_id" = String, Path, description = "The identifier for the payouts export job")
+    ),
+    responses(
+        (status = 200, description = "Payouts export job retrieved", body = PayoutExportJobResponse),
+        (status = 404, description = "Payouts export job not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payouts Export Job",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsExportRetrieve))]
+pub async fn payouts_export_job_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsExportRetrieve;
+    let payload = payout_types::PayoutExportJobId {
+        job_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            export::retrieve_payouts_export_job(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Download export
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    get,
+    path = "/payouts/export/{job_id}/download",
+    params(
+        ("job_id" = String, Path, description = "The identifier for the payouts export job")
+    ),
+    responses(
+        (status = 200, description = "Payouts export downloaded as CSV"),
+        (status = 404, description = "Payouts export job not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Download a Payouts Export",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsExportDownload))]
+pub async fn payouts_export_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsExportDownload;
+    let payload = payout_types::PayoutExportJobId {
+        job_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| export::download_payouts_export(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Connector usage
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    post,
+    path = "/payouts/connectors",
+    responses(
+        (status = 200, description = "Connectors used for payouts", body = PayoutConnectorUsageResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "List connectors used for payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsConnectorUsage))]
+pub async fn payouts_connector_usage(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_types::TimeRange>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsConnectorUsage;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| payouts_connector_usage_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
@@ -373,3 +1483,22 @@ pub async fn payouts_accounts() -> impl Responder {
 fn http_response<T: MessageBody + 'static>(response: T) -> HttpResponse<BoxBody> {
     HttpResponse::Ok().body(response)
 }
+
+/// Parses a payout request body. Unknown fields in it are rejected, unless the client opts into
+/// lenient parsing with the `X-Ignore-Unknown-Fields: true` header.
+fn parse_payout_request(
+    req: &HttpRequest,
+    payload: serde_json::Value,
+) -> Result<payout_types::PayoutCreateRequest, HttpResponse> {
+    let ignore_unknown_fields = req
+        .headers()
+        .get(headers::X_IGNORE_UNKNOWN_FIELDS)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.eq_ignore_ascii_case("true"));
+    payout_helpers::parse_payout_create_request(payload, ignore_unknown_fields).map_err(|error| {
+        HttpResponse::from_error(utils::error_parser::custom_json_error_handler(
+            JsonPayloadError::Deserialize(error),
+            req,
+        ))
+    })
+}
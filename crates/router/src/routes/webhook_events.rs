@@ -91,6 +91,45 @@ pub async fn list_webhook_delivery_attempts(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryRetryStatus))]
+pub async fn retrieve_webhook_delivery_retry_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryRetryStatus;
+    let (merchant_id_or_profile_id, initial_attempt_id) = path.into_inner();
+
+    let request_internal = WebhookDeliveryAttemptListRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        initial_attempt_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::retrieve_delivery_retry_status(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.initial_attempt_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryRetry))]
 pub async fn retry_webhook_delivery_attempt(
     state: web::Data<AppState>,
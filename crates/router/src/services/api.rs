@@ -721,6 +721,10 @@ pub enum ApplicationResponse<R> {
     JsonForRedirection(api::RedirectionResponse),
     Form(Box<RedirectionFormData>),
     PaymentLinkForm(Box<PaymentLinkAction>),
+    /// Misspelled name of `PaymentLinkForm`, rendered the same way. Kept for one release for the
+    /// callers which construct or match on it
+    #[deprecated(note = "use `ApplicationResponse::PaymentLinkForm` instead")]
+    PaymenkLinkForm(Box<PaymentLinkAction>),
     FileData((Vec<u8>, mime::Mime)),
     JsonWithHeaders((R, Vec<(String, Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
@@ -1009,6 +1013,7 @@ where
     skip(request, state, func, api_auth, payload),
     fields(request_method, request_url_path, status_code)
 )]
+#[allow(deprecated)]
 pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
     flow: impl router_env::types::FlowMetric,
     state: web::Data<AppState>,
@@ -1127,34 +1132,35 @@ where
             }
         }
 
-        Ok(ApplicationResponse::PaymentLinkForm(boxed_payment_link_data)) => {
-            match *boxed_payment_link_data {
-                PaymentLinkAction::PaymentLinkFormData(payment_link_data) => {
-                    match build_payment_link_html(payment_link_data) {
-                        Ok(rendered_html) => http_response_html_data(rendered_html),
-                        Err(_) => http_response_err(
-                            r#"{
+        Ok(
+            ApplicationResponse::PaymentLinkForm(boxed_payment_link_data)
+            | ApplicationResponse::PaymenkLinkForm(boxed_payment_link_data),
+        ) => match *boxed_payment_link_data {
+            PaymentLinkAction::PaymentLinkFormData(payment_link_data) => {
+                match build_payment_link_html(payment_link_data) {
+                    Ok(rendered_html) => http_response_html_data(rendered_html),
+                    Err(_) => http_response_err(
+                        r#"{
                                 "error": {
                                     "message": "Error while rendering payment link html page"
                                 }
                             }"#,
-                        ),
-                    }
+                    ),
                 }
-                PaymentLinkAction::PaymentLinkStatus(payment_link_data) => {
-                    match get_payment_link_status(payment_link_data) {
-                        Ok(rendered_html) => http_response_html_data(rendered_html),
-                        Err(_) => http_response_err(
-                            r#"{
+            }
+            PaymentLinkAction::PaymentLinkStatus(payment_link_data) => {
+                match get_payment_link_status(payment_link_data) {
+                    Ok(rendered_html) => http_response_html_data(rendered_html),
+                    Err(_) => http_response_err(
+                        r#"{
                                 "error": {
                                     "message": "Error while rendering payment link status page"
                                 }
                             }"#,
-                        ),
-                    }
+                    ),
                 }
             }
-        }
+        },
 
         Ok(ApplicationResponse::JsonWithHeaders((response, headers))) => {
             let request_elapsed_time = request.headers().get(X_HS_LATENCY).and_then(|value| {
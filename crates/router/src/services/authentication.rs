@@ -348,6 +348,35 @@ where
     }
 }
 
+/// API keys act on behalf of the merchant rather than a user, so the key id of the API key is
+/// returned in place of the user id
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationDataWithUserId, A> for ApiKeyAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationDataWithUserId, AuthenticationType)> {
+        let (auth, auth_type) =
+            AuthenticateAndFetch::<AuthenticationData, A>::authenticate_and_fetch(
+                self,
+                request_headers,
+                state,
+            )
+            .await?;
+        let key_id = match &auth_type {
+            AuthenticationType::ApiKey { key_id, .. } => key_id.clone(),
+            _ => Err(errors::ApiErrorResponse::InternalServerError).attach_printable(
+                "API key authentication returned a different authentication type",
+            )?,
+        };
+        Ok(((auth, key_id), auth_type))
+    }
+}
+
 #[cfg(feature = "olap")]
 #[derive(Debug)]
 pub(crate) struct SinglePurposeJWTAuth(pub TokenPurpose);
@@ -29,12 +29,16 @@ pub struct KafkaPayout<'a> {
     pub attempt_count: i16,
     pub status: storage_enums::PayoutStatus,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub purpose_code: Option<&'a String>,
 
     pub connector: Option<&'a String>,
     pub connector_payout_id: Option<&'a String>,
     pub is_eligible: Option<bool>,
     pub error_message: Option<&'a String>,
     pub error_code: Option<&'a String>,
+    pub reason_code: Option<storage_enums::PayoutStatusReasonCode>,
+    pub reason: Option<&'a String>,
+    pub recalled_amount: Option<MinorUnit>,
     pub business_country: Option<storage_enums::CountryAlpha2>,
     pub business_label: Option<&'a String>,
     pub merchant_connector_id: Option<&'a String>,
@@ -65,11 +69,15 @@ impl<'a> KafkaPayout<'a> {
             attempt_count: payouts.attempt_count,
             status: payouts.status,
             priority: payouts.priority,
+            purpose_code: payouts.purpose_code.as_ref(),
             connector: payout_attempt.connector.as_ref(),
             connector_payout_id: payout_attempt.connector_payout_id.as_ref(),
             is_eligible: payout_attempt.is_eligible,
             error_message: payout_attempt.error_message.as_ref(),
             error_code: payout_attempt.error_code.as_ref(),
+            reason_code: payout_attempt.reason_code,
+            reason: payout_attempt.reason.as_ref(),
+            recalled_amount: payout_attempt.recalled_amount,
             business_country: payout_attempt.business_country,
             business_label: payout_attempt.business_label.as_ref(),
             merchant_connector_id: payout_attempt.merchant_connector_id.as_ref(),
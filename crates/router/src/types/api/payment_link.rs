@@ -8,18 +8,24 @@ use crate::{
 
 #[async_trait::async_trait]
 pub(crate) trait PaymentLinkResponseExt: Sized {
-    async fn from_db_payment_link(payment_link: storage::PaymentLink) -> RouterResult<Self>;
+    async fn from_db_payment_link(
+        payment_link: storage::PaymentLink,
+        grace_period: time::Duration,
+    ) -> RouterResult<Self>;
 }
 
 #[async_trait::async_trait]
 impl PaymentLinkResponseExt for RetrievePaymentLinkResponse {
-    async fn from_db_payment_link(payment_link: storage::PaymentLink) -> RouterResult<Self> {
+    async fn from_db_payment_link(
+        payment_link: storage::PaymentLink,
+        grace_period: time::Duration,
+    ) -> RouterResult<Self> {
         let session_expiry = payment_link.fulfilment_time.unwrap_or_else(|| {
             payment_link
                 .created_at
                 .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
         });
-        let status = payment_link::check_payment_link_status(session_expiry);
+        let status = payment_link::check_payment_link_status(session_expiry, grace_period);
         Ok(Self {
             link_to_pay: payment_link.link_to_pay,
             payment_link_id: payment_link.payment_link_id,
@@ -1,8 +1,21 @@
 pub use api_models::payouts::{
-    AchBankTransfer, BacsBankTransfer, Bank as BankPayout, CardPayout, PayoutActionRequest,
-    PayoutCreateRequest, PayoutCreateResponse, PayoutListConstraints, PayoutListFilterConstraints,
-    PayoutListFilters, PayoutListResponse, PayoutMethodData, PayoutRequest, PayoutRetrieveBody,
-    PayoutRetrieveRequest, PixBankTransfer, SepaBankTransfer, Wallet as WalletPayout,
+    AchBankTransfer, BacsBankTransfer, Bank as BankPayout, CardPayout, CurrencyConversionAuditLog,
+    NetworkTokenPayout, PayoutActionRequest, PayoutAdminRetrieveResponse, PayoutApproveRequest,
+    PayoutBatchCreateRequest, PayoutBatchCreateResponse, PayoutBatchItemError,
+    PayoutBatchItemResponse, PayoutBulkRetrieveRequest, PayoutBulkRetrieveResponse,
+    PayoutConnectorDetails, PayoutConnectorUsage, PayoutConnectorUsageResponse,
+    PayoutCreateRequest, PayoutCreateResponse, PayoutCurrencyConversionAuditLogResponse,
+    PayoutDestinationVerificationConfirmRequest, PayoutDestinationVerificationId,
+    PayoutDestinationVerificationRequest, PayoutDestinationVerificationResponse, PayoutExportJobId,
+    PayoutExportJobResponse, PayoutExportJobStatus, PayoutHoldBalance, PayoutHoldBalanceResponse,
+    PayoutHoldCaptureRequest, PayoutHoldCreateRequest, PayoutHoldId, PayoutHoldResponse,
+    PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
+    PayoutMethodData, PayoutRecallRequest, PayoutReportingAmount, PayoutReprocessRequest,
+    PayoutRequest, PayoutRetrieveBody, PayoutRetrieveByConnectorReferenceRequest,
+    PayoutRetrieveRequest, PayoutSeriesCancelRequest, PayoutSeriesCancelResponse,
+    PayoutTemplateCreateRequest, PayoutTemplateId, PayoutTemplateListResponse,
+    PayoutTemplateResponse, PayoutVerificationRequest, PixBankTransfer, SepaBankTransfer,
+    StoreCredit as StoreCreditPayout, Wallet as WalletPayout,
 };
 pub use hyperswitch_domain_models::router_flow_types::payouts::{
     PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient, PoRecipientAccount,
@@ -9,6 +9,7 @@ pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod currency_conversion_audit_log;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -30,11 +31,15 @@ pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_destination_verification;
+pub mod payout_hold;
+pub mod payout_template;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod store_credit_ledger_entry;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
@@ -60,11 +65,13 @@ pub use scheduler::db::process_tracker;
 pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, user::*, user_authentication_method::*, user_role::*,
+    configs::*, currency_conversion_audit_log::*, customers::*, dashboard_metadata::*, dispute::*,
+    ephemeral_key::*, events::*, file::*, fraud_check::*, generic_link::*, gsm::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_link::*, payment_method::*, payout_destination_verification::*,
+    payout_hold::*, payout_template::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, store_credit_ledger_entry::*, user::*, user_authentication_method::*,
+    user_role::*,
 };
 use crate::types::api::routing;
 
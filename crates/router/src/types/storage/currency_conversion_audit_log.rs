@@ -0,0 +1,3 @@
+pub use diesel_models::currency_conversion_audit_log::{
+    CurrencyConversionAuditLog, CurrencyConversionAuditLogNew,
+};
@@ -0,0 +1,4 @@
+pub use diesel_models::payout_destination_verification::{
+    PayoutDestinationVerification, PayoutDestinationVerificationNew,
+    PayoutDestinationVerificationUpdate, PayoutDestinationVerificationUpdateInternal,
+};
@@ -0,0 +1,3 @@
+pub use diesel_models::payout_hold::{
+    PayoutHold, PayoutHoldNew, PayoutHoldUpdate, PayoutHoldUpdateInternal,
+};
@@ -1 +1,30 @@
+use api_models::{currency::ForexProvider, enums::PayoutConversionRounding};
+use common_utils::types::MinorUnit;
 pub use diesel_models::payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal};
+use time::PrimitiveDateTime;
+
+/// Conversion of the amount of a cross-currency payout from its source currency into its
+/// destination currency, stored against the payout as it was applied.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PayoutCurrencyConversion {
+    /// The amount passed for the payout, in the minor unit of the source currency
+    pub source_amount: MinorUnit,
+    /// Rate at which one unit of the source currency was converted
+    pub exchange_rate: String,
+    /// Source of the forex rates used for the conversion, not known for rates cached before it
+    /// was tracked
+    pub exchange_rate_source: Option<ForexProvider>,
+    /// Time at which the rates used for the conversion were last updated, in UTC
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub exchange_rate_timestamp: PrimitiveDateTime,
+    /// The converted amount before it was rounded to the minor unit of the destination currency,
+    /// in the major unit
+    pub converted_amount: String,
+    /// How the converted amount was brought to the minor unit of the destination currency
+    #[serde(default)]
+    pub rounding: PayoutConversionRounding,
+    /// The converted amount less the amount paid, in the major unit of the destination currency,
+    /// not known for conversions applied before it was tracked
+    #[serde(default)]
+    pub residual_amount: Option<String>,
+}
@@ -0,0 +1,3 @@
+pub use diesel_models::store_credit_ledger_entry::{
+    StoreCreditLedgerEntry, StoreCreditLedgerEntryNew,
+};
@@ -577,13 +577,17 @@ impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventTyp
             }
             storage_enums::PayoutStatus::Expired => Some(storage_enums::EventType::PayoutExpired),
             storage_enums::PayoutStatus::Reversed => Some(storage_enums::EventType::PayoutReversed),
+            storage_enums::PayoutStatus::Recalled => Some(storage_enums::EventType::PayoutRecalled),
             storage_enums::PayoutStatus::Ineligible
             | storage_enums::PayoutStatus::Pending
             | storage_enums::PayoutStatus::RequiresCreation
             | storage_enums::PayoutStatus::RequiresFulfillment
             | storage_enums::PayoutStatus::RequiresPayoutMethodData
             | storage_enums::PayoutStatus::RequiresVendorAccountCreation
-            | storage_enums::PayoutStatus::RequiresConfirmation => None,
+            | storage_enums::PayoutStatus::RequiresConfirmation
+            | storage_enums::PayoutStatus::Scheduled
+            | storage_enums::PayoutStatus::PendingApproval
+            | storage_enums::PayoutStatus::PendingVerification => None,
         }
     }
 }
@@ -643,6 +647,7 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
             api_models::webhooks::IncomingWebhookEvent::PayoutCreated => Ok(Self::Initiated),
             api_models::webhooks::IncomingWebhookEvent::PayoutExpired => Ok(Self::Expired),
             api_models::webhooks::IncomingWebhookEvent::PayoutReversed => Ok(Self::Reversed),
+            api_models::webhooks::IncomingWebhookEvent::PayoutRecalled => Ok(Self::Recalled),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
                 field_name: "incoming_webhook_event_type",
             }),
@@ -1018,12 +1023,21 @@ impl ForeignFrom<storage::Capture> for payments::CaptureResponse {
 }
 
 #[cfg(feature = "payouts")]
-impl ForeignFrom<api_models::payouts::PayoutMethodData> for api_enums::PaymentMethodType {
+impl ForeignFrom<api_models::payouts::PayoutMethodData> for Option<api_enums::PaymentMethodType> {
     fn foreign_from(value: api_models::payouts::PayoutMethodData) -> Self {
         match value {
-            api_models::payouts::PayoutMethodData::Bank(bank) => Self::foreign_from(bank),
-            api_models::payouts::PayoutMethodData::Card(_) => Self::Debit,
-            api_models::payouts::PayoutMethodData::Wallet(wallet) => Self::foreign_from(wallet),
+            api_models::payouts::PayoutMethodData::Bank(bank) => {
+                Some(api_enums::PaymentMethodType::foreign_from(bank))
+            }
+            api_models::payouts::PayoutMethodData::Card(_)
+            | api_models::payouts::PayoutMethodData::NetworkToken(_) => {
+                Some(api_enums::PaymentMethodType::Debit)
+            }
+            api_models::payouts::PayoutMethodData::Wallet(wallet) => {
+                Some(api_enums::PaymentMethodType::foreign_from(wallet))
+            }
+            // Store credit is settled in the merchant's ledger and has no payment method type
+            api_models::payouts::PayoutMethodData::StoreCredit(_) => None,
         }
     }
 }
@@ -1055,8 +1069,10 @@ impl ForeignFrom<api_models::payouts::PayoutMethodData> for api_enums::PaymentMe
     fn foreign_from(value: api_models::payouts::PayoutMethodData) -> Self {
         match value {
             api_models::payouts::PayoutMethodData::Bank(_) => Self::BankTransfer,
-            api_models::payouts::PayoutMethodData::Card(_) => Self::Card,
+            api_models::payouts::PayoutMethodData::Card(_)
+            | api_models::payouts::PayoutMethodData::NetworkToken(_) => Self::Card,
             api_models::payouts::PayoutMethodData::Wallet(_) => Self::Wallet,
+            api_models::payouts::PayoutMethodData::StoreCredit(_) => Self::GiftCard,
         }
     }
 }
@@ -1068,6 +1084,7 @@ impl ForeignFrom<api_models::enums::PayoutType> for api_enums::PaymentMethod {
             api_models::enums::PayoutType::Bank => Self::BankTransfer,
             api_models::enums::PayoutType::Card => Self::Card,
             api_models::enums::PayoutType::Wallet => Self::Wallet,
+            api_models::enums::PayoutType::StoreCredit => Self::GiftCard,
         }
     }
 }
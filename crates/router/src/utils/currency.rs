@@ -1,24 +1,47 @@
-use std::{collections::HashMap, ops::Deref, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Deref,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 
-use api_models::enums;
-use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
-use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use api_models::{currency::ForexProvider, enums};
+use common_utils::{
+    date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt, types::MinorUnit,
+};
+use currency_conversion::types::{CurrencyFactors, ExchangeRates, ForexRate};
 use error_stack::ResultExt;
 use masking::PeekInterface;
 use once_cell::sync::Lazy;
 use redis_interface::DelReply;
 use rust_decimal::Decimal;
 use strum::IntoEnumIterator;
-use tokio::{sync::RwLock, time::sleep};
+use tokio::{
+    sync::{Mutex, RwLock},
+    time::sleep,
+};
 
 use crate::{
     logger,
-    routes::app::settings::{Conversion, DefaultExchangeRates},
-    services, SessionState,
+    routes::app::settings::{Conversion, CryptoForexApi, DefaultExchangeRates},
+    services,
+    types::storage,
+    SessionState,
 };
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
 const REDIX_FOREX_CACHE_DATA: &str = "{forex_cache}_data";
+const REDIX_CRYPTO_RATES_CACHE_DATA: &str = "{forex_cache}_crypto_data";
+const REDIX_CRYPTO_RATES_CACHE_KEY: &str = "{forex_cache}_crypto_lock";
 const FOREX_API_TIMEOUT: u64 = 5;
+/// in seconds, the crypto rates lock outlives the timeout of the request made to the crypto rate
+/// source
+const CRYPTO_RATES_LOCK_TIMEOUT: i64 = 10;
+/// in seconds, the rate of a currency which is missing from the fetched rates is retained from the
+/// previous rates only until it is this old, conversions involving the currency failing after
+const MAX_RETAINED_RATE_AGE: i64 = 24 * 60 * 60;
+/// prefix of the config key under which the last rates fetched on a date, in UTC, are stored
+const FOREX_RATES_SNAPSHOT_KEY_PREFIX: &str = "forex_rates_snapshot";
 const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
 const FOREX_BASE_CURRENCY: &str = "&base=USD";
 const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
@@ -28,6 +51,14 @@ const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
 pub struct FxExchangeRatesCacheEntry {
     data: Arc<ExchangeRates>,
     timestamp: i64,
+    #[serde(default)]
+    provider: Option<ForexProvider>,
+    /// UNIX timestamp at which the rate of each currency was last updated. The rate of a currency
+    /// which was not received in a fetch is retained from the previous rates along with its
+    /// timestamp until it is [`MAX_RETAINED_RATE_AGE`] old, and currencies without a timestamp
+    /// were updated at `timestamp`
+    #[serde(default)]
+    currency_timestamps: HashMap<enums::Currency, i64>,
 }
 
 static FX_EXCHANGE_RATES_CACHE: Lazy<RwLock<Option<FxExchangeRatesCacheEntry>>> =
@@ -35,6 +66,50 @@ static FX_EXCHANGE_RATES_CACHE: Lazy<RwLock<Option<FxExchangeRatesCacheEntry>>>
 
 impl ApiEventMetric for FxExchangeRatesCacheEntry {}
 
+/// Rates of the supported crypto assets, cached separately from the fiat rates as they expire
+/// much sooner.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CryptoRatesCacheEntry {
+    /// Units of each crypto asset per USD, keyed by the upper case code of the asset
+    rates: Arc<HashMap<String, Decimal>>,
+    timestamp: i64,
+}
+
+static CRYPTO_RATES_CACHE: Lazy<RwLock<Option<CryptoRatesCacheEntry>>> =
+    Lazy::new(|| RwLock::new(None));
+
+/// Held while the crypto rates are refreshed, so that concurrent conversions in this process wait
+/// for a single refresh instead of each fetching the rates
+static CRYPTO_RATES_REFRESH_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Rates of the providers chosen explicitly in conversions, kept apart from the default rates
+static PROVIDER_FX_EXCHANGE_RATES_CACHE: Lazy<
+    RwLock<HashMap<ForexProvider, FxExchangeRatesCacheEntry>>,
+> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Either side of a conversion, a fiat currency or one of the configured crypto assets
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionAsset {
+    Fiat(enums::Currency),
+    Crypto { code: String, minor_units: u8 },
+}
+
+/// Forex rates along with the conversion of an amount, when one was requested.
+///
+/// A failed conversion does not fail the whole request, the rates are still returned with the
+/// reason for the failure in `conversion_error`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ForexRetrieveResponse {
+    #[serde(flatten)]
+    pub rates: FxExchangeRatesCacheEntry,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversion: Option<api_models::currency::CurrencyConversionResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversion_error: Option<String>,
+}
+
+impl ApiEventMetric for ForexRetrieveResponse {}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum ForexCacheError {
     #[error("API error")]
@@ -49,6 +124,16 @@ pub enum ForexCacheError {
     CouldNotAcquireLock,
     #[error("Provided currency not acceptable")]
     CurrencyNotAcceptable,
+    #[error("Forex rates are not loaded for {0} as it is not a configured forex currency")]
+    CurrencyNotConfigured(enums::Currency),
+    #[error("Forex rate is not available for {0}")]
+    RateNotAvailable(enums::Currency),
+    #[error("{0} is not a supported currency or crypto asset")]
+    UnsupportedAsset(String),
+    #[error("Rates are not available for the crypto asset {0}")]
+    CryptoRateNotAvailable(String),
+    #[error("Forex provider not configured")]
+    ProviderNotConfigured,
     #[error("Incorrect entries in default Currency response")]
     DefaultCurrencyParsingError,
     #[error("Entry not found in cache")]
@@ -71,6 +156,10 @@ pub enum ForexCacheError {
     RedisWriteError,
     #[error("Not able to acquire write lock")]
     WriteLockNotAcquired,
+    #[error("Error reading the forex rates snapshot")]
+    SnapshotReadError,
+    #[error("Error writing the forex rates snapshot")]
+    SnapshotWriteError,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -95,9 +184,75 @@ impl Deref for FloatDecimal {
 }
 
 impl FxExchangeRatesCacheEntry {
-    fn new(exchange_rate: ExchangeRates) -> Self {
+    fn new(exchange_rate: ExchangeRates, provider: Option<ForexProvider>) -> Self {
+        let timestamp = date_time::now_unix_timestamp();
         Self {
+            currency_timestamps: exchange_rate
+                .conversion
+                .keys()
+                .map(|currency| (*currency, timestamp))
+                .collect(),
             data: Arc::new(exchange_rate),
+            timestamp,
+            provider,
+        }
+    }
+    fn is_expired(&self, call_delay: i64) -> bool {
+        self.timestamp + call_delay < date_time::now_unix_timestamp()
+    }
+
+    /// Returns the UNIX timestamp at which the rate of the currency was last updated
+    pub fn get_currency_timestamp(&self, currency: enums::Currency) -> i64 {
+        self.currency_timestamps
+            .get(&currency)
+            .copied()
+            .unwrap_or(self.timestamp)
+    }
+
+    /// Retains the timestamps of the rates from the entry they were copied from, such as the
+    /// entry cached in redis
+    fn with_currency_timestamps_from(mut self, source: Option<&Self>) -> Self {
+        let Some(source) = source else {
+            return self;
+        };
+        self.currency_timestamps = self
+            .data
+            .conversion
+            .keys()
+            .map(|currency| (*currency, source.get_currency_timestamp(*currency)))
+            .collect();
+        self
+    }
+
+    /// Retains the rates of the currencies which were not received in the latest fetch from the
+    /// previous rates, along with the timestamps at which they were updated. Rates older than
+    /// [`MAX_RETAINED_RATE_AGE`] are dropped instead of being retained.
+    fn with_missing_rates_from(mut self, previous: Option<&Self>) -> Self {
+        let Some(previous) = previous else {
+            return self;
+        };
+        let mut exchange_rates = self.data.as_ref().clone();
+        for (currency, currency_factors) in &previous.data.conversion {
+            if !exchange_rates.conversion.contains_key(currency)
+                && previous.get_currency_timestamp(*currency) + MAX_RETAINED_RATE_AGE
+                    >= self.timestamp
+            {
+                exchange_rates
+                    .conversion
+                    .insert(*currency, currency_factors.clone());
+                self.currency_timestamps
+                    .insert(*currency, previous.get_currency_timestamp(*currency));
+            }
+        }
+        self.data = Arc::new(exchange_rates);
+        self
+    }
+}
+
+impl CryptoRatesCacheEntry {
+    fn new(rates: HashMap<String, Decimal>) -> Self {
+        Self {
+            rates: Arc::new(rates),
             timestamp: date_time::now_unix_timestamp(),
         }
     }
@@ -106,6 +261,59 @@ impl FxExchangeRatesCacheEntry {
     }
 }
 
+impl ConversionAsset {
+    pub fn is_crypto(&self) -> bool {
+        matches!(self, Self::Crypto { .. })
+    }
+
+    pub fn code(&self) -> String {
+        match self {
+            Self::Fiat(currency) => currency.to_string(),
+            Self::Crypto { code, .. } => code.clone(),
+        }
+    }
+
+    pub fn minor_units(&self) -> u8 {
+        match self {
+            Self::Fiat(currency) => currency.number_of_digits_after_decimal_point(),
+            Self::Crypto { minor_units, .. } => *minor_units,
+        }
+    }
+}
+
+/// Resolves the code of a conversion side to one of the configured crypto assets, or else to a
+/// fiat currency.
+pub fn get_conversion_asset(
+    state: &SessionState,
+    code: &str,
+) -> CustomResult<ConversionAsset, ForexCacheError> {
+    let crypto_asset = state
+        .conf
+        .forex_api
+        .get_inner()
+        .crypto
+        .as_ref()
+        .and_then(|crypto_api| find_crypto_asset(&crypto_api.assets, code));
+    match crypto_asset {
+        Some(crypto_asset) => Ok(crypto_asset),
+        None => enums::Currency::from_str(code)
+            .map(ConversionAsset::Fiat)
+            .change_context(ForexCacheError::UnsupportedAsset(code.to_string())),
+    }
+}
+
+/// Crypto assets are matched irrespective of the case of their code, and are always identified
+/// by the upper case code
+fn find_crypto_asset(assets: &HashMap<String, u8>, code: &str) -> Option<ConversionAsset> {
+    assets
+        .iter()
+        .find(|(asset, _)| asset.eq_ignore_ascii_case(code))
+        .map(|(asset, minor_units)| ConversionAsset::Crypto {
+            code: asset.to_uppercase(),
+            minor_units: *minor_units,
+        })
+}
+
 async fn retrieve_forex_from_local() -> Option<FxExchangeRatesCacheEntry> {
     FX_EXCHANGE_RATES_CACHE.read().await.clone()
 }
@@ -165,8 +373,8 @@ impl TryFrom<DefaultExchangeRates> for ExchangeRates {
 impl From<Conversion> for CurrencyFactors {
     fn from(value: Conversion) -> Self {
         Self {
-            to_factor: value.to_factor,
-            from_factor: value.from_factor,
+            to_factor: ForexRate::from(value.to_factor),
+            from_factor: ForexRate::from(value.from_factor),
         }
     }
 }
@@ -196,6 +404,26 @@ pub async fn get_forex_rates(
     }
 }
 
+/// Checks whether the forex rates can be served from the local or redis cache, without fetching
+/// them from the provider.
+pub async fn has_fresh_forex_rates(state: &SessionState, call_delay: i64) -> bool {
+    if retrieve_forex_from_local()
+        .await
+        .is_some_and(|local_rates| !local_rates.is_expired(call_delay))
+    {
+        return true;
+    }
+    match retrieve_forex_from_redis(state).await {
+        Ok(redis_data) => is_redis_expired(redis_data.as_ref(), call_delay)
+            .await
+            .is_some(),
+        Err(err) => {
+            logger::error!(?err);
+            false
+        }
+    }
+}
+
 async fn handler_local_no_data(
     state: &SessionState,
     call_delay: i64,
@@ -226,7 +454,10 @@ async fn successive_fetch_and_save_forex(
             }
             let api_rates = fetch_forex_rates(state).await;
             match api_rates {
-                Ok(rates) => successive_save_data_to_redis_local(state, rates).await,
+                Ok(rates) => {
+                    let rates = rates.with_missing_rates_from(stale_redis_data.as_ref());
+                    successive_save_data_to_redis_local(state, rates).await
+                }
                 Err(err) => {
                     // API not able to fetch data call secondary service
                     logger::error!(?err);
@@ -258,6 +489,8 @@ async fn successive_save_data_to_redis_local(
         .await
         .async_and_then(|_val| async { Ok(save_forex_to_local(forex.clone()).await) })
         .await
+        .async_and_then(|_val| async { save_forex_snapshot(state, &forex).await })
+        .await
         .map_or_else(
             |e| {
                 logger::error!(?e);
@@ -275,7 +508,9 @@ async fn fallback_forex_redis_check(
     match is_redis_expired(Some(redis_data.clone()).as_ref(), call_delay).await {
         Some(redis_forex) => {
             // Valid data present in redis
-            let exchange_rates = FxExchangeRatesCacheEntry::new(redis_forex.as_ref().clone());
+            let exchange_rates =
+                FxExchangeRatesCacheEntry::new(redis_forex.as_ref().clone(), redis_data.provider)
+                    .with_currency_timestamps_from(Some(&redis_data));
             save_forex_to_local(exchange_rates.clone()).await?;
             Ok(exchange_rates)
         }
@@ -296,8 +531,11 @@ async fn handler_local_expired(
             match is_redis_expired(redis_data.as_ref(), call_delay).await {
                 Some(redis_forex) => {
                     // Valid data present in redis
-                    let exchange_rates =
-                        FxExchangeRatesCacheEntry::new(redis_forex.as_ref().clone());
+                    let exchange_rates = FxExchangeRatesCacheEntry::new(
+                        redis_forex.as_ref().clone(),
+                        redis_data.as_ref().and_then(|data| data.provider),
+                    )
+                    .with_currency_timestamps_from(redis_data.as_ref());
                     save_forex_to_local(exchange_rates.clone()).await?;
                     Ok(exchange_rates)
                 }
@@ -315,6 +553,41 @@ async fn handler_local_expired(
     }
 }
 
+/// Returns the currencies for which the rates are to be loaded, all currencies unless a subset is
+/// configured.
+fn get_forex_currencies(state: &SessionState) -> Vec<enums::Currency> {
+    match &state.conf.forex_api.get_inner().currencies {
+        Some(currencies) => currencies.iter().copied().collect(),
+        None => enums::Currency::iter().collect(),
+    }
+}
+
+/// Fails for currencies outside the configured forex currencies, as their rates are not loaded.
+/// The base currency is always available.
+fn validate_forex_currency(
+    configured_currencies: Option<&HashSet<enums::Currency>>,
+    currency: enums::Currency,
+) -> CustomResult<(), ForexCacheError> {
+    match configured_currencies {
+        Some(currencies) if currency != enums::Currency::USD && !currencies.contains(&currency) => {
+            Err(ForexCacheError::CurrencyNotConfigured(currency).into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Currencies which are known but were never received from the provider have no rate to convert at
+fn validate_forex_rate_available(
+    rates: &ExchangeRates,
+    currency: enums::Currency,
+) -> CustomResult<(), ForexCacheError> {
+    if currency == rates.base_currency || rates.conversion.contains_key(&currency) {
+        Ok(())
+    } else {
+        Err(ForexCacheError::RateNotAvailable(currency).into())
+    }
+}
+
 async fn fetch_forex_rates(
     state: &SessionState,
 ) -> Result<FxExchangeRatesCacheEntry, error_stack::Report<ForexCacheError>> {
@@ -345,33 +618,43 @@ async fn fetch_forex_rates(
     logger::info!("{:?}", forex_response);
 
     let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
+    for enum_curr in get_forex_currencies(state) {
         match forex_response.rates.get(&enum_curr.to_string()) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
+            Some(rate) => match CurrencyFactors::from_rate(ForexRate::from(**rate)) {
+                Ok(currency_factors) => {
+                    conversions.insert(enum_curr, currency_factors);
+                }
+                Err(error) => {
+                    logger::error!(?error, "Rates for {} not received from API", &enum_curr);
+                }
+            },
             None => {
                 logger::error!("Rates for {} not received from API", &enum_curr);
             }
         };
     }
 
-    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
-        enums::Currency::USD,
-        conversions,
-    )))
+    Ok(FxExchangeRatesCacheEntry::new(
+        ExchangeRates::new(enums::Currency::USD, conversions),
+        Some(ForexProvider::OpenExchangeRates),
+    ))
 }
 
 pub async fn fallback_fetch_forex_rates(
     state: &SessionState,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+    let rates = fetch_fallback_forex_rates(state).await?;
+    match acquire_redis_lock(state).await {
+        Ok(_) => Ok(successive_save_data_to_redis_local(state, rates).await?),
+        Err(e) => {
+            logger::error!(?e);
+            Ok(rates)
+        }
+    }
+}
+
+async fn fetch_fallback_forex_rates(
+    state: &SessionState,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
     let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
 
@@ -400,7 +683,7 @@ pub async fn fallback_fetch_forex_rates(
 
     logger::info!("{:?}", fallback_forex_response);
     let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
+    for enum_curr in get_forex_currencies(state) {
         match fallback_forex_response.quotes.get(
             format!(
                 "{}{}",
@@ -409,34 +692,337 @@ pub async fn fallback_fetch_forex_rates(
             )
             .as_str(),
         ) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
+            Some(rate) => match CurrencyFactors::from_rate(ForexRate::from(**rate)) {
+                Ok(currency_factors) => {
+                    conversions.insert(enum_curr, currency_factors);
+                }
+                Err(error) => {
+                    logger::error!(?error, "Rates for {} not received from API", &enum_curr);
+                }
+            },
             None => {
                 logger::error!("Rates for {} not received from API", &enum_curr);
             }
         };
     }
 
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-        Err(e) => {
-            logger::error!(?e);
-            Ok(rates)
+    Ok(FxExchangeRatesCacheEntry::new(
+        ExchangeRates::new(enums::Currency::USD, conversions),
+        Some(ForexProvider::Apilayer),
+    ))
+}
+
+/// Fetches the forex rates from the given provider, reusing the cached default rates when they
+/// were fetched from the same provider and have not expired.
+///
+/// Rates fetched this way are cached per provider, locally and in redis, for the call delay. The
+/// default rates cache is left untouched.
+pub async fn get_forex_rates_from_provider(
+    state: &SessionState,
+    provider: ForexProvider,
+    call_delay: i64,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+    if let Some(local_rates) = retrieve_forex_from_local()
+        .await
+        .filter(|rates| rates.provider == Some(provider) && !rates.is_expired(call_delay))
+    {
+        return Ok(local_rates);
+    }
+    if let Some(provider_rates) = PROVIDER_FX_EXCHANGE_RATES_CACHE
+        .read()
+        .await
+        .get(&provider)
+        .filter(|rates| !rates.is_expired(call_delay))
+    {
+        return Ok(provider_rates.clone());
+    }
+
+    match retrieve_provider_forex_from_redis(state, provider).await {
+        Ok(Some(redis_rates)) if !redis_rates.is_expired(call_delay) => {
+            PROVIDER_FX_EXCHANGE_RATES_CACHE
+                .write()
+                .await
+                .insert(provider, redis_rates.clone());
+            return Ok(redis_rates);
+        }
+        Ok(_) => {}
+        Err(error) => logger::error!(?error),
+    }
+
+    let rates = fetch_forex_rates_from_provider(state, provider).await?;
+    if let Err(error) = save_provider_forex_to_redis(state, provider, &rates, call_delay).await {
+        logger::error!(?error);
+    }
+    PROVIDER_FX_EXCHANGE_RATES_CACHE
+        .write()
+        .await
+        .insert(provider, rates.clone());
+    Ok(rates)
+}
+
+async fn fetch_forex_rates_from_provider(
+    state: &SessionState,
+    provider: ForexProvider,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+    let forex_api = state.conf.forex_api.get_inner();
+    match provider {
+        ForexProvider::OpenExchangeRates => {
+            if forex_api.api_key.peek().is_empty() {
+                return Err(ForexCacheError::ProviderNotConfigured.into());
+            }
+            fetch_forex_rates(state).await
+        }
+        ForexProvider::Apilayer => {
+            if forex_api.fallback_api_key.peek().is_empty() {
+                return Err(ForexCacheError::ProviderNotConfigured.into());
+            }
+            fetch_fallback_forex_rates(state).await
+        }
+    }
+}
+
+/// Returns the rates of the crypto assets, from the local or redis cache while they are within the
+/// crypto call delay and from the crypto rate source otherwise. Stale crypto rates are never
+/// served, given their volatility.
+///
+/// The rates are fetched by a single task across instances, holding the local refresh lock and
+/// the redis lock. Instances which do not acquire the redis lock wait for the refreshed rates to
+/// be saved in redis.
+pub async fn get_crypto_rates(
+    state: &SessionState,
+    crypto_api: &CryptoForexApi,
+) -> CustomResult<CryptoRatesCacheEntry, ForexCacheError> {
+    if let Some(local_rates) = retrieve_crypto_rates_from_local(crypto_api.call_delay).await {
+        return Ok(local_rates);
+    }
+
+    let _refresh_guard = CRYPTO_RATES_REFRESH_LOCK.lock().await;
+    // The rates may have been refreshed while waiting for the lock
+    if let Some(local_rates) = retrieve_crypto_rates_from_local(crypto_api.call_delay).await {
+        return Ok(local_rates);
+    }
+    if let Some(redis_rates) = retrieve_fresh_crypto_rates_from_redis(state, crypto_api).await {
+        return Ok(redis_rates);
+    }
+
+    match acquire_crypto_redis_lock(state).await {
+        Ok(true) => {
+            let rates = fetch_and_save_crypto_rates(state, crypto_api).await;
+            if let Err(error) = release_crypto_redis_lock(state).await {
+                logger::error!(?error);
+            }
+            rates
+        }
+        Ok(false) => wait_for_crypto_rates_in_redis(state, crypto_api).await,
+        Err(error) => {
+            // The refresh can not be coordinated with the other instances without redis
+            logger::error!(?error);
+            fetch_and_save_crypto_rates(state, crypto_api).await
         }
     }
 }
 
+async fn retrieve_crypto_rates_from_local(call_delay: i64) -> Option<CryptoRatesCacheEntry> {
+    CRYPTO_RATES_CACHE
+        .read()
+        .await
+        .clone()
+        .filter(|rates| !rates.is_expired(call_delay))
+}
+
+async fn retrieve_fresh_crypto_rates_from_redis(
+    state: &SessionState,
+    crypto_api: &CryptoForexApi,
+) -> Option<CryptoRatesCacheEntry> {
+    match retrieve_crypto_rates_from_redis(state).await {
+        Ok(Some(redis_rates)) if !redis_rates.is_expired(crypto_api.call_delay) => {
+            *CRYPTO_RATES_CACHE.write().await = Some(redis_rates.clone());
+            Some(redis_rates)
+        }
+        Ok(_) => None,
+        Err(error) => {
+            logger::error!(?error);
+            None
+        }
+    }
+}
+
+async fn fetch_and_save_crypto_rates(
+    state: &SessionState,
+    crypto_api: &CryptoForexApi,
+) -> CustomResult<CryptoRatesCacheEntry, ForexCacheError> {
+    let rates = fetch_crypto_rates(state, crypto_api).await?;
+    if let Err(error) = save_crypto_rates_to_redis(state, &rates, crypto_api.call_delay).await {
+        logger::error!(?error);
+    }
+    *CRYPTO_RATES_CACHE.write().await = Some(rates.clone());
+    Ok(rates)
+}
+
+/// Polls redis for the rates being refreshed by another instance, at the local fetch retry delay
+async fn wait_for_crypto_rates_in_redis(
+    state: &SessionState,
+    crypto_api: &CryptoForexApi,
+) -> CustomResult<CryptoRatesCacheEntry, ForexCacheError> {
+    let forex_api = state.conf.forex_api.get_inner();
+    for _ in 0..forex_api.local_fetch_retry_count {
+        sleep(Duration::from_millis(forex_api.local_fetch_retry_delay)).await;
+        if let Some(redis_rates) = retrieve_fresh_crypto_rates_from_redis(state, crypto_api).await {
+            return Ok(redis_rates);
+        }
+    }
+    Err(ForexCacheError::CouldNotAcquireLock.into())
+}
+
+async fn acquire_crypto_redis_lock(state: &SessionState) -> CustomResult<bool, ForexCacheError> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ForexCacheError::RedisConnectionError)?
+        .set_key_if_not_exists_with_expiry(
+            REDIX_CRYPTO_RATES_CACHE_KEY,
+            "",
+            Some(CRYPTO_RATES_LOCK_TIMEOUT),
+        )
+        .await
+        .map(|val| matches!(val, redis_interface::SetnxReply::KeySet))
+        .change_context(ForexCacheError::CouldNotAcquireLock)
+}
+
+async fn release_crypto_redis_lock(
+    state: &SessionState,
+) -> CustomResult<DelReply, ForexCacheError> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ForexCacheError::RedisConnectionError)?
+        .delete_key(REDIX_CRYPTO_RATES_CACHE_KEY)
+        .await
+        .change_context(ForexCacheError::RedisLockReleaseFailed)
+}
+
+async fn fetch_crypto_rates(
+    state: &SessionState,
+    crypto_api: &CryptoForexApi,
+) -> CustomResult<CryptoRatesCacheEntry, ForexCacheError> {
+    let crypto_url = format!("{}{}", crypto_api.base_url, crypto_api.api_key.peek());
+    let crypto_request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(&crypto_url)
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(
+            &state.clone(),
+            crypto_request,
+            Some(FOREX_API_TIMEOUT),
+            false,
+        )
+        .await
+        .change_context(ForexCacheError::ApiUnresponsive)?;
+    let crypto_response = response
+        .json::<ForexResponse>()
+        .await
+        .change_context(ForexCacheError::ParsingError)?;
+
+    logger::info!("{:?}", crypto_response);
+
+    Ok(CryptoRatesCacheEntry::new(get_crypto_rates_from_response(
+        &crypto_api.assets,
+        &crypto_response.rates,
+    )))
+}
+
+/// Picks the rates of the configured assets from the response, matching the codes irrespective
+/// of their case
+fn get_crypto_rates_from_response(
+    assets: &HashMap<String, u8>,
+    response_rates: &HashMap<String, FloatDecimal>,
+) -> HashMap<String, Decimal> {
+    let mut rates: HashMap<String, Decimal> = HashMap::new();
+    for asset in assets.keys() {
+        let rate = response_rates
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(asset))
+            .map(|(_, rate)| **rate);
+        match rate {
+            Some(rate) if !rate.is_zero() => {
+                rates.insert(asset.to_uppercase(), rate);
+            }
+            _ => {
+                logger::error!("Rates for {} not received from API", asset);
+            }
+        }
+    }
+    rates
+}
+
+async fn save_crypto_rates_to_redis(
+    state: &SessionState,
+    crypto_rates: &CryptoRatesCacheEntry,
+    call_delay: i64,
+) -> CustomResult<(), ForexCacheError> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ForexCacheError::RedisConnectionError)?
+        .serialize_and_set_key_with_expiry(REDIX_CRYPTO_RATES_CACHE_DATA, crypto_rates, call_delay)
+        .await
+        .change_context(ForexCacheError::RedisWriteError)
+}
+
+async fn retrieve_crypto_rates_from_redis(
+    state: &SessionState,
+) -> CustomResult<Option<CryptoRatesCacheEntry>, ForexCacheError> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ForexCacheError::RedisConnectionError)?
+        .get_and_deserialize_key(REDIX_CRYPTO_RATES_CACHE_DATA, "CryptoRatesCache")
+        .await
+        .change_context(ForexCacheError::EntryNotFound)
+}
+
+fn get_provider_forex_redis_key(provider: ForexProvider) -> String {
+    format!("{REDIX_FOREX_CACHE_DATA}_{provider}")
+}
+
+async fn save_provider_forex_to_redis(
+    state: &SessionState,
+    provider: ForexProvider,
+    rates: &FxExchangeRatesCacheEntry,
+    call_delay: i64,
+) -> CustomResult<(), ForexCacheError> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ForexCacheError::RedisConnectionError)?
+        .serialize_and_set_key_with_expiry(
+            &get_provider_forex_redis_key(provider),
+            rates,
+            call_delay,
+        )
+        .await
+        .change_context(ForexCacheError::RedisWriteError)
+}
+
+async fn retrieve_provider_forex_from_redis(
+    state: &SessionState,
+    provider: ForexProvider,
+) -> CustomResult<Option<FxExchangeRatesCacheEntry>, ForexCacheError> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ForexCacheError::RedisConnectionError)?
+        .get_and_deserialize_key(
+            &get_provider_forex_redis_key(provider),
+            "FxExchangeRatesCache",
+        )
+        .await
+        .change_context(ForexCacheError::EntryNotFound)
+}
+
 async fn release_redis_lock(
     state: &SessionState,
 ) -> Result<DelReply, error_stack::Report<ForexCacheError>> {
@@ -496,6 +1082,61 @@ async fn retrieve_forex_from_redis(
         .change_context(ForexCacheError::EntryNotFound)
 }
 
+fn get_forex_rates_snapshot_key(date: time::Date) -> String {
+    format!("{FOREX_RATES_SNAPSHOT_KEY_PREFIX}_{date}")
+}
+
+/// Stores the rates as the snapshot of the date on which they were fetched, replacing the rates
+/// fetched earlier on the same date.
+async fn save_forex_snapshot(
+    state: &SessionState,
+    forex: &FxExchangeRatesCacheEntry,
+) -> CustomResult<(), ForexCacheError> {
+    let date = time::OffsetDateTime::from_unix_timestamp(forex.timestamp)
+        .change_context(ForexCacheError::ConversionError)?
+        .date();
+    let key = get_forex_rates_snapshot_key(date);
+    let config = serde_json::to_string(forex).change_context(ForexCacheError::ParsingError)?;
+
+    match state
+        .store
+        .update_config_in_database(
+            &key,
+            storage::ConfigUpdate::Update {
+                config: Some(config.clone()),
+            },
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ())
+            .change_context(ForexCacheError::SnapshotWriteError),
+        Err(error) => Err(error).change_context(ForexCacheError::SnapshotWriteError),
+    }
+}
+
+/// Returns the last rates fetched on the given date, in UTC, if any were fetched on that date.
+pub async fn get_forex_rates_snapshot(
+    state: &SessionState,
+    date: time::Date,
+) -> CustomResult<Option<FxExchangeRatesCacheEntry>, ForexCacheError> {
+    match state
+        .store
+        .find_config_by_key_from_db(&get_forex_rates_snapshot_key(date))
+        .await
+    {
+        Ok(config) => serde_json::from_str(&config.config)
+            .map(Some)
+            .change_context(ForexCacheError::ParsingError),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error).change_context(ForexCacheError::SnapshotReadError),
+    }
+}
+
 async fn is_redis_expired(
     redis_cache: Option<&FxExchangeRatesCacheEntry>,
     call_delay: i64,
@@ -514,6 +1155,7 @@ pub async fn convert_currency(
     amount: i64,
     to_currency: String,
     from_currency: String,
+    rate_precision: u32,
 ) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexCacheError> {
     let forex_api = state.conf.forex_api.get_inner();
     let rates = get_forex_rates(
@@ -525,18 +1167,623 @@ pub async fn convert_currency(
     .await
     .change_context(ForexCacheError::ApiError)?;
 
+    convert_currency_using_rates(
+        &rates,
+        forex_api.currencies.as_ref(),
+        amount,
+        to_currency,
+        from_currency,
+        rate_precision,
+    )
+}
+
+/// Converts the amount using the given rates. The converted amount is computed using the
+/// unrounded rates, the returned rate is rounded to `rate_precision` decimal places for display.
+///
+/// When the forex currencies are configured, both currencies have to be among them.
+pub fn convert_currency_using_rates(
+    rates: &FxExchangeRatesCacheEntry,
+    configured_currencies: Option<&HashSet<enums::Currency>>,
+    amount: i64,
+    to_currency: String,
+    from_currency: String,
+    rate_precision: u32,
+) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexCacheError> {
     let to_currency = enums::Currency::from_str(to_currency.as_str())
         .change_context(ForexCacheError::CurrencyNotAcceptable)?;
 
     let from_currency = enums::Currency::from_str(from_currency.as_str())
         .change_context(ForexCacheError::CurrencyNotAcceptable)?;
 
+    validate_forex_currency(configured_currencies, from_currency)?;
+    validate_forex_currency(configured_currencies, to_currency)?;
+    validate_forex_rate_available(&rates.data, from_currency)?;
+    validate_forex_rate_available(&rates.data, to_currency)?;
+
     let converted_amount =
         currency_conversion::conversion::convert(&rates.data, from_currency, to_currency, amount)
             .change_context(ForexCacheError::ConversionError)?;
+    let rate =
+        currency_conversion::conversion::exchange_rate(&rates.data, from_currency, to_currency)
+            .change_context(ForexCacheError::ConversionError)?;
+    let rate_path = get_fiat_rate_path(&rates.data, from_currency, to_currency, rate_precision)?;
 
     Ok(api_models::currency::CurrencyConversionResponse {
+        amount: MinorUnit::new(amount),
+        from_currency: from_currency.to_string(),
+        to_currency: to_currency.to_string(),
         converted_amount: converted_amount.to_string(),
         currency: to_currency.to_string(),
+        rate: rate.round_dp(rate_precision).to_string(),
+        provider: rates.provider,
+        formatted_amount: None,
+        formatted_converted_amount: None,
+        base_rate: None,
+        markup_bps: None,
+        rates_timestamp: rates
+            .get_currency_timestamp(from_currency)
+            .min(rates.get_currency_timestamp(to_currency)),
+        rate_path,
+    })
+}
+
+/// Returns the legs of the conversion when it is triangulated through the base currency of the
+/// rates, which is when neither currency is the base currency.
+fn get_fiat_rate_path(
+    rates: &ExchangeRates,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+    rate_precision: u32,
+) -> CustomResult<Option<api_models::currency::CurrencyConversionRatePath>, ForexCacheError> {
+    let pivot_currency = rates.base_currency;
+    if from_currency == pivot_currency || to_currency == pivot_currency {
+        return Ok(None);
+    }
+    let legs = [
+        (from_currency, pivot_currency),
+        (pivot_currency, to_currency),
+    ]
+    .into_iter()
+    .map(|(leg_from, leg_to)| {
+        currency_conversion::conversion::exchange_rate(rates, leg_from, leg_to)
+            .change_context(ForexCacheError::ConversionError)
+            .map(|rate| api_models::currency::CurrencyConversionLeg {
+                from_currency: leg_from.to_string(),
+                to_currency: leg_to.to_string(),
+                rate: rate.round_dp(rate_precision).to_string(),
+            })
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+    Ok(Some(api_models::currency::CurrencyConversionRatePath {
+        pivot_currency: pivot_currency.to_string(),
+        legs,
+    }))
+}
+
+/// Converts the amount when either side is a crypto asset, using the crypto rates. The fiat rates
+/// are needed only when the other side is a fiat currency other than the base currency.
+pub async fn convert_crypto_currency(
+    state: &SessionState,
+    amount: i64,
+    to_asset: &ConversionAsset,
+    from_asset: &ConversionAsset,
+    rate_precision: u32,
+) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexCacheError> {
+    let forex_api = state.conf.forex_api.get_inner();
+    let crypto_api = forex_api
+        .crypto
+        .as_ref()
+        .ok_or(ForexCacheError::ProviderNotConfigured)?;
+
+    let mut needs_fiat_rates = false;
+    for asset in [from_asset, to_asset] {
+        if let ConversionAsset::Fiat(currency) = asset {
+            validate_forex_currency(forex_api.currencies.as_ref(), *currency)?;
+            needs_fiat_rates |= *currency != enums::Currency::USD;
+        }
+    }
+
+    let crypto_rates = get_crypto_rates(state, crypto_api).await?;
+    let fiat_rates = if needs_fiat_rates {
+        Some(
+            get_forex_rates(
+                state,
+                forex_api.call_delay,
+                forex_api.local_fetch_retry_delay,
+                forex_api.local_fetch_retry_count,
+            )
+            .await
+            .change_context(ForexCacheError::ApiError)?,
+        )
+    } else {
+        None
+    };
+
+    convert_crypto_currency_using_rates(
+        &crypto_rates,
+        fiat_rates.as_ref(),
+        amount,
+        to_asset,
+        from_asset,
+        rate_precision,
+    )
+}
+
+/// Converts the amount using the crypto rates, along with the fiat rates when the other side is a
+/// fiat currency other than the base currency.
+fn convert_crypto_currency_using_rates(
+    crypto_rates: &CryptoRatesCacheEntry,
+    fiat_rates: Option<&FxExchangeRatesCacheEntry>,
+    amount: i64,
+    to_asset: &ConversionAsset,
+    from_asset: &ConversionAsset,
+    rate_precision: u32,
+) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexCacheError> {
+    let from_rate = get_units_per_usd(crypto_rates, fiat_rates, from_asset)?;
+    let to_rate = get_units_per_usd(crypto_rates, fiat_rates, to_asset)?;
+    let rate = to_rate
+        .checked_div(from_rate)
+        .ok_or(ForexCacheError::ConversionError)?;
+    let converted_amount = Decimal::new(amount, u32::from(from_asset.minor_units()))
+        .checked_mul(rate)
+        .ok_or(ForexCacheError::ConversionError)?;
+
+    let rates_timestamp = [from_asset, to_asset]
+        .into_iter()
+        .filter_map(|asset| match (asset, fiat_rates) {
+            (ConversionAsset::Fiat(currency), Some(fiat_rates)) => {
+                Some(fiat_rates.get_currency_timestamp(*currency))
+            }
+            _ => None,
+        })
+        .fold(crypto_rates.timestamp, i64::min);
+
+    // The crypto rates are quoted against USD, through which the conversion is triangulated
+    // unless either side is USD
+    let mut path = get_crypto_conversion_path_to_usd(from_asset, fiat_rates);
+    path.extend(
+        get_crypto_conversion_path_to_usd(to_asset, fiat_rates)
+            .into_iter()
+            .rev()
+            .skip(1),
+    );
+    let rate_path = (path.len() > 2)
+        .then(|| {
+            path.windows(2)
+                .map(|leg| match leg {
+                    [leg_from, leg_to] => {
+                        get_crypto_conversion_leg_rate(crypto_rates, fiat_rates, leg_from, leg_to)
+                            .map(|rate| api_models::currency::CurrencyConversionLeg {
+                                from_currency: leg_from.code(),
+                                to_currency: leg_to.code(),
+                                rate: rate.round_dp(rate_precision).to_string(),
+                            })
+                    }
+                    _ => Err(ForexCacheError::ConversionError.into()),
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|legs| api_models::currency::CurrencyConversionRatePath {
+                    pivot_currency: enums::Currency::USD.to_string(),
+                    legs,
+                })
+        })
+        .transpose()?;
+
+    Ok(api_models::currency::CurrencyConversionResponse {
+        amount: MinorUnit::new(amount),
+        from_currency: from_asset.code(),
+        to_currency: to_asset.code(),
+        converted_amount: converted_amount.to_string(),
+        currency: to_asset.code(),
+        rate: rate.round_dp(rate_precision).to_string(),
+        provider: None,
+        formatted_amount: None,
+        formatted_converted_amount: None,
+        base_rate: None,
+        markup_bps: None,
+        rates_timestamp,
+        rate_path,
     })
 }
+
+/// Returns the assets through which an asset is converted to USD, starting with the asset itself.
+/// Fiat currencies are converted through the base currency of the fiat rates when it is not USD.
+fn get_crypto_conversion_path_to_usd(
+    asset: &ConversionAsset,
+    fiat_rates: Option<&FxExchangeRatesCacheEntry>,
+) -> Vec<ConversionAsset> {
+    let usd = ConversionAsset::Fiat(enums::Currency::USD);
+    match asset {
+        ConversionAsset::Fiat(enums::Currency::USD) => vec![usd],
+        ConversionAsset::Fiat(currency) => match fiat_rates
+            .map(|fiat_rates| fiat_rates.data.base_currency)
+            .filter(|base_currency| {
+                base_currency != currency && *base_currency != enums::Currency::USD
+            }) {
+            Some(base_currency) => vec![asset.clone(), ConversionAsset::Fiat(base_currency), usd],
+            None => vec![asset.clone(), usd],
+        },
+        ConversionAsset::Crypto { .. } => vec![asset.clone(), usd],
+    }
+}
+
+/// Returns the rate of a leg of a crypto conversion. Legs between fiat currencies use the fiat
+/// rates, while the other legs are between USD and a crypto asset.
+fn get_crypto_conversion_leg_rate(
+    crypto_rates: &CryptoRatesCacheEntry,
+    fiat_rates: Option<&FxExchangeRatesCacheEntry>,
+    leg_from: &ConversionAsset,
+    leg_to: &ConversionAsset,
+) -> CustomResult<Decimal, ForexCacheError> {
+    match (leg_from, leg_to, fiat_rates) {
+        (ConversionAsset::Fiat(from_currency), ConversionAsset::Fiat(to_currency), Some(rates)) => {
+            currency_conversion::conversion::exchange_rate(
+                &rates.data,
+                *from_currency,
+                *to_currency,
+            )
+            .change_context(ForexCacheError::ConversionError)
+        }
+        _ => get_units_per_usd(crypto_rates, fiat_rates, leg_to)?
+            .checked_div(get_units_per_usd(crypto_rates, fiat_rates, leg_from)?)
+            .ok_or(ForexCacheError::ConversionError.into()),
+    }
+}
+
+/// Returns the units of the asset which one USD is worth
+fn get_units_per_usd(
+    crypto_rates: &CryptoRatesCacheEntry,
+    fiat_rates: Option<&FxExchangeRatesCacheEntry>,
+    asset: &ConversionAsset,
+) -> CustomResult<Decimal, ForexCacheError> {
+    match asset {
+        ConversionAsset::Crypto { code, .. } => crypto_rates
+            .rates
+            .get(code)
+            .copied()
+            .ok_or(ForexCacheError::CryptoRateNotAvailable(code.clone()).into()),
+        ConversionAsset::Fiat(enums::Currency::USD) => Ok(Decimal::ONE),
+        ConversionAsset::Fiat(currency) => currency_conversion::conversion::exchange_rate(
+            &fiat_rates.ok_or(ForexCacheError::ConversionError)?.data,
+            enums::Currency::USD,
+            *currency,
+        )
+        .change_context(ForexCacheError::ConversionError),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_convert_currency_using_rates_without_rate_for_currency() {
+        let rates = FxExchangeRatesCacheEntry::new(
+            ExchangeRates::new(
+                enums::Currency::USD,
+                HashMap::from([(
+                    enums::Currency::EUR,
+                    CurrencyFactors::new(Decimal::new(9, 1), Decimal::new(1_111_111, 6)),
+                )]),
+            ),
+            None,
+        );
+
+        let conversion = convert_currency_using_rates(
+            &rates,
+            None,
+            10000,
+            "EUR".to_string(),
+            "USD".to_string(),
+            6,
+        )
+        .unwrap();
+        assert_eq!(conversion.to_currency, "EUR");
+
+        let error = convert_currency_using_rates(
+            &rates,
+            None,
+            10000,
+            "EUR".to_string(),
+            "JPY".to_string(),
+            6,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            ForexCacheError::RateNotAvailable(enums::Currency::JPY)
+        ));
+    }
+
+    #[test]
+    fn test_with_missing_rates_from_drops_old_rates() {
+        let currency_factors =
+            || CurrencyFactors::new(Decimal::new(9, 1), Decimal::new(1_111_111, 6));
+        let mut previous = FxExchangeRatesCacheEntry::new(
+            ExchangeRates::new(
+                enums::Currency::USD,
+                HashMap::from([
+                    (enums::Currency::EUR, currency_factors()),
+                    (enums::Currency::GBP, currency_factors()),
+                    (enums::Currency::JPY, currency_factors()),
+                ]),
+            ),
+            None,
+        );
+        let now = previous.timestamp;
+        previous
+            .currency_timestamps
+            .insert(enums::Currency::GBP, now - MAX_RETAINED_RATE_AGE);
+        previous
+            .currency_timestamps
+            .insert(enums::Currency::JPY, now - MAX_RETAINED_RATE_AGE - 1);
+
+        let mut rates = FxExchangeRatesCacheEntry::new(
+            ExchangeRates::new(
+                enums::Currency::USD,
+                HashMap::from([(enums::Currency::EUR, currency_factors())]),
+            ),
+            None,
+        );
+        rates.timestamp = now;
+        let rates = rates.with_missing_rates_from(Some(&previous));
+
+        assert!(rates.data.conversion.contains_key(&enums::Currency::EUR));
+        assert!(rates.data.conversion.contains_key(&enums::Currency::GBP));
+        assert_eq!(
+            rates.get_currency_timestamp(enums::Currency::GBP),
+            now - MAX_RETAINED_RATE_AGE
+        );
+        assert!(!rates.data.conversion.contains_key(&enums::Currency::JPY));
+        assert!(matches!(
+            convert_currency_using_rates(
+                &rates,
+                None,
+                10000,
+                "JPY".to_string(),
+                "USD".to_string(),
+                6,
+            )
+            .unwrap_err()
+            .current_context(),
+            ForexCacheError::RateNotAvailable(enums::Currency::JPY)
+        ));
+    }
+
+    fn get_test_crypto_rates() -> CryptoRatesCacheEntry {
+        // 1 BTC = 50000 USD
+        CryptoRatesCacheEntry::new(HashMap::from([("BTC".to_string(), Decimal::new(2, 5))]))
+    }
+
+    fn get_test_btc_asset() -> ConversionAsset {
+        ConversionAsset::Crypto {
+            code: "BTC".to_string(),
+            minor_units: 8,
+        }
+    }
+
+    #[test]
+    fn test_find_crypto_asset_ignores_case() {
+        let assets = HashMap::from([("btc".to_string(), 8)]);
+
+        assert_eq!(
+            find_crypto_asset(&assets, "BTC"),
+            Some(get_test_btc_asset())
+        );
+        assert_eq!(
+            find_crypto_asset(&assets, "Btc"),
+            Some(get_test_btc_asset())
+        );
+        assert_eq!(find_crypto_asset(&assets, "ETH"), None);
+    }
+
+    #[test]
+    fn test_get_crypto_rates_from_response_ignores_case() {
+        let assets = HashMap::from([("btc".to_string(), 8), ("ETH".to_string(), 18)]);
+        let response_rates = HashMap::from([
+            ("BTC".to_string(), FloatDecimal(Decimal::new(2, 5))),
+            ("eth".to_string(), FloatDecimal(Decimal::ZERO)),
+        ]);
+
+        let rates = get_crypto_rates_from_response(&assets, &response_rates);
+        assert_eq!(
+            rates,
+            HashMap::from([("BTC".to_string(), Decimal::new(2, 5))])
+        );
+    }
+
+    #[test]
+    fn test_convert_crypto_currency_to_usd() {
+        let conversion = convert_crypto_currency_using_rates(
+            &get_test_crypto_rates(),
+            None,
+            100_000_000,
+            &ConversionAsset::Fiat(enums::Currency::USD),
+            &get_test_btc_asset(),
+            6,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Decimal::from_str(&conversion.converted_amount).unwrap(),
+            Decimal::new(50000, 0)
+        );
+        assert_eq!(
+            Decimal::from_str(&conversion.rate).unwrap(),
+            Decimal::new(50000, 0)
+        );
+        assert_eq!(conversion.currency, "USD");
+        assert!(conversion.rate_path.is_none());
+    }
+
+    #[test]
+    fn test_convert_crypto_currency_from_fiat_through_usd() {
+        let fiat_rates = FxExchangeRatesCacheEntry::new(
+            ExchangeRates::new(
+                enums::Currency::USD,
+                HashMap::from([(
+                    enums::Currency::EUR,
+                    CurrencyFactors::new(Decimal::new(8, 1), Decimal::new(125, 2)),
+                )]),
+            ),
+            None,
+        );
+
+        // 40000 EUR = 50000 USD = 1 BTC
+        let conversion = convert_crypto_currency_using_rates(
+            &get_test_crypto_rates(),
+            Some(&fiat_rates),
+            4_000_000,
+            &get_test_btc_asset(),
+            &ConversionAsset::Fiat(enums::Currency::EUR),
+            8,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Decimal::from_str(&conversion.converted_amount).unwrap(),
+            Decimal::ONE
+        );
+        let rate_path = conversion.rate_path.unwrap();
+        assert_eq!(rate_path.pivot_currency, "USD");
+        assert_eq!(
+            get_test_rate_path_legs(&rate_path),
+            vec![
+                ("EUR", "USD", Decimal::new(125, 2)),
+                ("USD", "BTC", Decimal::new(2, 5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_convert_crypto_currency_from_fiat_through_non_usd_base_currency() {
+        let fiat_rates = FxExchangeRatesCacheEntry::new(
+            ExchangeRates::new(
+                enums::Currency::EUR,
+                HashMap::from([
+                    (
+                        enums::Currency::USD,
+                        CurrencyFactors::new(Decimal::new(125, 2), Decimal::new(8, 1)),
+                    ),
+                    (
+                        enums::Currency::GBP,
+                        CurrencyFactors::new(Decimal::new(8, 1), Decimal::new(125, 2)),
+                    ),
+                ]),
+            ),
+            None,
+        );
+
+        // 32000 GBP = 40000 EUR = 50000 USD = 1 BTC
+        let conversion = convert_crypto_currency_using_rates(
+            &get_test_crypto_rates(),
+            Some(&fiat_rates),
+            3_200_000,
+            &get_test_btc_asset(),
+            &ConversionAsset::Fiat(enums::Currency::GBP),
+            8,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Decimal::from_str(&conversion.converted_amount).unwrap(),
+            Decimal::ONE
+        );
+        let rate_path = conversion.rate_path.unwrap();
+        assert_eq!(rate_path.pivot_currency, "USD");
+        assert_eq!(
+            get_test_rate_path_legs(&rate_path),
+            vec![
+                ("GBP", "EUR", Decimal::new(125, 2)),
+                ("EUR", "USD", Decimal::new(125, 2)),
+                ("USD", "BTC", Decimal::new(2, 5)),
+            ]
+        );
+
+        // The base currency itself is converted to USD directly
+        let conversion = convert_crypto_currency_using_rates(
+            &get_test_crypto_rates(),
+            Some(&fiat_rates),
+            100_000_000,
+            &ConversionAsset::Fiat(enums::Currency::EUR),
+            &get_test_btc_asset(),
+            8,
+        )
+        .unwrap();
+        assert_eq!(
+            Decimal::from_str(&conversion.converted_amount).unwrap(),
+            Decimal::new(40000, 0)
+        );
+        assert_eq!(
+            get_test_rate_path_legs(&conversion.rate_path.unwrap()),
+            vec![
+                ("BTC", "USD", Decimal::new(50000, 0)),
+                ("USD", "EUR", Decimal::new(8, 1)),
+            ]
+        );
+    }
+
+    fn get_test_rate_path_legs(
+        rate_path: &api_models::currency::CurrencyConversionRatePath,
+    ) -> Vec<(&str, &str, Decimal)> {
+        rate_path
+            .legs
+            .iter()
+            .map(|leg| {
+                (
+                    leg.from_currency.as_str(),
+                    leg.to_currency.as_str(),
+                    Decimal::from_str(&leg.rate).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_convert_crypto_currency_without_rate_for_asset() {
+        let error = convert_crypto_currency_using_rates(
+            &get_test_crypto_rates(),
+            None,
+            100,
+            &ConversionAsset::Fiat(enums::Currency::USD),
+            &ConversionAsset::Crypto {
+                code: "ETH".to_string(),
+                minor_units: 18,
+            },
+            6,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ForexCacheError::CryptoRateNotAvailable(code) if code == "ETH"
+        ));
+    }
+
+    #[test]
+    fn test_get_forex_rates_snapshot_key() {
+        assert_eq!(
+            get_forex_rates_snapshot_key(time::macros::date!(2024 - 07 - 03)),
+            "forex_rates_snapshot_2024-07-03"
+        );
+    }
+
+    #[test]
+    fn test_forex_rates_cache_round_trip_keeps_rates_exact() {
+        let rate = ForexRate::from(Decimal::new(1_623_456_789, 5));
+        let exchange_rates = ExchangeRates::new(
+            enums::Currency::USD,
+            HashMap::from([(
+                enums::Currency::IDR,
+                CurrencyFactors::from_rate(rate).unwrap(),
+            )]),
+        );
+
+        let serialized = serde_json::to_string(&exchange_rates).unwrap();
+        let deserialized: ExchangeRates = serde_json::from_str(&serialized).unwrap();
+        let currency_factors = deserialized.conversion.get(&enums::Currency::IDR).unwrap();
+        assert_eq!(currency_factors.to_factor, rate);
+        assert_eq!(currency_factors.from_factor, rate.inverse().unwrap());
+    }
+}
@@ -5,5 +5,13 @@ pub mod attach_payout_account_workflow;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_hold_expiry_workflow;
+#[cfg(feature = "payouts")]
+pub mod payout_verification_expiry_workflow;
+#[cfg(all(feature = "payouts", feature = "olap"))]
+pub mod payouts_export_workflow;
 pub mod refund_router;
+#[cfg(feature = "payouts")]
+pub mod scheduled_payout_workflow;
 pub mod tokenized_data;
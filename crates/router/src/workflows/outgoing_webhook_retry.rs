@@ -19,6 +19,7 @@ use scheduler::{
 #[cfg(feature = "payouts")]
 use crate::core::payouts;
 use crate::{
+    consts,
     core::webhooks::{self as webhooks_core, types::OutgoingWebhookTrackingData},
     db::StorageInterface,
     errors, logger,
@@ -301,7 +302,11 @@ pub(crate) async fn retry_webhook_delivery_task(
     process: storage::ProcessTracker,
 ) -> errors::CustomResult<(), errors::StorageError> {
     let schedule_time =
-        get_webhook_delivery_retry_schedule_time(db, merchant_id, process.retry_count + 1).await;
+        get_webhook_delivery_retry_schedule_time(db, merchant_id, process.retry_count + 1)
+            .await
+            .filter(|schedule_time| {
+                is_within_webhook_retry_duration(process.created_at, *schedule_time)
+            });
 
     match schedule_time {
         Some(schedule_time) => {
@@ -317,7 +322,20 @@ pub(crate) async fn retry_webhook_delivery_task(
     }
 }
 
+/// Whether a retry scheduled at `schedule_time` is within the maximum duration for which the
+/// delivery of an event is retried, counted from the creation of its retry task.
+fn is_within_webhook_retry_duration(
+    created_at: time::PrimitiveDateTime,
+    schedule_time: time::PrimitiveDateTime,
+) -> bool {
+    schedule_time
+        <= created_at.saturating_add(time::Duration::seconds(
+            consts::OUTGOING_WEBHOOK_MAX_RETRY_DURATION,
+        ))
+}
+
 #[instrument(skip_all)]
+#[allow(deprecated)]
 async fn get_outgoing_webhook_content_and_event_type(
     state: SessionState,
     req_state: ReqState,
@@ -380,6 +398,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::PaymenkLinkForm(_)
                     | ApplicationResponse::FileData(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
@@ -436,6 +455,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::PaymenkLinkForm(_)
                     | ApplicationResponse::FileData(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
@@ -468,6 +488,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::PaymenkLinkForm(_)
                     | ApplicationResponse::FileData(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
@@ -494,7 +515,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                 payouts::make_payout_data(&state, &merchant_account, &key_store, &request).await?;
 
             let router_response =
-                payouts::response_handler(&merchant_account, &payout_data).await?;
+                payouts::response_handler(&state, &merchant_account, &payout_data).await?;
 
             let payout_create_response: payout_models::PayoutCreateResponse = match router_response
             {
@@ -513,3 +534,28 @@ async fn get_outgoing_webhook_content_and_event_type(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_within_webhook_retry_duration() {
+        let created_at = common_utils::date_time::now();
+        let max_retry_duration =
+            time::Duration::seconds(consts::OUTGOING_WEBHOOK_MAX_RETRY_DURATION);
+
+        assert!(is_within_webhook_retry_duration(
+            created_at,
+            created_at + time::Duration::minutes(5)
+        ));
+        assert!(is_within_webhook_retry_duration(
+            created_at,
+            created_at + max_retry_duration
+        ));
+        assert!(!is_within_webhook_retry_duration(
+            created_at,
+            created_at + max_retry_duration + time::Duration::seconds(1)
+        ));
+    }
+}
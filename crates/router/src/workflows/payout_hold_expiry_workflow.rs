@@ -0,0 +1,63 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{core::payouts::holds, errors as core_errors, routes::SessionState, types::storage};
+
+pub struct PayoutHoldExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutHoldExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: holds::PayoutHoldExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutHoldExpiryTrackingData")?;
+
+        // The hold is expired under the lock under which it is captured or released, so that a
+        // hold being captured is not expired in the meantime
+        let lock_action = holds::get_payout_hold_lock_action(&tracking_data.hold_id);
+        lock_action
+            .clone()
+            .perform_locking_action(state, tracking_data.merchant_id.clone())
+            .await?;
+        let result =
+            holds::expire_payout_hold(state, &tracking_data.merchant_id, &tracking_data.hold_id)
+                .await;
+        lock_action
+            .free_lock_action(state, tracking_data.merchant_id.clone())
+            .await?;
+
+        if !result? {
+            logger::info!(
+                "Skipping expiry of payout hold {} which is no longer held",
+                tracking_data.hold_id
+            );
+        }
+
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,112 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::{payouts, webhooks},
+    errors as core_errors,
+    routes::SessionState,
+    services,
+    types::{api, storage, transformers::ForeignFrom},
+};
+
+pub struct PayoutVerificationExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutVerificationExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        // Gather context
+        let db = &*state.store;
+        let tracking_data: api::PayoutRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutRetrieveRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                merchant_id.as_ref(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+            .await?;
+
+        let request = api::payouts::PayoutRequest::PayoutRetrieveRequest(tracking_data);
+
+        let mut payout_data =
+            payouts::make_payout_data(state, &merchant_account, &key_store, &request).await?;
+
+        // The result of the verification might have been posted, or the payout cancelled, since
+        // the expiry was scheduled, in which case the payout is no longer pending verification
+        let is_expired = payout_data.payout_attempt.status
+            == storage_enums::PayoutStatus::PendingVerification
+            && payouts::resolve_payout_verification(
+                state,
+                &merchant_account,
+                &mut payout_data,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        if is_expired {
+            // Notify the merchant of the expiry
+            let response =
+                payouts::response_handler(state, &merchant_account, &payout_data).await?;
+            if let (Some(event_type), services::ApplicationResponse::Json(payout_response)) = (
+                Option::<storage_enums::EventType>::foreign_from(payout_data.payouts.status),
+                response,
+            ) {
+                webhooks::create_event_and_trigger_outgoing_webhook(
+                    state.clone(),
+                    merchant_account,
+                    payout_data.business_profile,
+                    &key_store,
+                    event_type,
+                    storage_enums::EventClass::Payouts,
+                    payout_data.payouts.payout_id,
+                    storage_enums::EventObjectType::PayoutDetails,
+                    api::OutgoingWebhookContent::PayoutDetails(payout_response),
+                    Some(payout_data.payout_attempt.created_at),
+                )
+                .await?;
+            }
+        } else {
+            logger::info!(
+                "Skipping verification expiry for payout {} in {} status",
+                payout_data.payouts.payout_id,
+                payout_data.payout_attempt.status
+            );
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,103 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts,
+    core::{errors as api_errors, payouts::export},
+    errors as core_errors,
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct PayoutsExportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutsExportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: export::PayoutsExportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutsExportTrackingData")?;
+
+        // The job runs again at the end of the retention period of the files, to delete them
+        if process.business_status == export::EXPORT_FILE_AVAILABLE {
+            for file_key in export::get_payouts_export_file_keys(&tracking_data) {
+                state
+                    .file_storage_client
+                    .delete_file(&file_key)
+                    .await
+                    .change_context(api_errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete a file of the payouts export")?;
+            }
+            db.as_scheduler()
+                .finish_process_with_business_status(process, export::EXPORT_FILE_EXPIRED)
+                .await?;
+            return Ok(());
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let parts_count =
+            export::store_payouts_csv_export(state, &merchant_account, &tracking_data).await?;
+        let tracking_data = export::PayoutsExportTrackingData {
+            parts_count: Some(parts_count),
+            ..tracking_data
+        };
+
+        let retention = state
+            .conf
+            .payouts
+            .export_file_retention
+            .unwrap_or(consts::DEFAULT_PAYOUTS_EXPORT_FILE_RETENTION);
+        let now = common_utils::date_time::now();
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: Some(
+                        now.saturating_add(time::Duration::seconds(i64::from(retention))),
+                    ),
+                    tracking_data: Some(
+                        serde_json::to_value(&tracking_data)
+                            .change_context(api_errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Failed to serialize payouts export tracking data")?,
+                    ),
+                    business_status: Some(String::from(export::EXPORT_FILE_AVAILABLE)),
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
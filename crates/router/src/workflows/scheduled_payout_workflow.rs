@@ -0,0 +1,112 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::payouts::{self, helpers},
+    errors as core_errors,
+    routes::SessionState,
+    types::{api, storage},
+};
+
+pub struct ScheduledPayoutWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ScheduledPayoutWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        // Gather context
+        let db = &*state.store;
+        let tracking_data: api::PayoutRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutRetrieveRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                merchant_id.as_ref(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+            .await?;
+
+        let request = api::payouts::PayoutRequest::PayoutRetrieveRequest(tracking_data);
+
+        let mut payout_data =
+            payouts::make_payout_data(state, &merchant_account, &key_store, &request).await?;
+
+        // The payout might have been cancelled or processed since it was scheduled. A payout left in
+        // `requires_creation` by a failed run of this task is disbursed again on the retry. The
+        // payout is only moved out of `scheduled` if it was not cancelled concurrently.
+        let should_disburse =
+            if payout_data.payout_attempt.status == storage_enums::PayoutStatus::Scheduled {
+                let status = storage_enums::PayoutStatus::RequiresCreation;
+                let updated_payout_attempt = helpers::get_payout_status_attempt_update(
+                    payout_data.payout_attempt.connector_payout_id.to_owned(),
+                    status,
+                    None,
+                    None,
+                );
+                helpers::update_payout_status_if(
+                    state,
+                    &merchant_account,
+                    &mut payout_data,
+                    storage_enums::PayoutStatus::Scheduled,
+                    updated_payout_attempt,
+                    storage::PayoutsUpdate::StatusUpdate { status },
+                )
+                .await?
+            } else {
+                helpers::should_disburse_scheduled_payout(payout_data.payout_attempt.status)
+            };
+
+        if should_disburse {
+            // The routing and connectors requested on creation are used by `payouts_core`
+            payouts::payouts_core(
+                state,
+                &merchant_account,
+                &key_store,
+                &mut payout_data,
+                None,
+                None,
+            )
+            .await?;
+        } else {
+            logger::info!(
+                "Skipping scheduled disbursement for payout {} in {} status",
+                payout_data.payouts.payout_id,
+                payout_data.payout_attempt.status
+            );
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
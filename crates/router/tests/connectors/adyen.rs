@@ -127,6 +127,7 @@ impl AdyenTest {
                         paypal_id: None,
                     }),
                 )),
+                enums::PayoutType::StoreCredit => None,
             },
             ..Default::default()
         })
@@ -81,6 +81,7 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
             product_name: "iphone 13".to_string(),
             quantity: 1,
             amount: 1000,
+            currency: None,
             product_img_link: None,
             requires_shipping: None,
             product_id: None,
@@ -381,6 +382,7 @@ async fn should_fail_payment_for_incorrect_cvc() {
                     product_name: "iphone 13".to_string(),
                     quantity: 1,
                     amount: 100,
+                    currency: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -420,6 +422,7 @@ async fn should_fail_payment_for_invalid_exp_month() {
                     product_name: "iphone 13".to_string(),
                     quantity: 1,
                     amount: 100,
+                    currency: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -459,6 +462,7 @@ async fn should_fail_payment_for_incorrect_expiry_year() {
                     product_name: "iphone 13".to_string(),
                     quantity: 1,
                     amount: 100,
+                    currency: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -470,6 +470,12 @@ pub trait ConnectorActions: Connector {
                 }),
                 vendor_details: None,
                 priority: None,
+                purpose_code: None,
+                originator: None,
+                value_date: None,
+                statement_descriptor: None,
+                charge_bearer: enums::PayoutChargeBearer::Sender,
+                settlement_network: None,
             },
             payment_info,
         )
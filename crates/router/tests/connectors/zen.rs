@@ -320,6 +320,7 @@ async fn should_fail_payment_for_incorrect_card_number() {
                     product_name: "test".to_string(),
                     quantity: 1,
                     amount: 1000,
+                    currency: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -362,6 +363,7 @@ async fn should_fail_payment_for_incorrect_cvc() {
                     product_name: "test".to_string(),
                     quantity: 1,
                     amount: 1000,
+                    currency: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -404,6 +406,7 @@ async fn should_fail_payment_for_invalid_exp_month() {
                     product_name: "test".to_string(),
                     quantity: 1,
                     amount: 1000,
+                    currency: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -446,6 +449,7 @@ async fn should_fail_payment_for_incorrect_expiry_year() {
                     product_name: "test".to_string(),
                     quantity: 1,
                     amount: 1000,
+                    currency: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -164,6 +164,57 @@ pub enum Flow {
     /// Payouts retrieve flow.
     PayoutsRetrieve,
     #[cfg(feature = "payouts")]
+    /// Payouts bulk retrieve flow.
+    PayoutsBulkRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payouts batch create flow.
+    PayoutsBatchCreate,
+    #[cfg(feature = "payouts")]
+    /// Payouts retrieve by connector reference flow.
+    PayoutsRetrieveByConnectorReference,
+    #[cfg(feature = "payouts")]
+    /// Payout templates create flow.
+    PayoutTemplatesCreate,
+    #[cfg(feature = "payouts")]
+    /// Payout templates retrieve flow.
+    PayoutTemplatesRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payout templates list flow.
+    PayoutTemplatesList,
+    #[cfg(feature = "payouts")]
+    /// Payout templates delete flow.
+    PayoutTemplatesDelete,
+    #[cfg(feature = "payouts")]
+    /// Payout destination verifications create flow.
+    PayoutDestinationVerificationsCreate,
+    #[cfg(feature = "payouts")]
+    /// Payout destination verifications retrieve flow.
+    PayoutDestinationVerificationsRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payout destination verifications confirm flow.
+    PayoutDestinationVerificationsConfirm,
+    #[cfg(feature = "payouts")]
+    /// Payout holds create flow.
+    PayoutHoldsCreate,
+    #[cfg(feature = "payouts")]
+    /// Payout holds retrieve flow.
+    PayoutHoldsRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payout holds capture flow.
+    PayoutHoldsCapture,
+    #[cfg(feature = "payouts")]
+    /// Payout holds release flow.
+    PayoutHoldsRelease,
+    #[cfg(feature = "payouts")]
+    /// Payout holds balance flow.
+    PayoutHoldsBalance,
+    #[cfg(feature = "payouts")]
+    /// Payouts admin retrieve flow.
+    PayoutsAdminRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payouts admin currency conversions flow.
+    PayoutsAdminCurrencyConversions,
+    #[cfg(feature = "payouts")]
     /// Payouts update flow.
     PayoutsUpdate,
     /// Payouts confirm flow.
@@ -175,11 +226,38 @@ pub enum Flow {
     /// Payouts fulfill flow.
     PayoutsFulfill,
     #[cfg(feature = "payouts")]
+    /// Payouts approve flow.
+    PayoutsApprove,
+    #[cfg(feature = "payouts")]
+    /// Payouts verify flow.
+    PayoutsVerify,
+    #[cfg(feature = "payouts")]
+    /// Payouts reprocess flow.
+    PayoutsReprocess,
+    #[cfg(feature = "payouts")]
+    /// Payouts recall flow.
+    PayoutsRecall,
+    #[cfg(feature = "payouts")]
+    /// Payouts series cancel flow.
+    PayoutsSeriesCancel,
+    #[cfg(feature = "payouts")]
     /// Payouts list flow.
     PayoutsList,
     #[cfg(feature = "payouts")]
     /// Payouts filter flow.
     PayoutsFilter,
+    #[cfg(feature = "payouts")]
+    /// Payouts export flow.
+    PayoutsExport,
+    #[cfg(feature = "payouts")]
+    /// Payouts export job retrieve flow.
+    PayoutsExportRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payouts export download flow.
+    PayoutsExportDownload,
+    #[cfg(feature = "payouts")]
+    /// Payouts connector usage flow.
+    PayoutsConnectorUsage,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payout link initiate flow
@@ -202,6 +280,12 @@ pub enum Flow {
     RefundsFilters,
     // Retrieve forex flow.
     RetrieveForexFlow,
+    /// Retrieve currency display metadata flow.
+    RetrieveCurrencyMetadata,
+    /// Forex conversion quote create flow.
+    ForexQuoteCreate,
+    /// Forex conversion quote cancel flow.
+    ForexQuoteCancel,
     /// Toggles recon service for a merchant.
     ReconMerchantUpdate,
     /// Recon token request flow.
@@ -284,6 +368,8 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Payment Link Preview flow
+    PaymentLinkPreview,
     /// Create a business profile
     BusinessProfileCreate,
     /// Update a business profile
@@ -444,6 +530,8 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Retrieve the retry status of the deliveries for a webhook event
+    WebhookEventDeliveryRetryStatus,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
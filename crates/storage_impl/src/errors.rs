@@ -373,6 +373,10 @@ pub enum HealthCheckDBError {
     ClickhouseAnalyticsError,
     #[error("Error while executing query in Opensearch")]
     OpensearchError,
+    #[error("Database schema is out of date, expected migrations are not applied")]
+    SchemaOutOfDate,
+    #[error("Simulated failure of the database health check")]
+    SimulatedFailure,
 }
 
 impl From<diesel::result::Error> for HealthCheckDBError {
@@ -401,10 +405,48 @@ pub enum HealthCheckRedisError {
     GetFailed,
     #[error("Failed to delete key value in Redis")]
     DeleteFailed,
+    #[error("Redis health check did not complete within the probe timeout")]
+    ProbeTimedOut,
+    #[error("Simulated failure of the Redis health check")]
+    SimulatedFailure,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum HealthCheckLockerError {
     #[error("Failed to establish Locker connection")]
     FailedToCallLocker,
+    #[error("Failed to store data in Locker")]
+    FailedToStoreData,
+    #[error("Failed to retrieve data from Locker")]
+    FailedToRetrieveData,
+    #[error("Failed to delete data from Locker")]
+    FailedToDeleteData,
+    #[error("Data retrieved from Locker does not match the data stored")]
+    RoundTripDataMismatch,
+    #[error("Simulated failure of the Locker health check")]
+    SimulatedFailure,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HealthCheckFileStoreError {
+    #[error("Failed to upload object to the file store")]
+    UploadFailed,
+    #[error("Failed to retrieve object from the file store")]
+    RetrieveFailed,
+    #[error("Failed to delete object from the file store")]
+    DeleteFailed,
+    #[error("Object retrieved from the file store does not match the object uploaded")]
+    RoundTripDataMismatch,
+    #[error("File store health check did not complete within the probe timeout")]
+    ProbeTimedOut,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HealthCheckSecretsManagerError {
+    #[error("Secret to be decrypted by the secrets manager probe is not configured")]
+    ProbeSecretNotConfigured,
+    #[error("Failed to decrypt the probe secret with the secrets manager")]
+    DecryptFailed,
+    #[error("Secrets manager health check did not complete within the probe timeout")]
+    ProbeTimedOut,
 }
@@ -55,6 +55,11 @@ pub struct MockDb {
     pub payout_attempt: Arc<Mutex<Vec<store::payout_attempt::PayoutAttempt>>>,
     #[cfg(feature = "payouts")]
     pub payouts: Arc<Mutex<Vec<store::payouts::Payouts>>>,
+    pub payout_destination_verifications:
+        Arc<Mutex<Vec<store::payout_destination_verification::PayoutDestinationVerification>>>,
+    pub currency_conversion_audit_logs:
+        Arc<Mutex<Vec<store::currency_conversion_audit_log::CurrencyConversionAuditLog>>>,
+    pub payout_holds: Arc<Mutex<Vec<store::payout_hold::PayoutHold>>>,
     pub authentications: Arc<Mutex<Vec<store::authentication::Authentication>>>,
     pub roles: Arc<Mutex<Vec<store::role::Role>>>,
     pub user_key_store: Arc<Mutex<Vec<store::user_key_store::UserKeyStore>>>,
@@ -101,6 +106,9 @@ impl MockDb {
             payout_attempt: Default::default(),
             #[cfg(feature = "payouts")]
             payouts: Default::default(),
+            payout_destination_verifications: Default::default(),
+            currency_conversion_audit_logs: Default::default(),
+            payout_holds: Default::default(),
             authentications: Default::default(),
             roles: Default::default(),
             user_key_store: Default::default(),
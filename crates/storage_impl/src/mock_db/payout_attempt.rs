@@ -5,6 +5,7 @@ use hyperswitch_domain_models::{
     payouts::{
         payout_attempt::{
             PayoutAttempt, PayoutAttemptInterface, PayoutAttemptNew, PayoutAttemptUpdate,
+            PayoutConnectorUsage,
         },
         payouts::Payouts,
     },
@@ -25,6 +26,18 @@ impl PayoutAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn update_payout_attempt_if_status(
+        &self,
+        _this: &PayoutAttempt,
+        _expected_status: storage_enums::PayoutStatus,
+        _payout_attempt_update: PayoutAttemptUpdate,
+        _payouts: &Payouts,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<PayoutAttempt>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     async fn insert_payout_attempt(
         &self,
         _payout_attempt: PayoutAttemptNew,
@@ -55,6 +68,26 @@ impl PayoutAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn list_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        _merchant_id: &str,
+        _payout_attempt_ids: &[String],
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PayoutAttempt>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn list_payout_attempts_by_merchant_id_connector_payout_id(
+        &self,
+        _merchant_id: &str,
+        _connector_payout_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PayoutAttempt>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     async fn get_filters_for_payouts(
         &self,
         _payouts: &[Payouts],
@@ -66,4 +99,14 @@ impl PayoutAttemptInterface for MockDb {
     > {
         Err(StorageError::MockDbError)?
     }
+
+    #[cfg(feature = "olap")]
+    async fn get_connector_usage_for_payouts(
+        &self,
+        _merchant_id: &str,
+        _filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PayoutConnectorUsage>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
 }
@@ -52,6 +52,26 @@ impl PayoutsInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn find_optional_payout_by_merchant_id_merchant_reference_id(
+        &self,
+        _merchant_id: &str,
+        _merchant_reference_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<Payouts>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn find_payouts_by_merchant_id_series_id(
+        &self,
+        _merchant_id: &str,
+        _series_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<Payouts>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
@@ -84,4 +104,28 @@ impl PayoutsInterface for MockDb {
         // TODO: Implement function for `MockDb`
         Err(StorageError::MockDbError)?
     }
+
+    #[cfg(feature = "olap")]
+    async fn find_earlier_payout_by_fingerprint_for_other_customer(
+        &self,
+        _merchant_id: &str,
+        _payout_method_fingerprint: &str,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _created_before: time::PrimitiveDateTime,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<Payouts>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn get_payout_count_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _created_at_or_before: time::PrimitiveDateTime,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<i64, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
 }
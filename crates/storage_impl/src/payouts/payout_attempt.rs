@@ -2,8 +2,12 @@ use std::str::FromStr;
 
 use api_models::enums::PayoutConnectors;
 use common_utils::{errors::CustomResult, ext_traits::Encode, fallback_reverse_lookup_not_found};
+#[cfg(feature = "olap")]
+use diesel::{ExpressionMethods, QueryDsl};
+#[cfg(feature = "olap")]
+use diesel_models::schema::payouts::dsl as po_dsl;
 use diesel_models::{
-    enums::MerchantStorageScheme,
+    enums::{self as storage_enums, MerchantStorageScheme},
     kv,
     payout_attempt::{
         PayoutAttempt as DieselPayoutAttempt, PayoutAttemptNew as DieselPayoutAttemptNew,
@@ -13,28 +17,36 @@ use diesel_models::{
     ReverseLookupNew,
 };
 use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::payouts::{payouts::PayoutsInterface, PayoutFetchConstraints};
 use hyperswitch_domain_models::{
     errors,
     payouts::{
         payout_attempt::{
             PayoutAttempt, PayoutAttemptInterface, PayoutAttemptNew, PayoutAttemptUpdate,
-            PayoutListFilters,
+            PayoutConnectorUsage, PayoutListFilters,
         },
         payouts::Payouts,
     },
 };
-use redis_interface::HsetnxReply;
+use redis_interface::{HsetnxReply, SetnxReply};
 use router_env::{instrument, logger, tracing};
 
 use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
     lookup::ReverseLookupInterface,
-    redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
+    redis::kv_store::{
+        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey, RedisConnInterface,
+    },
     utils::{self, pg_connection_read, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
 };
 
+/// Time in seconds for which a status transition of a payout attempt is claimed in KV mode, bounds
+/// how long other transitions are held off if the claimant fails to release the claim
+const PAYOUT_ATTEMPT_STATUS_CLAIM_TTL: i64 = 60;
+
 #[async_trait::async_trait]
 impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
     #[instrument(skip_all)]
@@ -81,6 +93,10 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     profile_id: new_payout_attempt.profile_id.clone(),
                     merchant_connector_id: new_payout_attempt.merchant_connector_id.clone(),
                     routing_info: new_payout_attempt.routing_info.clone(),
+                    reason_code: new_payout_attempt.reason_code,
+                    reason: new_payout_attempt.reason.clone(),
+                    destination_index: new_payout_attempt.destination_index,
+                    recalled_amount: new_payout_attempt.recalled_amount,
                 };
 
                 let redis_entry = kv::TypedSql {
@@ -233,6 +249,92 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn update_payout_attempt_if_status(
+        &self,
+        this: &PayoutAttempt,
+        expected_status: storage_enums::PayoutStatus,
+        payout_update: PayoutAttemptUpdate,
+        payouts: &Payouts,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<PayoutAttempt>, errors::StorageError> {
+        let key = PartitionKey::MerchantIdPayoutAttemptId {
+            merchant_id: &this.merchant_id,
+            payout_attempt_id: &this.payout_id,
+        };
+        let field = format!("poa_{}", this.payout_attempt_id);
+        let storage_scheme = decide_storage_scheme::<_, DieselPayoutAttempt>(
+            self,
+            storage_scheme,
+            Op::Update(key, &field, None),
+        )
+        .await;
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .update_payout_attempt_if_status(
+                        this,
+                        expected_status,
+                        payout_update,
+                        payouts,
+                        storage_scheme,
+                    )
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                // Redis offers no compare-and-set on a hash field, so the transition out of the
+                // expected status is claimed with a SETNX before the attempt is read and updated
+                let redis_conn = self
+                    .get_redis_conn()
+                    .map_err(|err| err.to_redis_failed_response(&field))?;
+                let claim_key = format!(
+                    "poa_status_claim_{}_{}_{}",
+                    this.merchant_id, this.payout_attempt_id, expected_status
+                );
+                let claim = redis_conn
+                    .set_key_if_not_exists_with_expiry(
+                        &claim_key,
+                        "true",
+                        Some(PAYOUT_ATTEMPT_STATUS_CLAIM_TTL),
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&claim_key))?;
+                if claim == SetnxReply::KeyNotSet {
+                    return Ok(None);
+                }
+
+                let result: error_stack::Result<_, errors::StorageError> = async {
+                    let current_attempt = self
+                        .find_payout_attempt_by_merchant_id_payout_attempt_id(
+                            &this.merchant_id,
+                            &this.payout_attempt_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    if current_attempt.status != expected_status {
+                        return Ok(None);
+                    }
+                    self.update_payout_attempt(
+                        &current_attempt,
+                        payout_update,
+                        payouts,
+                        storage_scheme,
+                    )
+                    .await
+                    .map(Some)
+                }
+                .await;
+
+                // The claim is released once the attempt has been updated, a transition which
+                // is attempted afterwards sees the updated status instead
+                if let Err(error) = redis_conn.delete_key(&claim_key).await {
+                    logger::error!(?error, "Failed to release payout attempt status claim");
+                }
+                result
+            }
+        }
+    }
+
     #[instrument(skip_all)]
     async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
         &self,
@@ -351,6 +453,106 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn list_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        merchant_id: &str,
+        payout_attempt_ids: &[String],
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        let payout_attempts = self
+            .router_store
+            .list_payout_attempts_by_merchant_id_payout_attempt_ids(
+                merchant_id,
+                payout_attempt_ids,
+                storage_scheme,
+            )
+            .await?;
+        let storage_scheme =
+            decide_storage_scheme::<_, DieselPayoutAttempt>(self, storage_scheme, Op::Find).await;
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => Ok(payout_attempts),
+            MerchantStorageScheme::RedisKv => {
+                // Attempts which are yet to be drained to the database are looked up in redis
+                let mut all_payout_attempts = payout_attempts;
+                for payout_attempt_id in payout_attempt_ids {
+                    if all_payout_attempts.iter().any(|payout_attempt| {
+                        &payout_attempt.payout_attempt_id == payout_attempt_id
+                    }) {
+                        continue;
+                    }
+                    match self
+                        .find_payout_attempt_by_merchant_id_payout_attempt_id(
+                            merchant_id,
+                            payout_attempt_id,
+                            storage_scheme,
+                        )
+                        .await
+                    {
+                        Ok(payout_attempt) => all_payout_attempts.push(payout_attempt),
+                        Err(error)
+                            if matches!(
+                                error.current_context(),
+                                errors::StorageError::ValueNotFound(_)
+                            ) => {}
+                        Err(error) => return Err(error),
+                    }
+                }
+                Ok(all_payout_attempts)
+            }
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_attempts_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        let payout_attempts = self
+            .router_store
+            .list_payout_attempts_by_merchant_id_connector_payout_id(
+                merchant_id,
+                connector_payout_id,
+                storage_scheme,
+            )
+            .await?;
+        let storage_scheme =
+            decide_storage_scheme::<_, DieselPayoutAttempt>(self, storage_scheme, Op::Find).await;
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => Ok(payout_attempts),
+            MerchantStorageScheme::RedisKv => {
+                // The attempt most recently assigned the connector payout id may be yet to be
+                // drained to the database, in which case it is looked up in redis
+                let mut all_payout_attempts = payout_attempts;
+                match self
+                    .find_payout_attempt_by_merchant_id_connector_payout_id(
+                        merchant_id,
+                        connector_payout_id,
+                        storage_scheme,
+                    )
+                    .await
+                {
+                    Ok(payout_attempt) => {
+                        all_payout_attempts.retain(|existing| {
+                            existing.payout_attempt_id != payout_attempt.payout_attempt_id
+                        });
+                        all_payout_attempts.push(payout_attempt);
+                        all_payout_attempts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                    }
+                    Err(error)
+                        if matches!(
+                            error.current_context(),
+                            errors::StorageError::ValueNotFound(_)
+                        ) => {}
+                    Err(error) => return Err(error),
+                }
+                Ok(all_payout_attempts)
+            }
+        }
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
@@ -362,6 +564,19 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
             .get_filters_for_payouts(payouts, merchant_id, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "olap")]
+    #[instrument(skip_all)]
+    async fn get_connector_usage_for_payouts(
+        &self,
+        merchant_id: &str,
+        filters: &PayoutFetchConstraints,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutConnectorUsage>, errors::StorageError> {
+        self.router_store
+            .get_connector_usage_for_payouts(merchant_id, filters, storage_scheme)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -404,6 +619,27 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
             .map(PayoutAttempt::from_storage_model)
     }
 
+    #[instrument(skip_all)]
+    async fn update_payout_attempt_if_status(
+        &self,
+        this: &PayoutAttempt,
+        expected_status: storage_enums::PayoutStatus,
+        payout: PayoutAttemptUpdate,
+        _payouts: &Payouts,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<PayoutAttempt>, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        this.clone()
+            .to_storage_model()
+            .update_with_attempt_id_and_status(&conn, expected_status, payout.to_storage_model())
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+            .map(|payout_attempt| payout_attempt.map(PayoutAttempt::from_storage_model))
+    }
+
     #[instrument(skip_all)]
     async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
         &self,
@@ -446,6 +682,58 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         })
     }
 
+    #[instrument(skip_all)]
+    async fn list_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        merchant_id: &str,
+        payout_attempt_ids: &[String],
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayoutAttempt::find_all_by_merchant_id_payout_attempt_ids(
+            &conn,
+            merchant_id,
+            payout_attempt_ids.to_vec(),
+        )
+        .await
+        .map(|payout_attempts| {
+            payout_attempts
+                .into_iter()
+                .map(PayoutAttempt::from_storage_model)
+                .collect()
+        })
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_attempts_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayoutAttempt::find_all_by_merchant_id_connector_payout_id(
+            &conn,
+            merchant_id,
+            connector_payout_id,
+        )
+        .await
+        .map(|payout_attempts| {
+            payout_attempts
+                .into_iter()
+                .map(PayoutAttempt::from_storage_model)
+                .collect()
+        })
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
@@ -487,6 +775,87 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
                 },
             )
     }
+
+    #[cfg(feature = "olap")]
+    #[instrument(skip_all)]
+    async fn get_connector_usage_for_payouts(
+        &self,
+        merchant_id: &str,
+        filters: &PayoutFetchConstraints,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PayoutConnectorUsage>, errors::StorageError> {
+        let (starting_at, ending_at) = match filters {
+            PayoutFetchConstraints::Single { .. } => (None, None),
+            PayoutFetchConstraints::List(params) => {
+                let starting_at = match (params.starting_at, &params.starting_after_id) {
+                    (Some(starting_at), _) => Some(starting_at),
+                    (None, Some(starting_after_id)) => Some(
+                        self.find_payout_by_merchant_id_payout_id(
+                            merchant_id,
+                            starting_after_id,
+                            storage_scheme,
+                        )
+                        .await?
+                        .created_at,
+                    ),
+                    (None, None) => None,
+                };
+                let ending_at = match (params.ending_at, &params.ending_before_id) {
+                    (Some(ending_at), _) => Some(ending_at),
+                    (None, Some(ending_before_id)) => Some(
+                        self.find_payout_by_merchant_id_payout_id(
+                            merchant_id,
+                            ending_before_id,
+                            storage_scheme,
+                        )
+                        .await?
+                        .created_at,
+                    ),
+                    (None, None) => None,
+                };
+                (starting_at, ending_at)
+            }
+        };
+        let payout_ids = filtered_payout_ids_query(merchant_id, filters, starting_at, ending_at);
+        let connector_filter = match filters {
+            PayoutFetchConstraints::List(params) => params.connector.clone(),
+            PayoutFetchConstraints::Single { .. } => None,
+        };
+
+        let conn = pg_connection_read(self).await?;
+        DieselPayoutAttempt::get_connector_usage_for_payouts(&conn, merchant_id, payout_ids)
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+            .map(|usage| {
+                usage
+                    .into_iter()
+                    .filter_map(|(connector, count, last_used_at)| {
+                        PayoutConnectors::from_str(&connector)
+                            .map_err(|e| {
+                                logger::error!(
+                                    "Failed to parse payout connector '{}' - {}",
+                                    connector,
+                                    e
+                                );
+                            })
+                            .ok()
+                            .map(|connector| PayoutConnectorUsage {
+                                connector,
+                                count,
+                                last_used_at,
+                            })
+                    })
+                    .filter(|usage| {
+                        connector_filter
+                            .as_ref()
+                            .map_or(true, |connectors| connectors.contains(&usage.connector))
+                    })
+                    .collect()
+            })
+    }
 }
 
 impl DataModelExt for PayoutAttempt {
@@ -513,6 +882,10 @@ impl DataModelExt for PayoutAttempt {
             profile_id: self.profile_id,
             merchant_connector_id: self.merchant_connector_id,
             routing_info: self.routing_info,
+            reason_code: self.reason_code,
+            reason: self.reason,
+            destination_index: self.destination_index,
+            recalled_amount: self.recalled_amount,
         }
     }
 
@@ -537,6 +910,10 @@ impl DataModelExt for PayoutAttempt {
             profile_id: storage_model.profile_id,
             merchant_connector_id: storage_model.merchant_connector_id,
             routing_info: storage_model.routing_info,
+            reason_code: storage_model.reason_code,
+            reason: storage_model.reason,
+            destination_index: storage_model.destination_index,
+            recalled_amount: storage_model.recalled_amount,
         }
     }
 }
@@ -564,6 +941,10 @@ impl DataModelExt for PayoutAttemptNew {
             profile_id: self.profile_id,
             merchant_connector_id: self.merchant_connector_id,
             routing_info: self.routing_info,
+            reason_code: self.reason_code,
+            reason: self.reason,
+            destination_index: self.destination_index,
+            recalled_amount: self.recalled_amount,
         }
     }
 
@@ -588,6 +969,10 @@ impl DataModelExt for PayoutAttemptNew {
             profile_id: storage_model.profile_id,
             merchant_connector_id: storage_model.merchant_connector_id,
             routing_info: storage_model.routing_info,
+            reason_code: storage_model.reason_code,
+            reason: storage_model.reason,
+            destination_index: storage_model.destination_index,
+            recalled_amount: storage_model.recalled_amount,
         }
     }
 }
@@ -601,12 +986,16 @@ impl DataModelExt for PayoutAttemptUpdate {
                 error_message,
                 error_code,
                 is_eligible,
+                reason_code,
+                reason,
             } => DieselPayoutAttemptUpdate::StatusUpdate {
                 connector_payout_id,
                 status,
                 error_message,
                 error_code,
                 is_eligible,
+                reason_code,
+                reason,
             },
             Self::PayoutTokenUpdate { payout_token } => {
                 DieselPayoutAttemptUpdate::PayoutTokenUpdate { payout_token }
@@ -625,6 +1014,15 @@ impl DataModelExt for PayoutAttemptUpdate {
                 connector,
                 routing_info,
             },
+            Self::RecallUpdate {
+                status,
+                reason,
+                recalled_amount,
+            } => DieselPayoutAttemptUpdate::RecallUpdate {
+                status,
+                reason,
+                recalled_amount,
+            },
         }
     }
 
@@ -656,3 +1054,126 @@ async fn add_connector_payout_id_to_reverse_lookup<T: DatabaseStore>(
         .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
         .await
 }
+
+/// Builds a subquery selecting the ids of the merchant's payouts which match the list filters.
+/// Pagination is deliberately not applied, since connector usage is aggregated over every
+/// matching payout.
+#[cfg(feature = "olap")]
+fn filtered_payout_ids_query(
+    merchant_id: &str,
+    filters: &PayoutFetchConstraints,
+    starting_at: Option<time::PrimitiveDateTime>,
+    ending_at: Option<time::PrimitiveDateTime>,
+) -> diesel::dsl::IntoBoxed<
+    'static,
+    diesel::dsl::Select<po_dsl::payouts, po_dsl::payout_id>,
+    diesel::pg::Pg,
+> {
+    let mut query = po_dsl::payouts
+        .select(po_dsl::payout_id)
+        .filter(po_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .into_boxed();
+
+    match filters {
+        PayoutFetchConstraints::Single { payout_id } => {
+            query = query.filter(po_dsl::payout_id.eq(payout_id.to_owned()));
+        }
+        PayoutFetchConstraints::List(params) => {
+            if let Some(customer_id) = &params.customer_id {
+                query = query.filter(po_dsl::customer_id.eq(customer_id.clone()));
+            }
+            if let Some(merchant_reference_id) = &params.merchant_reference_id {
+                query =
+                    query.filter(po_dsl::merchant_reference_id.eq(merchant_reference_id.clone()));
+            }
+            if let Some(profile_id) = &params.profile_id {
+                query = query.filter(po_dsl::profile_id.eq(profile_id.clone()));
+            }
+            if let Some(starting_at) = starting_at {
+                query = query.filter(po_dsl::created_at.ge(starting_at));
+            }
+            if let Some(ending_at) = ending_at {
+                query = query.filter(po_dsl::created_at.le(ending_at));
+            }
+            if let Some(currency) = &params.currency {
+                query = query.filter(po_dsl::destination_currency.eq_any(currency.clone()));
+            }
+            if let Some(status) = &params.status {
+                query = query.filter(po_dsl::status.eq_any(status.clone()));
+            }
+            if let Some(payout_method) = &params.payout_method {
+                query = query.filter(po_dsl::payout_type.eq_any(payout_method.clone()));
+            }
+        }
+    }
+
+    query
+}
+
+#[cfg(all(test, feature = "olap"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use diesel_models::enums;
+    use hyperswitch_domain_models::payouts::{PayoutFetchConstraints, PayoutListParams};
+
+    use super::filtered_payout_ids_query;
+
+    fn list_params() -> PayoutListParams {
+        PayoutListParams {
+            offset: 20,
+            starting_at: None,
+            ending_at: None,
+            connector: None,
+            currency: Some(vec![enums::Currency::EUR]),
+            status: Some(vec![enums::PayoutStatus::Success]),
+            payout_method: Some(vec![enums::PayoutType::Bank]),
+            profile_id: Some("pro_abc".to_string()),
+            customer_id: None,
+            merchant_reference_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            entity_type: None,
+            limit: Some(10),
+        }
+    }
+
+    #[test]
+    fn test_filtered_payout_ids_query_applies_list_filters_without_pagination() {
+        let filters = PayoutFetchConstraints::List(Box::new(list_params()));
+        let ending_at = common_utils::date_time::now();
+        let starting_at = ending_at - time::Duration::days(30);
+        let query =
+            filtered_payout_ids_query("merchant_1", &filters, Some(starting_at), Some(ending_at));
+        let sql = diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string();
+
+        assert!(sql.starts_with(r#"SELECT "payouts"."payout_id" FROM "payouts""#));
+        for column in [
+            "merchant_id",
+            "profile_id",
+            "created_at",
+            "destination_currency",
+            "status",
+            "payout_type",
+        ] {
+            assert!(
+                sql.contains(&format!(r#""payouts"."{column}""#)),
+                "{column} missing in {sql}"
+            );
+        }
+        assert!(!sql.contains("LIMIT"));
+        assert!(!sql.contains("OFFSET"));
+    }
+
+    #[test]
+    fn test_filtered_payout_ids_query_for_single_payout() {
+        let filters = PayoutFetchConstraints::Single {
+            payout_id: "payout_1".to_string(),
+        };
+        let query = filtered_payout_ids_query("merchant_1", &filters, None, None);
+        let sql = diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string();
+
+        assert!(sql.contains(r#""payouts"."payout_id" = $2"#));
+        assert!(!sql.contains("created_at"));
+    }
+}
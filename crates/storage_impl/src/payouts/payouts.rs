@@ -1,6 +1,6 @@
 #[cfg(feature = "olap")]
 use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
-use common_utils::ext_traits::Encode;
+use common_utils::{ext_traits::Encode, fallback_reverse_lookup_not_found, id_type};
 #[cfg(feature = "olap")]
 use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
 #[cfg(feature = "olap")]
@@ -17,12 +17,13 @@ use diesel_models::{
         Payouts as DieselPayouts, PayoutsNew as DieselPayoutsNew,
         PayoutsUpdate as DieselPayoutsUpdate,
     },
+    ReverseLookupNew,
 };
 use error_stack::ResultExt;
 #[cfg(feature = "olap")]
 use hyperswitch_domain_models::payouts::PayoutFetchConstraints;
 use hyperswitch_domain_models::{
-    errors::StorageError,
+    errors::{self, StorageError},
     payouts::{
         payout_attempt::PayoutAttempt,
         payouts::{Payouts, PayoutsInterface, PayoutsNew, PayoutsUpdate},
@@ -32,12 +33,14 @@ use redis_interface::HsetnxReply;
 #[cfg(feature = "olap")]
 use router_env::logger;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 #[cfg(feature = "olap")]
 use crate::connection;
 use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
+    lookup::ReverseLookupInterface,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
     utils::{self, pg_connection_read, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
@@ -92,8 +95,47 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
                     payout_link_id: new.payout_link_id.clone(),
                     client_secret: new.client_secret.clone(),
                     priority: new.priority,
+                    payout_method_fingerprint: new.payout_method_fingerprint.clone(),
+                    approved_by: new.approved_by.clone(),
+                    approved_at: new.approved_at,
+                    purpose_code: new.purpose_code.clone(),
+                    webhook_url: new.webhook_url.clone(),
+                    value_date: new.value_date,
+                    charge_bearer: new.charge_bearer,
+                    statement_descriptor: new.statement_descriptor.clone(),
+                    merchant_reference_id: new.merchant_reference_id.clone(),
+                    requires_verification: new.requires_verification,
+                    verified_at: new.verified_at,
+                    verification_reference: new.verification_reference.clone(),
+                    settlement_network: new.settlement_network,
+                    routing: new.routing.clone(),
+                    eligible_connectors: new.eligible_connectors.clone(),
+                    created_by: new.created_by.clone(),
+                    fallback_to_standard: new.fallback_to_standard,
+                    originator: new.originator.clone(),
+                    backup_destinations: new.backup_destinations.clone(),
+                    is_merchant_reference_id_unique: new.is_merchant_reference_id_unique,
+                    series_id: new.series_id.clone(),
+                    currency_conversion: new.currency_conversion.clone(),
                 };
 
+                // Reverse lookup for merchant_reference_id, the SETNX on the lookup guards
+                // merchants which enforce unique merchant reference ids against racing creates
+                if let (Some(true), Some(merchant_reference_id)) = (
+                    new.is_merchant_reference_id_unique,
+                    new.merchant_reference_id.as_ref(),
+                ) {
+                    let reverse_lookup = ReverseLookupNew {
+                        lookup_id: format!("po_ref_{merchant_id}_{merchant_reference_id}"),
+                        pk_id: key_str.clone(),
+                        sk_id: field.clone(),
+                        source: "payouts".to_string(),
+                        updated_by: storage_scheme.to_string(),
+                    };
+                    self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                        .await?;
+                }
+
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Insert {
                         insertable: kv::Insertable::Payouts(new.to_storage_model()),
@@ -287,6 +329,73 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         .map(|payout| payout.map(Payouts::from_storage_model))
     }
 
+    #[instrument(skip_all)]
+    async fn find_optional_payout_by_merchant_id_merchant_reference_id(
+        &self,
+        merchant_id: &str,
+        merchant_reference_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<Payouts>, StorageError> {
+        let database_call = || async {
+            let conn = pg_connection_read(self).await?;
+            DieselPayouts::find_optional_by_merchant_id_merchant_reference_id(
+                &conn,
+                merchant_id,
+                merchant_reference_id,
+            )
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+        };
+        let storage_scheme =
+            decide_storage_scheme::<_, DieselPayouts>(self, storage_scheme, Op::Find).await;
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => database_call().await,
+            MerchantStorageScheme::RedisKv => {
+                let lookup_id = format!("po_ref_{merchant_id}_{merchant_reference_id}");
+                let lookup = fallback_reverse_lookup_not_found!(
+                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await,
+                    database_call()
+                        .await
+                        .map(|payout| payout.map(Payouts::from_storage_model))
+                );
+                let key = PartitionKey::CombinationKey {
+                    combination: &lookup.pk_id,
+                };
+                Box::pin(utils::try_redis_get_else_try_database_get(
+                    async {
+                        kv_wrapper::<DieselPayouts, _, _>(
+                            self,
+                            KvOperation::<DieselPayouts>::HGet(&lookup.sk_id),
+                            key,
+                        )
+                        .await?
+                        .try_into_hget()
+                        .map(Some)
+                    },
+                    database_call,
+                ))
+                .await
+            }
+        }
+        .map(|payout| payout.map(Payouts::from_storage_model))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payouts_by_merchant_id_series_id(
+        &self,
+        merchant_id: &str,
+        series_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        self.router_store
+            .find_payouts_by_merchant_id_series_id(merchant_id, series_id, storage_scheme)
+            .await
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
@@ -326,6 +435,45 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
             .filter_payouts_by_time_range_constraints(merchant_id, time_range, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "olap")]
+    #[instrument(skip_all)]
+    async fn find_earlier_payout_by_fingerprint_for_other_customer(
+        &self,
+        merchant_id: &str,
+        payout_method_fingerprint: &str,
+        customer_id: &id_type::CustomerId,
+        created_before: PrimitiveDateTime,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<Payouts>, StorageError> {
+        self.router_store
+            .find_earlier_payout_by_fingerprint_for_other_customer(
+                merchant_id,
+                payout_method_fingerprint,
+                customer_id,
+                created_before,
+                storage_scheme,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payout_count_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        created_at_or_before: PrimitiveDateTime,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<i64, StorageError> {
+        self.router_store
+            .get_payout_count_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                created_at_or_before,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -401,6 +549,49 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
             })
     }
 
+    #[instrument(skip_all)]
+    async fn find_optional_payout_by_merchant_id_merchant_reference_id(
+        &self,
+        merchant_id: &str,
+        merchant_reference_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<Payouts>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayouts::find_optional_by_merchant_id_merchant_reference_id(
+            &conn,
+            merchant_id,
+            merchant_reference_id,
+        )
+        .await
+        .map(|x| x.map(Payouts::from_storage_model))
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payouts_by_merchant_id_series_id(
+        &self,
+        merchant_id: &str,
+        series_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayouts::find_by_merchant_id_series_id(&conn, merchant_id, series_id)
+            .await
+            .map(|payouts| {
+                payouts
+                    .into_iter()
+                    .map(Payouts::from_storage_model)
+                    .collect()
+            })
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
@@ -433,6 +624,10 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
                 if let Some(customer_id) = &params.customer_id {
                     query = query.filter(po_dsl::customer_id.eq(customer_id.clone()));
                 }
+                if let Some(merchant_reference_id) = &params.merchant_reference_id {
+                    query = query
+                        .filter(po_dsl::merchant_reference_id.eq(merchant_reference_id.clone()));
+                }
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(po_dsl::profile_id.eq(profile_id.clone()));
                 }
@@ -555,6 +750,11 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
                     query = query.filter(po_dsl::customer_id.eq(customer_id.clone()));
                 }
 
+                if let Some(merchant_reference_id) = &params.merchant_reference_id {
+                    query = query
+                        .filter(po_dsl::merchant_reference_id.eq(merchant_reference_id.clone()));
+                }
+
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(po_dsl::profile_id.eq(profile_id.clone()));
                 }
@@ -663,6 +863,54 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
         self.filter_payouts_by_constraints(merchant_id, &payout_filters, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "olap")]
+    #[instrument(skip_all)]
+    async fn find_earlier_payout_by_fingerprint_for_other_customer(
+        &self,
+        merchant_id: &str,
+        payout_method_fingerprint: &str,
+        customer_id: &id_type::CustomerId,
+        created_before: PrimitiveDateTime,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<Payouts>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayouts::find_optional_by_merchant_id_payout_method_fingerprint_other_customer(
+            &conn,
+            merchant_id,
+            payout_method_fingerprint,
+            customer_id,
+            created_before,
+        )
+        .await
+        .map(|x| x.map(Payouts::from_storage_model))
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payout_count_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        created_at_or_before: PrimitiveDateTime,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<i64, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayouts::get_count_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            created_at_or_before,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
 }
 
 impl DataModelExt for Payouts {
@@ -694,6 +942,28 @@ impl DataModelExt for Payouts {
             payout_link_id: self.payout_link_id,
             client_secret: self.client_secret,
             priority: self.priority,
+            payout_method_fingerprint: self.payout_method_fingerprint,
+            approved_by: self.approved_by,
+            approved_at: self.approved_at,
+            purpose_code: self.purpose_code,
+            webhook_url: self.webhook_url,
+            value_date: self.value_date,
+            charge_bearer: self.charge_bearer,
+            statement_descriptor: self.statement_descriptor,
+            merchant_reference_id: self.merchant_reference_id,
+            requires_verification: self.requires_verification,
+            verified_at: self.verified_at,
+            verification_reference: self.verification_reference,
+            settlement_network: self.settlement_network,
+            routing: self.routing,
+            eligible_connectors: self.eligible_connectors,
+            created_by: self.created_by,
+            fallback_to_standard: self.fallback_to_standard,
+            originator: self.originator,
+            backup_destinations: self.backup_destinations,
+            is_merchant_reference_id_unique: self.is_merchant_reference_id_unique,
+            series_id: self.series_id,
+            currency_conversion: self.currency_conversion,
         }
     }
 
@@ -723,6 +993,28 @@ impl DataModelExt for Payouts {
             payout_link_id: storage_model.payout_link_id,
             client_secret: storage_model.client_secret,
             priority: storage_model.priority,
+            payout_method_fingerprint: storage_model.payout_method_fingerprint,
+            approved_by: storage_model.approved_by,
+            approved_at: storage_model.approved_at,
+            purpose_code: storage_model.purpose_code,
+            webhook_url: storage_model.webhook_url,
+            value_date: storage_model.value_date,
+            charge_bearer: storage_model.charge_bearer,
+            statement_descriptor: storage_model.statement_descriptor,
+            merchant_reference_id: storage_model.merchant_reference_id,
+            requires_verification: storage_model.requires_verification,
+            verified_at: storage_model.verified_at,
+            verification_reference: storage_model.verification_reference,
+            settlement_network: storage_model.settlement_network,
+            routing: storage_model.routing,
+            eligible_connectors: storage_model.eligible_connectors,
+            created_by: storage_model.created_by,
+            fallback_to_standard: storage_model.fallback_to_standard,
+            originator: storage_model.originator,
+            backup_destinations: storage_model.backup_destinations,
+            is_merchant_reference_id_unique: storage_model.is_merchant_reference_id_unique,
+            series_id: storage_model.series_id,
+            currency_conversion: storage_model.currency_conversion,
         }
     }
 }
@@ -755,6 +1047,28 @@ impl DataModelExt for PayoutsNew {
             payout_link_id: self.payout_link_id,
             client_secret: self.client_secret,
             priority: self.priority,
+            payout_method_fingerprint: self.payout_method_fingerprint,
+            approved_by: self.approved_by,
+            approved_at: self.approved_at,
+            purpose_code: self.purpose_code,
+            webhook_url: self.webhook_url,
+            value_date: self.value_date,
+            charge_bearer: self.charge_bearer,
+            statement_descriptor: self.statement_descriptor,
+            merchant_reference_id: self.merchant_reference_id,
+            requires_verification: self.requires_verification,
+            verified_at: self.verified_at,
+            verification_reference: self.verification_reference,
+            settlement_network: self.settlement_network,
+            routing: self.routing,
+            eligible_connectors: self.eligible_connectors,
+            created_by: self.created_by,
+            fallback_to_standard: self.fallback_to_standard,
+            originator: self.originator,
+            backup_destinations: self.backup_destinations,
+            is_merchant_reference_id_unique: self.is_merchant_reference_id_unique,
+            series_id: self.series_id,
+            currency_conversion: self.currency_conversion,
         }
     }
 
@@ -784,6 +1098,28 @@ impl DataModelExt for PayoutsNew {
             payout_link_id: storage_model.payout_link_id,
             client_secret: storage_model.client_secret,
             priority: storage_model.priority,
+            payout_method_fingerprint: storage_model.payout_method_fingerprint,
+            approved_by: storage_model.approved_by,
+            approved_at: storage_model.approved_at,
+            purpose_code: storage_model.purpose_code,
+            webhook_url: storage_model.webhook_url,
+            value_date: storage_model.value_date,
+            charge_bearer: storage_model.charge_bearer,
+            statement_descriptor: storage_model.statement_descriptor,
+            merchant_reference_id: storage_model.merchant_reference_id,
+            requires_verification: storage_model.requires_verification,
+            verified_at: storage_model.verified_at,
+            verification_reference: storage_model.verification_reference,
+            settlement_network: storage_model.settlement_network,
+            routing: storage_model.routing,
+            eligible_connectors: storage_model.eligible_connectors,
+            created_by: storage_model.created_by,
+            fallback_to_standard: storage_model.fallback_to_standard,
+            originator: storage_model.originator,
+            backup_destinations: storage_model.backup_destinations,
+            is_merchant_reference_id_unique: storage_model.is_merchant_reference_id_unique,
+            series_id: storage_model.series_id,
+            currency_conversion: storage_model.currency_conversion,
         }
     }
 }
@@ -805,6 +1141,11 @@ impl DataModelExt for PayoutsUpdate {
                 status,
                 confirm,
                 payout_type,
+                fallback_to_standard,
+                purpose_code,
+                originator,
+                backup_destinations,
+                currency_conversion,
             } => DieselPayoutsUpdate::Update {
                 amount,
                 destination_currency,
@@ -819,6 +1160,11 @@ impl DataModelExt for PayoutsUpdate {
                 status,
                 confirm,
                 payout_type,
+                fallback_to_standard,
+                purpose_code,
+                originator,
+                backup_destinations,
+                currency_conversion,
             },
             Self::PayoutMethodIdUpdate { payout_method_id } => {
                 DieselPayoutsUpdate::PayoutMethodIdUpdate { payout_method_id }
@@ -830,6 +1176,49 @@ impl DataModelExt for PayoutsUpdate {
                 DieselPayoutsUpdate::AttemptCountUpdate { attempt_count }
             }
             Self::StatusUpdate { status } => DieselPayoutsUpdate::StatusUpdate { status },
+            Self::PayoutMethodFingerprintUpdate {
+                payout_method_fingerprint,
+            } => DieselPayoutsUpdate::PayoutMethodFingerprintUpdate {
+                payout_method_fingerprint,
+            },
+            Self::DescriptionUpdate { description } => {
+                DieselPayoutsUpdate::DescriptionUpdate { description }
+            }
+            Self::ApprovalUpdate {
+                approved_by,
+                approved_at,
+                status,
+            } => DieselPayoutsUpdate::ApprovalUpdate {
+                approved_by,
+                approved_at,
+                status,
+            },
+            Self::ApprovalRevokeUpdate => DieselPayoutsUpdate::ApprovalRevokeUpdate,
+            Self::VerificationUpdate {
+                verified_at,
+                verification_reference,
+                status,
+            } => DieselPayoutsUpdate::VerificationUpdate {
+                verified_at,
+                verification_reference,
+                status,
+            },
+            Self::PriorityUpdate { priority } => DieselPayoutsUpdate::PriorityUpdate { priority },
+            Self::SettlementNetworkUpdate {
+                settlement_network,
+                priority,
+            } => DieselPayoutsUpdate::SettlementNetworkUpdate {
+                settlement_network,
+                priority,
+            },
+            Self::RoutingUpdate {
+                routing,
+                eligible_connectors,
+            } => DieselPayoutsUpdate::RoutingUpdate {
+                routing,
+                eligible_connectors,
+            },
+            Self::AddressUpdate { address_id } => DieselPayoutsUpdate::AddressUpdate { address_id },
         }
     }
 
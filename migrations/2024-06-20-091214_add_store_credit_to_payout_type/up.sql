@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutType" ADD VALUE IF NOT EXISTS 'store_credit';
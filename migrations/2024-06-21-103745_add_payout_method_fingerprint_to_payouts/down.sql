@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS payout_method_fingerprint_merchant_id_index;
+
+ALTER TABLE payouts DROP COLUMN IF EXISTS payout_method_fingerprint;
@@ -0,0 +1,3 @@
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS payout_method_fingerprint VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS payout_method_fingerprint_merchant_id_index ON payouts (merchant_id, payout_method_fingerprint);
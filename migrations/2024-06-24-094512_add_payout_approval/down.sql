@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payouts DROP COLUMN IF EXISTS approved_at;
+
+ALTER TABLE payouts DROP COLUMN IF EXISTS approved_by;
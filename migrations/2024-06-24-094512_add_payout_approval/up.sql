@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'pending_approval';
+
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS approved_by VARCHAR(255);
+
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS approved_at TIMESTAMP;
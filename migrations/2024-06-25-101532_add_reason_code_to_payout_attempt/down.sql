@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payout_attempt DROP COLUMN IF EXISTS reason;
+
+ALTER TABLE payout_attempt DROP COLUMN IF EXISTS reason_code;
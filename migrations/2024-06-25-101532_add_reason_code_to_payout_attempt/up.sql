@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payout_attempt ADD COLUMN IF NOT EXISTS reason_code VARCHAR(64);
+
+ALTER TABLE payout_attempt ADD COLUMN IF NOT EXISTS reason TEXT;
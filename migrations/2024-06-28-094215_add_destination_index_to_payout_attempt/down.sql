@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payout_attempt DROP COLUMN IF EXISTS destination_index;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE payout_attempt ADD COLUMN IF NOT EXISTS destination_index SMALLINT;
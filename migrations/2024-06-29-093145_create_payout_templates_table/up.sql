@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_templates (
+    template_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    currency "Currency",
+    payout_type "PayoutType",
+    entity_type VARCHAR(64),
+    connector JSONB,
+    description VARCHAR(255),
+    priority VARCHAR(32),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS payout_templates_merchant_id_index ON payout_templates (merchant_id);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'recalled';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_recalled';
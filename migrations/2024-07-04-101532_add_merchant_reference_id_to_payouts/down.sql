@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS merchant_reference_id_merchant_id_index;
+
+ALTER TABLE payouts DROP COLUMN IF EXISTS merchant_reference_id;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS merchant_reference_id VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS merchant_reference_id_merchant_id_index ON payouts (merchant_id, merchant_reference_id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payouts DROP COLUMN IF EXISTS verification_reference;
+
+ALTER TABLE payouts DROP COLUMN IF EXISTS verified_at;
+
+ALTER TABLE payouts DROP COLUMN IF EXISTS requires_verification;
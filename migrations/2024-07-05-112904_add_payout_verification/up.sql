@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'pending_verification';
+
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS requires_verification BOOLEAN;
+
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS verified_at TIMESTAMP;
+
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS verification_reference VARCHAR(255);
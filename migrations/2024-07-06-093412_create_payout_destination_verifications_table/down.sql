@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payout_destination_verifications_fingerprint_index;
+DROP TABLE IF EXISTS payout_destination_verifications;
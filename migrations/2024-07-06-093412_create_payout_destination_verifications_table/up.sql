@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_destination_verifications (
+    verification_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    payout_method_fingerprint VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    micro_deposits JSONB NOT NULL,
+    remaining_attempts INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    verified_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS payout_destination_verifications_fingerprint_index ON payout_destination_verifications (merchant_id, customer_id, payout_method_fingerprint);
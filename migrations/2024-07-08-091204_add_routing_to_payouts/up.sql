@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS routing JSONB;
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS eligible_connectors JSONB;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS store_credit_ledger_entries_payout_id_index;
+DROP TABLE IF EXISTS store_credit_ledger_entries;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS merchant_reference_id_merchant_id_unique_index;
+
+DROP INDEX IF EXISTS merchant_reference_id_merchant_id_index;
+
+CREATE INDEX IF NOT EXISTS merchant_reference_id_merchant_id_index ON payouts (merchant_id, merchant_reference_id);
+
+ALTER TABLE payouts DROP COLUMN IF EXISTS is_merchant_reference_id_unique;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS is_merchant_reference_id_unique BOOLEAN;
+
+DROP INDEX IF EXISTS merchant_reference_id_merchant_id_index;
+
+CREATE INDEX IF NOT EXISTS merchant_reference_id_merchant_id_index ON payouts (merchant_id, merchant_reference_id) WHERE merchant_reference_id IS NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS merchant_reference_id_merchant_id_unique_index ON payouts (merchant_id, merchant_reference_id) WHERE merchant_reference_id IS NOT NULL AND is_merchant_reference_id_unique;
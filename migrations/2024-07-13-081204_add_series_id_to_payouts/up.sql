@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS series_id VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS payouts_merchant_id_series_id_index ON payouts (merchant_id, series_id) WHERE series_id IS NOT NULL;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS currency_conversion_audit_logs_merchant_id_payout_id_index;
+DROP TABLE IF EXISTS currency_conversion_audit_logs;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS currency_conversion_audit_logs (
+    audit_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payout_id VARCHAR(64),
+    source_amount BIGINT NOT NULL,
+    source_currency VARCHAR(32) NOT NULL,
+    destination_currency VARCHAR(32) NOT NULL,
+    converted_amount VARCHAR(64) NOT NULL,
+    exchange_rate VARCHAR(64) NOT NULL,
+    exchange_rate_source VARCHAR(32),
+    exchange_rate_timestamp TIMESTAMP NOT NULL,
+    is_binding BOOLEAN NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS currency_conversion_audit_logs_merchant_id_payout_id_index ON currency_conversion_audit_logs (merchant_id, payout_id);
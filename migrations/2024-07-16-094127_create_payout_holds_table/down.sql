@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payout_holds_merchant_id_status_index;
+DROP TABLE IF EXISTS payout_holds;